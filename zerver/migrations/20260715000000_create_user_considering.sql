@@ -0,0 +1,10 @@
+-- Per-user "considering" list: a cross-deck wishlist the swipe UI fills with
+-- an up-swipe, independent of any deck's maybeboard. Keyed by printing so the
+-- card the user saw is the card they get back; one row per (user, card) —
+-- re-adding is a no-op. Cascade: account delete, or the printing disappearing.
+CREATE TABLE user_considering (
+    user_id          UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    scryfall_data_id UUID        NOT NULL REFERENCES scryfall_data(id) ON DELETE CASCADE,
+    added_at         TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (user_id, scryfall_data_id)
+);
@@ -0,0 +1,10 @@
+-- Per-user favorite cards. Rapid swiping toggles these in batches, so writes
+-- arrive as a set of (card, on/off) changes applied in one transaction. Keyed
+-- by printing; one row per (user, card). Cascade: account delete, or the
+-- printing disappearing.
+CREATE TABLE user_favorites (
+    user_id          UUID        NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    scryfall_data_id UUID        NOT NULL REFERENCES scryfall_data(id) ON DELETE CASCADE,
+    favorited_at     TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (user_id, scryfall_data_id)
+);
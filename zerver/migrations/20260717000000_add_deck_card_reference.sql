@@ -0,0 +1,52 @@
+-- Deck cards can follow their oracle card instead of a pinned printing.
+-- 'printing' (default, all existing rows) keeps the chosen printing exactly;
+-- 'oracle' is re-pointed to the card's preferred printing during each card
+-- sync (and by the repoint_oracle_deck_cards trigger below when a printing is
+-- deleted), so the deck survives printings being dropped or superseded.
+ALTER TABLE deck_cards
+    ADD COLUMN reference TEXT NOT NULL DEFAULT 'printing',
+    ADD CONSTRAINT valid_reference CHECK (reference IN ('printing', 'oracle'));
+
+-- The printing an oracle card resolves to: its latest_cards row when that
+-- survives, otherwise the best remaining printing that still has a card
+-- profile (card reads join through card_profiles), ranked like latest_cards
+-- itself. NULL when no printing is left.
+-- `excluded_id` skips a printing that is on its way out.
+CREATE FUNCTION preferred_printing(target_oracle_id UUID, excluded_id UUID DEFAULT NULL)
+RETURNS UUID
+LANGUAGE sql STABLE AS $$
+    SELECT sd.id
+    FROM scryfall_data sd
+    JOIN card_profiles cp ON cp.scryfall_data_id = sd.id
+    WHERE sd.oracle_id = target_oracle_id
+      AND sd.id IS DISTINCT FROM excluded_id
+    ORDER BY EXISTS (SELECT 1 FROM latest_cards lc WHERE lc.id = sd.id) DESC,
+             sd.digital ASC,
+             sd.promo ASC,
+             sd.oversized ASC,
+             COALESCE(sd.content_warning, false) ASC,
+             sd.released_at DESC,
+             sd.id
+    LIMIT 1
+$$;
+
+-- deck_cards.scryfall_data_id cascades on delete, which would drop an
+-- oracle-referenced card along with its printing. Re-point those rows to a
+-- surviving printing first; pinned rows (and oracle rows with no surviving
+-- printing) still cascade as before.
+CREATE FUNCTION repoint_oracle_deck_cards() RETURNS trigger
+LANGUAGE plpgsql AS $$
+BEGIN
+    UPDATE deck_cards
+    SET scryfall_data_id = preferred_printing(oracle_id, OLD.id),
+        updated_at = NOW()
+    WHERE scryfall_data_id = OLD.id
+      AND reference = 'oracle'
+      AND preferred_printing(oracle_id, OLD.id) IS NOT NULL;
+    RETURN OLD;
+END;
+$$;
+
+CREATE TRIGGER repoint_oracle_deck_cards
+    BEFORE DELETE ON scryfall_data
+    FOR EACH ROW EXECUTE FUNCTION repoint_oracle_deck_cards();
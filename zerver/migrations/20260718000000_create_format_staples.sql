@@ -0,0 +1,42 @@
+-- Precomputed commander staples: the top 100 cards by edhrec_rank within each
+-- exact color identity bucket, refreshed by zervice after latest_cards. The
+-- recommender asks for "staples playable under identity X" over and over;
+-- reading the union of X's subset buckets here replaces a full latest_cards
+-- scan + sort per request. Any top-K (K <= 100) over a union of buckets is
+-- contained in the union of each bucket's top-K, so the read stays exact.
+--
+-- identity is the color_identity sorted bytewise, so {U,W} and {W,U} land in
+-- the same bucket and `identity <@ $1` selects every subset bucket.
+-- Basic lands and tokens are excluded; only commander-legal cards qualify.
+CREATE MATERIALIZED VIEW format_staples AS
+SELECT identity, bucket_rank, id, edhrec_rank
+FROM (
+    SELECT ARRAY(SELECT c FROM unnest(lc.color_identity) AS c ORDER BY c) AS identity,
+           ROW_NUMBER() OVER (
+               PARTITION BY ARRAY(SELECT c FROM unnest(lc.color_identity) AS c ORDER BY c)
+               ORDER BY lc.edhrec_rank ASC, lc.name ASC, lc.id
+           )::INT AS bucket_rank,
+           lc.id,
+           lc.edhrec_rank
+    FROM latest_cards lc
+    JOIN card_profiles cp ON cp.scryfall_data_id = lc.id
+    WHERE lc.edhrec_rank IS NOT NULL
+      AND lc.legalities->>'commander' = 'legal'
+      AND NOT cp.is_token
+      AND COALESCE(lc.type_line, '') NOT ILIKE 'Basic%Land%'
+) ranked
+WHERE bucket_rank <= 100
+WITH NO DATA;
+
+CREATE UNIQUE INDEX idx_format_staples_id ON format_staples (id);
+CREATE INDEX idx_format_staples_identity_rank ON format_staples (identity, bucket_rank);
+
+-- Populate now when latest_cards already is (existing deployments), so reads
+-- work before the next sync. A fresh database has latest_cards unpopulated
+-- too, and the first zervice run fills both.
+DO $$
+BEGIN
+    IF (SELECT relispopulated FROM pg_class WHERE relname = 'latest_cards') THEN
+        REFRESH MATERIALIZED VIEW format_staples;
+    END IF;
+END $$;
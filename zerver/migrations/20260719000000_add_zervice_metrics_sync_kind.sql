@@ -0,0 +1,6 @@
+-- Record whether each sync rewrote every card ('full') or only changed ones
+-- ('partial'), so the configurable sync schedule can find the last full run.
+-- Every sync before this column was a delta upsert, hence the default.
+ALTER TABLE zervice_metrics
+    ADD COLUMN sync_kind TEXT NOT NULL DEFAULT 'partial'
+    CHECK (sync_kind IN ('full', 'partial'));
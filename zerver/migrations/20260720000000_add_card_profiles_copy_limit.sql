@@ -0,0 +1,21 @@
+-- A card's own exception to its format's copy limit, read from its rules text
+-- at upsert: 'any' ("A deck can have any number of cards named ...") or a
+-- count ("... up to seven cards named ..."). NULL means the format's limit.
+ALTER TABLE card_profiles ADD COLUMN copy_limit TEXT;
+
+-- Backfill existing cards; later syncs keep the column current.
+UPDATE card_profiles cp
+SET copy_limit = 'any'
+FROM scryfall_data sd
+WHERE sd.id = cp.scryfall_data_id
+  AND sd.oracle_text ILIKE '%a deck can have any number of cards named%';
+
+UPDATE card_profiles cp
+SET copy_limit = CASE lower(substring(sd.oracle_text FROM '(?i)a deck can have up to (\w+) cards named'))
+    WHEN 'two' THEN '2' WHEN 'three' THEN '3' WHEN 'four' THEN '4'
+    WHEN 'five' THEN '5' WHEN 'six' THEN '6' WHEN 'seven' THEN '7'
+    WHEN 'eight' THEN '8' WHEN 'nine' THEN '9' WHEN 'ten' THEN '10'
+END
+FROM scryfall_data sd
+WHERE sd.id = cp.scryfall_data_id
+  AND sd.oracle_text ILIKE '%a deck can have up to % cards named%';
@@ -0,0 +1,17 @@
+-- User-defined deck labels ("cedh", "budget", "jank"): free text, per user,
+-- stored normalized (trimmed, lowercased) so filtering is a plain equality.
+-- Distinct from decks.tags / decks.other_tags, which hold curated slugs.
+-- user_id is the deck owner's, denormalized so a user's labels can be listed
+-- and filtered without joining decks.
+CREATE TABLE deck_labels (
+    deck_id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    label TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (deck_id, label),
+    CONSTRAINT fk_deck_labels_deck
+        FOREIGN KEY (deck_id) REFERENCES decks(id) ON DELETE CASCADE,
+    CONSTRAINT fk_deck_labels_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+CREATE INDEX idx_deck_labels_user_label ON deck_labels(user_id, label);
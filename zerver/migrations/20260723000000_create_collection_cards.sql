@@ -0,0 +1,12 @@
+-- A user's physical card collection: how many copies they own of each card.
+-- Keyed by oracle id so any printing counts toward a deck's needs. Only
+-- positive quantities are stored; setting a card to 0 deletes its row.
+CREATE TABLE collection_cards (
+    user_id UUID NOT NULL,
+    oracle_id UUID NOT NULL,
+    quantity INT NOT NULL CHECK (quantity > 0),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, oracle_id),
+    CONSTRAINT fk_collection_cards_user
+        FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
@@ -0,0 +1,4 @@
+-- Partial syncs now refresh only prices for cards whose gameplay fields are
+-- unchanged; count those separately from full rewrites.
+ALTER TABLE zervice_metrics
+    ADD COLUMN price_updated_count INT NOT NULL DEFAULT 0;
@@ -0,0 +1,5 @@
+-- Account role. 'admin' unlocks maintainer-only endpoints (e.g. raw card
+-- inspection); granted by hand in the database, never through the API.
+ALTER TABLE users
+    ADD COLUMN role TEXT NOT NULL DEFAULT 'user'
+    CHECK (role IN ('user', 'admin'));
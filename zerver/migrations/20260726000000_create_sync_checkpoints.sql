@@ -0,0 +1,10 @@
+-- How far an in-flight card sync got, so a run that dies halfway resumes
+-- instead of starting over. One row per sync kind; download_uri pins the
+-- bulk file the position refers to (Scryfall's URIs are timestamped), so a
+-- checkpoint into an older file is ignored. Cleared when a sync completes.
+CREATE TABLE sync_checkpoints (
+    sync_kind TEXT PRIMARY KEY,
+    download_uri TEXT NOT NULL,
+    processed_count INT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,10 @@
+-- Per-deck copy limit: 'singleton', 'standard' or 'custom:<n>' (a house
+-- rule). New decks take their format's limit; existing decks are backfilled
+-- the same way so validation doesn't change under them.
+ALTER TABLE decks ADD COLUMN copy_limit TEXT NOT NULL DEFAULT 'standard';
+
+UPDATE decks SET copy_limit = 'singleton'
+WHERE format IN (
+    'commander', 'brawl', 'standardbrawl', 'historicbrawl',
+    'paupercommander', 'duel', 'predh', 'oathbreaker', 'gladiator'
+);
@@ -0,0 +1,43 @@
+-- Legality history: one row each time a card's status in a format changes,
+-- so decks hit by a banlist update can be found after the sync that
+-- applied it. Rows are per oracle card; a sync updating every printing of
+-- a card records the change once.
+CREATE TABLE legality_changes (
+    id BIGSERIAL PRIMARY KEY,
+    oracle_id UUID NOT NULL,
+    format TEXT NOT NULL,
+    status TEXT NOT NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX legality_changes_status_changed_at ON legality_changes (status, changed_at);
+CREATE INDEX legality_changes_oracle_format ON legality_changes (oracle_id, format, changed_at DESC);
+
+-- Only updates are watched: a card's first insert is its starting point,
+-- not a change. A status is recorded unless it's already the latest one
+-- on file for that card and format.
+CREATE FUNCTION record_legality_changes() RETURNS trigger
+LANGUAGE plpgsql AS $$
+BEGIN
+    IF NEW.oracle_id IS NULL THEN
+        RETURN NEW;
+    END IF;
+    INSERT INTO legality_changes (oracle_id, format, status)
+    SELECT NEW.oracle_id, l.key, l.value
+    FROM jsonb_each_text(NEW.legalities) l
+    WHERE l.value IS DISTINCT FROM OLD.legalities->>l.key
+      AND l.value IS DISTINCT FROM (
+          SELECT lc.status FROM legality_changes lc
+          WHERE lc.oracle_id = NEW.oracle_id AND lc.format = l.key
+          ORDER BY lc.changed_at DESC, lc.id DESC
+          LIMIT 1
+      );
+    RETURN NEW;
+END;
+$$;
+
+CREATE TRIGGER record_legality_changes
+    AFTER UPDATE OF legalities ON scryfall_data
+    FOR EACH ROW
+    WHEN (OLD.legalities IS DISTINCT FROM NEW.legalities)
+    EXECUTE FUNCTION record_legality_changes();
@@ -0,0 +1,7 @@
+-- A card can sit on the sideboard and the deck at once (2 main, 2 side):
+-- the sideboard keeps its own row. Deck and maybeboard still share one row
+-- per card, so moving between them stays a board change.
+ALTER TABLE deck_cards DROP CONSTRAINT deck_card_oracle_unique;
+
+CREATE UNIQUE INDEX deck_cards_oracle_side_unique
+    ON deck_cards (deck_id, oracle_id, (board = 'sideboard'));
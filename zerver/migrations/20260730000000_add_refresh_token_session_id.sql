@@ -0,0 +1,12 @@
+-- A refresh token row is replaced on every rotation, so its serial id can't
+-- name a session. session_id is minted at login and carried across rotations
+-- (with the login time), giving each device a stable handle to list and
+-- revoke. Existing rows each become their own session.
+
+ALTER TABLE refresh_tokens
+    ADD COLUMN session_id UUID NOT NULL DEFAULT gen_random_uuid(),
+    ADD COLUMN session_started_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
+
+UPDATE refresh_tokens SET session_started_at = created_at;
+
+CREATE INDEX idx_refresh_tokens_session_id ON refresh_tokens(session_id);
@@ -0,0 +1,9 @@
+-- Rotated refresh tokens are kept (marked rotated_at) instead of deleted, so
+-- one presented again is recognised as reuse: a stolen token racing its
+-- owner. Reuse revokes every session of the user. Kept rows expire with the
+-- token they were, through the usual expired-token cleanup. session_id is
+-- the rotation family: every token of one sign-in shares it.
+
+ALTER TABLE refresh_tokens ADD COLUMN rotated_at TIMESTAMPTZ;
+
+CREATE INDEX idx_refresh_tokens_live ON refresh_tokens(user_id) WHERE rotated_at IS NULL;
@@ -0,0 +1,11 @@
+-- Usernames are unique regardless of case, so "John" and "john" can't both
+-- exist. The casing a user chose is still stored and displayed; only the
+-- uniqueness check (and the login lookup) folds case. The lowercased unique
+-- index replaces both the exact-match UNIQUE constraint and the plain
+-- username index. Existing case-insensitive duplicates must be renamed by
+-- hand before this runs.
+
+ALTER TABLE users DROP CONSTRAINT users_username_key;
+DROP INDEX idx_users_username;
+
+CREATE UNIQUE INDEX idx_users_username_lower ON users (LOWER(username));
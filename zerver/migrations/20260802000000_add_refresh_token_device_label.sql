@@ -0,0 +1,6 @@
+-- Friendly name of the device a session was opened from ("Chrome on macOS"),
+-- from a client-provided name or the User-Agent. Display only; carried
+-- across rotations like platform. NULL (older rows, unrecognised agents) is
+-- listed as "Unknown device".
+
+ALTER TABLE refresh_tokens ADD COLUMN device_label TEXT;
@@ -0,0 +1,7 @@
+-- Batch name resolution (deck import, multi-card adds) matches every printing
+-- by lowercased full name or by the lowercased front face of a double-faced
+-- card. Index both expressions so a 100-name lookup stays an index scan.
+
+CREATE INDEX IF NOT EXISTS idx_scryfall_data_lower_name ON scryfall_data (LOWER(name));
+CREATE INDEX IF NOT EXISTS idx_scryfall_data_lower_front_face
+    ON scryfall_data (LOWER(split_part(name, ' // ', 1)));
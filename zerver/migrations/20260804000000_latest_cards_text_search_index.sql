@@ -0,0 +1,12 @@
+-- Everything-search (`text_search`) matches one English tsvector over name,
+-- type line, oracle and flavor text, ranked by ts_rank for the Relevance
+-- sort. Index that exact expression on latest_cards so the @@ match is a GIN
+-- lookup; the search's expression must stay identical to this one.
+
+CREATE INDEX IF NOT EXISTS idx_latest_cards_text_search ON latest_cards USING GIN (
+    to_tsvector(
+        'english',
+        COALESCE(name, '') || ' ' || COALESCE(type_line, '') || ' ' ||
+        COALESCE(oracle_text, '') || ' ' || COALESCE(flavor_text, '')
+    )
+);
@@ -0,0 +1,15 @@
+-- Scryfall's set catalog, synced from `/sets`. Cards only carry their set's
+-- code and name; the symbol lives on the set, and child sets (tokens, promos,
+-- art series) reuse their parent's, so it can't be derived from the code.
+
+CREATE TABLE scryfall_sets (
+    code TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    parent_set_code TEXT,
+    icon_svg_uri TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Set lookups against the printings (icon fallback, set completion) match the
+-- code case-insensitively; index that expression so they stay index scans.
+CREATE INDEX IF NOT EXISTS idx_scryfall_data_lower_set ON scryfall_data (LOWER(set));
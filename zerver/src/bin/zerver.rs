@@ -3,7 +3,10 @@ use tracing_appender::rolling::{RollingFileAppender, Rotation};
 use tracing_subscriber::{EnvFilter, Layer, layer::SubscriberExt, util::SubscriberInitExt};
 use zwipe::{
     config::Config,
-    domain::{auth, card, deck, health, metrics, user},
+    domain::{
+        auth::{self, models::password::Argon2Params},
+        card, deck, health, metrics, user,
+    },
     inbound::http::{HttpServer, HttpServerConfig},
     outbound::{resend::Resend, sqlx::postgres::Postgres},
 };
@@ -54,6 +57,20 @@ async fn run() -> anyhow::Result<()> {
         )
         .init();
     tracing::info!("zerver running v{}", env!("CARGO_PKG_VERSION"));
+    Argon2Params::init_shared(config.argon2_params);
+    #[cfg(feature = "hibp")]
+    if config.hibp_breach_check {
+        zwipe::inbound::external::hibp::PwnedPasswords::init_shared().map_err(|e| {
+            anyhow::anyhow!(
+                "HIBP_BREACH_CHECK is set but the breach check client failed to build: {e}"
+            )
+        })?;
+        tracing::info!("password breach check enabled");
+    }
+    #[cfg(not(feature = "hibp"))]
+    if config.hibp_breach_check {
+        tracing::warn!("HIBP_BREACH_CHECK is set but zerver was built without the hibp feature");
+    }
     let db = Postgres::new(&config.database_url).await?;
     let resend = Resend::new(config.resend_api_key, config.resend_from_email);
     let auth_service = auth::services::Service::new(
@@ -63,10 +80,15 @@ async fn run() -> anyhow::Result<()> {
         config.jwt_secret,
         config.web_base_url.clone(),
         config.support_email_address,
-    );
+    )
+    .with_rehash_on_login(config.rehash_passwords_on_login)
+    .with_session_policy(config.session_policy);
     let user_service = user::services::Service::new(db.clone());
-    let health_service = health::services::Service::new(db.clone());
-    let card_service = card::services::Service::new(db.clone());
+    let health_service =
+        health::services::Service::new(db.clone()).with_sync_freshness(config.sync_freshness);
+    let card_service = card::services::Service::new(db.clone())
+        .with_search_cache(config.search_cache)
+        .with_filter_options_cache(config.filter_options_cache);
     let deck_service = deck::services::Service::new(db.clone(), db.clone());
     let metrics_service: Arc<dyn metrics::ports::ErasedMetricsService> =
         Arc::new(metrics::services::Service::new(db.clone()));
@@ -75,6 +97,10 @@ async fn run() -> anyhow::Result<()> {
         allowed_origins: config.allowed_origins,
         min_client_version: config.min_client_version,
         web_base_url: config.web_base_url,
+        trusted_proxies: config.trusted_proxies,
+        https_policy: config.https_policy,
+        security_headers: config.security_headers,
+        body_limits: config.body_limits,
     };
     let http_server = HttpServer::new(
         auth_service,
@@ -1,12 +1,21 @@
+use anyhow::Context;
+use std::path::Path;
 use tracing_appender::rolling::{RollingFileAppender, Rotation};
 use tracing_subscriber::{EnvFilter, Layer, layer::SubscriberExt, util::SubscriberInitExt};
 use zwipe::{
     config::Config,
     domain::{
         auth::{ports::AuthService, services::Service as AuthService_},
-        card::{ports::CardService, services::Service as CardService_},
+        card::{
+            models::{sync_progress::SyncProgressReporter, sync_schedule::SyncSchedule},
+            ports::CardService,
+            services::Service as CardService_,
+        },
+    },
+    inbound::external::scryfall::{
+        bulk::{BulkEndpoint, BulkParse, validate_bulk_file},
+        client::ScryfallClient,
     },
-    inbound::external::scryfall::bulk::BulkEndpoint,
     outbound::{resend::Resend, sqlx::postgres::Postgres},
 };
 use zwipe_core::domain::logo;
@@ -16,6 +25,7 @@ async fn main() -> anyhow::Result<()> {
     logo::Zervice::print();
     let config = Config::from_env()?;
     let args: Vec<String> = std::env::args().collect();
+    ScryfallClient::init_shared(config.scryfall_min_interval);
 
     // See zerver.rs for the rationale — RUST_LOG from the process env wins; otherwise
     // we use the directive string from Config. Per-layer because EnvFilter isn't Clone.
@@ -59,8 +69,60 @@ async fn main() -> anyhow::Result<()> {
         }
     );
 
+    // `--validate-bulk <path>`: dry-parse a downloaded bulk file and exit, no
+    // database needed. Non-zero exit when any record fails, for CI.
+    if let Some(path) = args
+        .iter()
+        .position(|a| a == "--validate-bulk")
+        .and_then(|i| args.get(i + 1))
+    {
+        let report = validate_bulk_file(Path::new(path))?;
+        tracing::info!("bulk validation of {path}: {report}");
+        for failure in &report.failures {
+            tracing::error!("bulk validation failure: {failure}");
+        }
+        if !report.is_clean() {
+            anyhow::bail!(
+                "{} of {} records failed to parse",
+                report.failed,
+                report.total()
+            );
+        }
+        return Ok(());
+    }
+
     let db = Postgres::new(&config.database_url).await?;
-    let card_service = CardService_::new(db.clone());
+    // `--whole-file`: parse the bulk download in one piece instead of
+    // streaming it. Only sensible against small fixture files.
+    let bulk_parse = if args.iter().any(|a| a == "--whole-file") {
+        BulkParse::WholeFile
+    } else {
+        BulkParse::Streaming
+    };
+    let card_service = CardService_::new(db.clone())
+        .with_bulk_parse(bulk_parse)
+        .with_sync_concurrency(config.sync_concurrency)
+        .with_sync_batch_size(config.sync_batch_size)
+        .with_download_retry(config.download_retry)
+        .with_sync_progress(SyncProgressReporter::new(
+            config.sync_progress_interval,
+            |progress| tracing::info!("card sync progress: {progress}"),
+        ));
+
+    // `--sets <codes>`: sync only these sets (comma-separated, e.g. `mh3,one`)
+    // via Scryfall's card search, refresh the latest-cards view, and exit.
+    // For local development and fixtures; the schedule is left untouched.
+    if let Some(sets) = args
+        .iter()
+        .position(|a| a == "--sets")
+        .and_then(|i| args.get(i + 1))
+    {
+        let set_codes: Vec<String> = sets.split(',').map(str::to_string).collect();
+        card_service.scryfall_sync_sets(&set_codes).await?;
+        card_service.refresh_latest_cards().await?;
+        return Ok(());
+    }
+
     let resend = Resend::new(config.resend_api_key, config.resend_from_email);
     let auth_service = AuthService_::new(
         db.clone(),
@@ -71,17 +133,91 @@ async fn main() -> anyhow::Result<()> {
         config.support_email_address,
     );
 
+    // `--watch`: stay resident and re-run the pipeline every check interval;
+    // the schedule decides whether each pass syncs cards. Without it zervice
+    // runs one pass and exits (cron).
+    if args.iter().any(|a| a == "--watch") {
+        let check_interval = config
+            .sync_schedule
+            .check_interval
+            .to_std()
+            .context("check interval must be positive")?;
+        let mut recategorize = recategorize;
+        loop {
+            let failures = run_pipeline(
+                &card_service,
+                &auth_service,
+                &config.sync_schedule,
+                recategorize,
+            )
+            .await;
+            if failures > 0 {
+                tracing::error!("zervice pass finished with {failures} failed step(s)");
+            }
+            recategorize = false;
+            tokio::time::sleep(check_interval).await;
+        }
+    }
+
+    let failures = run_pipeline(
+        &card_service,
+        &auth_service,
+        &config.sync_schedule,
+        recategorize,
+    )
+    .await;
+
+    if failures == 0 {
+        tracing::info!("zervice completed: all 6 steps ok");
+        Ok(())
+    } else {
+        tracing::error!("zervice completed with {failures} failed step(s) — see errors above");
+        anyhow::bail!("zervice finished with {failures} failed step(s)")
+    }
+}
+
+/// One zervice pass: card sync (when the schedule says one is due), then the
+/// set catalog, oracle tags, categories, matview refreshes and session
+/// pruning. Returns the number of failed steps.
+async fn run_pipeline(
+    card_service: &impl CardService,
+    auth_service: &impl AuthService,
+    schedule: &SyncSchedule,
+    recategorize: bool,
+) -> u32 {
     // Every step runs non-fatally: a failure logs and the pipeline continues, so one
     // broken step can't skip the rest (e.g. the serve-critical matview refreshes).
-    // Failures are tallied and surfaced as a non-zero exit at the end.
+    // Failures are tallied and surfaced to the caller.
     let mut failures = 0u32;
 
-    tracing::info!("step 1/5 card sync (default_cards): starting");
-    match card_service.scryfall_sync(BulkEndpoint::DefaultCards).await {
-        Ok(_) => tracing::info!("step 1/5 card sync: ok"),
+    tracing::info!("step 1/6 card sync (default_cards): starting");
+    match card_service.due_sync(schedule).await {
+        Ok(Some(sync_kind)) => {
+            match card_service
+                .scryfall_sync(BulkEndpoint::DefaultCards, sync_kind)
+                .await
+            {
+                Ok(_) => tracing::info!("step 1/6 card sync ({sync_kind}): ok"),
+                Err(e) => {
+                    failures += 1;
+                    tracing::error!("step 1/6 card sync ({sync_kind}) FAILED (continuing): {e:#}");
+                }
+            }
+        }
+        Ok(None) => tracing::info!("step 1/6 card sync: not due, skipping"),
         Err(e) => {
             failures += 1;
-            tracing::error!("step 1/5 card sync FAILED (continuing): {e:#}");
+            tracing::error!("step 1/6 card sync schedule check FAILED (continuing): {e:#}");
+        }
+    }
+    // The set catalog is one request, so it's kept current whether or not
+    // cards were due.
+    tracing::info!("step 2/6 set catalog: starting");
+    match card_service.sync_set_catalog().await {
+        Ok(sets) => tracing::info!("step 2/6 set catalog: synced {sets} sets"),
+        Err(e) => {
+            failures += 1;
+            tracing::error!("step 2/6 set catalog sync FAILED (continuing): {e:#}");
         }
     }
 
@@ -93,79 +229,79 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
-    tracing::info!("step 2/5 oracle tags: starting");
+    tracing::info!("step 3/6 oracle tags: starting");
     match card_service.sync_oracle_tags().await {
         Ok((tags, correlations)) => {
-            tracing::info!("step 2/5 oracle tags: synced {tags} tags, {correlations} correlations");
+            tracing::info!("step 3/6 oracle tags: synced {tags} tags, {correlations} correlations");
             match card_service.refresh_card_oracle_tags().await {
-                Ok(()) => tracing::info!("step 2/5 oracle tags projection: ok"),
+                Ok(()) => tracing::info!("step 3/6 oracle tags projection: ok"),
                 Err(e) => {
                     failures += 1;
-                    tracing::error!("step 2/5 oracle tags projection FAILED (continuing): {e:#}");
+                    tracing::error!("step 3/6 oracle tags projection FAILED (continuing): {e:#}");
                 }
             }
             match card_service.refresh_oracle_tag_groups().await {
-                Ok(rows) => tracing::info!("step 2/5 oracle tags grouping: {rows} rows"),
+                Ok(rows) => tracing::info!("step 3/6 oracle tags grouping: {rows} rows"),
                 Err(e) => {
                     failures += 1;
-                    tracing::error!("step 2/5 oracle tags grouping FAILED (continuing): {e:#}");
+                    tracing::error!("step 3/6 oracle tags grouping FAILED (continuing): {e:#}");
                 }
             }
         }
         Err(e) => {
             failures += 1;
             tracing::error!(
-                "step 2/5 oracle tags sync FAILED (skipping projection, continuing): {e:#}"
+                "step 3/6 oracle tags sync FAILED (skipping projection, continuing): {e:#}"
             );
         }
     }
 
-    tracing::info!("step 3/5 derive categories (otags + gaps): starting");
+    tracing::info!("step 4/6 derive categories (otags + gaps): starting");
     match card_service.derive_card_categories(1000).await {
         Ok((otag_rows, merges)) => {
             tracing::info!(
-                "step 3/5 derive categories: {otag_rows} rows from otags, {merges} straggler merges"
+                "step 4/6 derive categories: {otag_rows} rows from otags, {merges} straggler merges"
             );
         }
         Err(e) => {
             failures += 1;
-            tracing::error!("step 3/5 derive categories FAILED (continuing): {e:#}");
+            tracing::error!("step 4/6 derive categories FAILED (continuing): {e:#}");
         }
     }
 
-    tracing::info!("step 4/5 refresh materialized views: starting");
+    tracing::info!("step 5/6 refresh materialized views: starting");
     if let Err(e) = card_service.refresh_latest_cards().await {
         failures += 1;
-        tracing::error!("step 4/5 latest_cards refresh FAILED (continuing): {e:#}");
+        tracing::error!("step 5/6 latest_cards refresh FAILED (continuing): {e:#}");
     } else {
-        tracing::info!("step 4/5 latest_cards: refreshed");
+        tracing::info!("step 5/6 latest_cards: refreshed");
     }
     if let Err(e) = card_service.refresh_card_signal_rollup().await {
         failures += 1;
-        tracing::error!("step 4/5 card_signal_rollup refresh FAILED (continuing): {e:#}");
+        tracing::error!("step 5/6 card_signal_rollup refresh FAILED (continuing): {e:#}");
     } else {
-        tracing::info!("step 4/5 card_signal_rollup: refreshed");
+        tracing::info!("step 5/6 card_signal_rollup: refreshed");
     }
     if let Err(e) = card_service.refresh_otag_context_signal_rollup().await {
         failures += 1;
-        tracing::error!("step 4/5 otag_context_signal_rollup refresh FAILED (continuing): {e:#}");
+        tracing::error!("step 5/6 otag_context_signal_rollup refresh FAILED (continuing): {e:#}");
     } else {
-        tracing::info!("step 4/5 otag_context_signal_rollup: refreshed");
+        tracing::info!("step 5/6 otag_context_signal_rollup: refreshed");
     }
-
-    tracing::info!("step 5/5 prune expired sessions: starting");
-    if let Err(e) = auth_service.delete_expired_sessions().await {
+    if let Err(e) = card_service.refresh_format_staples().await {
         failures += 1;
-        tracing::error!("step 5/5 prune sessions FAILED (continuing): {e:#}");
+        tracing::error!("step 5/6 format_staples refresh FAILED (continuing): {e:#}");
     } else {
-        tracing::info!("step 5/5 prune sessions: ok");
+        tracing::info!("step 5/6 format_staples: refreshed");
     }
 
-    if failures == 0 {
-        tracing::info!("zervice completed: all 5 steps ok");
-        Ok(())
+    tracing::info!("step 6/6 prune expired sessions: starting");
+    if let Err(e) = auth_service.delete_expired_sessions().await {
+        failures += 1;
+        tracing::error!("step 6/6 prune sessions FAILED (continuing): {e:#}");
     } else {
-        tracing::error!("zervice completed with {failures} failed step(s) — see errors above");
-        anyhow::bail!("zervice finished with {failures} failed step(s)")
+        tracing::info!("step 6/6 prune sessions: ok");
     }
+
+    failures
 }
@@ -1,7 +1,8 @@
 //! JWT access token generation and validation.
 //!
 //! Re-exports pure types from zwipe-core and provides server-only operations:
-//! - [`JwtSecret`]: Server-side signing key
+//! - [`JwtSecret`]: Server-side signing key ring (current key + previous keys
+//!   still accepted during a rotation window, selected by the header `kid`)
 //! - [`JwtValidate`]: Extension trait for JWT signature verification
 //! - [`AccessTokenExt`]: Extension trait for token generation
 
@@ -11,7 +12,10 @@ use zwipe_core::domain::auth::models::access_token::{
 };
 
 #[cfg(feature = "zerver")]
-use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use jsonwebtoken::{
+    Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, decode_header, encode,
+    errors::ErrorKind,
+};
 #[cfg(feature = "zerver")]
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "zerver")]
@@ -36,6 +40,14 @@ pub enum JwtSecretError {
     /// No secret was provided (empty string).
     #[error("secret must be present")]
     MissingSecret,
+
+    /// Only the HMAC family (`HS256`, `HS384`, `HS512`) is supported.
+    #[error("unsupported jwt algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+
+    /// A key id was empty or used by more than one key in the ring.
+    #[error("invalid or duplicate key id: {0:?}")]
+    InvalidKeyId(String),
 }
 
 #[cfg(feature = "zerver")]
@@ -79,32 +91,131 @@ impl From<jsonwebtoken::errors::Error> for InvalidJwt {
 // ==========
 
 #[cfg(feature = "zerver")]
-/// Server-side secret key for signing and validating JWT tokens.
+/// One HMAC key in the ring, optionally named by a `kid`.
+#[derive(Debug, Clone)]
+struct JwtKey {
+    kid: Option<String>,
+    secret: String,
+}
+
+#[cfg(feature = "zerver")]
+/// Server-side key ring for signing and validating JWT tokens.
+///
+/// Tokens are always signed with the current key (its `kid`, when set, goes in
+/// the header). Validation picks the key by the token's `kid`, so previous keys
+/// keep verifying tokens they issued until those expire — a secret can be
+/// rotated without logging every user out. Tokens carrying a `kid` the ring
+/// doesn't know are rejected; tokens with no `kid` (issued before key ids were
+/// configured) are tried against every key.
 #[derive(Debug, Clone)]
-pub struct JwtSecret(String);
+pub struct JwtSecret {
+    current: JwtKey,
+    previous: Vec<JwtKey>,
+    algorithm: Algorithm,
+}
 
 #[cfg(feature = "zerver")]
 impl AsRef<[u8]> for JwtSecret {
     fn as_ref(&self) -> &[u8] {
-        self.0.as_ref()
+        self.current.secret.as_ref()
     }
 }
 
 #[cfg(feature = "zerver")]
 impl JwtSecret {
-    /// Creates a new JWT secret with validation.
+    /// Creates a single-key ring (no `kid`, `HS256`) with validation.
     ///
     /// # Errors
     ///
     /// Returns [`JwtSecretError`] if the secret is empty or shorter than 32 characters.
     pub fn new(raw: &str) -> Result<Self, JwtSecretError> {
+        Ok(Self {
+            current: JwtKey {
+                kid: None,
+                secret: Self::checked_secret(raw)?,
+            },
+            previous: Vec::new(),
+            algorithm: Algorithm::HS256,
+        })
+    }
+
+    /// Names the current key; signed tokens carry this `kid` in their header.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JwtSecretError::InvalidKeyId`] if `kid` is empty or already
+    /// names a previous key.
+    pub fn with_kid(mut self, kid: &str) -> Result<Self, JwtSecretError> {
+        let kid = self.checked_kid(kid)?;
+        self.current.kid = Some(kid);
+        Ok(self)
+    }
+
+    /// Adds a retired key that still verifies (but never signs) tokens.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JwtSecretError`] if the secret is invalid or `kid` is empty or
+    /// already in the ring.
+    pub fn with_previous(mut self, kid: &str, raw: &str) -> Result<Self, JwtSecretError> {
+        let kid = self.checked_kid(kid)?;
+        let secret = Self::checked_secret(raw)?;
+        self.previous.push(JwtKey {
+            kid: Some(kid),
+            secret,
+        });
+        Ok(self)
+    }
+
+    /// Sets the signing/verification algorithm (`HS256`, `HS384` or `HS512`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JwtSecretError::UnsupportedAlgorithm`] for anything outside
+    /// the HMAC family.
+    pub fn with_algorithm(mut self, algorithm: &str) -> Result<Self, JwtSecretError> {
+        self.algorithm = match algorithm.trim().to_uppercase().as_str() {
+            "HS256" => Algorithm::HS256,
+            "HS384" => Algorithm::HS384,
+            "HS512" => Algorithm::HS512,
+            other => return Err(JwtSecretError::UnsupportedAlgorithm(other.to_string())),
+        };
+        Ok(self)
+    }
+
+    /// The current key's id, if one is configured.
+    pub fn kid(&self) -> Option<&str> {
+        self.current.kid.as_deref()
+    }
+
+    fn checked_secret(raw: &str) -> Result<String, JwtSecretError> {
         if raw.is_empty() {
             return Err(JwtSecretError::MissingSecret);
         }
         if raw.len() < 32 {
             return Err(JwtSecretError::TooShort);
         }
-        Ok(Self(raw.to_string()))
+        Ok(raw.to_string())
+    }
+
+    fn checked_kid(&self, kid: &str) -> Result<String, JwtSecretError> {
+        let kid = kid.trim();
+        let taken = std::iter::once(&self.current)
+            .chain(&self.previous)
+            .any(|k| k.kid.as_deref() == Some(kid));
+        if kid.is_empty() || taken {
+            return Err(JwtSecretError::InvalidKeyId(kid.to_string()));
+        }
+        Ok(kid.to_string())
+    }
+
+    /// Keys a token may have been signed with, given its header `kid`.
+    fn verification_keys(&self, kid: Option<&str>) -> Vec<&JwtKey> {
+        let ring = std::iter::once(&self.current).chain(&self.previous);
+        match kid {
+            Some(kid) => ring.filter(|k| k.kid.as_deref() == Some(kid)).collect(),
+            None => ring.collect(),
+        }
     }
 }
 
@@ -122,29 +233,56 @@ pub trait JwtValidate {
 #[cfg(feature = "zerver")]
 impl JwtValidate for Jwt {
     fn validate(&self, secret: &JwtSecret) -> Result<UserClaims, jsonwebtoken::errors::Error> {
-        let token_data = decode::<UserClaims>(
-            &self.to_string(),
-            &DecodingKey::from_secret(secret.as_ref()),
-            &Validation::default(),
-        )?;
-        Ok(token_data.claims)
+        let token: &str = self;
+        let header = decode_header(token)?;
+        let validation = Validation::new(secret.algorithm);
+        let mut result = Err(ErrorKind::InvalidToken.into());
+        for key in secret.verification_keys(header.kid.as_deref()) {
+            result = decode::<UserClaims>(
+                token,
+                &DecodingKey::from_secret(key.secret.as_ref()),
+                &validation,
+            );
+            // Only a signature mismatch means "try the next key"; any other
+            // outcome (success, expiry, bad claims) is this key's verdict.
+            if !matches!(&result, Err(e) if *e.kind() == ErrorKind::InvalidSignature) {
+                break;
+            }
+        }
+        Ok(result?.claims)
     }
 }
 
 #[cfg(feature = "zerver")]
 /// Extension trait for server-side access token operations.
 pub trait AccessTokenExt {
-    /// Generates a new access token for a user.
+    /// Generates a new access token for a user, valid for
+    /// [`ACCESS_TOKEN_LIFESPAN`](crate::domain::auth::models::session_policy::ACCESS_TOKEN_LIFESPAN).
     fn generate(user: &User, secret: &JwtSecret) -> Result<AccessToken, InvalidJwt>;
+
+    /// Generates a new access token for a user that expires `lifespan` from now.
+    fn generate_with_lifespan(
+        user: &User,
+        secret: &JwtSecret,
+        lifespan: chrono::Duration,
+    ) -> Result<AccessToken, InvalidJwt>;
 }
 
 #[cfg(feature = "zerver")]
 impl AccessTokenExt for AccessToken {
     fn generate(user: &User, secret: &JwtSecret) -> Result<AccessToken, InvalidJwt> {
-        use chrono::{Duration, Utc};
+        use crate::domain::auth::models::session_policy::ACCESS_TOKEN_LIFESPAN;
+
+        Self::generate_with_lifespan(user, secret, ACCESS_TOKEN_LIFESPAN)
+    }
 
-        let issued_at = Utc::now();
-        let expires_at = issued_at + Duration::hours(24);
+    fn generate_with_lifespan(
+        user: &User,
+        secret: &JwtSecret,
+        lifespan: chrono::Duration,
+    ) -> Result<AccessToken, InvalidJwt> {
+        let issued_at = chrono::Utc::now();
+        let expires_at = issued_at + lifespan;
 
         let user_claims = UserClaims {
             user_id: user.id,
@@ -154,9 +292,11 @@ impl AccessTokenExt for AccessToken {
             iat: issued_at.timestamp(),
         };
 
+        let mut header = Header::new(secret.algorithm);
+        header.kid = secret.current.kid.clone();
         let value = Jwt::from_str(
             &encode(
-                &Header::default(),
+                &header,
                 &user_claims,
                 &EncodingKey::from_secret(secret.as_ref()),
             )
@@ -244,6 +384,147 @@ mod tests {
         assert_eq!(bytes, b"test-secret-that-is-long-enough-for-validation");
     }
 
+    // ==========================
+    //  key rotation tests
+    // ==========================
+
+    fn test_user() -> User {
+        User::new(
+            Uuid::new_v4(),
+            Username::new("testuser").unwrap(),
+            Email::from_str("test@email.com").unwrap(),
+        )
+    }
+
+    const OLD_SECRET: &str = "old-secret-that-is-long-enough-for-validation";
+    const NEW_SECRET: &str = "new-secret-that-is-long-enough-for-validation";
+
+    #[test]
+    fn test_token_signed_with_previous_key_verifies_during_rotation() {
+        let user = test_user();
+        let before = JwtSecret::new(OLD_SECRET).unwrap().with_kid("k1").unwrap();
+        let token = AccessToken::generate(&user, &before).unwrap();
+
+        let after = JwtSecret::new(NEW_SECRET)
+            .unwrap()
+            .with_kid("k2")
+            .unwrap()
+            .with_previous("k1", OLD_SECRET)
+            .unwrap();
+        let claims = token.value.validate(&after).unwrap();
+        assert_eq!(claims.user_id, user.id);
+
+        // new tokens are signed with (and carry the kid of) the current key
+        let fresh = AccessToken::generate(&user, &after).unwrap();
+        assert_eq!(
+            decode_header(&*fresh.value).unwrap().kid.as_deref(),
+            Some("k2")
+        );
+        assert!(fresh.value.validate(&after).is_ok());
+    }
+
+    #[test]
+    fn test_token_with_unknown_kid_is_rejected() {
+        let user = test_user();
+        let signer = JwtSecret::new(OLD_SECRET).unwrap().with_kid("k0").unwrap();
+        let token = AccessToken::generate(&user, &signer).unwrap();
+
+        // same secret under a different kid: the kid lookup fails first
+        let ring = JwtSecret::new(NEW_SECRET)
+            .unwrap()
+            .with_kid("k2")
+            .unwrap()
+            .with_previous("k1", OLD_SECRET)
+            .unwrap();
+        assert!(token.value.validate(&ring).is_err());
+    }
+
+    #[test]
+    fn test_retired_key_is_rejected_once_dropped_from_ring() {
+        let user = test_user();
+        let before = JwtSecret::new(OLD_SECRET).unwrap().with_kid("k1").unwrap();
+        let token = AccessToken::generate(&user, &before).unwrap();
+
+        let after = JwtSecret::new(NEW_SECRET).unwrap().with_kid("k2").unwrap();
+        assert!(token.value.validate(&after).is_err());
+    }
+
+    #[test]
+    fn test_kidless_legacy_token_verifies_against_previous_key() {
+        let user = test_user();
+        let legacy = JwtSecret::new(OLD_SECRET).unwrap();
+        let token = AccessToken::generate(&user, &legacy).unwrap();
+
+        let after = JwtSecret::new(NEW_SECRET)
+            .unwrap()
+            .with_kid("k2")
+            .unwrap()
+            .with_previous("k1", OLD_SECRET)
+            .unwrap();
+        assert!(token.value.validate(&after).is_ok());
+    }
+
+    #[test]
+    fn test_expired_token_reports_expiry_not_a_key_mismatch() {
+        use jsonwebtoken::{EncodingKey, Header, encode};
+
+        let user = test_user();
+        let now = chrono::Utc::now().timestamp();
+        let claims = UserClaims {
+            user_id: user.id,
+            username: user.username,
+            email: user.email,
+            exp: now - 3600,
+            iat: now - 7200,
+        };
+        let raw = encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(NEW_SECRET.as_bytes()),
+        )
+        .unwrap();
+        // kidless, so every key in the ring is tried; the current key's
+        // expiry verdict must win over the previous key's signature mismatch
+        let ring = JwtSecret::new(NEW_SECRET)
+            .unwrap()
+            .with_previous("k1", OLD_SECRET)
+            .unwrap();
+        let err = Jwt::from_str(&raw).unwrap().validate(&ring).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::ExpiredSignature);
+    }
+
+    #[test]
+    fn test_algorithm_is_configurable_and_enforced() {
+        let user = test_user();
+        let hs512 = JwtSecret::new(OLD_SECRET)
+            .unwrap()
+            .with_algorithm("hs512")
+            .unwrap();
+        let token = AccessToken::generate(&user, &hs512).unwrap();
+        assert_eq!(decode_header(&*token.value).unwrap().alg, Algorithm::HS512);
+        assert!(token.value.validate(&hs512).is_ok());
+
+        // same secret, different algorithm: rejected
+        let hs256 = JwtSecret::new(OLD_SECRET).unwrap();
+        assert!(token.value.validate(&hs256).is_err());
+    }
+
+    #[test]
+    fn test_jwt_secret_rejects_non_hmac_algorithm_and_duplicate_kid() {
+        let secret = JwtSecret::new(OLD_SECRET).unwrap();
+        assert!(matches!(
+            secret.clone().with_algorithm("RS256"),
+            Err(JwtSecretError::UnsupportedAlgorithm(_))
+        ));
+        assert!(matches!(
+            secret
+                .with_kid("k1")
+                .unwrap()
+                .with_previous("k1", NEW_SECRET),
+            Err(JwtSecretError::InvalidKeyId(_))
+        ));
+    }
+
     // =============================
     //  `AccessToken` generation tests
     // =============================
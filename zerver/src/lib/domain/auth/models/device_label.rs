@@ -0,0 +1,143 @@
+//! Friendly device labels for sessions.
+//!
+//! A session records where it was opened from as a short label ("Chrome on
+//! macOS") so the session list names devices instead of ids. A client may
+//! name itself through the [`DEVICE_NAME_HEADER`]; otherwise the label is
+//! parsed from the `User-Agent`. Purely descriptive: nothing validates
+//! against it, and a missing or unrecognisable agent simply yields no label
+//! (listed as [`UNKNOWN_DEVICE`](zwipe_core::domain::auth::models::session::UNKNOWN_DEVICE)).
+
+/// Request header a client may set to name its device (e.g. "Sam's iPad").
+pub const DEVICE_NAME_HEADER: &str = "x-device-name";
+
+/// Longest label stored; longer client-provided names are cut to this.
+const MAX_DEVICE_LABEL_CHARS: usize = 64;
+
+/// Browsers by the user-agent token that identifies them. Order matters:
+/// Edge and Opera also claim Chrome, Chrome also claims Safari.
+const BROWSERS: [(&str, &str); 9] = [
+    ("Edg/", "Edge"),
+    ("EdgiOS/", "Edge"),
+    ("OPR/", "Opera"),
+    ("Firefox/", "Firefox"),
+    ("FxiOS/", "Firefox"),
+    ("CriOS/", "Chrome"),
+    ("Chrome/", "Chrome"),
+    ("Safari/", "Safari"),
+    ("curl/", "curl"),
+];
+
+/// Operating systems by user-agent token, checked in order (iPhone and
+/// Android agents also mention "like Mac OS X" and "Linux").
+const OPERATING_SYSTEMS: [(&str, &str); 8] = [
+    ("iPhone", "iOS"),
+    ("iPad", "iPadOS"),
+    ("Android", "Android"),
+    ("CrOS", "ChromeOS"),
+    ("Windows", "Windows"),
+    ("Macintosh", "macOS"),
+    ("Mac OS X", "macOS"),
+    ("Linux", "Linux"),
+];
+
+/// Picks a session's label: the client-provided `device_name` when it has
+/// printable content, else one parsed from `user_agent`.
+pub fn device_label(device_name: Option<&str>, user_agent: Option<&str>) -> Option<String> {
+    device_name
+        .and_then(clean_device_name)
+        .or_else(|| user_agent.and_then(label_user_agent))
+}
+
+/// Labels a `User-Agent` as "<browser> on <os>", or whichever half is
+/// recognised; `None` when neither is.
+pub fn label_user_agent(user_agent: &str) -> Option<String> {
+    let find = |table: &[(&'static str, &'static str)]| {
+        table
+            .iter()
+            .find(|(token, _)| user_agent.contains(token))
+            .map(|(_, name)| *name)
+    };
+    match (find(&BROWSERS), find(&OPERATING_SYSTEMS)) {
+        (Some(browser), Some(os)) => Some(format!("{browser} on {os}")),
+        (Some(browser), None) => Some(browser.to_string()),
+        (None, Some(os)) => Some(os.to_string()),
+        (None, None) => None,
+    }
+}
+
+/// Drops control characters and collapses whitespace runs, capping the
+/// length; `None` if nothing is left.
+fn clean_device_name(raw: &str) -> Option<String> {
+    let collapsed = raw
+        .split_whitespace()
+        .map(|word| word.chars().filter(|c| !c.is_control()).collect::<String>())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let capped: String = collapsed.chars().take(MAX_DEVICE_LABEL_CHARS).collect();
+    let capped = capped.trim_end();
+    (!capped.is_empty()).then(|| capped.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_common_browsers() {
+        let cases = [
+            (
+                "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Safari/537.36",
+                "Chrome on macOS",
+            ),
+            (
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Safari/537.36 Edg/129.0.0.0",
+                "Edge on Windows",
+            ),
+            (
+                "Mozilla/5.0 (iPhone; CPU iPhone OS 17_6 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.6 Mobile/15E148 Safari/604.1",
+                "Safari on iOS",
+            ),
+            (
+                "Mozilla/5.0 (X11; Linux x86_64; rv:131.0) Gecko/20100101 Firefox/131.0",
+                "Firefox on Linux",
+            ),
+            (
+                "Mozilla/5.0 (Linux; Android 14; Pixel 8) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Mobile Safari/537.36",
+                "Chrome on Android",
+            ),
+            ("curl/8.5.0", "curl"),
+        ];
+        for (user_agent, expected) in cases {
+            assert_eq!(
+                label_user_agent(user_agent).as_deref(),
+                Some(expected),
+                "{user_agent}"
+            );
+        }
+    }
+
+    #[test]
+    fn garbage_user_agent_has_no_label() {
+        assert_eq!(label_user_agent(""), None);
+        assert_eq!(label_user_agent("???"), None);
+        assert_eq!(device_label(None, None), None);
+    }
+
+    #[test]
+    fn client_device_name_wins_and_is_cleaned() {
+        assert_eq!(
+            device_label(Some("  Sam's\tiPad  "), Some("curl/8.5.0")).as_deref(),
+            Some("Sam's iPad")
+        );
+        assert_eq!(
+            device_label(Some(" \n "), Some("curl/8.5.0")).as_deref(),
+            Some("curl")
+        );
+        let long = "x".repeat(100);
+        assert_eq!(
+            device_label(Some(&long), None).map(|l| l.chars().count()),
+            Some(MAX_DEVICE_LABEL_CHARS)
+        );
+    }
+}
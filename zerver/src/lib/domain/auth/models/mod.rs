@@ -6,12 +6,16 @@
 //! # Module Organization
 //!
 //! - [`access_token`]: JWT access token generation and validation
+//! - [`device_label`]: Friendly session device labels from the User-Agent
 //! - [`password`]: Password validation, hashing, and verification
 //! - [`refresh_token`]: Long-lived refresh token management
 //! - [`session`]: Session entity and constants
+//! - [`session_policy`]: Configurable session limit and token lifetimes
 
 pub mod access_token;
+pub mod device_label;
 pub mod password;
+pub mod session_policy;
 
 #[cfg(feature = "zerver")]
 use crate::domain::auth::models::password::HashedPassword;
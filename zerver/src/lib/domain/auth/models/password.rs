@@ -43,10 +43,15 @@
 
 #[cfg(feature = "zerver")]
 use argon2::{
-    Argon2, PasswordHasher, PasswordVerifier,
+    Algorithm, Argon2, Params, PasswordHasher, PasswordVerifier, Version,
     password_hash::{self, SaltString, rand_core::OsRng},
 };
 use std::fmt::Display;
+#[cfg(feature = "zerver")]
+use std::sync::OnceLock;
+
+#[cfg(feature = "hibp")]
+use crate::inbound::external::hibp::PwnedPasswords;
 
 use zwipe_core::domain::auth::password::InvalidPassword;
 
@@ -113,6 +118,33 @@ impl Password {
         Ok(Password(raw.to_string()))
     }
 
+    /// Like [`new`](Self::new), then refuses a password listed by Have I Been
+    /// Pwned while the breach check is on (see
+    /// [`PwnedPasswords::init_shared`]).
+    ///
+    /// Fails open: if HIBP can't be reached the password is allowed and a
+    /// warning logged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidPassword::Breached`] for a breached password, or the
+    /// policy error from [`new`](Self::new).
+    #[cfg(feature = "hibp")]
+    pub async fn new_checked(raw: impl AsRef<str>) -> Result<Self, InvalidPassword> {
+        let password = Self::new(raw)?;
+        let Some(hibp) = PwnedPasswords::shared() else {
+            return Ok(password);
+        };
+        match hibp.is_breached(password.read()).await {
+            Ok(true) => Err(InvalidPassword::Breached),
+            Ok(false) => Ok(password),
+            Err(e) => {
+                tracing::warn!(event = "hibp_unreachable", error = %e, "breach check skipped");
+                Ok(password)
+            }
+        }
+    }
+
     /// Hashes the password using Argon2id with a random salt.
     ///
     /// Consumes the `Password` and returns a [`HashedPassword`] suitable for
@@ -234,7 +266,8 @@ impl HashedPassword {
     /// Creates a cryptographically secure hash using:
     /// - Argon2id algorithm (hybrid of Argon2i and Argon2d)
     /// - Random salt from OS RNG
-    /// - Default parameters (balanced security/performance)
+    /// - The process-wide [`Argon2Params`] (the argon2 crate's defaults
+    ///   unless configured at startup)
     ///
     /// This method is also available via [`Password::hash()`].
     ///
@@ -250,13 +283,46 @@ impl HashedPassword {
     /// let hashed = HashedPassword::generate(password)?;
     /// ```
     pub fn generate(password: Password) -> Result<Self, password_hash::Error> {
+        Self::hash_with(password.read(), Argon2Params::shared().params())
+    }
+
+    /// Hashes `password` with Argon2id under `params`.
+    ///
+    /// Takes the raw string rather than a [`Password`] so a login can rehash
+    /// a password that was set under an older policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`password_hash::Error`] if hashing fails.
+    pub fn hash_with(password: &str, params: Params) -> Result<Self, password_hash::Error> {
         let salt = SaltString::generate(&mut OsRng);
-        let hash = Argon2::default()
-            .hash_password(password.read().as_bytes(), &salt)
+        let hash = Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password(password.as_bytes(), &salt)
             .map(|x| x.to_string())?;
         Ok(Self(hash))
     }
 
+    /// Whether this hash is weaker than one made with `params` now: a
+    /// different algorithm or version, or a lower memory, iteration or
+    /// parallelism cost. A hash that doesn't parse is left alone (it can't
+    /// have verified either).
+    pub fn needs_rehash(&self, params: &Params) -> bool {
+        let Ok(parsed) = argon2::PasswordHash::new(&self.0) else {
+            return false;
+        };
+        if parsed.algorithm != Algorithm::Argon2id.ident()
+            || parsed.version != Some(Version::V0x13 as u32)
+        {
+            return true;
+        }
+        let Ok(stored) = Params::try_from(&parsed) else {
+            return false;
+        };
+        stored.m_cost() < params.m_cost()
+            || stored.t_cost() < params.t_cost()
+            || stored.p_cost() < params.p_cost()
+    }
+
     /// Verifies a plaintext password against this hash.
     ///
     /// Uses constant-time comparison to prevent timing attacks. The verification
@@ -298,6 +364,74 @@ impl std::fmt::Display for HashedPassword {
     }
 }
 
+/// The process-wide parameters new hashes get; set once at startup by
+/// [`Argon2Params::init_shared`].
+#[cfg(feature = "zerver")]
+static SHARED_PARAMS: OnceLock<Argon2Params> = OnceLock::new();
+
+/// Argon2id cost parameters for newly created password hashes.
+///
+/// Raising them only affects hashes made from then on: every stored hash
+/// carries its own parameters, which verification reads back, so older
+/// hashes keep verifying (and are upgraded on login when rehashing is on).
+#[cfg(feature = "zerver")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Argon2Params(Params);
+
+#[cfg(feature = "zerver")]
+impl Argon2Params {
+    /// Validates the costs: memory in KiB (at least 8 per lane), iterations
+    /// (at least 1) and parallelism (lanes, at least 1).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`argon2::Error`] if a cost is out of Argon2's range.
+    pub fn new(memory_kib: u32, iterations: u32, parallelism: u32) -> Result<Self, argon2::Error> {
+        Params::new(memory_kib, iterations, parallelism, None).map(Self)
+    }
+
+    /// Memory cost in KiB.
+    pub fn memory_kib(&self) -> u32 {
+        self.0.m_cost()
+    }
+
+    /// Number of passes over memory.
+    pub fn iterations(&self) -> u32 {
+        self.0.t_cost()
+    }
+
+    /// Degree of parallelism (lanes).
+    pub fn parallelism(&self) -> u32 {
+        self.0.p_cost()
+    }
+
+    /// The underlying argon2 parameters.
+    pub fn params(&self) -> Params {
+        self.0.clone()
+    }
+
+    /// Makes these the parameters [`HashedPassword::generate`] uses. Returns
+    /// `false` (and changes nothing) if the shared parameters were already
+    /// set or read.
+    pub fn init_shared(self) -> bool {
+        SHARED_PARAMS.set(self).is_ok()
+    }
+
+    /// The process-wide parameters; the argon2 crate's defaults unless
+    /// [`init_shared`](Self::init_shared) configured them.
+    pub fn shared() -> &'static Self {
+        SHARED_PARAMS.get_or_init(Self::default)
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl Default for Argon2Params {
+    /// OWASP's minimum for Argon2id: 19 MiB, 2 iterations, 1 lane.
+    fn default() -> Self {
+        Self(Params::default())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -764,6 +898,45 @@ mod tests {
         }
     }
 
+    // ===========
+    //  rehashing
+    // ===========
+
+    #[test]
+    fn test_needs_rehash_only_when_weaker_than_params() {
+        let weak = Params::new(1024, 1, 1, None).unwrap();
+        let old = HashedPassword::hash_with("LegacyPass123!", weak.clone()).unwrap();
+        assert!(old.verify("LegacyPass123!").unwrap());
+        assert!(old.needs_rehash(&Params::default()));
+        assert!(!old.needs_rehash(&weak));
+
+        let current = HashedPassword::hash_with("LegacyPass123!", Params::default()).unwrap();
+        assert!(!current.needs_rehash(&Params::default()));
+        assert!(!current.needs_rehash(&weak));
+    }
+
+    #[test]
+    fn test_hash_with_custom_params_still_verifies() {
+        let custom = Argon2Params::new(8 * 1024, 3, 2).unwrap();
+        let hash = HashedPassword::hash_with("CustomPass123!", custom.params()).unwrap();
+        assert!(hash.to_string().contains("m=8192,t=3,p=2"));
+        assert!(hash.verify("CustomPass123!").unwrap());
+        assert!(!hash.verify("WrongPass123!").unwrap());
+        assert!(Argon2Params::new(0, 1, 1).is_err());
+    }
+
+    #[test]
+    fn test_needs_rehash_for_other_argon2_variants() {
+        let salt = SaltString::generate(&mut OsRng);
+        let argon2i = Argon2::new(Algorithm::Argon2i, Version::V0x13, Params::default())
+            .hash_password(b"LegacyPass123!", &salt)
+            .unwrap()
+            .to_string();
+        let hash = HashedPassword::new(&argon2i).unwrap();
+        assert!(hash.verify("LegacyPass123!").unwrap());
+        assert!(hash.needs_rehash(&Params::default()));
+    }
+
     // =============
     //  integration
     // =============
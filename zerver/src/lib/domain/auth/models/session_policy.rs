@@ -0,0 +1,134 @@
+//! Session limits and token lifetimes.
+//!
+//! Defaults match the values clients were built against (5 sessions, 24h
+//! access tokens, 14d refresh tokens); deployments can tune them through
+//! `Config` without recompiling.
+
+use chrono::Duration;
+use thiserror::Error;
+use zwipe_core::domain::auth::models::{
+    refresh_token::REFRESH_TOKEN_LIFESPAN, session::MAXIMUM_SESSION_COUNT,
+};
+
+/// Default access token lifetime (24 hours).
+pub const ACCESS_TOKEN_LIFESPAN: Duration = Duration::hours(24);
+
+/// How long after a rotation its old refresh token is still taken for a
+/// racing refresh from the same client rather than a replay.
+pub const ROTATION_GRACE: Duration = Duration::seconds(10);
+
+/// Errors that can occur while constructing a [`SessionPolicy`].
+#[derive(Debug, Clone, Error)]
+pub enum InvalidSessionPolicy {
+    /// A user must be allowed at least one session.
+    #[error("max sessions must be at least 1")]
+    NoSessions,
+    /// A token lifetime is zero or negative.
+    #[error("token lifetimes must be positive")]
+    NonPositiveTtl,
+    /// Access tokens would outlive the refresh tokens that renew them.
+    #[error("access token ttl ({access}) must be shorter than refresh token ttl ({refresh})")]
+    AccessOutlivesRefresh {
+        /// Configured access token lifetime.
+        access: Duration,
+        /// Configured refresh token lifetime.
+        refresh: Duration,
+    },
+}
+
+/// How many concurrent sessions a user may hold and how long each token
+/// lives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionPolicy {
+    max_sessions: u8,
+    access_token_ttl: Duration,
+    refresh_token_ttl: Duration,
+}
+
+impl SessionPolicy {
+    /// Creates a policy, refusing zero sessions, non-positive lifetimes and
+    /// an access token that lives at least as long as the refresh token.
+    pub fn new(
+        max_sessions: u8,
+        access_token_ttl: Duration,
+        refresh_token_ttl: Duration,
+    ) -> Result<Self, InvalidSessionPolicy> {
+        if max_sessions == 0 {
+            return Err(InvalidSessionPolicy::NoSessions);
+        }
+        if access_token_ttl <= Duration::zero() || refresh_token_ttl <= Duration::zero() {
+            return Err(InvalidSessionPolicy::NonPositiveTtl);
+        }
+        if access_token_ttl >= refresh_token_ttl {
+            return Err(InvalidSessionPolicy::AccessOutlivesRefresh {
+                access: access_token_ttl,
+                refresh: refresh_token_ttl,
+            });
+        }
+        Ok(Self {
+            max_sessions,
+            access_token_ttl,
+            refresh_token_ttl,
+        })
+    }
+
+    /// Sessions a user may hold; the oldest is evicted past this.
+    pub fn max_sessions(&self) -> u8 {
+        self.max_sessions
+    }
+
+    /// Lifetime of each access token.
+    pub fn access_token_ttl(&self) -> Duration {
+        self.access_token_ttl
+    }
+
+    /// Lifetime of each refresh token (renewed on every rotation).
+    pub fn refresh_token_ttl(&self) -> Duration {
+        self.refresh_token_ttl
+    }
+}
+
+impl Default for SessionPolicy {
+    fn default() -> Self {
+        Self {
+            max_sessions: MAXIMUM_SESSION_COUNT,
+            access_token_ttl: ACCESS_TOKEN_LIFESPAN,
+            refresh_token_ttl: REFRESH_TOKEN_LIFESPAN,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_a_valid_policy() {
+        let default = SessionPolicy::default();
+        let rebuilt = SessionPolicy::new(
+            default.max_sessions(),
+            default.access_token_ttl(),
+            default.refresh_token_ttl(),
+        )
+        .unwrap();
+        assert_eq!(rebuilt, default);
+    }
+
+    #[test]
+    fn access_ttl_must_be_shorter_than_refresh_ttl() {
+        assert!(matches!(
+            SessionPolicy::new(5, Duration::days(14), Duration::days(14)),
+            Err(InvalidSessionPolicy::AccessOutlivesRefresh { .. })
+        ));
+        assert!(matches!(
+            SessionPolicy::new(0, Duration::hours(1), Duration::days(1)),
+            Err(InvalidSessionPolicy::NoSessions)
+        ));
+        assert!(matches!(
+            SessionPolicy::new(5, Duration::zero(), Duration::days(1)),
+            Err(InvalidSessionPolicy::NonPositiveTtl)
+        ));
+        assert!(SessionPolicy::new(1, Duration::minutes(15), Duration::days(1)).is_ok());
+    }
+}
@@ -6,19 +6,28 @@
 use crate::domain::{
     BoxFuture,
     auth::{
-        models::{UserWithPasswordHash, access_token::JwtSecret},
+        models::{
+            UserWithPasswordHash, access_token::JwtSecret, password::HashedPassword,
+            session_policy::SessionPolicy,
+        },
         requests::{
+            authenticate_api_key::AuthenticateApiKeyError,
             authenticate_user::{AuthenticateUser, AuthenticateUserError},
             change_email::{ChangeEmail, ChangeEmailError},
             change_password::{ChangePassword, ChangePasswordError},
             change_username::{ChangeUsername, ChangeUsernameError},
+            create_api_key::{CreateApiKey, CreateApiKeyError},
             create_session::{CreateSession, CreateSessionError},
             delete_expired_sessions::DeleteExpiredSessionsError,
             delete_user::{DeleteUser, DeleteUserError},
+            list_api_keys::ListApiKeysError,
+            list_sessions::ListSessionsError,
             refresh_session::{RefreshSession, RefreshSessionError},
             register_user::{RegisterUser, RegisterUserError},
             request_password_reset::{RequestPasswordReset, RequestPasswordResetError},
             reset_password::{ResetPassword, ResetPasswordError},
+            revoke_api_key::{RevokeApiKey, RevokeApiKeyError},
+            revoke_session::{RevokeSession, RevokeSessionError},
             revoke_sessions::{RevokeSessions, RevokeSessionsError},
             verify_email::{VerifyEmail, VerifyEmailError},
         },
@@ -28,7 +37,12 @@ use chrono::{DateTime, Utc};
 use std::future::Future;
 use uuid::Uuid;
 use zwipe_core::domain::{
-    auth::models::{platform::ClientPlatform, refresh_token::RefreshToken, session::Session},
+    auth::models::{
+        api_key::{ApiKey, ApiKeyScope, CreatedApiKey},
+        platform::ClientPlatform,
+        refresh_token::RefreshToken,
+        session::{Session, SessionInfo},
+    },
     user::User,
 };
 
@@ -49,21 +63,26 @@ pub trait AuthRepository: Clone + Send + Sync + 'static {
     /// Creates a new user and initial refresh token (registration).
     ///
     /// Inserts user into database and generates first refresh token for immediate login.
+    /// Token lifetime and the session limit come from `policy`, as they do for
+    /// the other token-minting methods.
     fn create_user_and_refresh_token(
         &self,
         request: &RegisterUser,
+        policy: &SessionPolicy,
     ) -> impl Future<Output = Result<(User, RefreshToken), RegisterUserError>> + Send;
 
     /// Creates a new refresh token for an existing user (new session).
     ///
     /// Generates and stores a new refresh token for the specified user ID,
-    /// tagged with the client `platform` and `client_version` (`None` if
-    /// unknown/not reported).
+    /// tagged with the client `platform`, `client_version` and `device_label`
+    /// (`None` if unknown/not reported).
     fn create_refresh_token(
         &self,
         request: Uuid,
         platform: Option<ClientPlatform>,
         client_version: Option<String>,
+        device_label: Option<String>,
+        policy: &SessionPolicy,
     ) -> impl Future<Output = Result<RefreshToken, CreateSessionError>> + Send;
 
     /// Exchanges a refresh token for a new one (token rotation).
@@ -73,6 +92,7 @@ pub trait AuthRepository: Clone + Send + Sync + 'static {
     fn use_refresh_token(
         &self,
         request: &RefreshSession,
+        policy: &SessionPolicy,
     ) -> impl Future<Output = Result<RefreshToken, RefreshSessionError>> + Send;
 
     // =====
@@ -108,6 +128,16 @@ pub trait AuthRepository: Clone + Send + Sync + 'static {
         user_id: Uuid,
     ) -> impl Future<Output = Result<(), AuthenticateUserError>> + Send;
 
+    /// Swaps a user's password hash for a stronger one after a successful
+    /// login, unless the stored hash is no longer `old` (a password change
+    /// raced the login). Sessions are untouched.
+    fn upgrade_password_hash(
+        &self,
+        user_id: Uuid,
+        old: &HashedPassword,
+        new: &HashedPassword,
+    ) -> impl Future<Output = Result<(), AuthenticateUserError>> + Send;
+
     // ========
     //  update
     // ========
@@ -163,6 +193,22 @@ pub trait AuthRepository: Clone + Send + Sync + 'static {
         user_id: Uuid,
     ) -> impl Future<Output = Result<(), RevokeSessionsError>> + Send;
 
+    /// Deletes the refresh token behind one of the user's sessions.
+    ///
+    /// Returns [`RevokeSessionError::NotFound`] if no session with that id
+    /// belongs to the user.
+    fn delete_session(
+        &self,
+        request: &RevokeSession,
+    ) -> impl Future<Output = Result<(), RevokeSessionError>> + Send;
+
+    /// Lists the user's live (unexpired, unrevoked) sessions, most recently
+    /// refreshed first.
+    fn list_sessions(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<SessionInfo>, ListSessionsError>> + Send;
+
     // ========================
     //  email verification
     // ========================
@@ -241,8 +287,43 @@ pub trait AuthRepository: Clone + Send + Sync + 'static {
     fn reset_password_and_revoke_sessions(
         &self,
         user_id: Uuid,
-        new_hash: crate::domain::auth::models::password::HashedPassword,
+        new_hash: HashedPassword,
     ) -> impl Future<Output = Result<(), ResetPasswordError>> + Send;
+
+    // ==========
+    //  api keys
+    // ==========
+
+    /// Stores a new API key under `key_hash` (the SHA-256 of its secret).
+    fn create_api_key(
+        &self,
+        request: &CreateApiKey,
+        key_hash: &str,
+    ) -> impl Future<Output = Result<ApiKey, CreateApiKeyError>> + Send;
+
+    /// Lists the user's unrevoked API keys, newest first.
+    fn list_api_keys(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<ApiKey>, ListApiKeysError>> + Send;
+
+    /// Marks one of the user's keys revoked.
+    ///
+    /// Returns [`RevokeApiKeyError::NotFound`] if no active key with that id
+    /// belongs to the user.
+    fn revoke_api_key(
+        &self,
+        request: &RevokeApiKey,
+    ) -> impl Future<Output = Result<(), RevokeApiKeyError>> + Send;
+
+    /// Looks up the unrevoked key with this hash, stamps `last_used_at`, and
+    /// returns its owner and scope.
+    ///
+    /// Returns [`AuthenticateApiKeyError::Invalid`] if no active key matches.
+    fn use_api_key(
+        &self,
+        key_hash: &str,
+    ) -> impl Future<Output = Result<(User, ApiKeyScope), AuthenticateApiKeyError>> + Send;
 }
 
 /// Service port for authentication business logic.
@@ -360,6 +441,18 @@ pub trait AuthService: Clone + Send + Sync + 'static {
         request: &RevokeSessions,
     ) -> impl Future<Output = Result<(), RevokeSessionsError>> + Send;
 
+    /// Lists the user's live sessions (without tokens).
+    fn list_sessions(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<SessionInfo>, ListSessionsError>> + Send;
+
+    /// Revokes one of the user's sessions (logs one device out).
+    fn revoke_session(
+        &self,
+        request: &RevokeSession,
+    ) -> impl Future<Output = Result<(), RevokeSessionError>> + Send;
+
     // ========================
     //  email verification
     // ========================
@@ -401,6 +494,35 @@ pub trait AuthService: Clone + Send + Sync + 'static {
         &self,
         request: &ResetPassword,
     ) -> impl Future<Output = Result<uuid::Uuid, ResetPasswordError>> + Send;
+
+    // ==========
+    //  api keys
+    // ==========
+
+    /// Mints an API key for the user. The returned secret is shown once;
+    /// only its hash is stored.
+    fn create_api_key(
+        &self,
+        request: &CreateApiKey,
+    ) -> impl Future<Output = Result<CreatedApiKey, CreateApiKeyError>> + Send;
+
+    /// Lists the user's active API keys (without secrets).
+    fn list_api_keys(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<Vec<ApiKey>, ListApiKeysError>> + Send;
+
+    /// Revokes one of the user's API keys.
+    fn revoke_api_key(
+        &self,
+        request: &RevokeApiKey,
+    ) -> impl Future<Output = Result<(), RevokeApiKeyError>> + Send;
+
+    /// Resolves a bearer API key secret to its owner and scope.
+    fn authenticate_api_key(
+        &self,
+        secret: &str,
+    ) -> impl Future<Output = Result<(User, ApiKeyScope), AuthenticateApiKeyError>> + Send;
 }
 
 /// Object-safe wrapper used by `AppState` so the concrete service type stays
@@ -468,6 +590,18 @@ pub trait ErasedAuthService: Send + Sync + 'static {
         request: &'a RevokeSessions,
     ) -> BoxFuture<'a, Result<(), RevokeSessionsError>>;
 
+    /// See [`AuthService::list_sessions`].
+    fn list_sessions<'a>(
+        &'a self,
+        user_id: Uuid,
+    ) -> BoxFuture<'a, Result<Vec<SessionInfo>, ListSessionsError>>;
+
+    /// See [`AuthService::revoke_session`].
+    fn revoke_session<'a>(
+        &'a self,
+        request: &'a RevokeSession,
+    ) -> BoxFuture<'a, Result<(), RevokeSessionError>>;
+
     /// See [`AuthService::send_verification_email`].
     fn send_verification_email<'a>(
         &'a self,
@@ -492,6 +626,30 @@ pub trait ErasedAuthService: Send + Sync + 'static {
         &'a self,
         request: &'a ResetPassword,
     ) -> BoxFuture<'a, Result<uuid::Uuid, ResetPasswordError>>;
+
+    /// See [`AuthService::create_api_key`].
+    fn create_api_key<'a>(
+        &'a self,
+        request: &'a CreateApiKey,
+    ) -> BoxFuture<'a, Result<CreatedApiKey, CreateApiKeyError>>;
+
+    /// See [`AuthService::list_api_keys`].
+    fn list_api_keys<'a>(
+        &'a self,
+        user_id: Uuid,
+    ) -> BoxFuture<'a, Result<Vec<ApiKey>, ListApiKeysError>>;
+
+    /// See [`AuthService::revoke_api_key`].
+    fn revoke_api_key<'a>(
+        &'a self,
+        request: &'a RevokeApiKey,
+    ) -> BoxFuture<'a, Result<(), RevokeApiKeyError>>;
+
+    /// See [`AuthService::authenticate_api_key`].
+    fn authenticate_api_key<'a>(
+        &'a self,
+        secret: &'a str,
+    ) -> BoxFuture<'a, Result<(User, ApiKeyScope), AuthenticateApiKeyError>>;
 }
 
 impl<T> ErasedAuthService for T
@@ -573,6 +731,20 @@ where
         Box::pin(AuthService::revoke_sessions(self, request))
     }
 
+    fn list_sessions<'a>(
+        &'a self,
+        user_id: Uuid,
+    ) -> BoxFuture<'a, Result<Vec<SessionInfo>, ListSessionsError>> {
+        Box::pin(AuthService::list_sessions(self, user_id))
+    }
+
+    fn revoke_session<'a>(
+        &'a self,
+        request: &'a RevokeSession,
+    ) -> BoxFuture<'a, Result<(), RevokeSessionError>> {
+        Box::pin(AuthService::revoke_session(self, request))
+    }
+
     fn send_verification_email<'a>(
         &'a self,
         user_id: Uuid,
@@ -603,4 +775,32 @@ where
     ) -> BoxFuture<'a, Result<uuid::Uuid, ResetPasswordError>> {
         Box::pin(AuthService::reset_password(self, request))
     }
+
+    fn create_api_key<'a>(
+        &'a self,
+        request: &'a CreateApiKey,
+    ) -> BoxFuture<'a, Result<CreatedApiKey, CreateApiKeyError>> {
+        Box::pin(AuthService::create_api_key(self, request))
+    }
+
+    fn list_api_keys<'a>(
+        &'a self,
+        user_id: Uuid,
+    ) -> BoxFuture<'a, Result<Vec<ApiKey>, ListApiKeysError>> {
+        Box::pin(AuthService::list_api_keys(self, user_id))
+    }
+
+    fn revoke_api_key<'a>(
+        &'a self,
+        request: &'a RevokeApiKey,
+    ) -> BoxFuture<'a, Result<(), RevokeApiKeyError>> {
+        Box::pin(AuthService::revoke_api_key(self, request))
+    }
+
+    fn authenticate_api_key<'a>(
+        &'a self,
+        secret: &'a str,
+    ) -> BoxFuture<'a, Result<(User, ApiKeyScope), AuthenticateApiKeyError>> {
+        Box::pin(AuthService::authenticate_api_key(self, secret))
+    }
 }
@@ -0,0 +1,23 @@
+//! API key authentication.
+//!
+//! Resolves an `Authorization: Bearer <api_key>` secret to the owning user and
+//! the key's scope, stamping the key's `last_used_at`. Unknown and revoked
+//! keys are indistinguishable to the caller.
+
+#[cfg(feature = "zerver")]
+use thiserror::Error;
+
+/// Errors that can occur while authenticating an API key.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum AuthenticateApiKeyError {
+    /// No active key matches the secret (unknown or revoked).
+    #[error("invalid api key")]
+    Invalid,
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+    /// Database returned an invalid user or key record.
+    #[error("database returned invalid object: {0}")]
+    FromDb(anyhow::Error),
+}
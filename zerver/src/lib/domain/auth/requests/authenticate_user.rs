@@ -179,6 +179,10 @@ pub struct AuthenticateUser {
     /// Client app version (e.g. `"1.6.1"`) of the session being created (recorded
     /// on the token). `None` for re-authentication and older clients.
     pub client_version: Option<String>,
+
+    /// Friendly device name of the session being created (recorded on the
+    /// token). `None` for re-authentication or an unrecognised client.
+    pub device_label: Option<String>,
 }
 
 impl AuthenticateUser {
@@ -221,6 +225,7 @@ impl AuthenticateUser {
             password: password.to_string(),
             platform: None,
             client_version: None,
+            device_label: None,
         })
     }
 }
@@ -244,6 +249,7 @@ impl From<&ChangePassword> for AuthenticateUser {
             password: value.current_password.to_owned(),
             platform: None,
             client_version: None,
+            device_label: None,
         }
     }
 }
@@ -259,6 +265,7 @@ impl From<&ChangeUsername> for AuthenticateUser {
             password: value.password.to_string(),
             platform: None,
             client_version: None,
+            device_label: None,
         }
     }
 }
@@ -274,6 +281,7 @@ impl From<&ChangeEmail> for AuthenticateUser {
             password: value.password.to_string(),
             platform: None,
             client_version: None,
+            device_label: None,
         }
     }
 }
@@ -289,6 +297,7 @@ impl From<&DeleteUser> for AuthenticateUser {
             password: value.password.to_string(),
             platform: None,
             client_version: None,
+            device_label: None,
         }
     }
 }
@@ -95,6 +95,7 @@ impl FromStr for CreateSession {
             user_id: Uuid::try_parse(s)?,
             platform: None,
             client_version: None,
+            device_label: None,
         })
     }
 }
@@ -141,6 +142,10 @@ pub struct CreateSession {
     /// Client app version of the session (e.g. `"1.6.1"`, recorded on the token).
     /// `None` for admin/impersonation paths and older clients.
     pub client_version: Option<String>,
+
+    /// Friendly device name of the session (e.g. `"Chrome on macOS"`,
+    /// recorded on the token). `None` for admin/impersonation paths.
+    pub device_label: Option<String>,
 }
 
 #[cfg(feature = "zerver")]
@@ -157,6 +162,7 @@ impl From<Uuid> for CreateSession {
             user_id: value,
             platform: None,
             client_version: None,
+            device_label: None,
         }
     }
 }
@@ -0,0 +1,18 @@
+//! API key listing.
+//!
+//! Returns a user's active (unrevoked) keys, newest first, without secrets.
+
+#[cfg(feature = "zerver")]
+use thiserror::Error;
+
+/// Errors that can occur while listing API keys.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum ListApiKeysError {
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+    /// Database returned an invalid key record.
+    #[error("database returned invalid object: {0}")]
+    ApiKeyFromDb(anyhow::Error),
+}
@@ -0,0 +1,16 @@
+//! Session listing.
+//!
+//! Returns a user's live sessions, most recently refreshed first, as
+//! metadata only — never a refresh token.
+
+#[cfg(feature = "zerver")]
+use thiserror::Error;
+
+/// Errors that can occur while listing sessions.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum ListSessionsError {
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+}
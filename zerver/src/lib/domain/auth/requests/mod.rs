@@ -5,32 +5,44 @@
 //!
 //! # Module Organization
 //!
+//! - [`authenticate_api_key`]: Resolve an API key to its user and scope
 //! - [`authenticate_user`]: User login requests and responses
 //! - [`change_email`]: Email change requests with password verification
 //! - [`change_password`]: Password change requests with current password verification
 //! - [`change_username`]: Username change requests with password verification
+//! - [`create_api_key`]: Mint a scoped API key for programmatic access
 //! - [`create_session`]: Create new session for a user
 //! - [`delete_expired_sessions`]: Cleanup expired sessions
 //! - [`delete_user`]: Account deletion requests with password verification
 //! - [`enforce_session_maximum`]: Enforce max session limit per user
+//! - [`list_api_keys`]: List a user's active API keys
+//! - [`list_sessions`]: List a user's live sessions
 //! - [`refresh_session`]: Exchange refresh token for new access token
 //! - [`register_user`]: New user registration requests
 //! - [`request_password_reset`]: Password reset initiation
 //! - [`reset_password`]: Password reset completion
+//! - [`revoke_api_key`]: Revoke one API key
+//! - [`revoke_session`]: Revoke one session (log one device out)
 //! - [`revoke_sessions`]: Delete all user sessions (logout)
 //! - [`verify_email`]: Email verification
 
+pub mod authenticate_api_key;
 pub mod authenticate_user;
 pub mod change_email;
 pub mod change_password;
 pub mod change_username;
+pub mod create_api_key;
 pub mod create_session;
 pub mod delete_expired_sessions;
 pub mod delete_user;
 pub mod enforce_session_maximum;
+pub mod list_api_keys;
+pub mod list_sessions;
 pub mod refresh_session;
 pub mod register_user;
 pub mod request_password_reset;
 pub mod reset_password;
+pub mod revoke_api_key;
+pub mod revoke_session;
 pub mod revoke_sessions;
 pub mod verify_email;
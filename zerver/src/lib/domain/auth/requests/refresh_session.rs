@@ -8,13 +8,15 @@
 //! 1. Client's access token expires (after 24h)
 //! 2. Client sends refresh token and user ID
 //! 3. Service validates refresh token (exists, not expired, not revoked, matches user)
-//! 4. Service deletes old refresh token (single-use)
+//! 4. Service retires old refresh token (single-use)
 //! 5. Service creates new session with new access + refresh tokens
 //! 6. Client updates stored tokens
 //!
 //! # Security Features
 //!
-//! - **Single-Use Tokens**: Refresh tokens are deleted after use (rotation)
+//! - **Single-Use Tokens**: Refresh tokens are retired after use (rotation)
+//! - **Reuse Detection**: Presenting a retired token revokes every session,
+//!   unless it was retired within the last few seconds (a racing refresh)
 //! - **Token Ownership**: Refresh token must belong to requesting user
 //! - **Expiry Check**: Tokens expire after 14 days
 //! - **Revocation Support**: Tokens can be revoked (logout)
@@ -75,7 +77,7 @@ pub enum RefreshSessionError {
     ///
     /// This could indicate:
     /// - Invalid/corrupted token value
-    /// - Token already used (rotation)
+    /// - Token's session revoked or evicted
     /// - Token manually deleted from database
     #[error("match for given refresh token not found; user attempting: {0}")]
     NotFound(Uuid),
@@ -92,6 +94,21 @@ pub enum RefreshSessionError {
     #[error("given refresh token has been revoked; user attempting: {0}")]
     Revoked(Uuid),
 
+    /// The refresh token was already rotated, so a second party holds it.
+    ///
+    /// Treated as a compromise: every session of the user has been revoked,
+    /// and all their devices must re-authenticate.
+    #[error("rotated refresh token presented again; all sessions revoked for user: {0}")]
+    Reused(Uuid),
+
+    /// The refresh token was rotated moments ago by another request.
+    ///
+    /// Two refreshes racing with one token (a retried request, two tabs
+    /// waking at once) end here rather than in [`Reused`](Self::Reused):
+    /// the loser is refused, but no session is revoked.
+    #[error("refresh token was just rotated by another request; user attempting: {0}")]
+    Superseded(Uuid),
+
     /// The refresh token belongs to a different user.
     ///
     /// This is a security violation - someone is attempting to use another
@@ -116,11 +133,14 @@ pub enum RefreshSessionError {
 /// Request to refresh a session using a refresh token.
 ///
 /// The refresh operation validates the token and creates a new session with
-/// fresh access and refresh tokens. The old refresh token is deleted (single-use).
+/// fresh access and refresh tokens. The old refresh token is retired (single-use).
 ///
 /// # Security
 ///
-/// - Refresh tokens are single-use - attempting to reuse causes `NotFound` error
+/// - Refresh tokens are single-use - reusing one causes a `Reused` error and
+///   revokes all the user's sessions (a `Superseded` error, revoking nothing,
+///   within [`ROTATION_GRACE`](crate::domain::auth::models::session_policy::ROTATION_GRACE)
+///   of the rotation)
 /// - Token must belong to the requesting user - mismatches cause `Forbidden` error
 /// - Expired tokens cannot be used - user must re-authenticate
 ///
@@ -156,6 +176,10 @@ pub struct RefreshSession {
     /// `None` for older clients; the rotation then carries the consumed token's
     /// stored value forward.
     pub client_version: Option<String>,
+
+    /// Friendly device name from this request's headers. `None` keeps the
+    /// label the session already has.
+    pub device_label: Option<String>,
 }
 
 impl RefreshSession {
@@ -185,6 +209,7 @@ impl RefreshSession {
             user_id,
             refresh_token,
             client_version: None,
+            device_label: None,
         })
     }
 }
@@ -208,6 +233,7 @@ impl From<&Session> for RefreshSession {
             user_id: value.user.id,
             refresh_token: value.refresh_token.value.clone(),
             client_version: None,
+            device_label: None,
         }
     }
 }
@@ -273,6 +273,10 @@ pub struct RegisterUser {
     /// Client app version (e.g. `"1.6.1"`), recorded on the auto-login session.
     /// `None` for older clients; set by the HTTP handler from the request body.
     pub client_version: Option<String>,
+
+    /// Friendly device name, recorded on the auto-login session. Set by the
+    /// HTTP handler from the request headers; `None` if unrecognised.
+    pub device_label: Option<String>,
 }
 
 #[cfg(feature = "zerver")]
@@ -322,6 +326,7 @@ impl RegisterUser {
             password_hash,
             platform: None,
             client_version: None,
+            device_label: None,
         })
     }
 }
@@ -0,0 +1,37 @@
+//! API key revocation.
+//!
+//! Marks one of the user's keys revoked; any later request bearing it is
+//! rejected as an invalid token. Revoking is scoped to the owner, so another
+//! user's key id reads as not found.
+
+#[cfg(feature = "zerver")]
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors that can occur during API key revocation.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum RevokeApiKeyError {
+    /// No active key with this id belongs to the user.
+    #[error("api key not found")]
+    NotFound,
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+}
+
+/// Request to revoke one of a user's API keys.
+#[derive(Debug, Clone)]
+pub struct RevokeApiKey {
+    /// The key's owner.
+    pub user_id: Uuid,
+    /// The key to revoke.
+    pub key_id: Uuid,
+}
+
+impl RevokeApiKey {
+    /// Creates a new revocation request.
+    pub fn new(user_id: Uuid, key_id: Uuid) -> Self {
+        Self { user_id, key_id }
+    }
+}
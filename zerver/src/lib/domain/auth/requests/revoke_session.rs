@@ -0,0 +1,41 @@
+//! Single session revocation.
+//!
+//! Logs one device out: its refresh token is deleted, so the next refresh
+//! fails and the device has to sign in again. Its current access token lives
+//! out its short expiry. Revoking is scoped to the owner, so another user's
+//! session id reads as not found.
+
+#[cfg(feature = "zerver")]
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors that can occur during session revocation.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum RevokeSessionError {
+    /// No live session with this id belongs to the user.
+    #[error("session not found")]
+    NotFound,
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+}
+
+/// Request to revoke one of a user's sessions.
+#[derive(Debug, Clone)]
+pub struct RevokeSession {
+    /// The session's owner.
+    pub user_id: Uuid,
+    /// The session to revoke.
+    pub session_id: Uuid,
+}
+
+impl RevokeSession {
+    /// Creates a new revocation request.
+    pub fn new(user_id: Uuid, session_id: Uuid) -> Self {
+        Self {
+            user_id,
+            session_id,
+        }
+    }
+}
@@ -3,21 +3,28 @@ use crate::domain::{
         models::{
             UserWithPasswordHash,
             access_token::{AccessTokenExt, JwtSecret},
-            password::{HashedPassword, Password},
+            password::{Argon2Params, HashedPassword, Password},
+            session_policy::SessionPolicy,
         },
         ports::{AuthRepository, AuthService},
         requests::{
+            authenticate_api_key::AuthenticateApiKeyError,
             authenticate_user::{AuthenticateUser, AuthenticateUserError},
             change_email::{ChangeEmail, ChangeEmailError},
             change_password::{ChangePassword, ChangePasswordError},
             change_username::{ChangeUsername, ChangeUsernameError},
+            create_api_key::{CreateApiKey, CreateApiKeyError},
             create_session::{CreateSession, CreateSessionError},
             delete_expired_sessions::DeleteExpiredSessionsError,
             delete_user::{DeleteUser, DeleteUserError},
+            list_api_keys::ListApiKeysError,
+            list_sessions::ListSessionsError,
             refresh_session::{RefreshSession, RefreshSessionError},
             register_user::{RegisterUser, RegisterUserError},
             request_password_reset::{RequestPasswordReset, RequestPasswordResetError},
             reset_password::{ResetPassword, ResetPasswordError},
+            revoke_api_key::{RevokeApiKey, RevokeApiKeyError},
+            revoke_session::{RevokeSession, RevokeSessionError},
             revoke_sessions::{RevokeSessions, RevokeSessionsError},
             verify_email::{VerifyEmail, VerifyEmailError},
         },
@@ -32,13 +39,17 @@ use sha2::Digest;
 use std::sync::LazyLock;
 use uuid::Uuid;
 use zwipe_core::domain::{
-    auth::models::{access_token::AccessToken, session::Session},
+    auth::models::{
+        access_token::AccessToken,
+        api_key::{ApiKey, ApiKeyScope, ApiKeySecret, CreatedApiKey},
+        session::{Session, SessionInfo},
+    },
     user::{User, preferences::UserPreferences},
 };
 
 /// A throwaway Argon2 hash used to equalize login timing for non-existent
-/// accounts. Generated lazily with the same default Argon2 params as real
-/// hashes, so verifying against it costs the same as verifying a real one. It
+/// accounts. Generated lazily with the same configured [`Argon2Params`] as
+/// new hashes, so verifying against it costs the same as verifying a real one. It
 /// never matches any input — its only job is to burn equivalent CPU so a
 /// missing account isn't measurably faster to reject than a real one with a
 /// wrong password (which would leak whether the account exists — enumeration).
@@ -76,6 +87,11 @@ where
     web_base_url: String,
     /// User-facing support email address shown in transactional emails.
     support_email: String,
+    /// Whether a login rehashes a password stored under weaker Argon2
+    /// parameters than new hashes get.
+    rehash_on_login: bool,
+    /// Session limit and token lifetimes.
+    session_policy: SessionPolicy,
 }
 
 impl<AR, UR, ES> Service<AR, UR, ES>
@@ -101,6 +117,45 @@ where
             jwt_secret,
             web_base_url,
             support_email,
+            rehash_on_login: true,
+            session_policy: SessionPolicy::default(),
+        }
+    }
+
+    /// Sets the session limit and token lifetimes (see [`SessionPolicy`]).
+    pub fn with_session_policy(mut self, session_policy: SessionPolicy) -> Self {
+        self.session_policy = session_policy;
+        self
+    }
+
+    /// Turns rehash-on-login on or off (on by default).
+    pub fn with_rehash_on_login(mut self, rehash_on_login: bool) -> Self {
+        self.rehash_on_login = rehash_on_login;
+        self
+    }
+
+    /// Upgrades `old` to a hash under the current Argon2 parameters now that
+    /// a login has proven `password`. Best-effort: the login already
+    /// succeeded, so a failure is logged and the old hash kept.
+    async fn upgrade_password_hash(&self, user_id: Uuid, old: &HashedPassword, password: &str) {
+        let params = Argon2Params::shared().params();
+        if !self.rehash_on_login || !old.needs_rehash(&params) {
+            return;
+        }
+        let new = match HashedPassword::hash_with(password, params) {
+            Ok(new) => new,
+            Err(e) => {
+                tracing::warn!(%user_id, error = %e, "password rehash failed");
+                return;
+            }
+        };
+        match self
+            .auth_repo
+            .upgrade_password_hash(user_id, old, &new)
+            .await
+        {
+            Ok(()) => tracing::info!(%user_id, "password hash upgraded to current parameters"),
+            Err(e) => tracing::warn!(%user_id, error = %e, "failed to store rehashed password"),
         }
     }
 
@@ -201,13 +256,17 @@ where
     async fn register_user(&self, request: &RegisterUser) -> Result<Session, RegisterUserError> {
         let (user, refresh_token) = self
             .auth_repo
-            .create_user_and_refresh_token(request)
+            .create_user_and_refresh_token(request, &self.session_policy)
             .await?;
 
         let preferences = UserPreferences::default();
 
-        let access_token = AccessToken::generate(&user, &self.jwt_secret)
-            .map_err(|e| RegisterUserError::FailedAccessToken(anyhow!("{e}")))?;
+        let access_token = AccessToken::generate_with_lifespan(
+            &user,
+            &self.jwt_secret,
+            self.session_policy.access_token_ttl(),
+        )
+        .map_err(|e| RegisterUserError::FailedAccessToken(anyhow!("{e}")))?;
 
         // Fire-and-forget: don't fail registration if email sending fails.
         if let Err(e) = self
@@ -272,6 +331,8 @@ where
 
         self.auth_repo.reset_failed_attempts(user.id).await?;
         tracing::info!(event = "login_success", identifier = %request.identifier);
+        self.upgrade_password_hash(user.id, &password_hash, &request.password)
+            .await;
 
         let preferences = self
             .user_repo
@@ -279,12 +340,22 @@ where
             .await
             .unwrap_or_default();
 
-        let access_token = AccessToken::generate(&user, &self.jwt_secret)
-            .map_err(|e| AuthenticateUserError::FailedAccessToken(anyhow!("{e}")))?;
+        let access_token = AccessToken::generate_with_lifespan(
+            &user,
+            &self.jwt_secret,
+            self.session_policy.access_token_ttl(),
+        )
+        .map_err(|e| AuthenticateUserError::FailedAccessToken(anyhow!("{e}")))?;
 
         let refresh_token = self
             .auth_repo
-            .create_refresh_token(user.id, request.platform, request.client_version.clone())
+            .create_refresh_token(
+                user.id,
+                request.platform,
+                request.client_version.clone(),
+                request.device_label.clone(),
+                &self.session_policy,
+            )
             .await?;
 
         Ok(Session {
@@ -309,10 +380,16 @@ where
                 request.user_id,
                 request.platform,
                 request.client_version.clone(),
+                request.device_label.clone(),
+                &self.session_policy,
             )
             .await?;
 
-        let access_token = AccessToken::generate(&user, self.jwt_secret())?;
+        let access_token = AccessToken::generate_with_lifespan(
+            &user,
+            self.jwt_secret(),
+            self.session_policy.access_token_ttl(),
+        )?;
 
         let session = Session {
             user,
@@ -335,9 +412,16 @@ where
             .await
             .unwrap_or_default();
 
-        let refresh_token = self.auth_repo.use_refresh_token(request).await?;
+        let refresh_token = self
+            .auth_repo
+            .use_refresh_token(request, &self.session_policy)
+            .await?;
 
-        let access_token = AccessToken::generate(&user, self.jwt_secret())?;
+        let access_token = AccessToken::generate_with_lifespan(
+            &user,
+            self.jwt_secret(),
+            self.session_policy.access_token_ttl(),
+        )?;
 
         let session = Session {
             user,
@@ -356,6 +440,16 @@ where
         Ok(())
     }
 
+    async fn list_sessions(&self, user_id: Uuid) -> Result<Vec<SessionInfo>, ListSessionsError> {
+        self.auth_repo.list_sessions(user_id).await
+    }
+
+    async fn revoke_session(&self, request: &RevokeSession) -> Result<(), RevokeSessionError> {
+        self.auth_repo.delete_session(request).await?;
+        tracing::info!(event = "session_revoked", user_id = %request.user_id, session_id = %request.session_id);
+        Ok(())
+    }
+
     // ========
     //  update
     // ========
@@ -560,6 +654,48 @@ where
         tracing::info!(event = "password_reset_success", user_id = %user_id);
         Ok(user_id)
     }
+
+    // ==========
+    //  api keys
+    // ==========
+
+    async fn create_api_key(
+        &self,
+        request: &CreateApiKey,
+    ) -> Result<CreatedApiKey, CreateApiKeyError> {
+        let secret = ApiKeySecret::generate();
+        let key = self
+            .auth_repo
+            .create_api_key(request, &ApiKeySecret::hash(secret.as_str()))
+            .await?;
+        tracing::info!(event = "api_key_created", user_id = %request.user_id, key_id = %key.id, scope = %key.scope);
+        Ok(CreatedApiKey {
+            key,
+            secret: secret.to_string(),
+        })
+    }
+
+    async fn list_api_keys(&self, user_id: Uuid) -> Result<Vec<ApiKey>, ListApiKeysError> {
+        self.auth_repo.list_api_keys(user_id).await
+    }
+
+    async fn revoke_api_key(&self, request: &RevokeApiKey) -> Result<(), RevokeApiKeyError> {
+        self.auth_repo.revoke_api_key(request).await?;
+        tracing::info!(event = "api_key_revoked", user_id = %request.user_id, key_id = %request.key_id);
+        Ok(())
+    }
+
+    async fn authenticate_api_key(
+        &self,
+        secret: &str,
+    ) -> Result<(User, ApiKeyScope), AuthenticateApiKeyError> {
+        if !ApiKeySecret::is_api_key(secret) {
+            return Err(AuthenticateApiKeyError::Invalid);
+        }
+        self.auth_repo
+            .use_api_key(&ApiKeySecret::hash(secret))
+            .await
+    }
 }
 
 #[cfg(test)]
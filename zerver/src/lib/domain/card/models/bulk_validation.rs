@@ -0,0 +1,131 @@
+//! Dry-parse report for a Scryfall bulk file.
+//!
+//! Produced by [`CardService::validate_bulk`](crate::domain::card::ports::CardService::validate_bulk):
+//! every card in the file is deserialized as a sync would, nothing is written,
+//! and failures are grouped by error so schema drift shows up as a handful of
+//! categories rather than thousands of identical lines.
+
+use serde::Serialize;
+
+/// One failure category: every card that failed with the same error message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BulkParseFailure {
+    /// The deserialization error message shared by this category.
+    pub category: String,
+    /// Cards that failed with this error.
+    pub count: u32,
+    /// Zero-based position in the bulk array of the first failing card.
+    pub first_index: usize,
+    /// `id` of the first failing card, when the record had a string one.
+    pub first_id: Option<String>,
+    /// `name` of the first failing card, when the record had a string one.
+    pub first_name: Option<String>,
+}
+
+impl std::fmt::Display for BulkParseFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} card(s): {} (first at #{}",
+            self.count, self.category, self.first_index
+        )?;
+        if let Some(name) = &self.first_name {
+            write!(f, " {name}")?;
+        }
+        if let Some(id) = &self.first_id {
+            write!(f, " [{id}]")?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Parse counts for a bulk file plus its failures by category, in order of
+/// first occurrence.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct BulkValidationReport {
+    /// Cards that deserialized cleanly.
+    pub parsed: u32,
+    /// Cards that failed to deserialize.
+    pub failed: u32,
+    /// Failures grouped by error message.
+    pub failures: Vec<BulkParseFailure>,
+}
+
+impl BulkValidationReport {
+    /// Creates an empty report.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts a card that parsed.
+    pub fn record_parsed(&mut self) {
+        self.parsed += 1;
+    }
+
+    /// Counts a card that failed, opening a new category for an unseen
+    /// `category` and otherwise bumping the existing one.
+    pub fn record_failure(
+        &mut self,
+        index: usize,
+        id: Option<String>,
+        name: Option<String>,
+        category: String,
+    ) {
+        self.failed += 1;
+        match self.failures.iter_mut().find(|f| f.category == category) {
+            Some(failure) => failure.count += 1,
+            None => self.failures.push(BulkParseFailure {
+                category,
+                count: 1,
+                first_index: index,
+                first_id: id,
+                first_name: name,
+            }),
+        }
+    }
+
+    /// Cards seen in the file.
+    pub fn total(&self) -> u32 {
+        self.parsed + self.failed
+    }
+
+    /// True when every card parsed.
+    pub fn is_clean(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+impl std::fmt::Display for BulkValidationReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "total={} | parsed={} | failed={} | categories={}",
+            self.total(),
+            self.parsed,
+            self.failed,
+            self.failures.len()
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failures_group_by_category_keeping_the_first() {
+        let mut report = BulkValidationReport::new();
+        report.record_parsed();
+        report.record_failure(1, Some("a".into()), None, "missing field `name`".into());
+        report.record_failure(2, None, None, "invalid type".into());
+        report.record_failure(3, Some("b".into()), None, "missing field `name`".into());
+
+        assert_eq!((report.total(), report.parsed, report.failed), (4, 1, 3));
+        assert!(!report.is_clean());
+        assert_eq!(report.failures.len(), 2);
+        assert_eq!(report.failures[0].count, 2);
+        assert_eq!(report.failures[0].first_index, 1);
+        assert_eq!(report.failures[0].first_id.as_deref(), Some("a"));
+    }
+}
@@ -0,0 +1,306 @@
+//! In-memory cache of the card filter option lists.
+//!
+//! Artists, card types, keywords, oracle words, oracle tags, sets and
+//! languages are each a `DISTINCT` aggregate over every card — expensive, and
+//! identical for every caller between syncs. Each list is cached for a TTL;
+//! a sync through the same service marks them stale.
+//!
+//! With stale-while-revalidate on, a stale list is still served while one
+//! background task reloads it, so a slow aggregate after a sync doesn't stall
+//! every concurrent request. Only a cold cache (nothing loaded yet) waits.
+
+use std::{
+    fmt::Display,
+    future::Future,
+    sync::{
+        Arc, Mutex, PoisonError,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant},
+};
+use zwipe_core::domain::card::oracle_tag::OracleTag;
+
+/// Default lifetime of a cached option list.
+pub const FILTER_OPTIONS_CACHE_TTL_DEFAULT: Duration = Duration::from_secs(300);
+
+/// How long option lists live and whether stale ones are served.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FilterOptionsCacheConfig {
+    /// Lifetime of a list; zero disables the cache.
+    pub ttl: Duration,
+    /// Serve a stale list while one background task reloads it, instead of
+    /// making the caller wait for the reload.
+    pub stale_while_revalidate: bool,
+}
+
+impl Default for FilterOptionsCacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: FILTER_OPTIONS_CACHE_TTL_DEFAULT,
+            stale_while_revalidate: false,
+        }
+    }
+}
+
+impl FilterOptionsCacheConfig {
+    /// A config that caches nothing.
+    pub fn disabled() -> Self {
+        Self {
+            ttl: Duration::ZERO,
+            stale_while_revalidate: false,
+        }
+    }
+
+    /// True when lists are cached at all.
+    pub fn is_enabled(&self) -> bool {
+        !self.ttl.is_zero()
+    }
+}
+
+/// A loaded value and when it stops being fresh.
+#[derive(Debug)]
+struct Entry<T> {
+    stored_at: Instant,
+    invalidated: bool,
+    value: T,
+}
+
+/// One cached value with optional stale-while-revalidate.
+#[derive(Debug)]
+pub struct CachedValue<T> {
+    config: FilterOptionsCacheConfig,
+    entry: Mutex<Option<Entry<T>>>,
+    refreshing: AtomicBool,
+}
+
+impl<T> CachedValue<T>
+where
+    T: Clone + Send + 'static,
+{
+    /// Creates an empty (cold) value.
+    pub fn new(config: FilterOptionsCacheConfig) -> Self {
+        Self {
+            config,
+            entry: Mutex::new(None),
+            refreshing: AtomicBool::new(false),
+        }
+    }
+
+    /// The stored value and whether it's still fresh.
+    fn peek(&self) -> Option<(T, bool)> {
+        let entry = self.entry.lock().unwrap_or_else(PoisonError::into_inner);
+        entry.as_ref().map(|entry| {
+            let fresh = !entry.invalidated && entry.stored_at.elapsed() < self.config.ttl;
+            (entry.value.clone(), fresh)
+        })
+    }
+
+    fn store(&self, value: T) {
+        *self.entry.lock().unwrap_or_else(PoisonError::into_inner) = Some(Entry {
+            stored_at: Instant::now(),
+            invalidated: false,
+            value,
+        });
+    }
+
+    /// Marks the value stale; it's kept to serve while revalidating.
+    pub fn invalidate(&self) {
+        if let Some(entry) = self
+            .entry
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_mut()
+        {
+            entry.invalidated = true;
+        }
+    }
+
+    /// True while a background reload is running.
+    pub fn is_refreshing(&self) -> bool {
+        self.refreshing.load(Ordering::Acquire)
+    }
+
+    /// The cached value, loading it with `load` when it's missing or stale.
+    ///
+    /// A fresh value is returned as is. A stale one, with stale-while-
+    /// revalidate on, is returned immediately while `load` runs in a
+    /// background task — at most one at a time; a failed reload is logged
+    /// and the stale value kept. Otherwise the caller waits for `load`.
+    pub async fn get_or_load<E, F, Fut>(self: &Arc<Self>, load: F) -> Result<T, E>
+    where
+        E: Display + Send + 'static,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, E>> + Send + 'static,
+    {
+        if !self.config.is_enabled() {
+            return load().await;
+        }
+        match self.peek() {
+            Some((value, true)) => return Ok(value),
+            Some((value, false)) if self.config.stale_while_revalidate => {
+                if self
+                    .refreshing
+                    .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+                    .is_ok()
+                {
+                    let cache = Arc::clone(self);
+                    let reload = load();
+                    tokio::spawn(async move {
+                        match reload.await {
+                            Ok(value) => cache.store(value),
+                            Err(e) => tracing::warn!("filter options refresh failed: {e}"),
+                        }
+                        cache.refreshing.store(false, Ordering::Release);
+                    });
+                }
+                return Ok(value);
+            }
+            _ => {}
+        }
+        let value = load().await?;
+        self.store(value.clone());
+        Ok(value)
+    }
+}
+
+/// Every cached filter option list.
+#[derive(Debug)]
+pub struct FilterOptionsCache {
+    /// Distinct artist names.
+    pub artists: Arc<CachedValue<Vec<String>>>,
+    /// Distinct card types.
+    pub card_types: Arc<CachedValue<Vec<String>>>,
+    /// Distinct keywords.
+    pub keywords: Arc<CachedValue<Vec<String>>>,
+    /// Oracle tag catalog.
+    pub oracle_tags: Arc<CachedValue<Vec<OracleTag>>>,
+    /// Distinct oracle text words.
+    pub oracle_words: Arc<CachedValue<Vec<String>>>,
+    /// Distinct set names.
+    pub sets: Arc<CachedValue<Vec<String>>>,
+    /// Distinct languages.
+    pub languages: Arc<CachedValue<Vec<String>>>,
+}
+
+impl FilterOptionsCache {
+    /// Creates an empty cache.
+    pub fn new(config: FilterOptionsCacheConfig) -> Self {
+        Self {
+            artists: Arc::new(CachedValue::new(config)),
+            card_types: Arc::new(CachedValue::new(config)),
+            keywords: Arc::new(CachedValue::new(config)),
+            oracle_tags: Arc::new(CachedValue::new(config)),
+            oracle_words: Arc::new(CachedValue::new(config)),
+            sets: Arc::new(CachedValue::new(config)),
+            languages: Arc::new(CachedValue::new(config)),
+        }
+    }
+
+    /// Marks every list stale (card data changed).
+    pub fn invalidate(&self) {
+        self.artists.invalidate();
+        self.card_types.invalidate();
+        self.keywords.invalidate();
+        self.oracle_tags.invalidate();
+        self.oracle_words.invalidate();
+        self.sets.invalidate();
+        self.languages.invalidate();
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+    use tokio::sync::Notify;
+
+    fn config(stale_while_revalidate: bool) -> FilterOptionsCacheConfig {
+        FilterOptionsCacheConfig {
+            ttl: FILTER_OPTIONS_CACHE_TTL_DEFAULT,
+            stale_while_revalidate,
+        }
+    }
+
+    fn load(value: &str) -> std::future::Ready<Result<Vec<String>, String>> {
+        std::future::ready(Ok(vec![value.to_string()]))
+    }
+
+    #[tokio::test]
+    async fn fresh_value_is_served_without_loading() {
+        let cache = Arc::new(CachedValue::new(config(false)));
+        assert_eq!(cache.get_or_load(|| load("old")).await.unwrap(), ["old"]);
+        assert_eq!(cache.get_or_load(|| load("new")).await.unwrap(), ["old"]);
+
+        // without stale-while-revalidate an invalidated value waits for the reload
+        cache.invalidate();
+        assert_eq!(cache.get_or_load(|| load("new")).await.unwrap(), ["new"]);
+    }
+
+    #[tokio::test]
+    async fn disabled_cache_always_loads() {
+        let cache = Arc::new(CachedValue::new(FilterOptionsCacheConfig::disabled()));
+        assert_eq!(cache.get_or_load(|| load("a")).await.unwrap(), ["a"]);
+        assert_eq!(cache.get_or_load(|| load("b")).await.unwrap(), ["b"]);
+    }
+
+    #[tokio::test]
+    async fn readers_get_the_stale_value_while_one_refresh_runs() {
+        let cache = Arc::new(CachedValue::new(config(true)));
+        cache.get_or_load(|| load("old")).await.unwrap();
+        cache.invalidate();
+
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let release = Arc::new(Notify::new());
+        let mut readers = tokio::task::JoinSet::new();
+        for _ in 0..16 {
+            let (cache, refreshes, release) = (
+                Arc::clone(&cache),
+                Arc::clone(&refreshes),
+                Arc::clone(&release),
+            );
+            readers.spawn(async move {
+                cache
+                    .get_or_load(|| async move {
+                        refreshes.fetch_add(1, Ordering::SeqCst);
+                        release.notified().await;
+                        Ok::<_, String>(vec!["new".to_string()])
+                    })
+                    .await
+                    .unwrap()
+            });
+        }
+        while let Some(served) = readers.join_next().await {
+            assert_eq!(served.unwrap(), ["old"]);
+        }
+        assert!(cache.is_refreshing());
+
+        release.notify_one();
+        while cache.is_refreshing() {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+        assert_eq!(cache.get_or_load(|| load("newer")).await.unwrap(), ["new"]);
+    }
+
+    #[tokio::test]
+    async fn failed_refresh_keeps_the_stale_value() {
+        let cache = Arc::new(CachedValue::new(config(true)));
+        cache.get_or_load(|| load("old")).await.unwrap();
+        cache.invalidate();
+        let served = cache
+            .get_or_load(|| async { Err::<Vec<String>, _>("db down".to_string()) })
+            .await
+            .unwrap();
+        assert_eq!(served, ["old"]);
+        while cache.is_refreshing() {
+            tokio::task::yield_now().await;
+        }
+        // still stale, so the next read kicks off another refresh
+        assert_eq!(cache.get_or_load(|| load("new")).await.unwrap(), ["old"]);
+        while cache.is_refreshing() {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(cache.get_or_load(|| load("newer")).await.unwrap(), ["new"]);
+    }
+}
@@ -1,12 +1,38 @@
+/// Bulk file dry-parse report.
+pub mod bulk_validation;
+/// TTL cache of the card filter option lists, with optional
+/// stale-while-revalidate.
+#[cfg(feature = "zerver")]
+pub mod filter_options_cache;
 /// Helper traits and utilities for card operations.
 pub mod helpers;
 /// Card search error types.
 pub mod search_card;
 
+/// A card's stored rows as raw JSON, for admin inspection.
+#[cfg(feature = "zerver")]
+pub mod raw_card;
+
+/// Short-TTL cache of identical card searches.
+#[cfg(feature = "zerver")]
+pub mod search_cache;
+
 /// Commander synergy payload (cache read side).
 #[cfg(feature = "zerver")]
 pub mod synergy;
 
+/// Resume point for an interrupted Scryfall sync.
+#[cfg(feature = "zerver")]
+pub mod sync_checkpoint;
+
+/// Progress events for an in-flight Scryfall sync.
+#[cfg(feature = "zerver")]
+pub mod sync_progress;
+
+/// Scryfall sync cadence (full vs partial, intervals).
+#[cfg(feature = "zerver")]
+pub mod sync_schedule;
+
 /// Zervice metrics tracking for sync and classification operations.
 #[cfg(feature = "zerver")]
 pub mod zervice_metrics;
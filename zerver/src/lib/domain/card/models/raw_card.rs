@@ -0,0 +1,19 @@
+//! A card's stored rows, untouched by domain conversion.
+//!
+//! Admin-only: lets an operator see exactly what the sync wrote for a card,
+//! including rows that no longer parse into [`ScryfallData`] or
+//! [`CardProfile`].
+//!
+//! [`ScryfallData`]: zwipe_core::domain::card::scryfall_data::ScryfallData
+//! [`CardProfile`]: zwipe_core::domain::card::card_profile::CardProfile
+
+use serde::Serialize;
+
+/// The `scryfall_data` row and its `card_profiles` row, each as JSON.
+#[derive(Debug, Clone, Serialize)]
+pub struct RawCard {
+    /// The `scryfall_data` row, column name → value.
+    pub scryfall_data: serde_json::Value,
+    /// The `card_profiles` row, or `None` when the card has no profile yet.
+    pub card_profile: Option<serde_json::Value>,
+}
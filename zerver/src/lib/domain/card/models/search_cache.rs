@@ -0,0 +1,176 @@
+//! Short-lived in-memory cache of card search results.
+//!
+//! The hottest searches (a popular card name, the default browse) repeat
+//! constantly with identical filters. Results are keyed by the serialized
+//! [`CardQuery`] — criteria plus limit, offset and ordering, so each page is
+//! its own entry — and expire after a short TTL. A sync run through the same
+//! service clears the cache; a sync in another process (zervice) is bounded
+//! by the TTL instead.
+
+use dashmap::DashMap;
+use std::time::{Duration, Instant};
+use zwipe_core::domain::card::{Card, search_card::card_filter::CardQuery};
+
+/// Default lifetime of a cached result.
+pub const SEARCH_CACHE_TTL_DEFAULT: Duration = Duration::from_secs(30);
+
+/// Default maximum number of cached queries.
+pub const SEARCH_CACHE_CAPACITY_DEFAULT: usize = 1_000;
+
+/// How long results live and how many queries are kept.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchCacheConfig {
+    /// Lifetime of an entry; zero disables the cache.
+    pub ttl: Duration,
+    /// Maximum number of entries; zero disables the cache.
+    pub capacity: usize,
+}
+
+impl Default for SearchCacheConfig {
+    fn default() -> Self {
+        Self {
+            ttl: SEARCH_CACHE_TTL_DEFAULT,
+            capacity: SEARCH_CACHE_CAPACITY_DEFAULT,
+        }
+    }
+}
+
+impl SearchCacheConfig {
+    /// A config that caches nothing.
+    pub fn disabled() -> Self {
+        Self {
+            ttl: Duration::ZERO,
+            capacity: 0,
+        }
+    }
+
+    /// True when results are cached at all.
+    pub fn is_enabled(&self) -> bool {
+        !self.ttl.is_zero() && self.capacity > 0
+    }
+}
+
+/// Search results keyed by serialized query.
+#[derive(Debug)]
+pub struct SearchCache {
+    config: SearchCacheConfig,
+    entries: DashMap<String, (Instant, Vec<Card>)>,
+}
+
+impl SearchCache {
+    /// Creates an empty cache.
+    pub fn new(config: SearchCacheConfig) -> Self {
+        Self {
+            config,
+            entries: DashMap::new(),
+        }
+    }
+
+    /// The cache key for `query`, or `None` when caching is off.
+    fn key(&self, query: &CardQuery) -> Option<String> {
+        if !self.config.is_enabled() {
+            return None;
+        }
+        serde_json::to_string(query).ok()
+    }
+
+    /// The cached results for `query`, if present and not expired.
+    pub fn get(&self, query: &CardQuery) -> Option<Vec<Card>> {
+        let key = self.key(query)?;
+        let entry = self.entries.get(&key)?;
+        let (stored_at, cards) = entry.value();
+        if stored_at.elapsed() < self.config.ttl {
+            return Some(cards.clone());
+        }
+        drop(entry);
+        self.entries.remove(&key);
+        None
+    }
+
+    /// Caches `cards` as the results for `query`. At capacity, expired
+    /// entries are dropped first and the whole cache if that isn't enough.
+    pub fn insert(&self, query: &CardQuery, cards: &[Card]) {
+        let Some(key) = self.key(query) else {
+            return;
+        };
+        if self.entries.len() >= self.config.capacity {
+            let ttl = self.config.ttl;
+            self.entries
+                .retain(|_, (stored_at, _)| stored_at.elapsed() < ttl);
+            if self.entries.len() >= self.config.capacity {
+                self.entries.clear();
+            }
+        }
+        self.entries.insert(key, (Instant::now(), cards.to_vec()));
+    }
+
+    /// Drops every entry (card data changed).
+    pub fn clear(&self) {
+        self.entries.clear();
+    }
+
+    /// Number of cached queries, expired ones included.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// True when nothing is cached.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use zwipe_core::domain::card::search_card::card_filter::builder::CardQueryBuilder;
+
+    fn query(offset: u32) -> CardQuery {
+        let mut builder = CardQueryBuilder::with_name_contains("sol ring");
+        builder.set_offset(offset);
+        builder.build().unwrap()
+    }
+
+    #[test]
+    fn identical_query_hits_and_other_page_misses() {
+        let cache = SearchCache::new(SearchCacheConfig::default());
+        assert!(cache.get(&query(0)).is_none());
+
+        // An empty result page is still a result worth caching.
+        cache.insert(&query(0), &[]);
+        assert_eq!(cache.get(&query(0)), Some(Vec::new()));
+        assert!(cache.get(&query(25)).is_none());
+
+        cache.clear();
+        assert!(cache.get(&query(0)).is_none());
+    }
+
+    #[test]
+    fn expired_and_disabled_caches_miss() {
+        let cache = SearchCache::new(SearchCacheConfig {
+            ttl: Duration::from_millis(1),
+            capacity: 10,
+        });
+        cache.insert(&query(0), &[]);
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(cache.get(&query(0)).is_none());
+
+        let disabled = SearchCache::new(SearchCacheConfig::disabled());
+        disabled.insert(&query(0), &[]);
+        assert!(disabled.is_empty());
+    }
+
+    #[test]
+    fn full_cache_makes_room() {
+        let cache = SearchCache::new(SearchCacheConfig {
+            ttl: SEARCH_CACHE_TTL_DEFAULT,
+            capacity: 2,
+        });
+        for offset in [0, 25, 50] {
+            cache.insert(&query(offset), &[]);
+        }
+        assert!(cache.len() <= 2);
+        assert!(cache.get(&query(50)).is_some());
+    }
+}
@@ -0,0 +1,70 @@
+//! Resume point for an interrupted Scryfall sync.
+//!
+//! A streaming sync saves how many cards of the bulk file it has upserted
+//! after every batch. If the process dies, the next run of the same kind
+//! against the same file skips that many cards instead of starting over;
+//! upserts are idempotent, so replaying a partly written batch is harmless.
+
+use crate::domain::card::models::sync_schedule::SyncKind;
+
+/// How far a sync of one kind got through one bulk file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncCheckpoint {
+    /// Which sync this resumes.
+    pub sync_kind: SyncKind,
+    /// The bulk file's download URI; Scryfall timestamps these, so a new
+    /// file never matches an old checkpoint.
+    pub download_uri: String,
+    /// Cards from the start of the file already upserted.
+    pub processed_count: usize,
+}
+
+impl SyncCheckpoint {
+    /// How many leading cards a sync of `sync_kind` over `download_uri`
+    /// can skip: this checkpoint's count if it's for the same sync and
+    /// file, else zero.
+    pub fn resume_from(
+        checkpoint: Option<&Self>,
+        sync_kind: SyncKind,
+        download_uri: &str,
+    ) -> usize {
+        checkpoint
+            .filter(|c| c.sync_kind == sync_kind && c.download_uri == download_uri)
+            .map_or(0, |c| c.processed_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resumes_only_the_same_sync_over_the_same_file() {
+        let checkpoint = SyncCheckpoint {
+            sync_kind: SyncKind::Partial,
+            download_uri: "https://data.scryfall.io/default-cards-20261001.json".to_string(),
+            processed_count: 650,
+        };
+        let uri = checkpoint.download_uri.clone();
+        assert_eq!(
+            SyncCheckpoint::resume_from(Some(&checkpoint), SyncKind::Partial, &uri),
+            650
+        );
+        assert_eq!(
+            SyncCheckpoint::resume_from(Some(&checkpoint), SyncKind::Full, &uri),
+            0
+        );
+        assert_eq!(
+            SyncCheckpoint::resume_from(
+                Some(&checkpoint),
+                SyncKind::Partial,
+                "https://data.scryfall.io/default-cards-20261002.json"
+            ),
+            0
+        );
+        assert_eq!(
+            SyncCheckpoint::resume_from(None, SyncKind::Partial, &uri),
+            0
+        );
+    }
+}
@@ -0,0 +1,182 @@
+//! Progress events for an in-flight Scryfall sync.
+//!
+//! A sync only reports [`ZerviceMetrics`] once it's done. When a
+//! [`SyncProgressReporter`] is installed, the sync also hands a
+//! [`SyncProgress`] snapshot to its callback every `interval` cards, and once
+//! more at the end, so a consumer can log it or forward it elsewhere.
+
+use crate::domain::card::models::zervice_metrics::ZerviceMetrics;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Default number of cards between progress events.
+pub const SYNC_PROGRESS_INTERVAL_DEFAULT: usize = 10_000;
+
+/// Snapshot of a sync's progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncProgress {
+    /// Cards handled so far, including any skipped by resuming a checkpoint.
+    pub processed: usize,
+    /// Cards in the bulk file, when known up front (a streaming sync only
+    /// learns it at the end).
+    pub total: Option<usize>,
+    /// Cards written, price-only refreshes included.
+    pub upserted: usize,
+    /// Cards skipped as already up to date.
+    pub skipped: usize,
+    /// Cards that failed to write.
+    pub errors: usize,
+    /// Time since the sync started reporting.
+    pub elapsed: Duration,
+}
+
+impl std::fmt::Display for SyncProgress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.total {
+            Some(total) => write!(f, "processed={}/{total}", self.processed)?,
+            None => write!(f, "processed={}", self.processed)?,
+        }
+        write!(
+            f,
+            " | upserted={} | skipped={} | errors={} | elapsed={}s",
+            self.upserted,
+            self.skipped,
+            self.errors,
+            self.elapsed.as_secs()
+        )
+    }
+}
+
+/// Hands [`SyncProgress`] snapshots to a callback every `interval` cards.
+#[derive(Clone)]
+pub struct SyncProgressReporter {
+    interval: usize,
+    callback: Arc<dyn Fn(&SyncProgress) + Send + Sync>,
+}
+
+impl std::fmt::Debug for SyncProgressReporter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncProgressReporter")
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SyncProgressReporter {
+    /// Calls `callback` every `interval` processed cards. Zero is treated as one.
+    pub fn new(interval: usize, callback: impl Fn(&SyncProgress) + Send + Sync + 'static) -> Self {
+        Self {
+            interval: interval.max(1),
+            callback: Arc::new(callback),
+        }
+    }
+
+    /// Starts tracking one sync of `total` cards (if known); the clock
+    /// starts now.
+    pub fn start(&self, total: Option<usize>) -> SyncProgressTracker {
+        SyncProgressTracker {
+            reporter: self.clone(),
+            total,
+            started: Instant::now(),
+            processed: 0,
+            reported: 0,
+        }
+    }
+}
+
+/// One sync's running count, reporting through its [`SyncProgressReporter`].
+#[derive(Debug)]
+pub struct SyncProgressTracker {
+    reporter: SyncProgressReporter,
+    total: Option<usize>,
+    started: Instant,
+    processed: usize,
+    /// `processed` as of the last event.
+    reported: usize,
+}
+
+impl SyncProgressTracker {
+    /// Counts `cards` more as processed, with `metrics` holding the sync's
+    /// counts so far, and reports if that crossed an interval boundary.
+    pub fn advance(&mut self, cards: usize, metrics: &ZerviceMetrics) {
+        self.processed += cards;
+        let interval = self.reporter.interval;
+        if self.processed / interval > self.reported / interval {
+            self.report(metrics);
+        }
+    }
+
+    /// Reports the final count, now known to be `total`, unless the last
+    /// event already covered it.
+    pub fn finish(&mut self, total: usize, metrics: &ZerviceMetrics) {
+        self.total = Some(total);
+        if self.processed != self.reported || self.processed == 0 {
+            self.report(metrics);
+        }
+    }
+
+    fn report(&mut self, metrics: &ZerviceMetrics) {
+        let count = |n: i32| usize::try_from(n).unwrap_or_default();
+        let progress = SyncProgress {
+            processed: self.processed,
+            total: self.total,
+            upserted: count(metrics.upserted_count()) + count(metrics.price_updated_count()),
+            skipped: count(metrics.skipped_count()),
+            errors: count(metrics.error_count()),
+            elapsed: self.started.elapsed(),
+        };
+        self.reported = self.processed;
+        (self.reporter.callback)(&progress);
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn recording(interval: usize) -> (SyncProgressReporter, Arc<Mutex<Vec<SyncProgress>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let reporter = SyncProgressReporter::new(interval, move |p| sink.lock().unwrap().push(*p));
+        (reporter, events)
+    }
+
+    #[test]
+    fn reports_each_interval_crossed() {
+        let (reporter, events) = recording(100);
+        let mut metrics = ZerviceMetrics::new();
+        let mut tracker = reporter.start(None);
+        tracker.advance(60, &metrics);
+        metrics.add_upserted_count(100).add_skipped_count(20);
+        tracker.advance(60, &metrics);
+        tracker.advance(90, &metrics);
+        tracker.finish(210, &metrics);
+        let processed: Vec<usize> = events.lock().unwrap().iter().map(|p| p.processed).collect();
+        assert_eq!(processed, [120, 210]);
+        let last = *events.lock().unwrap().last().unwrap();
+        assert_eq!(last.upserted, 100);
+        assert_eq!(last.skipped, 20);
+        assert_eq!(last.total, None);
+    }
+
+    #[test]
+    fn finish_reports_the_total_unless_already_covered() {
+        let (reporter, events) = recording(100);
+        let metrics = ZerviceMetrics::new();
+        let mut tracker = reporter.start(Some(150));
+        tracker.advance(150, &metrics);
+        tracker.finish(150, &metrics);
+        assert_eq!(events.lock().unwrap().len(), 1);
+
+        let mut tracker = reporter.start(None);
+        tracker.advance(40, &metrics);
+        tracker.finish(40, &metrics);
+        let last = *events.lock().unwrap().last().unwrap();
+        assert_eq!(last.processed, 40);
+        assert_eq!(last.total, Some(40));
+    }
+}
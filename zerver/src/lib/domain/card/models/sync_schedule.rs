@@ -0,0 +1,186 @@
+//! Scryfall sync cadence.
+//!
+//! A full sync rewrites every card; a partial sync is the delta upsert that
+//! only touches cards Scryfall changed. [`SyncSchedule`] decides which (if
+//! either) is due from the last recorded runs, so cadence is configuration
+//! rather than code.
+
+use anyhow::anyhow;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// How much of the card table a sync rewrites.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncKind {
+    /// Upserts every received card regardless of its stored version.
+    Full,
+    /// Upserts only cards newer than the stored version.
+    #[default]
+    Partial,
+}
+
+impl std::fmt::Display for SyncKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Full => write!(f, "full"),
+            Self::Partial => write!(f, "partial"),
+        }
+    }
+}
+
+impl TryFrom<&str> for SyncKind {
+    type Error = anyhow::Error;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "full" => Ok(Self::Full),
+            "partial" => Ok(Self::Partial),
+            x => Err(anyhow!("failed to parse SyncKind from {x}")),
+        }
+    }
+}
+
+/// Error returned when a sync interval isn't `<positive integer><unit>`.
+#[derive(Debug, Clone, Error)]
+#[error("invalid sync interval {0:?}: expected a number and unit s/m/h/d/w (e.g. 7d)")]
+pub struct InvalidSyncInterval(String);
+
+/// Parses an interval like `90s`, `30m`, `1h`, `7d` or `4w`.
+pub fn parse_interval(raw: &str) -> Result<TimeDelta, InvalidSyncInterval> {
+    let invalid = || InvalidSyncInterval(raw.to_string());
+    let raw_trimmed = raw.trim();
+    let unit_at = raw_trimmed
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(invalid)?;
+    let (amount, unit) = raw_trimmed.split_at(unit_at);
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    if amount == 0 {
+        return Err(invalid());
+    }
+    let interval = match unit {
+        "s" => TimeDelta::try_seconds(amount),
+        "m" => TimeDelta::try_minutes(amount),
+        "h" => TimeDelta::try_hours(amount),
+        "d" => TimeDelta::try_days(amount),
+        "w" => TimeDelta::try_weeks(amount),
+        _ => None,
+    };
+    interval.ok_or_else(invalid)
+}
+
+/// True when `last` is at least `interval` before `now`. A run that never
+/// happened (`None`) is always long enough ago.
+pub fn was_ago(last: Option<DateTime<Utc>>, interval: TimeDelta, now: DateTime<Utc>) -> bool {
+    last.is_none_or(|last| now - last >= interval)
+}
+
+/// Sync cadence: how stale the last full and partial syncs may get, and how
+/// often a long-running zervice re-checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncSchedule {
+    /// Time between full syncs. Defaults to 30 days.
+    pub full_sync_interval: TimeDelta,
+    /// Time between syncs of any kind. Defaults to 7 days.
+    pub partial_sync_interval: TimeDelta,
+    /// Sleep between schedule checks in `zervice --watch`. Defaults to 1 hour.
+    pub check_interval: TimeDelta,
+}
+
+impl Default for SyncSchedule {
+    fn default() -> Self {
+        Self {
+            full_sync_interval: TimeDelta::days(30),
+            partial_sync_interval: TimeDelta::days(7),
+            check_interval: TimeDelta::hours(1),
+        }
+    }
+}
+
+impl SyncSchedule {
+    /// The sync due at `now`, given when the last sync of any kind and the
+    /// last full sync started. A due full sync wins over a partial one (it
+    /// covers everything a partial would); `None` means nothing is due.
+    pub fn due(
+        &self,
+        now: DateTime<Utc>,
+        last_sync: Option<DateTime<Utc>>,
+        last_full_sync: Option<DateTime<Utc>>,
+    ) -> Option<SyncKind> {
+        if was_ago(last_full_sync, self.full_sync_interval, now) {
+            Some(SyncKind::Full)
+        } else if was_ago(last_sync, self.partial_sync_interval, now) {
+            Some(SyncKind::Partial)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn days_ago(now: DateTime<Utc>, days: i64) -> DateTime<Utc> {
+        now - TimeDelta::days(days)
+    }
+
+    #[test]
+    fn parses_intervals_with_units() {
+        assert_eq!(parse_interval("90s").unwrap(), TimeDelta::seconds(90));
+        assert_eq!(parse_interval("1h").unwrap(), TimeDelta::hours(1));
+        assert_eq!(parse_interval(" 7d ").unwrap(), TimeDelta::days(7));
+        assert_eq!(parse_interval("2w").unwrap(), TimeDelta::weeks(2));
+        for bad in ["", "7", "d", "0d", "7y", "-1d", "1.5h"] {
+            assert!(parse_interval(bad).is_err(), "{bad:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn default_schedule_is_monthly_full_weekly_partial() {
+        let schedule = SyncSchedule::default();
+        let now = Utc::now();
+        assert_eq!(schedule.due(now, None, None), Some(SyncKind::Full));
+        assert_eq!(
+            schedule.due(now, Some(days_ago(now, 8)), Some(days_ago(now, 8))),
+            Some(SyncKind::Partial)
+        );
+        assert_eq!(
+            schedule.due(now, Some(days_ago(now, 3)), Some(days_ago(now, 31))),
+            Some(SyncKind::Full)
+        );
+        assert_eq!(
+            schedule.due(now, Some(days_ago(now, 3)), Some(days_ago(now, 20))),
+            None
+        );
+    }
+
+    #[test]
+    fn custom_intervals_drive_the_decision() {
+        let schedule = SyncSchedule {
+            full_sync_interval: TimeDelta::days(7),
+            partial_sync_interval: TimeDelta::days(1),
+            check_interval: TimeDelta::minutes(15),
+        };
+        let now = Utc::now();
+        // Two days since any sync: partial under the custom daily cadence,
+        // nothing under the weekly default.
+        assert_eq!(
+            schedule.due(now, Some(days_ago(now, 2)), Some(days_ago(now, 2))),
+            Some(SyncKind::Partial)
+        );
+        assert_eq!(
+            SyncSchedule::default().due(now, Some(days_ago(now, 2)), Some(days_ago(now, 2))),
+            None
+        );
+        // Eight days since the last full sync: due under the custom weekly
+        // full cadence even though a partial ran an hour ago.
+        let an_hour_ago = Some(now - TimeDelta::hours(1));
+        assert_eq!(
+            schedule.due(now, an_hour_ago, Some(days_ago(now, 8))),
+            Some(SyncKind::Full)
+        );
+        assert_eq!(schedule.due(now, an_hour_ago, Some(days_ago(now, 6))), None);
+    }
+}
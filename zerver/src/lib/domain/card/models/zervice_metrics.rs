@@ -2,6 +2,7 @@
 //!
 //! Tracks progress, errors, and outcomes of Scryfall bulk data sync operations.
 
+use crate::domain::card::models::sync_schedule::SyncKind;
 use anyhow::anyhow;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -148,6 +149,8 @@ impl std::ops::Deref for VecErrorMetrics {
 pub struct ZerviceMetrics {
     /// Final status (Success, PartialSuccess, Failure, InProgress).
     status: SyncStatus,
+    /// Whether every card was rewritten or only changed ones.
+    sync_kind: SyncKind,
     /// When sync started (UTC).
     started_at: DateTime<Utc>,
     /// When sync ended (UTC, None if still in progress).
@@ -158,6 +161,8 @@ pub struct ZerviceMetrics {
     received_count: i32,
     /// Cards successfully inserted/updated.
     upserted_count: i32,
+    /// Cards whose prices were refreshed without rewriting the rest of the row.
+    price_updated_count: i32,
     /// Cards skipped (already up-to-date).
     skipped_count: i32,
     /// Number of errors encountered.
@@ -170,11 +175,13 @@ impl Default for ZerviceMetrics {
     fn default() -> Self {
         Self {
             status: SyncStatus::InProgress,
+            sync_kind: SyncKind::default(),
             started_at: Utc::now(),
             ended_at: None,
             duration_in_seconds: 0,
             received_count: 0,
             upserted_count: 0,
+            price_updated_count: 0,
             skipped_count: 0,
             error_count: 0,
             errors: Vec::new(),
@@ -194,6 +201,12 @@ impl ZerviceMetrics {
         self
     }
 
+    /// Sets the sync kind (full or partial).
+    pub fn set_sync_kind(&mut self, sync_kind: SyncKind) -> &mut Self {
+        self.sync_kind = sync_kind;
+        self
+    }
+
     /// Sets the sync start time.
     pub fn set_started_at(&mut self, started_at: DateTime<Utc>) -> &mut Self {
         self.started_at = started_at;
@@ -230,6 +243,18 @@ impl ZerviceMetrics {
         self
     }
 
+    /// Sets the total number of cards that only had their prices refreshed.
+    pub fn set_price_updated_count(&mut self, count: i32) -> &mut Self {
+        self.price_updated_count = count;
+        self
+    }
+
+    /// Increments the price-updated count by the specified amount.
+    pub fn add_price_updated_count(&mut self, count: i32) -> &mut Self {
+        self.price_updated_count += count;
+        self
+    }
+
     /// Sets the total number of cards skipped (already up-to-date).
     pub fn set_skipped_count(&mut self, count: i32) -> &mut Self {
         self.skipped_count = count;
@@ -264,6 +289,17 @@ impl ZerviceMetrics {
         self
     }
 
+    /// Folds another run's counts and errors into this one, e.g. the metrics
+    /// of one concurrently upserted chunk. Status and timing are left as is.
+    pub fn merge(&mut self, other: ZerviceMetrics) -> &mut Self {
+        self.upserted_count += other.upserted_count;
+        self.price_updated_count += other.price_updated_count;
+        self.skipped_count += other.skipped_count;
+        self.errors.extend(other.errors);
+        self.error_count = self.errors.len() as i32;
+        self
+    }
+
     /// Marks sync as completed, evaluates status, and calculates duration.
     ///
     /// Call this when sync finishes (success or failure). This method:
@@ -295,6 +331,11 @@ impl ZerviceMetrics {
         self.duration_in_seconds
     }
 
+    /// Returns whether this was a full or partial sync.
+    pub fn sync_kind(&self) -> SyncKind {
+        self.sync_kind
+    }
+
     /// Returns the final sync status.
     pub fn status(&self) -> SyncStatus {
         self.status
@@ -310,6 +351,11 @@ impl ZerviceMetrics {
         self.upserted_count
     }
 
+    /// Returns total cards that only had their prices refreshed.
+    pub fn price_updated_count(&self) -> i32 {
+        self.price_updated_count
+    }
+
     /// Returns total cards skipped (already up-to-date).
     pub fn skipped_count(&self) -> i32 {
         self.skipped_count
@@ -331,14 +377,16 @@ impl ZerviceMetrics {
     /// - **PartialSuccess**: ≥70% intended cards upserted
     /// - **Failure**: <70% success rate
     ///
-    /// Intended cards = received_count - skipped_count
+    /// Intended cards = received_count - skipped_count; price-only refreshes
+    /// count as written.
     fn evaluate_status(&mut self) -> &mut Self {
         self.status = SyncStatus::Failure;
         let intended_to_import = self.received_count - self.skipped_count;
-        if self.upserted_count as f32 >= intended_to_import as f32 * 0.7 {
+        let written = self.upserted_count + self.price_updated_count;
+        if written as f32 >= intended_to_import as f32 * 0.7 {
             self.status = SyncStatus::PartialSuccess;
         }
-        if self.upserted_count == intended_to_import && self.error_count == 0 {
+        if written == intended_to_import && self.error_count == 0 {
             self.status = SyncStatus::Success;
         }
         self
@@ -349,13 +397,41 @@ impl std::fmt::Display for ZerviceMetrics {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "status={} | duration={}s | received={} | upserted={} | skipped={} | errors={}",
+            "kind={} | status={} | duration={}s | received={} | upserted={} | price_updated={} | skipped={} | errors={}",
+            self.sync_kind,
             self.status,
             self.duration_in_seconds,
             self.received_count,
             self.upserted_count,
+            self.price_updated_count,
             self.skipped_count,
             self.error_count,
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_sums_counts_and_keeps_every_error() {
+        let mut total = ZerviceMetrics::new();
+        total
+            .add_upserted_count(3)
+            .add_error(ErrorMetrics::new(Uuid::nil(), "First", "bad row"));
+        let mut chunk = ZerviceMetrics::new();
+        chunk
+            .add_upserted_count(2)
+            .add_price_updated_count(4)
+            .add_skipped_count(5)
+            .add_error(ErrorMetrics::new(Uuid::nil(), "Second", "chunk failed"));
+
+        total.merge(chunk);
+        assert_eq!(total.upserted_count(), 5);
+        assert_eq!(total.price_updated_count(), 4);
+        assert_eq!(total.skipped_count(), 5);
+        assert_eq!(total.error_count(), 2);
+        assert_eq!(total.errors().len(), 2);
+    }
+}
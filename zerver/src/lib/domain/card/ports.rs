@@ -5,15 +5,30 @@
 //! for fast querying.
 
 use crate::domain::BoxFuture;
-use std::future::Future;
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    path::Path,
+};
 
 use chrono::{DateTime, Utc};
+use tokio::io::AsyncBufRead;
 
 use crate::{
     domain::card::{
-        models::{search_card::error::SearchCardsError, zervice_metrics::ZerviceMetrics},
+        models::{
+            bulk_validation::BulkValidationReport,
+            raw_card::RawCard,
+            search_card::error::SearchCardsError,
+            sync_checkpoint::SyncCheckpoint,
+            sync_progress::SyncProgressTracker,
+            sync_schedule::{SyncKind, SyncSchedule},
+            zervice_metrics::ZerviceMetrics,
+        },
         requests::{
+            considering::ConsideringError,
             create_card::CreateCardError,
+            favorites::FavoritesError,
             get_artists::GetArtistsError,
             get_card::GetCardError,
             get_card_profile::{CardProfileIds, GetCardProfile, GetCardProfileError},
@@ -25,15 +40,33 @@ use crate::{
             get_scryfall_data::{
                 GetScryfallData, GetScryfallDataError, ScryfallDataIds, SearchScryfallDataError,
             },
+            get_set_completion::GetSetCompletionError,
+            get_set_icon::GetSetIconError,
             get_sets::GetSetsError,
+            import_collection::ImportCollectionError,
         },
     },
-    inbound::external::scryfall::{bulk::BulkEndpoint, oracle_tag::OracleTag as ScryfallOracleTag},
+    inbound::external::scryfall::{
+        bulk::BulkEndpoint, oracle_tag::OracleTag as ScryfallOracleTag, set::ScryfallSet,
+    },
 };
 use zwipe_core::domain::card::{
-    Card, card_profile::CardProfile, oracle_tag::OracleTag, scryfall_data::ScryfallData,
+    Card,
+    card_profile::CardProfile,
+    card_summary::CardSummary,
+    color_distribution::ColorDistribution,
+    oracle_tag::OracleTag,
+    printing_summary::CardWithPrintings,
+    scryfall_data::{ScryfallData, colors::Colors},
     search_card::card_filter::CardQuery,
+    set_completion::SetCompletion,
+    set_icon::SetIcon,
 };
+use zwipe_core::domain::deck::CollectionImportReport;
+
+/// Each requested card name paired with the card it resolved to, in request
+/// order; `None` when no card has that exact name.
+pub type NameLookup = Vec<(String, Option<Card>)>;
 
 /// The optional deck-serving inputs threaded into the deck-aware search entry
 /// points ([`CardRepository::search_scryfall_data_deck_aware`] +
@@ -56,6 +89,10 @@ pub struct DeckServeContext<'a> {
     pub commander_seed: Option<String>,
     /// The deck's selected oracle tags for the `W_ORACLE_TAG` correlation term.
     pub deck_oracle_tags: &'a [String],
+    /// Layout whitelist the `is_playable` filter checks against. `None` is the
+    /// deck whitelist (`PLAYABLE_LAYOUTS`); token searches pass one that
+    /// includes token layouts.
+    pub playable_layouts: Option<&'a [&'a str]>,
 }
 
 /// Database port for MTG card operations.
@@ -84,21 +121,49 @@ pub trait CardRepository: Clone + Send + Sync + 'static {
         zervice_metrics: &mut ZerviceMetrics,
     ) -> impl Future<Output = Result<Vec<Card>, CreateCardError>> + Send;
 
-    /// Delta upserts - only updates cards newer than database version.
+    /// Delta upserts - rewrites cards whose gameplay fields changed, refreshes
+    /// prices on the rest, and skips identical cards.
+    ///
+    /// The input is split into `batch_size` chunks, up to `concurrency` of
+    /// which run at once, each in its own transaction on its own connection.
+    /// A chunk that fails is recorded in `zervice_metrics` as errors rather
+    /// than aborting the others. `progress`, if given, advances as each
+    /// chunk lands.
     fn batch_delta_upsert(
         &self,
         multiple_scryfall_data: &[ScryfallData],
         batch_size: usize,
+        concurrency: usize,
         zervice_metrics: &mut ZerviceMetrics,
+        progress: Option<&mut SyncProgressTracker>,
     ) -> impl Future<Output = Result<Vec<Card>, CreateCardError>> + Send;
 
+    /// The saved resume point of an interrupted `sync_kind` sync, if any.
+    fn get_sync_checkpoint(
+        &self,
+        sync_kind: SyncKind,
+    ) -> impl Future<Output = anyhow::Result<Option<SyncCheckpoint>>> + Send;
+
+    /// Saves (replacing) the resume point of an in-flight sync.
+    fn save_sync_checkpoint(
+        &self,
+        checkpoint: &SyncCheckpoint,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
+    /// Drops the resume point of a `sync_kind` sync that completed.
+    fn clear_sync_checkpoint(
+        &self,
+        sync_kind: SyncKind,
+    ) -> impl Future<Output = anyhow::Result<()>> + Send;
+
     /// Records sync metrics to database.
     fn record_zervice_metrics(
         &self,
         zervice_metrics: &ZerviceMetrics,
     ) -> impl Future<Output = Result<ZerviceMetrics, anyhow::Error>> + Send;
 
-    /// Refreshes the `latest_cards` materialized view.
+    /// Refreshes the `latest_cards` materialized view, then re-points
+    /// oracle-referenced deck cards whose preferred printing moved.
     ///
     /// Must be called after any operation that changes `scryfall_data` or `card_profiles`
     /// membership (e.g., after Scryfall sync + classification).
@@ -113,6 +178,11 @@ pub trait CardRepository: Clone + Send + Sync + 'static {
     fn refresh_otag_context_signal_rollup(&self)
     -> impl Future<Output = anyhow::Result<()>> + Send;
 
+    /// Refreshes the `format_staples` materialized view (top commander-legal
+    /// cards by `edhrec_rank` per color identity). Reads `latest_cards`, so it
+    /// must run after [`refresh_latest_cards`](Self::refresh_latest_cards).
+    fn refresh_format_staples(&self) -> impl Future<Output = anyhow::Result<()>> + Send;
+
     /// Full-replaces the `oracle_tags` catalog and the scryfall-sourced rows of
     /// `card_oracle_tags` from a fresh Oracle Tags pull. Returns
     /// `(catalog_rows, correlation_rows)`.
@@ -121,6 +191,13 @@ pub trait CardRepository: Clone + Send + Sync + 'static {
         tags: &[ScryfallOracleTag],
     ) -> impl Future<Output = anyhow::Result<(u32, u32)>> + Send;
 
+    /// Upserts Scryfall's set catalog (name, parent, symbol URI per code)
+    /// into `scryfall_sets`. Returns the number of sets written.
+    fn sync_set_catalog(
+        &self,
+        sets: &[ScryfallSet],
+    ) -> impl Future<Output = anyhow::Result<u32>> + Send;
+
     /// Rebuilds the `card_profiles.oracle_tags` JSONB projection from
     /// `card_oracle_tags` (aggregated per `oracle_id`, fanned to every printing).
     fn refresh_card_oracle_tags(&self) -> impl Future<Output = anyhow::Result<()>> + Send;
@@ -141,6 +218,13 @@ pub trait CardRepository: Clone + Send + Sync + 'static {
         request: &ScryfallDataIds,
     ) -> impl Future<Output = Result<Vec<ScryfallData>, GetScryfallDataError>> + Send;
 
+    /// Returns which of `ids` exist in `scryfall_data`, so bulk operations
+    /// can report unknown ids up front.
+    fn existing_ids(
+        &self,
+        ids: &[uuid::Uuid],
+    ) -> impl Future<Output = Result<HashSet<uuid::Uuid>, GetScryfallDataError>> + Send;
+
     /// Searches for Scryfall data matching filter criteria.
     fn search_scryfall_data(
         &self,
@@ -161,6 +245,13 @@ pub trait CardRepository: Clone + Send + Sync + 'static {
         context: DeckServeContext<'_>,
     ) -> impl Future<Output = Result<Vec<ScryfallData>, SearchScryfallDataError>> + Send;
 
+    /// `search_scryfall_data` with the [`CardSummary`] projection: same
+    /// filtering, ordering, and paging, selecting only the summary columns.
+    fn search_card_summaries(
+        &self,
+        request: &CardQuery,
+    ) -> impl Future<Output = Result<Vec<CardSummary>, SearchScryfallDataError>> + Send;
+
     /// Retrieves complete card by Scryfall ID.
     fn get_card(
         &self,
@@ -179,12 +270,52 @@ pub trait CardRepository: Clone + Send + Sync + 'static {
         oracle_id: uuid::Uuid,
     ) -> impl Future<Output = Result<Vec<Card>, GetCardError>> + Send;
 
+    /// Retrieves a complete card plus a summary of every other printing of
+    /// its oracle card (oldest release first), for the card detail screen.
+    fn get_card_with_printings(
+        &self,
+        request: &GetScryfallData,
+    ) -> impl Future<Output = Result<CardWithPrintings, GetCardError>> + Send;
+
+    /// Retrieves a card's stored `scryfall_data` row (and its `card_profiles`
+    /// row, if any) as raw JSON, without domain conversion.
+    fn get_raw_card(
+        &self,
+        request: &GetScryfallData,
+    ) -> impl Future<Output = Result<RawCard, GetScryfallDataError>> + Send;
+
     /// Searches for complete cards matching filter criteria.
     fn search_cards(
         &self,
         request: &CardQuery,
     ) -> impl Future<Output = Result<Vec<Card>, SearchCardsError>> + Send;
 
+    /// Counts the cards matching `request`'s criteria per identity color, plus
+    /// colorless and multicolor. Pagination and sort are ignored.
+    fn color_distribution(
+        &self,
+        request: &CardQuery,
+    ) -> impl Future<Output = Result<ColorDistribution, SearchCardsError>> + Send;
+
+    /// The `limit` best-ranked staples playable under `color_identity` (every
+    /// card whose identity is a subset of it), best `edhrec_rank` first. Reads
+    /// the precomputed `format_staples` view; `limit` is capped at the view's
+    /// per-identity depth.
+    fn format_staples(
+        &self,
+        color_identity: &Colors,
+        limit: u32,
+    ) -> impl Future<Output = Result<Vec<Card>, SearchCardsError>> + Send;
+
+    /// [`format_staples`](Self::format_staples) minus every card (by
+    /// oracle_id) in any of `user_id`'s decks.
+    fn undiscovered_staples(
+        &self,
+        user_id: uuid::Uuid,
+        color_identity: &Colors,
+        limit: u32,
+    ) -> impl Future<Output = Result<Vec<Card>, SearchCardsError>> + Send;
+
     /// Retrieves all distinct artist names from card database.
     fn get_artists(&self) -> impl Future<Output = Result<Vec<String>, GetArtistsError>> + Send;
 
@@ -208,6 +339,23 @@ pub trait CardRepository: Clone + Send + Sync + 'static {
     /// Retrieves all distinct set codes from card database.
     fn get_sets(&self) -> impl Future<Output = Result<Vec<String>, GetSetsError>> + Send;
 
+    /// Resolves a set code (case-insensitive) to its symbol icon, from the
+    /// synced set catalog. `NotFound` when neither the catalog nor any synced
+    /// card carries that code.
+    fn get_set_icon(
+        &self,
+        set_code: &str,
+    ) -> impl Future<Output = Result<SetIcon, GetSetIconError>> + Send;
+
+    /// Counts a set's distinct cards (by oracle id) and how many of them the
+    /// user's collection holds. `NotFound` when no synced card carries that
+    /// set code.
+    fn get_set_completion(
+        &self,
+        user_id: uuid::Uuid,
+        set_code: &str,
+    ) -> impl Future<Output = Result<SetCompletion, GetSetCompletionError>> + Send;
+
     /// Retrieves all distinct language codes from card database.
     fn get_languages(&self) -> impl Future<Output = Result<Vec<String>, GetLanguagesError>> + Send;
 
@@ -251,6 +399,12 @@ pub trait CardRepository: Clone + Send + Sync + 'static {
         &self,
     ) -> impl Future<Output = anyhow::Result<Option<DateTime<Utc>>>> + Send;
 
+    /// Retrieves timestamp of the last full Scryfall sync. `None` if no full
+    /// sync has been recorded.
+    fn get_last_full_sync_date(
+        &self,
+    ) -> impl Future<Output = anyhow::Result<Option<DateTime<Utc>>>> + Send;
+
     /// Finds cards by exact name match (case-insensitive).
     ///
     /// Returns one card per unique card name, using the latest printing.
@@ -261,6 +415,25 @@ pub trait CardRepository: Clone + Send + Sync + 'static {
         names: &[String],
     ) -> impl Future<Output = Result<Vec<Card>, SearchCardsError>> + Send;
 
+    /// Finds one printing per card whose name (or double-faced front face)
+    /// matches one of `names`, case-insensitively, across every printing.
+    ///
+    /// Printings are ranked by the search builder's default preferences
+    /// (its language, then non-digital, non-oversized), then non-promo, then
+    /// most recent. Names with no matching card are silently omitted.
+    fn get_cards_by_names(
+        &self,
+        names: &[String],
+    ) -> impl Future<Output = Result<Vec<Card>, SearchCardsError>> + Send;
+
+    /// Finds printings by `(set code, collector number)` pairs (set code
+    /// case-insensitive). Pairs with no printing are silently omitted.
+    /// Used by decklist import when a line names its printing.
+    fn find_cards_by_printings(
+        &self,
+        printings: &[(String, String)],
+    ) -> impl Future<Output = Result<Vec<Card>, SearchCardsError>> + Send;
+
     /// `search_cards` with deck awareness: rows whose oracle_id is in
     /// `exclude_oracle_ids` are omitted, the deck's suppression set (skipped
     /// / removed cards) is filtered out when `deck_id` is given, and when
@@ -329,6 +502,68 @@ pub trait CardRepository: Clone + Send + Sync + 'static {
     fn get_card_ids_with_oracle_text(
         &self,
     ) -> impl Future<Output = Result<Vec<uuid::Uuid>, anyhow::Error>> + Send;
+
+    // =============
+    //  considering
+    // =============
+
+    /// Adds a card to the user's considering list; a no-op if already there.
+    fn add_to_considering(
+        &self,
+        user_id: uuid::Uuid,
+        scryfall_data_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<(), ConsideringError>> + Send;
+
+    /// Returns the user's considering list, most recently added first.
+    fn list_considering(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<Card>, ConsideringError>> + Send;
+
+    /// Removes a card from the user's considering list; a no-op if absent.
+    fn remove_from_considering(
+        &self,
+        user_id: uuid::Uuid,
+        scryfall_data_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<(), ConsideringError>> + Send;
+
+    // ===========
+    //  favorites
+    // ===========
+
+    /// Applies already-deduplicated favorite changes in one transaction:
+    /// `true` favorites the card (no-op if already), `false` unfavorites it.
+    /// `CardNotFound` (and nothing applied) if any favorited card is unknown.
+    fn set_favorites(
+        &self,
+        user_id: uuid::Uuid,
+        changes: &[(uuid::Uuid, bool)],
+    ) -> impl Future<Output = Result<(), FavoritesError>> + Send;
+
+    /// Returns the user's favorite cards, most recently favorited first.
+    fn list_favorites(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<Card>, FavoritesError>> + Send;
+
+    // ============
+    //  collection
+    // ============
+
+    /// Maps each id that names a card (oracle id) or a printing (Scryfall
+    /// id) to its oracle id. Unknown ids are left out.
+    fn resolve_oracle_ids(
+        &self,
+        ids: &[uuid::Uuid],
+    ) -> impl Future<Output = Result<HashMap<uuid::Uuid, uuid::Uuid>, ImportCollectionError>> + Send;
+
+    /// Sets the user's owned quantity of each `(oracle_id, quantity)` in one
+    /// statement, replacing what was there. Quantities must be positive.
+    fn set_owned_cards(
+        &self,
+        user_id: uuid::Uuid,
+        cards: &[(uuid::Uuid, i32)],
+    ) -> impl Future<Output = Result<(), ImportCollectionError>> + Send;
 }
 
 /// Service port for MTG card business logic.
@@ -342,9 +577,9 @@ pub trait CardRepository: Clone + Send + Sync + 'static {
 /// # Sync Strategy
 ///
 /// `scryfall_sync()` is the primary sync operation:
-/// 1. Download bulk JSON from Scryfall (~150MB)
-/// 2. Parse JSON into ScryfallData structs
-/// 3. Batch delta upsert (only update changed cards)
+/// 1. Download bulk JSON from Scryfall to a temp file
+/// 2. Stream it into ScryfallData structs, one batch at a time
+/// 3. Batch delta upsert each batch (only update changed cards)
 /// 4. Record metrics (duration, cards processed)
 ///
 /// # Implementation
@@ -361,12 +596,32 @@ pub trait CardService: Clone + Send + Sync + 'static {
         scryfall_data: ScryfallData,
     ) -> impl Future<Output = Result<Card, CreateCardError>> + Send;
 
-    /// Syncs database with Scryfall bulk data.
+    /// Syncs database with Scryfall bulk data. A full sync upserts every
+    /// card; a partial sync only those newer than the stored version.
     fn scryfall_sync(
         &self,
         bulk_endpoint: BulkEndpoint,
+        sync_kind: SyncKind,
+    ) -> impl Future<Output = anyhow::Result<ZerviceMetrics>> + Send;
+
+    /// Syncs only the printings in `set_codes` (e.g. `["mh3"]`), fetched via
+    /// Scryfall's card search instead of a bulk file and delta upserted.
+    /// Meant for local development and fixtures: the run isn't recorded, so
+    /// it never counts toward the sync schedule or readiness.
+    fn scryfall_sync_sets(
+        &self,
+        set_codes: &[String],
     ) -> impl Future<Output = anyhow::Result<ZerviceMetrics>> + Send;
 
+    /// Dry-parses a downloaded bulk card file without touching the database:
+    /// streams every record through the sync's deserializer and reports
+    /// parsed/failed counts with the first error per failure category.
+    /// Catches Scryfall schema drift before a real sync.
+    fn validate_bulk(
+        &self,
+        path: &Path,
+    ) -> impl Future<Output = anyhow::Result<BulkValidationReport>> + Send;
+
     /// Derives `card_roles` from Oracle Tags (18 subtrees + Tokens via
     /// `all_parts`), then merges the 4 heuristic stragglers (Pump/Stax/Protection/
     /// GraveyardHate) via `oracle_tag_gaps`. Returns `(otag_rows_written, gap_merges)`.
@@ -388,10 +643,18 @@ pub trait CardService: Clone + Send + Sync + 'static {
     fn refresh_otag_context_signal_rollup(&self)
     -> impl Future<Output = anyhow::Result<()>> + Send;
 
+    /// Refreshes the format_staples materialized view (nightly, zervice, after
+    /// latest_cards).
+    fn refresh_format_staples(&self) -> impl Future<Output = anyhow::Result<()>> + Send;
+
     /// Syncs the oracle_tag catalog + card correlation from Scryfall's Oracle
     /// Tags bulk file (nightly, zervice). Returns `(catalog_rows, correlation_rows)`.
     fn sync_oracle_tags(&self) -> impl Future<Output = anyhow::Result<(u32, u32)>> + Send;
 
+    /// Syncs the set catalog (names, parents, symbol URIs) from Scryfall's
+    /// `/sets` (nightly, zervice). Returns the number of sets written.
+    fn sync_set_catalog(&self) -> impl Future<Output = anyhow::Result<u32>> + Send;
+
     /// Rebuilds the `card_profiles.oracle_tags` projection (nightly, zervice).
     fn refresh_card_oracle_tags(&self) -> impl Future<Output = anyhow::Result<()>> + Send;
 
@@ -421,12 +684,54 @@ pub trait CardService: Clone + Send + Sync + 'static {
         oracle_id: uuid::Uuid,
     ) -> impl Future<Output = Result<Vec<Card>, GetCardError>> + Send;
 
+    /// Retrieves a complete card plus a summary of every other printing of
+    /// its oracle card (oldest release first), for the card detail screen.
+    fn get_card_with_printings(
+        &self,
+        request: &GetScryfallData,
+    ) -> impl Future<Output = Result<CardWithPrintings, GetCardError>> + Send;
+
+    /// Retrieves a card's stored rows as raw JSON (admin inspection).
+    fn get_raw_card(
+        &self,
+        request: &GetScryfallData,
+    ) -> impl Future<Output = Result<RawCard, GetScryfallDataError>> + Send;
+
     /// Searches for complete cards matching filter criteria.
     fn search_cards(
         &self,
         request: &CardQuery,
     ) -> impl Future<Output = Result<Vec<Card>, SearchCardsError>> + Send;
 
+    /// Searches for cards matching filter criteria, returning only the
+    /// summary fields list and swipe views render.
+    fn search_card_summaries(
+        &self,
+        request: &CardQuery,
+    ) -> impl Future<Output = Result<Vec<CardSummary>, SearchCardsError>> + Send;
+
+    /// Per-color card counts for a search (set-overview charts).
+    fn color_distribution(
+        &self,
+        request: &CardQuery,
+    ) -> impl Future<Output = Result<ColorDistribution, SearchCardsError>> + Send;
+
+    /// Top commander staples playable under a color identity (recommenders).
+    fn format_staples(
+        &self,
+        color_identity: &Colors,
+        limit: u32,
+    ) -> impl Future<Output = Result<Vec<Card>, SearchCardsError>> + Send;
+
+    /// Popular commander-legal cards playable under `color_identity` that
+    /// appear in none of the user's decks (recommendations screen).
+    fn undiscovered_staples(
+        &self,
+        user_id: uuid::Uuid,
+        color_identity: &Colors,
+        limit: u32,
+    ) -> impl Future<Output = Result<Vec<Card>, SearchCardsError>> + Send;
+
     /// Searches for commanders (context/archive/commander_select_ordering.md):
     /// popularity-ordered, banded + wildcarded per user per day, token-free.
     fn search_commanders(
@@ -458,6 +763,19 @@ pub trait CardService: Clone + Send + Sync + 'static {
     /// Retrieves all distinct set codes from card database.
     fn get_sets(&self) -> impl Future<Output = Result<Vec<String>, GetSetsError>> + Send;
 
+    /// Returns the symbol SVG URI (plus rarity color hints) for a set code.
+    fn set_icon_uri(
+        &self,
+        set_code: &str,
+    ) -> impl Future<Output = Result<SetIcon, GetSetIconError>> + Send;
+
+    /// Returns owned vs total distinct cards of a set for the user.
+    fn set_completion(
+        &self,
+        user_id: uuid::Uuid,
+        set_code: &str,
+    ) -> impl Future<Output = Result<SetCompletion, GetSetCompletionError>> + Send;
+
     /// Retrieves all distinct language codes from card database.
     fn get_languages(&self) -> impl Future<Output = Result<Vec<String>, GetLanguagesError>> + Send;
 
@@ -501,6 +819,13 @@ pub trait CardService: Clone + Send + Sync + 'static {
         &self,
     ) -> impl Future<Output = anyhow::Result<Option<DateTime<Utc>>>> + Send;
 
+    /// The sync `schedule` says is due now, from the recorded sync history.
+    /// `None` when the last syncs are recent enough.
+    fn due_sync(
+        &self,
+        schedule: &SyncSchedule,
+    ) -> impl Future<Output = anyhow::Result<Option<SyncKind>>> + Send;
+
     /// Finds cards by exact name match (case-insensitive).
     ///
     /// Returns one card per unique card name, using the latest printing.
@@ -510,6 +835,75 @@ pub trait CardService: Clone + Send + Sync + 'static {
         &self,
         names: &[String],
     ) -> impl Future<Output = Result<Vec<Card>, SearchCardsError>> + Send;
+
+    /// Resolves many card names in one query, pairing each requested name
+    /// (in order, as given) with its preferred printing, or `None` when no
+    /// card has that exact name.
+    ///
+    /// Matching is case-insensitive and whole-name, so "Island" never
+    /// resolves to "Island Sanctuary"; a double-faced card also answers to
+    /// its front face. Used by deck import and multi-card adds.
+    fn get_cards_by_names(
+        &self,
+        names: &[String],
+    ) -> impl Future<Output = Result<NameLookup, SearchCardsError>> + Send;
+
+    // =============
+    //  considering
+    // =============
+
+    /// Adds a card to the user's cross-deck considering list (the up-swipe
+    /// wishlist). Deduplicated per card: re-adding is a no-op.
+    fn add_to_considering(
+        &self,
+        user_id: uuid::Uuid,
+        scryfall_data_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<(), ConsideringError>> + Send;
+
+    /// Returns the user's considering list, most recently added first.
+    fn list_considering(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<Card>, ConsideringError>> + Send;
+
+    /// Removes a card from the user's considering list; a no-op if absent.
+    fn remove_from_considering(
+        &self,
+        user_id: uuid::Uuid,
+        scryfall_data_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<(), ConsideringError>> + Send;
+
+    // ===========
+    //  favorites
+    // ===========
+
+    /// Applies a batch of favorite/unfavorite toggles atomically. A card that
+    /// appears more than once takes its last entry.
+    fn set_favorites(
+        &self,
+        user_id: uuid::Uuid,
+        changes: Vec<(uuid::Uuid, bool)>,
+    ) -> impl Future<Output = Result<(), FavoritesError>> + Send;
+
+    /// Returns the user's favorite cards, most recently favorited first.
+    fn list_favorites(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<Card>, FavoritesError>> + Send;
+
+    // ============
+    //  collection
+    // ============
+
+    /// Reads a newline-delimited JSON collection export and sets the user's
+    /// owned quantity of every card in it, resolving ids in batches. Copies
+    /// of several printings of one card add up. Blank lines are skipped;
+    /// malformed lines and unknown ids are reported, not fatal.
+    fn import_collection_ndjson(
+        &self,
+        user_id: uuid::Uuid,
+        reader: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> impl Future<Output = Result<CollectionImportReport, ImportCollectionError>> + Send;
 }
 
 /// Object-safe wrapper used by `AppState` so the concrete service type stays
@@ -525,8 +919,21 @@ pub trait ErasedCardService: Send + Sync + 'static {
     fn scryfall_sync<'a>(
         &'a self,
         bulk_endpoint: BulkEndpoint,
+        sync_kind: SyncKind,
     ) -> BoxFuture<'a, anyhow::Result<ZerviceMetrics>>;
 
+    /// See [`CardService::scryfall_sync_sets`].
+    fn scryfall_sync_sets<'a>(
+        &'a self,
+        set_codes: &'a [String],
+    ) -> BoxFuture<'a, anyhow::Result<ZerviceMetrics>>;
+
+    /// See [`CardService::validate_bulk`].
+    fn validate_bulk<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxFuture<'a, anyhow::Result<BulkValidationReport>>;
+
     /// See [`CardService::clear_all_categories`].
     fn clear_all_categories<'a>(&'a self) -> BoxFuture<'a, anyhow::Result<()>>;
 
@@ -539,6 +946,9 @@ pub trait ErasedCardService: Send + Sync + 'static {
     /// See [`CardService::refresh_otag_context_signal_rollup`].
     fn refresh_otag_context_signal_rollup<'a>(&'a self) -> BoxFuture<'a, anyhow::Result<()>>;
 
+    /// See [`CardService::refresh_format_staples`].
+    fn refresh_format_staples<'a>(&'a self) -> BoxFuture<'a, anyhow::Result<()>>;
+
     /// See [`CardService::get_card`].
     fn get_card<'a>(
         &'a self,
@@ -557,12 +967,51 @@ pub trait ErasedCardService: Send + Sync + 'static {
         oracle_id: uuid::Uuid,
     ) -> BoxFuture<'a, Result<Vec<Card>, GetCardError>>;
 
+    /// See [`CardService::get_card_with_printings`].
+    fn get_card_with_printings<'a>(
+        &'a self,
+        request: &'a GetScryfallData,
+    ) -> BoxFuture<'a, Result<CardWithPrintings, GetCardError>>;
+
+    /// See [`CardService::get_raw_card`].
+    fn get_raw_card<'a>(
+        &'a self,
+        request: &'a GetScryfallData,
+    ) -> BoxFuture<'a, Result<RawCard, GetScryfallDataError>>;
+
     /// See [`CardService::search_cards`].
     fn search_cards<'a>(
         &'a self,
         request: &'a CardQuery,
     ) -> BoxFuture<'a, Result<Vec<Card>, SearchCardsError>>;
 
+    /// See [`CardService::search_card_summaries`].
+    fn search_card_summaries<'a>(
+        &'a self,
+        request: &'a CardQuery,
+    ) -> BoxFuture<'a, Result<Vec<CardSummary>, SearchCardsError>>;
+
+    /// See [`CardService::color_distribution`].
+    fn color_distribution<'a>(
+        &'a self,
+        request: &'a CardQuery,
+    ) -> BoxFuture<'a, Result<ColorDistribution, SearchCardsError>>;
+
+    /// See [`CardService::format_staples`].
+    fn format_staples<'a>(
+        &'a self,
+        color_identity: &'a Colors,
+        limit: u32,
+    ) -> BoxFuture<'a, Result<Vec<Card>, SearchCardsError>>;
+
+    /// See [`CardService::undiscovered_staples`].
+    fn undiscovered_staples<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+        color_identity: &'a Colors,
+        limit: u32,
+    ) -> BoxFuture<'a, Result<Vec<Card>, SearchCardsError>>;
+
     /// See [`CardService::search_commanders`].
     fn search_commanders<'a>(
         &'a self,
@@ -588,6 +1037,19 @@ pub trait ErasedCardService: Send + Sync + 'static {
     /// See [`CardService::get_sets`].
     fn get_sets<'a>(&'a self) -> BoxFuture<'a, Result<Vec<String>, GetSetsError>>;
 
+    /// See [`CardService::set_icon_uri`].
+    fn set_icon_uri<'a>(
+        &'a self,
+        set_code: &'a str,
+    ) -> BoxFuture<'a, Result<SetIcon, GetSetIconError>>;
+
+    /// See [`CardService::set_completion`].
+    fn set_completion<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+        set_code: &'a str,
+    ) -> BoxFuture<'a, Result<SetCompletion, GetSetCompletionError>>;
+
     /// See [`CardService::get_languages`].
     fn get_languages<'a>(&'a self) -> BoxFuture<'a, Result<Vec<String>, GetLanguagesError>>;
 
@@ -618,11 +1080,63 @@ pub trait ErasedCardService: Send + Sync + 'static {
     /// See [`CardService::get_last_sync_date`].
     fn get_last_sync_date<'a>(&'a self) -> BoxFuture<'a, anyhow::Result<Option<DateTime<Utc>>>>;
 
+    /// See [`CardService::due_sync`].
+    fn due_sync<'a>(
+        &'a self,
+        schedule: &'a SyncSchedule,
+    ) -> BoxFuture<'a, anyhow::Result<Option<SyncKind>>>;
+
     /// See [`CardService::find_cards_by_exact_names`].
     fn find_cards_by_exact_names<'a>(
         &'a self,
         names: &'a [String],
     ) -> BoxFuture<'a, Result<Vec<Card>, SearchCardsError>>;
+
+    /// See [`CardService::get_cards_by_names`].
+    fn get_cards_by_names<'a>(
+        &'a self,
+        names: &'a [String],
+    ) -> BoxFuture<'a, Result<NameLookup, SearchCardsError>>;
+
+    /// See [`CardService::add_to_considering`].
+    fn add_to_considering<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+        scryfall_data_id: uuid::Uuid,
+    ) -> BoxFuture<'a, Result<(), ConsideringError>>;
+
+    /// See [`CardService::list_considering`].
+    fn list_considering<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+    ) -> BoxFuture<'a, Result<Vec<Card>, ConsideringError>>;
+
+    /// See [`CardService::remove_from_considering`].
+    fn remove_from_considering<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+        scryfall_data_id: uuid::Uuid,
+    ) -> BoxFuture<'a, Result<(), ConsideringError>>;
+
+    /// See [`CardService::set_favorites`].
+    fn set_favorites<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+        changes: Vec<(uuid::Uuid, bool)>,
+    ) -> BoxFuture<'a, Result<(), FavoritesError>>;
+
+    /// See [`CardService::list_favorites`].
+    fn list_favorites<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+    ) -> BoxFuture<'a, Result<Vec<Card>, FavoritesError>>;
+
+    /// See [`CardService::import_collection_ndjson`].
+    fn import_collection_ndjson<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+        reader: &'a mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> BoxFuture<'a, Result<CollectionImportReport, ImportCollectionError>>;
 }
 
 impl<T> ErasedCardService for T
@@ -639,8 +1153,23 @@ where
     fn scryfall_sync<'a>(
         &'a self,
         bulk_endpoint: BulkEndpoint,
+        sync_kind: SyncKind,
+    ) -> BoxFuture<'a, anyhow::Result<ZerviceMetrics>> {
+        Box::pin(CardService::scryfall_sync(self, bulk_endpoint, sync_kind))
+    }
+
+    fn scryfall_sync_sets<'a>(
+        &'a self,
+        set_codes: &'a [String],
     ) -> BoxFuture<'a, anyhow::Result<ZerviceMetrics>> {
-        Box::pin(CardService::scryfall_sync(self, bulk_endpoint))
+        Box::pin(CardService::scryfall_sync_sets(self, set_codes))
+    }
+
+    fn validate_bulk<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> BoxFuture<'a, anyhow::Result<BulkValidationReport>> {
+        Box::pin(CardService::validate_bulk(self, path))
     }
 
     fn clear_all_categories<'a>(&'a self) -> BoxFuture<'a, anyhow::Result<()>> {
@@ -655,6 +1184,10 @@ where
         Box::pin(CardService::refresh_otag_context_signal_rollup(self))
     }
 
+    fn refresh_format_staples<'a>(&'a self) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(CardService::refresh_format_staples(self))
+    }
+
     fn refresh_latest_cards<'a>(&'a self) -> BoxFuture<'a, anyhow::Result<()>> {
         Box::pin(CardService::refresh_latest_cards(self))
     }
@@ -680,6 +1213,20 @@ where
         Box::pin(CardService::get_printings(self, oracle_id))
     }
 
+    fn get_card_with_printings<'a>(
+        &'a self,
+        request: &'a GetScryfallData,
+    ) -> BoxFuture<'a, Result<CardWithPrintings, GetCardError>> {
+        Box::pin(CardService::get_card_with_printings(self, request))
+    }
+
+    fn get_raw_card<'a>(
+        &'a self,
+        request: &'a GetScryfallData,
+    ) -> BoxFuture<'a, Result<RawCard, GetScryfallDataError>> {
+        Box::pin(CardService::get_raw_card(self, request))
+    }
+
     fn search_cards<'a>(
         &'a self,
         request: &'a CardQuery,
@@ -687,6 +1234,42 @@ where
         Box::pin(CardService::search_cards(self, request))
     }
 
+    fn search_card_summaries<'a>(
+        &'a self,
+        request: &'a CardQuery,
+    ) -> BoxFuture<'a, Result<Vec<CardSummary>, SearchCardsError>> {
+        Box::pin(CardService::search_card_summaries(self, request))
+    }
+
+    fn color_distribution<'a>(
+        &'a self,
+        request: &'a CardQuery,
+    ) -> BoxFuture<'a, Result<ColorDistribution, SearchCardsError>> {
+        Box::pin(CardService::color_distribution(self, request))
+    }
+
+    fn format_staples<'a>(
+        &'a self,
+        color_identity: &'a Colors,
+        limit: u32,
+    ) -> BoxFuture<'a, Result<Vec<Card>, SearchCardsError>> {
+        Box::pin(CardService::format_staples(self, color_identity, limit))
+    }
+
+    fn undiscovered_staples<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+        color_identity: &'a Colors,
+        limit: u32,
+    ) -> BoxFuture<'a, Result<Vec<Card>, SearchCardsError>> {
+        Box::pin(CardService::undiscovered_staples(
+            self,
+            user_id,
+            color_identity,
+            limit,
+        ))
+    }
+
     fn search_commanders<'a>(
         &'a self,
         request: &'a CardQuery,
@@ -719,6 +1302,21 @@ where
         Box::pin(CardService::get_sets(self))
     }
 
+    fn set_icon_uri<'a>(
+        &'a self,
+        set_code: &'a str,
+    ) -> BoxFuture<'a, Result<SetIcon, GetSetIconError>> {
+        Box::pin(CardService::set_icon_uri(self, set_code))
+    }
+
+    fn set_completion<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+        set_code: &'a str,
+    ) -> BoxFuture<'a, Result<SetCompletion, GetSetCompletionError>> {
+        Box::pin(CardService::set_completion(self, user_id, set_code))
+    }
+
     fn get_languages<'a>(&'a self) -> BoxFuture<'a, Result<Vec<String>, GetLanguagesError>> {
         Box::pin(CardService::get_languages(self))
     }
@@ -759,10 +1357,78 @@ where
         Box::pin(CardService::get_last_sync_date(self))
     }
 
+    fn due_sync<'a>(
+        &'a self,
+        schedule: &'a SyncSchedule,
+    ) -> BoxFuture<'a, anyhow::Result<Option<SyncKind>>> {
+        Box::pin(CardService::due_sync(self, schedule))
+    }
+
     fn find_cards_by_exact_names<'a>(
         &'a self,
         names: &'a [String],
     ) -> BoxFuture<'a, Result<Vec<Card>, SearchCardsError>> {
         Box::pin(CardService::find_cards_by_exact_names(self, names))
     }
+
+    fn get_cards_by_names<'a>(
+        &'a self,
+        names: &'a [String],
+    ) -> BoxFuture<'a, Result<NameLookup, SearchCardsError>> {
+        Box::pin(CardService::get_cards_by_names(self, names))
+    }
+
+    fn add_to_considering<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+        scryfall_data_id: uuid::Uuid,
+    ) -> BoxFuture<'a, Result<(), ConsideringError>> {
+        Box::pin(CardService::add_to_considering(
+            self,
+            user_id,
+            scryfall_data_id,
+        ))
+    }
+
+    fn list_considering<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+    ) -> BoxFuture<'a, Result<Vec<Card>, ConsideringError>> {
+        Box::pin(CardService::list_considering(self, user_id))
+    }
+
+    fn remove_from_considering<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+        scryfall_data_id: uuid::Uuid,
+    ) -> BoxFuture<'a, Result<(), ConsideringError>> {
+        Box::pin(CardService::remove_from_considering(
+            self,
+            user_id,
+            scryfall_data_id,
+        ))
+    }
+
+    fn set_favorites<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+        changes: Vec<(uuid::Uuid, bool)>,
+    ) -> BoxFuture<'a, Result<(), FavoritesError>> {
+        Box::pin(CardService::set_favorites(self, user_id, changes))
+    }
+
+    fn list_favorites<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+    ) -> BoxFuture<'a, Result<Vec<Card>, FavoritesError>> {
+        Box::pin(CardService::list_favorites(self, user_id))
+    }
+
+    fn import_collection_ndjson<'a>(
+        &'a self,
+        user_id: uuid::Uuid,
+        reader: &'a mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> BoxFuture<'a, Result<CollectionImportReport, ImportCollectionError>> {
+        Box::pin(CardService::import_collection_ndjson(self, user_id, reader))
+    }
 }
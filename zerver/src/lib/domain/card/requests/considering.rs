@@ -0,0 +1,18 @@
+//! Per-user "considering" list operations.
+//!
+//! A cross-deck wishlist, separate from deck maybeboards: the swipe UI adds a
+//! card with an up-swipe and the user reviews the list later. Entries are
+//! deduplicated per card, so adding twice is a no-op.
+
+use thiserror::Error;
+
+/// Errors that can occur when reading or changing the considering list.
+#[derive(Debug, Error)]
+pub enum ConsideringError {
+    /// The card being added does not exist.
+    #[error("card not found")]
+    CardNotFound,
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+}
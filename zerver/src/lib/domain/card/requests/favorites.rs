@@ -0,0 +1,50 @@
+//! Favorite card operations.
+//!
+//! The swipe UI can fire many favorite toggles in quick succession, so writes
+//! are batched: a list of `(card, favorite)` changes applied atomically.
+//! Conflicting entries for one card collapse to the last one sent.
+
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors that can occur when reading or changing favorites.
+#[derive(Debug, Error)]
+pub enum FavoritesError {
+    /// A card being favorited does not exist; nothing in the batch applied.
+    #[error("card not found")]
+    CardNotFound,
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+}
+
+/// Collapses a batch of favorite changes to one per card, last entry winning,
+/// in order of each card's first appearance.
+pub fn dedupe_favorite_changes(changes: &[(Uuid, bool)]) -> Vec<(Uuid, bool)> {
+    let mut deduped: Vec<(Uuid, bool)> = Vec::with_capacity(changes.len());
+    for &(id, favorite) in changes {
+        match deduped.iter_mut().find(|(seen, _)| *seen == id) {
+            Some(entry) => entry.1 = favorite,
+            None => deduped.push((id, favorite)),
+        }
+    }
+    deduped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn last_change_for_a_card_wins() {
+        let a = Uuid::from_u128(1);
+        let b = Uuid::from_u128(2);
+        let deduped = dedupe_favorite_changes(&[(a, true), (b, true), (a, false)]);
+        assert_eq!(deduped, vec![(a, false), (b, true)]);
+    }
+
+    #[test]
+    fn empty_batch_stays_empty() {
+        assert!(dedupe_favorite_changes(&[]).is_empty());
+    }
+}
@@ -0,0 +1,16 @@
+//! Get set completion operation.
+//!
+//! Compares a user's collection against a set's card list, by distinct card.
+
+use thiserror::Error;
+
+/// Errors that can occur when computing a set's completion.
+#[derive(Debug, Error)]
+pub enum GetSetCompletionError {
+    /// No synced card belongs to a set with this code.
+    #[error("set not found")]
+    NotFound,
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+}
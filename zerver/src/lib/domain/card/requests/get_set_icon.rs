@@ -0,0 +1,17 @@
+//! Get set icon operation.
+//!
+//! Resolves a set code to its symbol SVG URI plus rarity color hints, so the
+//! frontend never derives set-symbol URIs itself.
+
+use thiserror::Error;
+
+/// Errors that can occur when resolving a set icon.
+#[derive(Debug, Error)]
+pub enum GetSetIconError {
+    /// Neither the set catalog nor any synced card has this set code.
+    #[error("set not found")]
+    NotFound,
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+}
@@ -0,0 +1,92 @@
+//! Collection import operations.
+//!
+//! Collection exports (Moxfield, Archidekt) arrive as newline-delimited JSON,
+//! one `{ "oracle_or_scryfall_id": ..., "quantity": ... }` object per line.
+//! The id may name the card (oracle id) or any one printing (Scryfall id);
+//! both count toward the same oracle card. Bad lines and unknown ids are
+//! reported rather than failing the import.
+
+use serde::Deserialize;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Lines read before their ids are resolved in one query.
+pub const COLLECTION_IMPORT_BATCH_SIZE: usize = 1_000;
+
+/// Errors that stop a collection import.
+#[derive(Debug, Error)]
+pub enum ImportCollectionError {
+    /// The upload couldn't be read.
+    #[error("failed to read collection: {0}")]
+    Read(std::io::Error),
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+}
+
+/// A line that isn't a well-formed entry with a positive quantity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("invalid collection line")]
+pub struct InvalidCollectionLine;
+
+/// One NDJSON line of a collection export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct CollectionLine {
+    /// Oracle id or Scryfall (printing) id.
+    #[serde(alias = "oracle_id", alias = "scryfall_id", alias = "id")]
+    pub oracle_or_scryfall_id: Uuid,
+    /// Copies owned; must be positive.
+    pub quantity: i32,
+}
+
+impl CollectionLine {
+    /// Parses one line; `Ok(None)` for a blank one.
+    pub fn parse(line: &str) -> Result<Option<Self>, InvalidCollectionLine> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(None);
+        }
+        match serde_json::from_str::<Self>(line) {
+            Ok(entry) if entry.quantity > 0 => Ok(Some(entry)),
+            _ => Err(InvalidCollectionLine),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_entries_and_skips_blank_lines() {
+        let id = Uuid::from_u128(7);
+        assert_eq!(
+            CollectionLine::parse(&format!(
+                r#"{{"oracle_or_scryfall_id":"{id}","quantity":3}}"#
+            )),
+            Ok(Some(CollectionLine {
+                oracle_or_scryfall_id: id,
+                quantity: 3
+            }))
+        );
+        assert_eq!(
+            CollectionLine::parse(&format!(r#"{{"scryfall_id":"{id}","quantity":1}}"#))
+                .map(|l| l.map(|l| l.oracle_or_scryfall_id)),
+            Ok(Some(id))
+        );
+        assert_eq!(CollectionLine::parse("   "), Ok(None));
+    }
+
+    #[test]
+    fn rejects_malformed_lines_and_non_positive_quantities() {
+        let id = Uuid::from_u128(7);
+        assert!(CollectionLine::parse("not json").is_err());
+        assert!(CollectionLine::parse(r#"{"quantity":2}"#).is_err());
+        assert!(
+            CollectionLine::parse(&format!(
+                r#"{{"oracle_or_scryfall_id":"{id}","quantity":0}}"#
+            ))
+            .is_err()
+        );
+    }
+}
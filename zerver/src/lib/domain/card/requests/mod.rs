@@ -3,8 +3,12 @@
 //! This module contains all request/response types for card operations.
 //! Entities and value objects live in the sibling [`super::models`] module.
 
+/// Per-user "considering" list (cross-deck wishlist).
+pub mod considering;
 /// Card creation/upsert operations.
 pub mod create_card;
+/// Per-user favorite cards (batched toggles).
+pub mod favorites;
 /// Get distinct artist names from card database.
 pub mod get_artists;
 /// Get single/multiple cards operations.
@@ -23,5 +27,11 @@ pub mod get_oracle_tags;
 pub mod get_oracle_words;
 /// Get Scryfall data operations.
 pub mod get_scryfall_data;
+/// Compare a user's collection against a set's card list.
+pub mod get_set_completion;
+/// Resolve a set code to its symbol icon.
+pub mod get_set_icon;
 /// Get distinct set codes/names from card database.
 pub mod get_sets;
+/// Import a collection export (NDJSON).
+pub mod import_collection;
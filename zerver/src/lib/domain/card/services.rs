@@ -1,9 +1,21 @@
 use crate::{
     domain::card::{
-        models::{search_card::error::SearchCardsError, zervice_metrics::ZerviceMetrics},
-        ports::{CardRepository, CardService},
+        models::{
+            bulk_validation::BulkValidationReport,
+            filter_options_cache::{FilterOptionsCache, FilterOptionsCacheConfig},
+            raw_card::RawCard,
+            search_cache::{SearchCache, SearchCacheConfig},
+            search_card::error::SearchCardsError,
+            sync_checkpoint::SyncCheckpoint,
+            sync_progress::{SyncProgressReporter, SyncProgressTracker},
+            sync_schedule::{SyncKind, SyncSchedule},
+            zervice_metrics::ZerviceMetrics,
+        },
+        ports::{CardRepository, CardService, NameLookup},
         requests::{
+            considering::ConsideringError,
             create_card::CreateCardError,
+            favorites::{FavoritesError, dedupe_favorite_changes},
             get_artists::GetArtistsError,
             get_card::GetCardError,
             get_card_profile::{CardProfileIds, GetCardProfile, GetCardProfileError},
@@ -12,21 +24,41 @@ use crate::{
             get_languages::GetLanguagesError,
             get_oracle_tags::GetOracleTagsError,
             get_oracle_words::GetOracleWordsError,
-            get_scryfall_data::{GetScryfallData, ScryfallDataIds},
+            get_scryfall_data::{GetScryfallData, GetScryfallDataError, ScryfallDataIds},
+            get_set_completion::GetSetCompletionError,
+            get_set_icon::GetSetIconError,
             get_sets::GetSetsError,
+            import_collection::{
+                COLLECTION_IMPORT_BATCH_SIZE, CollectionLine, ImportCollectionError,
+            },
         },
     },
-    inbound::external::scryfall::bulk::BulkEndpoint,
+    inbound::external::scryfall::{
+        bulk::{BulkEndpoint, BulkParse, DownloadRetry, stream_bulk_file, validate_bulk_file},
+        planeswalker::{amass_sets, surveil_sets},
+    },
     outbound::sqlx::card::helpers::scryfall_data_fields::scryfall_data_field_count,
 };
+use anyhow::Context;
 use chrono::{DateTime, Utc};
+use std::{collections::HashMap, path::Path, sync::Arc};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
 use zwipe_core::domain::card::{
     Card,
     card_profile::CardProfile,
     card_role::{CardRole, classify_oracle_tag_gaps},
+    card_summary::CardSummary,
+    color_distribution::ColorDistribution,
     oracle_tag::OracleTag,
-    scryfall_data::ScryfallData,
+    printing_summary::CardWithPrintings,
+    scryfall_data::{ScryfallData, colors::Colors},
     search_card::card_filter::CardQuery,
+    set_completion::SetCompletion,
+    set_icon::SetIcon,
+};
+use zwipe_core::domain::deck::{
+    CollectionImportReport,
+    requests::import_deck_cards::{dfc_front_face, entry_front_face},
 };
 
 /// PostgreSQL parameter limit per query (~65k parameters).
@@ -34,7 +66,7 @@ use zwipe_core::domain::card::{
 /// Exceeding this limit causes "prepared statement contains too many parameters" errors.
 const POSTGRESQL_PARAMETER_HARD_LIMIT: usize = 65_535;
 
-/// Calculates optimal batch size for bulk card upserts.
+/// Calculates the default batch size for bulk card upserts.
 ///
 /// Determines how many cards can be upserted in a single query without exceeding
 /// PostgreSQL's parameter limit. Uses half the limit for performance.
@@ -43,10 +75,55 @@ const POSTGRESQL_PARAMETER_HARD_LIMIT: usize = 65_535;
 /// `batch_size = (POSTGRESQL_PARAM_LIMIT / 2) / scryfall_data_field_count()`
 ///
 /// With ~100 fields per card and 65k param limit, this yields ~327 cards per batch.
-fn batch_size() -> usize {
+pub fn default_sync_batch_size() -> usize {
     POSTGRESQL_PARAMETER_HARD_LIMIT / 2 / scryfall_data_field_count()
 }
 
+/// Refuses a sync batch size whose upsert would bind more parameters than
+/// PostgreSQL allows, naming the largest size that fits.
+pub fn check_sync_batch_size(batch_size: usize) -> anyhow::Result<()> {
+    let columns = scryfall_data_field_count();
+    let max = POSTGRESQL_PARAMETER_HARD_LIMIT / columns;
+    if batch_size == 0 || batch_size > max {
+        anyhow::bail!(
+            "sync batch size {batch_size} is out of range: {columns} columns per card \
+             allow 1 to {max} cards within PostgreSQL's {POSTGRESQL_PARAMETER_HARD_LIMIT} \
+             bind parameter limit"
+        );
+    }
+    Ok(())
+}
+
+/// Pairs each requested name with the card it names, in request order.
+///
+/// Cards are keyed by lowercased full name and, for double-faced cards, by
+/// front face; a full name wins over another card's front-face alias. An
+/// entry tries its full spelling first, then its pre-slash front face, so
+/// "A // B", "A / B" and "A" all land on the same card.
+fn pair_names_with_cards(names: &[String], cards: Vec<Card>) -> NameLookup {
+    let mut by_name: HashMap<String, Card> = HashMap::new();
+    for card in cards {
+        let full = card.scryfall_data.name.to_lowercase();
+        if let Some(front) = dfc_front_face(&full) {
+            by_name
+                .entry(front.to_string())
+                .or_insert_with(|| card.clone());
+        }
+        by_name.insert(full, card);
+    }
+    names
+        .iter()
+        .map(|name| {
+            let key = name.trim().to_lowercase();
+            let card = by_name
+                .get(&key)
+                .or_else(|| by_name.get(entry_front_face(&key)))
+                .cloned();
+            (name.clone(), card)
+        })
+        .collect()
+}
+
 /// Card service implementation handling MTG card operations and Scryfall synchronization.
 ///
 /// This service coordinates:
@@ -57,22 +134,232 @@ fn batch_size() -> usize {
 ///
 /// # Performance
 /// Bulk operations use batch processing to avoid PostgreSQL parameter limits.
-/// Batch size is auto-calculated based on ScryfallData field count (~327 cards/batch).
+/// Batch size defaults to [`default_sync_batch_size`] (~327 cards/batch); see
+/// [`Service::with_sync_batch_size`].
 #[derive(Debug, Clone)]
 pub struct Service<R>
 where
     R: CardRepository,
 {
     repo: R,
+    search_cache: Arc<SearchCache>,
+    filter_options: Arc<FilterOptionsCache>,
+    bulk_parse: BulkParse,
+    sync_concurrency: usize,
+    download_retry: DownloadRetry,
+    sync_progress: Option<SyncProgressReporter>,
+    sync_batch_size: usize,
 }
 
 impl<R> Service<R>
 where
     R: CardRepository,
 {
-    /// Creates a new card service with the provided repository.
+    /// Creates a new card service with the provided repository. Search
+    /// results and filter options are not cached; see
+    /// [`Self::with_search_cache`] and [`Self::with_filter_options_cache`].
     pub fn new(repo: R) -> Self {
-        Self { repo }
+        Self {
+            repo,
+            search_cache: Arc::new(SearchCache::new(SearchCacheConfig::disabled())),
+            filter_options: Arc::new(FilterOptionsCache::new(FilterOptionsCacheConfig::disabled())),
+            bulk_parse: BulkParse::default(),
+            sync_concurrency: 1,
+            download_retry: DownloadRetry::default(),
+            sync_progress: None,
+            sync_batch_size: default_sync_batch_size(),
+        }
+    }
+
+    /// Caches identical `search_cards` queries per `config`.
+    pub fn with_search_cache(mut self, config: SearchCacheConfig) -> Self {
+        self.search_cache = Arc::new(SearchCache::new(config));
+        self
+    }
+
+    /// Caches the filter option lists (artists, sets, ...) per `config`.
+    pub fn with_filter_options_cache(mut self, config: FilterOptionsCacheConfig) -> Self {
+        self.filter_options = Arc::new(FilterOptionsCache::new(config));
+        self
+    }
+
+    /// Reads sync bulk files per `bulk_parse` (streaming by default).
+    pub fn with_bulk_parse(mut self, bulk_parse: BulkParse) -> Self {
+        self.bulk_parse = bulk_parse;
+        self
+    }
+
+    /// Upserts up to `sync_concurrency` delta sync chunks at once (one at a
+    /// time by default). Zero is treated as one.
+    pub fn with_sync_concurrency(mut self, sync_concurrency: usize) -> Self {
+        self.sync_concurrency = sync_concurrency.max(1);
+        self
+    }
+
+    /// Retries transient bulk download failures per `download_retry`.
+    pub fn with_download_retry(mut self, download_retry: DownloadRetry) -> Self {
+        self.download_retry = download_retry;
+        self
+    }
+
+    /// Reports sync progress through `sync_progress` (no reporting by default).
+    pub fn with_sync_progress(mut self, sync_progress: SyncProgressReporter) -> Self {
+        self.sync_progress = Some(sync_progress);
+        self
+    }
+
+    /// Upserts synced cards `sync_batch_size` per query (see
+    /// [`default_sync_batch_size`]). Syncs fail up front, per
+    /// [`check_sync_batch_size`], if it breaks PostgreSQL's parameter limit.
+    pub fn with_sync_batch_size(mut self, sync_batch_size: usize) -> Self {
+        self.sync_batch_size = sync_batch_size;
+        self
+    }
+
+    /// Writes one batch of synced cards: every card on a full sync, the
+    /// delta on a partial one.
+    async fn upsert_sync_batch(
+        &self,
+        sync_kind: SyncKind,
+        scryfall_data: &[ScryfallData],
+        batch_size: usize,
+        zervice_metrics: &mut ZerviceMetrics,
+        progress: Option<&mut SyncProgressTracker>,
+    ) -> anyhow::Result<()> {
+        match sync_kind {
+            SyncKind::Full => {
+                self.repo
+                    .batch_upsert(scryfall_data, batch_size, zervice_metrics)
+                    .await?;
+                if let Some(progress) = progress {
+                    progress.advance(scryfall_data.len(), zervice_metrics);
+                }
+            }
+            SyncKind::Partial => {
+                self.repo
+                    .batch_delta_upsert(
+                        scryfall_data,
+                        batch_size,
+                        self.sync_concurrency,
+                        zervice_metrics,
+                        progress,
+                    )
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Downloads `bulk_endpoint` to a temp file and upserts it batch by batch
+    /// as a blocking task parses it. The bounded channel keeps at most two
+    /// parsed batches waiting, so memory stays flat whatever the file size.
+    /// Each batch commits on its own; a partial sync reads
+    /// `sync_concurrency` chunks per batch so they can upsert side by side.
+    ///
+    /// After every batch the position in the file is checkpointed, so a run
+    /// that dies resumes from there when the next run of the same kind gets
+    /// the same file; the checkpoint is cleared once the whole file is in.
+    /// Returns how many cards were received.
+    async fn stream_sync(
+        &self,
+        bulk_endpoint: BulkEndpoint,
+        sync_kind: SyncKind,
+        batch_size: usize,
+        zervice_metrics: &mut ZerviceMetrics,
+        mut progress: Option<&mut SyncProgressTracker>,
+    ) -> anyhow::Result<usize> {
+        let path = std::env::temp_dir().join(format!(
+            "zwipe_{}_{}.json",
+            bulk_endpoint.to_snake_case(),
+            uuid::Uuid::new_v4()
+        ));
+        let result = async {
+            let download_uri = bulk_endpoint
+                .download_to(&path, self.download_retry)
+                .await?;
+            let checkpoint = self.repo.get_sync_checkpoint(sync_kind).await?;
+            let resume_from =
+                SyncCheckpoint::resume_from(checkpoint.as_ref(), sync_kind, &download_uri);
+            if resume_from > 0 {
+                tracing::info!("resuming {sync_kind} sync after {resume_from} cards");
+            }
+            let (sender, mut receiver) = tokio::sync::mpsc::channel::<Vec<ScryfallData>>(2);
+            let parse_path = path.clone();
+            let stream_batch_size = match sync_kind {
+                SyncKind::Full => batch_size,
+                SyncKind::Partial => batch_size * self.sync_concurrency,
+            };
+            let parser = tokio::task::spawn_blocking(move || {
+                stream_bulk_file(&parse_path, stream_batch_size, |batch| {
+                    sender
+                        .blocking_send(batch)
+                        .map_err(|_| anyhow::anyhow!("sync stopped reading batches"))
+                })
+            });
+            let mut processed = 0;
+            while let Some(batch) = receiver.recv().await {
+                let batch_start = processed;
+                processed += batch.len();
+                if processed <= resume_from {
+                    continue;
+                }
+                let unprocessed = batch
+                    .get(resume_from.saturating_sub(batch_start)..)
+                    .unwrap_or_default();
+                if let Some(progress) = progress.as_deref_mut() {
+                    // cards a resumed run skips still count as processed
+                    progress.advance(batch.len() - unprocessed.len(), zervice_metrics);
+                }
+                self.upsert_sync_batch(
+                    sync_kind,
+                    unprocessed,
+                    batch_size,
+                    zervice_metrics,
+                    progress.as_deref_mut(),
+                )
+                .await?;
+                self.repo
+                    .save_sync_checkpoint(&SyncCheckpoint {
+                        sync_kind,
+                        download_uri: download_uri.clone(),
+                        processed_count: processed,
+                    })
+                    .await?;
+            }
+            let received = parser.await.context("bulk parse task panicked")??;
+            self.repo.clear_sync_checkpoint(sync_kind).await?;
+            Ok(received)
+        }
+        .await;
+        if let Err(e) = tokio::fs::remove_file(&path).await {
+            tracing::warn!("failed to remove bulk file {}: {e}", path.display());
+        }
+        result
+    }
+
+    /// Resolves a batch of collection lines to oracle ids, adding each
+    /// known line's copies to `owned` and reporting the unknown ids.
+    async fn resolve_collection_batch(
+        &self,
+        batch: &mut Vec<CollectionLine>,
+        owned: &mut HashMap<uuid::Uuid, i32>,
+        report: &mut CollectionImportReport,
+    ) -> Result<(), ImportCollectionError> {
+        let ids: Vec<uuid::Uuid> = batch.iter().map(|l| l.oracle_or_scryfall_id).collect();
+        let oracle_ids = self.repo.resolve_oracle_ids(&ids).await?;
+        for line in batch.drain(..) {
+            match oracle_ids.get(&line.oracle_or_scryfall_id) {
+                Some(oracle_id) => {
+                    let quantity = owned.entry(*oracle_id).or_default();
+                    *quantity = quantity.saturating_add(line.quantity);
+                }
+                None if !report.unknown_ids.contains(&line.oracle_or_scryfall_id) => {
+                    report.unknown_ids.push(line.oracle_or_scryfall_id);
+                }
+                None => {}
+            }
+        }
+        Ok(())
     }
 }
 
@@ -84,24 +371,97 @@ impl<R: CardRepository> CardService for Service<R> {
         self.repo.upsert(&scryfall_data).await
     }
 
-    async fn scryfall_sync(&self, bulk_endpoint: BulkEndpoint) -> anyhow::Result<ZerviceMetrics> {
+    async fn scryfall_sync(
+        &self,
+        bulk_endpoint: BulkEndpoint,
+        sync_kind: SyncKind,
+    ) -> anyhow::Result<ZerviceMetrics> {
         tracing::info!(
-            "performing scryfall sync with {}",
+            "performing {sync_kind} scryfall sync with {}",
             bulk_endpoint.to_snake_case()
         );
         let mut zervice_metrics = ZerviceMetrics::new();
-        let batch_size = batch_size();
-        let scryfall_data: Vec<ScryfallData> = bulk_endpoint.amass().await?;
-        zervice_metrics.set_received_count(scryfall_data.len() as i32);
-        self.repo
-            .batch_delta_upsert(&scryfall_data, batch_size, &mut zervice_metrics)
-            .await?;
+        zervice_metrics.set_sync_kind(sync_kind);
+        check_sync_batch_size(self.sync_batch_size)?;
+        let batch_size = self.sync_batch_size;
+        let mut progress;
+        let received = match self.bulk_parse {
+            BulkParse::Streaming => {
+                progress = self.sync_progress.as_ref().map(|r| r.start(None));
+                self.stream_sync(
+                    bulk_endpoint,
+                    sync_kind,
+                    batch_size,
+                    &mut zervice_metrics,
+                    progress.as_mut(),
+                )
+                .await?
+            }
+            BulkParse::WholeFile => {
+                let scryfall_data: Vec<ScryfallData> = bulk_endpoint.amass().await?;
+                progress = self
+                    .sync_progress
+                    .as_ref()
+                    .map(|r| r.start(Some(scryfall_data.len())));
+                self.upsert_sync_batch(
+                    sync_kind,
+                    &scryfall_data,
+                    batch_size,
+                    &mut zervice_metrics,
+                    progress.as_mut(),
+                )
+                .await?;
+                scryfall_data.len()
+            }
+        };
+        if let Some(progress) = progress.as_mut() {
+            progress.finish(received, &zervice_metrics);
+        }
+        zervice_metrics.set_received_count(received as i32);
         zervice_metrics.mark_as_completed();
+        self.search_cache.clear();
+        self.filter_options.invalidate();
         let zervice_metrics = self.repo.record_zervice_metrics(&zervice_metrics).await?;
         tracing::info!("sync complete: {}", zervice_metrics);
         Ok(zervice_metrics)
     }
 
+    async fn scryfall_sync_sets(&self, set_codes: &[String]) -> anyhow::Result<ZerviceMetrics> {
+        tracing::info!("performing scryfall sync of sets {}", set_codes.join(", "));
+        check_sync_batch_size(self.sync_batch_size)?;
+        let scryfall_data = amass_sets(set_codes).await?;
+        let received = scryfall_data.len();
+        let mut zervice_metrics = ZerviceMetrics::new();
+        zervice_metrics.set_sync_kind(SyncKind::Partial);
+        let mut progress = self.sync_progress.as_ref().map(|r| r.start(Some(received)));
+        self.upsert_sync_batch(
+            SyncKind::Partial,
+            &scryfall_data,
+            self.sync_batch_size,
+            &mut zervice_metrics,
+            progress.as_mut(),
+        )
+        .await?;
+        if let Some(progress) = progress.as_mut() {
+            progress.finish(received, &zervice_metrics);
+        }
+        zervice_metrics.set_received_count(received as i32);
+        zervice_metrics.mark_as_completed();
+        self.search_cache.clear();
+        self.filter_options.invalidate();
+        tracing::info!("set sync complete: {}", zervice_metrics);
+        Ok(zervice_metrics)
+    }
+
+    async fn validate_bulk(&self, path: &Path) -> anyhow::Result<BulkValidationReport> {
+        let path = path.to_path_buf();
+        let report = tokio::task::spawn_blocking(move || validate_bulk_file(&path))
+            .await
+            .context("bulk validation task panicked")??;
+        tracing::info!("bulk validation: {report}");
+        Ok(report)
+    }
+
     async fn derive_card_categories(&self, batch_size: usize) -> anyhow::Result<(u32, u32)> {
         // 1. Oracle-tag subtrees (18) + Tokens via all_parts — one SQL pass, all cards.
         let otag_rows = self.repo.derive_oracle_tag_categories().await?;
@@ -150,7 +510,11 @@ impl<R: CardRepository> CardService for Service<R> {
     }
 
     async fn refresh_latest_cards(&self) -> anyhow::Result<()> {
-        self.repo.refresh_latest_cards().await
+        self.repo.refresh_latest_cards().await?;
+        // searches read latest_cards: cached pages are stale from here on
+        self.search_cache.clear();
+        self.filter_options.invalidate();
+        Ok(())
     }
 
     async fn refresh_card_signal_rollup(&self) -> anyhow::Result<()> {
@@ -161,6 +525,10 @@ impl<R: CardRepository> CardService for Service<R> {
         self.repo.refresh_otag_context_signal_rollup().await
     }
 
+    async fn refresh_format_staples(&self) -> anyhow::Result<()> {
+        self.repo.refresh_format_staples().await
+    }
+
     async fn sync_oracle_tags(&self) -> anyhow::Result<(u32, u32)> {
         tracing::info!("performing oracle tags sync");
         let tags = BulkEndpoint::OracleTags.amass_oracle_tags().await?;
@@ -169,6 +537,13 @@ impl<R: CardRepository> CardService for Service<R> {
         Ok(counts)
     }
 
+    async fn sync_set_catalog(&self) -> anyhow::Result<u32> {
+        tracing::info!("performing set catalog sync");
+        let sets = surveil_sets().await?;
+        tracing::info!("fetched {} sets", sets.len());
+        self.repo.sync_set_catalog(&sets).await
+    }
+
     async fn refresh_card_oracle_tags(&self) -> anyhow::Result<()> {
         self.repo.refresh_card_oracle_tags().await
     }
@@ -192,8 +567,60 @@ impl<R: CardRepository> CardService for Service<R> {
         self.repo.get_printings(oracle_id).await
     }
 
+    async fn get_card_with_printings(
+        &self,
+        request: &GetScryfallData,
+    ) -> Result<CardWithPrintings, GetCardError> {
+        self.repo.get_card_with_printings(request).await
+    }
+
+    async fn get_raw_card(
+        &self,
+        request: &GetScryfallData,
+    ) -> Result<RawCard, GetScryfallDataError> {
+        self.repo.get_raw_card(request).await
+    }
+
     async fn search_cards(&self, request: &CardQuery) -> Result<Vec<Card>, SearchCardsError> {
-        self.repo.search_cards(request).await
+        if let Some(cards) = self.search_cache.get(request) {
+            return Ok(cards);
+        }
+        let cards = self.repo.search_cards(request).await?;
+        self.search_cache.insert(request, &cards);
+        Ok(cards)
+    }
+
+    async fn search_card_summaries(
+        &self,
+        request: &CardQuery,
+    ) -> Result<Vec<CardSummary>, SearchCardsError> {
+        Ok(self.repo.search_card_summaries(request).await?)
+    }
+
+    async fn color_distribution(
+        &self,
+        request: &CardQuery,
+    ) -> Result<ColorDistribution, SearchCardsError> {
+        self.repo.color_distribution(request).await
+    }
+
+    async fn format_staples(
+        &self,
+        color_identity: &Colors,
+        limit: u32,
+    ) -> Result<Vec<Card>, SearchCardsError> {
+        self.repo.format_staples(color_identity, limit).await
+    }
+
+    async fn undiscovered_staples(
+        &self,
+        user_id: uuid::Uuid,
+        color_identity: &Colors,
+        limit: u32,
+    ) -> Result<Vec<Card>, SearchCardsError> {
+        self.repo
+            .undiscovered_staples(user_id, color_identity, limit)
+            .await
     }
 
     async fn search_commanders(
@@ -205,31 +632,71 @@ impl<R: CardRepository> CardService for Service<R> {
     }
 
     async fn get_artists(&self) -> Result<Vec<String>, GetArtistsError> {
-        self.repo.get_artists().await
+        let repo = self.repo.clone();
+        self.filter_options
+            .artists
+            .get_or_load(|| async move { repo.get_artists().await })
+            .await
     }
 
     async fn get_card_types(&self) -> Result<Vec<String>, GetCardTypesError> {
-        self.repo.get_card_types().await
+        let repo = self.repo.clone();
+        self.filter_options
+            .card_types
+            .get_or_load(|| async move { repo.get_card_types().await })
+            .await
     }
 
     async fn get_keywords(&self) -> Result<Vec<String>, GetKeywordsError> {
-        self.repo.get_keywords().await
+        let repo = self.repo.clone();
+        self.filter_options
+            .keywords
+            .get_or_load(|| async move { repo.get_keywords().await })
+            .await
     }
 
     async fn get_oracle_tags(&self) -> Result<Vec<OracleTag>, GetOracleTagsError> {
-        self.repo.get_oracle_tags().await
+        let repo = self.repo.clone();
+        self.filter_options
+            .oracle_tags
+            .get_or_load(|| async move { repo.get_oracle_tags().await })
+            .await
     }
 
     async fn get_oracle_words(&self) -> Result<Vec<String>, GetOracleWordsError> {
-        self.repo.get_oracle_words().await
+        let repo = self.repo.clone();
+        self.filter_options
+            .oracle_words
+            .get_or_load(|| async move { repo.get_oracle_words().await })
+            .await
     }
 
     async fn get_sets(&self) -> Result<Vec<String>, GetSetsError> {
-        self.repo.get_sets().await
+        let repo = self.repo.clone();
+        self.filter_options
+            .sets
+            .get_or_load(|| async move { repo.get_sets().await })
+            .await
+    }
+
+    async fn set_icon_uri(&self, set_code: &str) -> Result<SetIcon, GetSetIconError> {
+        self.repo.get_set_icon(set_code).await
+    }
+
+    async fn set_completion(
+        &self,
+        user_id: uuid::Uuid,
+        set_code: &str,
+    ) -> Result<SetCompletion, GetSetCompletionError> {
+        self.repo.get_set_completion(user_id, set_code).await
     }
 
     async fn get_languages(&self) -> Result<Vec<String>, GetLanguagesError> {
-        self.repo.get_languages().await
+        let repo = self.repo.clone();
+        self.filter_options
+            .languages
+            .get_or_load(|| async move { repo.get_languages().await })
+            .await
     }
 
     async fn get_card_profile_with_id(
@@ -268,10 +735,125 @@ impl<R: CardRepository> CardService for Service<R> {
         self.repo.get_last_sync_date().await
     }
 
+    async fn due_sync(&self, schedule: &SyncSchedule) -> anyhow::Result<Option<SyncKind>> {
+        let last_sync = self.repo.get_last_sync_date().await?;
+        let last_full_sync = self.repo.get_last_full_sync_date().await?;
+        Ok(schedule.due(Utc::now(), last_sync, last_full_sync))
+    }
+
     async fn find_cards_by_exact_names(
         &self,
         names: &[String],
     ) -> Result<Vec<Card>, SearchCardsError> {
         self.repo.find_cards_by_exact_names(names).await
     }
+
+    async fn get_cards_by_names(&self, names: &[String]) -> Result<NameLookup, SearchCardsError> {
+        let cards = self.repo.get_cards_by_names(names).await?;
+        Ok(pair_names_with_cards(names, cards))
+    }
+
+    // =============
+    //  considering
+    // =============
+
+    async fn add_to_considering(
+        &self,
+        user_id: uuid::Uuid,
+        scryfall_data_id: uuid::Uuid,
+    ) -> Result<(), ConsideringError> {
+        self.repo
+            .add_to_considering(user_id, scryfall_data_id)
+            .await
+    }
+
+    async fn list_considering(&self, user_id: uuid::Uuid) -> Result<Vec<Card>, ConsideringError> {
+        self.repo.list_considering(user_id).await
+    }
+
+    async fn remove_from_considering(
+        &self,
+        user_id: uuid::Uuid,
+        scryfall_data_id: uuid::Uuid,
+    ) -> Result<(), ConsideringError> {
+        self.repo
+            .remove_from_considering(user_id, scryfall_data_id)
+            .await
+    }
+
+    // ===========
+    //  favorites
+    // ===========
+
+    async fn set_favorites(
+        &self,
+        user_id: uuid::Uuid,
+        changes: Vec<(uuid::Uuid, bool)>,
+    ) -> Result<(), FavoritesError> {
+        let changes = dedupe_favorite_changes(&changes);
+        if changes.is_empty() {
+            return Ok(());
+        }
+        self.repo.set_favorites(user_id, &changes).await
+    }
+
+    async fn list_favorites(&self, user_id: uuid::Uuid) -> Result<Vec<Card>, FavoritesError> {
+        self.repo.list_favorites(user_id).await
+    }
+
+    // ============
+    //  collection
+    // ============
+
+    async fn import_collection_ndjson(
+        &self,
+        user_id: uuid::Uuid,
+        reader: &mut (dyn AsyncBufRead + Unpin + Send),
+    ) -> Result<CollectionImportReport, ImportCollectionError> {
+        let mut report = CollectionImportReport::default();
+        let mut owned: HashMap<uuid::Uuid, i32> = HashMap::new();
+        let mut batch = Vec::with_capacity(COLLECTION_IMPORT_BATCH_SIZE);
+        let mut lines = reader.lines();
+        let mut line_number = 0;
+        while let Some(line) = lines
+            .next_line()
+            .await
+            .map_err(ImportCollectionError::Read)?
+        {
+            line_number += 1;
+            match CollectionLine::parse(&line) {
+                Ok(Some(entry)) => batch.push(entry),
+                Ok(None) => {}
+                Err(_) => report.invalid_lines.push(line_number),
+            }
+            if batch.len() == COLLECTION_IMPORT_BATCH_SIZE {
+                self.resolve_collection_batch(&mut batch, &mut owned, &mut report)
+                    .await?;
+            }
+        }
+        if !batch.is_empty() {
+            self.resolve_collection_batch(&mut batch, &mut owned, &mut report)
+                .await?;
+        }
+
+        let owned: Vec<(uuid::Uuid, i32)> = owned.into_iter().collect();
+        for chunk in owned.chunks(COLLECTION_IMPORT_BATCH_SIZE) {
+            self.repo.set_owned_cards(user_id, chunk).await?;
+        }
+        report.imported = owned.len();
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_sync_batch_size, default_sync_batch_size};
+
+    #[test]
+    fn sync_batch_size_must_fit_the_parameter_limit() {
+        assert!(check_sync_batch_size(default_sync_batch_size()).is_ok());
+        assert!(check_sync_batch_size(1).is_ok());
+        assert!(check_sync_batch_size(0).is_err());
+        assert!(check_sync_batch_size(default_sync_batch_size() * 3).is_err());
+    }
 }
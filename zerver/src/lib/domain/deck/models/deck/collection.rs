@@ -0,0 +1,41 @@
+//! Card collection operations (owned quantities, a deck's missing cards) —
+//! server-side error types only.
+
+#[cfg(feature = "zerver")]
+use thiserror::Error;
+
+/// Errors that can occur while setting an owned quantity.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum SetOwnedCardError {
+    /// No printing carries this oracle id.
+    #[error("card not found")]
+    CardNotFound,
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+}
+
+/// Errors that can occur while reading a user's collection.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum GetOwnedCardsError {
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+}
+
+/// Errors that can occur while comparing a deck against the collection.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum DeckMissingCardsError {
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+    /// Requesting user doesn't own this deck.
+    #[error("deck does not belong to requesting user")]
+    Forbidden,
+    /// Deck does not exist.
+    #[error("deck not found")]
+    NotFound,
+}
@@ -0,0 +1,40 @@
+//! Deck JSON backup operations (export a deck, restore one as a new deck) —
+//! server-side error types only.
+
+#[cfg(feature = "zerver")]
+use crate::domain::deck::models::deck::create_deck_profile::CreateDeckProfileError;
+#[cfg(feature = "zerver")]
+use thiserror::Error;
+
+/// Errors that can occur while exporting a deck.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum ExportDeckJsonError {
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+    /// Requesting user doesn't own this deck.
+    #[error("deck does not belong to requesting user")]
+    Forbidden,
+    /// Deck does not exist.
+    #[error("deck not found")]
+    NotFound,
+}
+
+/// Errors that can occur while restoring a deck from an export.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum ImportDeckJsonError {
+    /// Creating the new deck failed (deck limit, duplicate name, database).
+    #[error(transparent)]
+    CreateDeck(#[from] CreateDeckProfileError),
+    /// The export holds more cards than a deck may (verified user, true cap).
+    #[error("deck card limit reached")]
+    LimitReached,
+    /// The export holds more cards than an unverified user's deck may.
+    #[error("deck card limit reached, verify your email to unlock more")]
+    UnverifiedLimitReached,
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+}
@@ -0,0 +1,24 @@
+//! Create a deck from a pasted decklist — server-side error types only.
+
+#[cfg(feature = "zerver")]
+use crate::domain::deck::models::deck::create_deck_profile::CreateDeckProfileError;
+#[cfg(feature = "zerver")]
+use thiserror::Error;
+
+/// Errors that can occur while creating a deck from a decklist.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum ImportDecklistError {
+    /// Creating the new deck failed (deck limit, duplicate name, database).
+    #[error(transparent)]
+    CreateDeck(#[from] CreateDeckProfileError),
+    /// The list holds more cards than a deck may (verified user, true cap).
+    #[error("deck card limit reached")]
+    LimitReached,
+    /// The list holds more cards than an unverified user's deck may.
+    #[error("deck card limit reached, verify your email to unlock more")]
+    UnverifiedLimitReached,
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+}
@@ -6,8 +6,12 @@
 pub mod clear_deck_suppressions;
 /// Clone deck operation.
 pub mod clone_deck;
+/// Card collection operations (owned quantities, missing deck cards).
+pub mod collection;
 /// Create deck profile operation.
 pub mod create_deck_profile;
+/// Deck JSON backup operations (export, restore).
+pub mod deck_json;
 /// Delete deck operation.
 pub mod delete_deck;
 /// Get complete deck operation (profile + cards).
@@ -18,8 +22,12 @@ pub mod get_deck_profile;
 pub mod get_deck_tokens;
 /// Import a deck from Archidekt.
 pub mod import_archidekt;
+/// Create a deck from a pasted decklist.
+pub mod import_decklist;
 /// Deck-aware card search (exclusion + synergy ordering).
 pub mod search_deck_cards;
+/// Set deck labels operation (user-defined labels).
+pub mod set_deck_labels;
 /// Deck share operations (share token create/revoke, public shared read).
 pub mod share_deck;
 /// Skip deck card operation (single durable suppression).
@@ -0,0 +1,19 @@
+//! Set deck labels operation — server-side error types only.
+
+#[cfg(feature = "zerver")]
+use thiserror::Error;
+
+/// Errors that can occur while replacing a deck's labels.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum SetDeckLabelsError {
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+    /// Requesting user doesn't own this deck.
+    #[error("deck does not belong to requesting user")]
+    Forbidden,
+    /// Deck does not exist.
+    #[error("deck not found")]
+    NotFound,
+}
@@ -0,0 +1,64 @@
+//! Bulk add cards to deck operation.
+//!
+//! Request type lives in `zwipe_core`. Service-layer error type remains here;
+//! copy caps are the same [`CopyCap`](super::move_deck_cards::CopyCap) a
+//! move enforces.
+
+#[cfg(feature = "zerver")]
+use crate::domain::deck::models::deck::get_deck_profile::GetDeckProfileError;
+#[cfg(feature = "zerver")]
+use thiserror::Error;
+#[cfg(feature = "zerver")]
+use uuid::Uuid;
+
+/// Errors that can occur while adding cards to a deck in bulk. Any of them
+/// leaves the deck unchanged.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum BulkAddDeckCardsError {
+    /// Deck is missing or not owned by the caller.
+    #[error(transparent)]
+    Deck(#[from] GetDeckProfileError),
+    /// A card id matched no card.
+    #[error("card {0} not found")]
+    CardNotFound(Uuid),
+    /// A card is in the deck's command zone.
+    #[error("{name} is in this deck's command zone")]
+    IsCommander {
+        /// Card name.
+        name: String,
+    },
+    /// A card's color identity falls outside the command zone's.
+    #[error("{name} is outside the commander's color identity")]
+    OutsideColorIdentity {
+        /// Card name.
+        name: String,
+    },
+    /// The batch would put the deck over its copy limit for a card.
+    #[error("{name} would exceed the deck's copy limit ({max})")]
+    CopyLimitExceeded {
+        /// Card name.
+        name: String,
+        /// Maximum copies across the deck and sideboard.
+        max: u32,
+    },
+    /// The batch would take the deck past the card limit (verified user).
+    #[error("card limit reached (mainboard, maybeboard, and sideboard all count toward it)")]
+    LimitReached,
+    /// The batch would take the deck past the unverified card limit.
+    #[error("card limit reached across all boards, verify your email to unlock more")]
+    UnverifiedLimitReached,
+    /// Database returned an invalid deck card.
+    #[error("deck cards added but database returned invalid object {0}")]
+    DeckCardFromDb(anyhow::Error),
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+}
+
+#[cfg(feature = "zerver")]
+impl From<sqlx::Error> for BulkAddDeckCardsError {
+    fn from(value: sqlx::Error) -> Self {
+        Self::Database(value.into())
+    }
+}
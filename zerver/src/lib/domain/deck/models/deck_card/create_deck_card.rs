@@ -17,6 +17,9 @@ pub enum CreateDeckCardError {
     /// Cannot add the deck's commander as a regular card.
     #[error("card is this deck's commander")]
     IsCommander,
+    /// Card's color identity falls outside the command zone's.
+    #[error("card is outside the commander's color identity")]
+    OutsideColorIdentity,
     /// Deck has reached the maximum number of cards (verified user, true cap).
     #[error("card limit reached (mainboard, maybeboard, and sideboard all count toward it)")]
     LimitReached,
@@ -1,3 +1,5 @@
+/// Add many cards to a deck in one call.
+pub mod bulk_add_deck_cards;
 /// Add card to deck operation.
 pub mod create_deck_card;
 /// Remove card from deck operation.
@@ -6,5 +8,9 @@ pub mod delete_deck_card;
 pub mod get_deck_card;
 /// Bulk import cards from plain-text decklist.
 pub mod import_deck_cards;
+/// Move cards from one deck to another.
+pub mod move_deck_cards;
+/// Orphaned deck card detection and repair.
+pub mod orphaned_deck_cards;
 /// Update card quantity in deck operation.
 pub mod update_deck_card;
@@ -0,0 +1,58 @@
+//! Move deck cards operation.
+//!
+//! Request type lives in `zwipe_core`. Service-layer error type and the
+//! per-card copy caps the repository enforces remain here.
+
+#[cfg(feature = "zerver")]
+use crate::domain::deck::models::deck::get_deck_profile::GetDeckProfileError;
+#[cfg(feature = "zerver")]
+use thiserror::Error;
+#[cfg(feature = "zerver")]
+use uuid::Uuid;
+
+/// The most copies of one moved card the destination deck may hold once the
+/// move lands. Cards without a cap (basic lands, "any number" cards) move
+/// freely.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CopyCap {
+    /// Card name, for the error message.
+    pub name: String,
+    /// Maximum copies in the destination.
+    pub max: u32,
+}
+
+/// Errors that can occur while moving cards between decks.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum MoveDeckCardsError {
+    /// Either deck is missing or not owned by the caller.
+    #[error(transparent)]
+    Deck(#[from] GetDeckProfileError),
+    /// The source deck doesn't hold that many copies of the card.
+    #[error("source deck does not have {quantity} copies of card {scryfall_data_id}")]
+    NotEnoughCopies {
+        /// The card (Scryfall data ID) that was asked for.
+        scryfall_data_id: Uuid,
+        /// How many copies were to be moved.
+        quantity: i32,
+    },
+    /// The move would put the destination over its copy limit; nothing moved.
+    #[error("{name} would exceed the destination deck's copy limit ({max})")]
+    CopyLimitExceeded {
+        /// Card name.
+        name: String,
+        /// Maximum copies in the destination.
+        max: u32,
+    },
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+}
+
+#[cfg(feature = "zerver")]
+impl From<sqlx::Error> for MoveDeckCardsError {
+    fn from(value: sqlx::Error) -> Self {
+        Self::Database(value.into())
+    }
+}
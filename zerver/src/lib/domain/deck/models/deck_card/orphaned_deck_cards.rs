@@ -0,0 +1,18 @@
+//! Orphaned deck card detection and repair.
+//!
+//! Re-exported from `zwipe_core`. Service-layer error type remains here.
+
+#[cfg(feature = "zerver")]
+use thiserror::Error;
+
+/// Errors that can occur while finding or repairing orphaned deck cards.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Error)]
+pub enum OrphanedDeckCardsError {
+    /// Database operation failed.
+    #[error(transparent)]
+    Database(anyhow::Error),
+    /// A stored deck card failed domain validation.
+    #[error("deck card found but database returned invalid object: {0}")]
+    DeckCardFromDb(anyhow::Error),
+}
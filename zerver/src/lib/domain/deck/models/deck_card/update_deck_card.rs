@@ -20,6 +20,11 @@ pub enum UpdateDeckCardError {
     /// Starring would exceed the 3-MVP podium for this deck.
     #[error("deck already has 3 MVPs")]
     MvpCapReached,
+    /// Moving the card would put a second copy of it on the sideboard, or a
+    /// second main copy beside the deck's (change that copy's quantity
+    /// instead).
+    #[error("card is already on that board")]
+    AlreadyOnBoard,
     /// Only mainboard cards can be MVPs.
     #[error("only mainboard cards can be MVPs")]
     MvpNotMainboard,
@@ -3,36 +3,51 @@
 //! This module defines the interfaces (ports) for deck management in hexagonal architecture.
 //! Decks are collections of Magic: The Gathering cards with metadata like name, commander, and copy limits.
 
-use crate::domain::BoxFuture;
-use std::future::Future;
+use crate::domain::{
+    BoxFuture,
+    card::requests::{get_card::GetCardError, get_scryfall_data::GetScryfallData},
+};
+use chrono::{DateTime, Utc};
+use std::{collections::HashMap, future::Future};
 
 use crate::domain::deck::models::{
     deck::{
         clear_deck_suppressions::ClearDeckSuppressionsError,
         clone_deck::CloneDeckError,
+        collection::{DeckMissingCardsError, GetOwnedCardsError, SetOwnedCardError},
         create_deck_profile::CreateDeckProfileError,
+        deck_json::{ExportDeckJsonError, ImportDeckJsonError},
         delete_deck::DeleteDeckError,
         get_deck::GetDeckError,
         get_deck_profile::GetDeckProfileError,
         get_deck_tokens::GetDeckTokensError,
         import_archidekt::ArchidektCard,
+        import_decklist::ImportDecklistError,
         search_deck_cards::SearchDeckCardsError,
+        set_deck_labels::SetDeckLabelsError,
         share_deck::{GetSharedDeckError, ShareDeckError, SharedDeck},
         skip_deck_card::SkipDeckCardError,
         update_deck_profile::UpdateDeckProfileError,
     },
     deck_card::{
-        create_deck_card::CreateDeckCardError, delete_deck_card::DeleteDeckCardError,
-        get_deck_card::GetDeckCardError, import_deck_cards::ImportDeckCardsError,
+        bulk_add_deck_cards::BulkAddDeckCardsError,
+        create_deck_card::CreateDeckCardError,
+        delete_deck_card::DeleteDeckCardError,
+        get_deck_card::GetDeckCardError,
+        import_deck_cards::ImportDeckCardsError,
+        move_deck_cards::{CopyCap, MoveDeckCardsError},
+        orphaned_deck_cards::OrphanedDeckCardsError,
         update_deck_card::UpdateDeckCardError,
     },
 };
 use zwipe_core::domain::{
     card::{Card, search_card::card_filter::CardQuery},
     deck::{
-        Deck, DeckCard, DeckName,
+        BannedDeckCard, Deck, DeckCard, DeckExport, DeckName, DeckStats, ExportFormat, Format,
+        LegalityReport, MissingDeckCard, OrphanedDeckCard, OwnedCard, ProxySheet,
         deck_profile::DeckProfile,
         requests::{
+            bulk_add_deck_cards::BulkAddDeckCards,
             clear_deck_suppressions::ClearDeckSuppressions,
             clone_deck::CloneDeck,
             create_deck_card::CreateDeckCard,
@@ -42,6 +57,12 @@ use zwipe_core::domain::{
             get_deck_profile::GetDeckProfile,
             get_deck_profiles::GetDeckProfiles,
             import_deck_cards::{ImportDeckCards, ImportDeckCardsResult},
+            import_deck_json::{ImportDeckJson, ImportDeckJsonResult},
+            import_decklist::{ImportDecklist, ImportReport},
+            import_shared_deck::ImportSharedDeck,
+            move_deck_cards::MoveDeckCards,
+            set_deck_labels::SetDeckLabels,
+            set_owned_card::SetOwnedCard,
             skip_deck_card::SkipDeckCard,
             update_deck_card::UpdateDeckCard,
             update_deck_profile::UpdateDeckProfile,
@@ -151,6 +172,30 @@ pub trait DeckRepository: Clone + Send + Sync + 'static {
         cards: &[(uuid::Uuid, uuid::Uuid, i32, String)],
     ) -> impl Future<Output = Result<Vec<DeckCard>, ImportDeckCardsError>> + Send;
 
+    /// Moves cards from `request.from_deck_id` to `request.to_deck_id` in one
+    /// transaction: each leaves the source (its entry deleted once empty) and
+    /// merges into the destination's entry for the same oracle card, or is
+    /// added on its source board. A card in `caps` that would end up over
+    /// its cap rolls the whole move back. Callers must have verified
+    /// ownership of both decks first.
+    fn move_deck_cards(
+        &self,
+        request: &MoveDeckCards,
+        caps: &HashMap<uuid::Uuid, CopyCap>,
+    ) -> impl Future<Output = Result<(), MoveDeckCardsError>> + Send;
+
+    /// Adds every card in `request` to its deck in one transaction, merging
+    /// into existing entries. `oracle_ids` maps each card to its oracle id;
+    /// a card over its cap in `caps` (counting deck and sideboard together)
+    /// rolls the whole batch back. Returns the added or updated entries.
+    /// Callers must have verified ownership first.
+    fn bulk_add_deck_cards(
+        &self,
+        request: &BulkAddDeckCards,
+        oracle_ids: &HashMap<uuid::Uuid, uuid::Uuid>,
+        caps: &HashMap<uuid::Uuid, CopyCap>,
+    ) -> impl Future<Output = Result<Vec<DeckCard>, BulkAddDeckCardsError>> + Send;
+
     /// Deletes every card on `board` whose oracle_id is not in `keep_oracle_ids`.
     /// Used by replace-mode imports to make a board exactly match the imported
     /// list. Callers must have verified deck ownership first. Bulk deletes do
@@ -182,6 +227,38 @@ pub trait DeckRepository: Clone + Send + Sync + 'static {
         request: &SkipDeckCard,
     ) -> impl Future<Output = Result<(), SkipDeckCardError>> + Send;
 
+    // ========
+    //  orphans
+    // ========
+
+    /// Lists the user's deck cards whose printing no longer resolves to a card
+    /// (no `scryfall_data` or `card_profiles` row left after a sync), each
+    /// with the surviving printing of the same oracle card to remap to.
+    fn find_orphaned_deck_cards(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<OrphanedDeckCard>, OrphanedDeckCardsError>> + Send;
+
+    /// Transactionally remaps each of the user's orphaned deck cards that has
+    /// a replacement onto it. Returns every orphan found: those carrying a
+    /// `replacement_id` were remapped, the rest were left untouched.
+    fn repair_orphaned_deck_cards(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<OrphanedDeckCard>, OrphanedDeckCardsError>> + Send;
+
+    // =========
+    //  banlist
+    // =========
+
+    /// Every deck holding a card (command zone included) that became banned
+    /// in the deck's format at or after `since`, per the legality history
+    /// card syncs record.
+    fn decks_affected_by_banlist_changes(
+        &self,
+        since: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<BannedDeckCard>, anyhow::Error>> + Send;
+
     // ========
     //  clone
     // ========
@@ -198,6 +275,18 @@ pub trait DeckRepository: Clone + Send + Sync + 'static {
         owner_id: uuid::Uuid,
     ) -> impl Future<Output = Result<uuid::Uuid, CloneDeckError>> + Send;
 
+    /// [`clone_deck`](Self::clone_deck) for a deck adopted from another
+    /// user's share link: every card, and each command zone slot, moves to
+    /// its oracle card's current preferred printing (kept as is when none is
+    /// left). Tags and targets copy; share token and labels don't. No
+    /// authorization check — the caller resolved the share token.
+    fn copy_shared_deck(
+        &self,
+        source_deck_id: uuid::Uuid,
+        new_name: &DeckName,
+        owner_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<uuid::Uuid, CloneDeckError>> + Send;
+
     // =======
     //  share
     // =======
@@ -222,6 +311,54 @@ pub trait DeckRepository: Clone + Send + Sync + 'static {
         &self,
         token: uuid::Uuid,
     ) -> impl Future<Output = Result<Option<(uuid::Uuid, uuid::Uuid)>, anyhow::Error>> + Send;
+
+    // ========
+    //  labels
+    // ========
+
+    /// Replaces every label on the deck with `request.labels` and returns
+    /// them. The caller must have already verified ownership.
+    fn set_deck_labels(
+        &self,
+        request: &SetDeckLabels,
+    ) -> impl Future<Output = Result<Vec<String>, SetDeckLabelsError>> + Send;
+
+    // =============
+    //  json backup
+    // =============
+
+    /// Maps each oracle id to its current preferred printing (see the
+    /// `preferred_printing` SQL function). Oracle ids with no printing left
+    /// are absent from the map.
+    fn preferred_printings(
+        &self,
+        oracle_ids: &[uuid::Uuid],
+    ) -> impl Future<Output = Result<HashMap<uuid::Uuid, uuid::Uuid>, anyhow::Error>> + Send;
+
+    // ============
+    //  collection
+    // ============
+
+    /// Sets the user's owned quantity of a card; `0` removes it. Fails with
+    /// `CardNotFound` when no printing carries the oracle id.
+    fn set_owned(
+        &self,
+        request: &SetOwnedCard,
+    ) -> impl Future<Output = Result<(), SetOwnedCardError>> + Send;
+
+    /// Every card the user owns, by oracle id.
+    fn get_owned(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<OwnedCard>, GetOwnedCardsError>> + Send;
+
+    /// Each card the deck needs against the user's owned copies, sorted by
+    /// name. The caller must have already verified ownership.
+    fn deck_missing_cards(
+        &self,
+        user_id: uuid::Uuid,
+        deck_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<MissingDeckCard>, DeckMissingCardsError>> + Send;
 }
 
 /// Service port for deck building business logic.
@@ -264,6 +401,45 @@ pub trait DeckService: Clone + Send + Sync + 'static {
         request: &GetDeckProfile,
     ) -> impl Future<Output = Result<Deck, GetDeckError>> + Send;
 
+    /// Checks the deck against every rule of its format (size, card
+    /// legality, copy limits with basic lands exempt, color identity, the
+    /// command zone, the sideboard) in one report, with authorization check.
+    fn full_legality_report(
+        &self,
+        request: &GetDeckProfile,
+    ) -> impl Future<Output = Result<LegalityReport, GetDeckError>> + Send;
+
+    /// [`full_legality_report`](Self::full_legality_report) against `format`
+    /// rather than the deck's own, with authorization check. The deck is
+    /// left as it is.
+    fn validate_deck(
+        &self,
+        request: &GetDeckProfile,
+        format: Format,
+    ) -> impl Future<Output = Result<LegalityReport, GetDeckError>> + Send;
+
+    /// The deck's card images expanded by quantity for printing playtest
+    /// proxies, with authorization check. Capped at
+    /// [`MAX_PROXY_IMAGES`](zwipe_core::domain::deck::MAX_PROXY_IMAGES).
+    fn proxy_sheet(
+        &self,
+        request: &GetDeckProfile,
+    ) -> impl Future<Output = Result<ProxySheet, GetDeckError>> + Send;
+
+    /// The deck as decklist text in `format`, with authorization check.
+    fn export_deck(
+        &self,
+        request: &GetDeckProfile,
+        format: ExportFormat,
+    ) -> impl Future<Output = Result<String, GetDeckError>> + Send;
+
+    /// Mana curve, color pips, type distribution and average mana value of
+    /// the deck's active board, with authorization check.
+    fn deck_stats(
+        &self,
+        request: &GetDeckProfile,
+    ) -> impl Future<Output = Result<DeckStats, GetDeckError>> + Send;
+
     /// Deck-aware card search with authorization check: applies `filter` but
     /// excludes cards already in the deck (any board, plus commander/partner/
     /// background/signature slots), and when `filter` has no explicit
@@ -344,6 +520,25 @@ pub trait DeckService: Clone + Send + Sync + 'static {
         request: &ImportDeckCards,
     ) -> impl Future<Output = Result<ImportDeckCardsResult, ImportDeckCardsError>> + Send;
 
+    /// Moves cards between two decks the caller owns, atomically. The
+    /// destination's copy limits (see
+    /// [`max_copies_of`](zwipe_core::domain::deck::validate_deck::max_copies_of))
+    /// are enforced on the merged quantities; a move breaking one changes
+    /// neither deck.
+    fn move_deck_cards(
+        &self,
+        request: &MoveDeckCards,
+    ) -> impl Future<Output = Result<(), MoveDeckCardsError>> + Send;
+
+    /// Adds several cards to a deck the caller owns, all or nothing. Ownership
+    /// is checked once; command zone, color identity, card limit and copy
+    /// limit rules apply across the whole batch. Returns the added or updated
+    /// entries.
+    fn bulk_add_deck_cards(
+        &self,
+        request: &BulkAddDeckCards,
+    ) -> impl Future<Output = Result<Vec<DeckCard>, BulkAddDeckCardsError>> + Send;
+
     /// Imports an Archidekt card list into an existing deck owned by `user_id`,
     /// onto the given board — exactly like `import_deck_cards`, except cards
     /// resolve by Scryfall printing id (with a name fallback) instead of by
@@ -359,6 +554,35 @@ pub trait DeckService: Clone + Send + Sync + 'static {
         mode: zwipe_core::domain::deck::ImportMode,
     ) -> impl Future<Output = Result<ImportDeckCardsResult, ImportDeckCardsError>> + Send;
 
+    // ========
+    //  orphans
+    // ========
+
+    /// Reports the requesting user's deck cards left dangling by a card sync,
+    /// with the printing each can be remapped to.
+    fn find_orphaned_deck_cards(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<OrphanedDeckCard>, OrphanedDeckCardsError>> + Send;
+
+    /// Remaps the requesting user's orphaned deck cards onto a surviving
+    /// printing of the same oracle card where one exists.
+    fn repair_orphaned_deck_cards(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<OrphanedDeckCard>, OrphanedDeckCardsError>> + Send;
+
+    // =========
+    //  banlist
+    // =========
+
+    /// Decks left holding a card banned in their format by a sync since
+    /// `since`, one entry per deck and card, for notifying their owners.
+    fn decks_affected_by_banlist_changes(
+        &self,
+        since: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<BannedDeckCard>, anyhow::Error>> + Send;
+
     // ========
     //  clone
     // ========
@@ -366,10 +590,21 @@ pub trait DeckService: Clone + Send + Sync + 'static {
     /// Clones an existing deck owned by the caller into a new deck with a
     /// caller-chosen name. Performs source ownership check and deck-count
     /// limit enforcement, then delegates the transactional copy to the
-    /// repository. Returns the new deck's id.
+    /// repository. The copy gets its own id, timestamps and card rows.
+    /// Returns the new deck's profile.
     fn clone_deck(
         &self,
         request: &CloneDeck,
+    ) -> impl Future<Output = Result<DeckProfile, CloneDeckError>> + Send;
+
+    /// Copies the deck shared under `request.share_token` into the caller's
+    /// account, under `request.new_name` or else the shared deck's name,
+    /// with cards on their current printings. A revoked or unknown token is
+    /// [`CloneDeckError::SourceNotFound`]; the deck-count limit applies as
+    /// for any new deck. Returns the copy's id.
+    fn import_shared_deck(
+        &self,
+        request: &ImportSharedDeck,
     ) -> impl Future<Output = Result<uuid::Uuid, CloneDeckError>> + Send;
 
     // =======
@@ -389,6 +624,80 @@ pub trait DeckService: Clone + Send + Sync + 'static {
         request: &GetDeckProfile,
     ) -> impl Future<Output = Result<(), ShareDeckError>> + Send;
 
+    // ========
+    //  labels
+    // ========
+
+    /// Replaces the deck's user-defined labels with authorization check.
+    /// Returns the stored (normalized, sorted) labels.
+    fn set_deck_labels(
+        &self,
+        request: &SetDeckLabels,
+    ) -> impl Future<Output = Result<Vec<String>, SetDeckLabelsError>> + Send;
+
+    // =============
+    //  json backup
+    // =============
+
+    /// Exports the deck as a self-contained [`DeckExport`] (settings plus
+    /// cards by oracle id) with authorization check.
+    fn export_deck_json(
+        &self,
+        request: &GetDeckProfile,
+    ) -> impl Future<Output = Result<DeckExport, ExportDeckJsonError>> + Send;
+
+    /// Restores a [`DeckExport`] as a new deck, resolving each oracle id to
+    /// its current preferred printing. Enforces the deck-count and card
+    /// limits; oracle ids with no printing left are skipped and reported.
+    fn import_deck_json(
+        &self,
+        request: &ImportDeckJson,
+    ) -> impl Future<Output = Result<ImportDeckJsonResult, ImportDeckJsonError>> + Send;
+
+    /// Creates a new deck from a pasted decklist, resolving each line by
+    /// name (the named printing when the line gives set and collector
+    /// number). `Commander` lines fill the command zone. Enforces the
+    /// deck-count and card limits; lines matching no card, or more than one,
+    /// are skipped and reported.
+    fn import_deck(
+        &self,
+        request: &ImportDecklist,
+    ) -> impl Future<Output = Result<ImportReport, ImportDecklistError>> + Send;
+
+    // ============
+    //  collection
+    // ============
+
+    /// Sets the user's owned quantity of a card; `0` removes it.
+    fn set_owned(
+        &self,
+        request: &SetOwnedCard,
+    ) -> impl Future<Output = Result<(), SetOwnedCardError>> + Send;
+
+    /// Every card the user owns, by oracle id.
+    fn get_owned(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> impl Future<Output = Result<Vec<OwnedCard>, GetOwnedCardsError>> + Send;
+
+    /// What the deck still needs after the user's collection, with
+    /// authorization check. Fully-owned cards are listed with `missing: 0`.
+    fn deck_missing_cards(
+        &self,
+        request: &GetDeckProfile,
+    ) -> impl Future<Output = Result<Vec<MissingDeckCard>, DeckMissingCardsError>> + Send;
+
+    // =============
+    //  suggestions
+    // =============
+
+    /// Suggests a few deck names for a deck led by the given commander
+    /// (e.g. "Atraxa Superfriends"). Deterministic per commander.
+    fn suggest_deck_name(
+        &self,
+        commander_id: &GetScryfallData,
+    ) -> impl Future<Output = Result<Vec<String>, GetCardError>> + Send;
+
     /// Resolves a share token to the full deck aggregate plus command zone
     /// cards. **Unauthenticated**: possession of the token is the authority.
     fn get_shared_deck(
@@ -430,6 +739,38 @@ pub trait ErasedDeckService: Send + Sync + 'static {
         request: &'a GetDeckProfile,
     ) -> BoxFuture<'a, Result<Deck, GetDeckError>>;
 
+    /// See [`DeckService::full_legality_report`].
+    fn full_legality_report<'a>(
+        &'a self,
+        request: &'a GetDeckProfile,
+    ) -> BoxFuture<'a, Result<LegalityReport, GetDeckError>>;
+
+    /// See [`DeckService::validate_deck`].
+    fn validate_deck<'a>(
+        &'a self,
+        request: &'a GetDeckProfile,
+        format: Format,
+    ) -> BoxFuture<'a, Result<LegalityReport, GetDeckError>>;
+
+    /// See [`DeckService::proxy_sheet`].
+    fn proxy_sheet<'a>(
+        &'a self,
+        request: &'a GetDeckProfile,
+    ) -> BoxFuture<'a, Result<ProxySheet, GetDeckError>>;
+
+    /// See [`DeckService::export_deck`].
+    fn export_deck<'a>(
+        &'a self,
+        request: &'a GetDeckProfile,
+        format: ExportFormat,
+    ) -> BoxFuture<'a, Result<String, GetDeckError>>;
+
+    /// See [`DeckService::deck_stats`].
+    fn deck_stats<'a>(
+        &'a self,
+        request: &'a GetDeckProfile,
+    ) -> BoxFuture<'a, Result<DeckStats, GetDeckError>>;
+
     /// See [`DeckService::search_deck_cards`].
     fn search_deck_cards<'a>(
         &'a self,
@@ -491,6 +832,18 @@ pub trait ErasedDeckService: Send + Sync + 'static {
         request: &'a ImportDeckCards,
     ) -> BoxFuture<'a, Result<ImportDeckCardsResult, ImportDeckCardsError>>;
 
+    /// See [`DeckService::move_deck_cards`].
+    fn move_deck_cards<'a>(
+        &'a self,
+        request: &'a MoveDeckCards,
+    ) -> BoxFuture<'a, Result<(), MoveDeckCardsError>>;
+
+    /// See [`DeckService::bulk_add_deck_cards`].
+    fn bulk_add_deck_cards<'a>(
+        &'a self,
+        request: &'a BulkAddDeckCards,
+    ) -> BoxFuture<'a, Result<Vec<DeckCard>, BulkAddDeckCardsError>>;
+
     /// See [`DeckService::import_archidekt_deck`].
     fn import_archidekt_deck<'a>(
         &'a self,
@@ -502,10 +855,34 @@ pub trait ErasedDeckService: Send + Sync + 'static {
         mode: zwipe_core::domain::deck::ImportMode,
     ) -> BoxFuture<'a, Result<ImportDeckCardsResult, ImportDeckCardsError>>;
 
+    /// See [`DeckService::find_orphaned_deck_cards`].
+    fn find_orphaned_deck_cards(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> BoxFuture<'_, Result<Vec<OrphanedDeckCard>, OrphanedDeckCardsError>>;
+
+    /// See [`DeckService::repair_orphaned_deck_cards`].
+    fn repair_orphaned_deck_cards(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> BoxFuture<'_, Result<Vec<OrphanedDeckCard>, OrphanedDeckCardsError>>;
+
+    /// See [`DeckService::decks_affected_by_banlist_changes`].
+    fn decks_affected_by_banlist_changes(
+        &self,
+        since: DateTime<Utc>,
+    ) -> BoxFuture<'_, Result<Vec<BannedDeckCard>, anyhow::Error>>;
+
     /// See [`DeckService::clone_deck`].
     fn clone_deck<'a>(
         &'a self,
         request: &'a CloneDeck,
+    ) -> BoxFuture<'a, Result<DeckProfile, CloneDeckError>>;
+
+    /// See [`DeckService::import_shared_deck`].
+    fn import_shared_deck<'a>(
+        &'a self,
+        request: &'a ImportSharedDeck,
     ) -> BoxFuture<'a, Result<uuid::Uuid, CloneDeckError>>;
 
     /// See [`DeckService::share_deck`].
@@ -520,11 +897,59 @@ pub trait ErasedDeckService: Send + Sync + 'static {
         request: &'a GetDeckProfile,
     ) -> BoxFuture<'a, Result<(), ShareDeckError>>;
 
+    /// See [`DeckService::set_deck_labels`].
+    fn set_deck_labels<'a>(
+        &'a self,
+        request: &'a SetDeckLabels,
+    ) -> BoxFuture<'a, Result<Vec<String>, SetDeckLabelsError>>;
+
+    /// See [`DeckService::export_deck_json`].
+    fn export_deck_json<'a>(
+        &'a self,
+        request: &'a GetDeckProfile,
+    ) -> BoxFuture<'a, Result<DeckExport, ExportDeckJsonError>>;
+
+    /// See [`DeckService::import_deck_json`].
+    fn import_deck_json<'a>(
+        &'a self,
+        request: &'a ImportDeckJson,
+    ) -> BoxFuture<'a, Result<ImportDeckJsonResult, ImportDeckJsonError>>;
+
+    /// See [`DeckService::import_deck`].
+    fn import_deck<'a>(
+        &'a self,
+        request: &'a ImportDecklist,
+    ) -> BoxFuture<'a, Result<ImportReport, ImportDecklistError>>;
+
+    /// See [`DeckService::set_owned`].
+    fn set_owned<'a>(
+        &'a self,
+        request: &'a SetOwnedCard,
+    ) -> BoxFuture<'a, Result<(), SetOwnedCardError>>;
+
+    /// See [`DeckService::get_owned`].
+    fn get_owned(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> BoxFuture<'_, Result<Vec<OwnedCard>, GetOwnedCardsError>>;
+
+    /// See [`DeckService::deck_missing_cards`].
+    fn deck_missing_cards<'a>(
+        &'a self,
+        request: &'a GetDeckProfile,
+    ) -> BoxFuture<'a, Result<Vec<MissingDeckCard>, DeckMissingCardsError>>;
+
     /// See [`DeckService::get_shared_deck`].
     fn get_shared_deck(
         &self,
         token: uuid::Uuid,
     ) -> BoxFuture<'_, Result<SharedDeck, GetSharedDeckError>>;
+
+    /// See [`DeckService::suggest_deck_name`].
+    fn suggest_deck_name<'a>(
+        &'a self,
+        commander_id: &'a GetScryfallData,
+    ) -> BoxFuture<'a, Result<Vec<String>, GetCardError>>;
 }
 
 impl<T> ErasedDeckService for T
@@ -566,6 +991,43 @@ where
         Box::pin(DeckService::get_deck(self, request))
     }
 
+    fn full_legality_report<'a>(
+        &'a self,
+        request: &'a GetDeckProfile,
+    ) -> BoxFuture<'a, Result<LegalityReport, GetDeckError>> {
+        Box::pin(DeckService::full_legality_report(self, request))
+    }
+
+    fn validate_deck<'a>(
+        &'a self,
+        request: &'a GetDeckProfile,
+        format: Format,
+    ) -> BoxFuture<'a, Result<LegalityReport, GetDeckError>> {
+        Box::pin(DeckService::validate_deck(self, request, format))
+    }
+
+    fn proxy_sheet<'a>(
+        &'a self,
+        request: &'a GetDeckProfile,
+    ) -> BoxFuture<'a, Result<ProxySheet, GetDeckError>> {
+        Box::pin(DeckService::proxy_sheet(self, request))
+    }
+
+    fn export_deck<'a>(
+        &'a self,
+        request: &'a GetDeckProfile,
+        format: ExportFormat,
+    ) -> BoxFuture<'a, Result<String, GetDeckError>> {
+        Box::pin(DeckService::export_deck(self, request, format))
+    }
+
+    fn deck_stats<'a>(
+        &'a self,
+        request: &'a GetDeckProfile,
+    ) -> BoxFuture<'a, Result<DeckStats, GetDeckError>> {
+        Box::pin(DeckService::deck_stats(self, request))
+    }
+
     fn search_deck_cards<'a>(
         &'a self,
         request: &'a GetDeckProfile,
@@ -637,6 +1099,20 @@ where
         Box::pin(DeckService::import_deck_cards(self, request))
     }
 
+    fn move_deck_cards<'a>(
+        &'a self,
+        request: &'a MoveDeckCards,
+    ) -> BoxFuture<'a, Result<(), MoveDeckCardsError>> {
+        Box::pin(DeckService::move_deck_cards(self, request))
+    }
+
+    fn bulk_add_deck_cards<'a>(
+        &'a self,
+        request: &'a BulkAddDeckCards,
+    ) -> BoxFuture<'a, Result<Vec<DeckCard>, BulkAddDeckCardsError>> {
+        Box::pin(DeckService::bulk_add_deck_cards(self, request))
+    }
+
     fn import_archidekt_deck<'a>(
         &'a self,
         user_id: uuid::Uuid,
@@ -657,13 +1133,41 @@ where
         ))
     }
 
+    fn find_orphaned_deck_cards(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> BoxFuture<'_, Result<Vec<OrphanedDeckCard>, OrphanedDeckCardsError>> {
+        Box::pin(DeckService::find_orphaned_deck_cards(self, user_id))
+    }
+
+    fn repair_orphaned_deck_cards(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> BoxFuture<'_, Result<Vec<OrphanedDeckCard>, OrphanedDeckCardsError>> {
+        Box::pin(DeckService::repair_orphaned_deck_cards(self, user_id))
+    }
+
+    fn decks_affected_by_banlist_changes(
+        &self,
+        since: DateTime<Utc>,
+    ) -> BoxFuture<'_, Result<Vec<BannedDeckCard>, anyhow::Error>> {
+        Box::pin(DeckService::decks_affected_by_banlist_changes(self, since))
+    }
+
     fn clone_deck<'a>(
         &'a self,
         request: &'a CloneDeck,
-    ) -> BoxFuture<'a, Result<uuid::Uuid, CloneDeckError>> {
+    ) -> BoxFuture<'a, Result<DeckProfile, CloneDeckError>> {
         Box::pin(DeckService::clone_deck(self, request))
     }
 
+    fn import_shared_deck<'a>(
+        &'a self,
+        request: &'a ImportSharedDeck,
+    ) -> BoxFuture<'a, Result<uuid::Uuid, CloneDeckError>> {
+        Box::pin(DeckService::import_shared_deck(self, request))
+    }
+
     fn share_deck<'a>(
         &'a self,
         request: &'a GetDeckProfile,
@@ -678,10 +1182,66 @@ where
         Box::pin(DeckService::unshare_deck(self, request))
     }
 
+    fn set_deck_labels<'a>(
+        &'a self,
+        request: &'a SetDeckLabels,
+    ) -> BoxFuture<'a, Result<Vec<String>, SetDeckLabelsError>> {
+        Box::pin(DeckService::set_deck_labels(self, request))
+    }
+
+    fn export_deck_json<'a>(
+        &'a self,
+        request: &'a GetDeckProfile,
+    ) -> BoxFuture<'a, Result<DeckExport, ExportDeckJsonError>> {
+        Box::pin(DeckService::export_deck_json(self, request))
+    }
+
+    fn import_deck_json<'a>(
+        &'a self,
+        request: &'a ImportDeckJson,
+    ) -> BoxFuture<'a, Result<ImportDeckJsonResult, ImportDeckJsonError>> {
+        Box::pin(DeckService::import_deck_json(self, request))
+    }
+
+    fn import_deck<'a>(
+        &'a self,
+        request: &'a ImportDecklist,
+    ) -> BoxFuture<'a, Result<ImportReport, ImportDecklistError>> {
+        Box::pin(DeckService::import_deck(self, request))
+    }
+
+    fn set_owned<'a>(
+        &'a self,
+        request: &'a SetOwnedCard,
+    ) -> BoxFuture<'a, Result<(), SetOwnedCardError>> {
+        Box::pin(DeckService::set_owned(self, request))
+    }
+
+    fn get_owned(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> BoxFuture<'_, Result<Vec<OwnedCard>, GetOwnedCardsError>> {
+        Box::pin(DeckService::get_owned(self, user_id))
+    }
+
+    fn deck_missing_cards<'a>(
+        &'a self,
+        request: &'a GetDeckProfile,
+    ) -> BoxFuture<'a, Result<Vec<MissingDeckCard>, DeckMissingCardsError>> {
+        Box::pin(DeckService::deck_missing_cards(self, request))
+    }
+
     fn get_shared_deck(
         &self,
         token: uuid::Uuid,
     ) -> BoxFuture<'_, Result<SharedDeck, GetSharedDeckError>> {
         Box::pin(DeckService::get_shared_deck(self, token))
     }
+
+    fn suggest_deck_name<'a>(
+        &'a self,
+        commander_id: &'a GetScryfallData,
+    ) -> BoxFuture<'a, Result<Vec<String>, GetCardError>> {
+        Box::pin(DeckService::suggest_deck_name(self, commander_id))
+    }
 }
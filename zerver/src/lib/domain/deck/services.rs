@@ -1,43 +1,60 @@
 use std::{collections::HashMap, fmt::Debug};
 
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::domain::{
     card::{
         models::synergy::SynergyPayload,
         ports::{CardRepository, DeckServeContext},
-        requests::get_scryfall_data::ScryfallDataIds,
+        requests::{
+            get_card::GetCardError,
+            get_scryfall_data::{GetScryfallData, ScryfallDataIds},
+        },
     },
     deck::{
         models::{
             deck::{
                 clear_deck_suppressions::ClearDeckSuppressionsError,
                 clone_deck::CloneDeckError,
+                collection::{DeckMissingCardsError, GetOwnedCardsError, SetOwnedCardError},
                 create_deck_profile::CreateDeckProfileError,
+                deck_json::{ExportDeckJsonError, ImportDeckJsonError},
                 delete_deck::DeleteDeckError,
                 get_deck::GetDeckError,
                 get_deck_profile::GetDeckProfileError,
                 get_deck_tokens::GetDeckTokensError,
                 import_archidekt::ArchidektCard,
+                import_decklist::ImportDecklistError,
                 search_deck_cards::SearchDeckCardsError,
+                set_deck_labels::SetDeckLabelsError,
                 share_deck::{GetSharedDeckError, ShareDeckError, SharedDeck},
                 skip_deck_card::SkipDeckCardError,
                 update_deck_profile::UpdateDeckProfileError,
             },
             deck_card::{
-                create_deck_card::CreateDeckCardError, delete_deck_card::DeleteDeckCardError,
-                import_deck_cards::ImportDeckCardsError, update_deck_card::UpdateDeckCardError,
+                bulk_add_deck_cards::BulkAddDeckCardsError,
+                create_deck_card::CreateDeckCardError,
+                delete_deck_card::DeleteDeckCardError,
+                import_deck_cards::ImportDeckCardsError,
+                move_deck_cards::{CopyCap, MoveDeckCardsError},
+                orphaned_deck_cards::OrphanedDeckCardsError,
+                update_deck_card::UpdateDeckCardError,
             },
         },
         ports::{DeckRepository, DeckService},
     },
 };
 use zwipe_core::domain::{
-    card::{Card, search_card::card_filter::CardQuery},
+    card::{Card, scryfall_data::colors::Colors, search_card::card_filter::CardQuery},
     deck::{
-        Board, Deck, DeckCard, DeckEntry, ImportMode,
+        BannedDeckCard, Board, Deck, DeckCard, DeckEntry, DeckExport, DeckStats, DecklistSection,
+        ExportFormat, Format, ImportMode, LegalityReport, MissingDeckCard, OrphanedDeckCard,
+        OwnedCard, ProxySheet,
         deck_profile::DeckProfile,
+        legality_report, legality_report_for,
         requests::{
+            bulk_add_deck_cards::BulkAddDeckCards,
             clear_deck_suppressions::ClearDeckSuppressions,
             clone_deck::CloneDeck,
             create_deck_card::CreateDeckCard,
@@ -50,10 +67,18 @@ use zwipe_core::domain::{
                 ImportDeckCards, ImportDeckCardsResult, ImportedCard, UnresolvedCard,
                 dfc_front_face, entry_front_face,
             },
+            import_deck_json::{ImportDeckJson, ImportDeckJsonResult},
+            import_decklist::{ImportDecklist, ImportReport},
+            import_shared_deck::ImportSharedDeck,
+            move_deck_cards::MoveDeckCards,
+            set_deck_labels::SetDeckLabels,
+            set_owned_card::SetOwnedCard,
             skip_deck_card::SkipDeckCard,
             update_deck_card::UpdateDeckCard,
             update_deck_profile::UpdateDeckProfile,
         },
+        suggest_deck_names,
+        validate_deck::max_copies_of,
     },
 };
 
@@ -62,6 +87,12 @@ use crate::domain::deck::{
     UNVERIFIED_MAX_DECKS_PER_USER,
 };
 
+/// Whether batch row on `row_board` (a board name) takes a card bound for
+/// `board`: the sideboard keeps its own row, deck and maybeboard share one.
+fn same_row(row_board: &str, board: Board) -> bool {
+    (row_board == Board::Sideboard.display_name()) == board.is_sideboard()
+}
+
 /// Deck service implementation handling deck building and card management operations.
 ///
 /// This service coordinates:
@@ -96,6 +127,92 @@ where
             card_repo,
         }
     }
+
+    /// Refuses a card for the active deck whose color identity isn't a
+    /// subset of the commander's (joined with any partner and background),
+    /// the same subset test as the `color_identity_within` search filter.
+    /// Colorless cards pass trivially; basic lands always pass. Decks with no
+    /// commander, formats without the rule, and the side/maybeboards aren't
+    /// checked.
+    async fn check_color_identity(
+        &self,
+        deck_profile: &DeckProfile,
+        request: &CreateDeckCard,
+    ) -> Result<(), CreateDeckCardError> {
+        let checks = deck_profile
+            .format
+            .is_some_and(|format| format.checks_color_identity());
+        let Some(commander_id) = deck_profile.commander_id.filter(|_| checks) else {
+            return Ok(());
+        };
+        if !request.board.is_active() {
+            return Ok(());
+        }
+
+        let ids: ScryfallDataIds = [
+            Some(request.scryfall_data_id),
+            Some(commander_id),
+            deck_profile.partner_commander_id,
+            deck_profile.background_id,
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        let mut fetched = self
+            .card_repo
+            .get_multiple_scryfall_data(&ids)
+            .await
+            .map_err(|e| CreateDeckCardError::Database(e.into()))?;
+        let Some(at) = fetched
+            .iter()
+            .position(|sd| sd.id == request.scryfall_data_id)
+        else {
+            // unknown printing: leave it to the insert to reject
+            return Ok(());
+        };
+        let card = fetched.swap_remove(at);
+        // a commander whose printing is gone has no identity to hold cards to
+        if card.is_basic_land() || !fetched.iter().any(|sd| sd.id == commander_id) {
+            return Ok(());
+        }
+
+        let allowed: Colors = fetched
+            .iter()
+            .flat_map(|sd| sd.color_identity.iter().copied())
+            .collect();
+        if card.color_identity.iter().all(|c| allowed.contains(c)) {
+            Ok(())
+        } else {
+            Err(CreateDeckCardError::OutsideColorIdentity)
+        }
+    }
+
+    /// Refuses a deck copy once `user_id` is at the deck-count limit for
+    /// their verification state, as create_deck_profile does.
+    async fn check_copy_deck_limit(
+        &self,
+        user_id: Uuid,
+        email_verified: bool,
+    ) -> Result<(), CloneDeckError> {
+        let deck_count = self
+            .deck_repo
+            .count_decks_by_user(user_id)
+            .await
+            .map_err(CloneDeckError::Database)?;
+        let deck_limit = if email_verified {
+            MAX_DECKS_PER_USER
+        } else {
+            UNVERIFIED_MAX_DECKS_PER_USER
+        };
+        if deck_count >= deck_limit {
+            return Err(if email_verified {
+                CloneDeckError::LimitReached
+            } else {
+                CloneDeckError::UnverifiedLimitReached
+            });
+        }
+        Ok(())
+    }
 }
 
 impl<DR, CR> DeckService for Service<DR, CR>
@@ -159,6 +276,7 @@ where
                 return Err(CreateDeckCardError::IsCommander);
             }
         }
+        self.check_color_identity(&deck_profile, request).await?;
         let card_count = self
             .deck_repo
             .count_cards_in_deck(request.deck_id)
@@ -206,17 +324,26 @@ where
         let scryfall_data_ids = ScryfallDataIds::from(deck_cards.as_slice());
         let cards = self.card_repo.get_cards(&scryfall_data_ids).await?;
 
-        let mut deck_card_map: HashMap<Uuid, DeckCard> = deck_cards
-            .into_iter()
-            .map(|dc| (dc.scryfall_data_id, dc))
-            .collect();
+        // A printing can back two rows: its main copy and its sideboard copy.
+        let mut deck_card_map: HashMap<Uuid, Vec<DeckCard>> = HashMap::new();
+        for dc in deck_cards {
+            deck_card_map
+                .entry(dc.scryfall_data_id)
+                .or_default()
+                .push(dc);
+        }
 
         let entries: Vec<DeckEntry> = cards
             .into_iter()
-            .filter_map(|card| {
+            .flat_map(|card| {
                 deck_card_map
                     .remove(&card.scryfall_data.id)
-                    .map(|deck_card| DeckEntry { card, deck_card })
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(move |deck_card| DeckEntry {
+                        card: card.clone(),
+                        deck_card,
+                    })
             })
             .collect();
 
@@ -276,6 +403,32 @@ where
         Ok(deck)
     }
 
+    async fn full_legality_report(
+        &self,
+        request: &GetDeckProfile,
+    ) -> Result<LegalityReport, GetDeckError> {
+        let deck = self.get_deck(request).await?;
+        Ok(legality_report(
+            &deck.deck_profile,
+            &deck.entries,
+            &deck.command_zone(),
+        ))
+    }
+
+    async fn validate_deck(
+        &self,
+        request: &GetDeckProfile,
+        format: Format,
+    ) -> Result<LegalityReport, GetDeckError> {
+        let deck = self.get_deck(request).await?;
+        Ok(legality_report_for(
+            format,
+            &deck.deck_profile,
+            &deck.entries,
+            &deck.command_zone(),
+        ))
+    }
+
     async fn search_deck_cards(
         &self,
         request: &GetDeckProfile,
@@ -374,6 +527,25 @@ where
         Ok((cards, synergy_warming))
     }
 
+    async fn proxy_sheet(&self, request: &GetDeckProfile) -> Result<ProxySheet, GetDeckError> {
+        let deck = self.get_deck(request).await?;
+        Ok(ProxySheet::from_deck(&deck))
+    }
+
+    async fn export_deck(
+        &self,
+        request: &GetDeckProfile,
+        format: ExportFormat,
+    ) -> Result<String, GetDeckError> {
+        let deck = self.get_deck(request).await?;
+        Ok(format.render(&deck))
+    }
+
+    async fn deck_stats(&self, request: &GetDeckProfile) -> Result<DeckStats, GetDeckError> {
+        let deck = self.get_deck(request).await?;
+        Ok(DeckStats::new(&deck))
+    }
+
     async fn get_deck_tokens(
         &self,
         request: &GetDeckProfile,
@@ -508,10 +680,12 @@ where
                     .collect()
             };
 
-        // Classify lines, deduplicating by oracle_id (summing quantities).
+        // Classify lines, deduplicating by oracle_id and side (summing
+        // quantities): the sideboard keeps its own copy of a card.
         // Tuple: (scryfall_id, oracle_id, quantity, name, is_basic_land, board)
+        type Line = (Uuid, Uuid, i32, String, bool, String);
         let mut unresolved: Vec<UnresolvedCard> = Vec::new();
-        let mut insert_map: HashMap<Uuid, (Uuid, Uuid, i32, String, bool, String)> = HashMap::new();
+        let mut insert_map: HashMap<(Uuid, bool), Line> = HashMap::new();
 
         for line in &request.lines {
             let key = line.card_name.to_lowercase();
@@ -544,7 +718,7 @@ where
                 let is_basic_land = card.scryfall_data.is_basic_land();
                 let board = line.board.display_name().to_string();
                 insert_map
-                    .entry(oracle_id)
+                    .entry((oracle_id, line.board.is_sideboard()))
                     .and_modify(|(_, _, qty, _, _, _)| *qty += line.quantity)
                     .or_insert_with(|| {
                         (
@@ -656,6 +830,160 @@ where
         })
     }
 
+    async fn move_deck_cards(&self, request: &MoveDeckCards) -> Result<(), MoveDeckCardsError> {
+        // Ownership of both decks
+        self.get_deck_profile(&GetDeckProfile::new(request.user_id, request.from_deck_id))
+            .await?;
+        let destination = self
+            .get_deck_profile(&GetDeckProfile::new(request.user_id, request.to_deck_id))
+            .await?;
+
+        // The destination's cap on each moved card; the repository checks the
+        // merged quantities against these inside its transaction.
+        let ids: ScryfallDataIds = request.cards.iter().map(|(id, _)| *id).collect();
+        let caps: HashMap<Uuid, CopyCap> = self
+            .card_repo
+            .get_cards(&ids)
+            .await
+            .map_err(|e| MoveDeckCardsError::Database(e.into()))?
+            .into_iter()
+            .filter_map(|card| {
+                max_copies_of(&destination, &card).map(|max| {
+                    (
+                        card.scryfall_data.id,
+                        CopyCap {
+                            name: card.scryfall_data.name,
+                            max,
+                        },
+                    )
+                })
+            })
+            .collect();
+
+        self.deck_repo.move_deck_cards(request, &caps).await
+    }
+
+    async fn bulk_add_deck_cards(
+        &self,
+        request: &BulkAddDeckCards,
+    ) -> Result<Vec<DeckCard>, BulkAddDeckCardsError> {
+        let deck_profile = self
+            .get_deck_profile(&GetDeckProfile::new(request.user_id, request.deck_id))
+            .await?;
+
+        // Every card must resolve to a printing with an oracle id.
+        let ids: ScryfallDataIds = request.cards.iter().map(|(id, _)| *id).collect();
+        let cards = self
+            .card_repo
+            .get_cards(&ids)
+            .await
+            .map_err(|e| BulkAddDeckCardsError::Database(e.into()))?;
+        let mut oracle_ids: HashMap<Uuid, Uuid> = HashMap::new();
+        for (scryfall_data_id, _) in &request.cards {
+            let oracle_id = cards
+                .iter()
+                .find(|card| card.scryfall_data.id == *scryfall_data_id)
+                .and_then(|card| card.scryfall_data.oracle_id)
+                .ok_or(BulkAddDeckCardsError::CardNotFound(*scryfall_data_id))?;
+            oracle_ids.insert(*scryfall_data_id, oracle_id);
+        }
+
+        // Command zone cards can't be added, and on the active board every
+        // card must fit the command zone's color identity (as for a single
+        // add; see check_color_identity).
+        let cz_ids: ScryfallDataIds = [
+            deck_profile.commander_id,
+            deck_profile.partner_commander_id,
+            deck_profile.background_id,
+            deck_profile.signature_spell_id,
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        let command_zone = if cz_ids.is_empty() {
+            Vec::new()
+        } else {
+            self.card_repo
+                .get_multiple_scryfall_data(&cz_ids)
+                .await
+                .map_err(|e| BulkAddDeckCardsError::Database(e.into()))?
+        };
+        if let Some(card) = cards.iter().find(|card| {
+            card.scryfall_data
+                .oracle_id
+                .is_some_and(|oracle| command_zone.iter().any(|sd| sd.oracle_id == Some(oracle)))
+        }) {
+            return Err(BulkAddDeckCardsError::IsCommander {
+                name: card.scryfall_data.name.clone(),
+            });
+        }
+        let checks = request.board.is_active()
+            && deck_profile
+                .format
+                .is_some_and(|format| format.checks_color_identity());
+        if checks
+            && let Some(commander_id) = deck_profile.commander_id
+            && command_zone.iter().any(|sd| sd.id == commander_id)
+        {
+            let allowed: Colors = command_zone
+                .iter()
+                .filter(|sd| Some(sd.id) != deck_profile.signature_spell_id)
+                .flat_map(|sd| sd.color_identity.iter().copied())
+                .collect();
+            if let Some(card) = cards.iter().find(|card| {
+                !card.scryfall_data.is_basic_land()
+                    && !card
+                        .scryfall_data
+                        .color_identity
+                        .iter()
+                        .all(|c| allowed.contains(c))
+            }) {
+                return Err(BulkAddDeckCardsError::OutsideColorIdentity {
+                    name: card.scryfall_data.name.clone(),
+                });
+            }
+        }
+
+        let card_count = self
+            .deck_repo
+            .count_cards_in_deck(request.deck_id)
+            .await
+            .map_err(BulkAddDeckCardsError::Database)?;
+        let card_limit = if request.email_verified {
+            MAX_CARDS_PER_DECK
+        } else {
+            UNVERIFIED_MAX_CARDS_PER_DECK
+        };
+        if card_count + request.total_quantity() > card_limit {
+            return Err(if request.email_verified {
+                BulkAddDeckCardsError::LimitReached
+            } else {
+                BulkAddDeckCardsError::UnverifiedLimitReached
+            });
+        }
+
+        // Caps apply even to maybeboard adds: a copy merged into an existing
+        // deck entry counts. The repository checks the merged quantities.
+        let caps: HashMap<Uuid, CopyCap> = cards
+            .into_iter()
+            .filter_map(|card| {
+                max_copies_of(&deck_profile, &card).map(|max| {
+                    (
+                        card.scryfall_data.id,
+                        CopyCap {
+                            name: card.scryfall_data.name,
+                            max,
+                        },
+                    )
+                })
+            })
+            .collect();
+
+        self.deck_repo
+            .bulk_add_deck_cards(request, &oracle_ids, &caps)
+            .await
+    }
+
     async fn import_archidekt_deck(
         &self,
         user_id: Uuid,
@@ -851,10 +1179,48 @@ where
         })
     }
 
+    // =========
+    //  orphans
+    // =========
+    async fn find_orphaned_deck_cards(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<OrphanedDeckCard>, OrphanedDeckCardsError> {
+        // Scoped to the user's own decks by the query itself — no per-deck
+        // ownership check needed.
+        self.deck_repo.find_orphaned_deck_cards(user_id).await
+    }
+
+    async fn repair_orphaned_deck_cards(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<OrphanedDeckCard>, OrphanedDeckCardsError> {
+        let orphans = self.deck_repo.repair_orphaned_deck_cards(user_id).await?;
+        let unrepaired = orphans.iter().filter(|o| !o.is_repairable()).count();
+        if !orphans.is_empty() {
+            tracing::info!(
+                %user_id,
+                remapped = orphans.len() - unrepaired,
+                unrepaired,
+                "repaired orphaned deck cards"
+            );
+        }
+        Ok(orphans)
+    }
+
+    async fn decks_affected_by_banlist_changes(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<BannedDeckCard>, anyhow::Error> {
+        self.deck_repo
+            .decks_affected_by_banlist_changes(since)
+            .await
+    }
+
     // =======
     //  clone
     // =======
-    async fn clone_deck(&self, request: &CloneDeck) -> Result<Uuid, CloneDeckError> {
+    async fn clone_deck(&self, request: &CloneDeck) -> Result<DeckProfile, CloneDeckError> {
         // 1. Verify the source exists and is owned by the caller. get_deck_profile
         //    on the repo already enforces ownership (returns Forbidden on mismatch)
         //    and existence (returns NotFound).
@@ -868,27 +1234,48 @@ where
         }
 
         // 2. Enforce the same deck-count limit as create_deck_profile.
-        let deck_count = self
+        self.check_copy_deck_limit(request.user_id, request.email_verified)
+            .await?;
+
+        // 3. Delegate to the repo for the transactional copy.
+        let new_deck_id = self
             .deck_repo
-            .count_decks_by_user(request.user_id)
+            .clone_deck(request.source_deck_id, &request.new_name, request.user_id)
+            .await?;
+
+        // 4. Read the copy back; it only exists committed, with its own
+        //    timestamps and card count.
+        self.deck_repo
+            .get_deck_profile(&GetDeckProfile::new(request.user_id, new_deck_id))
             .await
-            .map_err(CloneDeckError::Database)?;
-        let deck_limit = if request.email_verified {
-            MAX_DECKS_PER_USER
-        } else {
-            UNVERIFIED_MAX_DECKS_PER_USER
-        };
-        if deck_count >= deck_limit {
-            return Err(if request.email_verified {
-                CloneDeckError::LimitReached
-            } else {
-                CloneDeckError::UnverifiedLimitReached
-            });
-        }
+            .map_err(|e| CloneDeckError::Database(e.into()))
+    }
 
-        // 3. Delegate to the repo for the transactional copy.
+    async fn import_shared_deck(&self, request: &ImportSharedDeck) -> Result<Uuid, CloneDeckError> {
+        // The token is the capability, as for the public read; the owner id
+        // it resolves to only serves to load the source profile.
+        let (source_deck_id, owner_id) = self
+            .deck_repo
+            .get_deck_id_by_share_token(request.share_token)
+            .await
+            .map_err(CloneDeckError::Database)?
+            .ok_or(CloneDeckError::SourceNotFound)?;
+
+        self.check_copy_deck_limit(request.user_id, request.email_verified)
+            .await?;
+
+        let new_name = match &request.new_name {
+            Some(name) => name.clone(),
+            None => {
+                self.deck_repo
+                    .get_deck_profile(&GetDeckProfile::new(owner_id, source_deck_id))
+                    .await
+                    .map_err(CloneDeckError::GetSource)?
+                    .name
+            }
+        };
         self.deck_repo
-            .clone_deck(request.source_deck_id, &request.new_name, request.user_id)
+            .copy_shared_deck(source_deck_id, &new_name, request.user_id)
             .await
     }
 
@@ -913,6 +1300,418 @@ where
         self.deck_repo.clear_share_token(request.deck_id).await
     }
 
+    // ========
+    //  labels
+    // ========
+    async fn set_deck_labels(
+        &self,
+        request: &SetDeckLabels,
+    ) -> Result<Vec<String>, SetDeckLabelsError> {
+        let profile = GetDeckProfile::new(request.user_id, request.deck_id);
+        self.get_deck_profile(&profile).await.map_err(|e| match e {
+            GetDeckProfileError::Forbidden => SetDeckLabelsError::Forbidden,
+            GetDeckProfileError::NotFound => SetDeckLabelsError::NotFound,
+            other => SetDeckLabelsError::Database(other.into()),
+        })?;
+        self.deck_repo.set_deck_labels(request).await
+    }
+
+    // =============
+    //  json backup
+    // =============
+    async fn export_deck_json(
+        &self,
+        request: &GetDeckProfile,
+    ) -> Result<DeckExport, ExportDeckJsonError> {
+        let profile = self.get_deck_profile(request).await.map_err(|e| match e {
+            GetDeckProfileError::Forbidden => ExportDeckJsonError::Forbidden,
+            GetDeckProfileError::NotFound => ExportDeckJsonError::NotFound,
+            other => ExportDeckJsonError::Database(other.into()),
+        })?;
+        let cards = self
+            .deck_repo
+            .get_deck_cards(request)
+            .await
+            .map_err(|e| ExportDeckJsonError::Database(e.into()))?;
+
+        // The command zone is stored by printing; the export wants oracle ids.
+        let cz_ids: ScryfallDataIds = [
+            profile.commander_id,
+            profile.partner_commander_id,
+            profile.background_id,
+            profile.signature_spell_id,
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        let oracle_ids: HashMap<Uuid, Uuid> = if cz_ids.is_empty() {
+            HashMap::new()
+        } else {
+            self.card_repo
+                .get_multiple_scryfall_data(&cz_ids)
+                .await
+                .map_err(|e| ExportDeckJsonError::Database(e.into()))?
+                .into_iter()
+                .filter_map(|sd| sd.oracle_id.map(|oracle_id| (sd.id, oracle_id)))
+                .collect()
+        };
+
+        Ok(DeckExport::new(&profile, &cards, &oracle_ids))
+    }
+
+    async fn import_deck_json(
+        &self,
+        request: &ImportDeckJson,
+    ) -> Result<ImportDeckJsonResult, ImportDeckJsonError> {
+        let user_id = request.profile.user_id;
+        let email_verified = request.profile.email_verified;
+        let printings = self
+            .deck_repo
+            .preferred_printings(&request.oracle_ids())
+            .await
+            .map_err(ImportDeckJsonError::Database)?;
+        let mut unresolved: Vec<Uuid> = Vec::new();
+
+        // deck_cards holds one main and one sideboard row per (deck, oracle):
+        // merge repeated oracle ids on the same side, summing quantities onto
+        // the first board seen.
+        // Tuple: (scryfall_data_id, oracle_id, quantity, board)
+        let mut batch: Vec<(Uuid, Uuid, i32, String)> = Vec::new();
+        for card in &request.cards {
+            let Some(&printing) = printings.get(&card.oracle_id) else {
+                unresolved.push(card.oracle_id);
+                continue;
+            };
+            match batch
+                .iter_mut()
+                .find(|(_, oid, _, b)| *oid == card.oracle_id && same_row(b, card.board))
+            {
+                Some((_, _, qty, _)) => *qty += *card.quantity,
+                None => batch.push((
+                    printing,
+                    card.oracle_id,
+                    *card.quantity,
+                    card.board.display_name().to_string(),
+                )),
+            }
+        }
+
+        // Check the card limit (deck board only) before creating anything, so
+        // an oversized export never leaves a half-restored deck behind.
+        let deck_total: i64 = batch
+            .iter()
+            .filter(|(_, _, _, board)| board == Board::Deck.display_name())
+            .map(|(_, _, qty, _)| i64::from(*qty))
+            .sum();
+        let card_limit = if email_verified {
+            MAX_CARDS_PER_DECK
+        } else {
+            UNVERIFIED_MAX_CARDS_PER_DECK
+        };
+        if deck_total > card_limit {
+            return Err(if email_verified {
+                ImportDeckJsonError::LimitReached
+            } else {
+                ImportDeckJsonError::UnverifiedLimitReached
+            });
+        }
+
+        let mut profile = request.profile.clone();
+        for (oracle_id, slot) in [
+            (request.commander, &mut profile.commander_id),
+            (request.partner_commander, &mut profile.partner_commander_id),
+            (request.background, &mut profile.background_id),
+            (request.signature_spell, &mut profile.signature_spell_id),
+        ] {
+            if let Some(oracle_id) = oracle_id {
+                *slot = printings.get(&oracle_id).copied();
+                if slot.is_none() {
+                    unresolved.push(oracle_id);
+                }
+            }
+        }
+        let deck_id = self.create_deck_profile(&profile).await?.id;
+
+        if !request.labels.is_empty() {
+            let labels = SetDeckLabels {
+                user_id,
+                deck_id,
+                labels: request.labels.clone(),
+            };
+            self.deck_repo
+                .set_deck_labels(&labels)
+                .await
+                .map_err(|e| ImportDeckJsonError::Database(e.into()))?;
+        }
+        let insert = ImportDeckCards {
+            user_id,
+            deck_id,
+            lines: Vec::new(),
+            email_verified,
+            mode: ImportMode::Add,
+        };
+        self.deck_repo
+            .bulk_create_deck_cards(&insert, &batch)
+            .await
+            .map_err(|e| ImportDeckJsonError::Database(e.into()))?;
+
+        let deck = self
+            .get_deck_profile(&GetDeckProfile::new(user_id, deck_id))
+            .await
+            .map_err(|e| ImportDeckJsonError::Database(e.into()))?;
+        Ok(ImportDeckJsonResult { deck, unresolved })
+    }
+
+    async fn import_deck(
+        &self,
+        request: &ImportDecklist,
+    ) -> Result<ImportReport, ImportDecklistError> {
+        use std::collections::HashSet;
+
+        let user_id = request.profile.user_id;
+        let email_verified = request.profile.email_verified;
+
+        // Resolve every name in one batch, and every named printing in another.
+        let names: Vec<String> = request
+            .lines
+            .iter()
+            .map(|line| line.name.to_lowercase())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let named = self
+            .card_repo
+            .find_cards_by_exact_names(&names)
+            .await
+            .map_err(|e| ImportDecklistError::Database(e.into()))?;
+        let printings: Vec<(String, String)> = request
+            .lines
+            .iter()
+            .filter_map(|line| Some((line.set.clone()?, line.collector_number.clone()?)))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let printed = if printings.is_empty() {
+            Vec::new()
+        } else {
+            self.card_repo
+                .find_cards_by_printings(&printings)
+                .await
+                .map_err(|e| ImportDecklistError::Database(e.into()))?
+        };
+
+        // Index by full name, and double-faced cards also by front face. Two
+        // cards sharing a front face make that front face ambiguous.
+        let mut by_name: HashMap<String, &Card> = HashMap::new();
+        let mut by_front: HashMap<String, Vec<&Card>> = HashMap::new();
+        for card in &named {
+            let full = card.scryfall_data.name.to_lowercase();
+            if let Some(front) = dfc_front_face(&full) {
+                by_front.entry(front.to_string()).or_default().push(card);
+            }
+            by_name.insert(full, card);
+        }
+        let resolve = |name: &str| -> Result<&Card, &'static str> {
+            let key = name.to_lowercase();
+            if let Some(card) = by_name.get(&key) {
+                return Ok(card);
+            }
+            let front = entry_front_face(&key);
+            if let Some(card) = by_name.get(front) {
+                return Ok(card);
+            }
+            match by_front.get(front).map(Vec::as_slice) {
+                Some([card]) => Ok(card),
+                Some([_, _, ..]) => Err("ambiguous: more than one card has that name"),
+                _ => Err("not found"),
+            }
+        };
+
+        let mut imported: Vec<ImportedCard> = Vec::new();
+        let mut unresolved: Vec<UnresolvedCard> = Vec::new();
+        let mut command_zone: Vec<&Card> = Vec::new();
+        // deck_cards holds one main and one sideboard row per (deck, oracle):
+        // merge repeated oracle ids on the same side, summing quantities onto
+        // the first board seen.
+        // Tuple: (scryfall_data_id, oracle_id, quantity, board)
+        let mut batch: Vec<(Uuid, Uuid, i32, String)> = Vec::new();
+        for line in &request.lines {
+            let card = match resolve(&line.name) {
+                Ok(card) => card,
+                Err(reason) => {
+                    unresolved.push(UnresolvedCard {
+                        name: line.name.clone(),
+                        reason: reason.to_string(),
+                    });
+                    continue;
+                }
+            };
+            let Some(oracle_id) = card.scryfall_data.oracle_id else {
+                unresolved.push(UnresolvedCard {
+                    name: line.name.clone(),
+                    reason: "missing oracle id".to_string(),
+                });
+                continue;
+            };
+            // The named printing, when the line gives one and it's this card;
+            // otherwise the name's latest printing.
+            let card = printed
+                .iter()
+                .find(|p| {
+                    p.scryfall_data.oracle_id == Some(oracle_id)
+                        && line.set.as_deref() == Some(p.scryfall_data.set.to_lowercase().as_str())
+                        && line.collector_number.as_deref()
+                            == Some(p.scryfall_data.collector_number.as_str())
+                })
+                .unwrap_or(card);
+            let board = match line.section {
+                DecklistSection::Commander => {
+                    if !command_zone
+                        .iter()
+                        .any(|c| c.scryfall_data.oracle_id == Some(oracle_id))
+                    {
+                        command_zone.push(card);
+                    }
+                    continue;
+                }
+                DecklistSection::Board(board) => board,
+            };
+            match batch
+                .iter_mut()
+                .find(|(_, oid, _, b)| *oid == oracle_id && same_row(b, board))
+            {
+                Some((_, _, qty, _)) => *qty += line.quantity,
+                None => batch.push((
+                    card.scryfall_data.id,
+                    oracle_id,
+                    line.quantity,
+                    board.display_name().to_string(),
+                )),
+            }
+        }
+
+        // Fill the command zone: a Background goes to its slot, the first
+        // other card is the commander and the second its partner.
+        let mut profile = request.profile.clone();
+        for card in &command_zone {
+            let is_background = card
+                .scryfall_data
+                .type_line
+                .as_deref()
+                .is_some_and(|t| t.contains("Background"));
+            let slot = if is_background {
+                &mut profile.background_id
+            } else if profile.commander_id.is_none() {
+                &mut profile.commander_id
+            } else {
+                &mut profile.partner_commander_id
+            };
+            if slot.is_some() {
+                unresolved.push(UnresolvedCard {
+                    name: card.scryfall_data.name.clone(),
+                    reason: "command zone is full".to_string(),
+                });
+                continue;
+            }
+            *slot = Some(card.scryfall_data.id);
+            imported.push(ImportedCard {
+                name: card.scryfall_data.name.clone(),
+                quantity: 1,
+            });
+        }
+        // Copies of the command zone don't also go in the deck.
+        let command_oracles: HashSet<Uuid> = command_zone
+            .iter()
+            .filter_map(|c| c.scryfall_data.oracle_id)
+            .collect();
+        batch.retain(|(_, oid, _, _)| !command_oracles.contains(oid));
+
+        // Check the card limit (deck board only) before creating anything, so
+        // an oversized list never leaves a half-imported deck behind.
+        let deck_total: i64 = batch
+            .iter()
+            .filter(|(_, _, _, board)| board == Board::Deck.display_name())
+            .map(|(_, _, qty, _)| i64::from(*qty))
+            .sum();
+        let card_limit = if email_verified {
+            MAX_CARDS_PER_DECK
+        } else {
+            UNVERIFIED_MAX_CARDS_PER_DECK
+        };
+        if deck_total > card_limit {
+            return Err(if email_verified {
+                ImportDecklistError::LimitReached
+            } else {
+                ImportDecklistError::UnverifiedLimitReached
+            });
+        }
+
+        let deck_id = self.create_deck_profile(&profile).await?.id;
+        let insert = ImportDeckCards {
+            user_id,
+            deck_id,
+            lines: Vec::new(),
+            email_verified,
+            mode: ImportMode::Add,
+        };
+        self.deck_repo
+            .bulk_create_deck_cards(&insert, &batch)
+            .await
+            .map_err(|e| ImportDecklistError::Database(e.into()))?;
+
+        let names_by_oracle: HashMap<Uuid, &str> = named
+            .iter()
+            .filter_map(|c| {
+                c.scryfall_data
+                    .oracle_id
+                    .map(|oid| (oid, c.scryfall_data.name.as_str()))
+            })
+            .collect();
+        imported.extend(batch.iter().map(|(_, oid, qty, _)| {
+            ImportedCard {
+                name: names_by_oracle
+                    .get(oid)
+                    .map_or_else(String::new, |name| (*name).to_string()),
+                quantity: *qty,
+            }
+        }));
+
+        let deck = self
+            .get_deck_profile(&GetDeckProfile::new(user_id, deck_id))
+            .await
+            .map_err(|e| ImportDecklistError::Database(e.into()))?;
+        Ok(ImportReport {
+            deck,
+            imported,
+            unresolved,
+        })
+    }
+
+    // ============
+    //  collection
+    // ============
+    async fn set_owned(&self, request: &SetOwnedCard) -> Result<(), SetOwnedCardError> {
+        self.deck_repo.set_owned(request).await
+    }
+
+    async fn get_owned(&self, user_id: Uuid) -> Result<Vec<OwnedCard>, GetOwnedCardsError> {
+        self.deck_repo.get_owned(user_id).await
+    }
+
+    async fn deck_missing_cards(
+        &self,
+        request: &GetDeckProfile,
+    ) -> Result<Vec<MissingDeckCard>, DeckMissingCardsError> {
+        self.get_deck_profile(request).await.map_err(|e| match e {
+            GetDeckProfileError::Forbidden => DeckMissingCardsError::Forbidden,
+            GetDeckProfileError::NotFound => DeckMissingCardsError::NotFound,
+            other => DeckMissingCardsError::Database(other.into()),
+        })?;
+        self.deck_repo
+            .deck_missing_cards(request.user_id, request.deck_id)
+            .await
+    }
+
     async fn get_shared_deck(&self, token: Uuid) -> Result<SharedDeck, GetSharedDeckError> {
         // The token is the capability: resolving it yields the owner's id,
         // which satisfies the ownership checks along the normal get_deck path.
@@ -994,4 +1793,15 @@ where
             tokens,
         })
     }
+
+    // =============
+    //  suggestions
+    // =============
+    async fn suggest_deck_name(
+        &self,
+        commander_id: &GetScryfallData,
+    ) -> Result<Vec<String>, GetCardError> {
+        let commander = self.card_repo.get_card(commander_id).await?;
+        Ok(suggest_deck_names(&commander.scryfall_data))
+    }
 }
@@ -1,3 +1,4 @@
+use chrono::{DateTime, TimeDelta, Utc};
 #[cfg(feature = "zerver")]
 use thiserror::Error;
 
@@ -9,3 +10,42 @@ use thiserror::Error;
 #[derive(Debug, Error)]
 #[error("failed health check: {0}")]
 pub struct HealthCheckFailed(pub anyhow::Error);
+
+/// Opt-in readiness criterion: card data counts as fresh only while the last
+/// successful sync is at most `max_age` old.
+///
+/// Catches a server whose sync job has silently died and is serving ever
+/// older prices and legalities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncFreshness {
+    /// Oldest acceptable last successful sync.
+    pub max_age: TimeDelta,
+}
+
+impl SyncFreshness {
+    /// Creates a criterion allowing syncs up to `max_age` old.
+    pub fn new(max_age: TimeDelta) -> Self {
+        Self { max_age }
+    }
+
+    /// Whether a last successful sync at `last_success` is recent enough at
+    /// `now`. Never having synced is stale.
+    pub fn is_fresh(&self, last_success: Option<DateTime<Utc>>, now: DateTime<Utc>) -> bool {
+        last_success.is_some_and(|at| now.signed_duration_since(at) <= self.max_age)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn freshness_follows_the_last_successful_sync() {
+        let now = Utc::now();
+        let freshness = SyncFreshness::new(TimeDelta::days(8));
+        assert!(freshness.is_fresh(Some(now - TimeDelta::days(7)), now));
+        assert!(freshness.is_fresh(Some(now - TimeDelta::days(8)), now));
+        assert!(!freshness.is_fresh(Some(now - TimeDelta::days(9)), now));
+        assert!(!freshness.is_fresh(None, now));
+    }
+}
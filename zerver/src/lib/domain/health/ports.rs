@@ -4,18 +4,30 @@
 //! Health checks verify the service is operational and can communicate with dependencies.
 
 use crate::domain::{BoxFuture, health::models::HealthCheckFailed};
+use chrono::{DateTime, Utc};
 use std::future::Future;
 
 /// Database port for health check operations.
 pub trait HealthRepository: Clone + Send + Sync + 'static {
     /// Checks database connectivity and basic query operations.
     fn check_database(&self) -> impl Future<Output = Result<(), HealthCheckFailed>> + Send;
+
+    /// When the last successful (or partially successful) card sync ended;
+    /// `None` if none has.
+    fn get_last_successful_sync(
+        &self,
+    ) -> impl Future<Output = Result<Option<DateTime<Utc>>, HealthCheckFailed>> + Send;
 }
 
 /// Service port for health check business logic.
 pub trait HealthService: Clone + Send + Sync + 'static {
     /// Checks all service dependencies.
     fn check_database(&self) -> impl Future<Output = Result<(), HealthCheckFailed>> + Send;
+
+    /// Checks the last successful card sync is recent enough. Always passes
+    /// unless a [`SyncFreshness`](crate::domain::health::models::SyncFreshness)
+    /// criterion is configured.
+    fn check_sync_freshness(&self) -> impl Future<Output = Result<(), HealthCheckFailed>> + Send;
 }
 
 /// Object-safe wrapper used by `AppState` so the concrete service type stays
@@ -23,6 +35,9 @@ pub trait HealthService: Clone + Send + Sync + 'static {
 pub trait ErasedHealthService: Send + Sync + 'static {
     /// See [`HealthService::check_database`].
     fn check_database<'a>(&'a self) -> BoxFuture<'a, Result<(), HealthCheckFailed>>;
+
+    /// See [`HealthService::check_sync_freshness`].
+    fn check_sync_freshness<'a>(&'a self) -> BoxFuture<'a, Result<(), HealthCheckFailed>>;
 }
 
 impl<T> ErasedHealthService for T
@@ -32,4 +47,8 @@ where
     fn check_database<'a>(&'a self) -> BoxFuture<'a, Result<(), HealthCheckFailed>> {
         Box::pin(HealthService::check_database(self))
     }
+
+    fn check_sync_freshness<'a>(&'a self) -> BoxFuture<'a, Result<(), HealthCheckFailed>> {
+        Box::pin(HealthService::check_sync_freshness(self))
+    }
 }
@@ -1,26 +1,57 @@
-use crate::domain::health::ports::{HealthRepository, HealthService};
+use crate::domain::health::{
+    models::{HealthCheckFailed, SyncFreshness},
+    ports::{HealthRepository, HealthService},
+};
+use anyhow::anyhow;
+use chrono::Utc;
 
 /// Health check service implementation for monitoring system status.
 ///
 /// Provides health check endpoints for:
 /// - **Database connectivity**: Verifies PostgreSQL connection is alive
 /// - **API liveness**: Basic health check for load balancers/orchestrators
+/// - **Card data freshness** (opt-in): Verifies a sync succeeded recently
 ///
 /// Used by Kubernetes/Docker health probes and monitoring systems.
 #[derive(Debug, Clone)]
 pub struct Service<R: HealthRepository> {
     repo: R,
+    sync_freshness: Option<SyncFreshness>,
 }
 
 impl<R: HealthRepository> Service<R> {
     /// Creates a new health service with the provided repository.
     pub fn new(repo: R) -> Self {
-        Self { repo }
+        Self {
+            repo,
+            sync_freshness: None,
+        }
+    }
+
+    /// Makes readiness require a recent successful card sync; `None` (the
+    /// default) skips the check.
+    pub fn with_sync_freshness(mut self, sync_freshness: Option<SyncFreshness>) -> Self {
+        self.sync_freshness = sync_freshness;
+        self
     }
 }
 
 impl<R: HealthRepository> HealthService for Service<R> {
-    async fn check_database(&self) -> Result<(), super::models::HealthCheckFailed> {
+    async fn check_database(&self) -> Result<(), HealthCheckFailed> {
         self.repo.check_database().await
     }
+
+    async fn check_sync_freshness(&self) -> Result<(), HealthCheckFailed> {
+        let Some(sync_freshness) = self.sync_freshness else {
+            return Ok(());
+        };
+        let last_success = self.repo.get_last_successful_sync().await?;
+        if sync_freshness.is_fresh(last_success, Utc::now()) {
+            return Ok(());
+        }
+        Err(HealthCheckFailed(match last_success {
+            Some(at) => anyhow!("card data is stale: last successful sync ended {at}"),
+            None => anyhow!("card data is stale: no successful sync yet"),
+        }))
+    }
 }
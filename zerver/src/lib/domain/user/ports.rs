@@ -21,7 +21,7 @@ use crate::domain::user::models::{
     preferences::{GetPreferencesError, UpdatePreferencesError},
 };
 use zwipe_core::domain::user::{
-    User,
+    User, UserRole,
     models::hints::MarkHintShown,
     preferences::{UpdatePreferences, UserPreferences},
     requests::get_user::GetUser,
@@ -37,6 +37,12 @@ pub trait UserRepository: Clone + Send + Sync + 'static {
     /// Returns user data without password hash (use AuthRepository for that).
     fn get_user(&self, user_id: Uuid) -> impl Future<Output = Result<User, GetUserError>> + Send;
 
+    /// Retrieves a user's role.
+    fn get_user_role(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<UserRole, GetUserError>> + Send;
+
     /// Fetches display preferences for a user. Returns defaults if no row exists.
     fn get_preferences(
         &self,
@@ -70,6 +76,13 @@ pub trait UserService: Clone + Send + Sync + 'static {
         request: &GetUser,
     ) -> impl Future<Output = Result<User, GetUserError>> + Send;
 
+    /// Retrieves a user's role, read fresh so a revoked admin loses access at
+    /// once.
+    fn get_user_role(
+        &self,
+        user_id: Uuid,
+    ) -> impl Future<Output = Result<UserRole, GetUserError>> + Send;
+
     // ===============
     //  preferences
     // ===============
@@ -103,6 +116,9 @@ pub trait ErasedUserService: Send + Sync + 'static {
     /// See [`UserService::get_user`].
     fn get_user<'a>(&'a self, request: &'a GetUser) -> BoxFuture<'a, Result<User, GetUserError>>;
 
+    /// See [`UserService::get_user_role`].
+    fn get_user_role<'a>(&'a self, user_id: Uuid) -> BoxFuture<'a, Result<UserRole, GetUserError>>;
+
     /// See [`UserService::get_preferences`].
     fn get_preferences<'a>(
         &'a self,
@@ -130,6 +146,10 @@ where
         Box::pin(UserService::get_user(self, request))
     }
 
+    fn get_user_role<'a>(&'a self, user_id: Uuid) -> BoxFuture<'a, Result<UserRole, GetUserError>> {
+        Box::pin(UserService::get_user_role(self, user_id))
+    }
+
     fn get_preferences<'a>(
         &'a self,
         user_id: Uuid,
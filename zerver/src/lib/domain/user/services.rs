@@ -11,7 +11,7 @@ use crate::domain::user::{
     ports::{UserRepository, UserService},
 };
 use zwipe_core::domain::user::{
-    User,
+    User, UserRole,
     models::hints::MarkHintShown,
     preferences::{UpdatePreferences, UserPreferences},
     requests::get_user::GetUser,
@@ -49,6 +49,10 @@ impl<R: UserRepository> UserService for Service<R> {
         self.repo.get_user(request.user_id).await
     }
 
+    async fn get_user_role(&self, user_id: Uuid) -> Result<UserRole, GetUserError> {
+        self.repo.get_user_role(user_id).await
+    }
+
     // ===============
     //  preferences
     // ===============
@@ -0,0 +1,122 @@
+//! Have I Been Pwned "Pwned Passwords" breach check.
+//!
+//! Uses the k-anonymity range API: only the first five hex characters of the
+//! password's SHA-1 leave the process, and the returned suffixes are matched
+//! locally. Responses are padded so their size doesn't hint at the prefix.
+//!
+//! The check is off unless [`PwnedPasswords::init_shared`] runs at startup
+//! (`HIBP_BREACH_CHECK=true`), and it fails open: an unreachable or erroring
+//! API lets the password through with a warning rather than blocking signups.
+
+use reqwest::{Client, header::USER_AGENT};
+use sha1::{Digest, Sha1};
+use std::{sync::OnceLock, time::Duration};
+
+const PWNED_PASSWORDS_RANGE_API: &str = "https://api.pwnedpasswords.com/range";
+const USER_AGENT_VALUE: &str = concat!("zwipe/", env!("CARGO_PKG_VERSION"));
+
+/// How long a range lookup may take before the check gives up (and allows).
+pub const HIBP_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// The process-wide checker; unset (check disabled) until
+/// [`PwnedPasswords::init_shared`].
+static SHARED: OnceLock<PwnedPasswords> = OnceLock::new();
+
+/// Client for the Pwned Passwords range API.
+#[derive(Debug, Clone)]
+pub struct PwnedPasswords {
+    http: Client,
+}
+
+impl PwnedPasswords {
+    /// Creates a client with a [`HIBP_TIMEOUT`] request timeout.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`reqwest::Error`] if the HTTP client can't be built (e.g. no
+    /// TLS backend is available).
+    pub fn new() -> Result<Self, reqwest::Error> {
+        let http = Client::builder().timeout(HIBP_TIMEOUT).build()?;
+        Ok(Self { http })
+    }
+
+    /// Turns the breach check on for the whole process. Returns `Ok(false)` if
+    /// it was already on.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`reqwest::Error`] if the HTTP client can't be built; the check
+    /// stays off rather than running without its timeout.
+    pub fn init_shared() -> Result<bool, reqwest::Error> {
+        Ok(SHARED.set(Self::new()?).is_ok())
+    }
+
+    /// The process-wide checker, or `None` while the check is off.
+    pub fn shared() -> Option<&'static Self> {
+        SHARED.get()
+    }
+
+    /// Whether `password` appears in a known breach.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`reqwest::Error`] if the API can't be reached or answers with
+    /// an error status.
+    pub async fn is_breached(&self, password: &str) -> Result<bool, reqwest::Error> {
+        let (prefix, suffix) = sha1_range(password);
+        let body = self
+            .http
+            .get(format!("{PWNED_PASSWORDS_RANGE_API}/{prefix}"))
+            .header(USER_AGENT, USER_AGENT_VALUE)
+            .header("Add-Padding", "true")
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        Ok(range_contains(&body, &suffix))
+    }
+}
+
+/// Splits the uppercase hex SHA-1 of `password` into the 5-character prefix
+/// sent to the API and the 35-character suffix matched locally.
+fn sha1_range(password: &str) -> (String, String) {
+    let mut hash = hex::encode_upper(Sha1::digest(password.as_bytes()));
+    let suffix = hash.split_off(5);
+    (hash, suffix)
+}
+
+/// Whether a range response (`SUFFIX:COUNT` lines) lists `suffix` with a
+/// non-zero count; padding entries carry a count of zero.
+fn range_contains(body: &str, suffix: &str) -> bool {
+    body.lines().any(|line| {
+        line.trim()
+            .split_once(':')
+            .is_some_and(|(candidate, count)| {
+                candidate.eq_ignore_ascii_case(suffix) && count.trim() != "0"
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_prefix_of_the_hash_is_sent() {
+        // SHA-1("password") = 5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8
+        let (prefix, suffix) = sha1_range("password");
+        assert_eq!(prefix, "5BAA6");
+        assert_eq!(suffix, "1E4C9B93F3F0682250B6CF8331B7EE68FD8");
+    }
+
+    #[test]
+    fn matches_listed_suffixes_but_not_padding() {
+        let body = "0018A45C4D1DEF81644B54AB7F969B88D65:1\r\n\
+                    1E4C9B93F3F0682250B6CF8331B7EE68FD8:10434004\r\n\
+                    1E4C9B93F3F0682250B6CF8331B7EE68FD9:0\r\n";
+        assert!(range_contains(body, "1E4C9B93F3F0682250B6CF8331B7EE68FD8"));
+        assert!(!range_contains(body, "1E4C9B93F3F0682250B6CF8331B7EE68FD9"));
+        assert!(!range_contains(body, "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"));
+    }
+}
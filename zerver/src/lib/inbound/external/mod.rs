@@ -2,3 +2,7 @@
 
 /// Scryfall MTG card data client.
 pub mod scryfall;
+
+/// Have I Been Pwned breach check.
+#[cfg(feature = "hibp")]
+pub mod hibp;
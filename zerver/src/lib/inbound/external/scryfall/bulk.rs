@@ -1,19 +1,163 @@
+use crate::domain::card::models::bulk_validation::BulkValidationReport;
+#[cfg(feature = "zerver")]
 use crate::inbound::external::scryfall::{
+    client::{SCRYFALL_API_BASE, ScryfallClient},
     oracle_tag::OracleTag,
-    planeswalker::{Planeswalker, SCRYFALL_API_BASE},
 };
 use anyhow::Context;
-use reqwest::Client;
-use serde::Deserialize;
+use serde::{
+    Deserializer,
+    de::{DeserializeSeed, SeqAccess, Visitor},
+};
 use serde_json::Value;
+use std::{fs::File, io::BufReader, path::Path};
+#[cfg(feature = "zerver")]
+use std::{future::Future, time::Duration};
+#[cfg(feature = "zerver")]
+use tokio::io::AsyncWriteExt;
 use zwipe_core::domain::card::scryfall_data::ScryfallData;
 
 /// Scryfall bulk data metadata response (contains the download URI).
-#[derive(Deserialize, Debug)]
+#[cfg(feature = "zerver")]
+#[derive(serde::Deserialize, Debug)]
 pub(super) struct BulkDataObject {
     pub(super) download_uri: String,
 }
 
+/// How a sync reads a bulk card file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BulkParse {
+    /// Download to a temp file, then deserialize one card at a time and hand
+    /// the sync fixed-size batches ([`stream_bulk_file`]). Memory stays
+    /// bounded by the batch size, whatever the file size.
+    #[default]
+    Streaming,
+    /// Buffer the whole response and deserialize it in one go
+    /// ([`BulkEndpoint::amass`]). Only for small fixtures: the real bulk file
+    /// is several GB.
+    WholeFile,
+}
+
+/// Default attempts at each bulk download step before a sync gives up.
+#[cfg(feature = "zerver")]
+pub const BULK_DOWNLOAD_MAX_ATTEMPTS_DEFAULT: u32 = 5;
+
+/// Backoff before the first retry; doubles with each further attempt.
+#[cfg(feature = "zerver")]
+const DOWNLOAD_BACKOFF_BASE: Duration = Duration::from_secs(2);
+
+/// Longest backoff between two download attempts.
+#[cfg(feature = "zerver")]
+const DOWNLOAD_BACKOFF_MAX: Duration = Duration::from_secs(120);
+
+/// How bulk downloads retry transient failures (connection errors, 5xx).
+///
+/// Waits grow exponentially from [`DOWNLOAD_BACKOFF_BASE`] with "equal
+/// jitter" — half the wait fixed, half random — so concurrent retries after
+/// an outage don't land on Scryfall together.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadRetry {
+    /// Attempts per step, the first included; at least 1.
+    pub max_attempts: u32,
+}
+
+#[cfg(feature = "zerver")]
+impl Default for DownloadRetry {
+    fn default() -> Self {
+        Self {
+            max_attempts: BULK_DOWNLOAD_MAX_ATTEMPTS_DEFAULT,
+        }
+    }
+}
+
+/// A failed download attempt, and whether trying again could help.
+#[cfg(feature = "zerver")]
+#[derive(Debug)]
+enum AttemptError {
+    /// Connection trouble or a 5xx: worth another attempt.
+    Transient(anyhow::Error),
+    /// A 4xx, a bad payload, or a local file error: retrying won't help.
+    Fatal(anyhow::Error),
+}
+
+#[cfg(feature = "zerver")]
+impl AttemptError {
+    fn into_inner(self) -> anyhow::Error {
+        match self {
+            Self::Transient(e) | Self::Fatal(e) => e,
+        }
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl DownloadRetry {
+    /// Retries up to `max_attempts` times in total.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+        }
+    }
+
+    /// Wait before the attempt following failed attempt number `attempt`
+    /// (1-based): half of the exponential step, plus up to as much again.
+    fn delay(&self, attempt: u32) -> Duration {
+        let step = DOWNLOAD_BACKOFF_BASE
+            .saturating_mul(1 << attempt.saturating_sub(1).min(16))
+            .min(DOWNLOAD_BACKOFF_MAX);
+        let half = step / 2;
+        let half_ms = u64::try_from(half.as_millis()).unwrap_or(u64::MAX);
+        half + Duration::from_millis(rand::random_range(0..=half_ms))
+    }
+
+    /// Runs `attempt` until it succeeds, fails fatally, or `max_attempts`
+    /// is used up; the final error says how many attempts were made.
+    async fn run<T, F, Fut>(&self, what: &str, mut attempt: F) -> anyhow::Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, AttemptError>>,
+    {
+        let mut made = 0;
+        loop {
+            made += 1;
+            match attempt().await {
+                Ok(value) => return Ok(value),
+                Err(AttemptError::Transient(e)) if made < self.max_attempts => {
+                    let wait = self.delay(made);
+                    tracing::warn!(
+                        "{what} failed (attempt {made}/{}): {e:#}; retrying in {wait:?}",
+                        self.max_attempts
+                    );
+                    tokio::time::sleep(wait).await;
+                }
+                Err(e) => {
+                    return Err(e
+                        .into_inner()
+                        .context(format!("{what} failed after {made} attempt(s)")));
+                }
+            }
+        }
+    }
+}
+
+/// Classifies a response status: 5xx (and a stale `Range`) are transient,
+/// any other failure status is fatal.
+#[cfg(feature = "zerver")]
+fn check_status(response: &reqwest::Response, what: &str) -> Result<(), AttemptError> {
+    let status = response.status();
+    if status.is_success() {
+        Ok(())
+    } else if status.is_server_error() || status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+        Err(AttemptError::Transient(anyhow::anyhow!(
+            "{what} returned {status}"
+        )))
+    } else {
+        Err(AttemptError::Fatal(anyhow::anyhow!(
+            "{what} returned {status}"
+        )))
+    }
+}
+
 /// Scryfall's bulk data download categories.
 #[derive(Debug, Clone, Copy)]
 #[allow(missing_docs)]
@@ -27,6 +171,7 @@ pub enum BulkEndpoint {
 }
 
 impl BulkEndpoint {
+    #[cfg(feature = "zerver")]
     pub(super) fn resolve(&self) -> String {
         match self {
             Self::OracleCards => "/bulk-data/oracle-cards".to_string(),
@@ -51,28 +196,118 @@ impl BulkEndpoint {
     }
 }
 
+#[cfg(feature = "zerver")]
 impl BulkEndpoint {
-    /// Fetches bulk card data in two steps: metadata endpoint → download URI → card data.
-    pub async fn amass(&self) -> anyhow::Result<Vec<ScryfallData>> {
-        // first get the bulk data object with our main url
+    /// Reads the bulk data metadata endpoint for the current download URI.
+    async fn download_uri(&self) -> Result<String, AttemptError> {
         let url = format!("{}{}", SCRYFALL_API_BASE, self.resolve());
-        let urza = Planeswalker::untap(Client::new(), &url);
+        let urza = ScryfallClient::shared().untap(&url);
 
         let bulk_response = urza
             .cast()
             .await
-            .context("failed to get bulk response with planeswalker")?;
+            .context("failed to get bulk response with planeswalker")
+            .map_err(AttemptError::Transient)?;
+        check_status(&bulk_response, "bulk metadata request")?;
 
         let bulk_json: Value = bulk_response
             .json()
             .await
-            .context("failed to parse json from main uri result")?;
+            .context("failed to parse json from main uri result")
+            .map_err(AttemptError::Transient)?;
 
         let bulk_data_object = serde_json::from_value::<BulkDataObject>(bulk_json)
-            .context("failed to parse BulkDataObject")?;
+            .context("failed to parse BulkDataObject")
+            .map_err(AttemptError::Fatal)?;
+
+        Ok(bulk_data_object.download_uri)
+    }
+
+    /// One attempt at downloading `download_uri` into `path`. Bytes already
+    /// on disk from an earlier attempt are kept and only the rest requested
+    /// (`Range`); a server that ignores the range sends the whole file,
+    /// which replaces them.
+    async fn fetch_into(download_uri: &str, path: &Path) -> Result<(), AttemptError> {
+        let written = tokio::fs::metadata(path).await.map_or(0, |m| m.len());
+        let mut karn = ScryfallClient::shared().untap(download_uri);
+        if written > 0 {
+            karn = karn.header(reqwest::header::RANGE, format!("bytes={written}-"));
+        }
+
+        let mut response = karn
+            .cast()
+            .await
+            .context("failed to get download response with planeswalker")
+            .map_err(AttemptError::Transient)?;
+        if response.status() == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+            // what's on disk doesn't line up with the file any more; start over
+            let _ = tokio::fs::remove_file(path).await;
+        }
+        check_status(&response, "bulk download")?;
+        let resumed = response.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+        if resumed {
+            tracing::info!("resuming bulk download after {written} bytes");
+        }
+
+        let file_error = |action: &str| {
+            let message = format!("failed to {action} {}", path.display());
+            move |e: std::io::Error| AttemptError::Fatal(anyhow::Error::new(e).context(message))
+        };
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(resumed)
+            .truncate(!resumed)
+            .open(path)
+            .await
+            .map_err(file_error("open"))?;
+        let read = loop {
+            match response.chunk().await {
+                Ok(Some(chunk)) => file.write_all(&chunk).await.map_err(file_error("write"))?,
+                Ok(None) => break Ok(()),
+                Err(e) => {
+                    break Err(AttemptError::Transient(
+                        anyhow::Error::new(e).context("failed to read bulk download body"),
+                    ));
+                }
+            }
+        };
+        // keep what arrived, even when the connection dropped, to resume from
+        file.flush().await.map_err(file_error("flush"))?;
+        read
+    }
+
+    /// Downloads the bulk file to `path` chunk by chunk, never holding more
+    /// than one network chunk in memory. Parse it with [`stream_bulk_file`].
+    /// Returns the download URI, which identifies this version of the file.
+    ///
+    /// Connection errors and 5xx responses are retried per `retry`; a
+    /// download cut off midway resumes from the bytes already written.
+    pub async fn download_to(&self, path: &Path, retry: DownloadRetry) -> anyhow::Result<String> {
+        let download_uri = retry
+            .run("bulk metadata request", || self.download_uri())
+            .await?;
+        retry
+            .run("bulk download", || Self::fetch_into(&download_uri, path))
+            .await?;
+        Ok(download_uri)
+    }
+
+    /// Fetches bulk card data in two steps: metadata endpoint → download URI → card data.
+    ///
+    /// Holds the whole file in memory (twice, briefly: as JSON and as cards),
+    /// so it's only for small fixtures; syncs stream via [`download_to`].
+    ///
+    /// [`download_to`]: BulkEndpoint::download_to
+    pub async fn amass(&self) -> anyhow::Result<Vec<ScryfallData>> {
+        // first get the download uri from the bulk data object
+        let download_uri = self
+            .download_uri()
+            .await
+            .map_err(AttemptError::into_inner)?;
 
         // then use the download_uri to fetch the actual card data
-        let karn = Planeswalker::untap(Client::new(), &bulk_data_object.download_uri);
+        let karn = ScryfallClient::shared().untap(&download_uri);
 
         let cards_response = karn
             .cast()
@@ -97,7 +332,7 @@ impl BulkEndpoint {
     pub async fn amass_oracle_tags(&self) -> anyhow::Result<Vec<OracleTag>> {
         // first get the bulk data object with our main url
         let url = format!("{}{}", SCRYFALL_API_BASE, self.resolve());
-        let urza = Planeswalker::untap(Client::new(), &url);
+        let urza = ScryfallClient::shared().untap(&url);
 
         let bulk_response = urza
             .cast()
@@ -113,7 +348,7 @@ impl BulkEndpoint {
             .context("failed to parse BulkDataObject for oracle-tags")?;
 
         // then use the download_uri to fetch the actual tag data
-        let karn = Planeswalker::untap(Client::new(), &bulk_data_object.download_uri);
+        let karn = ScryfallClient::shared().untap(&bulk_data_object.download_uri);
 
         let tags_response = karn
             .cast()
@@ -131,3 +366,323 @@ impl BulkEndpoint {
         Ok(tags)
     }
 }
+
+/// Dry-parses a bulk card file on disk: streams the top-level JSON array one
+/// record at a time, deserializes each as [`ScryfallData`] exactly as a sync
+/// would, and tallies the outcome. Only one record is held in memory at a
+/// time. A malformed record is counted and skipped; a file that isn't a JSON
+/// array (or is truncated mid-array) is an error.
+pub fn validate_bulk_file(path: &Path) -> anyhow::Result<BulkValidationReport> {
+    let file =
+        File::open(path).with_context(|| format!("failed to open bulk file {}", path.display()))?;
+    let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(file));
+    let mut report = BulkValidationReport::new();
+    ValidateCards(&mut report)
+        .deserialize(&mut deserializer)
+        .with_context(|| {
+            format!(
+                "failed to read bulk file {} as a JSON array",
+                path.display()
+            )
+        })?;
+    deserializer
+        .end()
+        .context("trailing data after bulk card array")?;
+    Ok(report)
+}
+
+/// Streams a bulk card file on disk: reads the top-level JSON array one
+/// record at a time, deserializes each as [`ScryfallData`], and hands
+/// `on_batch` every `batch_size` cards (the last batch may be shorter).
+/// Returns how many cards were read. Only the batch being filled is held in
+/// memory. A malformed record, a file that isn't a JSON array, or an error
+/// from `on_batch` stops the stream with an error.
+pub fn stream_bulk_file(
+    path: &Path,
+    batch_size: usize,
+    on_batch: impl FnMut(Vec<ScryfallData>) -> anyhow::Result<()>,
+) -> anyhow::Result<usize> {
+    let file =
+        File::open(path).with_context(|| format!("failed to open bulk file {}", path.display()))?;
+    let mut deserializer = serde_json::Deserializer::from_reader(BufReader::new(file));
+    let received = StreamCards {
+        batch_size: batch_size.max(1),
+        on_batch,
+    }
+    .deserialize(&mut deserializer)
+    .with_context(|| format!("failed to stream bulk file {}", path.display()))?;
+    deserializer
+        .end()
+        .context("trailing data after bulk card array")?;
+    Ok(received)
+}
+
+/// Seq visitor collecting array elements into batches for a callback.
+struct StreamCards<F> {
+    batch_size: usize,
+    on_batch: F,
+}
+
+impl<'de, F> DeserializeSeed<'de> for StreamCards<F>
+where
+    F: FnMut(Vec<ScryfallData>) -> anyhow::Result<()>,
+{
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, F> Visitor<'de> for StreamCards<F>
+where
+    F: FnMut(Vec<ScryfallData>) -> anyhow::Result<()>,
+{
+    type Value = usize;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("an array of Scryfall card objects")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(mut self, mut seq: A) -> Result<usize, A::Error> {
+        let mut received = 0;
+        let mut batch = Vec::with_capacity(self.batch_size);
+        while let Some(card) = seq.next_element::<ScryfallData>()? {
+            batch.push(card);
+            received += 1;
+            if batch.len() == self.batch_size {
+                let full = std::mem::replace(&mut batch, Vec::with_capacity(self.batch_size));
+                (self.on_batch)(full).map_err(serde::de::Error::custom)?;
+            }
+        }
+        if !batch.is_empty() {
+            (self.on_batch)(batch).map_err(serde::de::Error::custom)?;
+        }
+        Ok(received)
+    }
+}
+
+/// Seq visitor feeding each array element into the report.
+struct ValidateCards<'r>(&'r mut BulkValidationReport);
+
+impl<'de> DeserializeSeed<'de> for ValidateCards<'_> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ValidateCards<'_> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("an array of Scryfall card objects")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let mut index = 0;
+        while let Some(record) = seq.next_element::<Value>()? {
+            let field = |key: &str| record.get(key).and_then(Value::as_str).map(str::to_string);
+            let (id, name) = (field("id"), field("name"));
+            match serde_json::from_value::<ScryfallData>(record) {
+                Ok(_) => self.0.record_parsed(),
+                Err(e) => self.0.record_failure(index, id, name, e.to_string()),
+            }
+            index += 1;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn card_json(id: &str, name: &str) -> Value {
+        json!({
+            "object": "card",
+            "id": id,
+            "lang": "en",
+            "layout": "normal",
+            "name": name,
+            "cmc": 1.0,
+            "color_identity": [],
+            "legalities": {},
+            "reserved": false,
+            "prints_search_uri": "https://scryfall.test/prints",
+            "rulings_uri": "https://scryfall.test/rulings",
+            "scryfall_uri": "https://scryfall.test/card",
+            "uri": "https://scryfall.test/uri",
+            "booster": true,
+            "border_color": "black",
+            "collector_number": "1",
+            "digital": false,
+            "finishes": ["nonfoil"],
+            "frame": "2015",
+            "full_art": false,
+            "highres_image": true,
+            "image_status": "highres_scan",
+            "oversized": false,
+            "prices": {},
+            "promo": false,
+            "rarity": "common",
+            "related_uris": {},
+            "released_at": "2024-01-01",
+            "reprint": false,
+            "scryfall_set_uri": "https://scryfall.test/set",
+            "set_name": "Test Set",
+            "set_search_uri": "https://scryfall.test/set-search",
+            "set_type": "expansion",
+            "set_uri": "https://scryfall.test/set-uri",
+            "set": "tst",
+            "set_id": "00000000-0000-0000-0000-0000000000ff",
+            "story_spotlight": false,
+            "textless": false,
+            "variation": false,
+        })
+    }
+
+    /// Writes `records` as a JSON array to a fresh temp file.
+    fn write_bulk(records: &[Value]) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("zwipe_bulk_{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&path, Value::from(records.to_vec()).to_string()).unwrap();
+        path
+    }
+
+    #[test]
+    fn stream_bulk_file_hands_over_fixed_size_batches() {
+        let records: Vec<Value> = (1..=5)
+            .map(|i| {
+                card_json(
+                    &format!("00000000-0000-0000-0000-00000000000{i}"),
+                    &format!("Card {i}"),
+                )
+            })
+            .collect();
+        let path = write_bulk(&records);
+        let mut batches: Vec<Vec<String>> = Vec::new();
+        let received = stream_bulk_file(&path, 2, |batch| {
+            batches.push(batch.into_iter().map(|card| card.name).collect());
+            Ok(())
+        });
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(received.unwrap(), 5);
+        assert_eq!(
+            batches,
+            vec![
+                vec!["Card 1", "Card 2"],
+                vec!["Card 3", "Card 4"],
+                vec!["Card 5"],
+            ]
+        );
+    }
+
+    #[test]
+    fn stream_bulk_file_stops_on_a_bad_record_or_a_failed_batch() {
+        let good = card_json("00000000-0000-0000-0000-00000000000a", "Sol Ring");
+        let mut bad = card_json("00000000-0000-0000-0000-0000000000ba", "Drifted Card");
+        bad["cmc"] = json!("three");
+        let path = write_bulk(&[good.clone(), bad]);
+        let malformed = stream_bulk_file(&path, 10, |_| Ok(()));
+        std::fs::remove_file(&path).unwrap();
+        assert!(malformed.is_err());
+
+        let path = write_bulk(&[good.clone(), good]);
+        let mut calls = 0;
+        let refused = stream_bulk_file(&path, 1, |_| {
+            calls += 1;
+            anyhow::bail!("database went away")
+        });
+        std::fs::remove_file(&path).unwrap();
+        assert!(format!("{:#}", refused.unwrap_err()).contains("database went away"));
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn validate_bulk_file_counts_parsed_and_failed_records() {
+        let good_a = card_json("00000000-0000-0000-0000-00000000000a", "Sol Ring");
+        let good_b = card_json("00000000-0000-0000-0000-00000000000b", "Counterspell");
+        let mut bad = card_json("00000000-0000-0000-0000-0000000000ba", "Drifted Card");
+        bad["cmc"] = json!("three");
+
+        let path = std::env::temp_dir().join(format!("zwipe_bulk_{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&path, json!([good_a, bad, good_b]).to_string()).unwrap();
+        let report = validate_bulk_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        let report = report.unwrap();
+
+        assert_eq!(report.parsed, 2, "{:?}", report.failures);
+        assert_eq!(report.failed, 1);
+        assert_eq!(report.failures.len(), 1);
+        let failure = &report.failures[0];
+        assert_eq!(failure.first_index, 1);
+        assert_eq!(failure.first_name.as_deref(), Some("Drifted Card"));
+        assert!(
+            failure.category.contains("expected f64"),
+            "{}",
+            failure.category
+        );
+    }
+
+    #[cfg(feature = "zerver")]
+    #[test]
+    fn retry_delay_grows_with_jitter_and_caps() {
+        let retry = DownloadRetry::default();
+        for attempt in 1..=4 {
+            let step = DOWNLOAD_BACKOFF_BASE * (1 << (attempt - 1));
+            for _ in 0..20 {
+                let delay = retry.delay(attempt);
+                assert!(delay >= step / 2 && delay <= step, "{attempt}: {delay:?}");
+            }
+        }
+        assert!(retry.delay(30) <= DOWNLOAD_BACKOFF_MAX);
+        assert_eq!(DownloadRetry::new(0).max_attempts, 1);
+    }
+
+    #[cfg(feature = "zerver")]
+    #[tokio::test]
+    async fn retry_stops_on_fatal_errors_and_counts_attempts() {
+        let mut calls = 0;
+        let result: anyhow::Result<()> = DownloadRetry::new(5)
+            .run("fixture", || {
+                calls += 1;
+                std::future::ready(Err(AttemptError::Fatal(anyhow::anyhow!("404"))))
+            })
+            .await;
+        assert_eq!(calls, 1);
+        assert!(
+            format!("{:#}", result.unwrap_err()).contains("fixture failed after 1 attempt(s): 404")
+        );
+
+        let mut calls = 0;
+        let result: anyhow::Result<()> = DownloadRetry::new(1)
+            .run("fixture", || {
+                calls += 1;
+                std::future::ready(Err(AttemptError::Transient(anyhow::anyhow!("503"))))
+            })
+            .await;
+        assert_eq!(calls, 1);
+        assert!(format!("{:#}", result.unwrap_err()).contains("after 1 attempt(s)"));
+    }
+
+    #[cfg(feature = "zerver")]
+    #[tokio::test]
+    async fn retry_recovers_from_a_transient_failure() {
+        let mut calls = 0;
+        let result = DownloadRetry::new(2)
+            .run("fixture", || {
+                calls += 1;
+                std::future::ready(if calls == 1 {
+                    Err(AttemptError::Transient(anyhow::anyhow!("connection reset")))
+                } else {
+                    Ok(calls)
+                })
+            })
+            .await;
+        assert_eq!(result.unwrap(), 2);
+    }
+}
@@ -0,0 +1,292 @@
+//! Shared, rate-limited Scryfall HTTP client.
+//!
+//! Scryfall asks API clients to stay around 10 requests per second and to
+//! send an identifying `User-Agent` and an `Accept` header on every request;
+//! clients that don't risk a temporary (or permanent) ban. Every Scryfall call
+//! goes through a [`ScryfallClient`], and all clones share one
+//! [`TokenBucket`], so concurrent syncs in the same process queue behind a
+//! single budget instead of each pacing itself.
+//!
+//! A `429 Too Many Requests` is retried after the server's `Retry-After` (or
+//! an exponential backoff without one), and the wait holds off every caller
+//! sharing the bucket, not just the one that was throttled.
+
+use crate::inbound::external::scryfall::planeswalker::Planeswalker;
+use reqwest::{
+    Client,
+    header::{ACCEPT, HeaderMap, RETRY_AFTER, USER_AGENT},
+};
+use std::{
+    sync::{Arc, Mutex, OnceLock, PoisonError},
+    time::{Duration, Instant},
+};
+
+// ==============================
+//  equip onto scryfall requests
+// ==============================
+pub(super) const USER_AGENT_VALUE: &str = concat!("zwipe/", env!("CARGO_PKG_VERSION"));
+pub(super) const ACCEPT_VALUE: &str = "application/json;q=0.9,*/*;q=0.8";
+pub(super) const SCRYFALL_API_BASE: &str = "https://api.scryfall.com";
+pub(super) const CARDS_SEARCH_ENDPOINT: &str = "/cards/search";
+pub(super) const SETS_ENDPOINT: &str = "/sets";
+
+/// Scryfall's published guidance: about 10 requests per second.
+pub const SCRYFALL_REQUESTS_PER_SECOND: f64 = 10.0;
+
+/// No bursting by default: Scryfall asks for 50–100 ms between requests.
+pub const SCRYFALL_BURST: u32 = 1;
+
+/// Default minimum gap between requests, matching Scryfall's guidance.
+pub const SCRYFALL_MIN_INTERVAL_DEFAULT: Duration = Duration::from_millis(100);
+
+/// How many times a throttled (429) request is retried before the 429 is
+/// returned to the caller.
+pub const SCRYFALL_MAX_RETRIES: u32 = 4;
+
+/// First backoff after a 429; doubles with each retry.
+const RETRY_BACKOFF_BASE: Duration = Duration::from_secs(1);
+
+/// Longest single backoff, whatever `Retry-After` asks for.
+const RETRY_BACKOFF_MAX: Duration = Duration::from_secs(60);
+
+/// The process-wide client every Scryfall call uses; configured once at
+/// startup by [`ScryfallClient::init_shared`], Scryfall's guidance otherwise.
+static SHARED: OnceLock<ScryfallClient> = OnceLock::new();
+
+// =========
+//  limiter
+// =========
+
+/// Async token bucket: holds up to `burst` tokens, refilled at `rate` per
+/// second. A caller that finds the bucket empty reserves the next token
+/// anyway (the count goes negative) and sleeps until it would have refilled,
+/// so waiters are served in arrival order without holding the lock.
+#[derive(Debug)]
+pub struct TokenBucket {
+    rate: f64,
+    burst: f64,
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    /// A full bucket allowing `rate` requests per second, `burst` at once.
+    /// Zero values are raised to the smallest workable limit.
+    pub fn new(rate: f64, burst: u32) -> Self {
+        let burst = f64::from(burst.max(1));
+        Self {
+            rate: rate.max(f64::MIN_POSITIVE),
+            burst,
+            state: Mutex::new(BucketState {
+                tokens: burst,
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Adds the tokens earned since the last refill, up to `burst`.
+    fn refill(&self, state: &mut BucketState) {
+        let now = Instant::now();
+        let refill = now.duration_since(state.refilled_at).as_secs_f64() * self.rate;
+        state.tokens = (state.tokens + refill).min(self.burst);
+        state.refilled_at = now;
+    }
+
+    /// Waits until a request may be sent, then consumes a token.
+    pub async fn acquire(&self) {
+        let wait = {
+            let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+            self.refill(&mut state);
+            state.tokens -= 1.0;
+            (state.tokens < 0.0).then(|| Duration::from_secs_f64(-state.tokens / self.rate))
+        };
+        if let Some(wait) = wait {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Empties the bucket and pushes the next token `wait` further out, so
+    /// every caller backs off after the server throttled one of them.
+    pub fn hold_off(&self, wait: Duration) {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        self.refill(&mut state);
+        state.tokens = state.tokens.min(0.0) - wait.as_secs_f64() * self.rate;
+    }
+}
+
+/// The `Retry-After` delay of a throttled response, when given in seconds
+/// (the form Scryfall sends).
+pub(super) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+        .map(Duration::from_secs)
+}
+
+/// How long to back off before retry number `attempt` (0-based): at least
+/// what the server asked for, doubling from one second with each attempt,
+/// capped at a minute.
+pub(super) fn backoff(attempt: u32, retry_after: Option<Duration>) -> Duration {
+    let exponential = RETRY_BACKOFF_BASE.saturating_mul(1 << attempt.min(16));
+    exponential
+        .max(retry_after.unwrap_or_default())
+        .min(RETRY_BACKOFF_MAX)
+}
+
+// ========
+//  client
+// ========
+
+/// `reqwest` client with Scryfall's required headers and a shared rate
+/// limiter. Cheap to clone; clones share the connection pool and the bucket.
+#[derive(Debug, Clone)]
+pub struct ScryfallClient {
+    http: Client,
+    limiter: Arc<TokenBucket>,
+}
+
+impl ScryfallClient {
+    /// A client limited to `requests_per_second`, allowing `burst` at once.
+    pub fn new(requests_per_second: f64, burst: u32) -> Self {
+        Self {
+            http: Client::new(),
+            limiter: Arc::new(TokenBucket::new(requests_per_second, burst)),
+        }
+    }
+
+    /// A client sending at most one request per `min_interval`.
+    pub fn with_min_interval(min_interval: Duration) -> Self {
+        let min_interval = min_interval.max(Duration::from_millis(1));
+        Self::new(1.0 / min_interval.as_secs_f64(), 1)
+    }
+
+    /// Configures the process-wide client to keep `min_interval` between
+    /// requests. Call once at startup, before any Scryfall request; returns
+    /// `false` (and changes nothing) if the shared client already exists.
+    pub fn init_shared(min_interval: Duration) -> bool {
+        SHARED.set(Self::with_min_interval(min_interval)).is_ok()
+    }
+
+    /// The process-wide client; Scryfall's guidance unless
+    /// [`init_shared`](Self::init_shared) configured it.
+    pub fn shared() -> &'static ScryfallClient {
+        SHARED.get_or_init(|| Self::new(SCRYFALL_REQUESTS_PER_SECOND, SCRYFALL_BURST))
+    }
+
+    /// Starts a GET request to `full_url`, sent once the limiter allows.
+    pub(super) fn untap(&self, full_url: &str) -> Planeswalker {
+        let request = self
+            .http
+            .get(full_url)
+            .header(USER_AGENT, USER_AGENT_VALUE)
+            .header(ACCEPT, ACCEPT_VALUE);
+        Planeswalker::new(request, Arc::clone(&self.limiter))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn limiter_spaces_out_requests() {
+        // 50/s with no burst: the first passes at once, each later one waits
+        // ~20 ms behind the last.
+        let bucket = TokenBucket::new(50.0, 1);
+        let start = Instant::now();
+        for _ in 0..4 {
+            bucket.acquire().await;
+        }
+        assert!(
+            start.elapsed() >= Duration::from_millis(55),
+            "{:?}",
+            start.elapsed()
+        );
+    }
+
+    #[tokio::test]
+    async fn burst_passes_without_waiting() {
+        let bucket = TokenBucket::new(1.0, 3);
+        let start = Instant::now();
+        for _ in 0..3 {
+            bucket.acquire().await;
+        }
+        assert!(start.elapsed() < Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn hold_off_delays_the_next_request() {
+        let bucket = TokenBucket::new(1000.0, 1);
+        bucket.acquire().await;
+        bucket.hold_off(Duration::from_millis(60));
+        let start = Instant::now();
+        bucket.acquire().await;
+        assert!(
+            start.elapsed() >= Duration::from_millis(55),
+            "{:?}",
+            start.elapsed()
+        );
+    }
+
+    #[test]
+    fn min_interval_sets_the_rate() {
+        let client = ScryfallClient::with_min_interval(Duration::from_millis(250));
+        assert!((client.limiter.rate - 4.0).abs() < f64::EPSILON);
+        assert!((client.limiter.burst - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn retry_after_reads_delay_seconds() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+        headers.insert(RETRY_AFTER, "7".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+        // the HTTP-date form falls back to the exponential backoff
+        headers.insert(
+            RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[test]
+    fn backoff_doubles_honors_retry_after_and_caps() {
+        assert_eq!(backoff(0, None), Duration::from_secs(1));
+        assert_eq!(backoff(1, None), Duration::from_secs(2));
+        assert_eq!(backoff(3, None), Duration::from_secs(8));
+        assert_eq!(
+            backoff(0, Some(Duration::from_secs(5))),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            backoff(3, Some(Duration::from_secs(5))),
+            Duration::from_secs(8)
+        );
+        assert_eq!(backoff(10, None), RETRY_BACKOFF_MAX);
+        assert_eq!(
+            backoff(40, Some(Duration::from_secs(600))),
+            RETRY_BACKOFF_MAX
+        );
+    }
+
+    #[test]
+    fn requests_carry_required_headers() {
+        let request = ScryfallClient::shared()
+            .untap("https://api.scryfall.com/sets")
+            .build()
+            .unwrap();
+        assert_eq!(request.headers()[USER_AGENT], USER_AGENT_VALUE);
+        assert!(USER_AGENT_VALUE.starts_with("zwipe/"));
+        assert_eq!(request.headers()[ACCEPT], ACCEPT_VALUE);
+    }
+}
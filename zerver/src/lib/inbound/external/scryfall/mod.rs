@@ -2,7 +2,13 @@
 
 /// Bulk data download endpoints.
 pub mod bulk;
+/// Shared rate-limited Scryfall HTTP client.
+#[cfg(feature = "zerver")]
+pub mod client;
 /// Oracle Tags bulk-file record types.
 pub mod oracle_tag;
 /// HTTP request builder with Scryfall API headers.
+#[cfg(feature = "zerver")]
 pub mod planeswalker;
+/// Set catalog record types.
+pub mod set;
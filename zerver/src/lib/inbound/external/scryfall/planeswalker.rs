@@ -1,67 +1,207 @@
 //! HTTP request builder using MTG-themed naming conventions.
 //!
-//! - **Planeswalker** = HTTP client wrapper with Scryfall API headers
-//! - **untap** = create a new request builder
-//! - **cast** = send the request
+//! - **Planeswalker** = rate-limited request with Scryfall API headers
+//! - **untap** = create a new request builder (see [`ScryfallClient::untap`])
+//! - **cast** = wait for the limiter, then send the request (retrying 429s)
 //! - **tutor** = search for a card
+//! - **amass_sets** = fetch every printing in some sets
+//! - **surveil_sets** = fetch the set catalog itself
 
-use anyhow::Context;
-use reqwest::{
-    Client, RequestBuilder, Response,
-    header::{ACCEPT, USER_AGENT},
+use crate::inbound::external::scryfall::{
+    client::{
+        CARDS_SEARCH_ENDPOINT, SCRYFALL_API_BASE, SCRYFALL_MAX_RETRIES, SETS_ENDPOINT,
+        ScryfallClient, TokenBucket, backoff, retry_after,
+    },
+    set::ScryfallSet,
 };
+use anyhow::Context;
+use reqwest::{RequestBuilder, Response, StatusCode, header::HeaderName};
 use serde::Deserialize;
+use std::sync::Arc;
 use zwipe_core::domain::card::scryfall_data::ScryfallData;
 
-// ==============================
-//  equip onto scryfall requests
-// ==============================
-pub(super) const USER_AGENT_VALUE: &str = "zwipe/0.0";
-pub(super) const ACCEPT_VALUE: &str = "*/*";
-pub(super) const SCRYFALL_API_BASE: &str = "https://api.scryfall.com";
-pub(super) const CARDS_SEARCH_ENDPOINT: &str = "/cards/search";
-
-/// Scryfall search response wrapper.
+/// Scryfall search response wrapper: one page of results.
 #[derive(Deserialize, Debug)]
 struct ScryfallDataSearchResponse {
     data: Vec<ScryfallData>,
+    /// Absolute URL of the next page, when `has_more`.
+    #[serde(default)]
+    next_page: Option<String>,
+}
+
+/// Scryfall set list response: one page of the set catalog.
+#[derive(Deserialize, Debug)]
+struct ScryfallSetListResponse {
+    data: Vec<ScryfallSet>,
+    /// Absolute URL of the next page, when `has_more`.
+    #[serde(default)]
+    next_page: Option<String>,
+}
+
+/// Builds the Scryfall search query matching every printing in `set_codes`,
+/// e.g. `e:mh3 or e:one`. Codes are trimmed and lowercased; an empty list or
+/// a code that isn't alphanumeric is refused rather than sent as syntax.
+pub fn set_search_query(set_codes: &[String]) -> anyhow::Result<String> {
+    if set_codes.is_empty() {
+        anyhow::bail!("no set codes given");
+    }
+    let terms = set_codes
+        .iter()
+        .map(|code| {
+            let code = code.trim().to_lowercase();
+            if code.is_empty() || !code.chars().all(|c| c.is_ascii_alphanumeric()) {
+                anyhow::bail!("invalid set code {code:?}");
+            }
+            Ok(format!("e:{code}"))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(terms.join(" or "))
+}
+
+/// Fetches every printing in `set_codes` (tokens and variations included,
+/// as in the bulk files) through the card search endpoint, one page at a
+/// time. A search matching nothing yields no cards rather than an error.
+pub async fn amass_sets(set_codes: &[String]) -> anyhow::Result<Vec<ScryfallData>> {
+    let query = set_search_query(set_codes)?;
+    let client = ScryfallClient::shared();
+    let url = SCRYFALL_API_BASE.to_string() + CARDS_SEARCH_ENDPOINT;
+    let mut page = client.untap(&url).tutor_for(&query).with_query(&[
+        ("unique", "prints"),
+        ("include_extras", "true"),
+        ("include_variations", "true"),
+    ]);
+    let mut cards = Vec::new();
+    loop {
+        let response = page
+            .cast()
+            .await
+            .context("failed to get on cards search endpoint")?;
+        if response.status() == StatusCode::NOT_FOUND {
+            break;
+        }
+        let response: ScryfallDataSearchResponse = response
+            .error_for_status()
+            .context("cards search failed")?
+            .json()
+            .await
+            .context("failed to parse cards search page")?;
+        cards.extend(response.data);
+        let Some(next_page) = response.next_page else {
+            break;
+        };
+        page = client.untap(&next_page);
+    }
+    Ok(cards)
+}
+
+/// Fetches Scryfall's whole set catalog. It comes back as a single page
+/// today; further pages are followed should that change.
+pub async fn surveil_sets() -> anyhow::Result<Vec<ScryfallSet>> {
+    let client = ScryfallClient::shared();
+    let mut page = client.untap(&(SCRYFALL_API_BASE.to_string() + SETS_ENDPOINT));
+    let mut sets = Vec::new();
+    loop {
+        let response: ScryfallSetListResponse = page
+            .cast()
+            .await
+            .context("failed to get on sets endpoint")?
+            .error_for_status()
+            .context("sets list failed")?
+            .json()
+            .await
+            .context("failed to parse sets page")?;
+        sets.extend(response.data);
+        let Some(next_page) = response.next_page else {
+            break;
+        };
+        page = client.untap(&next_page);
+    }
+    Ok(sets)
 }
 
 // =========
 //  helpers
 // =========
 
-/// Wraps a `RequestBuilder` with Scryfall API headers (User-Agent, Accept).
+/// A Scryfall request (headers already equipped) and the limiter it must
+/// pass before it's sent.
 #[derive(Debug)]
-pub(super) struct Planeswalker(RequestBuilder);
+pub(super) struct Planeswalker {
+    request: RequestBuilder,
+    limiter: Arc<TokenBucket>,
+}
 
 impl Planeswalker {
-    /// Creates a new GET request builder with Scryfall headers.
-    pub(super) fn untap(client: Client, full_url: &str) -> Self {
-        Self(
-            client
-                .get(full_url)
-                .header(USER_AGENT, USER_AGENT_VALUE)
-                .header(ACCEPT, ACCEPT_VALUE),
-        )
+    /// Wraps a request built by [`ScryfallClient::untap`].
+    pub(super) fn new(request: RequestBuilder, limiter: Arc<TokenBucket>) -> Self {
+        Self { request, limiter }
     }
-    /// Sends the request.
+
+    /// Waits for the shared limiter, then sends the request.
+    ///
+    /// A 429 is retried up to [`SCRYFALL_MAX_RETRIES`] times, each after
+    /// [`backoff`] — which honors `Retry-After` — holding off the whole
+    /// limiter meanwhile. The last attempt's response is returned as is.
     pub(super) async fn cast(self) -> Result<Response, reqwest::Error> {
-        self.0.send().await
+        let mut attempt = 0;
+        loop {
+            // A body-less GET always clones; if one can't, it's sent once.
+            let retryable = if attempt < SCRYFALL_MAX_RETRIES {
+                self.request.try_clone()
+            } else {
+                None
+            };
+            self.limiter.acquire().await;
+            let Some(request) = retryable else {
+                return self.request.send().await;
+            };
+            let response = request.send().await?;
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return Ok(response);
+            }
+            let wait = backoff(attempt, retry_after(response.headers()));
+            tracing::warn!(
+                "scryfall throttled {}; retrying in {wait:?}",
+                response.url()
+            );
+            self.limiter.hold_off(wait);
+            attempt += 1;
+        }
+    }
+
+    /// Adds a header to the request.
+    pub(super) fn header(self, key: HeaderName, value: String) -> Self {
+        Self {
+            request: self.request.header(key, value),
+            limiter: self.limiter,
+        }
+    }
+
+    /// Builds the request without sending it.
+    #[cfg(test)]
+    pub(super) fn build(self) -> Result<reqwest::Request, reqwest::Error> {
+        self.request.build()
     }
 
     fn tutor_for(self, search_str: &str) -> Self {
-        Planeswalker(self.0.query(&[("q", search_str)]))
+        self.with_query(&[("q", search_str)])
+    }
+
+    fn with_query(self, query: &[(&str, &str)]) -> Self {
+        Self {
+            request: self.request.query(query),
+            limiter: self.limiter,
+        }
     }
 
     /// Searches for a card by name via the Scryfall search endpoint.
     #[allow(dead_code)]
     pub(super) async fn tutor(
-        client: Client,
+        client: &ScryfallClient,
         search_str: &str,
     ) -> anyhow::Result<Vec<ScryfallData>> {
         let url = SCRYFALL_API_BASE.to_string() + CARDS_SEARCH_ENDPOINT;
-        let urza = Planeswalker::untap(client, &url);
+        let urza = client.untap(&url);
 
         let get_result = urza
             .tutor_for(search_str)
@@ -79,15 +219,21 @@ impl Planeswalker {
     }
 }
 
-/// Extension trait for creating a `Planeswalker` from a reqwest `Client`.
-#[allow(dead_code)]
-pub(super) trait CreatePlaneswalker {
-    /// Builds a Planeswalker targeting the given Scryfall endpoint.
-    fn into_planeswalker(self, endpoint: &str) -> Planeswalker;
-}
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_search_query_joins_normalized_codes() {
+        let codes = ["MH3".to_string(), " one ".to_string()];
+        assert_eq!(set_search_query(&codes).unwrap(), "e:mh3 or e:one");
+    }
 
-impl CreatePlaneswalker for Client {
-    fn into_planeswalker(self, endpoint: &str) -> Planeswalker {
-        Planeswalker::untap(self, endpoint)
+    #[test]
+    fn set_search_query_refuses_empty_and_non_alphanumeric_codes() {
+        assert!(set_search_query(&[]).is_err());
+        assert!(set_search_query(&["mh3 or t:land".to_string()]).is_err());
+        assert!(set_search_query(&[String::new()]).is_err());
     }
 }
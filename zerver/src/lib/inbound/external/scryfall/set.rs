@@ -0,0 +1,62 @@
+//! Record type for Scryfall's set catalog (`GET /sets`).
+//!
+//! Cards carry only their set's code and name; the set symbol lives on the
+//! set. Child sets (tokens, promos, art series) point `icon_svg_uri` at their
+//! parent's symbol, so it can't be derived from the code alone.
+
+use serde::Deserialize;
+
+/// One set from Scryfall's set catalog.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScryfallSet {
+    /// Lowercase set code, e.g. `tmh2`.
+    pub code: String,
+    /// Full set name, e.g. `Modern Horizons 2 Tokens`.
+    pub name: String,
+    /// Code of the set this one belongs to, e.g. `mh2`; absent for top-level
+    /// sets.
+    #[serde(default)]
+    pub parent_set_code: Option<String>,
+    /// The set's monochrome SVG symbol.
+    pub icon_svg_uri: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A token set shaped like the real API: unknown fields are ignored and
+    /// the icon is the parent's.
+    #[test]
+    fn parses_real_shaped_record() {
+        let json = r#"{
+            "object": "set",
+            "id": "c3b5d9d6-3c23-4e6f-8d63-0a38f0e4ba21",
+            "code": "tmh2",
+            "name": "Modern Horizons 2 Tokens",
+            "set_type": "token",
+            "card_count": 34,
+            "parent_set_code": "mh2",
+            "digital": false,
+            "icon_svg_uri": "https://svgs.scryfall.io/sets/mh2.svg?1700000000"
+        }"#;
+
+        let set: ScryfallSet = serde_json::from_str(json).unwrap();
+        assert_eq!(set.code, "tmh2");
+        assert_eq!(set.parent_set_code.as_deref(), Some("mh2"));
+        assert!(set.icon_svg_uri.contains("/mh2.svg"));
+    }
+
+    /// Top-level sets have no parent.
+    #[test]
+    fn parent_defaults_to_none() {
+        let json = r#"{
+            "code": "mh2",
+            "name": "Modern Horizons 2",
+            "icon_svg_uri": "https://svgs.scryfall.io/sets/mh2.svg"
+        }"#;
+
+        let set: ScryfallSet = serde_json::from_str(json).unwrap();
+        assert!(set.parent_set_code.is_none());
+    }
+}
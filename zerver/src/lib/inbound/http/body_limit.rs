@@ -0,0 +1,52 @@
+//! Request body size caps.
+//!
+//! Every route accepts bodies up to [`BodyLimits::default`]; the import
+//! routes (decklists, deck backups, collection exports) up to
+//! [`BodyLimits::import`]. Anything larger is refused with `413 Payload Too
+//! Large` before it's buffered: a transport-level
+//! [`RequestBodyLimitLayer`](tower_http::limit::RequestBodyLimitLayer) caps
+//! every request at the larger of the two, and axum's
+//! [`DefaultBodyLimit`] holds each route to its own cap.
+
+use axum::extract::DefaultBodyLimit;
+
+/// Default cap on request bodies: 1 MiB.
+pub const REQUEST_BODY_LIMIT_DEFAULT: usize = 1024 * 1024;
+
+/// Default cap on import request bodies: 16 MiB.
+pub const IMPORT_BODY_LIMIT_DEFAULT: usize = 16 * 1024 * 1024;
+
+/// Request body caps, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BodyLimits {
+    /// Cap for every route without its own.
+    pub default: usize,
+    /// Cap for the import routes.
+    pub import: usize,
+}
+
+impl Default for BodyLimits {
+    fn default() -> Self {
+        Self {
+            default: REQUEST_BODY_LIMIT_DEFAULT,
+            import: IMPORT_BODY_LIMIT_DEFAULT,
+        }
+    }
+}
+
+impl BodyLimits {
+    /// The largest body any route accepts.
+    pub fn ceiling(&self) -> usize {
+        self.default.max(self.import)
+    }
+
+    /// Layer holding a route to the default cap.
+    pub fn default_layer(&self) -> DefaultBodyLimit {
+        DefaultBodyLimit::max(self.default)
+    }
+
+    /// Layer holding an import route to the import cap.
+    pub fn import_layer(&self) -> DefaultBodyLimit {
+        DefaultBodyLimit::max(self.import)
+    }
+}
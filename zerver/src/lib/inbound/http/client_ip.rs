@@ -0,0 +1,265 @@
+//! Real client IP resolution behind trusted reverse proxies.
+//!
+//! Behind a proxy the socket peer is the proxy, not the client. Proxies report
+//! the client in a forwarding header, but any client can send those headers
+//! too — so they're only believed when the immediate peer is a configured
+//! trusted proxy. Rate limiting and request logging both key off this.
+
+use axum::{
+    extract::ConnectInfo,
+    http::{HeaderMap, Request},
+};
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    str::FromStr,
+    sync::Arc,
+};
+use thiserror::Error;
+
+/// Cloudflare's header carrying the true client IP (set at its edge, where a
+/// client-supplied value is overwritten).
+const CF_CONNECTING_IP: &str = "cf-connecting-ip";
+
+/// Single-hop real IP header (nginx and most load balancers).
+const X_REAL_IP: &str = "x-real-ip";
+
+/// Hop-by-hop chain: `client, proxy1, proxy2`, each proxy appending its peer.
+const X_FORWARDED_FOR: &str = "x-forwarded-for";
+
+/// Error returned when a trusted proxy entry isn't an IP or CIDR.
+#[derive(Debug, Clone, Error)]
+#[error("invalid trusted proxy {0:?}: expected an IP address or CIDR (e.g. 10.0.0.0/8)")]
+pub struct InvalidTrustedProxy(String);
+
+/// An IP network in CIDR notation. A bare address is a single-host network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IpCidr {
+    network: IpAddr,
+    prefix: u8,
+}
+
+impl IpCidr {
+    /// True if `ip` falls inside this network. IPv4-mapped IPv6 peers
+    /// (`::ffff:a.b.c.d`, as a dual-stack listener reports them) match the
+    /// equivalent IPv4 network.
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let ip = match ip {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(ip, IpAddr::V4),
+            v4 => v4,
+        };
+        match (self.network, ip) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - u32::from(self.prefix))
+                    .unwrap_or(0);
+                u32::from(net) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - u32::from(self.prefix))
+                    .unwrap_or(0);
+                u128::from(net) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for IpCidr {
+    type Err = InvalidTrustedProxy;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidTrustedProxy(s.to_string());
+        let (addr, prefix) = match s.trim().split_once('/') {
+            Some((addr, prefix)) => (addr, Some(prefix)),
+            None => (s.trim(), None),
+        };
+        let network: IpAddr = addr.parse().map_err(|_| invalid())?;
+        let max = if network.is_ipv4() { 32 } else { 128 };
+        let prefix = match prefix {
+            Some(p) => p.parse::<u8>().map_err(|_| invalid())?,
+            None => max,
+        };
+        if prefix > max {
+            return Err(invalid());
+        }
+        Ok(Self { network, prefix })
+    }
+}
+
+/// The peers whose forwarding headers are believed.
+///
+/// Defaults to loopback only: in production `cloudflared` proxies every
+/// request from `127.0.0.1`, and a directly connected client is never on
+/// loopback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustedProxies(Arc<[IpCidr]>);
+
+impl Default for TrustedProxies {
+    fn default() -> Self {
+        Self(Arc::from([
+            IpCidr {
+                network: IpAddr::V4(Ipv4Addr::LOCALHOST),
+                prefix: 8,
+            },
+            IpCidr {
+                network: IpAddr::V6(Ipv6Addr::LOCALHOST),
+                prefix: 128,
+            },
+        ]))
+    }
+}
+
+impl FromStr for TrustedProxies {
+    type Err = InvalidTrustedProxy;
+
+    /// Parses a comma-separated list of IPs and CIDRs. An empty string trusts
+    /// no proxy (forwarding headers are never read).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .filter(|entry| !entry.trim().is_empty())
+            .map(IpCidr::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .map(|cidrs| Self(cidrs.into()))
+    }
+}
+
+impl TrustedProxies {
+    /// True if `ip` is a trusted proxy.
+    pub fn is_trusted(&self, ip: IpAddr) -> bool {
+        self.0.iter().any(|cidr| cidr.contains(ip))
+    }
+
+    /// Resolves the client IP for a request arriving from `peer`.
+    ///
+    /// An untrusted (or unknown) peer is the client, whatever its headers say.
+    /// From a trusted peer the first present header wins, in order:
+    /// `CF-Connecting-IP`, `X-Real-IP`, then `X-Forwarded-For` walked right to
+    /// left past any trusted hops — the first untrusted hop is the client, as
+    /// everything left of it was written by that client. Falls back to the
+    /// peer when no header yields an address.
+    pub fn client_ip(&self, headers: &HeaderMap, peer: Option<IpAddr>) -> Option<IpAddr> {
+        let peer = peer?;
+        if !self.is_trusted(peer) {
+            return Some(peer);
+        }
+
+        let single = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<IpAddr>().ok())
+        };
+        if let Some(ip) = single(CF_CONNECTING_IP).or_else(|| single(X_REAL_IP)) {
+            return Some(ip);
+        }
+
+        let chain: Vec<&str> = headers
+            .get_all(X_FORWARDED_FOR)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(','))
+            .collect();
+        let mut client = None;
+        for hop in chain.iter().rev() {
+            // An unparseable hop breaks the chain of trust; stop at the last
+            // address a trusted proxy vouched for.
+            let Ok(ip) = hop.trim().parse::<IpAddr>() else {
+                break;
+            };
+            client = Some(ip);
+            if !self.is_trusted(ip) {
+                break;
+            }
+        }
+        client.or(Some(peer))
+    }
+
+    /// [`client_ip`](Self::client_ip) with the peer taken from the request's
+    /// `ConnectInfo` extension.
+    pub fn client_ip_of<T>(&self, request: &Request<T>) -> Option<IpAddr> {
+        let peer = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|info| info.0.ip());
+        self.client_ip(request.headers(), peer)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.append(*name, HeaderValue::from_str(value).unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn cidr_parsing_and_containment() {
+        let net: IpCidr = "10.0.0.0/8".parse().unwrap();
+        assert!(net.contains(ip("10.200.3.4")));
+        assert!(!net.contains(ip("11.0.0.1")));
+        assert!(net.contains(ip("::ffff:10.1.2.3")));
+
+        let host: IpCidr = "2001:db8::1".parse().unwrap();
+        assert!(host.contains(ip("2001:db8::1")));
+        assert!(!host.contains(ip("2001:db8::2")));
+
+        assert!(
+            "0.0.0.0/0"
+                .parse::<IpCidr>()
+                .unwrap()
+                .contains(ip("8.8.8.8"))
+        );
+        assert!("10.0.0.0/33".parse::<IpCidr>().is_err());
+        assert!("not-an-ip".parse::<TrustedProxies>().is_err());
+    }
+
+    #[test]
+    fn spoofed_forwarded_for_from_untrusted_peer_is_ignored() {
+        let proxies: TrustedProxies = "10.0.0.0/8".parse().unwrap();
+        let spoofed = headers(&[
+            (X_FORWARDED_FOR, "1.2.3.4"),
+            (X_REAL_IP, "1.2.3.4"),
+            (CF_CONNECTING_IP, "1.2.3.4"),
+        ]);
+        let client = proxies.client_ip(&spoofed, Some(ip("203.0.113.7")));
+        assert_eq!(client, Some(ip("203.0.113.7")));
+    }
+
+    #[test]
+    fn forwarded_for_from_trusted_peer_is_honored() {
+        let proxies: TrustedProxies = "10.0.0.0/8".parse().unwrap();
+        // client-written junk on the left, then the real client, then an
+        // inner trusted proxy hop
+        let forwarded = headers(&[(X_FORWARDED_FOR, "6.6.6.6, 198.51.100.4, 10.0.0.2")]);
+        let client = proxies.client_ip(&forwarded, Some(ip("10.0.0.1")));
+        assert_eq!(client, Some(ip("198.51.100.4")));
+    }
+
+    #[test]
+    fn trusted_peer_without_headers_falls_back_to_peer() {
+        let proxies = TrustedProxies::default();
+        let client = proxies.client_ip(&HeaderMap::new(), Some(ip("127.0.0.1")));
+        assert_eq!(client, Some(ip("127.0.0.1")));
+        assert_eq!(proxies.client_ip(&HeaderMap::new(), None), None);
+    }
+
+    #[test]
+    fn real_ip_headers_take_precedence_over_forwarded_for() {
+        let proxies = TrustedProxies::default();
+        let both = headers(&[(X_FORWARDED_FOR, "198.51.100.4"), (X_REAL_IP, "192.0.2.9")]);
+        let client = proxies.client_ip(&both, Some(ip("127.0.0.1")));
+        assert_eq!(client, Some(ip("192.0.2.9")));
+    }
+}
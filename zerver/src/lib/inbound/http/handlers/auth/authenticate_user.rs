@@ -1,5 +1,9 @@
 #[cfg(feature = "zerver")]
-use axum::{Json, extract::State, http::StatusCode};
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+};
 #[cfg(feature = "zerver")]
 use zwipe_core::domain::auth::models::session::Session;
 #[cfg(feature = "zerver")]
@@ -59,11 +63,13 @@ impl TryFrom<HttpAuthenticateUser> for AuthenticateUser {
 #[cfg(feature = "zerver")]
 pub async fn authenticate_user(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(body): Json<HttpAuthenticateUser>,
 ) -> Result<(StatusCode, Json<Session>), ApiError> {
     let mut request = AuthenticateUser::new(&body.identifier, &body.password)?;
     request.platform = body.platform;
     request.client_version = body.client_version;
+    request.device_label = super::request_device_label(&headers);
 
     let session = state
         .auth_service
@@ -9,7 +9,7 @@ use crate::{
         auth::requests::change_email::{ChangeEmail, ChangeEmailError, InvalidChangeEmail},
         metrics::models::kinds::AuditAction,
     },
-    inbound::http::{ApiError, AppState, Log500, middleware::AuthenticatedUser},
+    inbound::http::{ApiError, AppState, Log500, middleware::SessionUser},
 };
 #[cfg(feature = "zerver")]
 use zwipe_core::domain::user::User;
@@ -44,7 +44,7 @@ impl From<InvalidChangeEmail> for ApiError {
 /// Changes the user's email after verifying the password.
 #[cfg(feature = "zerver")]
 pub async fn change_email(
-    user: AuthenticatedUser,
+    SessionUser(user): SessionUser,
     State(state): State<AppState>,
     Json(body): Json<HttpChangeEmail>,
 ) -> Result<(StatusCode, Json<User>), ApiError> {
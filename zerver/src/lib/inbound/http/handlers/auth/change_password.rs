@@ -1,3 +1,5 @@
+#[cfg(feature = "hibp")]
+use crate::domain::auth::models::password::Password;
 #[cfg(feature = "zerver")]
 use axum::{Json, extract::State, http::StatusCode};
 #[cfg(feature = "zerver")]
@@ -11,7 +13,7 @@ use crate::{
         },
         metrics::models::kinds::AuditAction,
     },
-    inbound::http::{ApiError, AppState, Log500, middleware::AuthenticatedUser},
+    inbound::http::{ApiError, AppState, Log500, middleware::SessionUser},
 };
 
 #[cfg(feature = "zerver")]
@@ -45,10 +47,14 @@ impl From<InvalidChangePassword> for ApiError {
 /// Changes the user's password after verifying the current one.
 #[cfg(feature = "zerver")]
 pub async fn change_password(
-    user: AuthenticatedUser,
+    SessionUser(user): SessionUser,
     State(state): State<AppState>,
     Json(body): Json<HttpChangePassword>,
 ) -> Result<(StatusCode, Json<()>), ApiError> {
+    #[cfg(feature = "hibp")]
+    Password::new_checked(&body.new_password)
+        .await
+        .map_err(InvalidChangePassword::Password)?;
     let request = ChangePassword::new(user.id, &body.current_password, &body.new_password)?;
 
     state
@@ -6,7 +6,7 @@ use crate::{
         },
         metrics::models::kinds::AuditAction,
     },
-    inbound::http::{ApiError, AppState, Log500, middleware::AuthenticatedUser},
+    inbound::http::{ApiError, AppState, Log500, middleware::SessionUser},
 };
 #[cfg(feature = "zerver")]
 use axum::{Json, extract::State, http::StatusCode};
@@ -46,7 +46,7 @@ impl From<InvalidChangeUsername> for ApiError {
 /// Changes the user's username after verifying the password.
 #[cfg(feature = "zerver")]
 pub async fn change_username(
-    user: AuthenticatedUser,
+    SessionUser(user): SessionUser,
     State(state): State<AppState>,
     Json(body): Json<HttpChangeUsername>,
 ) -> Result<(StatusCode, Json<User>), ApiError> {
@@ -0,0 +1,52 @@
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::auth::requests::create_api_key::{
+        CreateApiKey, CreateApiKeyError, InvalidCreateApiKey,
+    },
+    inbound::http::{ApiError, AppState, Log500, middleware::SessionUser},
+};
+#[cfg(feature = "zerver")]
+use axum::{Json, extract::State, http::StatusCode};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::auth::models::api_key::CreatedApiKey;
+#[cfg(feature = "zerver")]
+use zwipe_core::http::contracts::auth::HttpCreateApiKey;
+
+#[cfg(feature = "zerver")]
+impl From<CreateApiKeyError> for ApiError {
+    fn from(value: CreateApiKeyError) -> Self {
+        match value {
+            CreateApiKeyError::Database(e) => e.log_500(),
+            CreateApiKeyError::ApiKeyFromDb(e) => e.log_500(),
+        }
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl From<InvalidCreateApiKey> for ApiError {
+    fn from(value: InvalidCreateApiKey) -> Self {
+        Self::UnprocessableEntity(format!("invalid label: {}", value))
+    }
+}
+
+/// Mints an API key for the authenticated user. The response carries the
+/// secret, which is never shown again.
+///
+/// Keys are minted from a signed-in session only ([`SessionUser`]): an API
+/// key can't create further keys, so a leaked key can't entrench itself.
+#[cfg(feature = "zerver")]
+pub async fn create_api_key(
+    SessionUser(user): SessionUser,
+    State(state): State<AppState>,
+    Json(body): Json<HttpCreateApiKey>,
+) -> Result<(StatusCode, Json<CreatedApiKey>), ApiError> {
+    let request = CreateApiKey::new(user.id, &body.label, body.scope)?;
+
+    let created = state
+        .auth_service
+        .create_api_key(&request)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok((StatusCode::CREATED, Json(created)))
+}
@@ -7,7 +7,7 @@ use crate::{domain::auth::requests::delete_user::InvalidDeleteUser, inbound::htt
 #[cfg(feature = "zerver")]
 use crate::{
     domain::auth::requests::delete_user::{DeleteUser, DeleteUserError},
-    inbound::http::{AppState, Log500, middleware::AuthenticatedUser},
+    inbound::http::{AppState, Log500, middleware::SessionUser},
 };
 
 #[cfg(feature = "zerver")]
@@ -37,7 +37,7 @@ impl From<InvalidDeleteUser> for ApiError {
 /// Deletes the user's account and all associated data after password verification.
 #[cfg(feature = "zerver")]
 pub async fn delete_user(
-    user: AuthenticatedUser,
+    SessionUser(user): SessionUser,
     State(state): State<AppState>,
     Json(body): Json<HttpDeleteUser>,
 ) -> Result<StatusCode, ApiError> {
@@ -0,0 +1,24 @@
+#[cfg(feature = "zerver")]
+use crate::inbound::http::{ApiError, AppState, middleware::AuthenticatedUser};
+#[cfg(feature = "zerver")]
+use axum::{Json, extract::State, http::StatusCode};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::user::{User, requests::get_user::GetUser};
+
+/// Returns the user the bearer token belongs to, freshly read from the user
+/// service — "who am I" for a client restoring a stored token, without
+/// decoding the JWT itself.
+#[cfg(feature = "zerver")]
+pub async fn get_current_user(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<(StatusCode, Json<User>), ApiError> {
+    let request = GetUser::from(user);
+
+    state
+        .user_service
+        .get_user(&request)
+        .await
+        .map_err(ApiError::from)
+        .map(|user| (StatusCode::OK, Json(user)))
+}
@@ -0,0 +1,35 @@
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::auth::requests::list_api_keys::ListApiKeysError,
+    inbound::http::{ApiError, AppState, Log500, middleware::SessionUser},
+};
+#[cfg(feature = "zerver")]
+use axum::{Json, extract::State, http::StatusCode};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::auth::models::api_key::ApiKey;
+
+#[cfg(feature = "zerver")]
+impl From<ListApiKeysError> for ApiError {
+    fn from(value: ListApiKeysError) -> Self {
+        match value {
+            ListApiKeysError::Database(e) => e.log_500(),
+            ListApiKeysError::ApiKeyFromDb(e) => e.log_500(),
+        }
+    }
+}
+
+/// Lists the authenticated user's active API keys, newest first, without
+/// their secrets.
+#[cfg(feature = "zerver")]
+pub async fn list_api_keys(
+    SessionUser(user): SessionUser,
+    State(state): State<AppState>,
+) -> Result<(StatusCode, Json<Vec<ApiKey>>), ApiError> {
+    let keys = state
+        .auth_service
+        .list_api_keys(user.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok((StatusCode::OK, Json(keys)))
+}
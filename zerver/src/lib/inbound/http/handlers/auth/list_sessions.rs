@@ -0,0 +1,34 @@
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::auth::requests::list_sessions::ListSessionsError,
+    inbound::http::{ApiError, AppState, Log500, middleware::SessionUser},
+};
+#[cfg(feature = "zerver")]
+use axum::{Json, extract::State, http::StatusCode};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::auth::models::session::SessionInfo;
+
+#[cfg(feature = "zerver")]
+impl From<ListSessionsError> for ApiError {
+    fn from(value: ListSessionsError) -> Self {
+        match value {
+            ListSessionsError::Database(e) => e.log_500(),
+        }
+    }
+}
+
+/// Lists the authenticated user's live sessions, most recently refreshed
+/// first, without their refresh tokens.
+#[cfg(feature = "zerver")]
+pub async fn list_sessions(
+    SessionUser(user): SessionUser,
+    State(state): State<AppState>,
+) -> Result<(StatusCode, Json<Vec<SessionInfo>>), ApiError> {
+    let sessions = state
+        .auth_service
+        .list_sessions(user.id)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok((StatusCode::OK, Json(sessions)))
+}
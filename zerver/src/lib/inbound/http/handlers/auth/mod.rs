@@ -8,8 +8,16 @@ pub mod change_email;
 pub mod change_password;
 /// Username change handler.
 pub mod change_username;
+/// API key creation handler.
+pub mod create_api_key;
 /// Account deletion handler.
 pub mod delete_user;
+/// Current-user ("who am I") handler.
+pub mod get_current_user;
+/// API key listing handler.
+pub mod list_api_keys;
+/// Session listing handler.
+pub mod list_sessions;
 /// Session refresh handler.
 pub mod refresh_session;
 /// Registration handler.
@@ -20,7 +28,25 @@ pub mod request_password_reset;
 pub mod resend_verification;
 /// Password reset completion handler.
 pub mod reset_password;
+/// API key revocation handler.
+pub mod revoke_api_key;
+/// Single session revocation handler.
+pub mod revoke_session;
 /// Session revocation handler.
 pub mod revoke_sessions;
 /// Email verification handler.
 pub mod verify_email;
+
+#[cfg(feature = "zerver")]
+use crate::domain::auth::models::device_label::{DEVICE_NAME_HEADER, device_label};
+#[cfg(feature = "zerver")]
+use axum::http::{HeaderMap, header::USER_AGENT};
+
+/// Labels the device a session is opened from: the client-provided
+/// [`DEVICE_NAME_HEADER`], else the `User-Agent`. Headers that aren't valid
+/// text count as absent.
+#[cfg(feature = "zerver")]
+fn request_device_label(headers: &HeaderMap) -> Option<String> {
+    let header = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+    device_label(header(DEVICE_NAME_HEADER), header(USER_AGENT.as_str()))
+}
@@ -10,7 +10,11 @@ use crate::{
     inbound::http::{ApiError, AppState, Log500},
 };
 #[cfg(feature = "zerver")]
-use axum::{Json, extract::State, http::StatusCode};
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+};
 #[cfg(feature = "zerver")]
 use zwipe_core::domain::auth::models::session::Session;
 #[cfg(feature = "zerver")]
@@ -40,6 +44,16 @@ impl From<RefreshSessionError> for ApiError {
                 tracing::warn!(event = "token_refresh_failure", reason = "revoked", user_id = %u);
                 Self::Unauthorized("invalid refresh token".to_string())
             }
+            RefreshSessionError::Reused(u) => {
+                tracing::warn!(event = "token_refresh_failure", reason = "reused", user_id = %u);
+                Self::Unauthorized(
+                    "refresh token already used, signed out on all devices".to_string(),
+                )
+            }
+            RefreshSessionError::Superseded(u) => {
+                tracing::warn!(event = "token_refresh_failure", reason = "superseded", user_id = %u);
+                Self::Unauthorized("invalid refresh token".to_string())
+            }
             RefreshSessionError::Forbidden(u) => {
                 tracing::warn!(event = "token_refresh_failure", reason = "forbidden", user_id = %u);
                 Self::Forbidden("invalid refresh token".to_string())
@@ -73,10 +87,12 @@ impl TryFrom<HttpRefreshSession> for RefreshSession {
 #[cfg(feature = "zerver")]
 pub async fn refresh_session(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(body): Json<HttpRefreshSession>,
 ) -> Result<(StatusCode, Json<Session>), ApiError> {
     let mut request = RefreshSession::new(&body.user_id, &body.refresh_token)?;
     request.client_version = body.client_version;
+    request.device_label = super::request_device_label(&headers);
 
     let session = state
         .auth_service
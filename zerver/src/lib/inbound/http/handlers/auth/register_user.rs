@@ -1,3 +1,5 @@
+#[cfg(feature = "hibp")]
+use crate::domain::auth::models::password::Password;
 #[cfg(feature = "zerver")]
 use crate::inbound::http::Log500;
 #[cfg(feature = "zerver")]
@@ -17,7 +19,11 @@ use crate::{
     inbound::http::{ApiError, AppState},
 };
 #[cfg(feature = "zerver")]
-use axum::{Json, extract::State, http::StatusCode};
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+};
 #[cfg(feature = "zerver")]
 use zwipe_core::domain::auth::models::session::Session;
 #[cfg(feature = "zerver")]
@@ -103,11 +109,17 @@ impl TryFrom<HttpRegisterUser> for RegisterUser {
 #[cfg(feature = "zerver")]
 pub async fn register_user(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(body): Json<HttpRegisterUser>,
 ) -> Result<(StatusCode, Json<Session>), ApiError> {
+    #[cfg(feature = "hibp")]
+    Password::new_checked(&body.password)
+        .await
+        .map_err(InvalidRegisterUser::Password)?;
     let mut request = RegisterUser::new(&body.username, &body.email, &body.password)?;
     request.platform = body.platform;
     request.client_version = body.client_version;
+    request.device_label = super::request_device_label(&headers);
     tracing::info!(event = "register", username = %body.username);
     let session = state
         .auth_service
@@ -1,3 +1,5 @@
+#[cfg(feature = "hibp")]
+use crate::domain::auth::models::password::Password;
 #[cfg(feature = "zerver")]
 use axum::{Json, extract::State, http::StatusCode};
 #[cfg(feature = "zerver")]
@@ -33,6 +35,10 @@ pub async fn reset_password(
     State(state): State<AppState>,
     Json(body): Json<HttpResetPassword>,
 ) -> Result<StatusCode, ApiError> {
+    #[cfg(feature = "hibp")]
+    Password::new_checked(&body.new_password)
+        .await
+        .map_err(|e| ResetPasswordError::InvalidPassword(e.to_string()))?;
     let request = ResetPassword::new(body.token, &body.new_password)?;
     let user_id = state
         .auth_service
@@ -0,0 +1,38 @@
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::auth::requests::revoke_api_key::{RevokeApiKey, RevokeApiKeyError},
+    inbound::http::{ApiError, AppState, Log500, middleware::SessionUser},
+};
+#[cfg(feature = "zerver")]
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+#[cfg(feature = "zerver")]
+use uuid::Uuid;
+
+#[cfg(feature = "zerver")]
+impl From<RevokeApiKeyError> for ApiError {
+    fn from(value: RevokeApiKeyError) -> Self {
+        match value {
+            RevokeApiKeyError::NotFound => Self::NotFound(RevokeApiKeyError::NotFound.to_string()),
+            RevokeApiKeyError::Database(e) => e.log_500(),
+        }
+    }
+}
+
+/// Revokes one of the authenticated user's API keys; it stops working at once.
+#[cfg(feature = "zerver")]
+pub async fn revoke_api_key(
+    SessionUser(user): SessionUser,
+    State(state): State<AppState>,
+    Path(key_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .auth_service
+        .revoke_api_key(&RevokeApiKey::new(user.id, key_id))
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
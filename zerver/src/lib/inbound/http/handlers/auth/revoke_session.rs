@@ -0,0 +1,41 @@
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::auth::requests::revoke_session::{RevokeSession, RevokeSessionError},
+    inbound::http::{ApiError, AppState, Log500, middleware::SessionUser},
+};
+#[cfg(feature = "zerver")]
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+#[cfg(feature = "zerver")]
+use uuid::Uuid;
+
+#[cfg(feature = "zerver")]
+impl From<RevokeSessionError> for ApiError {
+    fn from(value: RevokeSessionError) -> Self {
+        match value {
+            RevokeSessionError::NotFound => {
+                Self::NotFound(RevokeSessionError::NotFound.to_string())
+            }
+            RevokeSessionError::Database(e) => e.log_500(),
+        }
+    }
+}
+
+/// Revokes one of the authenticated user's sessions; that device must sign
+/// in again once its access token expires.
+#[cfg(feature = "zerver")]
+pub async fn revoke_session(
+    SessionUser(user): SessionUser,
+    State(state): State<AppState>,
+    Path(session_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    state
+        .auth_service
+        .revoke_session(&RevokeSession::new(user.id, session_id))
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
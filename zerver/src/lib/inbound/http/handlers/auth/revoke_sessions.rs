@@ -7,7 +7,7 @@ use crate::{
         auth::requests::revoke_sessions::{RevokeSessions, RevokeSessionsError},
         metrics::models::kinds::{AuditAction, EventKind},
     },
-    inbound::http::{ApiError, AppState, Log500, middleware::AuthenticatedUser},
+    inbound::http::{ApiError, AppState, Log500, middleware::SessionUser},
 };
 
 #[cfg(feature = "zerver")]
@@ -22,7 +22,7 @@ impl From<RevokeSessionsError> for ApiError {
 /// Revokes all sessions for the authenticated user (logs out all devices).
 #[cfg(feature = "zerver")]
 pub async fn revoke_sessions(
-    user: AuthenticatedUser,
+    SessionUser(user): SessionUser,
     State(state): State<AppState>,
 ) -> Result<StatusCode, ApiError> {
     state
@@ -0,0 +1,24 @@
+#[cfg(feature = "zerver")]
+use crate::inbound::http::{ApiError, AppState, middleware::AuthenticatedUser};
+#[cfg(feature = "zerver")]
+use axum::{Json, extract::State, http::StatusCode};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::card::{
+    color_distribution::ColorDistribution, search_card::card_filter::CardQuery,
+};
+
+/// Counts the cards matching a search body per identity color. Takes the same
+/// `CardQuery` as `search_cards`; pagination and sort are ignored.
+#[cfg(feature = "zerver")]
+pub async fn color_distribution(
+    _: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(body): Json<CardQuery>,
+) -> Result<(StatusCode, Json<ColorDistribution>), ApiError> {
+    state
+        .card_service
+        .color_distribution(&body)
+        .await
+        .map_err(ApiError::from)
+        .map(|distribution| (StatusCode::OK, Json(distribution)))
+}
@@ -0,0 +1,75 @@
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::card::requests::considering::ConsideringError,
+    inbound::http::{ApiError, AppState, Log500, middleware::AuthenticatedUser},
+};
+#[cfg(feature = "zerver")]
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::card::Card;
+
+#[cfg(feature = "zerver")]
+impl From<ConsideringError> for ApiError {
+    fn from(value: ConsideringError) -> Self {
+        match value {
+            ConsideringError::CardNotFound => Self::NotFound("card not found".to_string()),
+            ConsideringError::Database(e) => e.log_500(),
+        }
+    }
+}
+
+#[cfg(feature = "zerver")]
+fn parse_card_id(scryfall_data_id: &str) -> Result<uuid::Uuid, ApiError> {
+    uuid::Uuid::try_parse(scryfall_data_id)
+        .map_err(|e| ApiError::UnprocessableEntity(format!("invalid card id: {}", e)))
+}
+
+/// Returns the user's considering list, most recently added first.
+#[cfg(feature = "zerver")]
+pub async fn list_considering(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+) -> Result<(StatusCode, Json<Vec<Card>>), ApiError> {
+    state
+        .card_service
+        .list_considering(user.id)
+        .await
+        .map_err(ApiError::from)
+        .map(|cards| (StatusCode::OK, Json(cards)))
+}
+
+/// Adds a card to the user's considering list (idempotent).
+#[cfg(feature = "zerver")]
+pub async fn add_to_considering(
+    State(state): State<AppState>,
+    Path(scryfall_data_id): Path<String>,
+    user: AuthenticatedUser,
+) -> Result<StatusCode, ApiError> {
+    let scryfall_data_id = parse_card_id(&scryfall_data_id)?;
+    state
+        .card_service
+        .add_to_considering(user.id, scryfall_data_id)
+        .await
+        .map_err(ApiError::from)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Removes a card from the user's considering list (idempotent).
+#[cfg(feature = "zerver")]
+pub async fn remove_from_considering(
+    State(state): State<AppState>,
+    Path(scryfall_data_id): Path<String>,
+    user: AuthenticatedUser,
+) -> Result<StatusCode, ApiError> {
+    let scryfall_data_id = parse_card_id(&scryfall_data_id)?;
+    state
+        .card_service
+        .remove_from_considering(user.id, scryfall_data_id)
+        .await
+        .map_err(ApiError::from)?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,53 @@
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::card::requests::favorites::FavoritesError,
+    inbound::http::{ApiError, AppState, Log500, middleware::AuthenticatedUser},
+};
+#[cfg(feature = "zerver")]
+use axum::{Json, extract::State, http::StatusCode};
+#[cfg(feature = "zerver")]
+use zwipe_core::{domain::card::Card, http::contracts::card::HttpSetFavorites};
+
+#[cfg(feature = "zerver")]
+impl From<FavoritesError> for ApiError {
+    fn from(value: FavoritesError) -> Self {
+        match value {
+            FavoritesError::CardNotFound => Self::NotFound("card not found".to_string()),
+            FavoritesError::Database(e) => e.log_500(),
+        }
+    }
+}
+
+/// Returns the user's favorite cards, most recently favorited first.
+#[cfg(feature = "zerver")]
+pub async fn list_favorites(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+) -> Result<(StatusCode, Json<Vec<Card>>), ApiError> {
+    state
+        .card_service
+        .list_favorites(user.id)
+        .await
+        .map_err(ApiError::from)
+        .map(|cards| (StatusCode::OK, Json(cards)))
+}
+
+/// Applies a batch of favorite/unfavorite toggles in one transaction.
+#[cfg(feature = "zerver")]
+pub async fn set_favorites(
+    State(state): State<AppState>,
+    user: AuthenticatedUser,
+    Json(body): Json<HttpSetFavorites>,
+) -> Result<StatusCode, ApiError> {
+    let changes = body
+        .changes
+        .into_iter()
+        .map(|c| (c.scryfall_data_id, c.favorite))
+        .collect();
+    state
+        .card_service
+        .set_favorites(user.id, changes)
+        .await
+        .map_err(ApiError::from)?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,28 @@
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::card::requests::get_scryfall_data::GetScryfallData,
+    inbound::http::{ApiError, AppState},
+};
+#[cfg(feature = "zerver")]
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::card::printing_summary::CardWithPrintings;
+
+/// Returns a card by Scryfall data ID plus a summary of its other printings.
+#[cfg(feature = "zerver")]
+pub async fn get_card_with_printings(
+    State(state): State<AppState>,
+    Path(scryfall_data_id): Path<String>,
+) -> Result<(StatusCode, Json<CardWithPrintings>), ApiError> {
+    let request = GetScryfallData::new(&scryfall_data_id)?;
+    state
+        .card_service
+        .get_card_with_printings(&request)
+        .await
+        .map_err(ApiError::from)
+        .map(|card| (StatusCode::OK, Json(card)))
+}
@@ -0,0 +1,28 @@
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::card::{models::raw_card::RawCard, requests::get_scryfall_data::GetScryfallData},
+    inbound::http::{ApiError, AppState, middleware::AdminUser},
+};
+#[cfg(feature = "zerver")]
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+/// Returns a card's stored `scryfall_data` and `card_profiles` rows as raw
+/// JSON. Admin only.
+#[cfg(feature = "zerver")]
+pub async fn get_raw_card(
+    _admin: AdminUser,
+    State(state): State<AppState>,
+    Path(scryfall_data_id): Path<String>,
+) -> Result<(StatusCode, Json<RawCard>), ApiError> {
+    let request = GetScryfallData::new(&scryfall_data_id)?;
+    state
+        .card_service
+        .get_raw_card(&request)
+        .await
+        .map_err(ApiError::from)
+        .map(|raw| (StatusCode::OK, Json(raw)))
+}
@@ -0,0 +1,38 @@
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::card::requests::get_set_completion::GetSetCompletionError,
+    inbound::http::{ApiError, AppState, Log500, middleware::AuthenticatedUser},
+};
+#[cfg(feature = "zerver")]
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::card::set_completion::SetCompletion;
+
+#[cfg(feature = "zerver")]
+impl From<GetSetCompletionError> for ApiError {
+    fn from(value: GetSetCompletionError) -> Self {
+        match value {
+            GetSetCompletionError::NotFound => Self::NotFound("set not found".to_string()),
+            GetSetCompletionError::Database(e) => e.log_500(),
+        }
+    }
+}
+
+/// Returns how many of a set's distinct cards the user owns.
+#[cfg(feature = "zerver")]
+pub async fn get_set_completion(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(set_code): Path<String>,
+) -> Result<(StatusCode, Json<SetCompletion>), ApiError> {
+    state
+        .card_service
+        .set_completion(user.id, &set_code)
+        .await
+        .map_err(ApiError::from)
+        .map(|completion| (StatusCode::OK, Json(completion)))
+}
@@ -0,0 +1,37 @@
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::card::requests::get_set_icon::GetSetIconError,
+    inbound::http::{ApiError, AppState, Log500},
+};
+#[cfg(feature = "zerver")]
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::card::set_icon::SetIcon;
+
+#[cfg(feature = "zerver")]
+impl From<GetSetIconError> for ApiError {
+    fn from(value: GetSetIconError) -> Self {
+        match value {
+            GetSetIconError::NotFound => Self::NotFound("set not found".to_string()),
+            GetSetIconError::Database(e) => e.log_500(),
+        }
+    }
+}
+
+/// Returns the symbol SVG URI and rarity color hints for a set code.
+#[cfg(feature = "zerver")]
+pub async fn get_set_icon(
+    State(state): State<AppState>,
+    Path(set_code): Path<String>,
+) -> Result<(StatusCode, Json<SetIcon>), ApiError> {
+    state
+        .card_service
+        .set_icon_uri(&set_code)
+        .await
+        .map_err(ApiError::from)
+        .map(|icon| (StatusCode::OK, Json(icon)))
+}
@@ -1,5 +1,11 @@
 //! Card data handlers.
 
+/// Color pie coverage of a search (`POST /api/card/search/color-distribution`).
+pub mod color_distribution;
+/// Per-user considering list handlers (list / add / remove).
+pub mod considering;
+/// Per-user favorites handlers (list / bulk set).
+pub mod favorites;
 /// Distinct artist names handler.
 pub mod get_artists;
 /// Single card lookup handler.
@@ -8,6 +14,8 @@ pub mod get_card;
 pub mod get_card_roles;
 /// Distinct card type names handler.
 pub mod get_card_types;
+/// Card plus its other printings (`GET /api/card/{id}/with-printings`).
+pub mod get_card_with_printings;
 /// Distinct keyword ability names handler.
 pub mod get_keywords;
 /// Distinct language names handler.
@@ -18,9 +26,17 @@ pub mod get_oracle_tags;
 pub mod get_oracle_words;
 /// All printings of a card by oracle ID.
 pub mod get_printings;
+/// Admin-only raw stored rows of a card (`GET /api/admin/card/{id}/raw`).
+pub mod get_raw_card;
+/// Collection coverage of a set (`GET /api/card/sets/{set_code}/completion`).
+pub mod get_set_completion;
+/// Set symbol icon handler.
+pub mod get_set_icon;
 /// Distinct set names handler.
 pub mod get_sets;
-/// Card search handler.
+/// Card search handlers (full cards and summaries).
 pub mod search_card;
 /// Commander search handler (popularity-ordered, banded, wildcarded).
 pub mod search_commanders;
+/// Staples missing from all of the caller's decks (`POST /api/card/undiscovered-staples`).
+pub mod undiscovered_staples;
@@ -4,9 +4,22 @@ use crate::{
     inbound::http::{ApiError, AppState, Log500, middleware::AuthenticatedUser},
 };
 #[cfg(feature = "zerver")]
-use axum::{Json, extract::State, http::StatusCode};
+use axum::{
+    Json,
+    extract::State,
+    http::{HeaderMap, HeaderValue, StatusCode, header::ACCEPT_LANGUAGE},
+};
 #[cfg(feature = "zerver")]
-use zwipe_core::domain::card::{Card, search_card::card_filter::CardQuery};
+use zwipe_core::domain::card::{
+    Card,
+    card_summary::CardSummary,
+    scryfall_data::language::Language,
+    search_card::card_filter::{CardQuery, error::InvalidCardCriteria},
+};
+
+/// Response header carrying the cursor for the next page of a full page of
+/// search results.
+pub const NEXT_CURSOR_HEADER: &str = "x-next-cursor";
 
 #[cfg(feature = "zerver")]
 impl From<SearchCardsError> for ApiError {
@@ -15,16 +28,63 @@ impl From<SearchCardsError> for ApiError {
     }
 }
 
+#[cfg(feature = "zerver")]
+impl From<InvalidCardCriteria> for ApiError {
+    fn from(value: InvalidCardCriteria) -> Self {
+        Self::UnprocessableEntity(value.to_string())
+    }
+}
+
+/// Narrows a query that names no language to the client's
+/// `Accept-Language` (English when none it lists is printed). Without the
+/// header the query is left as sent.
+#[cfg(feature = "zerver")]
+fn negotiate_language(query: &mut CardQuery, headers: &HeaderMap) {
+    if let Some(accept_language) = headers.get(ACCEPT_LANGUAGE).and_then(|v| v.to_str().ok()) {
+        query.default_language(Language::negotiate(accept_language));
+    }
+}
+
 /// Searches cards using a `CardQuery` deserialized from the JSON body.
+/// A query with no search intent (or a `Random` secondary sort) is refused
+/// rather than served as the whole pool.
+///
+/// When more results may follow under a cursor-resumable sort, the
+/// [`NEXT_CURSOR_HEADER`] header carries the next page's `after_cursor`;
+/// the body stays a bare card array.
 #[cfg(feature = "zerver")]
 pub async fn search_cards(
     _: AuthenticatedUser,
     State(state): State<AppState>,
-    Json(body): Json<CardQuery>,
-) -> Result<(StatusCode, Json<Vec<Card>>), ApiError> {
+    headers: HeaderMap,
+    Json(mut body): Json<CardQuery>,
+) -> Result<(StatusCode, HeaderMap, Json<Vec<Card>>), ApiError> {
+    body.validate()?;
+    negotiate_language(&mut body, &headers);
+    let cards = state.card_service.search_cards(&body).await?;
+    let mut response_headers = HeaderMap::new();
+    if let Some(cursor) = body.next_cursor(&cards)
+        && let Ok(value) = HeaderValue::from_str(&cursor.to_string())
+    {
+        response_headers.insert(NEXT_CURSOR_HEADER, value);
+    }
+    Ok((StatusCode::OK, response_headers, Json(cards)))
+}
+
+/// Searches cards like [`search_cards`] but returns only the summary fields,
+/// for list and swipe views that don't need the full card.
+#[cfg(feature = "zerver")]
+pub async fn search_card_summaries(
+    _: AuthenticatedUser,
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(mut body): Json<CardQuery>,
+) -> Result<(StatusCode, Json<Vec<CardSummary>>), ApiError> {
+    body.validate()?;
+    negotiate_language(&mut body, &headers);
     state
         .card_service
-        .search_cards(&body)
+        .search_card_summaries(&body)
         .await
         .map_err(ApiError::from)
         .map(|cards| (StatusCode::OK, Json(cards)))
@@ -0,0 +1,22 @@
+#[cfg(feature = "zerver")]
+use crate::inbound::http::{ApiError, AppState, middleware::AuthenticatedUser};
+#[cfg(feature = "zerver")]
+use axum::{Json, extract::State, http::StatusCode};
+#[cfg(feature = "zerver")]
+use zwipe_core::{domain::card::Card, http::contracts::card::HttpUndiscoveredStaples};
+
+/// Popular commander-legal cards within a color identity that appear in none
+/// of the caller's decks, most popular first. Feeds the recommendations screen.
+#[cfg(feature = "zerver")]
+pub async fn undiscovered_staples(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(body): Json<HttpUndiscoveredStaples>,
+) -> Result<(StatusCode, Json<Vec<Card>>), ApiError> {
+    state
+        .card_service
+        .undiscovered_staples(user.id, &body.color_identity, body.limit)
+        .await
+        .map_err(ApiError::from)
+        .map(|cards| (StatusCode::OK, Json(cards)))
+}
@@ -18,11 +18,14 @@ use axum::{
 #[cfg(feature = "zerver")]
 use uuid::Uuid;
 #[cfg(feature = "zerver")]
-use zwipe_core::domain::deck::requests::clone_deck::{CloneDeck, InvalidCloneDeck};
+use zwipe_core::domain::deck::requests::{
+    clone_deck::{CloneDeck, InvalidCloneDeck},
+    import_shared_deck::ImportSharedDeck,
+};
 #[cfg(feature = "zerver")]
 use zwipe_core::domain::user::requests::get_user::GetUser;
 #[cfg(feature = "zerver")]
-use zwipe_core::http::contracts::deck::{HttpCloneDeck, HttpClonedDeck};
+use zwipe_core::http::contracts::deck::{HttpCloneDeck, HttpClonedDeck, HttpImportSharedDeck};
 
 #[cfg(feature = "zerver")]
 impl From<CloneDeckError> for ApiError {
@@ -85,15 +88,62 @@ pub async fn clone_deck(
 
     let request = CloneDeck::new(source_deck_id, body.new_name, user.id, email_verified)?;
 
-    let new_deck_id = state
+    let new_deck = state
         .deck_service
         .clone_deck(&request)
         .await
         .map_err(ApiError::from)?;
 
+    record_deck_copy(&state, user.id, new_deck.id);
+
+    Ok((
+        StatusCode::CREATED,
+        Json(HttpClonedDeck {
+            deck_id: new_deck.id,
+        }),
+    ))
+}
+
+/// Copies a deck someone shared into the authenticated user's account.
+///
+/// The share token from the URL path is the only authority needed; an
+/// unknown or revoked token is a 404. The copy keeps the shared deck's name
+/// unless the body names it, and every card follows its oracle card to the
+/// current printing.
+#[cfg(feature = "zerver")]
+pub async fn import_shared_deck(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(share_token): Path<Uuid>,
+    Json(body): Json<HttpImportSharedDeck>,
+) -> Result<(StatusCode, Json<HttpClonedDeck>), ApiError> {
+    let db_user = state.user_service.get_user(&GetUser::from(user.id)).await?;
+    let email_verified = db_user.email_verified_at.is_some();
+
+    let request = ImportSharedDeck::new(share_token, body.new_name, user.id, email_verified)
+        .map_err(|e| ApiError::UnprocessableEntity(format!("invalid deck name: {}", e)))?;
+
+    let new_deck_id = state
+        .deck_service
+        .import_shared_deck(&request)
+        .await
+        .map_err(ApiError::from)?;
+
+    record_deck_copy(&state, user.id, new_deck_id);
+
+    Ok((
+        StatusCode::CREATED,
+        Json(HttpClonedDeck {
+            deck_id: new_deck_id,
+        }),
+    ))
+}
+
+/// Records a copied deck as created, in the background.
+#[cfg(feature = "zerver")]
+fn record_deck_copy(state: &AppState, uid: Uuid, new_deck_id: Uuid) {
     let metrics = std::sync::Arc::clone(&state.metrics_service);
     let deck_service = std::sync::Arc::clone(&state.deck_service);
-    let uid = user.id;
     tokio::spawn(async move {
         if let Err(e) = metrics.increment_decks_created(uid).await {
             tracing::warn!(error = ?e, "metrics: increment decks_created failed (clone)");
@@ -108,11 +158,4 @@ pub async fn clone_deck(
         // valid — run the completion check immediately.
         check_deck_completion(deck_service, metrics, uid, new_deck_id).await;
     });
-
-    Ok((
-        StatusCode::CREATED,
-        Json(HttpClonedDeck {
-            deck_id: new_deck_id,
-        }),
-    ))
 }
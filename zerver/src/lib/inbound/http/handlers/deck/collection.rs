@@ -0,0 +1,148 @@
+#[cfg(feature = "zerver")]
+use axum::{
+    Json,
+    body::Bytes,
+    extract::{Path, State},
+    http::StatusCode,
+};
+#[cfg(feature = "zerver")]
+use uuid::Uuid;
+
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::card::requests::import_collection::ImportCollectionError,
+    domain::deck::models::deck::collection::{
+        DeckMissingCardsError, GetOwnedCardsError, SetOwnedCardError,
+    },
+    inbound::http::{ApiError, AppState, Log500, middleware::AuthenticatedUser},
+};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::deck::{
+    CollectionImportReport, MissingDeckCard, OwnedCard,
+    requests::{
+        get_deck_profile::GetDeckProfile,
+        set_owned_card::{InvalidSetOwnedCard, SetOwnedCard},
+    },
+};
+#[cfg(feature = "zerver")]
+use zwipe_core::http::contracts::deck::HttpSetOwnedCard;
+
+#[cfg(feature = "zerver")]
+impl From<SetOwnedCardError> for ApiError {
+    fn from(value: SetOwnedCardError) -> Self {
+        match value {
+            SetOwnedCardError::CardNotFound => Self::NotFound(value.to_string()),
+            SetOwnedCardError::Database(e) => e.log_500(),
+        }
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl From<InvalidSetOwnedCard> for ApiError {
+    fn from(value: InvalidSetOwnedCard) -> Self {
+        Self::UnprocessableEntity(value.to_string())
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl From<GetOwnedCardsError> for ApiError {
+    fn from(value: GetOwnedCardsError) -> Self {
+        match value {
+            GetOwnedCardsError::Database(e) => e.log_500(),
+        }
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl From<ImportCollectionError> for ApiError {
+    fn from(value: ImportCollectionError) -> Self {
+        match value {
+            ImportCollectionError::Read(_) => Self::UnprocessableEntity(value.to_string()),
+            ImportCollectionError::Database(e) => e.log_500(),
+        }
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl From<DeckMissingCardsError> for ApiError {
+    fn from(value: DeckMissingCardsError) -> Self {
+        match value {
+            DeckMissingCardsError::Database(e) => e.log_500(),
+            DeckMissingCardsError::Forbidden => Self::NotFound("deck not found".to_string()),
+            DeckMissingCardsError::NotFound => {
+                Self::NotFound(DeckMissingCardsError::NotFound.to_string())
+            }
+        }
+    }
+}
+
+/// Sets how many copies of a card (any printing) the user owns. `0`
+/// removes it from the collection.
+#[cfg(feature = "zerver")]
+pub async fn set_owned(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(oracle_id): Path<Uuid>,
+    Json(body): Json<HttpSetOwnedCard>,
+) -> Result<StatusCode, ApiError> {
+    let request = SetOwnedCard::new(user.id, oracle_id, body.quantity)?;
+
+    state
+        .deck_service
+        .set_owned(&request)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Returns every card the user owns, by oracle id.
+#[cfg(feature = "zerver")]
+pub async fn get_owned(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<(StatusCode, Json<Vec<OwnedCard>>), ApiError> {
+    state
+        .deck_service
+        .get_owned(user.id)
+        .await
+        .map_err(ApiError::from)
+        .map(|cards| (StatusCode::OK, Json(cards)))
+}
+
+/// Imports a newline-delimited JSON collection export (one
+/// `{ "oracle_or_scryfall_id", "quantity" }` per line), setting the owned
+/// quantity of each card in it. Unknown ids and bad lines are reported.
+#[cfg(feature = "zerver")]
+pub async fn import_collection(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    body: Bytes,
+) -> Result<(StatusCode, Json<CollectionImportReport>), ApiError> {
+    let mut reader: &[u8] = &body;
+
+    state
+        .card_service
+        .import_collection_ndjson(user.id, &mut reader)
+        .await
+        .map_err(ApiError::from)
+        .map(|report| (StatusCode::OK, Json(report)))
+}
+
+/// Returns each card the deck needs against the user's collection, after
+/// ownership verification. Fully-owned cards report `missing: 0`.
+#[cfg(feature = "zerver")]
+pub async fn deck_missing_cards(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(deck_id): Path<Uuid>,
+) -> Result<(StatusCode, Json<Vec<MissingDeckCard>>), ApiError> {
+    let request = GetDeckProfile::new(user.id, deck_id);
+
+    state
+        .deck_service
+        .deck_missing_cards(&request)
+        .await
+        .map_err(ApiError::from)
+        .map(|cards| (StatusCode::OK, Json(cards)))
+}
@@ -65,6 +65,9 @@ impl From<InvalidCreateDeckProfile> for ApiError {
             InvalidCreateDeckProfile::TooManyOracleTags => {
                 Self::UnprocessableEntity("a deck may have at most 30 oracle tags".to_string())
             }
+            InvalidCreateDeckProfile::ZeroCopyLimit => {
+                Self::UnprocessableEntity("a custom copy limit must be at least 1".to_string())
+            }
         }
     }
 }
@@ -91,6 +94,7 @@ pub async fn create_deck_profile(
         .land_target(body.land_target)
         .price_target(body.price_target)
         .price_target_currency(body.price_target_currency)
+        .copy_limit(body.copy_limit)
         .build()?;
 
     let deck_profile = state
@@ -0,0 +1,125 @@
+#[cfg(feature = "zerver")]
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+#[cfg(feature = "zerver")]
+use uuid::Uuid;
+
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::{
+        deck::models::deck::deck_json::{ExportDeckJsonError, ImportDeckJsonError},
+        metrics::models::kinds::EventKind,
+    },
+    inbound::http::{ApiError, AppState, Log500, middleware::AuthenticatedUser},
+};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::deck::{
+    DeckExport,
+    requests::{
+        get_deck_profile::GetDeckProfile,
+        import_deck_json::{ImportDeckJson, ImportDeckJsonResult, InvalidImportDeckJson},
+    },
+};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::user::requests::get_user::GetUser;
+
+#[cfg(feature = "zerver")]
+impl From<ExportDeckJsonError> for ApiError {
+    fn from(value: ExportDeckJsonError) -> Self {
+        match value {
+            ExportDeckJsonError::Database(e) => e.log_500(),
+            ExportDeckJsonError::Forbidden => Self::NotFound("deck not found".to_string()),
+            ExportDeckJsonError::NotFound => {
+                Self::NotFound(ExportDeckJsonError::NotFound.to_string())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl From<ImportDeckJsonError> for ApiError {
+    fn from(value: ImportDeckJsonError) -> Self {
+        match value {
+            ImportDeckJsonError::CreateDeck(e) => e.into(),
+            ImportDeckJsonError::LimitReached => {
+                Self::UnprocessableEntity("deck card limit reached".to_string())
+            }
+            ImportDeckJsonError::UnverifiedLimitReached => Self::UnprocessableEntity(
+                "deck card limit reached, verify your email to unlock more".to_string(),
+            ),
+            ImportDeckJsonError::Database(e) => e.log_500(),
+        }
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl From<InvalidImportDeckJson> for ApiError {
+    fn from(value: InvalidImportDeckJson) -> Self {
+        match value {
+            InvalidImportDeckJson::UnsupportedVersion(_) => {
+                Self::UnprocessableEntity(value.to_string())
+            }
+            InvalidImportDeckJson::Profile(e) => e.into(),
+            InvalidImportDeckJson::Labels(e) => e.into(),
+        }
+    }
+}
+
+/// Exports the deck as a JSON backup (settings plus cards by oracle id)
+/// after ownership verification.
+#[cfg(feature = "zerver")]
+pub async fn export_deck_json(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(deck_id): Path<Uuid>,
+) -> Result<(StatusCode, Json<DeckExport>), ApiError> {
+    let request = GetDeckProfile::new(user.id, deck_id);
+
+    let export = state
+        .deck_service
+        .export_deck_json(&request)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok((StatusCode::OK, Json(export)))
+}
+
+/// Restores a JSON backup as a new deck for the authenticated user. Cards
+/// resolve to their current preferred printing; any without one are skipped
+/// and listed in the response.
+#[cfg(feature = "zerver")]
+pub async fn import_deck_json(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(body): Json<DeckExport>,
+) -> Result<(StatusCode, Json<ImportDeckJsonResult>), ApiError> {
+    let db_user = state.user_service.get_user(&GetUser::from(user.id)).await?;
+    let email_verified = db_user.email_verified_at.is_some();
+    let request = ImportDeckJson::new(user.id, email_verified, body)?;
+
+    let result = state
+        .deck_service
+        .import_deck_json(&request)
+        .await
+        .map_err(ApiError::from)?;
+
+    let metrics = std::sync::Arc::clone(&state.metrics_service);
+    let uid = user.id;
+    let deck_id = result.deck.id;
+    tokio::spawn(async move {
+        if let Err(e) = metrics.increment_decks_created(uid).await {
+            tracing::warn!(error = ?e, "metrics: increment decks_created failed (json import)");
+        }
+        if let Err(e) = metrics
+            .record_event(uid, EventKind::DeckCreated, Some(deck_id))
+            .await
+        {
+            tracing::warn!(error = ?e, "metrics: record deck_created event failed (json import)");
+        }
+    });
+
+    Ok((StatusCode::CREATED, Json(result)))
+}
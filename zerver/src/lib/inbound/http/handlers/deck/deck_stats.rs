@@ -0,0 +1,33 @@
+//! Deck statistics for the deck screen charts.
+
+#[cfg(feature = "zerver")]
+use crate::inbound::http::{ApiError, AppState, middleware::AuthenticatedUser};
+#[cfg(feature = "zerver")]
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+#[cfg(feature = "zerver")]
+use uuid::Uuid;
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::deck::{DeckStats, requests::get_deck_profile::GetDeckProfile};
+
+/// Returns the deck's mana curve, color pips, type distribution and average
+/// mana value after ownership verification.
+#[cfg(feature = "zerver")]
+pub async fn deck_stats(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(deck_id): Path<Uuid>,
+) -> Result<(StatusCode, Json<DeckStats>), ApiError> {
+    let request = GetDeckProfile::new(user.id, deck_id);
+
+    let stats = state
+        .deck_service
+        .deck_stats(&request)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok((StatusCode::OK, Json(stats)))
+}
@@ -0,0 +1,39 @@
+//! Export a deck as decklist text (plain text, MTG Arena or MTGO).
+
+#[cfg(feature = "zerver")]
+use crate::inbound::http::{ApiError, AppState, middleware::AuthenticatedUser};
+#[cfg(feature = "zerver")]
+use axum::{
+    extract::{Path, State},
+    http::{StatusCode, header},
+};
+#[cfg(feature = "zerver")]
+use uuid::Uuid;
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::deck::{ExportFormat, requests::get_deck_profile::GetDeckProfile};
+
+/// Returns the deck as `text/plain` decklist text in `format` (`text`,
+/// `arena` or `mtgo`) after ownership verification. An unknown format is
+/// refused with 422.
+#[cfg(feature = "zerver")]
+pub async fn export_deck(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path((deck_id, format)): Path<(Uuid, String)>,
+) -> Result<(StatusCode, [(header::HeaderName, &'static str); 1], String), ApiError> {
+    let format = ExportFormat::try_from(format.as_str())
+        .map_err(|e| ApiError::UnprocessableEntity(e.to_string()))?;
+    let request = GetDeckProfile::new(user.id, deck_id);
+
+    let text = state
+        .deck_service
+        .export_deck(&request, format)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok((
+        StatusCode::OK,
+        [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+        text,
+    ))
+}
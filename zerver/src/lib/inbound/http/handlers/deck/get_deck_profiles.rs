@@ -1,12 +1,18 @@
 #[cfg(feature = "zerver")]
 use crate::inbound::http::{ApiError, AppState, middleware::AuthenticatedUser};
 #[cfg(feature = "zerver")]
-use axum::{Json, extract::State, http::StatusCode};
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+};
 #[cfg(feature = "zerver")]
 use zwipe_core::domain::deck::{
     deck_profile::DeckProfile,
     requests::get_deck_profiles::{GetDeckProfiles, InvalidGetDeckProfiles},
 };
+#[cfg(feature = "zerver")]
+use zwipe_core::http::contracts::deck::HttpDeckProfilesQuery;
 
 #[cfg(feature = "zerver")]
 impl From<InvalidGetDeckProfiles> for ApiError {
@@ -19,13 +25,18 @@ impl From<InvalidGetDeckProfiles> for ApiError {
     }
 }
 
-/// Returns all deck profiles for the authenticated user.
+/// Returns all deck profiles for the authenticated user, or only those
+/// carrying `?label=`.
 #[cfg(feature = "zerver")]
 pub async fn get_deck_profiles(
     user: AuthenticatedUser,
     State(state): State<AppState>,
+    Query(query): Query<HttpDeckProfilesQuery>,
 ) -> Result<(StatusCode, Json<Vec<DeckProfile>>), ApiError> {
-    let request = GetDeckProfiles::new(user.id);
+    let mut request = GetDeckProfiles::new(user.id);
+    if let Some(label) = query.label.as_deref() {
+        request = request.with_label(label);
+    }
     state
         .deck_service
         .get_deck_profiles(&request)
@@ -0,0 +1,71 @@
+//! Create a deck from pasted decklist text (plain text, MTG Arena or MTGO).
+
+#[cfg(feature = "zerver")]
+use axum::{Json, extract::State, http::StatusCode};
+
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::{
+        deck::models::deck::import_decklist::ImportDecklistError, metrics::models::kinds::EventKind,
+    },
+    inbound::http::{ApiError, AppState, Log500, middleware::AuthenticatedUser},
+};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::deck::requests::import_decklist::{ImportDecklist, ImportReport};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::user::requests::get_user::GetUser;
+#[cfg(feature = "zerver")]
+use zwipe_core::http::contracts::deck::HttpImportDecklist;
+
+#[cfg(feature = "zerver")]
+impl From<ImportDecklistError> for ApiError {
+    fn from(value: ImportDecklistError) -> Self {
+        match value {
+            ImportDecklistError::CreateDeck(e) => e.into(),
+            ImportDecklistError::LimitReached => {
+                Self::UnprocessableEntity("deck card limit reached".to_string())
+            }
+            ImportDecklistError::UnverifiedLimitReached => Self::UnprocessableEntity(
+                "deck card limit reached, verify your email to unlock more".to_string(),
+            ),
+            ImportDecklistError::Database(e) => e.log_500(),
+        }
+    }
+}
+
+/// Creates a deck for the authenticated user from a pasted plain text,
+/// Arena or MTGO decklist. Lines matching no card (or more than one) are
+/// skipped and listed in the response.
+#[cfg(feature = "zerver")]
+pub async fn import_decklist(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Json(body): Json<HttpImportDecklist>,
+) -> Result<(StatusCode, Json<ImportReport>), ApiError> {
+    let db_user = state.user_service.get_user(&GetUser::from(user.id)).await?;
+    let email_verified = db_user.email_verified_at.is_some();
+    let request = ImportDecklist::new(user.id, email_verified, body.name, &body.text, body.format)?;
+
+    let report = state
+        .deck_service
+        .import_deck(&request)
+        .await
+        .map_err(ApiError::from)?;
+
+    let metrics = std::sync::Arc::clone(&state.metrics_service);
+    let uid = user.id;
+    let deck_id = report.deck.id;
+    tokio::spawn(async move {
+        if let Err(e) = metrics.increment_decks_created(uid).await {
+            tracing::warn!(error = ?e, "metrics: increment decks_created failed (decklist import)");
+        }
+        if let Err(e) = metrics
+            .record_event(uid, EventKind::DeckCreated, Some(deck_id))
+            .await
+        {
+            tracing::warn!(error = ?e, "metrics: record deck_created event failed (decklist import)");
+        }
+    });
+
+    Ok((StatusCode::CREATED, Json(report)))
+}
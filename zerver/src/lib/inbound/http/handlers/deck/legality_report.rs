@@ -0,0 +1,56 @@
+//! Get a deck's combined legality report, against its own format or another.
+
+#[cfg(feature = "zerver")]
+use crate::inbound::http::{ApiError, AppState, middleware::AuthenticatedUser};
+#[cfg(feature = "zerver")]
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+#[cfg(feature = "zerver")]
+use uuid::Uuid;
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::deck::{Format, LegalityReport};
+
+/// Returns every format rule the deck breaks, grouped by rule.
+#[cfg(feature = "zerver")]
+pub async fn legality_report(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(deck_id): Path<Uuid>,
+) -> Result<(StatusCode, Json<LegalityReport>), ApiError> {
+    use zwipe_core::domain::deck::requests::get_deck_profile::GetDeckProfile;
+
+    let request = GetDeckProfile::new(user.id, deck_id);
+
+    state
+        .deck_service
+        .full_legality_report(&request)
+        .await
+        .map_err(ApiError::from)
+        .map(|report| (StatusCode::OK, Json(report)))
+}
+
+/// Returns every rule the deck would break in `format` (a legality key like
+/// `modern`), whatever format the deck is built for. An unknown format is
+/// refused with 422.
+#[cfg(feature = "zerver")]
+pub async fn validate_deck(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path((deck_id, format)): Path<(Uuid, String)>,
+) -> Result<(StatusCode, Json<LegalityReport>), ApiError> {
+    use zwipe_core::domain::deck::requests::get_deck_profile::GetDeckProfile;
+
+    let format = Format::try_from(format.as_str())
+        .map_err(|e| ApiError::UnprocessableEntity(e.to_string()))?;
+    let request = GetDeckProfile::new(user.id, deck_id);
+
+    state
+        .deck_service
+        .validate_deck(&request, format)
+        .await
+        .map_err(ApiError::from)
+        .map(|report| (StatusCode::OK, Json(report)))
+}
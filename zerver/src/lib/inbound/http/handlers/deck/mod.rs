@@ -2,12 +2,20 @@
 
 /// Deck suppression-clear handler.
 pub mod clear_deck_suppressions;
-/// Deck clone handler.
+/// Deck clone and shared-deck import handlers.
 pub mod clone_deck;
+/// Card collection handlers (owned quantities, a deck's missing cards).
+pub mod collection;
 /// Deck creation handler.
 pub mod create_deck_profile;
+/// Deck JSON backup export/import handlers.
+pub mod deck_json;
+/// Deck statistics handler (mana curve, color pips, type distribution).
+pub mod deck_stats;
 /// Deck deletion handler.
 pub mod delete_deck;
+/// Decklist text export handler (plain text, Arena, MTGO).
+pub mod export_deck;
 /// Full deck with cards handler.
 pub mod get_deck;
 /// Deck metadata handler.
@@ -22,11 +30,21 @@ pub mod get_deck_tokens;
 pub mod get_shared_deck;
 /// Archidekt deck import handler.
 pub mod import_archidekt;
+/// Decklist text import handler (plain text, Arena, MTGO).
+pub mod import_decklist;
+/// Deck legality report handler.
+pub mod legality_report;
+/// Deck proxy-sheet handler (printable images by quantity).
+pub mod proxy_sheet;
 /// Deck-aware card search handler (exclusion + synergy ordering).
 pub mod search_deck_cards;
+/// Deck label replacement handler.
+pub mod set_deck_labels;
 /// Deck share/unshare handlers (owner-side token management).
 pub mod share_deck;
 /// Single-card skip/unskip suppression handlers.
 pub mod skip_deck_card;
+/// Commander-based deck name suggestions handler.
+pub mod suggest_deck_name;
 /// Deck metadata update handler.
 pub mod update_deck_profile;
@@ -0,0 +1,32 @@
+//! Proxy sheet for a deck: card images expanded by quantity for printing.
+
+#[cfg(feature = "zerver")]
+use crate::inbound::http::{ApiError, AppState, middleware::AuthenticatedUser};
+#[cfg(feature = "zerver")]
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+#[cfg(feature = "zerver")]
+use uuid::Uuid;
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::deck::{ProxySheet, requests::get_deck_profile::GetDeckProfile};
+
+/// Returns the deck's proxy images (command zone and main deck, one per
+/// copy, backs after fronts) after ownership verification.
+#[cfg(feature = "zerver")]
+pub async fn proxy_sheet(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(deck_id): Path<Uuid>,
+) -> Result<(StatusCode, Json<ProxySheet>), ApiError> {
+    let request = GetDeckProfile::new(user.id, deck_id);
+
+    state
+        .deck_service
+        .proxy_sheet(&request)
+        .await
+        .map_err(ApiError::from)
+        .map(|sheet| (StatusCode::OK, Json(sheet)))
+}
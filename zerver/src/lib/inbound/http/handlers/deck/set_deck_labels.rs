@@ -0,0 +1,58 @@
+#[cfg(feature = "zerver")]
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+#[cfg(feature = "zerver")]
+use uuid::Uuid;
+
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::deck::models::deck::set_deck_labels::SetDeckLabelsError,
+    inbound::http::{ApiError, AppState, Log500, middleware::AuthenticatedUser},
+};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::deck::{InvalidDeckLabels, requests::set_deck_labels::SetDeckLabels};
+#[cfg(feature = "zerver")]
+use zwipe_core::http::contracts::deck::{HttpDeckLabels, HttpSetDeckLabels};
+
+#[cfg(feature = "zerver")]
+impl From<SetDeckLabelsError> for ApiError {
+    fn from(value: SetDeckLabelsError) -> Self {
+        match value {
+            SetDeckLabelsError::Database(e) => e.log_500(),
+            SetDeckLabelsError::Forbidden => Self::NotFound("deck not found".to_string()),
+            SetDeckLabelsError::NotFound => {
+                Self::NotFound(SetDeckLabelsError::NotFound.to_string())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl From<InvalidDeckLabels> for ApiError {
+    fn from(value: InvalidDeckLabels) -> Self {
+        Self::UnprocessableEntity(value.to_string())
+    }
+}
+
+/// Replaces the deck's labels after ownership verification. Returns the
+/// labels as stored: trimmed, lowercased, deduped and sorted.
+#[cfg(feature = "zerver")]
+pub async fn set_deck_labels(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(deck_id): Path<Uuid>,
+    Json(body): Json<HttpSetDeckLabels>,
+) -> Result<(StatusCode, Json<HttpDeckLabels>), ApiError> {
+    let request = SetDeckLabels::new(user.id, deck_id, &body.labels)?;
+
+    let labels = state
+        .deck_service
+        .set_deck_labels(&request)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok((StatusCode::OK, Json(HttpDeckLabels { labels })))
+}
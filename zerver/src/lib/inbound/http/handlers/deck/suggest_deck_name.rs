@@ -0,0 +1,28 @@
+#[cfg(feature = "zerver")]
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::card::requests::get_scryfall_data::GetScryfallData,
+    inbound::http::{ApiError, AppState},
+};
+
+/// Suggests deck names for a deck led by the given commander, best guess
+/// first. Feeds the name field of the create-deck flow.
+#[cfg(feature = "zerver")]
+pub async fn suggest_deck_name(
+    State(state): State<AppState>,
+    Path(commander_id): Path<String>,
+) -> Result<(StatusCode, Json<Vec<String>>), ApiError> {
+    let request = GetScryfallData::new(&commander_id)?;
+    state
+        .deck_service
+        .suggest_deck_name(&request)
+        .await
+        .map_err(ApiError::from)
+        .map(|names| (StatusCode::OK, Json(names)))
+}
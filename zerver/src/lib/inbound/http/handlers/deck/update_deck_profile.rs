@@ -68,6 +68,9 @@ impl From<InvalidUpdateDeckProfile> for ApiError {
             InvalidUpdateDeckProfile::TooManyOracleTags => {
                 Self::UnprocessableEntity("a deck may have at most 30 oracle tags".to_string())
             }
+            InvalidUpdateDeckProfile::ZeroCopyLimit => {
+                Self::UnprocessableEntity("a custom copy limit must be at least 1".to_string())
+            }
             InvalidUpdateDeckProfile::NoUpdates => {
                 Self::UnprocessableEntity("must update at least one field".to_string())
             }
@@ -102,6 +105,7 @@ pub async fn update_deck_profile(
         .land_target(body.land_target.into_option())
         .price_target(body.price_target.into_option())
         .price_target_currency(body.price_target_currency.into_option())
+        .copy_limit(body.copy_limit)
         .build()?;
 
     let deck_profile = state
@@ -0,0 +1,99 @@
+//! Add many cards to a deck in one call.
+
+#[cfg(feature = "zerver")]
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::user::requests::get_user::GetUser;
+#[cfg(feature = "zerver")]
+use zwipe_core::http::contracts::deck_card::HttpBulkAddDeckCards;
+
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::deck::models::deck_card::bulk_add_deck_cards::BulkAddDeckCardsError,
+    inbound::http::{
+        ApiError, AppState, Log500, handlers::metrics::check_completion::check_deck_completion,
+        middleware::AuthenticatedUser,
+    },
+};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::deck::{
+    Board, DeckCard,
+    requests::bulk_add_deck_cards::{BulkAddDeckCards, InvalidBulkAddDeckCards},
+};
+
+#[cfg(feature = "zerver")]
+impl From<BulkAddDeckCardsError> for ApiError {
+    fn from(value: BulkAddDeckCardsError) -> Self {
+        match value {
+            BulkAddDeckCardsError::Deck(e) => ApiError::from(e),
+            BulkAddDeckCardsError::CardNotFound(_) => Self::NotFound(value.to_string()),
+            e @ (BulkAddDeckCardsError::IsCommander { .. }
+            | BulkAddDeckCardsError::OutsideColorIdentity { .. }
+            | BulkAddDeckCardsError::CopyLimitExceeded { .. }
+            | BulkAddDeckCardsError::LimitReached
+            | BulkAddDeckCardsError::UnverifiedLimitReached) => {
+                Self::UnprocessableEntity(e.to_string())
+            }
+            BulkAddDeckCardsError::DeckCardFromDb(e) => e.log_500(),
+            BulkAddDeckCardsError::Database(e) => e.log_500(),
+        }
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl From<InvalidBulkAddDeckCards> for ApiError {
+    fn from(value: InvalidBulkAddDeckCards) -> Self {
+        match value {
+            InvalidBulkAddDeckCards::Quantity(e) => {
+                Self::UnprocessableEntity(format!("invalid quantity: {}", e))
+            }
+            e => Self::UnprocessableEntity(e.to_string()),
+        }
+    }
+}
+
+/// Adds every listed card to the deck, all or nothing, returning the added
+/// or updated entries.
+#[cfg(feature = "zerver")]
+pub async fn bulk_add_deck_cards(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(deck_id): Path<String>,
+    Json(body): Json<HttpBulkAddDeckCards>,
+) -> Result<(StatusCode, Json<Vec<DeckCard>>), ApiError> {
+    let db_user = state.user_service.get_user(&GetUser::from(user.id)).await?;
+    let email_verified = db_user.email_verified_at.is_some();
+    let deck_id = uuid::Uuid::try_parse(&deck_id)?;
+    let board = body
+        .board
+        .as_deref()
+        .map(Board::try_from)
+        .transpose()
+        .map_err(|_| ApiError::UnprocessableEntity("invalid board value".to_string()))?;
+    let cards = body
+        .cards
+        .iter()
+        .map(|c| Ok((uuid::Uuid::try_parse(&c.scryfall_data_id)?, c.quantity)))
+        .collect::<Result<Vec<_>, uuid::Error>>()?;
+    let request = BulkAddDeckCards::new(user.id, deck_id, board, cards, email_verified)?;
+
+    let deck_cards = state
+        .deck_service
+        .bulk_add_deck_cards(&request)
+        .await
+        .map_err(ApiError::from)?;
+
+    let metrics = std::sync::Arc::clone(&state.metrics_service);
+    let deck_service = std::sync::Arc::clone(&state.deck_service);
+    tokio::spawn(check_deck_completion(
+        deck_service,
+        metrics,
+        user.id,
+        deck_id,
+    ));
+    Ok((StatusCode::CREATED, Json(deck_cards)))
+}
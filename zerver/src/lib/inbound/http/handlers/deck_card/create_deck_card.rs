@@ -33,6 +33,9 @@ impl From<CreateDeckCardError> for ApiError {
             CreateDeckCardError::IsCommander => {
                 Self::UnprocessableEntity(CreateDeckCardError::IsCommander.to_string())
             }
+            CreateDeckCardError::OutsideColorIdentity => {
+                Self::UnprocessableEntity(CreateDeckCardError::OutsideColorIdentity.to_string())
+            }
             CreateDeckCardError::LimitReached => Self::UnprocessableEntity(
                 "card limit reached (mainboard, maybeboard, and sideboard all count toward it)"
                     .to_string(),
@@ -95,7 +98,8 @@ pub async fn create_deck_card(
         body.quantity,
         board,
         email_verified,
-    )?;
+    )?
+    .with_reference(body.reference.unwrap_or_default());
 
     let deck_card = state
         .deck_service
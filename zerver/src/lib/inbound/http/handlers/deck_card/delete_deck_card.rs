@@ -1,6 +1,6 @@
 #[cfg(feature = "zerver")]
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
 };
 
@@ -8,12 +8,19 @@ use axum::{
 use crate::{
     domain::deck::models::deck_card::delete_deck_card::DeleteDeckCardError,
     inbound::http::{
-        ApiError, AppState, Log500, handlers::metrics::check_completion::check_deck_completion,
+        ApiError, AppState, Log500,
+        handlers::{
+            deck_card::update_deck_card::parse_board,
+            metrics::check_completion::check_deck_completion,
+        },
         middleware::AuthenticatedUser,
     },
 };
 #[cfg(feature = "zerver")]
-use zwipe_core::domain::deck::requests::delete_deck_card::{DeleteDeckCard, InvalidDeleteDeckCard};
+use zwipe_core::{
+    domain::deck::requests::delete_deck_card::{DeleteDeckCard, InvalidDeleteDeckCard},
+    http::contracts::deck_card::HttpDeckCardBoard,
+};
 
 #[cfg(feature = "zerver")]
 impl From<DeleteDeckCardError> for ApiError {
@@ -45,14 +52,19 @@ impl From<InvalidDeleteDeckCard> for ApiError {
     }
 }
 
-/// Removes a card from a deck.
+/// Removes a card from a deck. `?board=sideboard` removes its sideboard
+/// copy.
 #[cfg(feature = "zerver")]
 pub async fn delete_deck_card(
     user: AuthenticatedUser,
     State(state): State<AppState>,
     Path((deck_id, scryfall_data_id)): Path<(String, String)>,
+    Query(selector): Query<HttpDeckCardBoard>,
 ) -> Result<StatusCode, ApiError> {
-    let request = DeleteDeckCard::new(user.id, &deck_id, &scryfall_data_id)?;
+    let mut request = DeleteDeckCard::new(user.id, &deck_id, &scryfall_data_id)?;
+    if let Some(board) = parse_board(selector.board.as_deref())? {
+        request = request.on_board(board);
+    }
 
     state
         .deck_service
@@ -1,5 +1,7 @@
 //! Deck card composition handlers.
 
+/// Bulk add cards to deck handler.
+pub mod bulk_add_deck_cards;
 /// Add card to deck handler.
 pub mod create_deck_card;
 /// Remove card from deck handler.
@@ -8,5 +10,9 @@ pub mod delete_deck_card;
 pub mod get_deck_card;
 /// Import cards from plain-text decklist handler.
 pub mod import_deck_cards;
+/// Move cards between decks handler.
+pub mod move_deck_cards;
+/// Orphaned deck card find/repair handlers.
+pub mod orphaned_deck_cards;
 /// Card quantity update handler.
 pub mod update_deck_card;
@@ -0,0 +1,70 @@
+//! Move cards from one deck to another.
+
+#[cfg(feature = "zerver")]
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+};
+#[cfg(feature = "zerver")]
+use zwipe_core::http::contracts::deck_card::HttpMoveDeckCards;
+
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::deck::models::deck_card::move_deck_cards::MoveDeckCardsError,
+    inbound::http::{ApiError, AppState, Log500, middleware::AuthenticatedUser},
+};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::deck::requests::move_deck_cards::{InvalidMoveDeckCards, MoveDeckCards};
+
+#[cfg(feature = "zerver")]
+impl From<MoveDeckCardsError> for ApiError {
+    fn from(value: MoveDeckCardsError) -> Self {
+        match value {
+            MoveDeckCardsError::Deck(e) => ApiError::from(e),
+            e @ (MoveDeckCardsError::NotEnoughCopies { .. }
+            | MoveDeckCardsError::CopyLimitExceeded { .. }) => {
+                Self::UnprocessableEntity(e.to_string())
+            }
+            MoveDeckCardsError::Database(e) => e.log_500(),
+        }
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl From<InvalidMoveDeckCards> for ApiError {
+    fn from(value: InvalidMoveDeckCards) -> Self {
+        match value {
+            InvalidMoveDeckCards::Quantity(e) => {
+                Self::UnprocessableEntity(format!("invalid quantity: {}", e))
+            }
+            e => Self::UnprocessableEntity(e.to_string()),
+        }
+    }
+}
+
+/// Moves cards from the path deck to `to_deck_id`, all or nothing.
+#[cfg(feature = "zerver")]
+pub async fn move_deck_cards(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+    Path(deck_id): Path<String>,
+    Json(body): Json<HttpMoveDeckCards>,
+) -> Result<StatusCode, ApiError> {
+    let from_deck_id = uuid::Uuid::try_parse(&deck_id)?;
+    let to_deck_id = uuid::Uuid::try_parse(&body.to_deck_id)?;
+    let cards = body
+        .cards
+        .iter()
+        .map(|c| Ok((uuid::Uuid::try_parse(&c.scryfall_data_id)?, c.quantity)))
+        .collect::<Result<Vec<_>, uuid::Error>>()?;
+    let request = MoveDeckCards::new(user.id, from_deck_id, to_deck_id, cards)?;
+
+    state
+        .deck_service
+        .move_deck_cards(&request)
+        .await
+        .map_err(ApiError::from)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
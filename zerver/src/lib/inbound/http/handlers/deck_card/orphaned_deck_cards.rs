@@ -0,0 +1,51 @@
+//! Find and repair deck cards left dangling by a card sync.
+
+#[cfg(feature = "zerver")]
+use crate::{
+    domain::deck::models::deck_card::orphaned_deck_cards::OrphanedDeckCardsError,
+    inbound::http::{ApiError, AppState, Log500, middleware::AuthenticatedUser},
+};
+#[cfg(feature = "zerver")]
+use axum::{Json, extract::State, http::StatusCode};
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::deck::OrphanedDeckCard;
+
+#[cfg(feature = "zerver")]
+impl From<OrphanedDeckCardsError> for ApiError {
+    fn from(value: OrphanedDeckCardsError) -> Self {
+        match value {
+            OrphanedDeckCardsError::Database(e) => e.log_500(),
+            OrphanedDeckCardsError::DeckCardFromDb(e) => e.log_500(),
+        }
+    }
+}
+
+/// Lists the user's deck cards whose printing no longer exists, with the
+/// printing each would be remapped to.
+#[cfg(feature = "zerver")]
+pub async fn find_orphaned_deck_cards(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<(StatusCode, Json<Vec<OrphanedDeckCard>>), ApiError> {
+    state
+        .deck_service
+        .find_orphaned_deck_cards(user.id)
+        .await
+        .map_err(ApiError::from)
+        .map(|orphans| (StatusCode::OK, Json(orphans)))
+}
+
+/// Remaps the user's orphaned deck cards onto surviving printings, returning
+/// the orphans found (entries without a `replacement_id` were left as is).
+#[cfg(feature = "zerver")]
+pub async fn repair_orphaned_deck_cards(
+    user: AuthenticatedUser,
+    State(state): State<AppState>,
+) -> Result<(StatusCode, Json<Vec<OrphanedDeckCard>>), ApiError> {
+    state
+        .deck_service
+        .repair_orphaned_deck_cards(user.id)
+        .await
+        .map_err(ApiError::from)
+        .map(|orphans| (StatusCode::OK, Json(orphans)))
+}
@@ -1,11 +1,11 @@
 #[cfg(feature = "zerver")]
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
 };
 #[cfg(feature = "zerver")]
-use zwipe_core::http::contracts::deck_card::HttpUpdateDeckCard;
+use zwipe_core::http::contracts::deck_card::{HttpDeckCardBoard, HttpUpdateDeckCard};
 
 #[cfg(feature = "zerver")]
 use crate::{
@@ -35,6 +35,9 @@ impl From<UpdateDeckCardError> for ApiError {
             UpdateDeckCardError::MvpCapReached => {
                 Self::UnprocessableEntity("This deck already has 3 MVPs".to_string())
             }
+            UpdateDeckCardError::AlreadyOnBoard => {
+                Self::UnprocessableEntity(UpdateDeckCardError::AlreadyOnBoard.to_string())
+            }
             UpdateDeckCardError::MvpNotMainboard => {
                 Self::UnprocessableEntity("Only cards in the deck can be MVPs".to_string())
             }
@@ -71,21 +74,19 @@ impl From<InvalidUpdateDeckCard> for ApiError {
     }
 }
 
-/// Updates a card's quantity, board, and/or printing.
+/// Updates a card's quantity, board, printing, and/or card reference.
+/// `?board=sideboard` targets the card's sideboard copy.
 #[cfg(feature = "zerver")]
 pub async fn update_deck_card(
     user: AuthenticatedUser,
     State(state): State<AppState>,
     Path((deck_id, scryfall_data_id)): Path<(String, String)>,
+    Query(selector): Query<HttpDeckCardBoard>,
     Json(body): Json<HttpUpdateDeckCard>,
 ) -> Result<(StatusCode, Json<DeckCard>), ApiError> {
-    let board = body
-        .board
-        .as_deref()
-        .map(zwipe_core::domain::deck::Board::try_from)
-        .transpose()
-        .map_err(|_| ApiError::UnprocessableEntity("invalid board value".to_string()))?;
-    let request = UpdateDeckCard::new(
+    let board = parse_board(body.board.as_deref())?;
+    let from_board = parse_board(selector.board.as_deref())?;
+    let mut request = UpdateDeckCard::new(
         user.id,
         &deck_id,
         &scryfall_data_id,
@@ -93,7 +94,11 @@ pub async fn update_deck_card(
         board,
         body.scryfall_data_id.as_deref(),
         body.mvp,
+        body.reference,
     )?;
+    if let Some(from_board) = from_board {
+        request = request.on_board(from_board);
+    }
 
     let deck_card = state
         .deck_service
@@ -109,3 +114,14 @@ pub async fn update_deck_card(
 
     Ok((StatusCode::OK, Json(deck_card)))
 }
+
+/// Parses an optional board name ("deck", "maybeboard", "sideboard").
+#[cfg(feature = "zerver")]
+pub(super) fn parse_board(
+    board: Option<&str>,
+) -> Result<Option<zwipe_core::domain::deck::Board>, ApiError> {
+    board
+        .map(zwipe_core::domain::deck::Board::try_from)
+        .transpose()
+        .map_err(|_| ApiError::UnprocessableEntity("invalid board value".to_string()))
+}
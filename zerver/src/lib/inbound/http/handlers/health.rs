@@ -1,7 +1,7 @@
 #[cfg(feature = "zerver")]
 use crate::inbound::http::AppState;
 #[cfg(feature = "zerver")]
-use axum::{Json, extract::State};
+use axum::{Json, extract::State, http::StatusCode};
 use chrono::Utc;
 use serde::Serialize;
 use serde_json::{Value, json};
@@ -77,3 +77,28 @@ pub async fn are_server_and_database_running(State(state): State<AppState>) -> J
 
     Json(json!(HealthCheckResponse::new(result)))
 }
+
+/// Readiness: the database answers and, when a sync freshness window is
+/// configured, card data is recent. Responds 503 when not ready so probes
+/// take the instance out of rotation.
+#[cfg(feature = "zerver")]
+pub async fn is_server_ready(State(state): State<AppState>) -> (StatusCode, Json<Value>) {
+    let result = if state.health_service.check_database().await.is_err() {
+        Err("cannot connect to database")
+    } else if let Err(e) = state.health_service.check_sync_freshness().await {
+        tracing::warn!("readiness failed: {e}");
+        Err("card data is stale")
+    } else {
+        Ok("healthy")
+    };
+    match result {
+        Ok(status) => (
+            StatusCode::OK,
+            Json(json!(HealthCheckResponse::new(status))),
+        ),
+        Err(status) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!(HealthCheckResponse::new(status))),
+        ),
+    }
+}
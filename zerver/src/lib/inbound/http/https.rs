@@ -0,0 +1,207 @@
+//! Optional HTTPS enforcement: redirect plain HTTP, send HSTS on HTTPS.
+//!
+//! zerver binds plain TCP and leaves TLS to whatever sits in front of it.
+//! Behind the Cloudflare tunnel that front already redirects; a direct
+//! deployment behind a bare TLS-terminating load balancer does not, so this
+//! layer does it here when enabled. A request counts as HTTPS when its URI
+//! carries the `https` scheme or a trusted proxy reports `X-Forwarded-Proto:
+//! https` — the same trust rule as the client IP (see [`TrustedProxies`]).
+//!
+//! The API authenticates with Bearer tokens and sets no cookies. Any cookie
+//! added later must carry `Secure` (and `HttpOnly`, `SameSite`) so it never
+//! travels over the plain-HTTP hop this layer redirects away from.
+
+use crate::inbound::http::client_ip::TrustedProxies;
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Redirect, Response},
+};
+use std::{net::SocketAddr, time::Duration};
+
+/// Scheme header set by TLS-terminating proxies and load balancers.
+const X_FORWARDED_PROTO: &str = "x-forwarded-proto";
+
+/// Default HSTS lifetime: one year, the preload-list minimum.
+pub const HSTS_MAX_AGE_DEFAULT: Duration = Duration::from_secs(365 * 24 * 60 * 60);
+
+/// Whether to enforce HTTPS, and for how long browsers should remember it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HttpsPolicy {
+    /// Redirect HTTP to HTTPS and send HSTS. Off by default.
+    pub enforce: bool,
+    /// `max-age` of the `Strict-Transport-Security` header.
+    pub hsts_max_age: Duration,
+}
+
+impl HttpsPolicy {
+    /// Enforcement on with the given HSTS lifetime.
+    pub fn enforced(hsts_max_age: Duration) -> Self {
+        Self {
+            enforce: true,
+            hsts_max_age,
+        }
+    }
+
+    /// The `Strict-Transport-Security` header value for this policy.
+    fn hsts_header(&self) -> HeaderValue {
+        HeaderValue::from_str(&format!(
+            "max-age={}; includeSubDomains",
+            self.hsts_max_age.as_secs()
+        ))
+        .unwrap_or_else(|_| HeaderValue::from_static("max-age=31536000; includeSubDomains"))
+    }
+}
+
+/// Middleware state: the policy plus the proxies whose scheme header counts.
+#[derive(Debug, Clone)]
+pub struct HttpsGuard {
+    policy: HttpsPolicy,
+    trusted_proxies: TrustedProxies,
+}
+
+impl HttpsGuard {
+    /// Creates the guard for `policy`, believing `X-Forwarded-Proto` only
+    /// from `trusted_proxies`.
+    pub fn new(policy: HttpsPolicy, trusted_proxies: TrustedProxies) -> Self {
+        Self {
+            policy,
+            trusted_proxies,
+        }
+    }
+
+    /// True when the request arrived over HTTPS.
+    fn is_https(&self, request: &Request) -> bool {
+        if request.uri().scheme_str() == Some("https") {
+            return true;
+        }
+        let trusted_peer = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .is_some_and(|info| self.trusted_proxies.is_trusted(info.0.ip()));
+        // Proxy chains append: the first entry is the client-facing hop.
+        trusted_peer
+            && request
+                .headers()
+                .get(X_FORWARDED_PROTO)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.split(',').next())
+                .is_some_and(|proto| proto.trim().eq_ignore_ascii_case("https"))
+    }
+}
+
+/// Redirects plain-HTTP requests to the same URL over HTTPS (308, so the
+/// method and body are kept) and stamps HSTS on HTTPS responses. A no-op
+/// when the policy is off.
+pub async fn enforce_https(
+    State(guard): State<HttpsGuard>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !guard.policy.enforce {
+        return next.run(request).await;
+    }
+    if !guard.is_https(&request) {
+        let host = request
+            .headers()
+            .get(header::HOST)
+            .and_then(|v| v.to_str().ok())
+            .or_else(|| request.uri().authority().map(|a| a.as_str()));
+        let Some(host) = host else {
+            return (StatusCode::BAD_REQUEST, "https required").into_response();
+        };
+        let path = request.uri().path_and_query().map_or("/", |pq| pq.as_str());
+        return Redirect::permanent(&format!("https://{host}{path}")).into_response();
+    }
+    let mut response = next.run(request).await;
+    response.headers_mut().insert(
+        header::STRICT_TRANSPORT_SECURITY,
+        guard.policy.hsts_header(),
+    );
+    response
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use axum::{Router, body::Body, routing::get};
+    use std::net::{IpAddr, Ipv4Addr};
+    use tower::ServiceExt;
+
+    fn router(policy: HttpsPolicy) -> Router {
+        let guard = HttpsGuard::new(policy, TrustedProxies::default());
+        Router::new()
+            .route("/health", get(|| async { "ok" }))
+            .layer(axum::middleware::from_fn_with_state(guard, enforce_https))
+    }
+
+    fn request(uri: &str, peer: Ipv4Addr, forwarded_proto: Option<&str>) -> Request {
+        let mut builder = Request::builder()
+            .uri(uri)
+            .header(header::HOST, "api.zwipe.test");
+        if let Some(proto) = forwarded_proto {
+            builder = builder.header(X_FORWARDED_PROTO, proto);
+        }
+        let mut request = builder.body(Body::empty()).unwrap();
+        request
+            .extensions_mut()
+            .insert(ConnectInfo(SocketAddr::new(IpAddr::V4(peer), 40000)));
+        request
+    }
+
+    #[tokio::test]
+    async fn enforced_policy_redirects_http_and_sets_hsts_on_https() {
+        let app = router(HttpsPolicy::enforced(HSTS_MAX_AGE_DEFAULT));
+        let proxy = Ipv4Addr::LOCALHOST;
+
+        let response = app
+            .clone()
+            .oneshot(request("/health?x=1", proxy, Some("http")))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PERMANENT_REDIRECT);
+        assert_eq!(
+            response.headers()[header::LOCATION],
+            "https://api.zwipe.test/health?x=1"
+        );
+
+        let response = app
+            .clone()
+            .oneshot(request("/health", proxy, Some("https")))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::STRICT_TRANSPORT_SECURITY],
+            "max-age=31536000; includeSubDomains"
+        );
+
+        // A client can't claim HTTPS itself: the header only counts from a
+        // trusted proxy.
+        let spoofed = app
+            .oneshot(request(
+                "/health",
+                Ipv4Addr::new(203, 0, 113, 7),
+                Some("https"),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(spoofed.status(), StatusCode::PERMANENT_REDIRECT);
+    }
+
+    #[tokio::test]
+    async fn disabled_policy_passes_http_through() {
+        let response = router(HttpsPolicy::default())
+            .oneshot(request("/health", Ipv4Addr::LOCALHOST, None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(
+            !response
+                .headers()
+                .contains_key(header::STRICT_TRANSPORT_SECURITY)
+        );
+    }
+}
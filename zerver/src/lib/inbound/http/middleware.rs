@@ -1,26 +1,28 @@
-//! JWT authentication and last-active tracking middleware.
+//! JWT / API key authentication and last-active tracking middleware.
 
 #[cfg(feature = "zerver")]
 use crate::{
-    domain::auth::models::access_token::{JwtSecret, JwtValidate},
-    inbound::http::AppState,
+    domain::auth::{
+        models::access_token::{JwtSecret, JwtValidate},
+        requests::authenticate_api_key::AuthenticateApiKeyError,
+    },
+    domain::user::models::get_user::GetUserError,
+    inbound::http::{ApiError, AppState, Log500, client_ip::TrustedProxies},
 };
 #[cfg(feature = "zerver")]
-use axum::http::header::AUTHORIZATION;
+use axum::http::header::{AUTHORIZATION, CONTENT_TYPE};
 #[cfg(feature = "zerver")]
 use axum::{
-    extract::{ConnectInfo, FromRequestParts, Request, State},
-    http::{StatusCode, request::Parts},
+    Json,
+    extract::{FromRequestParts, Request, State},
+    http::{HeaderMap, HeaderValue, Method, StatusCode, request::Parts},
     middleware::Next,
-    response::Response,
+    response::{IntoResponse, Response},
 };
 #[cfg(feature = "zerver")]
-use axum_extra::{
-    TypedHeader,
-    headers::{Authorization, authorization::Bearer},
-};
+use jsonwebtoken::errors::ErrorKind;
 #[cfg(feature = "zerver")]
-use std::net::{IpAddr, SocketAddr};
+use std::net::IpAddr;
 #[cfg(feature = "zerver")]
 use std::str::FromStr;
 #[cfg(feature = "zerver")]
@@ -32,18 +34,28 @@ use tower_governor::{GovernorError, key_extractor::KeyExtractor};
 use uuid::Uuid;
 use zwipe_core::domain::{
     Email,
-    auth::models::access_token::{Jwt, UserClaims},
+    auth::models::{
+        access_token::{Jwt, UserClaims},
+        api_key::ApiKeyScope,
+    },
     user::username::Username,
 };
+#[cfg(feature = "zerver")]
+use zwipe_core::domain::{auth::models::api_key::ApiKeySecret, user::User};
+#[cfg(feature = "zerver")]
+use zwipe_core::http::contracts::auth::{HttpTokenError, TokenErrorCode};
 
-/// Axum extractor that enforces JWT authentication.
+/// Axum extractor that enforces JWT or API key authentication.
 ///
 /// Including this in a handler signature means the route requires a valid Bearer token.
 /// Extraction flow: `Authorization: Bearer <token>` → parse JWT → validate signature
-/// → extract claims.
+/// → extract claims. A token carrying the API key prefix is instead looked up by
+/// hash and resolved to its owner.
 ///
-/// Rejects with `400 Bad Request` if the header is missing or malformed,
-/// `401 Unauthorized` if the signature is invalid.
+/// Rejects with `401 Unauthorized` and a [`TokenRejection`] JSON body naming
+/// the reason (`token_missing`, `token_expired`, `token_invalid`), or `403`
+/// (`insufficient_scope`) when a read-only API key is sent on a request that
+/// changes data.
 pub struct AuthenticatedUser {
     /// User ID from JWT claims.
     pub id: Uuid,
@@ -51,55 +63,146 @@ pub struct AuthenticatedUser {
     pub username: Username,
     /// Email from JWT claims.
     pub email: Email,
+    /// Scope of the API key that authenticated the request; `None` for a
+    /// session JWT.
+    pub api_key_scope: Option<ApiKeyScope>,
+}
+
+/// Marks a `POST` route that only reads (e.g. a search whose filters don't
+/// fit in a query string), so read-only API keys may call it. Attach with
+/// `.layer(Extension(ReadOnlyRoute))`.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Clone, Copy)]
+pub struct ReadOnlyRoute;
+
+/// Why a request's bearer token was refused, rendered as a `401` with an
+/// [`HttpTokenError`] JSON body so clients can tell "refresh and retry" from
+/// "log in again".
+#[cfg(feature = "zerver")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenRejection(pub TokenErrorCode);
+
+#[cfg(feature = "zerver")]
+impl TokenRejection {
+    /// `403` for a scope refusal (re-authenticating won't help), `401` otherwise.
+    fn status(&self) -> StatusCode {
+        match self.0 {
+            TokenErrorCode::InsufficientScope => StatusCode::FORBIDDEN,
+            _ => StatusCode::UNAUTHORIZED,
+        }
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl IntoResponse for TokenRejection {
+    fn into_response(self) -> Response {
+        (self.status(), Json(HttpTokenError::from(self.0))).into_response()
+    }
+}
+
+/// The governor layer on private routes runs before the extractor, so the key
+/// extractor surfaces the same rejection through `GovernorError::Other`.
+#[cfg(feature = "zerver")]
+impl From<TokenRejection> for GovernorError {
+    fn from(value: TokenRejection) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        Self::Other {
+            code: value.status(),
+            msg: serde_json::to_string(&HttpTokenError::from(value.0)).ok(),
+            headers: Some(headers),
+        }
+    }
+}
+
+/// The request's `Authorization: Bearer` token, or why there isn't one. The
+/// scheme is matched case-insensitively (RFC 7235), so `bearer <token>` works.
+#[cfg(feature = "zerver")]
+fn bearer_token(headers: &HeaderMap) -> Result<&str, TokenRejection> {
+    let header = headers
+        .get(AUTHORIZATION)
+        .ok_or(TokenRejection(TokenErrorCode::TokenMissing))?;
+    header
+        .to_str()
+        .ok()
+        .and_then(|v| v.split_once(' '))
+        .filter(|(scheme, _)| scheme.eq_ignore_ascii_case("Bearer"))
+        .map(|(_, token)| token.trim_start())
+        .filter(|token| !token.is_empty())
+        .ok_or(TokenRejection(TokenErrorCode::TokenInvalid))
+}
+
+/// Validates the request's `Authorization: Bearer` token, classifying a
+/// failure as missing (no header), expired (verified but past `exp`), or
+/// invalid (anything else).
+#[cfg(feature = "zerver")]
+pub fn authenticate(headers: &HeaderMap, secret: &JwtSecret) -> Result<UserClaims, TokenRejection> {
+    let invalid = TokenRejection(TokenErrorCode::TokenInvalid);
+    let jwt = Jwt::from_str(bearer_token(headers)?).map_err(|_| invalid)?;
+    jwt.validate(secret).map_err(|e| match e.kind() {
+        ErrorKind::ExpiredSignature => TokenRejection(TokenErrorCode::TokenExpired),
+        _ => invalid,
+    })
+}
+
+/// The bucket a private-route request is rate limited in.
+#[cfg(feature = "zerver")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitKey {
+    /// A session JWT's user.
+    User(Uuid),
+    /// The client IP of an API-key request.
+    Client(IpAddr),
 }
 
 /// Rate-limit key extractor that keys by authenticated user ID from the JWT.
 ///
 /// Used on private routes so each user gets their own rate limit bucket
-/// regardless of IP address. Falls back to `UnableToExtractKey` for
-/// missing or invalid tokens — the auth middleware rejects those downstream.
+/// regardless of IP address. Missing or invalid tokens are rejected here with
+/// the [`TokenRejection`] the `AuthenticatedUser` extractor would give.
+///
+/// Resolving an API key needs the database, which a key extractor can't
+/// reach, so API-key requests are bucketed by client IP (as on public
+/// routes). Keying on the presented key would let a caller mint a fresh
+/// bucket per request with random keys.
 #[cfg(feature = "zerver")]
 #[derive(Debug, Clone)]
 pub struct UserIdKeyExtractor {
     jwt_secret: JwtSecret,
+    client_ip: ClientIpKeyExtractor,
 }
 
 #[cfg(feature = "zerver")]
 impl UserIdKeyExtractor {
-    /// Creates a new extractor with the given JWT secret for token validation.
-    pub fn new(jwt_secret: JwtSecret) -> Self {
-        Self { jwt_secret }
+    /// Creates a new extractor with the given JWT secret for token validation,
+    /// trusting forwarding headers from `proxies` for API-key requests.
+    pub fn new(jwt_secret: JwtSecret, proxies: TrustedProxies) -> Self {
+        Self {
+            jwt_secret,
+            client_ip: ClientIpKeyExtractor::new(proxies),
+        }
     }
 }
 
 #[cfg(feature = "zerver")]
 impl KeyExtractor for UserIdKeyExtractor {
-    type Key = Uuid;
+    type Key = RateLimitKey;
     fn extract<T>(
         &self,
         req: &axum::http::Request<T>,
     ) -> Result<Self::Key, tower_governor::errors::GovernorError> {
-        let token = req
-            .headers()
-            .get(AUTHORIZATION)
-            .and_then(|v| v.to_str().ok())
-            .and_then(|v| v.strip_prefix("Bearer "))
-            .ok_or(GovernorError::UnableToExtractKey)?;
-
-        let jwt = Jwt::from_str(token).map_err(|_| GovernorError::UnableToExtractKey)?;
-        let claims = jwt
-            .validate(&self.jwt_secret)
-            .map_err(|_| GovernorError::UnableToExtractKey)?;
-
-        Ok(claims.user_id)
+        if let Ok(token) = bearer_token(req.headers())
+            && ApiKeySecret::is_api_key(token)
+        {
+            return self.client_ip.extract(req).map(RateLimitKey::Client);
+        }
+        authenticate(req.headers(), &self.jwt_secret)
+            .map(|claims| RateLimitKey::User(claims.user_id))
+            .map_err(GovernorError::from)
     }
 }
 
-/// Canonical Cloudflare header carrying the true client IP.
-#[cfg(feature = "zerver")]
-const CF_CONNECTING_IP: &str = "cf-connecting-ip";
-
-/// Rate-limit key extractor that keys by the real client IP behind Cloudflare.
+/// Rate-limit key extractor that keys by the real client IP.
 ///
 /// The server runs behind a Cloudflare Tunnel: `cloudflared` proxies every
 /// request from `127.0.0.1`, so the TCP peer address is identical for all
@@ -108,38 +211,31 @@ const CF_CONNECTING_IP: &str = "cf-connecting-ip";
 /// exhaust it and lock everyone out, and per-attacker brute-force throttling
 /// wouldn't work at all.
 ///
-/// Cloudflare sets `CF-Connecting-IP` to the true client IP and overwrites any
-/// client-supplied value at its edge, so requests arriving through the tunnel
-/// can't forge it. The origin is unreachable directly from the public internet
-/// (ufw default-deny inbound; only loopback and `tailscale0` are allowed), so
-/// the header is trustworthy here.
-///
-/// Falls back to the socket peer IP when the header is absent — i.e. for
-/// non-Cloudflare paths (localhost health checks, Tailscale admin access),
-/// which are trusted. Real internet traffic always carries the header.
+/// Resolution is delegated to [`TrustedProxies::client_ip`]: forwarding
+/// headers (`CF-Connecting-IP`, `X-Real-IP`, `X-Forwarded-For`) are only read
+/// when the socket peer is a trusted proxy, so a client connecting directly
+/// can't pick its own bucket by spoofing them.
 #[cfg(feature = "zerver")]
-#[derive(Debug, Clone)]
-pub struct CfConnectingIpKeyExtractor;
+#[derive(Debug, Clone, Default)]
+pub struct ClientIpKeyExtractor {
+    proxies: TrustedProxies,
+}
 
 #[cfg(feature = "zerver")]
-impl KeyExtractor for CfConnectingIpKeyExtractor {
+impl ClientIpKeyExtractor {
+    /// Creates an extractor trusting forwarding headers from `proxies`.
+    pub fn new(proxies: TrustedProxies) -> Self {
+        Self { proxies }
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl KeyExtractor for ClientIpKeyExtractor {
     type Key = IpAddr;
 
     fn extract<T>(&self, req: &axum::http::Request<T>) -> Result<Self::Key, GovernorError> {
-        if let Some(ip) = req
-            .headers()
-            .get(CF_CONNECTING_IP)
-            .and_then(|v| v.to_str().ok())
-            .and_then(|v| v.trim().parse::<IpAddr>().ok())
-        {
-            return Ok(ip);
-        }
-
-        // No Cloudflare header: fall back to the socket peer IP (localhost /
-        // Tailscale paths). External traffic always carries the header.
-        req.extensions()
-            .get::<ConnectInfo<SocketAddr>>()
-            .map(|info| info.0.ip())
+        self.proxies
+            .client_ip_of(req)
             .ok_or(GovernorError::UnableToExtractKey)
     }
 }
@@ -150,6 +246,20 @@ impl From<UserClaims> for AuthenticatedUser {
             id: value.user_id,
             username: value.username,
             email: value.email,
+            api_key_scope: None,
+        }
+    }
+}
+
+#[cfg(feature = "zerver")]
+impl AuthenticatedUser {
+    /// The owner of an API key, carrying the key's scope.
+    fn from_api_key(user: User, scope: ApiKeyScope) -> Self {
+        Self {
+            id: user.id,
+            username: user.username,
+            email: user.email,
+            api_key_scope: Some(scope),
         }
     }
 }
@@ -163,22 +273,19 @@ const LAST_ACTIVE_DEBOUNCE: Duration = Duration::from_secs(60);
 ///
 /// Peeks the Bearer token without enforcing it — missing or invalid tokens
 /// pass through untouched and are rejected downstream by the
-/// `AuthenticatedUser` extractor. The write is fire-and-forget so it never
-/// adds latency to the request path. The debounce cache is in-memory and
-/// lost on restart, which is fine: the first request after a restart writes.
+/// `AuthenticatedUser` extractor. API keys are skipped: they record their own
+/// `last_used_at` and don't count as the user being active in the app. The
+/// write is fire-and-forget so it never adds latency to the request path. The
+/// debounce cache is in-memory and lost on restart, which is fine: the first
+/// request after a restart writes.
 #[cfg(feature = "zerver")]
 pub async fn track_last_active(
     State(state): State<AppState>,
     request: Request,
     next: Next,
 ) -> Response {
-    let user_id = request
-        .headers()
-        .get(AUTHORIZATION)
-        .and_then(|v| v.to_str().ok())
-        .and_then(|v| v.strip_prefix("Bearer "))
-        .and_then(|token| Jwt::from_str(token).ok())
-        .and_then(|jwt| jwt.validate(state.auth_service.jwt_secret()).ok())
+    let user_id = authenticate(request.headers(), state.auth_service.jwt_secret())
+        .ok()
         .map(|claims| claims.user_id);
 
     if let Some(user_id) = user_id {
@@ -202,31 +309,99 @@ pub async fn track_last_active(
 
 #[cfg(feature = "zerver")]
 impl FromRequestParts<AppState> for AuthenticatedUser {
-    type Rejection = StatusCode;
+    type Rejection = Response;
     async fn from_request_parts(
         parts: &mut Parts,
         state: &AppState,
     ) -> Result<Self, Self::Rejection> {
-        let TypedHeader(Authorization(bearer)) =
-            TypedHeader::<Authorization<Bearer>>::from_request_parts(parts, state)
-                .await
-                .map_err(|_| StatusCode::UNAUTHORIZED)?;
-        let jwt = Jwt::from_str(bearer.token()).map_err(|_| StatusCode::UNAUTHORIZED)?;
-        let claims = jwt
-            .validate(state.auth_service.jwt_secret())
-            .map_err(|_| StatusCode::UNAUTHORIZED)?;
-
-        Ok(AuthenticatedUser::from(claims))
+        let token = bearer_token(&parts.headers).map_err(IntoResponse::into_response)?;
+        if !ApiKeySecret::is_api_key(token) {
+            return authenticate(&parts.headers, state.auth_service.jwt_secret())
+                .map(AuthenticatedUser::from)
+                .map_err(IntoResponse::into_response);
+        }
+
+        let (user, scope) = match state.auth_service.authenticate_api_key(token).await {
+            Ok(owner) => owner,
+            Err(AuthenticateApiKeyError::Invalid) => {
+                return Err(TokenRejection(TokenErrorCode::TokenInvalid).into_response());
+            }
+            Err(e) => return Err(e.log_500().into_response()),
+        };
+        if !scope.can_write() && !is_read_only_request(parts) {
+            return Err(TokenRejection(TokenErrorCode::InsufficientScope).into_response());
+        }
+        Ok(AuthenticatedUser::from_api_key(user, scope))
     }
 }
 
+/// Axum extractor that admits only accounts with the admin role.
+///
+/// Authenticates exactly like [`AuthenticatedUser`], then reads the account's
+/// role from the database on every request (so revoking it takes effect at
+/// once). Rejects a non-admin with `403 Forbidden`.
+#[cfg(feature = "zerver")]
+pub struct AdminUser(pub AuthenticatedUser);
+
+#[cfg(feature = "zerver")]
+impl FromRequestParts<AppState> for AdminUser {
+    type Rejection = Response;
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let user = AuthenticatedUser::from_request_parts(parts, state).await?;
+        let forbidden = || ApiError::Forbidden("admin only".to_string()).into_response();
+        match state.user_service.get_user_role(user.id).await {
+            Ok(role) if role.is_admin() => Ok(Self(user)),
+            Ok(_) | Err(GetUserError::NotFound) => Err(forbidden()),
+            Err(e) => Err(ApiError::from(e).into_response()),
+        }
+    }
+}
+
+/// Axum extractor for account-management routes (sessions, API keys,
+/// credentials, account deletion): authenticates like [`AuthenticatedUser`]
+/// but accepts session JWTs only. An API key — whatever its scope — is
+/// rejected with `403` (`insufficient_scope`), so a leaked key can't lock the
+/// owner out or outlive its own revocation.
+#[cfg(feature = "zerver")]
+pub struct SessionUser(pub AuthenticatedUser);
+
+#[cfg(feature = "zerver")]
+impl FromRequestParts<AppState> for SessionUser {
+    type Rejection = Response;
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let user = AuthenticatedUser::from_request_parts(parts, state).await?;
+        if user.api_key_scope.is_some() {
+            return Err(TokenRejection(TokenErrorCode::InsufficientScope).into_response());
+        }
+        Ok(Self(user))
+    }
+}
+
+/// True when the request can't change data: a safe method, or a route
+/// marked [`ReadOnlyRoute`].
+#[cfg(feature = "zerver")]
+fn is_read_only_request(parts: &Parts) -> bool {
+    matches!(parts.method, Method::GET | Method::HEAD | Method::OPTIONS)
+        || parts.extensions.get::<ReadOnlyRoute>().is_some()
+}
+
 #[cfg(all(test, feature = "zerver"))]
 #[allow(clippy::unwrap_used)]
 mod tests {
-    use super::{CF_CONNECTING_IP, CfConnectingIpKeyExtractor};
-    use axum::extract::ConnectInfo;
+    use super::{ClientIpKeyExtractor, TokenRejection, bearer_token};
+    use axum::{
+        extract::ConnectInfo,
+        http::{HeaderMap, HeaderValue, header::AUTHORIZATION},
+    };
     use std::net::{IpAddr, Ipv4Addr, SocketAddr};
     use tower_governor::key_extractor::KeyExtractor;
+    use zwipe_core::http::contracts::auth::TokenErrorCode;
 
     fn peer(ip: [u8; 4]) -> ConnectInfo<SocketAddr> {
         ConnectInfo(SocketAddr::new(
@@ -241,7 +416,7 @@ mod tests {
     ) -> axum::http::Request<()> {
         let mut builder = axum::http::Request::builder();
         if let Some(h) = header {
-            builder = builder.header(CF_CONNECTING_IP, h);
+            builder = builder.header("cf-connecting-ip", h);
         }
         let mut req = builder.body(()).unwrap();
         if let Some(p) = peer_ip {
@@ -259,7 +434,7 @@ mod tests {
         // Even though the socket peer is loopback (the tunnel), the real
         // client IP from the header must win.
         let req = request(Some("203.0.113.7"), Some(peer([127, 0, 0, 1])));
-        let key = CfConnectingIpKeyExtractor.extract(&req).unwrap();
+        let key = ClientIpKeyExtractor::default().extract(&req).unwrap();
         assert_eq!(key, ip("203.0.113.7"));
     }
 
@@ -267,10 +442,10 @@ mod tests {
     fn distinct_cf_ips_yield_distinct_keys() {
         // The core property: two clients behind the same tunnel peer get
         // separate buckets.
-        let a = CfConnectingIpKeyExtractor
+        let a = ClientIpKeyExtractor::default()
             .extract(&request(Some("203.0.113.7"), Some(peer([127, 0, 0, 1]))))
             .unwrap();
-        let b = CfConnectingIpKeyExtractor
+        let b = ClientIpKeyExtractor::default()
             .extract(&request(Some("198.51.100.4"), Some(peer([127, 0, 0, 1]))))
             .unwrap();
         assert_ne!(a, b);
@@ -280,27 +455,55 @@ mod tests {
     fn falls_back_to_peer_when_header_absent() {
         // Non-Cloudflare path (e.g. Tailscale): no header, key off the peer.
         let req = request(None, Some(peer([100, 64, 0, 9])));
-        let key = CfConnectingIpKeyExtractor.extract(&req).unwrap();
+        let key = ClientIpKeyExtractor::default().extract(&req).unwrap();
         assert_eq!(key, ip("100.64.0.9"));
     }
 
     #[test]
     fn falls_back_to_peer_when_header_garbage() {
-        let req = request(Some("not-an-ip"), Some(peer([100, 64, 0, 9])));
-        let key = CfConnectingIpKeyExtractor.extract(&req).unwrap();
+        let req = request(Some("not-an-ip"), Some(peer([127, 0, 0, 1])));
+        let key = ClientIpKeyExtractor::default().extract(&req).unwrap();
+        assert_eq!(key, ip("127.0.0.1"));
+    }
+
+    #[test]
+    fn ignores_header_from_untrusted_peer() {
+        // A client reaching the origin directly can't choose its own bucket.
+        let req = request(Some("203.0.113.7"), Some(peer([100, 64, 0, 9])));
+        let key = ClientIpKeyExtractor::default().extract(&req).unwrap();
         assert_eq!(key, ip("100.64.0.9"));
     }
 
     #[test]
     fn trims_whitespace_in_header() {
         let req = request(Some("  203.0.113.7  "), Some(peer([127, 0, 0, 1])));
-        let key = CfConnectingIpKeyExtractor.extract(&req).unwrap();
+        let key = ClientIpKeyExtractor::default().extract(&req).unwrap();
         assert_eq!(key, ip("203.0.113.7"));
     }
 
     #[test]
     fn errors_when_no_header_and_no_peer() {
         let req = request(None, None);
-        assert!(CfConnectingIpKeyExtractor.extract(&req).is_err());
+        assert!(ClientIpKeyExtractor::default().extract(&req).is_err());
+    }
+
+    #[test]
+    fn bearer_scheme_is_case_insensitive() {
+        let token = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(AUTHORIZATION, HeaderValue::from_str(value).unwrap());
+            bearer_token(&headers).map(str::to_string)
+        };
+        for value in ["Bearer abc", "bearer abc", "BEARER abc"] {
+            assert_eq!(token(value).as_deref(), Ok("abc"), "{value}");
+        }
+        let invalid = Err(TokenRejection(TokenErrorCode::TokenInvalid));
+        assert_eq!(token("Basic abc"), invalid);
+        assert_eq!(token("Bearer"), invalid);
+        assert_eq!(token("Bearer "), invalid);
+        assert_eq!(
+            bearer_token(&HeaderMap::new()),
+            Err(TokenRejection(TokenErrorCode::TokenMissing))
+        );
     }
 }
@@ -1,12 +1,24 @@
 //! HTTP layer: Axum server, error mapping, middleware, and route definitions.
 
+#[cfg(feature = "zerver")]
+/// Request body size caps (default and import routes).
+pub mod body_limit;
+#[cfg(feature = "zerver")]
+/// Real client IP resolution behind trusted reverse proxies.
+pub mod client_ip;
 /// HTTP request handlers organized by domain.
 pub mod handlers;
 #[cfg(feature = "zerver")]
+/// Optional HTTP → HTTPS redirect and HSTS.
+pub mod https;
+#[cfg(feature = "zerver")]
 /// JWT authentication and last-active tracking middleware.
 pub mod middleware;
 /// Route definitions mapping paths to handlers.
 pub mod routes;
+#[cfg(feature = "zerver")]
+/// Configurable security response headers (CSP, framing, referrer).
+pub mod security_headers;
 
 #[cfg(feature = "zerver")]
 use crate::{
@@ -19,16 +31,20 @@ use crate::{
         metrics::ports::ErasedMetricsService,
         user::ports::{ErasedUserService, UserService},
     },
-    inbound::http::routes::{private_routes, public_routes},
+    inbound::http::{
+        body_limit::BodyLimits,
+        client_ip::TrustedProxies,
+        https::{HttpsGuard, HttpsPolicy, enforce_https},
+        routes::{private_routes, public_routes},
+        security_headers::{SecurityHeaders, security_headers},
+    },
 };
 #[cfg(feature = "zerver")]
 use anyhow::{Context, anyhow};
 #[cfg(feature = "zerver")]
 use axum::{
-    extract::Request,
     http::{HeaderValue, Method, StatusCode, header},
-    middleware::Next,
-    response::{IntoResponse, Response},
+    response::IntoResponse,
 };
 #[cfg(feature = "zerver")]
 use dashmap::DashMap;
@@ -52,6 +68,7 @@ use tower_http::{
 };
 #[cfg(feature = "zerver")]
 use uuid::Uuid;
+use zwipe_core::http::contracts::auth::HttpTokenError;
 
 // =======
 //  error
@@ -126,7 +143,12 @@ impl From<(reqwest::StatusCode, String)> for ApiError {
         let message = message.to_lowercase();
         match status {
             reqwest::StatusCode::INTERNAL_SERVER_ERROR => Self::InternalServerError(message),
-            reqwest::StatusCode::UNAUTHORIZED => Self::Unauthorized(message),
+            // Token rejections carry a JSON body; surface its message.
+            reqwest::StatusCode::UNAUTHORIZED => Self::Unauthorized(
+                serde_json::from_str::<HttpTokenError>(&message)
+                    .map(|e| e.message)
+                    .unwrap_or(message),
+            ),
             reqwest::StatusCode::FORBIDDEN => Self::Forbidden(message),
             reqwest::StatusCode::NOT_FOUND => Self::NotFound(message),
             reqwest::StatusCode::UNPROCESSABLE_ENTITY => Self::UnprocessableEntity(message),
@@ -184,33 +206,20 @@ where
 }
 
 // ========
-//  security headers
+//  server
 // ========
 
-/// Adds security-relevant HTTP response headers to every response.
-///
-/// - `X-Content-Type-Options: nosniff` — prevents MIME-type sniffing
-/// - `X-Frame-Options: DENY` — prevents clickjacking via iframe embedding
-/// - `Referrer-Policy: strict-origin-when-cross-origin` — limits referrer leakage
+/// Response headers browser clients may read cross-origin (pagination,
+/// caching, request correlation). CORS hides every non-safelisted response
+/// header from frontend JS unless it's listed in `Access-Control-Expose-Headers`.
 #[cfg(feature = "zerver")]
-async fn security_headers(request: Request, next: Next) -> Response {
-    let mut response = next.run(request).await;
-    let headers = response.headers_mut();
-    headers.insert(
-        header::X_CONTENT_TYPE_OPTIONS,
-        HeaderValue::from_static("nosniff"),
-    );
-    headers.insert(header::X_FRAME_OPTIONS, HeaderValue::from_static("DENY"));
-    headers.insert(
-        header::REFERRER_POLICY,
-        HeaderValue::from_static("strict-origin-when-cross-origin"),
-    );
-    response
-}
-
-// ========
-//  server
-// ========
+pub const CORS_EXPOSE_HEADERS: [&str; 5] = [
+    "x-total-count",
+    "link",
+    "etag",
+    "x-request-id",
+    handlers::card::search_card::NEXT_CURSOR_HEADER,
+];
 
 /// Bind address and CORS origins for the HTTP server.
 #[cfg(feature = "zerver")]
@@ -225,6 +234,14 @@ pub struct HttpServerConfig<'a> {
     /// Public web base URL (e.g. `https://zwipe.net`); used for outbound
     /// User-Agent contact info.
     pub web_base_url: String,
+    /// Peers whose forwarding headers are trusted for the client IP.
+    pub trusted_proxies: TrustedProxies,
+    /// HTTP → HTTPS redirect and HSTS (off by default).
+    pub https_policy: HttpsPolicy,
+    /// Security response header values.
+    pub security_headers: SecurityHeaders,
+    /// Request body size caps.
+    pub body_limits: BodyLimits,
 }
 
 /// Shared application state holding all service implementations.
@@ -266,12 +283,21 @@ pub fn build_router(
     state: AppState,
     jwt_secret: JwtSecret,
     allowed_origins: Vec<HeaderValue>,
+    trusted_proxies: TrustedProxies,
+    https_policy: HttpsPolicy,
+    security_headers_config: SecurityHeaders,
+    body_limits: BodyLimits,
 ) -> axum::Router {
     // RequestId is set by SetRequestIdLayer before TraceLayer fires, so it's
     // available as a request extension when we build the span.
+    let span_proxies = trusted_proxies.clone();
     let trace_layer = tower_http::trace::TraceLayer::new_for_http().make_span_with(
-        |request: &axum::extract::Request<_>| {
+        move |request: &axum::extract::Request<_>| {
             let uri = request.uri().to_string();
+            let client_ip = span_proxies
+                .client_ip_of(request)
+                .map(|ip| ip.to_string())
+                .unwrap_or_default();
             let request_id = request
                 .extensions()
                 .get::<tower_http::request_id::RequestId>()
@@ -282,39 +308,51 @@ pub fn build_router(
                 method = ?request.method(),
                 uri,
                 request_id = %request_id,
+                client_ip,
             )
         },
     );
 
+    let https_guard = HttpsGuard::new(https_policy, trusted_proxies.clone());
+
     // Layer order is innermost-first, outermost-last. Request flows outer→inner;
     // response flows inner→outer. Effective stack: SetRequestId → PropagateRequestId
-    // → trace → CatchPanic → Compression → Cors → security_headers → Timeout(30s)
-    // → RequestBodyLimit(2 MiB, innermost).
+    // → trace → enforce_https → CatchPanic → Compression → Cors → security_headers
+    // → Timeout(30s) → RequestBodyLimit(largest body cap) → DefaultBodyLimit
+    // (per-route body cap, innermost).
     let x_request_id = header::HeaderName::from_static("x-request-id");
     axum::Router::new()
         .merge(
             // last-active layer wraps private routes only — it peeks the Bearer
             // token, so it must sit where every request carries one
-            private_routes(jwt_secret).layer(axum::middleware::from_fn_with_state(
-                state.clone(),
-                middleware::track_last_active,
-            )),
+            private_routes(jwt_secret, trusted_proxies.clone(), body_limits).layer(
+                axum::middleware::from_fn_with_state(state.clone(), middleware::track_last_active),
+            ),
         )
-        .merge(public_routes())
-        .layer(RequestBodyLimitLayer::new(2 * 1024 * 1024))
+        .merge(public_routes(trusted_proxies))
+        .layer(body_limits.default_layer())
+        .layer(RequestBodyLimitLayer::new(body_limits.ceiling()))
         .layer(TimeoutLayer::with_status_code(
             StatusCode::REQUEST_TIMEOUT,
             Duration::from_secs(30),
         ))
-        .layer(axum::middleware::from_fn(security_headers))
+        .layer(axum::middleware::from_fn_with_state(
+            security_headers_config,
+            security_headers,
+        ))
         .layer(
             CorsLayer::new()
                 .allow_origin(allowed_origins)
                 .allow_methods([Method::GET, Method::POST, Method::PUT, Method::DELETE])
-                .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION]),
+                .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION])
+                .expose_headers(CORS_EXPOSE_HEADERS.map(header::HeaderName::from_static)),
         )
         .layer(CompressionLayer::new())
         .layer(CatchPanicLayer::new())
+        .layer(axum::middleware::from_fn_with_state(
+            https_guard,
+            enforce_https,
+        ))
         .layer(trace_layer)
         .layer(PropagateRequestIdLayer::new(x_request_id.clone()))
         .layer(SetRequestIdLayer::new(x_request_id, MakeRequestUuid))
@@ -348,7 +386,15 @@ impl HttpServer {
             web_base_url: Arc::from(config.web_base_url.as_str()),
         };
 
-        let router = build_router(state, jwt_secret, config.allowed_origins);
+        let router = build_router(
+            state,
+            jwt_secret,
+            config.allowed_origins,
+            config.trusted_proxies,
+            config.https_policy,
+            config.security_headers,
+            config.body_limits,
+        );
 
         let listener = net::TcpListener::bind(&config.bind_address)
             .await
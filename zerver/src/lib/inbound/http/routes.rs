@@ -5,29 +5,55 @@ use crate::domain::auth::models::access_token::JwtSecret;
 #[cfg(feature = "zerver")]
 use crate::inbound::http::AppState;
 #[cfg(feature = "zerver")]
+use crate::inbound::http::body_limit::BodyLimits;
+#[cfg(feature = "zerver")]
+use crate::inbound::http::client_ip::TrustedProxies;
+#[cfg(feature = "zerver")]
 use crate::inbound::http::handlers::{
     auth::{
         authenticate_user::authenticate_user, change_email::change_email,
         change_password::change_password, change_username::change_username,
-        delete_user::delete_user, refresh_session::refresh_session, register_user::register_user,
-        request_password_reset::request_password_reset, resend_verification::resend_verification,
-        reset_password::reset_password, revoke_sessions::revoke_sessions,
-        verify_email::verify_email,
+        create_api_key::create_api_key, delete_user::delete_user,
+        get_current_user::get_current_user, list_api_keys::list_api_keys,
+        list_sessions::list_sessions, refresh_session::refresh_session,
+        register_user::register_user, request_password_reset::request_password_reset,
+        resend_verification::resend_verification, reset_password::reset_password,
+        revoke_api_key::revoke_api_key, revoke_session::revoke_session,
+        revoke_sessions::revoke_sessions, verify_email::verify_email,
     },
     card::{
-        get_artists::get_artists, get_card::get_card, get_card_roles::get_card_roles,
-        get_card_types::get_card_types, get_keywords::get_keywords, get_languages::get_languages,
-        get_oracle_tags::get_oracle_tags, get_oracle_words::get_oracle_words,
-        get_printings::get_printings, get_sets::get_sets, search_card::search_cards,
+        color_distribution::color_distribution,
+        considering::{add_to_considering, list_considering, remove_from_considering},
+        favorites::{list_favorites, set_favorites},
+        get_artists::get_artists,
+        get_card::get_card,
+        get_card_roles::get_card_roles,
+        get_card_types::get_card_types,
+        get_card_with_printings::get_card_with_printings,
+        get_keywords::get_keywords,
+        get_languages::get_languages,
+        get_oracle_tags::get_oracle_tags,
+        get_oracle_words::get_oracle_words,
+        get_printings::get_printings,
+        get_raw_card::get_raw_card,
+        get_set_completion::get_set_completion,
+        get_set_icon::get_set_icon,
+        get_sets::get_sets,
+        search_card::{search_card_summaries, search_cards},
         search_commanders::search_commanders,
+        undiscovered_staples::undiscovered_staples,
     },
     changelog::get_changelog,
     client::get_min_client_version,
     deck::{
         clear_deck_suppressions::clear_deck_suppressions,
-        clone_deck::clone_deck,
+        clone_deck::{clone_deck, import_shared_deck},
+        collection::{deck_missing_cards, get_owned, import_collection, set_owned},
         create_deck_profile::create_deck_profile,
+        deck_json::{export_deck_json, import_deck_json},
+        deck_stats::deck_stats,
         delete_deck::delete_deck,
+        export_deck::export_deck,
         get_deck::get_deck,
         get_deck_profile::get_deck_profile,
         get_deck_profiles::get_deck_profiles,
@@ -35,16 +61,26 @@ use crate::inbound::http::handlers::{
         get_deck_tokens::get_deck_tokens,
         get_shared_deck::get_shared_deck,
         import_archidekt::import_archidekt_deck,
+        import_decklist::import_decklist,
+        legality_report::{legality_report, validate_deck},
+        proxy_sheet::proxy_sheet,
         search_deck_cards::search_deck_cards,
+        set_deck_labels::set_deck_labels,
         share_deck::{share_deck, unshare_deck},
         skip_deck_card::{skip_deck_card, unskip_deck_card},
+        suggest_deck_name::suggest_deck_name,
         update_deck_profile::update_deck_profile,
     },
     deck_card::{
-        create_deck_card::create_deck_card, delete_deck_card::delete_deck_card,
-        import_deck_cards::import_deck_cards, update_deck_card::update_deck_card,
+        bulk_add_deck_cards::bulk_add_deck_cards,
+        create_deck_card::create_deck_card,
+        delete_deck_card::delete_deck_card,
+        import_deck_cards::import_deck_cards,
+        move_deck_cards::move_deck_cards,
+        orphaned_deck_cards::{find_orphaned_deck_cards, repair_orphaned_deck_cards},
+        update_deck_card::update_deck_card,
     },
-    health::{are_server_and_database_running, is_server_running, root},
+    health::{are_server_and_database_running, is_server_ready, is_server_running, root},
     metrics::{
         get_my_metrics::get_my_metrics, get_public_metrics::get_public_metrics,
         record_anonymous_event::record_anonymous_event, record_usage::record_usage,
@@ -55,20 +91,21 @@ use crate::inbound::http::handlers::{
     },
 };
 #[cfg(feature = "zerver")]
-use crate::inbound::http::middleware::{CfConnectingIpKeyExtractor, UserIdKeyExtractor};
-#[cfg(feature = "zerver")]
-use axum::Router;
+use crate::inbound::http::middleware::{
+    ClientIpKeyExtractor, ReadOnlyRoute, TokenRejection, UserIdKeyExtractor,
+};
 #[cfg(feature = "zerver")]
 use axum::routing::{delete, get, post, put};
 #[cfg(feature = "zerver")]
-use axum::{
-    body::Body,
-    http::{Response, StatusCode},
-};
+use axum::{Extension, Router};
+#[cfg(feature = "zerver")]
+use axum::{body::Body, http::Response, response::IntoResponse};
 #[cfg(feature = "zerver")]
 use std::{sync::Arc, time::Duration};
 #[cfg(feature = "zerver")]
 use tower_governor::{GovernorLayer, errors::GovernorError, governor::GovernorConfigBuilder};
+#[cfg(feature = "zerver")]
+use zwipe_core::http::contracts::auth::TokenErrorCode;
 
 /// Rate-limit error handler for the routes keyed by user id
 /// (`UserIdKeyExtractor`).
@@ -81,7 +118,9 @@ use tower_governor::{GovernorLayer, errors::GovernorError, governor::GovernorCon
 /// instead of 401, polluting error logs and misleading health probes.
 ///
 /// This remaps that one case to **401 Unauthorized** (the honest status for
-/// "you didn't authenticate"). Every other variant — notably
+/// "you didn't authenticate"). `UserIdKeyExtractor` itself reports bad tokens
+/// as `GovernorError::Other` carrying the classified [`TokenRejection`] JSON,
+/// so `UnableToExtractKey` is only the fallback. Every other variant — notably
 /// `TooManyRequests` (429) for genuine rate-limit hits — is delegated to the
 /// library's default response, so real rate limiting is unchanged.
 ///
@@ -91,9 +130,7 @@ use tower_governor::{GovernorLayer, errors::GovernorError, governor::GovernorCon
 fn unauthorized_on_missing_key(error: GovernorError) -> Response<Body> {
     match error {
         GovernorError::UnableToExtractKey => {
-            let mut response = Response::new(Body::from("missing or invalid authorization"));
-            *response.status_mut() = StatusCode::UNAUTHORIZED;
-            response
+            TokenRejection(TokenErrorCode::TokenInvalid).into_response()
         }
         other => other.into_response().map(Body::from),
     }
@@ -104,13 +141,13 @@ pub use zwipe_core::http::paths::*;
 /// Routes that don't require authentication.
 #[cfg(feature = "zerver")]
 #[allow(clippy::expect_used)]
-pub fn public_routes() -> Router<AppState> {
+pub fn public_routes(trusted_proxies: TrustedProxies) -> Router<AppState> {
     // 5 req / 30s — tight limit, brute-force target
     let login_config = Arc::new(
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(6))
             .burst_size(5)
-            .key_extractor(CfConnectingIpKeyExtractor)
+            .key_extractor(ClientIpKeyExtractor::new(trusted_proxies.clone()))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -119,7 +156,7 @@ pub fn public_routes() -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(720))
             .burst_size(5)
-            .key_extractor(CfConnectingIpKeyExtractor)
+            .key_extractor(ClientIpKeyExtractor::new(trusted_proxies.clone()))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -128,7 +165,7 @@ pub fn public_routes() -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(3))
             .burst_size(20)
-            .key_extractor(CfConnectingIpKeyExtractor)
+            .key_extractor(ClientIpKeyExtractor::new(trusted_proxies.clone()))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -137,7 +174,7 @@ pub fn public_routes() -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(720))
             .burst_size(5)
-            .key_extractor(CfConnectingIpKeyExtractor)
+            .key_extractor(ClientIpKeyExtractor::new(trusted_proxies.clone()))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -146,7 +183,7 @@ pub fn public_routes() -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(360))
             .burst_size(10)
-            .key_extractor(CfConnectingIpKeyExtractor)
+            .key_extractor(ClientIpKeyExtractor::new(trusted_proxies.clone()))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -156,7 +193,7 @@ pub fn public_routes() -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(1))
             .burst_size(60)
-            .key_extractor(CfConnectingIpKeyExtractor)
+            .key_extractor(ClientIpKeyExtractor::new(trusted_proxies.clone()))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -166,7 +203,7 @@ pub fn public_routes() -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(2))
             .burst_size(30)
-            .key_extractor(CfConnectingIpKeyExtractor)
+            .key_extractor(ClientIpKeyExtractor::new(trusted_proxies.clone()))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -176,7 +213,7 @@ pub fn public_routes() -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(2))
             .burst_size(30)
-            .key_extractor(CfConnectingIpKeyExtractor)
+            .key_extractor(ClientIpKeyExtractor::new(trusted_proxies.clone()))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -187,7 +224,7 @@ pub fn public_routes() -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(2))
             .burst_size(30)
-            .key_extractor(CfConnectingIpKeyExtractor)
+            .key_extractor(ClientIpKeyExtractor::new(trusted_proxies.clone()))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -198,7 +235,7 @@ pub fn public_routes() -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(6))
             .burst_size(10)
-            .key_extractor(CfConnectingIpKeyExtractor)
+            .key_extractor(ClientIpKeyExtractor::new(trusted_proxies.clone()))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -208,7 +245,7 @@ pub fn public_routes() -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(2))
             .burst_size(30)
-            .key_extractor(CfConnectingIpKeyExtractor)
+            .key_extractor(ClientIpKeyExtractor::new(trusted_proxies.clone()))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -219,7 +256,7 @@ pub fn public_routes() -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(2))
             .burst_size(30)
-            .key_extractor(CfConnectingIpKeyExtractor)
+            .key_extractor(ClientIpKeyExtractor::new(trusted_proxies))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -229,7 +266,7 @@ pub fn public_routes() -> Router<AppState> {
         .nest(
             "/health",
             Router::new()
-                .route("/", get(are_server_and_database_running))
+                .route("/", get(is_server_ready))
                 .route("/server", get(is_server_running))
                 .route("/database", get(are_server_and_database_running))
                 .layer(GovernorLayer::new(health_config)),
@@ -272,6 +309,10 @@ pub fn public_routes() -> Router<AppState> {
                     Router::new()
                         .route("/{scryfall_data_id}", get(get_card))
                         .route("/{oracle_id}/printings", get(get_printings))
+                        .route(
+                            "/{scryfall_data_id}/with-printings",
+                            get(get_card_with_printings),
+                        )
                         .route("/artists", get(get_artists))
                         .route("/types", get(get_card_types))
                         .route("/keywords", get(get_keywords))
@@ -280,6 +321,7 @@ pub fn public_routes() -> Router<AppState> {
                         .route("/oracle-words", get(get_oracle_words))
                         .route("/languages", get(get_languages))
                         .route("/sets", get(get_sets))
+                        .route("/sets/{set_code}/icon", get(get_set_icon))
                         .layer(GovernorLayer::new(public_card_config)),
                 )
                 .nest(
@@ -313,16 +355,25 @@ pub fn public_routes() -> Router<AppState> {
         )
 }
 
-/// Routes that require `AuthenticatedUser` (JWT Bearer token).
+/// Routes that require `AuthenticatedUser` (JWT Bearer token). Import
+/// routes accept bodies up to `body_limits.import`; API-key requests are
+/// rate limited by client IP resolved through `trusted_proxies`.
 #[cfg(feature = "zerver")]
 #[allow(clippy::expect_used)]
-pub fn private_routes(jwt_secret: JwtSecret) -> Router<AppState> {
+pub fn private_routes(
+    jwt_secret: JwtSecret,
+    trusted_proxies: TrustedProxies,
+    body_limits: BodyLimits,
+) -> Router<AppState> {
     // 500 req / 5min (~1.67/s avg) — generous for swiping, keyed by user ID
     let private_config = Arc::new(
         GovernorConfigBuilder::default()
             .period(Duration::from_millis(600))
             .burst_size(500)
-            .key_extractor(UserIdKeyExtractor::new(jwt_secret.clone()))
+            .key_extractor(UserIdKeyExtractor::new(
+                jwt_secret.clone(),
+                trusted_proxies.clone(),
+            ))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -331,7 +382,10 @@ pub fn private_routes(jwt_secret: JwtSecret) -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(1800))
             .burst_size(2)
-            .key_extractor(UserIdKeyExtractor::new(jwt_secret.clone()))
+            .key_extractor(UserIdKeyExtractor::new(
+                jwt_secret.clone(),
+                trusted_proxies.clone(),
+            ))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -340,7 +394,10 @@ pub fn private_routes(jwt_secret: JwtSecret) -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(10))
             .burst_size(20)
-            .key_extractor(UserIdKeyExtractor::new(jwt_secret.clone()))
+            .key_extractor(UserIdKeyExtractor::new(
+                jwt_secret.clone(),
+                trusted_proxies.clone(),
+            ))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -350,7 +407,10 @@ pub fn private_routes(jwt_secret: JwtSecret) -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(60))
             .burst_size(1)
-            .key_extractor(UserIdKeyExtractor::new(jwt_secret.clone()))
+            .key_extractor(UserIdKeyExtractor::new(
+                jwt_secret.clone(),
+                trusted_proxies.clone(),
+            ))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -359,7 +419,7 @@ pub fn private_routes(jwt_secret: JwtSecret) -> Router<AppState> {
         GovernorConfigBuilder::default()
             .period(Duration::from_secs(5))
             .burst_size(12)
-            .key_extractor(UserIdKeyExtractor::new(jwt_secret))
+            .key_extractor(UserIdKeyExtractor::new(jwt_secret, trusted_proxies))
             .finish()
             .expect("rate limit config: burst_size and period must be non-zero"),
     );
@@ -370,13 +430,20 @@ pub fn private_routes(jwt_secret: JwtSecret) -> Router<AppState> {
             Router::new()
                 .nest(
                     "/auth",
-                    Router::new().route("/logout", post(revoke_sessions)).route(
-                        "/resend-verification",
-                        post(resend_verification).layer(
-                            GovernorLayer::new(resend_verification_config)
-                                .error_handler(unauthorized_on_missing_key),
-                        ),
-                    ),
+                    Router::new()
+                        .route("/me", get(get_current_user))
+                        .route("/logout", post(revoke_sessions))
+                        .route("/sessions", get(list_sessions))
+                        .route("/sessions/{session_id}", delete(revoke_session))
+                        .route(
+                            "/resend-verification",
+                            post(resend_verification).layer(
+                                GovernorLayer::new(resend_verification_config)
+                                    .error_handler(unauthorized_on_missing_key),
+                            ),
+                        )
+                        .route("/api-keys", get(list_api_keys).post(create_api_key))
+                        .route("/api-keys/{key_id}", delete(revoke_api_key)),
                 )
                 .nest(
                     "/user",
@@ -424,17 +491,45 @@ pub fn private_routes(jwt_secret: JwtSecret) -> Router<AppState> {
                                     .error_handler(unauthorized_on_missing_key),
                             ),
                         )
+                        .route(
+                            "/search/summary",
+                            post(search_card_summaries).layer(
+                                GovernorLayer::new(Arc::clone(&card_search_config))
+                                    .error_handler(unauthorized_on_missing_key),
+                            ),
+                        )
+                        .route("/undiscovered-staples", post(undiscovered_staples))
                         // Commander search shares the card-search budget: it's the
                         // same user behavior (typing in a select screen), just a
                         // dedicated serving path.
+                        .route(
+                            "/search/color-distribution",
+                            post(color_distribution).layer(
+                                GovernorLayer::new(Arc::clone(&card_search_config))
+                                    .error_handler(unauthorized_on_missing_key),
+                            ),
+                        )
                         .route(
                             "/search/commanders",
                             post(search_commanders).layer(
                                 GovernorLayer::new(card_search_config)
                                     .error_handler(unauthorized_on_missing_key),
                             ),
+                        )
+                        // every POST above only reads, so read-only API keys may call it
+                        .route_layer(Extension(ReadOnlyRoute))
+                        .route("/considering", get(list_considering))
+                        .route("/sets/{set_code}/completion", get(get_set_completion))
+                        .route("/favorites", get(list_favorites).put(set_favorites))
+                        .route(
+                            "/considering/{scryfall_data_id}",
+                            post(add_to_considering).delete(remove_from_considering),
                         ),
                 )
+                .nest(
+                    "/admin",
+                    Router::new().route("/card/{scryfall_data_id}/raw", get(get_raw_card)),
+                )
                 .nest(
                     "/metrics",
                     Router::new().route(
@@ -445,19 +540,46 @@ pub fn private_routes(jwt_secret: JwtSecret) -> Router<AppState> {
                         ),
                     ),
                 )
+                .nest(
+                    "/collection",
+                    Router::new()
+                        .route("/", get(get_owned))
+                        .route(
+                            "/import",
+                            post(import_collection).layer(body_limits.import_layer()),
+                        )
+                        .route("/{oracle_id}", put(set_owned)),
+                )
                 .nest(
                     "/deck",
                     Router::new()
                         .route("/", get(get_deck_profiles).post(create_deck_profile))
                         .route("/tags", get(get_deck_tags))
+                        .route("/name-suggestions/{commander_id}", get(suggest_deck_name))
+                        .route("/orphaned-cards", get(find_orphaned_deck_cards))
+                        .route("/orphaned-cards/repair", post(repair_orphaned_deck_cards))
+                        .route(
+                            "/import",
+                            post(import_deck_json).layer(body_limits.import_layer()),
+                        )
+                        .route(
+                            "/import/text",
+                            post(import_decklist).layer(body_limits.import_layer()),
+                        )
                         .route("/{deck_id}/import/archidekt", post(import_archidekt_deck))
                         .route("/profile/{deck_id}", get(get_deck_profile))
                         .route(
                             "/{deck_id}",
                             get(get_deck).put(update_deck_profile).delete(delete_deck),
                         )
+                        .route("/{deck_id}/stats", get(deck_stats))
                         .route("/{deck_id}/clone", post(clone_deck))
+                        .route("/shared/{token}", post(import_shared_deck))
                         .route("/{deck_id}/share", post(share_deck).delete(unshare_deck))
+                        .route("/{deck_id}/labels", put(set_deck_labels))
+                        .route("/{deck_id}/export", get(export_deck_json))
+                        .route("/{deck_id}/export/{format}", get(export_deck))
+                        .route("/{deck_id}/missing", get(deck_missing_cards))
                         .route(
                             "/{deck_id}/suppressions",
                             delete(clear_deck_suppressions).post(skip_deck_card),
@@ -466,13 +588,22 @@ pub fn private_routes(jwt_secret: JwtSecret) -> Router<AppState> {
                             "/{deck_id}/suppressions/{oracle_id}",
                             delete(unskip_deck_card),
                         )
+                        .route("/{deck_id}/legality", get(legality_report))
+                        .route("/{deck_id}/legality/{format}", get(validate_deck))
                         .route("/{deck_id}/tokens", get(get_deck_tokens))
+                        .route("/{deck_id}/proxies", get(proxy_sheet))
                         .nest(
                             "/{deck_id}/card",
                             Router::new()
-                                .route("/", post(create_deck_card))
-                                .route("/import", post(import_deck_cards))
                                 .route("/search", post(search_deck_cards))
+                                .route_layer(Extension(ReadOnlyRoute))
+                                .route("/", post(create_deck_card))
+                                .route(
+                                    "/import",
+                                    post(import_deck_cards).layer(body_limits.import_layer()),
+                                )
+                                .route("/move", post(move_deck_cards))
+                                .route("/bulk", post(bulk_add_deck_cards))
                                 .route(
                                     "/{scryfall_data_id}",
                                     put(update_deck_card).delete(delete_deck_card),
@@ -0,0 +1,130 @@
+//! Security response headers applied to every response.
+//!
+//! `X-Content-Type-Options: nosniff` is always sent. Framing, referrer and
+//! content-security policies come from [`SecurityHeaders`], whose defaults
+//! suit a JSON API: nothing may frame it, nothing it serves may load
+//! subresources, and referrers stop at the origin cross-site. An empty
+//! configured value drops that header.
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderValue, header},
+    middleware::Next,
+    response::Response,
+};
+
+/// Default `Content-Security-Policy`: the API serves JSON and set-symbol
+/// SVGs, neither of which needs to load anything or be framed.
+pub const CONTENT_SECURITY_POLICY_DEFAULT: &str = "default-src 'none'; frame-ancestors 'none'";
+
+/// Default `X-Frame-Options` (legacy twin of `frame-ancestors`).
+pub const X_FRAME_OPTIONS_DEFAULT: &str = "DENY";
+
+/// Default `Referrer-Policy`.
+pub const REFERRER_POLICY_DEFAULT: &str = "strict-origin-when-cross-origin";
+
+/// Configurable security header values; `None` omits the header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecurityHeaders {
+    /// `Content-Security-Policy` value.
+    pub content_security_policy: Option<HeaderValue>,
+    /// `X-Frame-Options` value.
+    pub frame_options: Option<HeaderValue>,
+    /// `Referrer-Policy` value.
+    pub referrer_policy: Option<HeaderValue>,
+}
+
+impl Default for SecurityHeaders {
+    fn default() -> Self {
+        Self {
+            content_security_policy: Some(HeaderValue::from_static(
+                CONTENT_SECURITY_POLICY_DEFAULT,
+            )),
+            frame_options: Some(HeaderValue::from_static(X_FRAME_OPTIONS_DEFAULT)),
+            referrer_policy: Some(HeaderValue::from_static(REFERRER_POLICY_DEFAULT)),
+        }
+    }
+}
+
+/// Stamps the configured security headers on every response, replacing any
+/// a handler set.
+pub async fn security_headers(
+    State(config): State<SecurityHeaders>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+    headers.insert(
+        header::X_CONTENT_TYPE_OPTIONS,
+        HeaderValue::from_static("nosniff"),
+    );
+    for (name, value) in [
+        (
+            header::CONTENT_SECURITY_POLICY,
+            config.content_security_policy,
+        ),
+        (header::X_FRAME_OPTIONS, config.frame_options),
+        (header::REFERRER_POLICY, config.referrer_policy),
+    ] {
+        if let Some(value) = value {
+            headers.insert(name, value);
+        }
+    }
+    response
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use axum::{Router, body::Body, routing::get};
+    use tower::ServiceExt;
+
+    async fn respond(config: SecurityHeaders) -> Response {
+        Router::new()
+            .route("/health", get(|| async { "ok" }))
+            .layer(axum::middleware::from_fn_with_state(
+                config,
+                security_headers,
+            ))
+            .oneshot(
+                Request::builder()
+                    .uri("/health")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn default_headers_are_set() {
+        let response = respond(SecurityHeaders::default()).await;
+        let headers = response.headers();
+        assert_eq!(headers[header::X_CONTENT_TYPE_OPTIONS], "nosniff");
+        assert_eq!(headers[header::X_FRAME_OPTIONS], "DENY");
+        assert_eq!(
+            headers[header::REFERRER_POLICY],
+            "strict-origin-when-cross-origin"
+        );
+        assert_eq!(
+            headers[header::CONTENT_SECURITY_POLICY],
+            CONTENT_SECURITY_POLICY_DEFAULT
+        );
+    }
+
+    #[tokio::test]
+    async fn configured_values_override_and_none_omits() {
+        let response = respond(SecurityHeaders {
+            content_security_policy: None,
+            frame_options: Some(HeaderValue::from_static("SAMEORIGIN")),
+            ..SecurityHeaders::default()
+        })
+        .await;
+        let headers = response.headers();
+        assert_eq!(headers[header::X_CONTENT_TYPE_OPTIONS], "nosniff");
+        assert_eq!(headers[header::X_FRAME_OPTIONS], "SAMEORIGIN");
+        assert!(!headers.contains_key(header::CONTENT_SECURITY_POLICY));
+    }
+}
@@ -2,11 +2,14 @@
 
 use crate::{
     domain::auth::requests::{
-        authenticate_user::AuthenticateUserError, change_email::ChangeEmailError,
-        change_username::ChangeUsernameError, create_session::CreateSessionError,
+        authenticate_api_key::AuthenticateApiKeyError, authenticate_user::AuthenticateUserError,
+        change_email::ChangeEmailError, change_username::ChangeUsernameError,
+        create_api_key::CreateApiKeyError, create_session::CreateSessionError,
         delete_expired_sessions::DeleteExpiredSessionsError, delete_user::DeleteUserError,
-        enforce_session_maximum::EnforceSessionMaximumError, refresh_session::RefreshSessionError,
-        register_user::RegisterUserError, revoke_sessions::RevokeSessionsError,
+        enforce_session_maximum::EnforceSessionMaximumError, list_api_keys::ListApiKeysError,
+        list_sessions::ListSessionsError, refresh_session::RefreshSessionError,
+        register_user::RegisterUserError, revoke_api_key::RevokeApiKeyError,
+        revoke_session::RevokeSessionError, revoke_sessions::RevokeSessionsError,
     },
     outbound::sqlx::{postgres::IsConstraintViolation, user::error::IntoUserError},
 };
@@ -128,3 +131,42 @@ impl From<sqlx::Error> for RefreshSessionError {
         Self::Database(value.into())
     }
 }
+
+impl From<sqlx::Error> for CreateApiKeyError {
+    fn from(value: sqlx::Error) -> Self {
+        Self::Database(value.into())
+    }
+}
+
+impl From<sqlx::Error> for ListApiKeysError {
+    fn from(value: sqlx::Error) -> Self {
+        Self::Database(value.into())
+    }
+}
+
+impl From<sqlx::Error> for RevokeApiKeyError {
+    fn from(value: sqlx::Error) -> Self {
+        Self::Database(value.into())
+    }
+}
+
+impl From<sqlx::Error> for ListSessionsError {
+    fn from(value: sqlx::Error) -> Self {
+        Self::Database(value.into())
+    }
+}
+
+impl From<sqlx::Error> for RevokeSessionError {
+    fn from(value: sqlx::Error) -> Self {
+        Self::Database(value.into())
+    }
+}
+
+impl From<sqlx::Error> for AuthenticateApiKeyError {
+    fn from(value: sqlx::Error) -> Self {
+        match value {
+            sqlx::Error::RowNotFound => Self::Invalid,
+            e => Self::Database(e.into()),
+        }
+    }
+}
@@ -6,17 +6,19 @@
 use std::future::Future;
 
 use crate::{
-    domain::auth::requests::{
-        create_session::CreateSessionError, enforce_session_maximum::EnforceSessionMaximumError,
+    domain::auth::{
+        models::session_policy::SessionPolicy,
+        requests::{
+            create_session::CreateSessionError, enforce_session_maximum::EnforceSessionMaximumError,
+        },
     },
     outbound::sqlx::auth::models::DatabaseRefreshToken,
 };
-use sqlx::{PgTransaction, query, query_as};
+use sqlx::{PgTransaction, query};
 use uuid::Uuid;
 use zwipe_core::domain::auth::models::{
     platform::ClientPlatform,
     refresh_token::{RefreshToken, Sha256Hash},
-    session::MAXIMUM_SESSION_COUNT,
 };
 
 /// Extension trait for refresh token operations within a PostgreSQL transaction.
@@ -26,22 +28,29 @@ use zwipe_core::domain::auth::models::{
 pub trait TxHelper {
     /// Creates a new refresh token for the specified user.
     ///
-    /// Generates a cryptographically secure token, stores its hash in the database,
-    /// and enforces the maximum session count by removing oldest tokens if needed.
+    /// Generates a cryptographically secure token that lives for the policy's
+    /// refresh token TTL, stores its hash in the database, and enforces the
+    /// policy's session limit by removing the oldest sessions if needed.
+    /// A rotation passes the token it replaces as `continues`, so the new token
+    /// keeps its session id and start time; otherwise a new session begins.
     fn create_refresh_token(
         &mut self,
         user_id: Uuid,
         platform: Option<ClientPlatform>,
         client_version: Option<String>,
+        device_label: Option<String>,
+        continues: Option<&DatabaseRefreshToken>,
+        policy: &SessionPolicy,
     ) -> impl Future<Output = Result<RefreshToken, CreateSessionError>> + Send;
 
     /// Enforces the maximum number of concurrent sessions per user.
     ///
-    /// Deletes the oldest refresh tokens when the count exceeds [`MAXIMUM_SESSION_COUNT`],
-    /// keeping only the most recently created tokens.
+    /// Deletes the oldest sessions when the live token count exceeds
+    /// `max_sessions`, keeping only the most recently active ones.
     fn enforce_refresh_token_max(
         &mut self,
         user_id: Uuid,
+        max_sessions: u8,
     ) -> impl Future<Output = Result<(), EnforceSessionMaximumError>> + Send;
 }
 
@@ -51,41 +60,51 @@ impl<'a> TxHelper for PgTransaction<'a> {
         user_id: Uuid,
         platform: Option<ClientPlatform>,
         client_version: Option<String>,
+        device_label: Option<String>,
+        continues: Option<&DatabaseRefreshToken>,
+        policy: &SessionPolicy,
     ) -> Result<RefreshToken, CreateSessionError> {
-        let refresh_token = RefreshToken::generate();
+        let refresh_token = RefreshToken::generate_with_lifespan(policy.refresh_token_ttl());
         let platform = platform.map(|p| p.to_string());
-        let _result = query_as!(
-            DatabaseRefreshToken,
-            "INSERT INTO refresh_tokens (user_id, value_hash, expires_at, platform, client_version) VALUES ($1, $2, $3, $4, $5) RETURNING id, user_id, expires_at, revoked, platform, client_version",
+        query!(
+            "INSERT INTO refresh_tokens (user_id, value_hash, expires_at, platform, client_version, device_label, session_id, session_started_at) \
+             VALUES ($1, $2, $3, $4, $5, $6, COALESCE($7, gen_random_uuid()), COALESCE($8, NOW()))",
             user_id,
             refresh_token.sha256_hash(),
             refresh_token.expires_at,
             platform,
-            client_version
+            client_version,
+            device_label,
+            continues.map(|t| t.session_id),
+            continues.map(|t| t.session_started_at)
         )
-        .fetch_one(&mut **self)
+        .execute(&mut **self)
         .await?;
-        self.enforce_refresh_token_max(user_id).await?;
+        self.enforce_refresh_token_max(user_id, policy.max_sessions())
+            .await?;
         Ok(refresh_token)
     }
 
     async fn enforce_refresh_token_max(
         &mut self,
         user_id: Uuid,
+        max_sessions: u8,
     ) -> Result<(), EnforceSessionMaximumError> {
+        // Counts live tokens only; an evicted session goes with its rotated
+        // tokens, so their reuse can't revoke the sessions that remain.
         query!(
-            r#"DELETE FROM refresh_tokens WHERE id IN (
-                        SELECT id FROM (
+            r#"DELETE FROM refresh_tokens WHERE user_id = $1 AND session_id IN (
+                        SELECT session_id FROM (
                             SELECT
-                                id,
+                                session_id,
                                 ROW_NUMBER() OVER(PARTITION BY user_id ORDER BY created_at DESC) token_number
                             FROM refresh_tokens
-                            WHERE user_id = $1
+                            WHERE user_id = $1 AND rotated_at IS NULL
                         ) users_refresh_tokens
                         WHERE token_number > $2
                 )"#,
             user_id,
-            MAXIMUM_SESSION_COUNT as i64
+            i64::from(max_sessions)
         )
         .execute(&mut **self)
         .await?;
@@ -9,19 +9,29 @@ pub mod models;
 
 use crate::{
     domain::auth::{
-        models::{UserWithPasswordHash, password::HashedPassword},
+        models::{
+            UserWithPasswordHash,
+            password::HashedPassword,
+            session_policy::{ROTATION_GRACE, SessionPolicy},
+        },
         ports::AuthRepository,
         requests::{
+            authenticate_api_key::AuthenticateApiKeyError,
             authenticate_user::{AuthenticateUser, AuthenticateUserError},
             change_email::{ChangeEmail, ChangeEmailError},
             change_password::{ChangePassword, ChangePasswordError},
             change_username::{ChangeUsername, ChangeUsernameError},
+            create_api_key::{CreateApiKey, CreateApiKeyError},
             create_session::CreateSessionError,
             delete_expired_sessions::DeleteExpiredSessionsError,
             delete_user::{DeleteUser, DeleteUserError},
+            list_api_keys::ListApiKeysError,
+            list_sessions::ListSessionsError,
             refresh_session::{RefreshSession, RefreshSessionError},
             register_user::{RegisterUser, RegisterUserError},
             reset_password::ResetPasswordError,
+            revoke_api_key::{RevokeApiKey, RevokeApiKeyError},
+            revoke_session::{RevokeSession, RevokeSessionError},
             revoke_sessions::RevokeSessionsError,
             verify_email::VerifyEmailError,
         },
@@ -29,7 +39,10 @@ use crate::{
     outbound::sqlx::{
         auth::{
             helpers::TxHelper,
-            models::{DatabaseRefreshToken, DatabaseUserWithPasswordHash},
+            models::{
+                DatabaseApiKey, DatabaseApiKeyOwner, DatabaseRefreshToken,
+                DatabaseUserWithPasswordHash,
+            },
         },
         postgres::Postgres,
         user::models::DatabaseUser,
@@ -40,8 +53,10 @@ use sqlx::{query, query_as, query_scalar};
 use uuid::Uuid;
 use zwipe_core::domain::{
     auth::models::{
+        api_key::{ApiKey, ApiKeyScope},
         platform::ClientPlatform,
         refresh_token::{RefreshToken, Sha256Hash},
+        session::SessionInfo,
     },
     user::User,
 };
@@ -53,6 +68,7 @@ impl AuthRepository for Postgres {
     async fn create_user_and_refresh_token(
         &self,
         request: &RegisterUser,
+        policy: &SessionPolicy,
     ) -> Result<(User, RefreshToken), RegisterUserError> {
         let mut tx = self.pool.begin().await?;
 
@@ -66,7 +82,14 @@ impl AuthRepository for Postgres {
 
         let user: User = database_user.try_into()?;
         let refresh_token = tx
-            .create_refresh_token(user.id, request.platform, request.client_version.clone())
+            .create_refresh_token(
+                user.id,
+                request.platform,
+                request.client_version.clone(),
+                request.device_label.clone(),
+                None,
+                policy,
+            )
             .await?;
         tx.commit().await?;
 
@@ -78,10 +101,19 @@ impl AuthRepository for Postgres {
         user_id: Uuid,
         platform: Option<ClientPlatform>,
         client_version: Option<String>,
+        device_label: Option<String>,
+        policy: &SessionPolicy,
     ) -> Result<RefreshToken, CreateSessionError> {
         let mut tx = self.pool.begin().await?;
         let refresh_token = tx
-            .create_refresh_token(user_id, platform, client_version)
+            .create_refresh_token(
+                user_id,
+                platform,
+                client_version,
+                device_label,
+                None,
+                policy,
+            )
             .await?;
         tx.commit().await?;
 
@@ -91,13 +123,14 @@ impl AuthRepository for Postgres {
     //  get
     // =====
     /// Looks up a user by ID, username, **or** email using a single `OR` query.
+    /// The username match ignores case, as username uniqueness does.
     async fn get_user_with_password_hash(
         &self,
         request: &AuthenticateUser,
     ) -> Result<UserWithPasswordHash, AuthenticateUserError> {
         let database_user: DatabaseUserWithPasswordHash = query_as!(
             DatabaseUserWithPasswordHash,
-            "SELECT id, username, email, password_hash, lockout_until, email_verified_at, hints_shown FROM users WHERE (id::text = $1 OR username = $1 OR email = $1)",
+            "SELECT id, username, email, password_hash, lockout_until, email_verified_at, hints_shown FROM users WHERE (id::text = $1 OR LOWER(username) = LOWER($1) OR email = $1)",
             request.identifier
         )
         .fetch_one(&self.pool)
@@ -159,6 +192,25 @@ impl AuthRepository for Postgres {
         Ok(())
     }
 
+    async fn upgrade_password_hash(
+        &self,
+        user_id: Uuid,
+        old: &HashedPassword,
+        new: &HashedPassword,
+    ) -> Result<(), AuthenticateUserError> {
+        query!(
+            "UPDATE users SET password_hash = $1 WHERE id = $2 AND password_hash = $3",
+            new.to_string(),
+            user_id,
+            old.to_string()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| AuthenticateUserError::Database(e.into()))?;
+
+        Ok(())
+    }
+
     // ========
     //  update
     // ========
@@ -235,15 +287,18 @@ impl AuthRepository for Postgres {
     async fn use_refresh_token(
         &self,
         request: &RefreshSession,
+        policy: &SessionPolicy,
     ) -> Result<RefreshToken, RefreshSessionError> {
         let mut tx = self.pool.begin().await?;
 
         // FOR UPDATE serializes concurrent refreshes on the row: the winner
-        // deletes it; losers block, re-read after commit, hit RowNotFound,
-        // and get 401. Makes the token strictly single-use under concurrency.
+        // retires it; losers block, re-read it after commit as just rotated,
+        // and are refused without revoking anything. Makes the token strictly
+        // single-use under concurrency.
         let existing = query_as!(
             DatabaseRefreshToken,
-            "SELECT id, user_id, expires_at, revoked, platform, client_version FROM refresh_tokens WHERE value_hash = $1 FOR UPDATE",
+            "SELECT id, user_id, created_at, expires_at, revoked, platform, client_version, device_label, session_id, session_started_at, rotated_at \
+             FROM refresh_tokens WHERE value_hash = $1 FOR UPDATE",
             request.refresh_token.sha256_hash()
         )
         .fetch_one(&mut *tx)
@@ -257,6 +312,30 @@ impl AuthRepository for Postgres {
             return Err(RefreshSessionError::Forbidden(request.user_id));
         }
 
+        // A rotated token should never come back: whoever holds it copied it
+        // from the legitimate client (or vice versa). Revoke the whole family
+        // — every session of the user — and commit that before refusing.
+        // Right after a rotation it's more likely the same client racing
+        // itself, so that only loses the race.
+        if let Some(rotated_at) = existing.rotated_at {
+            if Utc::now() - rotated_at < ROTATION_GRACE {
+                return Err(RefreshSessionError::Superseded(request.user_id));
+            }
+            query!(
+                "DELETE FROM refresh_tokens WHERE user_id = $1",
+                existing.user_id
+            )
+            .execute(&mut *tx)
+            .await?;
+            tx.commit().await?;
+            tracing::warn!(
+                event = "refresh_token_reuse",
+                user_id = %existing.user_id,
+                session_id = %existing.session_id
+            );
+            return Err(RefreshSessionError::Reused(request.user_id));
+        }
+
         if existing.expires_at < Utc::now() {
             return Err(RefreshSessionError::Expired(request.user_id));
         }
@@ -265,13 +344,17 @@ impl AuthRepository for Postgres {
             return Err(RefreshSessionError::Revoked(request.user_id));
         }
 
-        let deleted = query!("DELETE FROM refresh_tokens WHERE id = $1", existing.id)
-            .execute(&mut *tx)
-            .await?;
+        // Retire rather than delete, so a second presentation is caught above.
+        let retired = query!(
+            "UPDATE refresh_tokens SET rotated_at = NOW() WHERE id = $1 AND rotated_at IS NULL",
+            existing.id
+        )
+        .execute(&mut *tx)
+        .await?;
 
-        // belt-and-suspenders: zero rows deleted means another transaction
+        // belt-and-suspenders: zero rows updated means another transaction
         // already consumed this token — refuse to mint a replacement
-        if deleted.rows_affected() != 1 {
+        if retired.rows_affected() != 1 {
             return Err(RefreshSessionError::Revoked(request.user_id));
         }
 
@@ -283,9 +366,25 @@ impl AuthRepository for Postgres {
             .and_then(|p| p.parse::<ClientPlatform>().ok());
         // Version can change on app update, so prefer what the client just sent;
         // fall back to the old row's value for clients that don't send it yet.
-        let client_version = request.client_version.clone().or(existing.client_version);
+        let client_version = request
+            .client_version
+            .clone()
+            .or_else(|| existing.client_version.clone());
+        // Likewise a label from this request (the device may have been
+        // renamed) wins over the one the session started with.
+        let device_label = request
+            .device_label
+            .clone()
+            .or_else(|| existing.device_label.clone());
         let new = tx
-            .create_refresh_token(request.user_id, carried, client_version)
+            .create_refresh_token(
+                request.user_id,
+                carried,
+                client_version,
+                device_label,
+                Some(&existing),
+                policy,
+            )
             .await?;
 
         tx.commit().await?;
@@ -323,6 +422,21 @@ impl AuthRepository for Postgres {
         Ok(())
     }
 
+    async fn delete_session(&self, request: &RevokeSession) -> Result<(), RevokeSessionError> {
+        let result = query!(
+            "DELETE FROM refresh_tokens WHERE user_id = $1 AND session_id = $2",
+            request.user_id,
+            request.session_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RevokeSessionError::NotFound);
+        }
+        Ok(())
+    }
+
     async fn delete_expired_refresh_tokens(&self) -> Result<(), DeleteExpiredSessionsError> {
         let mut tx = self.pool.begin().await?;
 
@@ -496,4 +610,95 @@ impl AuthRepository for Postgres {
 
         Ok(())
     }
+
+    // ==========
+    //  api keys
+    // ==========
+
+    async fn create_api_key(
+        &self,
+        request: &CreateApiKey,
+        key_hash: &str,
+    ) -> Result<ApiKey, CreateApiKeyError> {
+        let database_api_key = query_as!(
+            DatabaseApiKey,
+            "INSERT INTO api_keys (user_id, label, key_hash, scope) VALUES ($1, $2, $3, $4) RETURNING id, label, scope, created_at, last_used_at",
+            request.user_id,
+            &request.label,
+            key_hash,
+            request.scope.as_str()
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        ApiKey::try_from(database_api_key).map_err(|e| CreateApiKeyError::ApiKeyFromDb(e.into()))
+    }
+
+    async fn list_api_keys(&self, user_id: Uuid) -> Result<Vec<ApiKey>, ListApiKeysError> {
+        let database_api_keys = query_as!(
+            DatabaseApiKey,
+            "SELECT id, label, scope, created_at, last_used_at FROM api_keys WHERE user_id = $1 AND revoked_at IS NULL ORDER BY created_at DESC",
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        database_api_keys
+            .into_iter()
+            .map(|k| ApiKey::try_from(k).map_err(|e| ListApiKeysError::ApiKeyFromDb(e.into())))
+            .collect()
+    }
+
+    async fn list_sessions(&self, user_id: Uuid) -> Result<Vec<SessionInfo>, ListSessionsError> {
+        let tokens = query_as!(
+            DatabaseRefreshToken,
+            "SELECT id, user_id, created_at, expires_at, revoked, platform, client_version, device_label, session_id, session_started_at, rotated_at \
+             FROM refresh_tokens \
+             WHERE user_id = $1 AND NOT revoked AND rotated_at IS NULL AND expires_at > NOW() \
+             ORDER BY created_at DESC",
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tokens.into_iter().map(SessionInfo::from).collect())
+    }
+
+    async fn revoke_api_key(&self, request: &RevokeApiKey) -> Result<(), RevokeApiKeyError> {
+        let result = query!(
+            "UPDATE api_keys SET revoked_at = NOW() WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL",
+            request.key_id,
+            request.user_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(RevokeApiKeyError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn use_api_key(
+        &self,
+        key_hash: &str,
+    ) -> Result<(User, ApiKeyScope), AuthenticateApiKeyError> {
+        let owner = query_as!(
+            DatabaseApiKeyOwner,
+            "UPDATE api_keys k SET last_used_at = NOW() \
+             FROM users u \
+             WHERE k.key_hash = $1 AND k.revoked_at IS NULL AND u.id = k.user_id \
+             RETURNING u.id, u.username, u.email, u.email_verified_at, u.hints_shown, k.scope",
+            key_hash
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        let (user, scope) = owner.into_parts();
+        let scope = scope
+            .parse::<ApiKeyScope>()
+            .map_err(|e| AuthenticateApiKeyError::FromDb(e.into()))?;
+        let user = User::try_from(user).map_err(|e| AuthenticateApiKeyError::FromDb(e.into()))?;
+        Ok((user, scope))
+    }
 }
@@ -1,11 +1,17 @@
 use crate::{
     domain::auth::models::{UserWithPasswordHash, password::HashedPassword},
-    outbound::sqlx::auth::error::IntoUserWithPasswordHashError,
+    outbound::sqlx::{auth::error::IntoUserWithPasswordHashError, user::models::DatabaseUser},
 };
 use chrono::{DateTime, Utc};
 use sqlx_macros::FromRow;
 use uuid::Uuid;
-use zwipe_core::domain::user::username::Username;
+use zwipe_core::domain::{
+    auth::models::{
+        api_key::{ApiKey, ApiKeyScope, ParseApiKeyScopeError},
+        session::{SessionInfo, UNKNOWN_DEVICE},
+    },
+    user::username::Username,
+};
 
 /// raw database user with password hash record
 /// (unvalidated data from `PostgreSQL`)
@@ -49,10 +55,89 @@ impl TryFrom<DatabaseUserWithPasswordHash> for UserWithPasswordHash {
 pub struct DatabaseRefreshToken {
     pub id: i32,
     pub user_id: Uuid,
+    pub created_at: DateTime<Utc>,
     pub expires_at: DateTime<Utc>,
     pub revoked: bool,
     /// Client platform string (`ios`/`android`/`desktop`/`web`), NULL if unknown.
     pub platform: Option<String>,
     /// Client app version string (e.g. `"1.6.1"`), NULL if unknown.
     pub client_version: Option<String>,
+    /// Friendly device name (e.g. `"Chrome on macOS"`), NULL if unknown.
+    pub device_label: Option<String>,
+    /// Stable session id, carried across rotations.
+    pub session_id: Uuid,
+    /// When the session's first token was issued.
+    pub session_started_at: DateTime<Utc>,
+    /// When the token was rotated away; NULL while it's the session's live token.
+    pub rotated_at: Option<DateTime<Utc>>,
+}
+
+/// converts database refresh token to the session metadata shown to its owner
+impl From<DatabaseRefreshToken> for SessionInfo {
+    fn from(value: DatabaseRefreshToken) -> Self {
+        Self {
+            id: value.session_id,
+            created_at: value.session_started_at,
+            last_refreshed_at: value.created_at,
+            expires_at: value.expires_at,
+            // an invalid stored value degrades to None
+            platform: value.platform.as_deref().and_then(|p| p.parse().ok()),
+            client_version: value.client_version,
+            device_label: value
+                .device_label
+                .unwrap_or_else(|| UNKNOWN_DEVICE.to_string()),
+        }
+    }
+}
+
+/// raw database api key record (never includes the hash)
+#[allow(missing_docs)]
+#[derive(Debug, Clone, FromRow)]
+pub struct DatabaseApiKey {
+    pub id: Uuid,
+    pub label: String,
+    pub scope: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// converts database api key to domain api key
+impl TryFrom<DatabaseApiKey> for ApiKey {
+    type Error = ParseApiKeyScopeError;
+
+    fn try_from(value: DatabaseApiKey) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            label: value.label,
+            scope: value.scope.parse::<ApiKeyScope>()?,
+            created_at: value.created_at,
+            last_used_at: value.last_used_at,
+        })
+    }
+}
+
+/// raw owner of an authenticated api key: the user row plus the key's scope
+#[allow(missing_docs)]
+#[derive(Debug, Clone, FromRow)]
+pub struct DatabaseApiKeyOwner {
+    pub id: Uuid,
+    pub username: String,
+    pub email: String,
+    pub email_verified_at: Option<DateTime<Utc>>,
+    pub hints_shown: serde_json::Value,
+    pub scope: String,
+}
+
+impl DatabaseApiKeyOwner {
+    /// splits into the owner's user row and the key's scope
+    pub fn into_parts(self) -> (DatabaseUser, String) {
+        let user = DatabaseUser {
+            id: self.id,
+            username: self.username,
+            email: self.email,
+            email_verified_at: self.email_verified_at,
+            hints_shown: self.hints_shown,
+        };
+        (user, self.scope)
+    }
 }
@@ -4,7 +4,10 @@ use chrono::{DateTime, Utc};
 use sqlx_macros::FromRow;
 use std::collections::BTreeMap;
 use uuid::Uuid;
-use zwipe_core::domain::card::{card_profile::CardProfile, card_role::CardRole};
+use zwipe_core::domain::card::{
+    card_profile::{CardCopyLimit, CardProfile},
+    card_role::CardRole,
+};
 
 /// Raw database card profile record (unvalidated data from PostgreSQL).
 #[derive(Debug, Clone, FromRow)]
@@ -16,6 +19,7 @@ pub struct DatabaseCardProfile {
     pub oracle_tags: Option<serde_json::Value>,
     pub oracle_tags_by_role: Option<serde_json::Value>,
     pub other_oracle_tags: Option<serde_json::Value>,
+    pub copy_limit: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -57,6 +61,9 @@ impl From<DatabaseCardProfile> for CardProfile {
             oracle_tags,
             oracle_tags_by_role,
             other_oracle_tags,
+            copy_limit: value
+                .copy_limit
+                .and_then(|s| CardCopyLimit::try_from(s.as_str()).ok()),
             created_at: value.created_at,
             updated_at: value.updated_at,
         }
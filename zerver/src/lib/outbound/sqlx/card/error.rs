@@ -1,12 +1,17 @@
 use crate::{
     domain::card::requests::{
+        considering::ConsideringError,
         create_card::CreateCardError,
+        favorites::FavoritesError,
         get_artists::GetArtistsError,
         get_card_profile::GetCardProfileError,
         get_card_types::GetCardTypesError,
         get_languages::GetLanguagesError,
         get_scryfall_data::{GetScryfallDataError, SearchScryfallDataError},
+        get_set_completion::GetSetCompletionError,
+        get_set_icon::GetSetIconError,
         get_sets::GetSetsError,
+        import_collection::ImportCollectionError,
     },
     outbound::sqlx::postgres::IsConstraintViolation,
 };
@@ -56,6 +61,36 @@ impl From<sqlx::Error> for GetSetsError {
     }
 }
 
+impl From<sqlx::Error> for ConsideringError {
+    fn from(value: sqlx::Error) -> Self {
+        Self::Database(value.into())
+    }
+}
+
+impl From<sqlx::Error> for FavoritesError {
+    fn from(value: sqlx::Error) -> Self {
+        Self::Database(value.into())
+    }
+}
+
+impl From<sqlx::Error> for GetSetIconError {
+    fn from(value: sqlx::Error) -> Self {
+        Self::Database(value.into())
+    }
+}
+
+impl From<sqlx::Error> for GetSetCompletionError {
+    fn from(value: sqlx::Error) -> Self {
+        Self::Database(value.into())
+    }
+}
+
+impl From<sqlx::Error> for ImportCollectionError {
+    fn from(value: sqlx::Error) -> Self {
+        Self::Database(value.into())
+    }
+}
+
 impl From<sqlx::Error> for GetLanguagesError {
     fn from(value: sqlx::Error) -> Self {
         Self::Database(value.into())
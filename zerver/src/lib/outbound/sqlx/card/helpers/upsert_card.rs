@@ -4,7 +4,8 @@
 //! - **Single**: One card at a time with full error reporting
 //! - **Bulk**: Multiple cards in a single query for maximum throughput
 //! - **Batch**: Chunked processing with automatic fallback on failure
-//! - **Delta**: Only upserts cards that have actually changed
+//! - **Delta**: Rewrites cards whose gameplay changed, refreshes only prices
+//!   otherwise, and skips identical cards
 //!
 //! All operations work within existing transactions (caller commits).
 
@@ -24,9 +25,13 @@ use crate::{
         models::DatabaseScryfallData,
     },
 };
-use sqlx::{PgTransaction, QueryBuilder, query_as};
-use std::future::Future;
-use zwipe_core::domain::card::{Card, card_profile::CardProfile, scryfall_data::ScryfallData};
+use sqlx::{PgTransaction, QueryBuilder, query_as, types::Json};
+use std::{collections::HashMap, future::Future};
+use zwipe_core::domain::card::{
+    Card,
+    card_profile::{CardCopyLimit, CardProfile},
+    scryfall_data::ScryfallData,
+};
 
 /// Postgres error substring used to filter noise from card-by-card fallback retries.
 ///
@@ -43,6 +48,42 @@ fn is_token(scryfall_data: &ScryfallData) -> bool {
     scryfall_data.layout == "token"
 }
 
+/// The card's own copy-limit exception in its stored form (`any` or a count).
+fn copy_limit(scryfall_data: &ScryfallData) -> Option<String> {
+    CardCopyLimit::of(scryfall_data).map(|limit| limit.to_string())
+}
+
+/// How an incoming card differs from its stored row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CardDelta {
+    /// New card, or its oracle text, type line, mana cost or legalities
+    /// changed — rewrite the whole row.
+    Full,
+    /// Gameplay fields match but prices moved — refresh prices only.
+    Prices,
+    /// Gameplay fields and prices match — nothing to write.
+    Unchanged,
+}
+
+/// Classifies `incoming` against its stored row. Only gameplay fields trigger a
+/// full rewrite; other drift (images, ranks, …) waits for the next full sync.
+fn card_delta(incoming: &ScryfallData, existing: Option<&ScryfallData>) -> CardDelta {
+    let Some(existing) = existing else {
+        return CardDelta::Full;
+    };
+    if incoming.oracle_text != existing.oracle_text
+        || incoming.type_line != existing.type_line
+        || incoming.mana_cost != existing.mana_cost
+        || incoming.legalities != existing.legalities
+    {
+        CardDelta::Full
+    } else if incoming.prices != existing.prices {
+        CardDelta::Prices
+    } else {
+        CardDelta::Unchanged
+    }
+}
+
 // ===========
 //  insertion
 // ===========
@@ -62,7 +103,8 @@ where
 {
     /// Inserts or updates this card within the given transaction.
     ///
-    /// Also creates/updates the card profile with computed `is_token` flag.
+    /// Also creates/updates the card profile with computed `is_token` flag
+    /// and copy-limit exception.
     fn single_upsert_with_tx(
         &self,
         tx: &mut PgTransaction<'_>,
@@ -87,15 +129,17 @@ impl SingleUpsertWithTx for ScryfallData {
         let scryfall_data: ScryfallData =
             db.try_into().map_err(CreateCardError::ScryfallDataFromDb)?;
         let is_token = is_token(&scryfall_data);
+        let copy_limit = copy_limit(&scryfall_data);
         let database_card_profile = query_as!(
             DatabaseCardProfile,
-            "INSERT INTO card_profiles (scryfall_data_id, is_token)
-             VALUES ($1, $2)
+            "INSERT INTO card_profiles (scryfall_data_id, is_token, copy_limit)
+             VALUES ($1, $2, $3)
              ON CONFLICT (scryfall_data_id)
-             DO UPDATE SET updated_at = NOW(), is_token = EXCLUDED.is_token
-             RETURNING scryfall_data_id, is_token, card_roles, oracle_tags, oracle_tags_by_role, other_oracle_tags, created_at, updated_at",
+             DO UPDATE SET updated_at = NOW(), is_token = EXCLUDED.is_token, copy_limit = EXCLUDED.copy_limit
+             RETURNING scryfall_data_id, is_token, card_roles, oracle_tags, oracle_tags_by_role, other_oracle_tags, copy_limit, created_at, updated_at",
             scryfall_data_id,
-            is_token
+            is_token,
+            copy_limit
         )
         .fetch_one(&mut **tx)
         .await?;
@@ -147,8 +191,9 @@ impl BulkUpsertWithTx for &[ScryfallData] {
             .map(ScryfallData::try_from)
             .collect::<Result<_, _>>()
             .map_err(CreateCardError::ScryfallDataFromDb)?;
-        let mut card_profile_query_builder =
-            QueryBuilder::new("INSERT INTO card_profiles (scryfall_data_id, is_token) VALUES");
+        let mut card_profile_query_builder = QueryBuilder::new(
+            "INSERT INTO card_profiles (scryfall_data_id, is_token, copy_limit) VALUES",
+        );
         for (i, scryfall_data) in database_scryfall_data.iter().enumerate() {
             if i > 0 {
                 card_profile_query_builder.push(",");
@@ -159,12 +204,14 @@ impl BulkUpsertWithTx for &[ScryfallData] {
                 .push_bind(scryfall_data.id)
                 .push(",")
                 .push_bind(is_token)
+                .push(",")
+                .push_bind(copy_limit(scryfall_data))
                 .push(")");
         }
         card_profile_query_builder
-            .push(" ON CONFLICT (scryfall_data_id) DO UPDATE SET updated_at = NOW(), is_token = EXCLUDED.is_token ");
+            .push(" ON CONFLICT (scryfall_data_id) DO UPDATE SET updated_at = NOW(), is_token = EXCLUDED.is_token, copy_limit = EXCLUDED.copy_limit ");
         card_profile_query_builder.push(
-            " RETURNING scryfall_data_id, is_token, card_roles, oracle_tags, created_at, updated_at;",
+            " RETURNING scryfall_data_id, is_token, card_roles, oracle_tags, oracle_tags_by_role, other_oracle_tags, copy_limit, created_at, updated_at;",
         );
         let card_profiles: Vec<CardProfile> = card_profile_query_builder
             .build_query_as::<DatabaseCardProfile>()
@@ -250,57 +297,80 @@ impl BatchUpsertWithTx for &[ScryfallData] {
     }
 }
 
-/// Delta-aware bulk upsert that skips unchanged cards.
+/// Outcome of one [`BulkDeltaUpsertWithTx`] chunk.
+#[derive(Debug, Default)]
+pub struct DeltaUpsert {
+    /// Cards rewritten in full (new, or gameplay fields changed).
+    pub upserted: Vec<Card>,
+    /// Cards whose prices alone were refreshed.
+    pub price_updated: usize,
+    /// Cards left untouched.
+    pub skipped: usize,
+}
+
+/// Delta-aware bulk upsert that writes only what changed.
 ///
-/// Fetches existing records by ID, compares with input, and only upserts the
-/// diff. Returns both the upserted cards and a count of skipped (unchanged) cards.
-/// Ideal for incremental sync operations.
+/// Fetches existing records by ID and compares each incoming card's gameplay
+/// fields (oracle text, type line, mana cost, legalities): changed or new cards
+/// are upserted in full, otherwise only moved prices are written and identical
+/// cards are skipped. Ideal for incremental sync operations.
 pub trait BulkDeltaUpsertWithTx
 where
     Self: Sized,
 {
-    /// Upserts only cards that differ from their existing database records.
-    ///
-    /// Returns a tuple of (upserted cards, skipped count).
+    /// Upserts changed cards and refreshes prices on the rest.
     fn bulk_delta_upsert_with_tx(
         self,
         tx: &mut PgTransaction<'_>,
-    ) -> impl Future<Output = Result<(Vec<Card>, usize), CreateCardError>> + Send;
+    ) -> impl Future<Output = Result<DeltaUpsert, CreateCardError>> + Send;
 }
 
 impl BulkDeltaUpsertWithTx for &[ScryfallData] {
     async fn bulk_delta_upsert_with_tx(
         self,
         tx: &mut PgTransaction<'_>,
-    ) -> Result<(Vec<Card>, usize), CreateCardError> {
+    ) -> Result<DeltaUpsert, CreateCardError> {
         let existing_db: Vec<DatabaseScryfallData> =
             query_as("SELECT * FROM scryfall_data WHERE id = ANY($1)")
                 .bind(&*ScryfallDataIds::from(self))
                 .fetch_all(&mut **tx)
                 .await
                 .map_err(|e| CreateCardError::GetScryfallData(e.into()))?;
-        let existing: Vec<ScryfallData> = existing_db
+        let existing: HashMap<_, ScryfallData> = existing_db
             .into_iter()
-            .map(ScryfallData::try_from)
+            .map(|db| ScryfallData::try_from(db).map(|sd| (sd.id, sd)))
             .collect::<Result<_, _>>()
             .map_err(CreateCardError::ScryfallDataFromDb)?;
-        let delta: Vec<ScryfallData> = self
-            .iter()
-            .filter(|x| !existing.contains(x))
-            .map(|x| x.to_owned())
-            .collect();
-        let skipped_count = self.len() - delta.len();
-        if delta.is_empty() {
-            return Ok((Vec::new(), skipped_count));
+        let mut full: Vec<ScryfallData> = Vec::new();
+        let mut prices: Vec<&ScryfallData> = Vec::new();
+        let mut outcome = DeltaUpsert::default();
+        for incoming in self {
+            match card_delta(incoming, existing.get(&incoming.id)) {
+                CardDelta::Full => full.push(incoming.to_owned()),
+                CardDelta::Prices => prices.push(incoming),
+                CardDelta::Unchanged => outcome.skipped += 1,
+            }
+        }
+        if !prices.is_empty() {
+            let mut qb =
+                QueryBuilder::new("UPDATE scryfall_data AS sd SET prices = v.prices FROM (");
+            qb.push_values(prices.iter(), |mut b, card| {
+                b.push_bind(card.id).push_bind(Json(card.prices.clone()));
+            });
+            qb.push(") AS v(id, prices) WHERE sd.id = v.id");
+            outcome.price_updated = qb.build().execute(&mut **tx).await?.rows_affected() as usize;
+        }
+        if !full.is_empty() {
+            outcome.upserted = full.as_slice().bulk_upsert_with_tx(tx).await?;
         }
-        let cards = delta.as_slice().bulk_upsert_with_tx(tx).await?;
-        Ok((cards, skipped_count))
+        Ok(outcome)
     }
 }
 
 /// Combines chunked batching with delta detection.
 ///
-/// Each chunk runs through [`BulkDeltaUpsertWithTx`] (skip unchanged, upsert diff).
+/// Each chunk runs through [`BulkDeltaUpsertWithTx`] (skip unchanged, refresh
+/// prices, upsert the gameplay diff).
 /// On chunk failure, falls back to card-by-card insertion. Best for large
 /// incremental syncs where most cards haven't changed.
 pub trait BatchDeltaUpsertWithTx
@@ -309,7 +379,7 @@ where
 {
     /// Batch-processes cards with delta detection and automatic fallback.
     ///
-    /// Updates `zervice_metrics` with upserted, skipped, and error counts.
+    /// Updates `zervice_metrics` with upserted, price-updated, skipped, and error counts.
     fn batch_delta_upsert_with_tx(
         self,
         tx: &mut PgTransaction<'_>,
@@ -328,8 +398,13 @@ impl BatchDeltaUpsertWithTx for &[ScryfallData] {
         let mut cards: Vec<Card> = Vec::new();
         for chunk in self.chunks(batch_size) {
             match chunk.bulk_delta_upsert_with_tx(tx).await {
-                Ok((upserted, skipped)) => {
+                Ok(DeltaUpsert {
+                    upserted,
+                    price_updated,
+                    skipped,
+                }) => {
                     zervice_metrics.add_upserted_count(upserted.len() as i32);
+                    zervice_metrics.add_price_updated_count(price_updated as i32);
                     zervice_metrics.add_skipped_count(skipped as i32);
                     cards.extend(upserted);
                 }
@@ -16,12 +16,19 @@ pub mod zervice_metrics;
 use crate::{
     domain::card::{
         models::{
-            helpers::SleeveCardProfile, search_card::error::SearchCardsError,
-            zervice_metrics::ZerviceMetrics,
+            helpers::SleeveCardProfile,
+            raw_card::RawCard,
+            search_card::error::SearchCardsError,
+            sync_checkpoint::SyncCheckpoint,
+            sync_progress::SyncProgressTracker,
+            sync_schedule::SyncKind,
+            zervice_metrics::{ErrorMetrics, ZerviceMetrics},
         },
         ports::{CardRepository, DeckServeContext},
         requests::{
+            considering::ConsideringError,
             create_card::CreateCardError,
+            favorites::FavoritesError,
             get_artists::GetArtistsError,
             get_card::GetCardError,
             get_card_profile::{CardProfileIds, GetCardProfile, GetCardProfileError},
@@ -33,7 +40,10 @@ use crate::{
             get_scryfall_data::{
                 GetScryfallData, GetScryfallDataError, ScryfallDataIds, SearchScryfallDataError,
             },
+            get_set_completion::GetSetCompletionError,
+            get_set_icon::GetSetIconError,
             get_sets::GetSetsError,
+            import_collection::ImportCollectionError,
         },
     },
     outbound::sqlx::{
@@ -42,7 +52,7 @@ use crate::{
             helpers::upsert_card::{
                 BatchDeltaUpsertWithTx, BatchUpsertWithTx, BulkUpsertWithTx, SingleUpsertWithTx,
             },
-            models::DatabaseScryfallData,
+            models::{DatabaseCardSummary, DatabaseScryfallData},
             oracle_tag::DatabaseOracleTag,
             zervice_metrics::DatabaseZerviceMetrics,
         },
@@ -53,18 +63,68 @@ use zwipe_core::domain::{
     card::{
         Card,
         card_profile::CardProfile,
+        card_summary::CardSummary,
+        color_distribution::ColorDistribution,
         oracle_tag::OracleTag,
-        scryfall_data::ScryfallData,
+        printing_summary::{CardWithPrintings, PrintingSummary},
+        scryfall_data::{
+            ScryfallData,
+            colors::{Color, Colors},
+        },
         search_card::card_filter::{
-            CardQuery, card_sort_key::CardSortKey, criteria::PLAYABLE_LAYOUTS,
+            CardCriteria, CardQuery, Cursor, builder::CardQueryBuilder, card_sort_key::CardSortKey,
+            criteria::PLAYABLE_LAYOUTS,
         },
+        set_completion::SetCompletion,
+        set_icon::SetIcon,
     },
     deck::{Format, requests::import_deck_cards::entry_front_face},
 };
 
+use std::collections::{HashMap, HashSet};
+use tokio::task::JoinSet;
+
 use anyhow::Context;
 use chrono::{DateTime, Utc};
-use sqlx::{Postgres, QueryBuilder, query_as, query_builder::Separated, query_scalar};
+use sqlx::{
+    FromRow, Postgres, QueryBuilder, postgres::PgRow, query, query_as, query_builder::Separated,
+    query_scalar,
+};
+
+/// Delta upserts one chunk in its own transaction on its own pooled
+/// connection. A chunk that fails to begin or commit rolls back whole, so its
+/// counts are dropped and every card in it is recorded as an error instead.
+async fn delta_upsert_chunk(
+    pool: sqlx::PgPool,
+    chunk: Vec<ScryfallData>,
+) -> (Vec<Card>, ZerviceMetrics) {
+    let mut zervice_metrics = ZerviceMetrics::new();
+    let result = async {
+        let mut tx = pool.begin().await?;
+        let cards = chunk
+            .as_slice()
+            .batch_delta_upsert_with_tx(&mut tx, chunk.len(), &mut zervice_metrics)
+            .await?;
+        tx.commit().await?;
+        Ok::<_, CreateCardError>(cards)
+    }
+    .await;
+    match result {
+        Ok(cards) => (cards, zervice_metrics),
+        Err(e) => {
+            tracing::error!("delta upsert chunk of {} cards failed: {e}", chunk.len());
+            let mut zervice_metrics = ZerviceMetrics::new();
+            for scryfall_data in &chunk {
+                zervice_metrics.add_error(ErrorMetrics::new(
+                    scryfall_data.id,
+                    &scryfall_data.name,
+                    e.to_string(),
+                ));
+            }
+            (Vec::new(), zervice_metrics)
+        }
+    }
+}
 
 /// Hard ceiling on rows returned by a single card search.
 ///
@@ -152,6 +212,36 @@ const POPULARITY_JOIN: &str = "LEFT JOIN (SELECT oracle_id AS pop_oracle_id, dec
 /// data-accrues behavior (context/archive/commander_select_signal.md §3).
 const SELECT_SIGNAL_JOIN: &str = "LEFT JOIN (SELECT commander_oracle_id AS sel_oid, shown AS sel_shown FROM commander_select_signal) sel ON sel.sel_oid = latest_cards.oracle_id";
 
+/// SELECT list for [`CardSummary`] searches: just the summary columns, with
+/// a multi-face card's front image lifted out of `card_faces` so the JSON
+/// for every face never leaves Postgres.
+const SUMMARY_COLUMNS: &str = "latest_cards.id, latest_cards.oracle_id, latest_cards.name, \
+     latest_cards.mana_cost, latest_cards.cmc, latest_cards.type_line, \
+     latest_cards.color_identity, latest_cards.rarity, latest_cards.set, \
+     latest_cards.edhrec_rank, latest_cards.prices, \
+     COALESCE(latest_cards.image_uris, latest_cards.card_faces -> 0 -> 'image_uris') AS image_uris";
+
+/// The document `text_search` matches and ranks against: name, type line,
+/// oracle and flavor text as one English `tsvector`. Written exactly as the
+/// GIN expression index on `latest_cards` indexes it, so the planner can use
+/// that index.
+const TEXT_SEARCH_DOCUMENT: &str = "to_tsvector('english', COALESCE(latest_cards.name, '') || ' ' || \
+     COALESCE(latest_cards.type_line, '') || ' ' || COALESCE(latest_cards.oracle_text, '') || ' ' || \
+     COALESCE(latest_cards.flavor_text, ''))";
+
+/// A card's printed color count, as `ScryfallData::color_count` counts it:
+/// top-level `colors`, else the front face's (double-faced layouts leave the
+/// top level NULL), else 0. The face's `colors` can be JSON null, which
+/// `jsonb_array_length` would reject, hence the type check.
+const COLOR_COUNT: &str = "COALESCE(cardinality(colors), \
+     jsonb_array_length(CASE WHEN jsonb_typeof(card_faces -> 0 -> 'colors') = 'array' \
+     THEN card_faces -> 0 -> 'colors' END), 0)";
+
+/// Cards kept per color identity in the `format_staples` view (the
+/// `bucket_rank <= 100` cut in its migration). A read over a union of buckets
+/// is only exact up to this many rows.
+const FORMAT_STAPLES_DEPTH: u32 = 100;
+
 impl CardRepository for MyPostgres {
     // ========
     //  create
@@ -191,16 +281,83 @@ impl CardRepository for MyPostgres {
         &self,
         multiple_scryfall_data: &[ScryfallData],
         batch_size: usize,
+        concurrency: usize,
         zervice_metrics: &mut ZerviceMetrics,
+        mut progress: Option<&mut SyncProgressTracker>,
     ) -> Result<Vec<Card>, CreateCardError> {
-        let mut tx = self.pool.begin().await?;
-        let cards = multiple_scryfall_data
-            .batch_delta_upsert_with_tx(&mut tx, batch_size, zervice_metrics)
-            .await?;
-        tx.commit().await?;
+        let batch_size = batch_size.max(1);
+        let mut chunks = multiple_scryfall_data
+            .chunks(batch_size)
+            .map(<[ScryfallData]>::to_vec);
+        let mut tasks = JoinSet::new();
+        let mut cards = Vec::with_capacity(multiple_scryfall_data.len());
+        loop {
+            while tasks.len() < concurrency.max(1)
+                && let Some(chunk) = chunks.next()
+            {
+                let pool = self.pool.clone();
+                tasks.spawn(async move { (chunk.len(), delta_upsert_chunk(pool, chunk).await) });
+            }
+            let Some(joined) = tasks.join_next().await else {
+                break;
+            };
+            let (chunk_len, (chunk_cards, chunk_metrics)) = joined.map_err(|e| {
+                CreateCardError::Database(anyhow::anyhow!("upsert task failed: {e}"))
+            })?;
+            zervice_metrics.merge(chunk_metrics);
+            cards.extend(chunk_cards);
+            if let Some(progress) = progress.as_deref_mut() {
+                progress.advance(chunk_len, zervice_metrics);
+            }
+        }
         Ok(cards)
     }
 
+    async fn get_sync_checkpoint(
+        &self,
+        sync_kind: SyncKind,
+    ) -> anyhow::Result<Option<SyncCheckpoint>> {
+        let row: Option<(String, i32)> = query_as(
+            "SELECT download_uri, processed_count FROM sync_checkpoints WHERE sync_kind = $1",
+        )
+        .bind(sync_kind.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .context("failed to get sync checkpoint")?;
+        Ok(row.map(|(download_uri, processed_count)| SyncCheckpoint {
+            sync_kind,
+            download_uri,
+            processed_count: usize::try_from(processed_count).unwrap_or_default(),
+        }))
+    }
+
+    async fn save_sync_checkpoint(&self, checkpoint: &SyncCheckpoint) -> anyhow::Result<()> {
+        query(
+            "INSERT INTO sync_checkpoints (sync_kind, download_uri, processed_count) \
+             VALUES ($1, $2, $3) \
+             ON CONFLICT (sync_kind) DO UPDATE SET \
+             download_uri = EXCLUDED.download_uri, \
+             processed_count = EXCLUDED.processed_count, \
+             updated_at = NOW()",
+        )
+        .bind(checkpoint.sync_kind.to_string())
+        .bind(&checkpoint.download_uri)
+        .bind(i32::try_from(checkpoint.processed_count).unwrap_or(i32::MAX))
+        .execute(&self.pool)
+        .await
+        .context("failed to save sync checkpoint")?;
+        Ok(())
+    }
+
+    async fn clear_sync_checkpoint(&self, sync_kind: SyncKind) -> anyhow::Result<()> {
+        query("DELETE FROM sync_checkpoints WHERE sync_kind = $1")
+            .bind(sync_kind.to_string())
+            .execute(&self.pool)
+            .await
+            .context("failed to clear sync checkpoint")?;
+        Ok(())
+    }
+
     /// Persists a completed sync run to `zervice_metrics`.
     async fn record_zervice_metrics(
         &self,
@@ -208,15 +365,17 @@ impl CardRepository for MyPostgres {
     ) -> Result<ZerviceMetrics, anyhow::Error> {
         let mut tx = self.pool.begin().await?;
         let query_sql = "INSERT INTO zervice_metrics \
-             (started_at, ended_at, duration_in_seconds, status, received_count, upserted_count, skipped_count, error_count, errors) \
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) RETURNING *";
+             (started_at, ended_at, duration_in_seconds, status, sync_kind, received_count, upserted_count, price_updated_count, skipped_count, error_count, errors) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) RETURNING *";
         let database_zervice_metrics: DatabaseZerviceMetrics = query_as(query_sql)
             .bind(zervice_metrics.started_at())
             .bind(zervice_metrics.ended_at())
             .bind(zervice_metrics.duration_in_seconds())
             .bind(zervice_metrics.status().to_string())
+            .bind(zervice_metrics.sync_kind().to_string())
             .bind(zervice_metrics.received_count())
             .bind(zervice_metrics.upserted_count())
+            .bind(zervice_metrics.price_updated_count())
             .bind(zervice_metrics.skipped_count())
             .bind(zervice_metrics.error_count())
             .bind(zervice_metrics.errors())
@@ -232,6 +391,24 @@ impl CardRepository for MyPostgres {
             .execute(&self.pool)
             .await
             .context("failed to refresh latest_cards materialized view")?;
+        // Oracle-referenced deck cards follow the preferred printing, which a
+        // refresh can move (a newer printing landed); re-point them here so
+        // deck reads stay plain reads. Dropped printings are handled by the
+        // `repoint_oracle_deck_cards` trigger.
+        let result = sqlx::query!(
+            "UPDATE deck_cards dc SET scryfall_data_id = r.id, updated_at = NOW()
+             FROM (SELECT DISTINCT oracle_id, preferred_printing(oracle_id) AS id
+                   FROM deck_cards WHERE reference = 'oracle') r
+             WHERE dc.reference = 'oracle' AND dc.oracle_id = r.oracle_id
+               AND r.id IS NOT NULL AND dc.scryfall_data_id <> r.id"
+        )
+        .execute(&self.pool)
+        .await
+        .context("failed to re-point oracle-referenced deck cards")?;
+        tracing::info!(
+            event = "deck_cards_repointed",
+            rows_updated = result.rows_affected()
+        );
         Ok(())
     }
 
@@ -251,6 +428,14 @@ impl CardRepository for MyPostgres {
         Ok(())
     }
 
+    async fn refresh_format_staples(&self) -> anyhow::Result<()> {
+        sqlx::query("REFRESH MATERIALIZED VIEW format_staples")
+            .execute(&self.pool)
+            .await
+            .context("failed to refresh format_staples materialized view")?;
+        Ok(())
+    }
+
     async fn sync_oracle_tags(
         &self,
         tags: &[crate::inbound::external::scryfall::oracle_tag::OracleTag],
@@ -258,6 +443,34 @@ impl CardRepository for MyPostgres {
         crate::outbound::sqlx::card::helpers::oracle_tags::sync_oracle_tags(&self.pool, tags).await
     }
 
+    async fn sync_set_catalog(
+        &self,
+        sets: &[crate::inbound::external::scryfall::set::ScryfallSet],
+    ) -> anyhow::Result<u32> {
+        let codes: Vec<String> = sets.iter().map(|s| s.code.to_lowercase()).collect();
+        let names: Vec<&str> = sets.iter().map(|s| s.name.as_str()).collect();
+        let parents: Vec<Option<&str>> =
+            sets.iter().map(|s| s.parent_set_code.as_deref()).collect();
+        let icons: Vec<&str> = sets.iter().map(|s| s.icon_svg_uri.as_str()).collect();
+        let result = sqlx::query(
+            "INSERT INTO scryfall_sets (code, name, parent_set_code, icon_svg_uri)
+             SELECT * FROM unnest($1::text[], $2::text[], $3::text[], $4::text[])
+             ON CONFLICT (code) DO UPDATE
+             SET name = EXCLUDED.name,
+                 parent_set_code = EXCLUDED.parent_set_code,
+                 icon_svg_uri = EXCLUDED.icon_svg_uri,
+                 updated_at = NOW()",
+        )
+        .bind(&codes)
+        .bind(&names)
+        .bind(&parents)
+        .bind(&icons)
+        .execute(&self.pool)
+        .await
+        .context("failed to upsert set catalog")?;
+        Ok(u32::try_from(result.rows_affected()).unwrap_or(u32::MAX))
+    }
+
     async fn refresh_card_oracle_tags(&self) -> anyhow::Result<()> {
         // Aggregate oracle tags per oracle_id once, then fan out to every printing's
         // card_profile. LEFT JOIN so cards that lost all tags reset to '[]'.
@@ -313,6 +526,18 @@ impl CardRepository for MyPostgres {
         Ok(scryfall_data)
     }
 
+    async fn existing_ids(
+        &self,
+        ids: &[uuid::Uuid],
+    ) -> Result<HashSet<uuid::Uuid>, GetScryfallDataError> {
+        let existing: Vec<uuid::Uuid> =
+            query_scalar("SELECT id FROM scryfall_data WHERE id = ANY($1)")
+                .bind(ids)
+                .fetch_all(&self.pool)
+                .await?;
+        Ok(existing.into_iter().collect())
+    }
+
     /// Searches the `latest_cards` materialized view (pre-deduplicated to one row per
     /// oracle_id). Joins `card_profiles` for is_token / card_roles filters.
     /// Filter clauses are composed with `AND` via `QueryBuilder::separated`.
@@ -340,678 +565,1217 @@ impl CardRepository for MyPostgres {
         request: &CardQuery,
         context: DeckServeContext<'_>,
     ) -> Result<Vec<ScryfallData>, SearchScryfallDataError> {
-        // Deck-serving inputs arrive bundled; unpack them into the locals the
-        // QueryBuilder threads them into (never stored beyond this call).
-        let DeckServeContext {
-            deck_id,
-            exclude_oracle_ids,
-            synergy_scores,
-            synergy_only,
-            commander_seed,
-            deck_oracle_tags,
-        } = context;
-        // WHERE clauses read the predicate fields; LIMIT/OFFSET/ORDER BY read
-        // the query config — the CardCriteria/CardQuery split, mirrored here.
-        let criteria = request.criteria();
-        // Default synergy ordering (no explicit sort, score map present) gets
-        // the signal + jitter terms, which need the pooled rollup and the
-        // global rate in scope; every other path keeps the plain FROM.
-        let signal_ordering = request.sort().is_none() && synergy_scores.is_some();
-        // Commander-select mode: caller (search_commanders) supplied a shuffle
-        // seed. Token/emblem exclusion applies whenever this is set; popularity
-        // ordering + banding + wildcard apply only when the user hasn't pinned
-        // an explicit sort (an explicit sort still wins, as everywhere).
-        let commander_select = commander_seed.is_some();
-        let popularity_ordering = commander_select && request.sort().is_none();
-        // Wildcard serving (context/archive/wildcard_slot/): the banded serve
-        // reserves WILDCARD_SLOTS per page for deep-pool probes. It needs the
-        // ranked pool twice (band + deep slice), so the query becomes a CTE.
-        // Synergy seeds by deck; commander-select seeds by `commander_seed`.
-        let wildcard_serving =
-            WILDCARD_SLOTS > 0 && ((signal_ordering && deck_id.is_some()) || popularity_ordering);
-        // The (base + signal) score expression, shared by the wildcard CTE
-        // header and the plain signal ORDER BY below:
-        //   base: the commander's synergy score (unscored cards anchor below
-        //         the scored floor, see UNSCORED_ANCHOR)
-        //   signal: the pooled net-rate, shrunk toward and centered on the
-        //         global rate — a card with no impressions adds zero.
-        let push_score = |qb: &mut QueryBuilder<Postgres>, scores: &serde_json::Value| {
-            qb.push("COALESCE((");
-            qb.push_bind(scores.clone());
-            qb.push(format!(" ->> LOWER(name))::float8, {UNSCORED_ANCHOR})"));
-            qb.push(format!(
-                " + {W_SIGNAL} * ((COALESCE(sig.net, 0) + {SHRINK_K} * g.rate) \
-                   / (COALESCE(sig.shown, 0) + {SHRINK_K}) - g.rate)"
-            ));
-            // Oracle-tag term (Phase 4): a flat lift for cards whose otags overlap
-            // the deck's selected otags. Dormant when the deck selected none, so
-            // decks without otags keep byte-identical ordering. Mirrors the
-            // filter's `?|` jsonb-array overlap probe.
-            if !deck_oracle_tags.is_empty() {
-                qb.push(format!(
-                    " + {W_ORACLE_TAG} * (card_profiles.oracle_tags ?| "
-                ));
-                qb.push_bind(deck_oracle_tags.to_vec());
-                qb.push(")::int");
-            }
-        };
-        // Banding/wildcard shuffle seed: synergy serves seed by deck+day,
-        // commander-select by the caller's `commander_seed` (user+day, no deck).
-        let band_seed: Option<String> = if popularity_ordering {
-            commander_seed.clone()
-        } else if signal_ordering {
-            deck_id.map(|d| format!("{d}:{}", Utc::now().date_naive()))
-        } else {
-            None
-        };
-        let mut qb: QueryBuilder<Postgres> = if wildcard_serving
-            && signal_ordering
-            && let (Some(scores), Some(seed)) = (synergy_scores, band_seed.clone())
-        {
-            // Synergy wildcard CTE: rank + shuffle + exposure computed once; the
-            // two slices at the end page it two ways. The predicate pushes below
-            // land inside the CTE, so filters, legality, and suppressions bound
-            // both slices.
-            let mut qb: QueryBuilder<Postgres> = QueryBuilder::new(
-                "WITH pool AS (SELECT latest_cards.*, row_number() OVER (ORDER BY ",
-            );
-            push_score(&mut qb, scores);
-            qb.push(
-                " DESC, name ASC, latest_cards.id) AS rn, \
-                     hashtext(COALESCE(latest_cards.oracle_id::text, '') || ",
-            );
-            qb.push_bind(seed);
-            qb.push(
-                ") AS shuffle, COALESCE(sig.shown, 0) AS pool_shown
-             FROM latest_cards
-             JOIN card_profiles ON latest_cards.id = card_profiles.scryfall_data_id
-             LEFT JOIN card_signal_rollup sig ON sig.card_oracle_id = latest_cards.oracle_id
-             CROSS JOIN (SELECT COALESCE(SUM(net) / NULLIF(SUM(shown), 0), 0) AS rate
-                         FROM card_signal_rollup) g
-             WHERE ",
-            );
-            qb
-        } else if wildcard_serving
-            && popularity_ordering
-            && let Some(seed) = band_seed.clone()
-        {
-            // Commander-select wildcard CTE: same banded-pool + deep-probe
-            // machinery as the synergy arm, ranked by decks-helmed popularity
-            // instead of synergy score. The deep slice orders least-shown first
-            // using the first-party select signal; with no signal rows yet the
-            // COALESCE floors every pool_shown to 0 and the ordering collapses
-            // to the daily shuffle alone — byte-identical to the pre-signal
-            // behavior (context/archive/commander_select_signal.md §3). Both
-            // joins are 1:1 (PK on oracle_id) and aliased so no bare
-            // `name`/`oracle_id` collides with the shared WHERE filters.
-            let mut qb: QueryBuilder<Postgres> = QueryBuilder::new(
-                "WITH pool AS (SELECT latest_cards.*, row_number() OVER (ORDER BY ",
-            );
-            qb.push(POPULARITY_RANK);
-            qb.push(
-                ") AS rn, \
-                     hashtext(COALESCE(latest_cards.oracle_id::text, '') || ",
-            );
-            qb.push_bind(seed);
-            qb.push(format!(
-                ") AS shuffle, COALESCE(sel.sel_shown, 0) AS pool_shown
-             FROM latest_cards
-             JOIN card_profiles ON latest_cards.id = card_profiles.scryfall_data_id
-             {POPULARITY_JOIN}
-             {SELECT_SIGNAL_JOIN}
-             WHERE "
-            ));
-            qb
-        } else if signal_ordering {
-            QueryBuilder::new(
-                "SELECT latest_cards.* FROM latest_cards
-             JOIN card_profiles ON latest_cards.id = card_profiles.scryfall_data_id
-             LEFT JOIN card_signal_rollup sig ON sig.card_oracle_id = latest_cards.oracle_id
-             CROSS JOIN (SELECT COALESCE(SUM(net) / NULLIF(SUM(shown), 0), 0) AS rate
-                         FROM card_signal_rollup) g
-             WHERE ",
-            )
-        } else if popularity_ordering {
-            // Non-wildcard commander-select (WILDCARD_SLOTS = 0 revert lever):
-            // popularity join for the banded ORDER BY below, no deep-probe CTE.
-            QueryBuilder::new(format!(
-                "SELECT latest_cards.* FROM latest_cards
-             JOIN card_profiles ON latest_cards.id = card_profiles.scryfall_data_id
-             {POPULARITY_JOIN}
-             WHERE "
-            ))
-        } else {
-            QueryBuilder::new(
-                "SELECT latest_cards.* FROM latest_cards
-             JOIN card_profiles ON latest_cards.id = card_profiles.scryfall_data_id
-             WHERE ",
-            )
-        };
-        let mut sep: Separated<Postgres, &'static str> = qb.separated(" AND ");
-        // Seed an always-true clause so the baked `WHERE` is valid even when no
-        // filter conditions are pushed (e.g. an empty filter). It also lets every
-        // real condition below rely on the `AND` separator: the first real push
-        // becomes the second element, so it is correctly prefixed with ` AND `.
-        sep.push("TRUE");
+        let db_rows: Vec<DatabaseScryfallData> =
+            self.search_rows(request, context, "latest_cards.*").await?;
+        db_rows
+            .into_iter()
+            .map(ScryfallData::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(SearchScryfallDataError::Database)
+    }
 
-        // Commander-select candidate pool must exclude token/emblem printings.
-        // The popularity table (written by the synergy worker) keys on the real
-        // card's oracle_id and excludes these layouts, so serving must agree —
-        // otherwise a same-named token could be offered as a commander and would
-        // never join a real deck. Applies to every commander search, even one
-        // with an explicit sort. (context/archive/commander_select_ordering.md §1.)
-        if commander_select {
-            sep.push("latest_cards.layout NOT IN ('token', 'double_faced_token', 'emblem')");
-        }
+    async fn search_card_summaries(
+        &self,
+        request: &CardQuery,
+    ) -> Result<Vec<CardSummary>, SearchScryfallDataError> {
+        let db_rows: Vec<DatabaseCardSummary> = self
+            .search_rows(request, DeckServeContext::default(), SUMMARY_COLUMNS)
+            .await?;
+        db_rows
+            .into_iter()
+            .map(CardSummary::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(SearchScryfallDataError::Database)
+    }
 
-        // Strip punctuation from DB columns for punctuation-insensitive text search.
-        // The query values are already stripped by CardQueryBuilder setters.
-        const STRIP_NAME: &str = "regexp_replace(name, '[^a-zA-Z0-9 ]', '', 'g') ILIKE ";
-        const STRIP_TYPE: &str = "regexp_replace(type_line, '[^a-zA-Z0-9 ]', '', 'g') ILIKE ";
+    async fn get_card(&self, request: &GetScryfallData) -> Result<Card, GetCardError> {
+        let scryfall_data = self.get_scryfall_data(request).await?;
+        let card_profile = self.get_card_profile_with_scryfall_data_id(request).await?;
+        let card = Card::new(card_profile, scryfall_data);
+        Ok(card)
+    }
 
-        if let Some(query_string) = &criteria.name_contains() {
-            sep.push(STRIP_NAME);
-            sep.push_bind_unseparated(format!("%{}%", query_string));
-        }
+    async fn get_cards(&self, request: &ScryfallDataIds) -> Result<Vec<Card>, GetCardError> {
+        let scryfall_data = self.get_multiple_scryfall_data(request).await?;
+        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
+        let card_profiles = self
+            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
+            .await?;
+        let cards = card_profiles.sleeve(scryfall_data);
+        Ok(cards)
+    }
 
-        if let Some(query_string) = &criteria.name_not_contains() {
-            sep.push("NOT (");
-            sep.push_unseparated(STRIP_NAME);
-            sep.push_bind_unseparated(format!("%{}%", query_string));
-            sep.push_unseparated(")");
-        }
-
-        if let Some(query_string) = &criteria.type_line_contains() {
-            sep.push(STRIP_TYPE);
-            sep.push_bind_unseparated(format!("%{}%", query_string));
+    /// Returns all printings of a card by oracle_id, ordered by release date (newest first).
+    async fn get_printings(&self, oracle_id: uuid::Uuid) -> Result<Vec<Card>, GetCardError> {
+        let db_rows: Vec<DatabaseScryfallData> =
+            query_as("SELECT sd.* FROM scryfall_data sd JOIN card_profiles cp ON sd.id = cp.scryfall_data_id WHERE sd.oracle_id = $1 ORDER BY sd.released_at ASC")
+                .bind(oracle_id)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| GetScryfallDataError::Database(e.into()))?;
+        let scryfall_data: Vec<ScryfallData> = db_rows
+            .into_iter()
+            .map(ScryfallData::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(GetScryfallDataError::Database)?;
+        if scryfall_data.is_empty() {
+            return Ok(vec![]);
         }
+        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
+        let card_profiles = self
+            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
+            .await?;
+        let cards = card_profiles.sleeve(scryfall_data);
+        Ok(cards)
+    }
 
-        if let Some(query_string_array) = &criteria.type_line_contains_any() {
-            sep.push(" (");
-            query_string_array
-                .iter()
-                .enumerate()
-                .for_each(|(i, query_string)| {
-                    if i > 0 {
-                        sep.push_unseparated(" OR ");
-                    }
-                    sep.push_unseparated(STRIP_TYPE);
-                    sep.push_bind_unseparated(format!("%{}%", query_string));
-                });
-            sep.push_unseparated(") ");
-        }
+    /// Both rows through `to_jsonb`, so a row that no longer converts to
+    /// the domain types can still be inspected.
+    async fn get_raw_card(
+        &self,
+        request: &GetScryfallData,
+    ) -> Result<RawCard, GetScryfallDataError> {
+        let scryfall_data: serde_json::Value =
+            query_scalar("SELECT to_jsonb(sd) FROM scryfall_data sd WHERE sd.id = $1")
+                .bind(**request)
+                .fetch_one(&self.pool)
+                .await?;
+        let card_profile: Option<serde_json::Value> = query_scalar(
+            "SELECT to_jsonb(cp) FROM card_profiles cp WHERE cp.scryfall_data_id = $1",
+        )
+        .bind(**request)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(RawCard {
+            scryfall_data,
+            card_profile,
+        })
+    }
 
-        if let Some(card_types) = &criteria.card_type_contains_any() {
-            sep.push(" (");
-            card_types.iter().enumerate().for_each(|(i, query_string)| {
-                if i > 0 {
-                    sep.push_unseparated(" OR ");
-                }
-                sep.push_unseparated(STRIP_TYPE);
-                sep.push_bind_unseparated(format!("%{}%", query_string));
+    /// The focused card through [`get_card`](Self::get_card), then its
+    /// siblings by oracle id in a single query, summarized.
+    async fn get_card_with_printings(
+        &self,
+        request: &GetScryfallData,
+    ) -> Result<CardWithPrintings, GetCardError> {
+        let card = self.get_card(request).await?;
+        let Some(oracle_id) = card.scryfall_data.oracle_id else {
+            return Ok(CardWithPrintings {
+                card,
+                printings: vec![],
             });
-            sep.push_unseparated(") ");
-        }
+        };
+        let db_rows: Vec<DatabaseScryfallData> = query_as(
+            "SELECT sd.* FROM scryfall_data sd
+             JOIN card_profiles cp ON sd.id = cp.scryfall_data_id
+             WHERE sd.oracle_id = $1 AND sd.id <> $2
+             ORDER BY sd.released_at ASC, sd.set ASC, sd.collector_number ASC",
+        )
+        .bind(oracle_id)
+        .bind(card.scryfall_data.id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| GetScryfallDataError::Database(e.into()))?;
+        let printings = db_rows
+            .into_iter()
+            .map(|row| ScryfallData::try_from(row).map(|sd| PrintingSummary::from(&sd)))
+            .collect::<Result<_, _>>()
+            .map_err(GetScryfallDataError::Database)?;
+        Ok(CardWithPrintings { card, printings })
+    }
 
-        if let Some(query_string_array) = &criteria.type_line_contains_all() {
-            for query_string in query_string_array.iter() {
-                sep.push(STRIP_TYPE);
-                sep.push_bind_unseparated(format!("%{}%", query_string));
-            }
+    /// Composes `search_scryfall_data` results with card profiles into `Card` values.
+    async fn search_cards(&self, request: &CardQuery) -> Result<Vec<Card>, SearchCardsError> {
+        let scryfall_data = self.search_scryfall_data(request).await?;
+        if scryfall_data.is_empty() {
+            return Ok(vec![]);
         }
+        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
+        let card_profiles = self
+            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
+            .await?;
+        let cards = card_profiles.sleeve(scryfall_data);
+        Ok(cards)
+    }
 
-        if let Some(card_types) = &criteria.card_type_contains_all() {
-            for card_type in card_types.iter() {
-                sep.push(STRIP_TYPE);
-                sep.push_bind_unseparated(format!("%{}%", card_type));
-            }
+    /// Unions the subset buckets of `color_identity` in `format_staples` (at
+    /// most 32 × [`FORMAT_STAPLES_DEPTH`] rows) and takes the best ranks.
+    async fn format_staples(
+        &self,
+        color_identity: &Colors,
+        limit: u32,
+    ) -> Result<Vec<Card>, SearchCardsError> {
+        let limit = limit.min(FORMAT_STAPLES_DEPTH);
+        if limit == 0 {
+            return Ok(vec![]);
         }
+        let db_rows: Vec<DatabaseScryfallData> = query_as(
+            "SELECT lc.* FROM format_staples fs
+             JOIN latest_cards lc ON lc.id = fs.id
+             WHERE fs.identity <@ $1
+             ORDER BY fs.edhrec_rank ASC, lc.name ASC, lc.id
+             LIMIT $2",
+        )
+        .bind(color_identity.to_short_names())
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(SearchScryfallDataError::from)?;
+        self.sleeve_rows(db_rows).await
+    }
 
-        if let Some(query_string) = &criteria.type_line_not_contains() {
-            sep.push("(type_line IS NULL OR NOT (");
-            sep.push_unseparated(STRIP_TYPE);
-            sep.push_bind_unseparated(format!("%{}%", query_string));
-            sep.push_unseparated("))");
+    /// `format_staples` anti-joined against every card in the user's decks
+    /// (any board), by oracle_id so another printing still counts as used.
+    async fn undiscovered_staples(
+        &self,
+        user_id: uuid::Uuid,
+        color_identity: &Colors,
+        limit: u32,
+    ) -> Result<Vec<Card>, SearchCardsError> {
+        let limit = limit.min(FORMAT_STAPLES_DEPTH);
+        if limit == 0 {
+            return Ok(vec![]);
         }
+        let db_rows: Vec<DatabaseScryfallData> = query_as(
+            "SELECT lc.* FROM format_staples fs
+             JOIN latest_cards lc ON lc.id = fs.id
+             WHERE fs.identity <@ $1
+               AND NOT EXISTS (
+                   SELECT 1 FROM deck_cards dc
+                   JOIN decks d ON d.id = dc.deck_id
+                   WHERE d.user_id = $3 AND dc.oracle_id = lc.oracle_id
+               )
+             ORDER BY fs.edhrec_rank ASC, lc.name ASC, lc.id
+             LIMIT $2",
+        )
+        .bind(color_identity.to_short_names())
+        .bind(i64::from(limit))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(SearchScryfallDataError::from)?;
+        self.sleeve_rows(db_rows).await
+    }
 
-        if let Some(query_string_array) = &criteria.type_line_excludes_any() {
-            sep.push("(type_line IS NULL OR NOT (");
-            query_string_array
-                .iter()
-                .enumerate()
-                .for_each(|(i, query_string)| {
-                    if i > 0 {
-                        sep.push_unseparated(" OR ");
+    /// Buckets the filtered set with one `GROUP BY`: each identity color, plus
+    /// `colorless`/`multicolor` by identity cardinality and a `total` row.
+    async fn color_distribution(
+        &self,
+        request: &CardQuery,
+    ) -> Result<ColorDistribution, SearchCardsError> {
+        let mut qb: QueryBuilder<Postgres> = QueryBuilder::new(
+            "WITH filtered AS (SELECT latest_cards.color_identity FROM latest_cards
+             JOIN card_profiles ON latest_cards.id = card_profiles.scryfall_data_id
+             WHERE ",
+        );
+        let mut sep: Separated<Postgres, &'static str> = qb.separated(" AND ");
+        sep.push("TRUE");
+        push_criteria_filters(&mut sep, request.criteria(), PLAYABLE_LAYOUTS);
+        qb.push(
+            ") SELECT bucket, COUNT(*) FROM (
+                 SELECT unnest(color_identity) AS bucket FROM filtered
+                 UNION ALL
+                 SELECT CASE WHEN COALESCE(cardinality(color_identity), 0) = 0
+                             THEN 'colorless' ELSE 'multicolor' END
+                 FROM filtered
+                 WHERE COALESCE(cardinality(color_identity), 0) <> 1
+                 UNION ALL
+                 SELECT 'total' FROM filtered
+             ) buckets
+             GROUP BY bucket",
+        );
+
+        let rows: Vec<(String, i64)> = qb
+            .build_query_as()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(SearchScryfallDataError::from)?;
+
+        let mut distribution = ColorDistribution::default();
+        for (bucket, count) in rows {
+            let count = u32::try_from(count).unwrap_or(u32::MAX);
+            match bucket.as_str() {
+                "total" => distribution.total = count,
+                "colorless" => distribution.colorless = count,
+                "multicolor" => distribution.multicolor = count,
+                color => {
+                    if let Ok(color) = Color::try_from(color) {
+                        *distribution.count_mut(color) = count;
                     }
-                    sep.push_unseparated(STRIP_TYPE);
-                    sep.push_bind_unseparated(format!("%{}%", query_string));
-                });
-            sep.push_unseparated(")) ");
-        }
-
-        if let Some(card_types) = &criteria.card_type_excludes_any() {
-            sep.push("(type_line IS NULL OR NOT (");
-            card_types.iter().enumerate().for_each(|(i, query_string)| {
-                if i > 0 {
-                    sep.push_unseparated(" OR ");
                 }
-                sep.push_unseparated(STRIP_TYPE);
-                sep.push_bind_unseparated(format!("%{}%", query_string));
-            });
-            sep.push_unseparated(")) ");
+            }
         }
+        Ok(distribution)
+    }
 
-        if let Some(sets) = criteria.set_equals_any() {
-            sep.push("set_name = ANY(");
-            sep.push_bind_unseparated(sets);
-            sep.push_unseparated(")");
-        }
+    /// Extracts distinct card types by tokenizing `type_line` with `STRING_TO_ARRAY`.
+    async fn get_card_types(&self) -> Result<Vec<String>, GetCardTypesError> {
+        // Stop words: see domain::card::models::search_card::stop_words::TYPE_STOP_WORDS
+        let card_types: Vec<String> = query_scalar!(
+            "SELECT DISTINCT subtype FROM (
+                SELECT TRIM(BOTH ':-?, ' FROM UNNEST(STRING_TO_ARRAY(type_line, ' '))) subtype
+                FROM latest_cards
+            ) subtypes
+            WHERE subtype NOT IN ('//', '-', 'the', 'of', 'and/or', 'you', 'you''ll')
+            ORDER BY subtype ASC"
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+        Ok(card_types)
+    }
 
-        if let Some(artists) = criteria.artist_equals_any() {
-            sep.push("artist = ANY(");
-            sep.push_bind_unseparated(artists);
-            sep.push_unseparated(")");
-        }
+    /// Returns distinct keyword abilities from the `keywords` array column.
+    async fn get_keywords(&self) -> Result<Vec<String>, GetKeywordsError> {
+        let keywords: Vec<String> = query_scalar!(
+            "SELECT DISTINCT LOWER(TRIM(keyword)) AS keyword
+             FROM latest_cards, UNNEST(keywords) AS keyword
+             WHERE keywords IS NOT NULL
+             ORDER BY keyword ASC"
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| GetKeywordsError::Database(e.into()))?
+        .into_iter()
+        .flatten()
+        .collect();
+        Ok(keywords)
+    }
 
-        if let Some(rarities) = criteria.rarity_equals_any() {
-            sep.push("rarity = ANY(");
-            sep.push_bind_unseparated(rarities.to_short_names());
-            sep.push_unseparated(")");
-        }
+    /// Returns the full oracle tag catalog: every row of `oracle_tags` with its
+    /// `parent_ids` resolved to parent slugs, ordered by slug.
+    async fn get_oracle_tags(&self) -> Result<Vec<OracleTag>, GetOracleTagsError> {
+        let oracle_tags: Vec<OracleTag> = query_as!(
+            DatabaseOracleTag,
+            r#"SELECT
+                 o.slug AS "slug!",
+                 o.label AS "label!",
+                 o.description,
+                 COALESCE(
+                     ARRAY(
+                         SELECT p.slug FROM oracle_tags p
+                         WHERE p.id = ANY(o.parent_ids)
+                         ORDER BY p.slug
+                     ),
+                     ARRAY[]::text[]
+                 ) AS "parent_slugs!"
+               FROM oracle_tags o
+               ORDER BY o.slug ASC"#
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| GetOracleTagsError::Database(e.into()))?
+        .into_iter()
+        .map(OracleTag::from)
+        .collect();
+        Ok(oracle_tags)
+    }
 
-        if let Some(sets) = criteria.set_excludes_any() {
-            sep.push("NOT (set_name = ANY(");
-            sep.push_bind_unseparated(sets);
-            sep.push_unseparated("))");
-        }
+    /// Returns distinct normalized words extracted from oracle text, noise-filtered.
+    async fn get_oracle_words(&self) -> Result<Vec<String>, GetOracleWordsError> {
+        // Stop words: see domain::card::models::search_card::stop_words::ORACLE_STOP_WORDS
+        let words: Vec<String> = query_scalar!(
+            "SELECT DISTINCT LOWER(REGEXP_REPLACE(word, '[^a-zA-Z]', '', 'g')) AS word
+             FROM latest_cards, REGEXP_SPLIT_TO_TABLE(oracle_text, '\\s+') AS word
+             WHERE oracle_text IS NOT NULL
+               AND LOWER(REGEXP_REPLACE(word, '[^a-zA-Z]', '', 'g')) NOT IN (
+                 'a', 'an', 'the', 'of', 'to', 'in', 'on', 'at', 'by', 'for', 'with',
+                 'from', 'into', 'as', 'and', 'or', 'but', 'that', 'which', 'who',
+                 'it', 'its', 'you', 'your', 'this', 'those', 'these', 'they', 'their',
+                 'is', 'are', 'was', 'be', 'has', 'have', 'do', 'does', 'been', ''
+               )
+             ORDER BY word ASC"
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| GetOracleWordsError::Database(e.into()))?
+        .into_iter()
+        .flatten()
+        .collect();
+        Ok(words)
+    }
 
-        if let Some(artists) = criteria.artist_excludes_any() {
-            sep.push("(artist IS NULL OR NOT (artist = ANY(");
-            sep.push_bind_unseparated(artists);
-            sep.push_unseparated(")))");
-        }
+    async fn get_artists(&self) -> Result<Vec<String>, GetArtistsError> {
+        let artists: Vec<String> = query_scalar!(
+            "SELECT DISTINCT artist FROM latest_cards
+             WHERE artist IS NOT NULL
+             ORDER BY artist"
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+        Ok(artists)
+    }
 
-        if let Some(rarities) = criteria.rarity_excludes_any() {
-            sep.push("NOT (rarity = ANY(");
-            sep.push_bind_unseparated(rarities.to_short_names());
-            sep.push_unseparated("))");
-        }
+    async fn get_sets(&self) -> Result<Vec<String>, GetSetsError> {
+        let sets: Vec<String> =
+            query_scalar!("SELECT DISTINCT set_name FROM latest_cards ORDER BY set_name")
+                .fetch_all(&self.pool)
+                .await?
+                .into_iter()
+                .flatten()
+                .collect();
+        Ok(sets)
+    }
 
-        if let Some(query_string) = criteria.cmc_equals() {
-            sep.push("cmc = ");
-            sep.push_bind_unseparated(query_string);
-        }
+    async fn get_set_icon(&self, set_code: &str) -> Result<SetIcon, GetSetIconError> {
+        let catalog: Option<(String, String, String)> =
+            query_as("SELECT code, name, icon_svg_uri FROM scryfall_sets WHERE code = LOWER($1)")
+                .bind(set_code.trim())
+                .fetch_optional(&self.pool)
+                .await?;
+        if let Some((code, name, icon_svg_uri)) = catalog {
+            return Ok(SetIcon::with_icon_uri(&code, &name, &icon_svg_uri));
+        }
+        // Not in the catalog yet (it syncs after the cards): fall back to the
+        // printings and the code-keyed symbol.
+        let (code, name): (String, String) = query_as(
+            "SELECT set, set_name FROM scryfall_data
+             WHERE LOWER(set) = LOWER($1)
+             LIMIT 1",
+        )
+        .bind(set_code.trim())
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(GetSetIconError::NotFound)?;
+        Ok(SetIcon::new(&code, &name))
+    }
 
-        if let Some(cmc_range) = criteria.cmc_range() {
-            let lower = cmc_range.0.min(cmc_range.1);
-            let higher = cmc_range.0.max(cmc_range.1);
-            sep.push("cmc between ");
-            sep.push_bind_unseparated(lower);
-            sep.push("");
-            sep.push_bind_unseparated(higher);
-        }
+    /// One pass over the set's printings, left-joined to the user's
+    /// collection; both counts are distinct oracle ids.
+    async fn get_set_completion(
+        &self,
+        user_id: uuid::Uuid,
+        set_code: &str,
+    ) -> Result<SetCompletion, GetSetCompletionError> {
+        let (code, name, total, owned): (Option<String>, Option<String>, i64, i64) = query_as(
+            "SELECT MIN(sd.set), MIN(sd.set_name),
+                    COUNT(DISTINCT sd.oracle_id),
+                    COUNT(DISTINCT sd.oracle_id) FILTER (WHERE cc.oracle_id IS NOT NULL)
+             FROM scryfall_data sd
+             LEFT JOIN collection_cards cc
+               ON cc.user_id = $1 AND cc.oracle_id = sd.oracle_id
+             WHERE LOWER(sd.set) = LOWER($2) AND sd.oracle_id IS NOT NULL",
+        )
+        .bind(user_id)
+        .bind(set_code.trim())
+        .fetch_one(&self.pool)
+        .await?;
+        let (Some(code), Some(name)) = (code, name) else {
+            return Err(GetSetCompletionError::NotFound);
+        };
+        Ok(SetCompletion::new(
+            &code,
+            &name,
+            u32::try_from(owned).unwrap_or(u32::MAX),
+            u32::try_from(total).unwrap_or(u32::MAX),
+        ))
+    }
 
-        // Price range against the selected currency's JSONB price. NULLIF turns
-        // empty/missing prices into NULL (excluded — no cast error), matching the
-        // client predicate. json_key() is a fixed enum literal, not user input.
-        if criteria.price_min().is_some() || criteria.price_max().is_some() {
-            let col = format!(
-                "NULLIF(prices->>'{}', '')::FLOAT8",
-                criteria.price_currency().unwrap_or_default().json_key()
-            );
-            if let Some(min) = criteria.price_min() {
-                sep.push(format!("{col} >= "));
-                sep.push_bind_unseparated(min);
+    async fn get_languages(&self) -> Result<Vec<String>, GetLanguagesError> {
+        let languages: Vec<String> = query_scalar!(
+            "SELECT DISTINCT lang FROM latest_cards
+             WHERE lang IS NOT NULL
+             ORDER BY lang"
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .flatten()
+        .collect();
+        Ok(languages)
+    }
+
+    async fn get_card_profile_with_id(
+        &self,
+        request: &GetCardProfile,
+    ) -> Result<CardProfile, GetCardProfileError> {
+        let card_profile: CardProfile = query_as!(
+            DatabaseCardProfile,
+            "SELECT scryfall_data_id, is_token, card_roles, oracle_tags, oracle_tags_by_role, other_oracle_tags, copy_limit, created_at, updated_at FROM card_profiles WHERE scryfall_data_id = $1",
+            **request
+        )
+        .fetch_one(&self.pool)
+        .await?
+        .into();
+        Ok(card_profile)
+    }
+
+    async fn get_card_profile_with_scryfall_data_id(
+        &self,
+        request: &GetScryfallData,
+    ) -> Result<CardProfile, GetCardProfileError> {
+        let card_profile: CardProfile = query_as!(
+            DatabaseCardProfile,
+            "SELECT scryfall_data_id, is_token, card_roles, oracle_tags, oracle_tags_by_role, other_oracle_tags, copy_limit, created_at, updated_at
+            FROM card_profiles WHERE scryfall_data_id = $1",
+            **request
+        )
+        .fetch_one(&self.pool)
+        .await?
+        .into();
+        Ok(card_profile)
+    }
+
+    async fn get_card_profiles_with_ids(
+        &self,
+        request: &CardProfileIds,
+    ) -> Result<Vec<CardProfile>, GetCardProfileError> {
+        let card_profiles: Vec<CardProfile> = query_as!(
+            DatabaseCardProfile,
+            "SELECT scryfall_data_id, is_token, card_roles, oracle_tags, oracle_tags_by_role, other_oracle_tags, copy_limit, created_at, updated_at
+            FROM card_profiles WHERE scryfall_data_id = ANY($1)",
+            &**request
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|dcp| dcp.into())
+        .collect();
+        Ok(card_profiles)
+    }
+
+    async fn get_card_profiles_with_scryfall_data_ids(
+        &self,
+        request: &ScryfallDataIds,
+    ) -> Result<Vec<CardProfile>, GetCardProfileError> {
+        let card_profiles: Vec<CardProfile> = query_as!(
+            DatabaseCardProfile,
+            "SELECT scryfall_data_id, is_token, card_roles, oracle_tags, oracle_tags_by_role, other_oracle_tags, copy_limit, created_at, updated_at
+            FROM card_profiles WHERE scryfall_data_id = ANY($1)",
+            &**request
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .map(|dcp| dcp.into())
+        .collect();
+        Ok(card_profiles)
+    }
+
+    async fn get_last_sync_date(&self) -> anyhow::Result<Option<DateTime<Utc>>> {
+        let last_sync_date: Option<DateTime<Utc>> = query_scalar(
+            "SELECT started_at FROM zervice_metrics
+            ORDER BY started_at DESC LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .context("failed to get last sync date")?;
+        Ok(last_sync_date)
+    }
+
+    async fn get_last_full_sync_date(&self) -> anyhow::Result<Option<DateTime<Utc>>> {
+        let last_full_sync_date: Option<DateTime<Utc>> = query_scalar(
+            "SELECT started_at FROM zervice_metrics
+            WHERE sync_kind = 'full'
+            ORDER BY started_at DESC LIMIT 1",
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .context("failed to get last full sync date")?;
+        Ok(last_full_sync_date)
+    }
+
+    async fn find_cards_by_exact_names(
+        &self,
+        names: &[String],
+    ) -> Result<Vec<Card>, SearchCardsError> {
+        if names.is_empty() {
+            return Ok(vec![]);
+        }
+        // Match the full name, and also the front face of double-faced cards, so
+        // "Boggart Trawler // Boggart Bog" resolves whether the entry is the full
+        // name, just the front ("Boggart Trawler"), or a "/" spelling. Each entry
+        // contributes both itself and its pre-slash front to the match set, and
+        // the query checks each card's full name and its front face.
+        let mut lowered: std::collections::HashSet<String> = std::collections::HashSet::new();
+        for name in names {
+            let lower = name.to_lowercase();
+            let front = entry_front_face(&lower);
+            if !front.is_empty() {
+                lowered.insert(front.to_string());
             }
-            if let Some(max) = criteria.price_max() {
-                sep.push(format!("{col} <= "));
-                sep.push_bind_unseparated(max);
+            lowered.insert(lower);
+        }
+        let lowered: Vec<String> = lowered.into_iter().collect();
+        // ORDER BY makes the row order stable, so when an entry is ambiguous
+        // (two double-faced cards share a front face) resolution is deterministic.
+        let db_rows: Vec<DatabaseScryfallData> = query_as(
+            "SELECT * FROM latest_cards \
+             WHERE LOWER(name) = ANY($1) \
+                OR LOWER(split_part(name, ' // ', 1)) = ANY($1) \
+             ORDER BY name",
+        )
+        .bind(&lowered)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| SearchScryfallDataError::Database(e.into()))?;
+        let scryfall_data: Vec<ScryfallData> = db_rows
+            .into_iter()
+            .map(ScryfallData::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(SearchScryfallDataError::Database)?;
+        if scryfall_data.is_empty() {
+            return Ok(vec![]);
+        }
+        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
+        let card_profiles = self
+            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
+            .await?;
+        let cards = card_profiles.sleeve(scryfall_data);
+        Ok(cards)
+    }
+
+    async fn get_cards_by_names(&self, names: &[String]) -> Result<Vec<Card>, SearchCardsError> {
+        if names.is_empty() {
+            return Ok(vec![]);
+        }
+        // Same match set as `find_cards_by_exact_names`: each entry and its
+        // pre-slash front face, lowercased.
+        let mut lowered: HashSet<String> = HashSet::new();
+        for name in names {
+            let lower = name.trim().to_lowercase();
+            let front = entry_front_face(&lower);
+            if !front.is_empty() {
+                lowered.insert(front.to_string());
             }
+            lowered.insert(lower);
         }
+        let lowered: Vec<String> = lowered.into_iter().collect();
+        // Rank every printing rather than trusting `latest_cards`, whose pick
+        // ignores language. The builder's defaults say which printing a plain
+        // search would show; promos and content warnings sort last as they do
+        // in the view.
+        let defaults = CardQueryBuilder::default();
+        let db_rows: Vec<DatabaseScryfallData> = query_as(
+            "SELECT DISTINCT ON (COALESCE(sd.oracle_id, sd.id)) sd.* \
+             FROM scryfall_data sd \
+             JOIN card_profiles cp ON cp.scryfall_data_id = sd.id \
+             WHERE LOWER(sd.name) = ANY($1) \
+                OR LOWER(split_part(sd.name, ' // ', 1)) = ANY($1) \
+             ORDER BY COALESCE(sd.oracle_id, sd.id), \
+                      (sd.lang IS DISTINCT FROM $2) ASC, \
+                      (sd.digital IS DISTINCT FROM $3) ASC, \
+                      (sd.oversized IS DISTINCT FROM $4) ASC, \
+                      (sd.promo) ASC, \
+                      (COALESCE(sd.content_warning, false)) ASC, \
+                      sd.released_at DESC",
+        )
+        .bind(&lowered)
+        .bind(defaults.language())
+        .bind(defaults.digital())
+        .bind(defaults.oversized())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| SearchScryfallDataError::Database(e.into()))?;
+        let scryfall_data: Vec<ScryfallData> = db_rows
+            .into_iter()
+            .map(ScryfallData::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(SearchScryfallDataError::Database)?;
+        if scryfall_data.is_empty() {
+            return Ok(vec![]);
+        }
+        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
+        let card_profiles = self
+            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
+            .await?;
+        // Sleeve in name order so pairing an ambiguous front face is stable.
+        let mut cards = card_profiles.sleeve(scryfall_data);
+        cards.sort_by(|a, b| a.scryfall_data.name.cmp(&b.scryfall_data.name));
+        Ok(cards)
+    }
 
-        if let Some(query_string) = criteria.power_equals() {
-            sep.push("power ~ '^\\d+$' AND CAST(power AS INT) = ");
-            sep.push_bind_unseparated(query_string);
+    async fn find_cards_by_printings(
+        &self,
+        printings: &[(String, String)],
+    ) -> Result<Vec<Card>, SearchCardsError> {
+        if printings.is_empty() {
+            return Ok(vec![]);
+        }
+        let (sets, numbers): (Vec<String>, Vec<String>) = printings
+            .iter()
+            .map(|(set, number)| (set.to_lowercase(), number.clone()))
+            .unzip();
+        let db_rows: Vec<DatabaseScryfallData> = query_as(
+            "SELECT sd.* FROM scryfall_data sd \
+             JOIN UNNEST($1::text[], $2::text[]) AS p(set, collector_number) \
+               ON LOWER(sd.set) = p.set AND sd.collector_number = p.collector_number",
+        )
+        .bind(&sets)
+        .bind(&numbers)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| SearchScryfallDataError::Database(e.into()))?;
+        let scryfall_data: Vec<ScryfallData> = db_rows
+            .into_iter()
+            .map(ScryfallData::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(SearchScryfallDataError::Database)?;
+        if scryfall_data.is_empty() {
+            return Ok(vec![]);
         }
+        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
+        let card_profiles = self
+            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
+            .await?;
+        Ok(card_profiles.sleeve(scryfall_data))
+    }
 
-        if let Some(power_range) = criteria.power_range() {
-            let lower = power_range.0.min(power_range.1);
-            let higher = power_range.0.max(power_range.1);
-            sep.push("power ~ '^\\d+$' AND CAST(power AS INT) between ");
-            sep.push_bind_unseparated(lower);
-            sep.push_unseparated(" AND ");
-            sep.push_bind_unseparated(higher);
+    async fn search_cards_deck_aware(
+        &self,
+        request: &CardQuery,
+        context: DeckServeContext<'_>,
+    ) -> Result<Vec<Card>, SearchCardsError> {
+        // Card-level wrapper: forward the deck-serve context straight through
+        // (its callers never set a commander seed — that path is commander-select).
+        let scryfall_data = self
+            .search_scryfall_data_deck_aware(request, context)
+            .await?;
+        if scryfall_data.is_empty() {
+            return Ok(vec![]);
+        }
+        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
+        let card_profiles = self
+            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
+            .await?;
+        let cards = card_profiles.sleeve(scryfall_data);
+        Ok(cards)
+    }
+
+    /// First-class commander search (context/archive/commander_select_ordering.md):
+    /// decks-helmed popularity ordering, banded + wildcarded per user per day
+    /// (no deck required), token/emblem printings excluded. An explicit sort in
+    /// `request` still wins; the pool is always token-free. The shuffle seed is
+    /// `{user_id}:{date}`, mirroring the synergy serve's `{deck_id}:{date}`.
+    async fn search_commanders(
+        &self,
+        request: &CardQuery,
+        user_id: uuid::Uuid,
+    ) -> Result<Vec<Card>, SearchCardsError> {
+        let seed = format!("{user_id}:{}", Utc::now().date_naive());
+        let scryfall_data = self
+            .search_scryfall_data_deck_aware(
+                request,
+                DeckServeContext {
+                    commander_seed: Some(seed),
+                    ..Default::default()
+                },
+            )
+            .await?;
+        if scryfall_data.is_empty() {
+            return Ok(vec![]);
         }
+        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
+        let card_profiles = self
+            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
+            .await?;
+        let cards = card_profiles.sleeve(scryfall_data);
+        Ok(cards)
+    }
+
+    async fn commander_synergy_payload(
+        &self,
+        commander_printing_id: uuid::Uuid,
+    ) -> Result<Option<serde_json::Value>, SearchCardsError> {
+        let payload: Option<serde_json::Value> = sqlx::query_scalar(
+            "SELECT cs.payload FROM scryfall_data s
+             JOIN commander_synergy cs ON cs.oracle_id = s.oracle_id
+             WHERE s.id = $1",
+        )
+        .bind(commander_printing_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| SearchScryfallDataError::Database(e.into()))?;
+        Ok(payload)
+    }
+
+    // ====================
+    //  category derivation
+    // ====================
 
-        if let Some(query_string) = criteria.toughness_equals() {
-            sep.push("toughness ~ '^\\d+$' AND CAST(toughness AS INT) = ");
-            sep.push_bind_unseparated(query_string);
+    async fn get_cards_batch(&self, ids: &[uuid::Uuid]) -> Result<Vec<Card>, anyhow::Error> {
+        if ids.is_empty() {
+            return Ok(vec![]);
         }
+        let scryfall_data_ids: ScryfallDataIds = ids.iter().copied().collect();
+        self.get_cards(&scryfall_data_ids)
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to fetch card batch: {e}"))
+    }
 
-        if let Some(toughness_range) = criteria.toughness_range() {
-            let lower = toughness_range.0.min(toughness_range.1);
-            let higher = toughness_range.0.max(toughness_range.1);
-            sep.push("toughness ~ '^\\d+$' AND CAST(toughness AS INT) between ");
-            sep.push_bind_unseparated(lower);
-            sep.push_unseparated(" AND ");
-            sep.push_bind_unseparated(higher);
+    async fn update_card_roles(
+        &self,
+        updates: &[(
+            uuid::Uuid,
+            Vec<zwipe_core::domain::card::card_role::CardRole>,
+        )],
+    ) -> Result<(), anyhow::Error> {
+        if updates.is_empty() {
+            return Ok(());
         }
 
-        if let Some(colors) = criteria.color_identity_equals() {
-            sep.push("color_identity @> ");
-            sep.push_bind_unseparated(colors.to_short_names());
-            sep.push("color_identity <@ ");
-            sep.push_bind_unseparated(colors.to_short_names());
-        }
+        let mut tx = self.pool.begin().await?;
 
-        if let Some(colors) = criteria.color_identity_within() {
-            sep.push("color_identity <@ ");
-            sep.push_bind_unseparated(colors.to_short_names());
+        for (id, categories) in updates {
+            let cat_strings: Vec<String> = categories.iter().map(|c| c.to_string()).collect();
+            let json = serde_json::to_value(&cat_strings)?;
+            sqlx::query!(
+                "UPDATE card_profiles SET card_roles = $1, updated_at = NOW() WHERE scryfall_data_id = $2",
+                json,
+                id
+            )
+            .execute(&mut *tx)
+            .await?;
         }
 
-        const STRIP_ORACLE: &str = "regexp_replace(oracle_text, '[^a-zA-Z0-9 ]', '', 'g') ILIKE ";
-
-        if let Some(query_string) = &criteria.oracle_text_contains() {
-            sep.push(STRIP_ORACLE);
-            sep.push_bind_unseparated(format!("%{}%", query_string));
-        }
+        tx.commit().await?;
+        Ok(())
+    }
 
-        if let Some(query_string_array) = &criteria.oracle_text_contains_any() {
-            sep.push(" (");
-            query_string_array
-                .iter()
-                .enumerate()
-                .for_each(|(i, query_string)| {
-                    if i > 0 {
-                        sep.push_unseparated(" OR ");
-                    }
-                    sep.push_unseparated(STRIP_ORACLE);
-                    sep.push_bind_unseparated(format!("%{}%", query_string));
-                });
-            sep.push_unseparated(") ");
-        }
+    async fn clear_all_categories(&self) -> Result<(), anyhow::Error> {
+        // Batch clear to avoid a single slow UPDATE on 100k+ rows
+        loop {
+            let result = sqlx::query!(
+                "UPDATE card_profiles SET card_roles = '[]'::jsonb
+                 WHERE scryfall_data_id IN (
+                     SELECT scryfall_data_id FROM card_profiles
+                     WHERE card_roles != '[]'::jsonb
+                     LIMIT 5000
+                 )"
+            )
+            .execute(&self.pool)
+            .await?;
 
-        if let Some(query_string_array) = &criteria.oracle_text_contains_all() {
-            for query_string in query_string_array.iter() {
-                sep.push(STRIP_ORACLE);
-                sep.push_bind_unseparated(format!("%{}%", query_string));
+            if result.rows_affected() == 0 {
+                break;
             }
         }
+        Ok(())
+    }
 
-        if let Some(query_string) = &criteria.oracle_text_not_contains() {
-            sep.push("(oracle_text IS NULL OR NOT (");
-            sep.push_unseparated(STRIP_ORACLE);
-            sep.push_bind_unseparated(format!("%{}%", query_string));
-            sep.push_unseparated("))");
-        }
-
-        if let Some(query_string_array) = &criteria.oracle_text_excludes_any() {
-            sep.push("(oracle_text IS NULL OR NOT (");
-            query_string_array
-                .iter()
-                .enumerate()
-                .for_each(|(i, query_string)| {
-                    if i > 0 {
-                        sep.push_unseparated(" OR ");
-                    }
-                    sep.push_unseparated(STRIP_ORACLE);
-                    sep.push_bind_unseparated(format!("%{}%", query_string));
-                });
-            sep.push_unseparated(")) ");
-        }
+    async fn derive_oracle_tag_categories(&self) -> anyhow::Result<u64> {
+        crate::outbound::sqlx::card::helpers::derive_categories::derive_categories(&self.pool).await
+    }
 
-        // Keywords are stored capitalized (e.g. "Flying") but UI lowercases them.
-        // Use array_lowercase() via subquery to compare case-insensitively.
-        if let Some(keywords) = &criteria.keywords_contains_any() {
-            sep.push("(SELECT array_agg(lower(k)) FROM unnest(keywords) k) && ARRAY[");
-            keywords.iter().enumerate().for_each(|(i, kw)| {
-                if i > 0 {
-                    sep.push_unseparated(", ");
-                }
-                sep.push_bind_unseparated(kw.to_lowercase());
-            });
-            sep.push_unseparated("]::text[]");
-        }
+    async fn refresh_oracle_tag_groups(&self) -> anyhow::Result<u64> {
+        crate::outbound::sqlx::card::helpers::oracle_tag_groups::refresh_oracle_tag_groups(
+            &self.pool,
+        )
+        .await
+    }
 
-        if let Some(keywords) = &criteria.keywords_contains_all() {
-            sep.push("(SELECT array_agg(lower(k)) FROM unnest(keywords) k) @> ARRAY[");
-            keywords.iter().enumerate().for_each(|(i, kw)| {
-                if i > 0 {
-                    sep.push_unseparated(", ");
-                }
-                sep.push_bind_unseparated(kw.to_lowercase());
-            });
-            sep.push_unseparated("]::text[]");
-        }
+    async fn get_card_ids_with_oracle_text(&self) -> Result<Vec<uuid::Uuid>, anyhow::Error> {
+        let ids: Vec<uuid::Uuid> = sqlx::query_scalar(
+            "SELECT cp.scryfall_data_id FROM card_profiles cp \
+             JOIN scryfall_data sd ON cp.scryfall_data_id = sd.id \
+             WHERE sd.oracle_text IS NOT NULL AND sd.oracle_id IS NOT NULL",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(ids)
+    }
 
-        if let Some(keywords) = &criteria.keywords_excludes() {
-            sep.push("(keywords IS NULL OR NOT ((SELECT array_agg(lower(k)) FROM unnest(keywords) k) && ARRAY[");
-            keywords.iter().enumerate().for_each(|(i, kw)| {
-                if i > 0 {
-                    sep.push_unseparated(", ");
-                }
-                sep.push_bind_unseparated(kw.to_lowercase());
-            });
-            sep.push_unseparated("]::text[]))");
-        }
+    // =============
+    //  considering
+    // =============
 
-        if let Some(colors) = &criteria.produced_mana_contains_any() {
-            sep.push("produced_mana && ARRAY[");
-            colors.iter().enumerate().for_each(|(i, c)| {
-                if i > 0 {
-                    sep.push_unseparated(", ");
-                }
-                sep.push_bind_unseparated(c.to_uppercase());
-            });
-            sep.push_unseparated("]::text[]");
+    async fn add_to_considering(
+        &self,
+        user_id: uuid::Uuid,
+        scryfall_data_id: uuid::Uuid,
+    ) -> Result<(), ConsideringError> {
+        // INSERT … SELECT so an unknown card inserts nothing instead of
+        // tripping the FK; ON CONFLICT makes a re-add a no-op.
+        let inserted = sqlx::query(
+            "INSERT INTO user_considering (user_id, scryfall_data_id)
+             SELECT $1, id FROM scryfall_data WHERE id = $2
+             ON CONFLICT (user_id, scryfall_data_id) DO NOTHING",
+        )
+        .bind(user_id)
+        .bind(scryfall_data_id)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+        if inserted == 0 {
+            let exists: bool =
+                query_scalar("SELECT EXISTS (SELECT 1 FROM scryfall_data WHERE id = $1)")
+                    .bind(scryfall_data_id)
+                    .fetch_one(&self.pool)
+                    .await?;
+            if !exists {
+                return Err(ConsideringError::CardNotFound);
+            }
         }
+        Ok(())
+    }
 
-        if let Some(colors) = &criteria.produced_mana_contains_all() {
-            sep.push("produced_mana @> ARRAY[");
-            colors.iter().enumerate().for_each(|(i, c)| {
-                if i > 0 {
-                    sep.push_unseparated(", ");
-                }
-                sep.push_bind_unseparated(c.to_uppercase());
-            });
-            sep.push_unseparated("]::text[]");
+    async fn list_considering(&self, user_id: uuid::Uuid) -> Result<Vec<Card>, ConsideringError> {
+        let db_rows: Vec<DatabaseScryfallData> = query_as(
+            "SELECT sd.* FROM user_considering uc
+             JOIN scryfall_data sd ON sd.id = uc.scryfall_data_id
+             WHERE uc.user_id = $1
+             ORDER BY uc.added_at DESC, sd.name ASC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        let scryfall_data: Vec<ScryfallData> = db_rows
+            .into_iter()
+            .map(ScryfallData::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(ConsideringError::Database)?;
+        if scryfall_data.is_empty() {
+            return Ok(vec![]);
         }
+        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
+        let card_profiles = self
+            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
+            .await
+            .map_err(|e| ConsideringError::Database(e.into()))?;
+        Ok(card_profiles.sleeve(scryfall_data))
+    }
 
-        if let Some(colors) = &criteria.produced_mana_excludes() {
-            sep.push("(produced_mana IS NULL OR NOT (produced_mana && ARRAY[");
-            colors.iter().enumerate().for_each(|(i, c)| {
-                if i > 0 {
-                    sep.push_unseparated(", ");
-                }
-                sep.push_bind_unseparated(c.to_uppercase());
-            });
-            sep.push_unseparated("]::text[]))");
-        }
+    async fn remove_from_considering(
+        &self,
+        user_id: uuid::Uuid,
+        scryfall_data_id: uuid::Uuid,
+    ) -> Result<(), ConsideringError> {
+        sqlx::query("DELETE FROM user_considering WHERE user_id = $1 AND scryfall_data_id = $2")
+            .bind(user_id)
+            .bind(scryfall_data_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
 
-        if let Some(query_string) = &criteria.flavor_text_contains() {
-            sep.push("regexp_replace(flavor_text, '[^a-zA-Z0-9 ]', '', 'g') ILIKE ");
-            sep.push_bind_unseparated(format!("%{}%", query_string));
-        }
+    // ===========
+    //  favorites
+    // ===========
 
-        if let Some(query_string) = &criteria.flavor_text_not_contains() {
-            sep.push("(flavor_text IS NULL OR NOT (regexp_replace(flavor_text, '[^a-zA-Z0-9 ]', '', 'g') ILIKE ");
-            sep.push_bind_unseparated(format!("%{}%", query_string));
-            sep.push_unseparated("))");
-        }
+    async fn set_favorites(
+        &self,
+        user_id: uuid::Uuid,
+        changes: &[(uuid::Uuid, bool)],
+    ) -> Result<(), FavoritesError> {
+        let (on, off): (Vec<_>, Vec<_>) = changes.iter().partition(|(_, favorite)| *favorite);
+        let on: Vec<uuid::Uuid> = on.into_iter().map(|(id, _)| id).collect();
+        let off: Vec<uuid::Uuid> = off.into_iter().map(|(id, _)| id).collect();
 
-        if let Some(has_flavor_text) = criteria.has_flavor_text() {
-            if has_flavor_text {
-                sep.push("flavor_text IS NOT NULL AND flavor_text != ''");
-            } else {
-                sep.push("(flavor_text IS NULL OR flavor_text = '')");
+        let mut tx = self.pool.begin().await?;
+        if !on.is_empty() {
+            let known: i64 = query_scalar("SELECT COUNT(*) FROM scryfall_data WHERE id = ANY($1)")
+                .bind(&on)
+                .fetch_one(&mut *tx)
+                .await?;
+            if known != on.len() as i64 {
+                return Err(FavoritesError::CardNotFound);
             }
+            sqlx::query(
+                "INSERT INTO user_favorites (user_id, scryfall_data_id)
+                 SELECT $1, UNNEST($2::uuid[])
+                 ON CONFLICT (user_id, scryfall_data_id) DO NOTHING",
+            )
+            .bind(user_id)
+            .bind(&on)
+            .execute(&mut *tx)
+            .await?;
         }
-
-        // flag filters
-        if let Some(is_tok) = criteria.is_token() {
-            sep.push(" card_profiles.is_token = ");
-            sep.push_bind_unseparated(is_tok);
-        }
-
-        if let Some(is_playable) = criteria.is_playable()
-            && is_playable
-        {
-            // Only playable layouts
-            sep.push("latest_cards.layout = ANY(");
-            sep.push_bind_unseparated(PLAYABLE_LAYOUTS);
-            sep.push_unseparated(")");
-        } else if let Some(is_playable) = criteria.is_playable()
-            && !is_playable
-        {
-            // Only non-playable layouts
-            sep.push("latest_cards.layout != ALL(");
-            sep.push_bind_unseparated(PLAYABLE_LAYOUTS);
-            sep.push_unseparated(")");
-        }
-
-        if let Some(is_digital) = criteria.digital() {
-            sep.push("latest_cards.digital = ");
-            sep.push_bind_unseparated(is_digital);
+        if !off.is_empty() {
+            sqlx::query(
+                "DELETE FROM user_favorites WHERE user_id = $1 AND scryfall_data_id = ANY($2)",
+            )
+            .bind(user_id)
+            .bind(&off)
+            .execute(&mut *tx)
+            .await?;
         }
+        tx.commit().await?;
+        Ok(())
+    }
 
-        if let Some(is_oversized) = criteria.oversized() {
-            sep.push("latest_cards.oversized = ");
-            sep.push_bind_unseparated(is_oversized);
+    async fn list_favorites(&self, user_id: uuid::Uuid) -> Result<Vec<Card>, FavoritesError> {
+        let db_rows: Vec<DatabaseScryfallData> = query_as(
+            "SELECT sd.* FROM user_favorites uf
+             JOIN scryfall_data sd ON sd.id = uf.scryfall_data_id
+             WHERE uf.user_id = $1
+             ORDER BY uf.favorited_at DESC, sd.name ASC",
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+        let scryfall_data: Vec<ScryfallData> = db_rows
+            .into_iter()
+            .map(ScryfallData::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(FavoritesError::Database)?;
+        if scryfall_data.is_empty() {
+            return Ok(vec![]);
         }
+        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
+        let card_profiles = self
+            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
+            .await
+            .map_err(|e| FavoritesError::Database(e.into()))?;
+        Ok(card_profiles.sleeve(scryfall_data))
+    }
 
-        if let Some(is_promo) = criteria.promo() {
-            sep.push("latest_cards.promo = ");
-            sep.push_bind_unseparated(is_promo);
-        }
+    // ============
+    //  collection
+    // ============
 
-        if let Some(has_warning) = criteria.content_warning() {
-            if has_warning {
-                sep.push("latest_cards.content_warning = true");
-            } else {
-                // Hide cards with warnings (include false OR null)
-                sep.push("(latest_cards.content_warning = false OR latest_cards.content_warning IS NULL)");
-            }
-        }
+    async fn resolve_oracle_ids(
+        &self,
+        ids: &[uuid::Uuid],
+    ) -> Result<HashMap<uuid::Uuid, uuid::Uuid>, ImportCollectionError> {
+        // An oracle id resolves to itself; otherwise the id must be a printing.
+        let rows: Vec<(uuid::Uuid, uuid::Uuid)> = query_as(
+            "SELECT i.id, COALESCE(o.oracle_id, p.oracle_id) \
+             FROM UNNEST($1::uuid[]) AS i(id) \
+             LEFT JOIN LATERAL ( \
+                 SELECT oracle_id FROM scryfall_data WHERE oracle_id = i.id LIMIT 1 \
+             ) o ON TRUE \
+             LEFT JOIN scryfall_data p ON p.id = i.id \
+             WHERE COALESCE(o.oracle_id, p.oracle_id) IS NOT NULL",
+        )
+        .bind(ids)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows.into_iter().collect())
+    }
 
-        if let Some(language) = criteria.language() {
-            sep.push("latest_cards.lang = ");
-            sep.push_bind_unseparated(language);
-        }
+    async fn set_owned_cards(
+        &self,
+        user_id: uuid::Uuid,
+        cards: &[(uuid::Uuid, i32)],
+    ) -> Result<(), ImportCollectionError> {
+        let (oracle_ids, quantities): (Vec<uuid::Uuid>, Vec<i32>) = cards.iter().copied().unzip();
+        query(
+            "INSERT INTO collection_cards (user_id, oracle_id, quantity) \
+             SELECT $1, c.oracle_id, c.quantity \
+             FROM UNNEST($2::uuid[], $3::int[]) AS c(oracle_id, quantity) \
+             ON CONFLICT (user_id, oracle_id) \
+             DO UPDATE SET quantity = EXCLUDED.quantity, updated_at = NOW()",
+        )
+        .bind(user_id)
+        .bind(oracle_ids)
+        .bind(quantities)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
 
-        if let Some(formats) = criteria.legalities_contains_any() {
-            sep.push("(");
-            for (i, format_key) in formats.iter().enumerate() {
-                if i > 0 {
-                    sep.push_unseparated(" OR ");
-                }
-                sep.push_unseparated("legalities->>");
-                sep.push_bind_unseparated(format_key.clone());
-                sep.push_unseparated(" IN ('legal', 'restricted')");
-            }
-            sep.push_unseparated(")");
+impl MyPostgres {
+    /// Converts full `scryfall_data` rows to cards, attaching each row's
+    /// card profile and keeping the row order.
+    async fn sleeve_rows(
+        &self,
+        db_rows: Vec<DatabaseScryfallData>,
+    ) -> Result<Vec<Card>, SearchCardsError> {
+        let scryfall_data: Vec<ScryfallData> = db_rows
+            .into_iter()
+            .map(ScryfallData::try_from)
+            .collect::<Result<_, _>>()
+            .map_err(SearchScryfallDataError::Database)?;
+        if scryfall_data.is_empty() {
+            return Ok(vec![]);
         }
+        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
+        let card_profiles = self
+            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
+            .await?;
+        Ok(card_profiles.sleeve(scryfall_data))
+    }
 
-        if let Some(format) = criteria.is_commander_in_format() {
-            match format {
-                // Legendary creature, legendary vehicle with P/T, or "can be your commander"
-                Format::Commander | Format::Duel | Format::Predh => {
-                    sep.push(
-                        "((type_line ILIKE '%Legendary%' AND type_line ILIKE '%Creature%') \
-                         OR (type_line ILIKE '%Legendary%' AND power IS NOT NULL AND toughness IS NOT NULL) \
-                         OR oracle_text ILIKE '%can be your commander%')",
-                    );
-                }
-                // Legendary creature or legendary planeswalker
-                Format::Brawl | Format::StandardBrawl | Format::HistoricBrawl => {
-                    sep.push(
-                        "(type_line ILIKE '%Legendary%' AND \
-                         (type_line ILIKE '%Creature%' OR type_line ILIKE '%Planeswalker%'))",
-                    );
-                }
-                // Uncommon creature — legendary or not. Two fixes here:
-                //   1. Rarity is stored as the short code ('U'), not the word
-                //      'uncommon' — the old literal matched nothing.
-                //   2. PDH eligibility is "has appeared at uncommon in ANY
-                //      printing", not "this cached printing is uncommon", so we
-                //      check all printings via scryfall_data (catches cards whose
-                //      preferred printing is common but were printed uncommon).
-                Format::PauperCommander => {
-                    sep.push(
-                        "(type_line ILIKE '%Creature%' AND EXISTS (\
-                         SELECT 1 FROM scryfall_data sd2 \
-                         WHERE sd2.oracle_id = latest_cards.oracle_id \
-                         AND sd2.rarity = 'U'))",
-                    );
-                }
-                // Any planeswalker
-                Format::Oathbreaker => {
-                    sep.push("type_line ILIKE '%Planeswalker%'");
-                }
-                // Non-commander formats: no filter (should not happen, but safe)
-                _ => {}
+    /// The card search engine behind [`CardRepository::search_scryfall_data_deck_aware`]
+    /// and [`CardRepository::search_card_summaries`], generic over the
+    /// projection: `columns` is the SELECT list (`latest_cards.*` for full
+    /// rows, [`SUMMARY_COLUMNS`] for summaries) and `R` the row it decodes to.
+    /// Filtering, ordering, and paging are identical for every projection.
+    async fn search_rows<R>(
+        &self,
+        request: &CardQuery,
+        context: DeckServeContext<'_>,
+        columns: &str,
+    ) -> Result<Vec<R>, SearchScryfallDataError>
+    where
+        R: for<'r> FromRow<'r, PgRow> + Send + Unpin,
+    {
+        // Deck-serving inputs arrive bundled; unpack them into the locals the
+        // QueryBuilder threads them into (never stored beyond this call).
+        let DeckServeContext {
+            deck_id,
+            exclude_oracle_ids,
+            synergy_scores,
+            synergy_only,
+            commander_seed,
+            deck_oracle_tags,
+            playable_layouts,
+        } = context;
+        // WHERE clauses read the predicate fields; LIMIT/OFFSET/ORDER BY read
+        // the query config — the CardCriteria/CardQuery split, mirrored here.
+        let criteria = request.criteria();
+        // Default synergy ordering (no explicit sort, score map present) gets
+        // the signal + jitter terms, which need the pooled rollup and the
+        // global rate in scope; every other path keeps the plain FROM.
+        let signal_ordering = request.sort().is_none() && synergy_scores.is_some();
+        // Commander-select mode: caller (search_commanders) supplied a shuffle
+        // seed. Token/emblem exclusion applies whenever this is set; popularity
+        // ordering + banding + wildcard apply only when the user hasn't pinned
+        // an explicit sort (an explicit sort still wins, as everywhere).
+        let commander_select = commander_seed.is_some();
+        let popularity_ordering = commander_select && request.sort().is_none();
+        // Wildcard serving (context/archive/wildcard_slot/): the banded serve
+        // reserves WILDCARD_SLOTS per page for deep-pool probes. It needs the
+        // ranked pool twice (band + deep slice), so the query becomes a CTE.
+        // Synergy seeds by deck; commander-select seeds by `commander_seed`.
+        let wildcard_serving =
+            WILDCARD_SLOTS > 0 && ((signal_ordering && deck_id.is_some()) || popularity_ordering);
+        // The (base + signal) score expression, shared by the wildcard CTE
+        // header and the plain signal ORDER BY below:
+        //   base: the commander's synergy score (unscored cards anchor below
+        //         the scored floor, see UNSCORED_ANCHOR)
+        //   signal: the pooled net-rate, shrunk toward and centered on the
+        //         global rate — a card with no impressions adds zero.
+        let push_score = |qb: &mut QueryBuilder<Postgres>, scores: &serde_json::Value| {
+            qb.push("COALESCE((");
+            qb.push_bind(scores.clone());
+            qb.push(format!(" ->> LOWER(name))::float8, {UNSCORED_ANCHOR})"));
+            qb.push(format!(
+                " + {W_SIGNAL} * ((COALESCE(sig.net, 0) + {SHRINK_K} * g.rate) \
+                   / (COALESCE(sig.shown, 0) + {SHRINK_K}) - g.rate)"
+            ));
+            // Oracle-tag term (Phase 4): a flat lift for cards whose otags overlap
+            // the deck's selected otags. Dormant when the deck selected none, so
+            // decks without otags keep byte-identical ordering. Mirrors the
+            // filter's `?|` jsonb-array overlap probe.
+            if !deck_oracle_tags.is_empty() {
+                qb.push(format!(
+                    " + {W_ORACLE_TAG} * (card_profiles.oracle_tags ?| "
+                ));
+                qb.push_bind(deck_oracle_tags.to_vec());
+                qb.push(")::int");
             }
-        }
-
-        // partner/background/spell filters
-        if let Some(true) = criteria.is_partner() {
-            sep.push(
-                "(type_line ILIKE '%Legendary%' AND type_line ILIKE '%Creature%' AND (\
-                 keywords @> ARRAY['Partner']::text[] \
-                 OR keywords @> ARRAY['Friends forever']::text[] \
-                 OR keywords @> ARRAY['Doctor''s companion']::text[] \
-                 OR oracle_text ILIKE '%partner with%'))",
+        };
+        // Banding/wildcard shuffle seed: synergy serves seed by deck+day,
+        // commander-select by the caller's `commander_seed` (user+day, no deck).
+        let band_seed: Option<String> = if popularity_ordering {
+            commander_seed.clone()
+        } else if signal_ordering {
+            deck_id.map(|d| format!("{d}:{}", Utc::now().date_naive()))
+        } else {
+            None
+        };
+        let mut qb: QueryBuilder<Postgres> = if wildcard_serving
+            && signal_ordering
+            && let (Some(scores), Some(seed)) = (synergy_scores, band_seed.clone())
+        {
+            // Synergy wildcard CTE: rank + shuffle + exposure computed once; the
+            // two slices at the end page it two ways. The predicate pushes below
+            // land inside the CTE, so filters, legality, and suppressions bound
+            // both slices.
+            let mut qb: QueryBuilder<Postgres> = QueryBuilder::new(format!(
+                "WITH pool AS (SELECT {columns}, row_number() OVER (ORDER BY "
+            ));
+            push_score(&mut qb, scores);
+            qb.push(
+                " DESC, name ASC, latest_cards.id) AS rn, \
+                     hashtext(COALESCE(latest_cards.oracle_id::text, '') || ",
             );
-        }
-
-        if let Some(true) = criteria.is_background() {
-            sep.push(
-                "(type_line ILIKE '%Legendary%' AND type_line ILIKE '%Enchantment%' \
-                 AND type_line ILIKE '%Background%')",
+            qb.push_bind(seed);
+            qb.push(
+                ") AS shuffle, COALESCE(sig.shown, 0) AS pool_shown
+             FROM latest_cards
+             JOIN card_profiles ON latest_cards.id = card_profiles.scryfall_data_id
+             LEFT JOIN card_signal_rollup sig ON sig.card_oracle_id = latest_cards.oracle_id
+             CROSS JOIN (SELECT COALESCE(SUM(net) / NULLIF(SUM(shown), 0), 0) AS rate
+                         FROM card_signal_rollup) g
+             WHERE ",
             );
-        }
-
-        if let Some(true) = criteria.is_signature_spell() {
-            sep.push("(type_line ILIKE '%Instant%' OR type_line ILIKE '%Sorcery%')");
-        }
-
-        // mechanical category filters
-        if let Some(categories) = criteria.card_roles_contains_any() {
-            sep.push("(card_profiles.card_roles ?| ");
-            sep.push_bind_unseparated(categories.to_vec());
-            sep.push_unseparated(")");
-        }
-
-        if let Some(categories) = criteria.card_roles_contains_all() {
-            let json = serde_json::to_value(categories).unwrap_or_default();
-            sep.push("(card_profiles.card_roles @> ");
-            sep.push_bind_unseparated(json);
-            sep.push_unseparated(")");
-        }
-
-        if let Some(categories) = criteria.card_roles_excludes() {
-            sep.push("NOT (card_profiles.card_roles ?| ");
-            sep.push_bind_unseparated(categories.to_vec());
-            sep.push_unseparated(")");
-        }
-
-        if let Some(tags) = criteria.oracle_tags_contains_any() {
-            sep.push("(card_profiles.oracle_tags ?| ");
-            sep.push_bind_unseparated(tags.to_vec());
-            sep.push_unseparated(")");
-        }
+            qb
+        } else if wildcard_serving
+            && popularity_ordering
+            && let Some(seed) = band_seed.clone()
+        {
+            // Commander-select wildcard CTE: same banded-pool + deep-probe
+            // machinery as the synergy arm, ranked by decks-helmed popularity
+            // instead of synergy score. The deep slice orders least-shown first
+            // using the first-party select signal; with no signal rows yet the
+            // COALESCE floors every pool_shown to 0 and the ordering collapses
+            // to the daily shuffle alone — byte-identical to the pre-signal
+            // behavior (context/archive/commander_select_signal.md §3). Both
+            // joins are 1:1 (PK on oracle_id) and aliased so no bare
+            // `name`/`oracle_id` collides with the shared WHERE filters.
+            let mut qb: QueryBuilder<Postgres> = QueryBuilder::new(format!(
+                "WITH pool AS (SELECT {columns}, row_number() OVER (ORDER BY "
+            ));
+            qb.push(POPULARITY_RANK);
+            qb.push(
+                ") AS rn, \
+                     hashtext(COALESCE(latest_cards.oracle_id::text, '') || ",
+            );
+            qb.push_bind(seed);
+            qb.push(format!(
+                ") AS shuffle, COALESCE(sel.sel_shown, 0) AS pool_shown
+             FROM latest_cards
+             JOIN card_profiles ON latest_cards.id = card_profiles.scryfall_data_id
+             {POPULARITY_JOIN}
+             {SELECT_SIGNAL_JOIN}
+             WHERE "
+            ));
+            qb
+        } else if signal_ordering {
+            QueryBuilder::new(format!(
+                "SELECT {columns} FROM latest_cards
+             JOIN card_profiles ON latest_cards.id = card_profiles.scryfall_data_id
+             LEFT JOIN card_signal_rollup sig ON sig.card_oracle_id = latest_cards.oracle_id
+             CROSS JOIN (SELECT COALESCE(SUM(net) / NULLIF(SUM(shown), 0), 0) AS rate
+                         FROM card_signal_rollup) g
+             WHERE ",
+            ))
+        } else if popularity_ordering {
+            // Non-wildcard commander-select (WILDCARD_SLOTS = 0 revert lever):
+            // popularity join for the banded ORDER BY below, no deep-probe CTE.
+            QueryBuilder::new(format!(
+                "SELECT {columns} FROM latest_cards
+             JOIN card_profiles ON latest_cards.id = card_profiles.scryfall_data_id
+             {POPULARITY_JOIN}
+             WHERE "
+            ))
+        } else {
+            QueryBuilder::new(format!(
+                "SELECT {columns} FROM latest_cards
+             JOIN card_profiles ON latest_cards.id = card_profiles.scryfall_data_id
+             WHERE ",
+            ))
+        };
+        let mut sep: Separated<Postgres, &'static str> = qb.separated(" AND ");
+        // Seed an always-true clause so the baked `WHERE` is valid even when no
+        // filter conditions are pushed (e.g. an empty filter). It also lets every
+        // real condition below rely on the `AND` separator: the first real push
+        // becomes the second element, so it is correctly prefixed with ` AND `.
+        sep.push("TRUE");
 
-        if let Some(tags) = criteria.oracle_tags_contains_all() {
-            let json = serde_json::to_value(tags).unwrap_or_default();
-            sep.push("(card_profiles.oracle_tags @> ");
-            sep.push_bind_unseparated(json);
-            sep.push_unseparated(")");
+        // Commander-select candidate pool must exclude token/emblem printings.
+        // The popularity table (written by the synergy worker) keys on the real
+        // card's oracle_id and excludes these layouts, so serving must agree —
+        // otherwise a same-named token could be offered as a commander and would
+        // never join a real deck. Applies to every commander search, even one
+        // with an explicit sort. (context/archive/commander_select_ordering.md §1.)
+        if commander_select {
+            sep.push("latest_cards.layout NOT IN ('token', 'double_faced_token', 'emblem')");
         }
 
-        if let Some(tags) = criteria.oracle_tags_excludes() {
-            sep.push("NOT (card_profiles.oracle_tags ?| ");
-            sep.push_bind_unseparated(tags.to_vec());
-            sep.push_unseparated(")");
-        }
+        push_criteria_filters(
+            &mut sep,
+            criteria,
+            playable_layouts.unwrap_or(PLAYABLE_LAYOUTS),
+        );
 
         // Deck-aware exclusion: omit cards already in the deck. Null-oracle
         // printings are kept — they can't match a deck's oracle_ids anyway,
@@ -1048,6 +1812,8 @@ impl CardRepository for MyPostgres {
             let null_filter = match order_by {
                 CardSortKey::Power => Some("power IS NOT NULL AND power ~ '^\\d+$'"),
                 CardSortKey::Toughness => Some("toughness IS NOT NULL AND toughness ~ '^\\d+$'"),
+                CardSortKey::Loyalty => Some("loyalty IS NOT NULL AND loyalty ~ '^\\d+$'"),
+                CardSortKey::Defense => Some("defense IS NOT NULL AND defense ~ '^\\d+$'"),
                 CardSortKey::PriceUsd => {
                     Some("prices->>'usd' IS NOT NULL AND prices->>'usd' != ''")
                 }
@@ -1057,6 +1823,7 @@ impl CardRepository for MyPostgres {
                 CardSortKey::PriceTix => {
                     Some("prices->>'tix' IS NOT NULL AND prices->>'tix' != ''")
                 }
+                CardSortKey::EdhrecRank => Some("edhrec_rank IS NOT NULL"),
                 _ => None,
             };
             if let Some(filter) = null_filter {
@@ -1064,9 +1831,31 @@ impl CardRepository for MyPostgres {
             }
         }
 
+        // Keyset page: resume strictly after the cursor's card. A cursor
+        // taken under another sort can't be resumed (validation rejects it
+        // at the edge); it's ignored here.
+        if let Some(cursor) = request
+            .after_cursor()
+            .filter(|c| request.sort() == Some(c.sort()) && request.then_sort().is_none())
+        {
+            push_after_cursor(&mut sep, cursor, request.ascending());
+        }
+
         // ORDER BY
         if let Some(order_by) = request.sort() {
             qb.push(" ORDER BY ");
+            // Relevance leads with the text search's rank, best match first
+            // whatever the direction; name breaks ties (and is the whole
+            // order when there's no text search to rank).
+            if order_by == CardSortKey::Relevance
+                && let Some(term) = criteria.text_search()
+            {
+                qb.push("ts_rank(");
+                qb.push(TEXT_SEARCH_DOCUMENT);
+                qb.push(", plainto_tsquery('english', ");
+                qb.push_bind(term.to_string());
+                qb.push(")) DESC, ");
+            }
             let col = match order_by {
                 CardSortKey::Name => "name",
                 CardSortKey::Cmc => "cmc",
@@ -1078,17 +1867,62 @@ impl CardRepository for MyPostgres {
                 CardSortKey::PriceEur => "(prices->>'eur')::NUMERIC",
                 CardSortKey::PriceTix => "(prices->>'tix')::NUMERIC",
                 CardSortKey::EdhrecRank => "edhrec_rank",
+                CardSortKey::Loyalty => "CAST(NULLIF(loyalty, '') AS INT)",
+                CardSortKey::Defense => "CAST(NULLIF(defense, '') AS INT)",
                 CardSortKey::Random => "RANDOM()",
+                CardSortKey::Relevance => "name",
             };
+            let direction = if request.ascending() { " ASC" } else { " DESC" };
             qb.push(col);
             if order_by != CardSortKey::Random {
-                qb.push(if request.ascending() { " ASC" } else { " DESC" });
+                qb.push(direction);
+            }
+            // The secondary key only breaks ties, so it doesn't get the primary
+            // key's NULL filter: non-numeric stats and missing prices become
+            // NULL, which Postgres sorts last ascending and first descending —
+            // the same place `CardSortKey::compare` puts them in memory.
+            if let Some(then_by) = request
+                .then_sort()
+                .filter(|k| *k != order_by && order_by != CardSortKey::Random)
+            {
+                let then_col = match then_by {
+                    CardSortKey::Name => Some("name"),
+                    CardSortKey::Cmc => Some("cmc"),
+                    CardSortKey::Power => {
+                        Some("CASE WHEN power ~ '^\\d+$' THEN CAST(power AS INT) END")
+                    }
+                    CardSortKey::Toughness => {
+                        Some("CASE WHEN toughness ~ '^\\d+$' THEN CAST(toughness AS INT) END")
+                    }
+                    CardSortKey::Rarity => Some("rarity"),
+                    CardSortKey::ReleasedAt => Some("released_at"),
+                    CardSortKey::PriceUsd => Some("NULLIF(prices->>'usd', '')::NUMERIC"),
+                    CardSortKey::PriceEur => Some("NULLIF(prices->>'eur', '')::NUMERIC"),
+                    CardSortKey::PriceTix => Some("NULLIF(prices->>'tix', '')::NUMERIC"),
+                    CardSortKey::EdhrecRank => Some("edhrec_rank"),
+                    CardSortKey::Loyalty => {
+                        Some("CASE WHEN loyalty ~ '^\\d+$' THEN CAST(loyalty AS INT) END")
+                    }
+                    CardSortKey::Defense => {
+                        Some("CASE WHEN defense ~ '^\\d+$' THEN CAST(defense AS INT) END")
+                    }
+                    // rejected by the builder; ignored if it arrives off the wire
+                    CardSortKey::Random => None,
+                    // a rank only leads; as a tiebreak it's ignored
+                    CardSortKey::Relevance => None,
+                };
+                if let Some(then_col) = then_col {
+                    qb.push(", ");
+                    qb.push(then_col);
+                    qb.push(direction);
+                }
             }
-            // edhrec_rank is nullable (obscure/new cards lack a rank): keep them
-            // but sort last in either direction, with a name tiebreak so paging
-            // through the unranked tail stays stable.
-            if order_by == CardSortKey::EdhrecRank {
-                qb.push(" NULLS LAST, name ASC");
+            // An id tiebreak keeps paging stable across shared values (a
+            // common mana value, the rare shared rank), and is the second
+            // half of the keyset a cursor resumes from.
+            if order_by != CardSortKey::Random {
+                qb.push(", latest_cards.id");
+                qb.push(direction);
             }
         } else if wildcard_serving {
             // Close the pool CTE and take two slices of it
@@ -1174,506 +2008,833 @@ impl CardRepository for MyPostgres {
             }
         }
 
-        // The wildcard CTE carries per-slice LIMIT/OFFSET above.
-        if !wildcard_serving {
-            qb.push(" LIMIT ");
-            qb.push_bind(request.limit().min(MAX_SEARCH_LIMIT) as i32);
+        // The wildcard CTE carries per-slice LIMIT/OFFSET above.
+        if !wildcard_serving {
+            qb.push(" LIMIT ");
+            qb.push_bind(request.limit().min(MAX_SEARCH_LIMIT) as i32);
+
+            // Guard the u32->i32 cast: a value above i32::MAX wraps negative, and
+            // Postgres rejects a negative OFFSET (errors the whole query). A
+            // cursor already positions the page.
+            if request.after_cursor().is_none() {
+                qb.push(" OFFSET ");
+                qb.push_bind(request.offset().min(i32::MAX as u32) as i32);
+            }
+        }
+
+        let mut rows: Vec<R> = qb.build_query_as().fetch_all(&self.pool).await?;
+
+        // Wildcard splice: the outer ORDER BY sorts probes after the band
+        // page, so any rows past the band width are wildcards — lift them to
+        // a fixed mid-hand position. A short band page (pool exhausted) can
+        // hide a probe inside the band width; it then simply serves at the
+        // tail, which is fine.
+        if wildcard_serving {
+            let band_limit = (request.limit().min(MAX_SEARCH_LIMIT) as usize)
+                .saturating_sub(WILDCARD_SLOTS as usize)
+                .max(1);
+            if rows.len() > band_limit {
+                let wildcards = rows.split_off(band_limit);
+                for wildcard in wildcards {
+                    let position = WILDCARD_POSITION.min(rows.len());
+                    rows.insert(position, wildcard);
+                }
+            }
+        }
+        Ok(rows)
+    }
+}
+
+/// Pushes the keyset clause for a page after `cursor`: the row's sort value
+/// and id, compared as a pair, must come after the cursor's in the sort
+/// direction. Each value expression matches the ORDER BY's, except that a
+/// missing mana value compares as infinity — where both orders put NULL.
+fn push_after_cursor(
+    sep: &mut Separated<'_, Postgres, &'static str>,
+    cursor: &Cursor,
+    ascending: bool,
+) {
+    let (column, cast) = match cursor.sort() {
+        CardSortKey::Name => ("name", "TEXT"),
+        CardSortKey::Cmc => ("COALESCE(cmc, 'Infinity'::FLOAT8)", "FLOAT8"),
+        CardSortKey::Power => ("CAST(NULLIF(power, '') AS INT)", "INT"),
+        CardSortKey::Toughness => ("CAST(NULLIF(toughness, '') AS INT)", "INT"),
+        CardSortKey::Loyalty => ("CAST(NULLIF(loyalty, '') AS INT)", "INT"),
+        CardSortKey::Defense => ("CAST(NULLIF(defense, '') AS INT)", "INT"),
+        CardSortKey::Rarity => ("rarity", "TEXT"),
+        CardSortKey::ReleasedAt => ("released_at", "DATE"),
+        CardSortKey::PriceUsd => ("(prices->>'usd')::NUMERIC", "NUMERIC"),
+        CardSortKey::PriceEur => ("(prices->>'eur')::NUMERIC", "NUMERIC"),
+        CardSortKey::PriceTix => ("(prices->>'tix')::NUMERIC", "NUMERIC"),
+        CardSortKey::EdhrecRank => ("edhrec_rank", "INT"),
+        // cursors never carry Random or Relevance
+        CardSortKey::Random | CardSortKey::Relevance => return,
+    };
+    let comparison = if ascending { ">" } else { "<" };
+    sep.push(format!("({column}, latest_cards.id) {comparison} (CAST("));
+    sep.push_bind_unseparated(cursor.value().to_string());
+    sep.push_unseparated(format!(" AS {cast}), "));
+    sep.push_bind_unseparated(cursor.id());
+    sep.push_unseparated(")");
+}
+
+/// Pushes an inclusive range filter on a power/toughness `column`.
+///
+/// Without a `floor` only plain integers match. With one, `*` counts as
+/// `floor` and `N+*` / `*+N` as `N + floor`, mirroring `CardCriteria::matches`;
+/// other variable shapes (`*²`, `7-*`) resolve to NULL and never match.
+fn push_combat_stat_range(
+    sep: &mut Separated<'_, Postgres, &'static str>,
+    column: &'static str,
+    range: (i32, i32),
+    floor: Option<i32>,
+) {
+    let lower = range.0.min(range.1);
+    let higher = range.0.max(range.1);
+    match floor {
+        None => {
+            sep.push(format!(
+                "{column} ~ '^\\d+$' AND CAST({column} AS INT) between "
+            ));
+        }
+        Some(floor) => {
+            // BIGINT so a huge floor can't overflow the addition
+            let floor = i64::from(floor);
+            sep.push(format!(
+                "(CASE WHEN {column} ~ '^\\d+$' THEN CAST({column} AS BIGINT) WHEN {column} = '*' THEN "
+            ));
+            sep.push_bind_unseparated(floor);
+            sep.push_unseparated(format!(
+                " WHEN {column} ~ '^\\d+\\+\\*$' THEN CAST(split_part({column}, '+', 1) AS BIGINT) + "
+            ));
+            sep.push_bind_unseparated(floor);
+            sep.push_unseparated(format!(
+                " WHEN {column} ~ '^\\*\\+\\d+$' THEN CAST(split_part({column}, '+', 2) AS BIGINT) + "
+            ));
+            sep.push_bind_unseparated(floor);
+            sep.push_unseparated(" END) between ");
+        }
+    }
+    sep.push_bind_unseparated(lower);
+    sep.push_unseparated(" AND ");
+    sep.push_bind_unseparated(higher);
+}
+
+/// Pushes one `AND`-separated WHERE clause per criterion set on `criteria` —
+/// the predicate half of every card search, shared by the paged serve and the
+/// aggregate queries (`color_distribution`). Expects `latest_cards` joined to
+/// `card_profiles` in scope, and must mirror `CardCriteria::matches_with_layouts`
+/// (`is_playable` checks the layout against `playable_layouts`).
+fn push_criteria_filters(
+    sep: &mut Separated<'_, Postgres, &'static str>,
+    criteria: &CardCriteria,
+    playable_layouts: &[&str],
+) {
+    // Strip punctuation from DB columns for punctuation-insensitive text search.
+    // The query values are already stripped by CardQueryBuilder setters.
+    const STRIP_NAME: &str = "regexp_replace(name, '[^a-zA-Z0-9 ]', '', 'g') ILIKE ";
+    const STRIP_TYPE: &str = "regexp_replace(type_line, '[^a-zA-Z0-9 ]', '', 'g') ILIKE ";
+
+    if let Some(query_string) = &criteria.name_contains() {
+        sep.push(STRIP_NAME);
+        sep.push_bind_unseparated(format!("%{}%", query_string));
+    }
+
+    if let Some(name) = criteria.name_equals() {
+        sep.push("LOWER(name) = LOWER(");
+        sep.push_bind_unseparated(name);
+        sep.push_unseparated(")");
+    }
+
+    // Stemmed full-text match OR a punctuation-insensitive substring in any
+    // field: the tsquery finds "goblins" in "Goblin" and ranks, the ILIKE
+    // half catches partial words and stopword-only terms the tsquery drops.
+    if let Some(term) = criteria.text_search() {
+        sep.push("(");
+        sep.push_unseparated(TEXT_SEARCH_DOCUMENT);
+        sep.push_unseparated(" @@ plainto_tsquery('english', ");
+        sep.push_bind_unseparated(term.to_string());
+        sep.push_unseparated(")");
+        for column in ["name", "type_line", "oracle_text", "flavor_text"] {
+            sep.push_unseparated(format!(
+                " OR regexp_replace(latest_cards.{column}, '[^a-zA-Z0-9 ]', '', 'g') ILIKE "
+            ));
+            sep.push_bind_unseparated(format!("%{term}%"));
+        }
+        sep.push_unseparated(")");
+    }
+
+    if let Some(query_string) = &criteria.name_not_contains() {
+        sep.push("NOT (");
+        sep.push_unseparated(STRIP_NAME);
+        sep.push_bind_unseparated(format!("%{}%", query_string));
+        sep.push_unseparated(")");
+    }
+
+    if let Some(query_string) = &criteria.type_line_contains() {
+        sep.push(STRIP_TYPE);
+        sep.push_bind_unseparated(format!("%{}%", query_string));
+    }
+
+    if let Some(query_string_array) = &criteria.type_line_contains_any() {
+        sep.push(" (");
+        query_string_array
+            .iter()
+            .enumerate()
+            .for_each(|(i, query_string)| {
+                if i > 0 {
+                    sep.push_unseparated(" OR ");
+                }
+                sep.push_unseparated(STRIP_TYPE);
+                sep.push_bind_unseparated(format!("%{}%", query_string));
+            });
+        sep.push_unseparated(") ");
+    }
+
+    if let Some(card_types) = &criteria.card_type_contains_any() {
+        sep.push(" (");
+        card_types.iter().enumerate().for_each(|(i, query_string)| {
+            if i > 0 {
+                sep.push_unseparated(" OR ");
+            }
+            sep.push_unseparated(STRIP_TYPE);
+            sep.push_bind_unseparated(format!("%{}%", query_string));
+        });
+        sep.push_unseparated(") ");
+    }
+
+    if let Some(query_string_array) = &criteria.type_line_contains_all() {
+        for query_string in query_string_array.iter() {
+            sep.push(STRIP_TYPE);
+            sep.push_bind_unseparated(format!("%{}%", query_string));
+        }
+    }
+
+    if let Some(card_types) = &criteria.card_type_contains_all() {
+        for card_type in card_types.iter() {
+            sep.push(STRIP_TYPE);
+            sep.push_bind_unseparated(format!("%{}%", card_type));
+        }
+    }
+
+    if let Some(query_string) = &criteria.type_line_not_contains() {
+        sep.push("(type_line IS NULL OR NOT (");
+        sep.push_unseparated(STRIP_TYPE);
+        sep.push_bind_unseparated(format!("%{}%", query_string));
+        sep.push_unseparated("))");
+    }
+
+    if let Some(query_string_array) = &criteria.type_line_excludes_any() {
+        sep.push("(type_line IS NULL OR NOT (");
+        query_string_array
+            .iter()
+            .enumerate()
+            .for_each(|(i, query_string)| {
+                if i > 0 {
+                    sep.push_unseparated(" OR ");
+                }
+                sep.push_unseparated(STRIP_TYPE);
+                sep.push_bind_unseparated(format!("%{}%", query_string));
+            });
+        sep.push_unseparated(")) ");
+    }
+
+    if let Some(card_types) = &criteria.card_type_excludes_any() {
+        sep.push("(type_line IS NULL OR NOT (");
+        card_types.iter().enumerate().for_each(|(i, query_string)| {
+            if i > 0 {
+                sep.push_unseparated(" OR ");
+            }
+            sep.push_unseparated(STRIP_TYPE);
+            sep.push_bind_unseparated(format!("%{}%", query_string));
+        });
+        sep.push_unseparated(")) ");
+    }
+
+    if let Some(sets) = criteria.set_equals_any() {
+        sep.push("set_name = ANY(");
+        sep.push_bind_unseparated(sets);
+        sep.push_unseparated(")");
+    }
+
+    if let Some(set_types) = criteria.set_type_equals_any() {
+        sep.push("set_type = ANY(");
+        sep.push_bind_unseparated(
+            set_types
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>(),
+        );
+        sep.push_unseparated(")");
+    }
+
+    if let Some(artists) = criteria.artist_equals_any() {
+        sep.push("artist = ANY(");
+        sep.push_bind_unseparated(artists);
+        sep.push_unseparated(")");
+    }
+
+    // A NULL frame_effects (standard frame) never overlaps, so it drops out.
+    if let Some(effects) = criteria.frame_effects_contains_any() {
+        sep.push("frame_effects && ");
+        sep.push_bind_unseparated(effects);
+    }
+
+    if let Some(borders) = criteria.border_color_equals_any() {
+        sep.push("border_color = ANY(");
+        sep.push_bind_unseparated(borders);
+        sep.push_unseparated(")");
+    }
+
+    // A NULL watermark never equals anything, so unmarked printings drop out.
+    if let Some(watermarks) = criteria.watermark_equals_any() {
+        sep.push("watermark = ANY(");
+        sep.push_bind_unseparated(watermarks);
+        sep.push_unseparated(")");
+    }
+
+    // A NULL games list (no known availability) never contains the game.
+    if let Some(game) = criteria.available_in_game() {
+        sep.push("games @> ARRAY[");
+        sep.push_bind_unseparated(game.as_str());
+        sep.push_unseparated("]::text[]");
+    }
+
+    if let Some(finish) = criteria.finish_available() {
+        sep.push("finishes @> ARRAY[");
+        sep.push_bind_unseparated(finish.as_str());
+        sep.push_unseparated("]::text[]");
+    }
+
+    // NULL promo_types (no promo types) never overlaps, so it drops out.
+    if let Some(promo_types) = criteria.promo_types_contains_any() {
+        sep.push("promo_types && ");
+        sep.push_bind_unseparated(promo_types);
+    }
+
+    if let Some(rarities) = criteria.rarity_equals_any() {
+        sep.push("rarity = ANY(");
+        sep.push_bind_unseparated(rarities.to_short_names());
+        sep.push_unseparated(")");
+    }
+
+    if let Some(sets) = criteria.set_excludes_any() {
+        sep.push("NOT (set_name = ANY(");
+        sep.push_bind_unseparated(sets);
+        sep.push_unseparated("))");
+    }
+
+    if let Some(artists) = criteria.artist_excludes_any() {
+        sep.push("(artist IS NULL OR NOT (artist = ANY(");
+        sep.push_bind_unseparated(artists);
+        sep.push_unseparated(")))");
+    }
+
+    if let Some(rarities) = criteria.rarity_excludes_any() {
+        sep.push("NOT (rarity = ANY(");
+        sep.push_bind_unseparated(rarities.to_short_names());
+        sep.push_unseparated("))");
+    }
+
+    if let Some(query_string) = criteria.cmc_equals() {
+        sep.push("cmc = ");
+        sep.push_bind_unseparated(query_string);
+    }
 
-            // Guard the u32->i32 cast: a value above i32::MAX wraps negative, and
-            // Postgres rejects a negative OFFSET (errors the whole query).
-            qb.push(" OFFSET ");
-            qb.push_bind(request.offset().min(i32::MAX as u32) as i32);
+    if let Some(cmc_range) = criteria.cmc_range() {
+        let lower = cmc_range.0.min(cmc_range.1);
+        let higher = cmc_range.0.max(cmc_range.1);
+        sep.push("cmc between ");
+        sep.push_bind_unseparated(lower);
+        sep.push("");
+        sep.push_bind_unseparated(higher);
+    }
+
+    if let Some(symbols) = criteria.mana_cost_contains() {
+        sep.push("mana_cost ILIKE ");
+        sep.push_bind_unseparated(format!("%{}%", symbols));
+    }
+
+    // Exact cost, order-insensitive: re-assemble the braced symbols sorted
+    // bytewise (COLLATE "C") to match ManaCost::canonical on the query side.
+    if let Some(canonical) = criteria.mana_cost_equals() {
+        sep.push(
+            "(SELECT string_agg(UPPER(m[1]), '' ORDER BY UPPER(m[1]) COLLATE \"C\") \
+             FROM regexp_matches(mana_cost, '(\\{[^{}]+\\})', 'g') AS m) = ",
+        );
+        sep.push_bind_unseparated(canonical.to_string());
+    }
+
+    // Price range against the selected currency's JSONB price on this
+    // printing (not the card's cheapest printing). NULLIF turns empty/missing
+    // prices into NULL (excluded — no cast error), matching the client
+    // predicate. json_key() is a fixed enum literal, not user input.
+    if criteria.price_min().is_some() || criteria.price_max().is_some() {
+        let col = format!(
+            "NULLIF(prices->>'{}', '')::FLOAT8",
+            criteria.price_currency().unwrap_or_default().json_key()
+        );
+        if let Some(min) = criteria.price_min() {
+            sep.push(format!("{col} >= "));
+            sep.push_bind_unseparated(min);
+        }
+        if let Some(max) = criteria.price_max() {
+            sep.push(format!("{col} <= "));
+            sep.push_bind_unseparated(max);
         }
+    }
 
-        let db_rows: Vec<DatabaseScryfallData> = qb.build_query_as().fetch_all(&self.pool).await?;
-        let mut scryfall_data: Vec<ScryfallData> = db_rows
-            .into_iter()
-            .map(ScryfallData::try_from)
-            .collect::<Result<_, _>>()
-            .map_err(SearchScryfallDataError::Database)?;
+    if let Some(edhrec_rank_range) = criteria.edhrec_rank_range() {
+        let lower = edhrec_rank_range.0.min(edhrec_rank_range.1);
+        let higher = edhrec_rank_range.0.max(edhrec_rank_range.1);
+        sep.push("edhrec_rank BETWEEN ");
+        sep.push_bind_unseparated(lower);
+        sep.push_unseparated(" AND ");
+        sep.push_bind_unseparated(higher);
+    }
 
-        // Wildcard splice: the outer ORDER BY sorts probes after the band
-        // page, so any rows past the band width are wildcards — lift them to
-        // a fixed mid-hand position. A short band page (pool exhausted) can
-        // hide a probe inside the band width; it then simply serves at the
-        // tail, which is fine.
-        if wildcard_serving {
-            let band_limit = (request.limit().min(MAX_SEARCH_LIMIT) as usize)
-                .saturating_sub(WILDCARD_SLOTS as usize)
-                .max(1);
-            if scryfall_data.len() > band_limit {
-                let wildcards = scryfall_data.split_off(band_limit);
-                for wildcard in wildcards {
-                    let position = WILDCARD_POSITION.min(scryfall_data.len());
-                    scryfall_data.insert(position, wildcard);
-                }
-            }
+    if let Some((after, before)) = criteria.released_range() {
+        if let Some(after) = after {
+            sep.push("released_at >= ");
+            sep.push_bind_unseparated(after);
+        }
+        if let Some(before) = before {
+            sep.push("released_at <= ");
+            sep.push_bind_unseparated(before);
         }
-        Ok(scryfall_data)
     }
 
-    async fn get_card(&self, request: &GetScryfallData) -> Result<Card, GetCardError> {
-        let scryfall_data = self.get_scryfall_data(request).await?;
-        let card_profile = self.get_card_profile_with_scryfall_data_id(request).await?;
-        let card = Card::new(card_profile, scryfall_data);
-        Ok(card)
+    if let Some(query_string) = criteria.power_equals() {
+        sep.push("power ~ '^\\d+$' AND CAST(power AS INT) = ");
+        sep.push_bind_unseparated(query_string);
     }
 
-    async fn get_cards(&self, request: &ScryfallDataIds) -> Result<Vec<Card>, GetCardError> {
-        let scryfall_data = self.get_multiple_scryfall_data(request).await?;
-        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
-        let card_profiles = self
-            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
-            .await?;
-        let cards = card_profiles.sleeve(scryfall_data);
-        Ok(cards)
+    if let Some(power_range) = criteria.power_range() {
+        push_combat_stat_range(sep, "power", power_range, criteria.variable_stat_floor());
     }
 
-    /// Returns all printings of a card by oracle_id, ordered by release date (newest first).
-    async fn get_printings(&self, oracle_id: uuid::Uuid) -> Result<Vec<Card>, GetCardError> {
-        let db_rows: Vec<DatabaseScryfallData> =
-            query_as("SELECT sd.* FROM scryfall_data sd JOIN card_profiles cp ON sd.id = cp.scryfall_data_id WHERE sd.oracle_id = $1 ORDER BY sd.released_at ASC")
-                .bind(oracle_id)
-                .fetch_all(&self.pool)
-                .await
-                .map_err(|e| GetScryfallDataError::Database(e.into()))?;
-        let scryfall_data: Vec<ScryfallData> = db_rows
-            .into_iter()
-            .map(ScryfallData::try_from)
-            .collect::<Result<_, _>>()
-            .map_err(GetScryfallDataError::Database)?;
-        if scryfall_data.is_empty() {
-            return Ok(vec![]);
-        }
-        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
-        let card_profiles = self
-            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
-            .await?;
-        let cards = card_profiles.sleeve(scryfall_data);
-        Ok(cards)
+    if let Some(query_string) = criteria.toughness_equals() {
+        sep.push("toughness ~ '^\\d+$' AND CAST(toughness AS INT) = ");
+        sep.push_bind_unseparated(query_string);
     }
 
-    /// Composes `search_scryfall_data` results with card profiles into `Card` values.
-    async fn search_cards(&self, request: &CardQuery) -> Result<Vec<Card>, SearchCardsError> {
-        let scryfall_data = self.search_scryfall_data(request).await?;
-        if scryfall_data.is_empty() {
-            return Ok(vec![]);
-        }
-        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
-        let card_profiles = self
-            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
-            .await?;
-        let cards = card_profiles.sleeve(scryfall_data);
-        Ok(cards)
+    if let Some(toughness_range) = criteria.toughness_range() {
+        push_combat_stat_range(
+            sep,
+            "toughness",
+            toughness_range,
+            criteria.variable_stat_floor(),
+        );
     }
 
-    /// Extracts distinct card types by tokenizing `type_line` with `STRING_TO_ARRAY`.
-    async fn get_card_types(&self) -> Result<Vec<String>, GetCardTypesError> {
-        // Stop words: see domain::card::models::search_card::stop_words::TYPE_STOP_WORDS
-        let card_types: Vec<String> = query_scalar!(
-            "SELECT DISTINCT subtype FROM (
-                SELECT TRIM(BOTH ':-?, ' FROM UNNEST(STRING_TO_ARRAY(type_line, ' '))) subtype
-                FROM latest_cards
-            ) subtypes
-            WHERE subtype NOT IN ('//', '-', 'the', 'of', 'and/or', 'you', 'you''ll')
-            ORDER BY subtype ASC"
-        )
-        .fetch_all(&self.pool)
-        .await?
-        .into_iter()
-        .flatten()
-        .collect();
-        Ok(card_types)
+    if let Some(is_variable) = criteria.power_is_variable() {
+        // `*` is literal in LIKE; NULL power (non-creatures) counts as fixed
+        sep.push("COALESCE(power LIKE '%*%', false) = ");
+        sep.push_bind_unseparated(is_variable);
     }
 
-    /// Returns distinct keyword abilities from the `keywords` array column.
-    async fn get_keywords(&self) -> Result<Vec<String>, GetKeywordsError> {
-        let keywords: Vec<String> = query_scalar!(
-            "SELECT DISTINCT LOWER(TRIM(keyword)) AS keyword
-             FROM latest_cards, UNNEST(keywords) AS keyword
-             WHERE keywords IS NOT NULL
-             ORDER BY keyword ASC"
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| GetKeywordsError::Database(e.into()))?
-        .into_iter()
-        .flatten()
-        .collect();
-        Ok(keywords)
+    if let Some(is_variable) = criteria.toughness_is_variable() {
+        sep.push("COALESCE(toughness LIKE '%*%', false) = ");
+        sep.push_bind_unseparated(is_variable);
     }
 
-    /// Returns the full oracle tag catalog: every row of `oracle_tags` with its
-    /// `parent_ids` resolved to parent slugs, ordered by slug.
-    async fn get_oracle_tags(&self) -> Result<Vec<OracleTag>, GetOracleTagsError> {
-        let oracle_tags: Vec<OracleTag> = query_as!(
-            DatabaseOracleTag,
-            r#"SELECT
-                 o.slug AS "slug!",
-                 o.label AS "label!",
-                 o.description,
-                 COALESCE(
-                     ARRAY(
-                         SELECT p.slug FROM oracle_tags p
-                         WHERE p.id = ANY(o.parent_ids)
-                         ORDER BY p.slug
-                     ),
-                     ARRAY[]::text[]
-                 ) AS "parent_slugs!"
-               FROM oracle_tags o
-               ORDER BY o.slug ASC"#
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| GetOracleTagsError::Database(e.into()))?
-        .into_iter()
-        .map(OracleTag::from)
-        .collect();
-        Ok(oracle_tags)
+    if let Some(loyalty) = criteria.loyalty_equals() {
+        sep.push("loyalty ~ '^\\d+$' AND CAST(loyalty AS INT) = ");
+        sep.push_bind_unseparated(loyalty);
     }
 
-    /// Returns distinct normalized words extracted from oracle text, noise-filtered.
-    async fn get_oracle_words(&self) -> Result<Vec<String>, GetOracleWordsError> {
-        // Stop words: see domain::card::models::search_card::stop_words::ORACLE_STOP_WORDS
-        let words: Vec<String> = query_scalar!(
-            "SELECT DISTINCT LOWER(REGEXP_REPLACE(word, '[^a-zA-Z]', '', 'g')) AS word
-             FROM latest_cards, REGEXP_SPLIT_TO_TABLE(oracle_text, '\\s+') AS word
-             WHERE oracle_text IS NOT NULL
-               AND LOWER(REGEXP_REPLACE(word, '[^a-zA-Z]', '', 'g')) NOT IN (
-                 'a', 'an', 'the', 'of', 'to', 'in', 'on', 'at', 'by', 'for', 'with',
-                 'from', 'into', 'as', 'and', 'or', 'but', 'that', 'which', 'who',
-                 'it', 'its', 'you', 'your', 'this', 'those', 'these', 'they', 'their',
-                 'is', 'are', 'was', 'be', 'has', 'have', 'do', 'does', 'been', ''
-               )
-             ORDER BY word ASC"
-        )
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| GetOracleWordsError::Database(e.into()))?
-        .into_iter()
-        .flatten()
-        .collect();
-        Ok(words)
+    if let Some(loyalty_range) = criteria.loyalty_range() {
+        let lower = loyalty_range.0.min(loyalty_range.1);
+        let higher = loyalty_range.0.max(loyalty_range.1);
+        sep.push("loyalty ~ '^\\d+$' AND CAST(loyalty AS INT) between ");
+        sep.push_bind_unseparated(lower);
+        sep.push_unseparated(" AND ");
+        sep.push_bind_unseparated(higher);
     }
 
-    async fn get_artists(&self) -> Result<Vec<String>, GetArtistsError> {
-        let artists: Vec<String> = query_scalar!(
-            "SELECT DISTINCT artist FROM latest_cards
-             WHERE artist IS NOT NULL
-             ORDER BY artist"
-        )
-        .fetch_all(&self.pool)
-        .await?
-        .into_iter()
-        .flatten()
-        .collect();
-        Ok(artists)
+    if let Some(is_variable) = criteria.loyalty_is_variable() {
+        // `X` (or any other non-numeric) loyalty; NULL counts as not variable
+        sep.push("COALESCE(loyalty !~ '^\\d+$', false) = ");
+        sep.push_bind_unseparated(is_variable);
     }
 
-    async fn get_sets(&self) -> Result<Vec<String>, GetSetsError> {
-        let sets: Vec<String> =
-            query_scalar!("SELECT DISTINCT set_name FROM latest_cards ORDER BY set_name")
-                .fetch_all(&self.pool)
-                .await?
-                .into_iter()
-                .flatten()
-                .collect();
-        Ok(sets)
+    if let Some(defense) = criteria.defense_equals() {
+        sep.push("defense ~ '^\\d+$' AND CAST(defense AS INT) = ");
+        sep.push_bind_unseparated(defense);
     }
 
-    async fn get_languages(&self) -> Result<Vec<String>, GetLanguagesError> {
-        let languages: Vec<String> = query_scalar!(
-            "SELECT DISTINCT lang FROM latest_cards
-             WHERE lang IS NOT NULL
-             ORDER BY lang"
-        )
-        .fetch_all(&self.pool)
-        .await?
-        .into_iter()
-        .flatten()
-        .collect();
-        Ok(languages)
+    if let Some(defense_range) = criteria.defense_range() {
+        let lower = defense_range.0.min(defense_range.1);
+        let higher = defense_range.0.max(defense_range.1);
+        sep.push("defense ~ '^\\d+$' AND CAST(defense AS INT) between ");
+        sep.push_bind_unseparated(lower);
+        sep.push_unseparated(" AND ");
+        sep.push_bind_unseparated(higher);
     }
 
-    async fn get_card_profile_with_id(
-        &self,
-        request: &GetCardProfile,
-    ) -> Result<CardProfile, GetCardProfileError> {
-        let card_profile: CardProfile = query_as!(
-            DatabaseCardProfile,
-            "SELECT scryfall_data_id, is_token, card_roles, oracle_tags, oracle_tags_by_role, other_oracle_tags, created_at, updated_at FROM card_profiles WHERE scryfall_data_id = $1",
-            **request
-        )
-        .fetch_one(&self.pool)
-        .await?
-        .into();
-        Ok(card_profile)
+    if let Some(colors) = criteria.color_identity_equals() {
+        sep.push("color_identity @> ");
+        sep.push_bind_unseparated(colors.to_short_names());
+        sep.push("color_identity <@ ");
+        sep.push_bind_unseparated(colors.to_short_names());
     }
 
-    async fn get_card_profile_with_scryfall_data_id(
-        &self,
-        request: &GetScryfallData,
-    ) -> Result<CardProfile, GetCardProfileError> {
-        let card_profile: CardProfile = query_as!(
-            DatabaseCardProfile,
-            "SELECT scryfall_data_id, is_token, card_roles, oracle_tags, oracle_tags_by_role, other_oracle_tags, created_at, updated_at
-            FROM card_profiles WHERE scryfall_data_id = $1",
-            **request
-        )
-        .fetch_one(&self.pool)
-        .await?
-        .into();
-        Ok(card_profile)
+    if let Some(colors) = criteria.color_identity_within() {
+        sep.push("color_identity <@ ");
+        sep.push_bind_unseparated(colors.to_short_names());
+    }
+
+    if let Some(count) = criteria.color_count_equals() {
+        sep.push(format!("{COLOR_COUNT} = "));
+        sep.push_bind_unseparated(i32::from(count));
+    }
+
+    if let Some((a, b)) = criteria.color_count_range() {
+        sep.push(format!("{COLOR_COUNT} BETWEEN "));
+        sep.push_bind_unseparated(i32::from(a.min(b)));
+        sep.push_unseparated(" AND ");
+        sep.push_bind_unseparated(i32::from(a.max(b)));
+    }
+
+    if let Some(multicolor) = criteria.is_multicolor() {
+        sep.push(format!("({COLOR_COUNT} > 1) = "));
+        sep.push_bind_unseparated(multicolor);
+    }
+
+    if let Some(colorless) = criteria.is_colorless() {
+        sep.push(format!("({COLOR_COUNT} = 0) = "));
+        sep.push_bind_unseparated(colorless);
+    }
+
+    const STRIP_ORACLE: &str = "regexp_replace(oracle_text, '[^a-zA-Z0-9 ]', '', 'g') ILIKE ";
+
+    if let Some(query_string) = &criteria.oracle_text_contains() {
+        sep.push(STRIP_ORACLE);
+        sep.push_bind_unseparated(format!("%{}%", query_string));
+    }
+
+    if let Some(query_string_array) = &criteria.oracle_text_contains_any() {
+        sep.push(" (");
+        query_string_array
+            .iter()
+            .enumerate()
+            .for_each(|(i, query_string)| {
+                if i > 0 {
+                    sep.push_unseparated(" OR ");
+                }
+                sep.push_unseparated(STRIP_ORACLE);
+                sep.push_bind_unseparated(format!("%{}%", query_string));
+            });
+        sep.push_unseparated(") ");
+    }
+
+    if let Some(query_string_array) = &criteria.oracle_text_contains_all() {
+        for query_string in query_string_array.iter() {
+            sep.push(STRIP_ORACLE);
+            sep.push_bind_unseparated(format!("%{}%", query_string));
+        }
+    }
+
+    if let Some(query_string) = &criteria.oracle_text_not_contains() {
+        sep.push("(oracle_text IS NULL OR NOT (");
+        sep.push_unseparated(STRIP_ORACLE);
+        sep.push_bind_unseparated(format!("%{}%", query_string));
+        sep.push_unseparated("))");
+    }
+
+    if let Some(query_string_array) = &criteria.oracle_text_excludes_any() {
+        sep.push("(oracle_text IS NULL OR NOT (");
+        query_string_array
+            .iter()
+            .enumerate()
+            .for_each(|(i, query_string)| {
+                if i > 0 {
+                    sep.push_unseparated(" OR ");
+                }
+                sep.push_unseparated(STRIP_ORACLE);
+                sep.push_bind_unseparated(format!("%{}%", query_string));
+            });
+        sep.push_unseparated(")) ");
+    }
+
+    // Keywords are stored capitalized (e.g. "Flying") but UI lowercases them.
+    // Use array_lowercase() via subquery to compare case-insensitively.
+    if let Some(keywords) = &criteria.keywords_contains_any() {
+        sep.push("(SELECT array_agg(lower(k)) FROM unnest(keywords) k) && ARRAY[");
+        keywords.iter().enumerate().for_each(|(i, kw)| {
+            if i > 0 {
+                sep.push_unseparated(", ");
+            }
+            sep.push_bind_unseparated(kw.to_lowercase());
+        });
+        sep.push_unseparated("]::text[]");
+    }
+
+    if let Some(keywords) = &criteria.keywords_contains_all() {
+        sep.push("(SELECT array_agg(lower(k)) FROM unnest(keywords) k) @> ARRAY[");
+        keywords.iter().enumerate().for_each(|(i, kw)| {
+            if i > 0 {
+                sep.push_unseparated(", ");
+            }
+            sep.push_bind_unseparated(kw.to_lowercase());
+        });
+        sep.push_unseparated("]::text[]");
     }
 
-    async fn get_card_profiles_with_ids(
-        &self,
-        request: &CardProfileIds,
-    ) -> Result<Vec<CardProfile>, GetCardProfileError> {
-        let card_profiles: Vec<CardProfile> = query_as!(
-            DatabaseCardProfile,
-            "SELECT scryfall_data_id, is_token, card_roles, oracle_tags, oracle_tags_by_role, other_oracle_tags, created_at, updated_at
-            FROM card_profiles WHERE scryfall_data_id = ANY($1)",
-            &**request
-        )
-        .fetch_all(&self.pool)
-        .await?
-        .into_iter()
-        .map(|dcp| dcp.into())
-        .collect();
-        Ok(card_profiles)
+    if let Some(keywords) = &criteria.keywords_excludes() {
+        sep.push("(keywords IS NULL OR NOT ((SELECT array_agg(lower(k)) FROM unnest(keywords) k) && ARRAY[");
+        keywords.iter().enumerate().for_each(|(i, kw)| {
+            if i > 0 {
+                sep.push_unseparated(", ");
+            }
+            sep.push_bind_unseparated(kw.to_lowercase());
+        });
+        sep.push_unseparated("]::text[]))");
     }
 
-    async fn get_card_profiles_with_scryfall_data_ids(
-        &self,
-        request: &ScryfallDataIds,
-    ) -> Result<Vec<CardProfile>, GetCardProfileError> {
-        let card_profiles: Vec<CardProfile> = query_as!(
-            DatabaseCardProfile,
-            "SELECT scryfall_data_id, is_token, card_roles, oracle_tags, oracle_tags_by_role, other_oracle_tags, created_at, updated_at
-            FROM card_profiles WHERE scryfall_data_id = ANY($1)",
-            &**request
-        )
-        .fetch_all(&self.pool)
-        .await?
-        .into_iter()
-        .map(|dcp| dcp.into())
-        .collect();
-        Ok(card_profiles)
+    if let Some(colors) = &criteria.produced_mana_contains_any() {
+        sep.push("produced_mana && ARRAY[");
+        colors.iter().enumerate().for_each(|(i, c)| {
+            if i > 0 {
+                sep.push_unseparated(", ");
+            }
+            sep.push_bind_unseparated(c.to_uppercase());
+        });
+        sep.push_unseparated("]::text[]");
     }
 
-    async fn get_last_sync_date(&self) -> anyhow::Result<Option<DateTime<Utc>>> {
-        let last_sync_date: Option<DateTime<Utc>> = query_scalar(
-            "SELECT started_at FROM zervice_metrics
-            ORDER BY started_at DESC LIMIT 1",
-        )
-        .fetch_optional(&self.pool)
-        .await
-        .context("failed to get last sync date")?;
-        Ok(last_sync_date)
+    if let Some(colors) = &criteria.produced_mana_contains_all() {
+        sep.push("produced_mana @> ARRAY[");
+        colors.iter().enumerate().for_each(|(i, c)| {
+            if i > 0 {
+                sep.push_unseparated(", ");
+            }
+            sep.push_bind_unseparated(c.to_uppercase());
+        });
+        sep.push_unseparated("]::text[]");
     }
 
-    async fn find_cards_by_exact_names(
-        &self,
-        names: &[String],
-    ) -> Result<Vec<Card>, SearchCardsError> {
-        if names.is_empty() {
-            return Ok(vec![]);
-        }
-        // Match the full name, and also the front face of double-faced cards, so
-        // "Boggart Trawler // Boggart Bog" resolves whether the entry is the full
-        // name, just the front ("Boggart Trawler"), or a "/" spelling. Each entry
-        // contributes both itself and its pre-slash front to the match set, and
-        // the query checks each card's full name and its front face.
-        let mut lowered: std::collections::HashSet<String> = std::collections::HashSet::new();
-        for name in names {
-            let lower = name.to_lowercase();
-            let front = entry_front_face(&lower);
-            if !front.is_empty() {
-                lowered.insert(front.to_string());
+    if let Some(colors) = &criteria.produced_mana_excludes() {
+        sep.push("(produced_mana IS NULL OR NOT (produced_mana && ARRAY[");
+        colors.iter().enumerate().for_each(|(i, c)| {
+            if i > 0 {
+                sep.push_unseparated(", ");
             }
-            lowered.insert(lower);
-        }
-        let lowered: Vec<String> = lowered.into_iter().collect();
-        // ORDER BY makes the row order stable, so when an entry is ambiguous
-        // (two double-faced cards share a front face) resolution is deterministic.
-        let db_rows: Vec<DatabaseScryfallData> = query_as(
-            "SELECT * FROM latest_cards \
-             WHERE LOWER(name) = ANY($1) \
-                OR LOWER(split_part(name, ' // ', 1)) = ANY($1) \
-             ORDER BY name",
-        )
-        .bind(&lowered)
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| SearchScryfallDataError::Database(e.into()))?;
-        let scryfall_data: Vec<ScryfallData> = db_rows
-            .into_iter()
-            .map(ScryfallData::try_from)
-            .collect::<Result<_, _>>()
-            .map_err(SearchScryfallDataError::Database)?;
-        if scryfall_data.is_empty() {
-            return Ok(vec![]);
-        }
-        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
-        let card_profiles = self
-            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
-            .await?;
-        let cards = card_profiles.sleeve(scryfall_data);
-        Ok(cards)
+            sep.push_bind_unseparated(c.to_uppercase());
+        });
+        sep.push_unseparated("]::text[]))");
     }
 
-    async fn search_cards_deck_aware(
-        &self,
-        request: &CardQuery,
-        context: DeckServeContext<'_>,
-    ) -> Result<Vec<Card>, SearchCardsError> {
-        // Card-level wrapper: forward the deck-serve context straight through
-        // (its callers never set a commander seed — that path is commander-select).
-        let scryfall_data = self
-            .search_scryfall_data_deck_aware(request, context)
-            .await?;
-        if scryfall_data.is_empty() {
-            return Ok(vec![]);
-        }
-        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
-        let card_profiles = self
-            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
-            .await?;
-        let cards = card_profiles.sleeve(scryfall_data);
-        Ok(cards)
+    if let Some(query_string) = &criteria.flavor_text_contains() {
+        sep.push("regexp_replace(flavor_text, '[^a-zA-Z0-9 ]', '', 'g') ILIKE ");
+        sep.push_bind_unseparated(format!("%{}%", query_string));
     }
 
-    /// First-class commander search (context/archive/commander_select_ordering.md):
-    /// decks-helmed popularity ordering, banded + wildcarded per user per day
-    /// (no deck required), token/emblem printings excluded. An explicit sort in
-    /// `request` still wins; the pool is always token-free. The shuffle seed is
-    /// `{user_id}:{date}`, mirroring the synergy serve's `{deck_id}:{date}`.
-    async fn search_commanders(
-        &self,
-        request: &CardQuery,
-        user_id: uuid::Uuid,
-    ) -> Result<Vec<Card>, SearchCardsError> {
-        let seed = format!("{user_id}:{}", Utc::now().date_naive());
-        let scryfall_data = self
-            .search_scryfall_data_deck_aware(
-                request,
-                DeckServeContext {
-                    commander_seed: Some(seed),
-                    ..Default::default()
-                },
-            )
-            .await?;
-        if scryfall_data.is_empty() {
-            return Ok(vec![]);
+    if let Some(query_string) = &criteria.flavor_text_not_contains() {
+        sep.push("(flavor_text IS NULL OR NOT (regexp_replace(flavor_text, '[^a-zA-Z0-9 ]', '', 'g') ILIKE ");
+        sep.push_bind_unseparated(format!("%{}%", query_string));
+        sep.push_unseparated("))");
+    }
+
+    if let Some(has_flavor_text) = criteria.has_flavor_text() {
+        if has_flavor_text {
+            sep.push("flavor_text IS NOT NULL AND flavor_text != ''");
+        } else {
+            sep.push("(flavor_text IS NULL OR flavor_text = '')");
         }
-        let scryfall_data_ids: ScryfallDataIds = scryfall_data.as_slice().into();
-        let card_profiles = self
-            .get_card_profiles_with_scryfall_data_ids(&scryfall_data_ids)
-            .await?;
-        let cards = card_profiles.sleeve(scryfall_data);
-        Ok(cards)
     }
 
-    async fn commander_synergy_payload(
-        &self,
-        commander_printing_id: uuid::Uuid,
-    ) -> Result<Option<serde_json::Value>, SearchCardsError> {
-        let payload: Option<serde_json::Value> = sqlx::query_scalar(
-            "SELECT cs.payload FROM scryfall_data s
-             JOIN commander_synergy cs ON cs.oracle_id = s.oracle_id
-             WHERE s.id = $1",
-        )
-        .bind(commander_printing_id)
-        .fetch_optional(&self.pool)
-        .await
-        .map_err(|e| SearchScryfallDataError::Database(e.into()))?;
-        Ok(payload)
+    // flag filters
+    if let Some(is_tok) = criteria.is_token() {
+        sep.push(" card_profiles.is_token = ");
+        sep.push_bind_unseparated(is_tok);
     }
 
-    // ====================
-    //  category derivation
-    // ====================
+    if let Some(is_playable) = criteria.is_playable() {
+        // Only playable layouts, or only non-playable ones
+        sep.push(if is_playable {
+            "latest_cards.layout = ANY("
+        } else {
+            "latest_cards.layout != ALL("
+        });
+        sep.push_bind_unseparated(
+            playable_layouts
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<String>>(),
+        );
+        sep.push_unseparated(")");
+    }
 
-    async fn get_cards_batch(&self, ids: &[uuid::Uuid]) -> Result<Vec<Card>, anyhow::Error> {
-        if ids.is_empty() {
-            return Ok(vec![]);
-        }
-        let scryfall_data_ids: ScryfallDataIds = ids.iter().copied().collect();
-        self.get_cards(&scryfall_data_ids)
-            .await
-            .map_err(|e| anyhow::anyhow!("failed to fetch card batch: {e}"))
+    if let Some(is_digital) = criteria.digital() {
+        sep.push("latest_cards.digital = ");
+        sep.push_bind_unseparated(is_digital);
     }
 
-    async fn update_card_roles(
-        &self,
-        updates: &[(
-            uuid::Uuid,
-            Vec<zwipe_core::domain::card::card_role::CardRole>,
-        )],
-    ) -> Result<(), anyhow::Error> {
-        if updates.is_empty() {
-            return Ok(());
-        }
+    if let Some(is_oversized) = criteria.oversized() {
+        sep.push("latest_cards.oversized = ");
+        sep.push_bind_unseparated(is_oversized);
+    }
 
-        let mut tx = self.pool.begin().await?;
+    if let Some(is_promo) = criteria.promo() {
+        sep.push("latest_cards.promo = ");
+        sep.push_bind_unseparated(is_promo);
+    }
 
-        for (id, categories) in updates {
-            let cat_strings: Vec<String> = categories.iter().map(|c| c.to_string()).collect();
-            let json = serde_json::to_value(&cat_strings)?;
-            sqlx::query!(
-                "UPDATE card_profiles SET card_roles = $1, updated_at = NOW() WHERE scryfall_data_id = $2",
-                json,
-                id
-            )
-            .execute(&mut *tx)
-            .await?;
+    if let Some(has_warning) = criteria.content_warning() {
+        if has_warning {
+            sep.push("latest_cards.content_warning = true");
+        } else {
+            // Hide cards with warnings (include false OR null)
+            sep.push(
+                "(latest_cards.content_warning = false OR latest_cards.content_warning IS NULL)",
+            );
         }
+    }
 
-        tx.commit().await?;
-        Ok(())
+    if let Some(is_game_changer) = criteria.game_changer() {
+        // Scryfall leaves the flag NULL on most cards; NULL counts as false
+        sep.push("COALESCE(latest_cards.game_changer, false) = ");
+        sep.push_bind_unseparated(is_game_changer);
     }
 
-    async fn clear_all_categories(&self) -> Result<(), anyhow::Error> {
-        // Batch clear to avoid a single slow UPDATE on 100k+ rows
-        loop {
-            let result = sqlx::query!(
-                "UPDATE card_profiles SET card_roles = '[]'::jsonb
-                 WHERE scryfall_data_id IN (
-                     SELECT scryfall_data_id FROM card_profiles
-                     WHERE card_roles != '[]'::jsonb
-                     LIMIT 5000
-                 )"
-            )
-            .execute(&self.pool)
-            .await?;
+    if let Some(story_spotlight) = criteria.story_spotlight() {
+        sep.push("latest_cards.story_spotlight = ");
+        sep.push_bind_unseparated(story_spotlight);
+    }
 
-            if result.rows_affected() == 0 {
-                break;
+    if let Some(textless) = criteria.textless() {
+        sep.push("latest_cards.textless = ");
+        sep.push_bind_unseparated(textless);
+    }
+
+    if let Some(language) = criteria.language() {
+        sep.push("latest_cards.lang = ");
+        sep.push_bind_unseparated(language);
+    }
+
+    if let Some(formats) = criteria.legalities_contains_any() {
+        sep.push("(");
+        for (i, format_key) in formats.iter().enumerate() {
+            if i > 0 {
+                sep.push_unseparated(" OR ");
             }
+            sep.push_unseparated("legalities->>");
+            sep.push_bind_unseparated(format_key.clone());
+            sep.push_unseparated(" IN ('legal', 'restricted')");
         }
-        Ok(())
+        sep.push_unseparated(")");
     }
 
-    async fn derive_oracle_tag_categories(&self) -> anyhow::Result<u64> {
-        crate::outbound::sqlx::card::helpers::derive_categories::derive_categories(&self.pool).await
+    if let Some(legal_in) = criteria.legal_in() {
+        // a format missing from the object reads as not legal (ScryfallData::legality)
+        for (format, status) in legal_in {
+            sep.push("COALESCE(legalities->>");
+            sep.push_bind_unseparated(format.to_legality_key().to_string());
+            sep.push_unseparated(", 'not_legal') = ");
+            sep.push_bind_unseparated(status.as_str());
+        }
     }
 
-    async fn refresh_oracle_tag_groups(&self) -> anyhow::Result<u64> {
-        crate::outbound::sqlx::card::helpers::oracle_tag_groups::refresh_oracle_tag_groups(
-            &self.pool,
-        )
-        .await
+    if let Some(format) = criteria.is_commander_in_format() {
+        match format {
+            // Legendary creature, legendary vehicle with P/T, or "can be your commander"
+            Format::Commander | Format::Duel | Format::Predh => {
+                sep.push(
+                    "((type_line ILIKE '%Legendary%' AND type_line ILIKE '%Creature%') \
+                     OR (type_line ILIKE '%Legendary%' AND power IS NOT NULL AND toughness IS NOT NULL) \
+                     OR oracle_text ILIKE '%can be your commander%')",
+                );
+            }
+            // Legendary creature or legendary planeswalker
+            Format::Brawl | Format::StandardBrawl | Format::HistoricBrawl => {
+                sep.push(
+                    "(type_line ILIKE '%Legendary%' AND \
+                     (type_line ILIKE '%Creature%' OR type_line ILIKE '%Planeswalker%'))",
+                );
+            }
+            // Uncommon creature — legendary or not. Two fixes here:
+            //   1. Rarity is stored as the short code ('U'), not the word
+            //      'uncommon' — the old literal matched nothing.
+            //   2. PDH eligibility is "has appeared at uncommon in ANY
+            //      printing", not "this cached printing is uncommon", so we
+            //      check all printings via scryfall_data (catches cards whose
+            //      preferred printing is common but were printed uncommon).
+            Format::PauperCommander => {
+                sep.push(
+                    "(type_line ILIKE '%Creature%' AND EXISTS (\
+                     SELECT 1 FROM scryfall_data sd2 \
+                     WHERE sd2.oracle_id = latest_cards.oracle_id \
+                     AND sd2.rarity = 'U'))",
+                );
+            }
+            // Any planeswalker
+            Format::Oathbreaker => {
+                sep.push("type_line ILIKE '%Planeswalker%'");
+            }
+            // Non-commander formats: no filter (should not happen, but safe)
+            _ => {}
+        }
     }
 
-    async fn get_card_ids_with_oracle_text(&self) -> Result<Vec<uuid::Uuid>, anyhow::Error> {
-        let ids: Vec<uuid::Uuid> = sqlx::query_scalar(
-            "SELECT cp.scryfall_data_id FROM card_profiles cp \
-             JOIN scryfall_data sd ON cp.scryfall_data_id = sd.id \
-             WHERE sd.oracle_text IS NOT NULL AND sd.oracle_id IS NOT NULL",
-        )
-        .fetch_all(&self.pool)
-        .await?;
-        Ok(ids)
+    // partner/background/spell filters
+    if let Some(true) = criteria.is_partner() {
+        sep.push(
+            "(type_line ILIKE '%Legendary%' AND type_line ILIKE '%Creature%' AND (\
+             keywords @> ARRAY['Partner']::text[] \
+             OR keywords @> ARRAY['Friends forever']::text[] \
+             OR keywords @> ARRAY['Doctor''s companion']::text[] \
+             OR oracle_text ILIKE '%partner with%'))",
+        );
+    }
+
+    if let Some(true) = criteria.is_background() {
+        sep.push(
+            "(type_line ILIKE '%Legendary%' AND type_line ILIKE '%Enchantment%' \
+             AND type_line ILIKE '%Background%')",
+        );
+    }
+
+    if let Some(true) = criteria.is_signature_spell() {
+        sep.push("(type_line ILIKE '%Instant%' OR type_line ILIKE '%Sorcery%')");
+    }
+
+    // mechanical category filters
+    if let Some(categories) = criteria.card_roles_contains_any() {
+        sep.push("(card_profiles.card_roles ?| ");
+        sep.push_bind_unseparated(categories.to_vec());
+        sep.push_unseparated(")");
+    }
+
+    if let Some(categories) = criteria.card_roles_contains_all() {
+        let json = serde_json::to_value(categories).unwrap_or_default();
+        sep.push("(card_profiles.card_roles @> ");
+        sep.push_bind_unseparated(json);
+        sep.push_unseparated(")");
+    }
+
+    if let Some(categories) = criteria.card_roles_excludes() {
+        sep.push("NOT (card_profiles.card_roles ?| ");
+        sep.push_bind_unseparated(categories.to_vec());
+        sep.push_unseparated(")");
+    }
+
+    if let Some(tags) = criteria.oracle_tags_contains_any() {
+        sep.push("(card_profiles.oracle_tags ?| ");
+        sep.push_bind_unseparated(tags.to_vec());
+        sep.push_unseparated(")");
+    }
+
+    if let Some(tags) = criteria.oracle_tags_contains_all() {
+        let json = serde_json::to_value(tags).unwrap_or_default();
+        sep.push("(card_profiles.oracle_tags @> ");
+        sep.push_bind_unseparated(json);
+        sep.push_unseparated(")");
+    }
+
+    if let Some(tags) = criteria.oracle_tags_excludes() {
+        sep.push("NOT (card_profiles.oracle_tags ?| ");
+        sep.push_bind_unseparated(tags.to_vec());
+        sep.push_unseparated(")");
     }
 }
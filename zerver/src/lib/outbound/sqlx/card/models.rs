@@ -2,9 +2,12 @@
 
 use sqlx::types::Json;
 use sqlx_macros::FromRow;
-use zwipe_core::domain::card::scryfall_data::{
-    ScryfallData, all_parts::AllParts, card_faces::CardFaces, colors::Colors,
-    image_uris::ImageUris, legalities::Legalities, prices::Prices, rarity::Rarity,
+use zwipe_core::domain::card::{
+    card_summary::CardSummary,
+    scryfall_data::{
+        ScryfallData, all_parts::AllParts, card_faces::CardFaces, colors::Colors,
+        image_uris::ImageUris, legalities::Legalities, prices::Prices, rarity::Rarity,
+    },
 };
 
 /// Raw database Scryfall data record (unvalidated data from PostgreSQL).
@@ -211,3 +214,42 @@ impl TryFrom<DatabaseScryfallData> for ScryfallData {
         })
     }
 }
+
+/// Raw database row for a summary search (the `SUMMARY_COLUMNS` projection).
+#[derive(Debug, FromRow)]
+#[allow(missing_docs)]
+pub struct DatabaseCardSummary {
+    pub id: uuid::Uuid,
+    pub oracle_id: Option<uuid::Uuid>,
+    pub name: String,
+    pub mana_cost: Option<String>,
+    pub cmc: Option<f64>,
+    pub type_line: Option<String>,
+    pub color_identity: Vec<String>,
+    pub rarity: String,
+    pub set: String,
+    pub edhrec_rank: Option<i32>,
+    pub prices: Json<Prices>,
+    pub image_uris: Option<Json<ImageUris>>,
+}
+
+impl TryFrom<DatabaseCardSummary> for CardSummary {
+    type Error = anyhow::Error;
+
+    fn try_from(db: DatabaseCardSummary) -> Result<Self, Self::Error> {
+        Ok(CardSummary {
+            id: db.id,
+            oracle_id: db.oracle_id,
+            name: db.name,
+            mana_cost: db.mana_cost,
+            cmc: db.cmc,
+            type_line: db.type_line,
+            color_identity: Colors::from_short_names(db.color_identity)?,
+            rarity: Rarity::try_from(db.rarity)?,
+            set: db.set,
+            edhrec_rank: db.edhrec_rank,
+            prices: db.prices.0,
+            image_uris: db.image_uris.map(|j| j.0),
+        })
+    }
+}
@@ -8,8 +8,9 @@ use sqlx::{Decode, Encode, Postgres, Type, encode::IsNull, types::JsonValue};
 use sqlx_macros::FromRow;
 use uuid::Uuid;
 
-use crate::domain::card::models::zervice_metrics::{
-    ErrorMetrics, SyncStatus, VecErrorMetrics, ZerviceMetrics,
+use crate::domain::card::models::{
+    sync_schedule::SyncKind,
+    zervice_metrics::{ErrorMetrics, SyncStatus, VecErrorMetrics, ZerviceMetrics},
 };
 
 impl TryFrom<ErrorMetrics> for JsonValue {
@@ -135,11 +136,13 @@ pub struct DatabaseZerviceMetrics {
     #[sqlx(rename = "id")]
     _id: Uuid,
     status: String,
+    sync_kind: String,
     started_at: DateTime<Utc>,
     ended_at: Option<DateTime<Utc>>,
     duration_in_seconds: i32,
     received_count: i32,
     upserted_count: i32,
+    price_updated_count: i32,
     skipped_count: i32,
     error_count: i32,
     errors: VecErrorMetrics,
@@ -149,6 +152,7 @@ impl TryFrom<DatabaseZerviceMetrics> for ZerviceMetrics {
     type Error = anyhow::Error;
     fn try_from(value: DatabaseZerviceMetrics) -> anyhow::Result<Self> {
         let status = SyncStatus::try_from(value.status.as_str())?;
+        let sync_kind = SyncKind::try_from(value.sync_kind.as_str())?;
         let errors: Vec<ErrorMetrics> = value.errors.to_vec();
 
         let zervice_metrics = ZerviceMetrics::new()
@@ -156,8 +160,10 @@ impl TryFrom<DatabaseZerviceMetrics> for ZerviceMetrics {
             .set_ended_at(value.ended_at)
             .set_duration_in_seconds(value.duration_in_seconds)
             .set_status(status)
+            .set_sync_kind(sync_kind)
             .set_received_count(value.received_count)
             .set_upserted_count(value.upserted_count)
+            .set_price_updated_count(value.price_updated_count)
             .set_skipped_count(value.skipped_count)
             .set_error_count(value.error_count)
             .set_errors(errors)
@@ -7,16 +7,17 @@ use crate::{
             get_deck_profile::GetDeckProfileError, update_deck_profile::UpdateDeckProfileError,
         },
         deck_card::{
-            create_deck_card::CreateDeckCardError, delete_deck_card::DeleteDeckCardError,
-            get_deck_card::GetDeckCardError, update_deck_card::UpdateDeckCardError,
+            bulk_add_deck_cards::BulkAddDeckCardsError, create_deck_card::CreateDeckCardError,
+            delete_deck_card::DeleteDeckCardError, get_deck_card::GetDeckCardError,
+            orphaned_deck_cards::OrphanedDeckCardsError, update_deck_card::UpdateDeckCardError,
         },
     },
     outbound::sqlx::postgres::IsConstraintViolation,
 };
 use thiserror::Error;
 use zwipe_core::domain::deck::{
-    board::InvalidBoard, deck_name::InvalidDeckname, format::InvalidFormat,
-    quantity::InvalidQuantity,
+    InvalidCopyLimit, board::InvalidBoard, card_reference::InvalidCardReference,
+    deck_name::InvalidDeckname, format::InvalidFormat, quantity::InvalidQuantity,
 };
 
 /// Errors from converting a database deck profile row into domain types.
@@ -27,6 +28,8 @@ pub enum IntoDeckProfileError {
     DeckName(#[from] InvalidDeckname),
     #[error(transparent)]
     Format(#[from] InvalidFormat),
+    #[error(transparent)]
+    CopyLimit(#[from] InvalidCopyLimit),
 }
 
 impl From<IntoDeckProfileError> for CreateDeckProfileError {
@@ -99,6 +102,8 @@ pub enum IntoDeckCardError {
     InvalidQuantity(#[from] InvalidQuantity),
     #[error(transparent)]
     InvalidBoard(#[from] InvalidBoard),
+    #[error(transparent)]
+    InvalidReference(#[from] InvalidCardReference),
 }
 
 impl From<IntoDeckCardError> for CreateDeckCardError {
@@ -107,6 +112,12 @@ impl From<IntoDeckCardError> for CreateDeckCardError {
     }
 }
 
+impl From<IntoDeckCardError> for BulkAddDeckCardsError {
+    fn from(value: IntoDeckCardError) -> Self {
+        Self::DeckCardFromDb(value.into())
+    }
+}
+
 impl From<IntoDeckCardError> for GetDeckCardError {
     fn from(value: IntoDeckCardError) -> Self {
         Self::DeckCardFromDb(value.into())
@@ -119,6 +130,18 @@ impl From<IntoDeckCardError> for UpdateDeckCardError {
     }
 }
 
+impl From<IntoDeckCardError> for OrphanedDeckCardsError {
+    fn from(value: IntoDeckCardError) -> Self {
+        Self::DeckCardFromDb(value.into())
+    }
+}
+
+impl From<sqlx::Error> for OrphanedDeckCardsError {
+    fn from(value: sqlx::Error) -> Self {
+        Self::Database(value.into())
+    }
+}
+
 impl From<sqlx::Error> for CreateDeckCardError {
     fn from(value: sqlx::Error) -> Self {
         match value {
@@ -141,6 +164,7 @@ impl From<sqlx::Error> for UpdateDeckCardError {
     fn from(value: sqlx::Error) -> Self {
         match value {
             e if e.is_check_constraint_violation() => Self::QuantityUnderflow,
+            e if e.is_unique_constraint_violation() => Self::AlreadyOnBoard,
             sqlx::Error::RowNotFound => Self::NotFound,
             e => Self::Database(e.into()),
         }
@@ -11,14 +11,25 @@ use crate::{
     domain::deck::{
         models::{
             deck::{
-                clear_deck_suppressions::ClearDeckSuppressionsError, clone_deck::CloneDeckError,
-                create_deck_profile::CreateDeckProfileError, delete_deck::DeleteDeckError,
-                get_deck_profile::GetDeckProfileError, share_deck::ShareDeckError,
-                skip_deck_card::SkipDeckCardError, update_deck_profile::UpdateDeckProfileError,
+                clear_deck_suppressions::ClearDeckSuppressionsError,
+                clone_deck::CloneDeckError,
+                collection::{DeckMissingCardsError, GetOwnedCardsError, SetOwnedCardError},
+                create_deck_profile::CreateDeckProfileError,
+                delete_deck::DeleteDeckError,
+                get_deck_profile::GetDeckProfileError,
+                set_deck_labels::SetDeckLabelsError,
+                share_deck::ShareDeckError,
+                skip_deck_card::SkipDeckCardError,
+                update_deck_profile::UpdateDeckProfileError,
             },
             deck_card::{
-                create_deck_card::CreateDeckCardError, delete_deck_card::DeleteDeckCardError,
-                get_deck_card::GetDeckCardError, import_deck_cards::ImportDeckCardsError,
+                bulk_add_deck_cards::BulkAddDeckCardsError,
+                create_deck_card::CreateDeckCardError,
+                delete_deck_card::DeleteDeckCardError,
+                get_deck_card::GetDeckCardError,
+                import_deck_cards::ImportDeckCardsError,
+                move_deck_cards::{CopyCap, MoveDeckCardsError},
+                orphaned_deck_cards::OrphanedDeckCardsError,
                 update_deck_card::UpdateDeckCardError,
             },
         },
@@ -28,22 +39,29 @@ use crate::{
         deck::{
             error::{IntoDeckCardError, IntoDeckProfileError},
             helper::OwnsDeck,
-            models::{DatabaseDeckCard, DatabaseDeckProfile},
+            models::{
+                DatabaseBannedDeckCard, DatabaseDeckCard, DatabaseDeckProfile,
+                DatabaseOrphanedDeckCard,
+            },
         },
         postgres::Postgres,
     },
 };
-use sqlx::{QueryBuilder, query, query_as, query_scalar};
+use sqlx::{PgPool, QueryBuilder, query, query_as, query_scalar};
+use std::collections::HashMap;
+use uuid::Uuid;
 use zwipe_core::domain::deck::{
-    Board, DeckCard, DeckName, DeckOtherTag, DeckTag,
+    BannedDeckCard, Board, DeckCard, DeckName, DeckOtherTag, DeckTag, MissingDeckCard,
+    OrphanedDeckCard, OwnedCard,
     deck_profile::DeckProfile,
     requests::{
-        clear_deck_suppressions::ClearDeckSuppressions, create_deck_card::CreateDeckCard,
-        create_deck_profile::CreateDeckProfile, delete_deck::DeleteDeck,
-        delete_deck_card::DeleteDeckCard, get_deck_profile::GetDeckProfile,
-        get_deck_profiles::GetDeckProfiles, import_deck_cards::ImportDeckCards,
-        skip_deck_card::SkipDeckCard, update_deck_card::UpdateDeckCard,
-        update_deck_profile::UpdateDeckProfile,
+        bulk_add_deck_cards::BulkAddDeckCards, clear_deck_suppressions::ClearDeckSuppressions,
+        create_deck_card::CreateDeckCard, create_deck_profile::CreateDeckProfile,
+        delete_deck::DeleteDeck, delete_deck_card::DeleteDeckCard,
+        get_deck_profile::GetDeckProfile, get_deck_profiles::GetDeckProfiles,
+        import_deck_cards::ImportDeckCards, move_deck_cards::MoveDeckCards,
+        set_deck_labels::SetDeckLabels, set_owned_card::SetOwnedCard, skip_deck_card::SkipDeckCard,
+        update_deck_card::UpdateDeckCard, update_deck_profile::UpdateDeckProfile,
     },
 };
 
@@ -78,6 +96,54 @@ fn deck_oracle_tags_to_json(tags: &[String]) -> serde_json::Value {
     )
 }
 
+/// A user's deck cards whose printing no longer resolves to a card — its
+/// `scryfall_data` row or `card_profiles` row is gone, which is what card reads
+/// join through — each with the best surviving printing of the same oracle
+/// card (see the `preferred_printing` SQL function).
+async fn orphaned_deck_cards(
+    executor: impl sqlx::PgExecutor<'_>,
+    user_id: Uuid,
+) -> Result<Vec<DatabaseOrphanedDeckCard>, sqlx::Error> {
+    query_as!(
+        DatabaseOrphanedDeckCard,
+        "SELECT dc.deck_id, dc.scryfall_data_id, dc.oracle_id, \
+                dc.quantity, dc.board, dc.mvp_at, dc.reference, \
+                preferred_printing(dc.oracle_id) AS replacement_id \
+         FROM deck_cards dc \
+         JOIN decks d ON d.id = dc.deck_id \
+         WHERE d.user_id = $1 \
+           AND NOT EXISTS (SELECT 1 FROM scryfall_data sd \
+                           JOIN card_profiles cp ON cp.scryfall_data_id = sd.id \
+                           WHERE sd.id = dc.scryfall_data_id) \
+         ORDER BY dc.deck_id, dc.scryfall_data_id",
+        user_id
+    )
+    .fetch_all(executor)
+    .await
+}
+
+/// Whether the targeted copy of a card is the sideboard's: `from_board`'s
+/// side when given, else the main copy's if the card has one. `None` when
+/// the deck holds no such copy.
+async fn card_side(
+    conn: &mut sqlx::PgConnection,
+    deck_id: Uuid,
+    scryfall_data_id: Uuid,
+    from_board: Option<Board>,
+) -> Result<Option<bool>, sqlx::Error> {
+    query_scalar!(
+        r#"SELECT board = 'sideboard' AS "sideboard!" FROM deck_cards
+           WHERE deck_id = $1 AND scryfall_data_id = $2
+             AND ($3::BOOLEAN IS NULL OR (board = 'sideboard') = $3)
+           ORDER BY board = 'sideboard' LIMIT 1"#,
+        deck_id,
+        scryfall_data_id,
+        from_board.map(|board| board.is_sideboard())
+    )
+    .fetch_optional(conn)
+    .await
+}
+
 impl DeckRepository for Postgres {
     // ========
     //  create
@@ -92,9 +158,9 @@ impl DeckRepository for Postgres {
         let oracle_tags_json = deck_oracle_tags_to_json(&request.oracle_tags);
         let database_deck_profile = query_as!(
             DatabaseDeckProfile,
-            r#"INSERT INTO decks (name, commander_id, partner_commander_id, background_id, signature_spell_id, format, tags, power_level, other_tags, oracle_tags, land_target, price_target, price_target_currency, user_id)
-               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
-               RETURNING id, name, commander_id, partner_commander_id, background_id, signature_spell_id, format, tags as "tags?", power_level, other_tags as "other_tags?", oracle_tags as "oracle_tags?", land_target, price_target, price_target_currency, share_token, user_id,
+            r#"INSERT INTO decks (name, commander_id, partner_commander_id, background_id, signature_spell_id, format, tags, power_level, other_tags, oracle_tags, land_target, price_target, price_target_currency, copy_limit, user_id)
+               VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+               RETURNING id, name, commander_id, partner_commander_id, background_id, signature_spell_id, format, tags as "tags?", power_level, other_tags as "other_tags?", oracle_tags as "oracle_tags?", land_target, price_target, price_target_currency, copy_limit, share_token, user_id,
                          0::bigint as "card_count",
                          (SELECT sd.name FROM scryfall_data sd WHERE sd.id = commander_id) as "commander_name?",
                          (SELECT sd.name FROM scryfall_data sd WHERE sd.id = partner_commander_id) as "partner_commander_name?",
@@ -117,6 +183,7 @@ impl DeckRepository for Postgres {
             request.land_target,
             request.price_target,
             request.price_target_currency.map(|c| c.json_key().to_string()) as Option<String>,
+            request.copy_limit.to_string(),
             request.user_id
         )
         .fetch_one(&mut *tx)
@@ -140,12 +207,13 @@ impl DeckRepository for Postgres {
         let mut tx = self.pool.begin().await?;
         let database_deck_card = query_as!(
             DatabaseDeckCard,
-            "INSERT INTO deck_cards (deck_id, scryfall_data_id, oracle_id, quantity, board) VALUES ($1, $2, $3, $4, $5) RETURNING deck_id, scryfall_data_id, oracle_id, quantity, board, mvp_at",
+            "INSERT INTO deck_cards (deck_id, scryfall_data_id, oracle_id, quantity, board, reference) VALUES ($1, $2, $3, $4, $5, $6) RETURNING deck_id, scryfall_data_id, oracle_id, quantity, board, mvp_at, reference",
             request.deck_id,
             request.scryfall_data_id,
             request.oracle_id,
             *request.quantity,
-            request.board.display_name()
+            request.board.display_name(),
+            request.reference.display_name()
         )
         .fetch_one(&mut *tx)
         .await?;
@@ -230,7 +298,7 @@ impl DeckRepository for Postgres {
         let database_deck_profile = query_as!(
             DatabaseDeckProfile,
             r#"SELECT d.id, d.name, d.commander_id, d.partner_commander_id, d.background_id, d.signature_spell_id,
-                      d.format, d.tags as "tags?", d.power_level, d.other_tags as "other_tags?", d.oracle_tags as "oracle_tags?", d.land_target, d.price_target, d.price_target_currency, d.share_token, d.user_id,
+                      d.format, d.tags as "tags?", d.power_level, d.other_tags as "other_tags?", d.oracle_tags as "oracle_tags?", d.land_target, d.price_target, d.price_target_currency, d.copy_limit, d.share_token, d.user_id,
                       COALESCE(SUM(dc.quantity) FILTER (WHERE dc.board = 'deck'), 0) as "card_count",
                       sd.name as "commander_name?",
                       (SELECT s2.name FROM scryfall_data s2 WHERE s2.id = d.partner_commander_id) as "partner_commander_name?",
@@ -245,7 +313,7 @@ impl DeckRepository for Postgres {
                LEFT JOIN scryfall_data sd ON d.commander_id = sd.id
                WHERE d.id = $1
                GROUP BY d.id, d.name, d.commander_id, d.partner_commander_id, d.background_id, d.signature_spell_id,
-                        d.format, d.land_target, d.price_target, d.price_target_currency, d.share_token, d.user_id, sd.name"#,
+                        d.format, d.land_target, d.price_target, d.price_target_currency, d.copy_limit, d.share_token, d.user_id, sd.name"#,
             request.deck_id
         )
         .fetch_one(&self.pool)
@@ -253,7 +321,8 @@ impl DeckRepository for Postgres {
         if database_deck_profile.user_id != request.user_id {
             return Err(GetDeckProfileError::Forbidden);
         }
-        let deck_profile: DeckProfile = database_deck_profile.try_into()?;
+        let mut deck_profile: DeckProfile = database_deck_profile.try_into()?;
+        attach_labels(&self.pool, std::slice::from_mut(&mut deck_profile)).await?;
         Ok(deck_profile)
     }
 
@@ -264,7 +333,7 @@ impl DeckRepository for Postgres {
         let database_deck_profiles = query_as!(
             DatabaseDeckProfile,
             r#"SELECT d.id, d.name, d.commander_id, d.partner_commander_id, d.background_id, d.signature_spell_id,
-                      d.format, d.tags as "tags?", d.power_level, d.other_tags as "other_tags?", d.oracle_tags as "oracle_tags?", d.land_target, d.price_target, d.price_target_currency, d.share_token, d.user_id,
+                      d.format, d.tags as "tags?", d.power_level, d.other_tags as "other_tags?", d.oracle_tags as "oracle_tags?", d.land_target, d.price_target, d.price_target_currency, d.copy_limit, d.share_token, d.user_id,
                       COALESCE(SUM(dc.quantity) FILTER (WHERE dc.board = 'deck'), 0) as "card_count",
                       sd.name as "commander_name?",
                       (SELECT s2.name FROM scryfall_data s2 WHERE s2.id = d.partner_commander_id) as "partner_commander_name?",
@@ -278,16 +347,19 @@ impl DeckRepository for Postgres {
                LEFT JOIN deck_cards dc ON d.id = dc.deck_id
                LEFT JOIN scryfall_data sd ON d.commander_id = sd.id
                WHERE d.user_id = $1
+                 AND ($2::TEXT IS NULL OR EXISTS (SELECT 1 FROM deck_labels dl WHERE dl.deck_id = d.id AND dl.label = $2))
                GROUP BY d.id, d.name, d.commander_id, d.partner_commander_id, d.background_id, d.signature_spell_id,
-                        d.format, d.land_target, d.price_target, d.price_target_currency, d.share_token, d.user_id, sd.name"#,
-            request.user_id
+                        d.format, d.land_target, d.price_target, d.price_target_currency, d.copy_limit, d.share_token, d.user_id, sd.name"#,
+            request.user_id,
+            request.label
         )
         .fetch_all(&self.pool)
         .await?;
-        let deck_profiles: Vec<DeckProfile> = database_deck_profiles
+        let mut deck_profiles: Vec<DeckProfile> = database_deck_profiles
             .into_iter()
             .map(|x| x.try_into())
             .collect::<Result<Vec<DeckProfile>, IntoDeckProfileError>>()?;
+        attach_labels(&self.pool, &mut deck_profiles).await?;
         Ok(deck_profiles)
     }
 
@@ -302,9 +374,12 @@ impl DeckRepository for Postgres {
         {
             return Err(GetDeckCardError::Forbidden);
         }
+        // Oracle references are kept on their preferred printing by the card
+        // sync (and the printing-delete trigger), so the stored id is one the
+        // card endpoints can load and the per-card routes will match.
         let database_deck_cards = query_as!(
             DatabaseDeckCard,
-            "SELECT deck_id, scryfall_data_id, oracle_id, quantity, board, mvp_at FROM deck_cards WHERE deck_id = $1",
+            "SELECT deck_id, scryfall_data_id, oracle_id, quantity, board, mvp_at, reference FROM deck_cards WHERE deck_id = $1",
             request.deck_id
         )
         .fetch_all(&self.pool)
@@ -389,12 +464,16 @@ impl DeckRepository for Postgres {
             sep.push("price_target_currency = ")
                 .push_bind_unseparated(price_target_currency.map(|c| c.json_key().to_string()));
         }
+        if let Some(copy_limit) = &request.copy_limit {
+            sep.push("copy_limit = ")
+                .push_bind_unseparated(copy_limit.to_string());
+        }
         let now = chrono::Utc::now();
         sep.push("updated_at = ").push_bind_unseparated(now);
 
         qb.push(" WHERE id = ")
             .push_bind(request.deck_id)
-            .push(r#" RETURNING id, name, commander_id, partner_commander_id, background_id, signature_spell_id, format, tags, power_level, other_tags, oracle_tags, land_target, price_target, price_target_currency, share_token, user_id,
+            .push(r#" RETURNING id, name, commander_id, partner_commander_id, background_id, signature_spell_id, format, tags, power_level, other_tags, oracle_tags, land_target, price_target, price_target_currency, copy_limit, share_token, user_id,
                        (SELECT COALESCE(SUM(dc.quantity) FILTER (WHERE dc.board = 'deck'), 0) FROM deck_cards dc WHERE dc.deck_id = decks.id) as card_count,
                        (SELECT sd.name FROM scryfall_data sd WHERE sd.id = decks.commander_id) as commander_name,
                        (SELECT sd.name FROM scryfall_data sd WHERE sd.id = decks.partner_commander_id) as partner_commander_name,
@@ -431,16 +510,26 @@ impl DeckRepository for Postgres {
             return Err(UpdateDeckCardError::Forbidden);
         }
         let mut tx = self.pool.begin().await?;
+        let sideboard = card_side(
+            &mut tx,
+            request.deck_id,
+            request.scryfall_data_id,
+            request.from_board,
+        )
+        .await?
+        .ok_or(UpdateDeckCardError::NotFound)?;
         if request.mvp == Some(true) {
             // Board rule: the star lands on the mainboard — either the board
             // this request sets, or the row's current board when untouched.
             let effective_board = match &request.board {
                 Some(board) => *board,
                 None => {
-                    let current: String = query_scalar!(
-                        "SELECT board FROM deck_cards WHERE deck_id = $1 AND scryfall_data_id = $2",
+                    let current = query_scalar!(
+                        "SELECT board FROM deck_cards \
+                         WHERE deck_id = $1 AND scryfall_data_id = $2 AND (board = 'sideboard') = $3",
                         request.deck_id,
-                        request.scryfall_data_id
+                        request.scryfall_data_id,
+                        sideboard
                     )
                     .fetch_optional(&mut *tx)
                     .await?
@@ -481,6 +570,10 @@ impl DeckRepository for Postgres {
             sep.push("scryfall_data_id = ")
                 .push_bind_unseparated(new_id);
         }
+        if let Some(reference) = &request.reference {
+            sep.push("reference = ")
+                .push_bind_unseparated(reference.display_name().to_string());
+        }
         if let Some(mvp) = request.mvp {
             // COALESCE keeps the original vesting clock if a client re-sends
             // true; false clears the star.
@@ -497,7 +590,9 @@ impl DeckRepository for Postgres {
             .push_bind(request.deck_id)
             .push(" AND scryfall_data_id = ")
             .push_bind(request.scryfall_data_id)
-            .push(" RETURNING deck_id::TEXT, scryfall_data_id::TEXT, oracle_id::TEXT, quantity, board, mvp_at");
+            .push(" AND (board = 'sideboard') = ")
+            .push_bind(sideboard)
+            .push(" RETURNING deck_id::TEXT, scryfall_data_id::TEXT, oracle_id::TEXT, quantity, board, mvp_at, reference");
         let database_deck_card: DatabaseDeckCard = qb.build_query_as().fetch_one(&mut *tx).await?;
         let deck_card: DeckCard = database_deck_card.try_into()?;
         tx.commit().await?;
@@ -625,17 +720,26 @@ impl DeckRepository for Postgres {
             return Err(DeleteDeckCardError::Forbidden);
         }
         let mut tx = self.pool.begin().await?;
-        let deleted = query!(
-            "DELETE FROM deck_cards WHERE deck_id = $1 AND scryfall_data_id = $2
-             RETURNING oracle_id",
+        let Some(sideboard) = card_side(
+            &mut tx,
             request.deck_id,
-            request.scryfall_data_id
+            request.scryfall_data_id,
+            request.from_board,
         )
-        .fetch_optional(&mut *tx)
-        .await?;
-        let Some(deleted) = deleted else {
+        .await?
+        else {
             return Err(DeleteDeckCardError::NotFound);
         };
+        let oracle_id = query_scalar!(
+            "DELETE FROM deck_cards \
+             WHERE deck_id = $1 AND scryfall_data_id = $2 AND (board = 'sideboard') = $3 \
+             RETURNING oracle_id",
+            request.deck_id,
+            request.scryfall_data_id,
+            sideboard
+        )
+        .fetch_one(&mut *tx)
+        .await?;
         // A deliberate single-card removal is a "doesn't fit" signal: suppress
         // the card so the deck-aware search stops re-serving it. Bulk deletes
         // (replace-mode imports) intentionally don't do this.
@@ -646,7 +750,7 @@ impl DeckRepository for Postgres {
                    source = EXCLUDED.source,
                    suppressed_at = now()"#,
             request.deck_id,
-            deleted.oracle_id,
+            oracle_id,
         )
         .execute(&mut *tx)
         .await?;
@@ -689,7 +793,7 @@ impl DeckRepository for Postgres {
             },
         );
         qb.push(
-            " ON CONFLICT (deck_id, oracle_id) DO UPDATE SET quantity = EXCLUDED.quantity, board = EXCLUDED.board RETURNING deck_id::TEXT, scryfall_data_id::TEXT, oracle_id::TEXT, quantity, board, mvp_at",
+            " ON CONFLICT (deck_id, oracle_id, (board = 'sideboard')) DO UPDATE SET quantity = EXCLUDED.quantity, board = EXCLUDED.board RETURNING deck_id::TEXT, scryfall_data_id::TEXT, oracle_id::TEXT, quantity, board, mvp_at, reference",
         );
         let rows: Vec<DatabaseDeckCard> = qb
             .build_query_as()
@@ -709,6 +813,288 @@ impl DeckRepository for Postgres {
         Ok(deck_cards)
     }
 
+    async fn move_deck_cards(
+        &self,
+        request: &MoveDeckCards,
+        caps: &HashMap<Uuid, CopyCap>,
+    ) -> Result<(), MoveDeckCardsError> {
+        let mut tx = self.pool.begin().await?;
+        for (scryfall_data_id, quantity) in &request.cards {
+            let quantity = **quantity;
+            // Lock the source row so a concurrent edit can't move the same
+            // copies twice. The main copy goes before a sideboard one.
+            let source = query!(
+                "SELECT oracle_id, quantity, board, reference FROM deck_cards \
+                 WHERE deck_id = $1 AND scryfall_data_id = $2 \
+                 ORDER BY board = 'sideboard' LIMIT 1 FOR UPDATE",
+                request.from_deck_id,
+                scryfall_data_id
+            )
+            .fetch_optional(&mut *tx)
+            .await?;
+            let Some(source) = source.filter(|source| source.quantity >= quantity) else {
+                return Err(MoveDeckCardsError::NotEnoughCopies {
+                    scryfall_data_id: *scryfall_data_id,
+                    quantity,
+                });
+            };
+            // `positive_quantity` forbids a zero row, so an emptied entry is
+            // deleted rather than decremented. Moving isn't a "doesn't fit"
+            // signal, so no removal suppression is recorded.
+            if source.quantity == quantity {
+                query!(
+                    "DELETE FROM deck_cards \
+                     WHERE deck_id = $1 AND scryfall_data_id = $2 AND board = $3",
+                    request.from_deck_id,
+                    scryfall_data_id,
+                    source.board
+                )
+                .execute(&mut *tx)
+                .await?;
+            } else {
+                query!(
+                    "UPDATE deck_cards SET quantity = quantity - $3, updated_at = NOW() \
+                     WHERE deck_id = $1 AND scryfall_data_id = $2 AND board = $4",
+                    request.from_deck_id,
+                    scryfall_data_id,
+                    quantity,
+                    source.board
+                )
+                .execute(&mut *tx)
+                .await?;
+            }
+            // An existing destination entry keeps its own printing and board;
+            // only the quantity merges.
+            let merged = query_scalar!(
+                "INSERT INTO deck_cards (deck_id, scryfall_data_id, oracle_id, quantity, board, reference) \
+                 VALUES ($1, $2, $3, $4, $5, $6) \
+                 ON CONFLICT (deck_id, oracle_id, (board = 'sideboard')) DO UPDATE \
+                 SET quantity = deck_cards.quantity + EXCLUDED.quantity, updated_at = NOW() \
+                 RETURNING quantity",
+                request.to_deck_id,
+                scryfall_data_id,
+                source.oracle_id,
+                quantity,
+                source.board,
+                source.reference
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+            if let Some(cap) = caps.get(scryfall_data_id)
+                && u32::try_from(merged).unwrap_or(u32::MAX) > cap.max
+            {
+                // Returning drops `tx` uncommitted: nothing moved.
+                return Err(MoveDeckCardsError::CopyLimitExceeded {
+                    name: cap.name.clone(),
+                    max: cap.max,
+                });
+            }
+            query!(
+                "DELETE FROM deck_card_suppressions WHERE deck_id = $1 AND oracle_id = $2",
+                request.to_deck_id,
+                source.oracle_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn bulk_add_deck_cards(
+        &self,
+        request: &BulkAddDeckCards,
+        oracle_ids: &HashMap<Uuid, Uuid>,
+        caps: &HashMap<Uuid, CopyCap>,
+    ) -> Result<Vec<DeckCard>, BulkAddDeckCardsError> {
+        // One entry per oracle card: two printings of a card merge into one
+        // row, and an upsert can touch a row only once per statement. The
+        // first printing named is the one a new row keeps.
+        let mut printings: Vec<Uuid> = Vec::with_capacity(request.cards.len());
+        let mut oracles: Vec<Uuid> = Vec::with_capacity(request.cards.len());
+        let mut quantities: Vec<i32> = Vec::with_capacity(request.cards.len());
+        let mut oracle_caps: HashMap<Uuid, &CopyCap> = HashMap::new();
+        for (scryfall_data_id, quantity) in &request.cards {
+            let Some(&oracle_id) = oracle_ids.get(scryfall_data_id) else {
+                return Err(BulkAddDeckCardsError::CardNotFound(*scryfall_data_id));
+            };
+            if let Some(cap) = caps.get(scryfall_data_id) {
+                oracle_caps.entry(oracle_id).or_insert(cap);
+            }
+            match oracles.iter().position(|o| *o == oracle_id) {
+                Some(i) => {
+                    if let Some(total) = quantities.get_mut(i) {
+                        *total = total.saturating_add(**quantity);
+                    }
+                }
+                None => {
+                    printings.push(*scryfall_data_id);
+                    oracles.push(oracle_id);
+                    quantities.push(**quantity);
+                }
+            }
+        }
+
+        let mut tx = self.pool.begin().await?;
+        // An existing entry keeps its own printing and board; only the
+        // quantity merges.
+        let rows = query_as!(
+            DatabaseDeckCard,
+            "INSERT INTO deck_cards (deck_id, scryfall_data_id, oracle_id, quantity, board) \
+             SELECT $1, c.scryfall_data_id, c.oracle_id, c.quantity, $5 \
+             FROM UNNEST($2::UUID[], $3::UUID[], $4::INT[]) AS c(scryfall_data_id, oracle_id, quantity) \
+             ON CONFLICT (deck_id, oracle_id, (board = 'sideboard')) DO UPDATE \
+             SET quantity = deck_cards.quantity + EXCLUDED.quantity, updated_at = NOW() \
+             RETURNING deck_id, scryfall_data_id, oracle_id, quantity, board, mvp_at, reference",
+            request.deck_id,
+            &printings,
+            &oracles,
+            &quantities,
+            request.board.display_name()
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let capped: Vec<Uuid> = oracles
+            .iter()
+            .copied()
+            .filter(|oracle_id| oracle_caps.contains_key(oracle_id))
+            .collect();
+        if !capped.is_empty() {
+            // Deck and sideboard copies share one limit.
+            let held: HashMap<Uuid, i64> = query!(
+                r#"SELECT oracle_id, SUM(quantity)::BIGINT AS "held!" FROM deck_cards
+                   WHERE deck_id = $1 AND oracle_id = ANY($2) AND board <> 'maybeboard'
+                   GROUP BY oracle_id"#,
+                request.deck_id,
+                &capped
+            )
+            .fetch_all(&mut *tx)
+            .await?
+            .into_iter()
+            .map(|row| (row.oracle_id, row.held))
+            .collect();
+            for oracle_id in &capped {
+                if let (Some(cap), Some(held)) = (oracle_caps.get(oracle_id), held.get(oracle_id))
+                    && u32::try_from(*held).unwrap_or(u32::MAX) > cap.max
+                {
+                    // Returning drops `tx` uncommitted: nothing was added.
+                    return Err(BulkAddDeckCardsError::CopyLimitExceeded {
+                        name: cap.name.clone(),
+                        max: cap.max,
+                    });
+                }
+            }
+        }
+
+        query!(
+            "DELETE FROM deck_card_suppressions WHERE deck_id = $1 AND oracle_id = ANY($2)",
+            request.deck_id,
+            &oracles
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        let mut deck_cards = rows
+            .into_iter()
+            .map(DeckCard::try_from)
+            .collect::<Result<Vec<_>, IntoDeckCardError>>()?;
+        // RETURNING order isn't guaranteed; hand entries back as requested.
+        deck_cards.sort_by_key(|card| oracles.iter().position(|o| *o == card.oracle_id));
+        Ok(deck_cards)
+    }
+
+    // =========
+    //  orphans
+    // =========
+    async fn find_orphaned_deck_cards(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> Result<Vec<OrphanedDeckCard>, OrphanedDeckCardsError> {
+        let rows = orphaned_deck_cards(&self.pool, user_id).await?;
+        let orphans = rows
+            .into_iter()
+            .map(OrphanedDeckCard::try_from)
+            .collect::<Result<Vec<_>, IntoDeckCardError>>()?;
+        Ok(orphans)
+    }
+
+    async fn repair_orphaned_deck_cards(
+        &self,
+        user_id: uuid::Uuid,
+    ) -> Result<Vec<OrphanedDeckCard>, OrphanedDeckCardsError> {
+        let mut tx = self.pool.begin().await?;
+        let rows = orphaned_deck_cards(&mut *tx, user_id).await?;
+        let orphans = rows
+            .into_iter()
+            .map(OrphanedDeckCard::try_from)
+            .collect::<Result<Vec<_>, IntoDeckCardError>>()?;
+        // The replacement shares the orphan's oracle_id, so the
+        // (deck_id, oracle_id) uniqueness can't trip.
+        for orphan in &orphans {
+            let Some(replacement_id) = orphan.replacement_id else {
+                continue;
+            };
+            query!(
+                "UPDATE deck_cards SET scryfall_data_id = $1, updated_at = NOW() \
+                 WHERE deck_id = $2 AND scryfall_data_id = $3",
+                replacement_id,
+                orphan.deck_card.deck_id,
+                orphan.deck_card.scryfall_data_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(orphans)
+    }
+
+    async fn decks_affected_by_banlist_changes(
+        &self,
+        since: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<BannedDeckCard>, anyhow::Error> {
+        // Decks holding a card (deck, sideboard or command zone — the
+        // maybeboard isn't played) whose latest legality change in the deck's
+        // format is a ban recorded at or after `since`. Consecutive equal
+        // statuses aren't recorded, so any later change means the ban was
+        // lifted again.
+        let rows = query_as!(
+            DatabaseBannedDeckCard,
+            r#"WITH bans AS (
+                   SELECT lc.oracle_id, lc.format, lc.changed_at AS banned_at
+                   FROM legality_changes lc
+                   WHERE lc.status = 'banned' AND lc.changed_at >= $1
+                     AND NOT EXISTS (SELECT 1 FROM legality_changes later
+                                     WHERE later.oracle_id = lc.oracle_id
+                                       AND later.format = lc.format
+                                       AND (later.changed_at, later.id) > (lc.changed_at, lc.id))
+               ), held AS (
+                   SELECT deck_id, oracle_id FROM deck_cards WHERE board IN ('deck', 'sideboard')
+                   UNION
+                   SELECT d.id, sd.oracle_id FROM decks d
+                   JOIN scryfall_data sd ON sd.id IN (d.commander_id, d.partner_commander_id,
+                                                      d.background_id, d.signature_spell_id)
+               )
+               SELECT d.id AS deck_id, d.user_id, d.name AS deck_name, d.format AS "format!",
+                      b.oracle_id AS "oracle_id!",
+                      (SELECT sd.name FROM scryfall_data sd WHERE sd.oracle_id = b.oracle_id LIMIT 1)
+                          AS "card_name!",
+                      b.banned_at AS "banned_at!"
+               FROM bans b
+               JOIN held h ON h.oracle_id = b.oracle_id
+               JOIN decks d ON d.id = h.deck_id AND d.format = b.format
+               ORDER BY b.banned_at, d.id, b.oracle_id"#,
+            since
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        let banned = rows
+            .into_iter()
+            .map(BannedDeckCard::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(banned)
+    }
+
     // =======
     //  clone
     // =======
@@ -732,12 +1118,12 @@ impl DeckRepository for Postgres {
             INSERT INTO decks (
                 name, commander_id, partner_commander_id, background_id,
                 signature_spell_id, format, tags, power_level, other_tags, oracle_tags,
-                land_target, price_target, price_target_currency, user_id
+                land_target, price_target, price_target_currency, copy_limit, user_id
             )
             SELECT
                 $1, commander_id, partner_commander_id, background_id,
                 signature_spell_id, format, tags, power_level, other_tags, oracle_tags,
-                land_target, price_target, price_target_currency, $2
+                land_target, price_target, price_target_currency, copy_limit, $2
             FROM decks
             WHERE id = $3
             RETURNING id
@@ -751,11 +1137,66 @@ impl DeckRepository for Postgres {
 
         // 2. Bulk copy every deck_cards row from source to new deck in a
         //    single SQL statement. Preserves board / quantity /
-        //    scryfall_data_id / oracle_id verbatim. No Rust-side iteration.
+        //    scryfall_data_id / oracle_id / reference verbatim. No Rust-side iteration.
         sqlx::query!(
             r#"
-            INSERT INTO deck_cards (deck_id, scryfall_data_id, oracle_id, quantity, board, mvp_at)
-            SELECT $1, scryfall_data_id, oracle_id, quantity, board, mvp_at
+            INSERT INTO deck_cards (deck_id, scryfall_data_id, oracle_id, quantity, board, mvp_at, reference)
+            SELECT $1, scryfall_data_id, oracle_id, quantity, board, mvp_at, reference
+            FROM deck_cards
+            WHERE deck_id = $2
+            "#,
+            new_deck_id,
+            source_deck_id,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(new_deck_id)
+    }
+
+    async fn copy_shared_deck(
+        &self,
+        source_deck_id: uuid::Uuid,
+        new_name: &DeckName,
+        owner_id: uuid::Uuid,
+    ) -> Result<uuid::Uuid, CloneDeckError> {
+        let mut tx = self.pool.begin().await?;
+
+        // Same shape as clone_deck, except every card — command zone
+        // included — re-resolves to its oracle card's current printing, so
+        // the copy doesn't inherit printings the sharer pinned long ago.
+        // A card whose oracle has no printing left keeps its old one.
+        let new_deck_id = query_scalar!(
+            r#"
+            INSERT INTO decks (
+                name, commander_id, partner_commander_id, background_id,
+                signature_spell_id, format, tags, power_level, other_tags, oracle_tags,
+                land_target, price_target, price_target_currency, copy_limit, user_id
+            )
+            SELECT
+                $1,
+                COALESCE(preferred_printing((SELECT oracle_id FROM scryfall_data WHERE id = commander_id)), commander_id),
+                COALESCE(preferred_printing((SELECT oracle_id FROM scryfall_data WHERE id = partner_commander_id)), partner_commander_id),
+                COALESCE(preferred_printing((SELECT oracle_id FROM scryfall_data WHERE id = background_id)), background_id),
+                COALESCE(preferred_printing((SELECT oracle_id FROM scryfall_data WHERE id = signature_spell_id)), signature_spell_id),
+                format, tags, power_level, other_tags, oracle_tags,
+                land_target, price_target, price_target_currency, copy_limit, $2
+            FROM decks
+            WHERE id = $3
+            RETURNING id
+            "#,
+            new_name.to_string(),
+            owner_id,
+            source_deck_id,
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        query!(
+            r#"
+            INSERT INTO deck_cards (deck_id, scryfall_data_id, oracle_id, quantity, board, mvp_at, reference)
+            SELECT $1, COALESCE(preferred_printing(oracle_id), scryfall_data_id), oracle_id, quantity, board, mvp_at, reference
             FROM deck_cards
             WHERE deck_id = $2
             "#,
@@ -813,4 +1254,178 @@ impl DeckRepository for Postgres {
         .await?;
         Ok(row.map(|r| (r.id, r.user_id)))
     }
+
+    // ========
+    //  labels
+    // ========
+    async fn set_deck_labels(
+        &self,
+        request: &SetDeckLabels,
+    ) -> Result<Vec<String>, SetDeckLabelsError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| SetDeckLabelsError::Database(e.into()))?;
+        query!(
+            "DELETE FROM deck_labels WHERE deck_id = $1",
+            request.deck_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| SetDeckLabelsError::Database(e.into()))?;
+        query!(
+            "INSERT INTO deck_labels (deck_id, user_id, label) \
+             SELECT $1, $2, label FROM unnest($3::TEXT[]) AS label \
+             ON CONFLICT DO NOTHING",
+            request.deck_id,
+            request.user_id,
+            &request.labels
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| SetDeckLabelsError::Database(e.into()))?;
+        tx.commit()
+            .await
+            .map_err(|e| SetDeckLabelsError::Database(e.into()))?;
+        Ok(request.labels.clone())
+    }
+
+    // =============
+    //  json backup
+    // =============
+    async fn preferred_printings(
+        &self,
+        oracle_ids: &[uuid::Uuid],
+    ) -> Result<HashMap<uuid::Uuid, uuid::Uuid>, anyhow::Error> {
+        if oracle_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let rows = query!(
+            r#"SELECT oracle_id AS "oracle_id!", printing AS "printing!" FROM
+               (SELECT oracle_id, preferred_printing(oracle_id) AS printing
+                FROM unnest($1::UUID[]) AS oracle_id) r
+               WHERE printing IS NOT NULL"#,
+            oracle_ids
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.oracle_id, row.printing))
+            .collect())
+    }
+
+    // ============
+    //  collection
+    // ============
+    async fn set_owned(&self, request: &SetOwnedCard) -> Result<(), SetOwnedCardError> {
+        if request.quantity == 0 {
+            query!(
+                "DELETE FROM collection_cards WHERE user_id = $1 AND oracle_id = $2",
+                request.user_id,
+                request.oracle_id
+            )
+            .execute(&self.pool)
+            .await
+            .map_err(|e| SetOwnedCardError::Database(e.into()))?;
+            return Ok(());
+        }
+        let stored = query!(
+            "INSERT INTO collection_cards (user_id, oracle_id, quantity) \
+             SELECT $1, $2, $3 \
+             WHERE EXISTS (SELECT 1 FROM scryfall_data WHERE oracle_id = $2) \
+             ON CONFLICT (user_id, oracle_id) \
+             DO UPDATE SET quantity = EXCLUDED.quantity, updated_at = NOW()",
+            request.user_id,
+            request.oracle_id,
+            request.quantity
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| SetOwnedCardError::Database(e.into()))?;
+        if stored.rows_affected() == 0 {
+            return Err(SetOwnedCardError::CardNotFound);
+        }
+        Ok(())
+    }
+
+    async fn get_owned(&self, user_id: Uuid) -> Result<Vec<OwnedCard>, GetOwnedCardsError> {
+        let rows = query!(
+            "SELECT oracle_id, quantity FROM collection_cards \
+             WHERE user_id = $1 ORDER BY oracle_id",
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| GetOwnedCardsError::Database(e.into()))?;
+        Ok(rows
+            .into_iter()
+            .map(|row| OwnedCard {
+                oracle_id: row.oracle_id,
+                quantity: row.quantity,
+            })
+            .collect())
+    }
+
+    async fn deck_missing_cards(
+        &self,
+        user_id: Uuid,
+        deck_id: Uuid,
+    ) -> Result<Vec<MissingDeckCard>, DeckMissingCardsError> {
+        // Needed = deck and sideboard quantities plus one per command zone
+        // slot (stored on the deck, not in deck_cards). Maybeboard cards are
+        // only being considered, so they aren't needed.
+        let rows = query!(
+            r#"WITH needs AS (
+                 SELECT dc.oracle_id, sd.name, dc.quantity
+                 FROM deck_cards dc
+                 JOIN scryfall_data sd ON sd.id = dc.scryfall_data_id
+                 WHERE dc.deck_id = $1 AND dc.board <> 'maybeboard'
+                 UNION ALL
+                 SELECT sd.oracle_id, sd.name, 1
+                 FROM decks d
+                 JOIN scryfall_data sd ON sd.id IN
+                     (d.commander_id, d.partner_commander_id, d.background_id, d.signature_spell_id)
+                 WHERE d.id = $1 AND sd.oracle_id IS NOT NULL
+             )
+             SELECT n.oracle_id AS "oracle_id!", MIN(n.name) AS "name!",
+                    SUM(n.quantity)::INT AS "needed!", COALESCE(MAX(cc.quantity), 0) AS "owned!"
+             FROM needs n
+             LEFT JOIN collection_cards cc ON cc.user_id = $2 AND cc.oracle_id = n.oracle_id
+             GROUP BY n.oracle_id
+             ORDER BY MIN(n.name), n.oracle_id"#,
+            deck_id,
+            user_id
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DeckMissingCardsError::Database(e.into()))?;
+        Ok(rows
+            .into_iter()
+            .map(|row| MissingDeckCard::new(row.oracle_id, row.name, row.needed, row.owned))
+            .collect())
+    }
+}
+
+/// Fills each profile's `labels` from `deck_labels` in one query.
+async fn attach_labels(pool: &PgPool, profiles: &mut [DeckProfile]) -> Result<(), sqlx::Error> {
+    if profiles.is_empty() {
+        return Ok(());
+    }
+    let deck_ids: Vec<Uuid> = profiles.iter().map(|p| p.id).collect();
+    let rows = query!(
+        "SELECT deck_id, label FROM deck_labels WHERE deck_id = ANY($1) ORDER BY deck_id, label",
+        &deck_ids
+    )
+    .fetch_all(pool)
+    .await?;
+    let mut by_deck: HashMap<Uuid, Vec<String>> = HashMap::new();
+    for row in rows {
+        by_deck.entry(row.deck_id).or_default().push(row.label);
+    }
+    for profile in profiles {
+        profile.labels = by_deck.remove(&profile.id).unwrap_or_default();
+    }
+    Ok(())
 }
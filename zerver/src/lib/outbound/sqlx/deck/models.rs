@@ -5,8 +5,12 @@ use crate::outbound::sqlx::deck::error::{IntoDeckCardError, IntoDeckProfileError
 use zwipe_core::domain::{
     card::search_card::card_filter::price_currency::PriceCurrency,
     deck::{
-        Board, DeckCard, DeckOtherTag, PowerLevel, deck_name::DeckName, deck_profile::DeckProfile,
-        format::Format, quantity::Quantity,
+        BannedDeckCard, Board, CardReference, CopyLimit, DeckCard, DeckOtherTag, OrphanedDeckCard,
+        PowerLevel,
+        deck_name::DeckName,
+        deck_profile::DeckProfile,
+        format::{Format, InvalidFormat},
+        quantity::Quantity,
     },
 };
 
@@ -29,6 +33,7 @@ pub struct DatabaseDeckProfile {
     pub land_target: Option<i32>,
     pub price_target: Option<f64>,
     pub price_target_currency: Option<String>,
+    pub copy_limit: String,
     pub share_token: Option<Uuid>,
     pub user_id: Uuid,
     pub card_count: Option<i64>,
@@ -45,6 +50,7 @@ impl TryFrom<DatabaseDeckProfile> for DeckProfile {
     fn try_from(value: DatabaseDeckProfile) -> Result<Self, Self::Error> {
         let name = DeckName::new(value.name)?;
         let format = value.format.map(Format::try_from).transpose()?;
+        let copy_limit = CopyLimit::try_from(value.copy_limit.as_str())?;
         // Unrecognized tag strings are dropped (forward-compatible), like card roles.
         // Deck tags are stored + carried as slugs (Vec<String>); no enum parse.
         let tags = value
@@ -85,6 +91,8 @@ impl TryFrom<DatabaseDeckProfile> for DeckProfile {
             power_level,
             other_tags,
             oracle_tags,
+            // Filled from `deck_labels` by the profile reads that return them.
+            labels: Vec::new(),
             land_target: value.land_target,
             price_target: value.price_target,
             // Unrecognized currency strings fall back to None (USD), forward-compatible.
@@ -92,6 +100,7 @@ impl TryFrom<DatabaseDeckProfile> for DeckProfile {
                 .price_target_currency
                 .as_deref()
                 .and_then(PriceCurrency::from_key),
+            copy_limit,
             share_token: value.share_token,
             user_id: value.user_id,
             card_count: value.card_count.unwrap_or(0),
@@ -115,6 +124,7 @@ pub struct DatabaseDeckCard {
     pub quantity: i32,
     pub board: String,
     pub mvp_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub reference: String,
 }
 
 /// converts database deck card to validated domain deck card
@@ -128,6 +138,7 @@ impl TryFrom<DatabaseDeckCard> for DeckCard {
             Uuid::try_parse(&value.oracle_id).map_err(IntoDeckCardError::InvalidOracleId)?;
         let quantity = Quantity::new(value.quantity)?;
         let board = Board::try_from(value.board.as_str())?;
+        let reference = CardReference::try_from(value.reference.as_str())?;
         Ok(Self {
             deck_id,
             scryfall_data_id,
@@ -135,6 +146,71 @@ impl TryFrom<DatabaseDeckCard> for DeckCard {
             quantity,
             board,
             mvp_at: value.mvp_at,
+            reference,
+        })
+    }
+}
+
+/// raw orphaned deck card record: the stored deck card plus the surviving
+/// printing it could be remapped to
+#[allow(missing_docs)]
+#[derive(Debug, Clone, FromRow)]
+pub struct DatabaseOrphanedDeckCard {
+    pub deck_id: String,
+    pub scryfall_data_id: String,
+    pub oracle_id: String,
+    pub quantity: i32,
+    pub board: String,
+    pub mvp_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub reference: String,
+    pub replacement_id: Option<Uuid>,
+}
+
+/// converts database orphaned deck card to validated domain orphaned deck card
+impl TryFrom<DatabaseOrphanedDeckCard> for OrphanedDeckCard {
+    type Error = IntoDeckCardError;
+    fn try_from(value: DatabaseOrphanedDeckCard) -> Result<Self, Self::Error> {
+        let deck_card = DatabaseDeckCard {
+            deck_id: value.deck_id,
+            scryfall_data_id: value.scryfall_data_id,
+            oracle_id: value.oracle_id,
+            quantity: value.quantity,
+            board: value.board,
+            mvp_at: value.mvp_at,
+            reference: value.reference,
+        };
+        Ok(Self {
+            deck_card: deck_card.try_into()?,
+            replacement_id: value.replacement_id,
+        })
+    }
+}
+
+/// raw database row for a deck card hit by a ban
+#[allow(missing_docs)]
+#[derive(Debug, Clone, FromRow)]
+pub struct DatabaseBannedDeckCard {
+    pub deck_id: Uuid,
+    pub user_id: Uuid,
+    pub deck_name: String,
+    pub format: String,
+    pub oracle_id: Uuid,
+    pub card_name: String,
+    pub banned_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// converts database banned deck card to domain banned deck card
+impl TryFrom<DatabaseBannedDeckCard> for BannedDeckCard {
+    type Error = InvalidFormat;
+    fn try_from(value: DatabaseBannedDeckCard) -> Result<Self, Self::Error> {
+        Ok(Self {
+            deck_id: value.deck_id,
+            user_id: value.user_id,
+            deck_name: value.deck_name,
+            format: Format::try_from(value.format)?,
+            oracle_id: value.oracle_id,
+            card_name: value.card_name,
+            banned_at: value.banned_at,
         })
     }
 }
@@ -159,6 +235,7 @@ mod tests {
             land_target: None,
             price_target: None,
             price_target_currency: None,
+            copy_limit: "standard".to_string(),
             share_token: None,
             user_id: Uuid::new_v4(),
             card_count: Some(0),
@@ -189,4 +266,23 @@ mod tests {
         let profile = DeckProfile::try_from(db).unwrap();
         assert!(profile.color_identity.is_empty());
     }
+
+    #[test]
+    fn copy_limit_is_parsed_and_unknown_values_are_rejected() {
+        let db = DatabaseDeckProfile {
+            copy_limit: "custom:2".to_string(),
+            ..base_profile()
+        };
+        let profile = DeckProfile::try_from(db).unwrap();
+        assert_eq!(profile.copy_limit, CopyLimit::Custom(2));
+
+        let db = DatabaseDeckProfile {
+            copy_limit: "unlimited".to_string(),
+            ..base_profile()
+        };
+        assert!(matches!(
+            DeckProfile::try_from(db),
+            Err(IntoDeckProfileError::CopyLimit(_))
+        ));
+    }
 }
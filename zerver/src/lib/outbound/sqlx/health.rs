@@ -1,7 +1,9 @@
-//! Health check repository implementation (`SELECT 1` connectivity test).
+//! Health check repository implementation (`SELECT 1` connectivity test and
+//! last successful sync lookup).
 
 use anyhow::anyhow;
-use sqlx::query;
+use chrono::{DateTime, Utc};
+use sqlx::{query, query_scalar};
 
 use crate::{
     domain::health::{models::HealthCheckFailed, ports::HealthRepository},
@@ -17,4 +19,14 @@ impl HealthRepository for Postgres {
 
         Ok(())
     }
+
+    async fn get_last_successful_sync(&self) -> Result<Option<DateTime<Utc>>, HealthCheckFailed> {
+        query_scalar(
+            "SELECT MAX(COALESCE(ended_at, started_at)) FROM zervice_metrics
+            WHERE status IN ('success', 'partial_success')",
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| HealthCheckFailed(anyhow!("{e}")))
+    }
 }
@@ -16,10 +16,10 @@ use crate::{
     },
     outbound::sqlx::{postgres::Postgres, user::models::DatabaseUser},
 };
-use sqlx::query_as;
+use sqlx::{query_as, query_scalar};
 use uuid::Uuid;
 use zwipe_core::domain::user::{
-    User,
+    User, UserRole,
     models::hints::MarkHintShown,
     preferences::{UpdatePreferences, UserPreferences},
 };
@@ -61,6 +61,15 @@ impl UserRepository for Postgres {
         Ok(user)
     }
 
+    async fn get_user_role(&self, user_id: Uuid) -> Result<UserRole, GetUserError> {
+        let role: String = query_scalar("SELECT role FROM users WHERE id = $1")
+            .bind(user_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        UserRole::try_from(role.as_str()).map_err(|e| GetUserError::UserFromDb(e.into()))
+    }
+
     // =======
     //  hints
     // =======
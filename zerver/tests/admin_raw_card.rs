@@ -0,0 +1,45 @@
+//! Admin raw-card inspection through the real router: an admin sees the
+//! stored `scryfall_data` and `card_profiles` rows as JSON; everyone else is
+//! refused with 403.
+//!
+//! Requires `DATABASE_URL`: `set -a; source zerver/.env; set +a`.
+
+#![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{TestApp, card, seed_cards};
+use zwipe_core::http::paths::admin_raw_card_route;
+
+#[sqlx::test]
+async fn only_admins_can_read_raw_card_rows(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (admin, admin_id) = app.register("operator").await;
+    let (user, _) = app.register("player").await;
+    sqlx::query("UPDATE users SET role = 'admin' WHERE id = $1::uuid")
+        .bind(&admin_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let bolt = card("Lightning Bolt").mono("R").type_line("Instant");
+    let route = admin_raw_card_route(bolt.id());
+    seed_cards(&pool, &[bolt]).await;
+
+    let (status, body) = app.get(&route, Some(&admin)).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["scryfall_data"]["name"], "Lightning Bolt");
+    assert_eq!(body["scryfall_data"]["type_line"], "Instant");
+    assert_eq!(body["card_profile"]["is_token"], false);
+
+    let (status, _) = app.get(&route, Some(&user)).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = app.get(&route, None).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+    let (status, _) = app
+        .get(&admin_raw_card_route(uuid::Uuid::new_v4()), Some(&admin))
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
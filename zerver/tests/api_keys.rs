@@ -0,0 +1,240 @@
+//! Per-user API keys through the real router: a key authenticates as its
+//! owner, revocation takes effect immediately, a read-only key can read
+//! (including the search POSTs) but not change decks, no key can manage the
+//! account, and made-up keys can't dodge the rate limit.
+//!
+//! Requires `DATABASE_URL`: `set -a; source zerver/.env; set +a`.
+
+#![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+mod common;
+
+use axum::http::StatusCode;
+use common::TestApp;
+use serde_json::{Value, json};
+
+/// Mints a key with `scope` using the session `token`; returns the key body.
+async fn create_key(app: &TestApp, token: &str, scope: &str) -> Value {
+    let (status, key) = app
+        .post(
+            "/api/auth/api-keys",
+            json!({ "label": "script", "scope": scope }),
+            Some(token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "create key: {key}");
+    assert!(key["secret"].as_str().unwrap().starts_with("zwk_"), "{key}");
+    key
+}
+
+#[sqlx::test]
+async fn valid_key_authenticates_as_owner(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let (token, uid) = app.register("scripter").await;
+    let key = create_key(&app, &token, "read_write").await;
+    let secret = key["secret"].as_str().unwrap();
+
+    let (status, me) = app.get("/api/user", Some(secret)).await;
+    assert_eq!(status, StatusCode::OK, "{me}");
+    assert_eq!(me["id"], uid);
+
+    // listed without its secret, and now marked used
+    let (status, keys) = app.get("/api/auth/api-keys", Some(&token)).await;
+    assert_eq!(status, StatusCode::OK, "{keys}");
+    let keys = keys.as_array().unwrap();
+    assert_eq!(keys.len(), 1);
+    assert_eq!(keys[0]["id"], key["id"]);
+    assert!(keys[0].get("secret").is_none(), "{keys:?}");
+    assert!(keys[0]["last_used_at"].is_string(), "{keys:?}");
+
+    // a key can't mint further keys
+    let (status, _) = app
+        .post(
+            "/api/auth/api-keys",
+            json!({ "label": "child", "scope": "read_write" }),
+            Some(secret),
+        )
+        .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[sqlx::test]
+async fn revoked_key_is_rejected(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let (token, _uid) = app.register("revoker").await;
+    let key = create_key(&app, &token, "read_write").await;
+    let secret = key["secret"].as_str().unwrap();
+    let id = key["id"].as_str().unwrap();
+
+    let (status, _) = app
+        .delete(&format!("/api/auth/api-keys/{id}"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, body) = app.get("/api/user", Some(secret)).await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["code"], "token_invalid", "{body}");
+
+    // gone from the list, and a second revoke finds nothing
+    let (_, keys) = app.get("/api/auth/api-keys", Some(&token)).await;
+    assert!(keys.as_array().unwrap().is_empty(), "{keys}");
+    let (status, _) = app
+        .delete(&format!("/api/auth/api-keys/{id}"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test]
+async fn other_users_key_cannot_be_revoked(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let (alice, _) = app.register("alice").await;
+    let (mallory, _) = app.register("mallory").await;
+    let key = create_key(&app, &alice, "read_only").await;
+    let id = key["id"].as_str().unwrap();
+
+    let (status, _) = app
+        .delete(&format!("/api/auth/api-keys/{id}"), Some(&mallory))
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = app
+        .get("/api/user", Some(key["secret"].as_str().unwrap()))
+        .await;
+    assert_eq!(status, StatusCode::OK, "alice's key still works");
+}
+
+#[sqlx::test]
+async fn read_only_key_cannot_mutate_decks(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let (token, _uid) = app.register("reader").await;
+    let (status, deck) = app
+        .post(
+            "/api/deck",
+            json!({ "name": "Session Deck", "format": "commander" }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "{deck}");
+    let deck_id = deck["id"].as_str().unwrap();
+
+    let key = create_key(&app, &token, "read_only").await;
+    let secret = key["secret"].as_str().unwrap();
+
+    // reads work, including a search sent as POST
+    let (status, list) = app.get("/api/deck", Some(secret)).await;
+    assert_eq!(status, StatusCode::OK, "{list}");
+    let (status, results) = app
+        .post(
+            "/api/card/search",
+            json!({ "name_contains": "Goblin" }),
+            Some(secret),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{results}");
+
+    // writes are refused with 403, not 401
+    let (status, body) = app
+        .post(
+            "/api/deck",
+            json!({ "name": "Key Deck", "format": "commander" }),
+            Some(secret),
+        )
+        .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert_eq!(body["code"], "insufficient_scope", "{body}");
+    let (status, _) = app
+        .delete(&format!("/api/deck/{deck_id}"), Some(secret))
+        .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = app
+        .get(&format!("/api/deck/profile/{deck_id}"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::OK, "deck survived the read-only key");
+}
+
+#[sqlx::test]
+async fn key_cannot_manage_the_account(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let (token, _uid) = app.register("keyholder").await;
+    let key = create_key(&app, &token, "read_write").await;
+    let secret = key["secret"].as_str().unwrap();
+    let key_id = key["id"].as_str().unwrap();
+    let session_id = uuid::Uuid::new_v4();
+
+    let refused = [
+        app.get("/api/auth/sessions", Some(secret)).await,
+        app.delete(&format!("/api/auth/sessions/{session_id}"), Some(secret))
+            .await,
+        app.post("/api/auth/logout", json!({}), Some(secret)).await,
+        app.get("/api/auth/api-keys", Some(secret)).await,
+        app.post(
+            "/api/auth/api-keys",
+            json!({ "label": "child", "scope": "read_write" }),
+            Some(secret),
+        )
+        .await,
+        app.delete(&format!("/api/auth/api-keys/{key_id}"), Some(secret))
+            .await,
+        app.put(
+            "/api/user/change-password",
+            json!({ "current_password": "TestPass123!", "new_password": "NewPass456!" }),
+            Some(secret),
+        )
+        .await,
+        app.delete_json(
+            "/api/user/delete-user",
+            json!({ "password": "TestPass123!" }),
+            Some(secret),
+        )
+        .await,
+    ];
+    for (status, body) in refused {
+        assert_eq!(status, StatusCode::FORBIDDEN, "{body}");
+        assert_eq!(body["code"], "insufficient_scope", "{body}");
+    }
+
+    // nothing changed: the key, the session, and the password all still work
+    let (status, _) = app.get("/api/user", Some(secret)).await;
+    assert_eq!(
+        status,
+        StatusCode::OK,
+        "key survived its own revoke attempt"
+    );
+    let (status, sessions) = app.get("/api/auth/sessions", Some(&token)).await;
+    assert_eq!(status, StatusCode::OK, "{sessions}");
+    assert!(!sessions.as_array().unwrap().is_empty(), "{sessions}");
+    let (status, body) = app
+        .post(
+            "/api/auth/login",
+            json!({ "identifier": "keyholder", "password": "TestPass123!" }),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "password unchanged: {body}");
+}
+
+#[sqlx::test]
+async fn made_up_keys_share_the_client_bucket(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let body = json!({ "current_password": "TestPass123!", "new_password": "NewPass456!" });
+
+    // the sensitive limiter allows a burst of 2; a fresh random key per
+    // request must not buy a fresh bucket
+    let mut statuses = Vec::new();
+    for _ in 0..3 {
+        let key = format!("zwk_{}", uuid::Uuid::new_v4().simple());
+        let (status, _) = app
+            .put("/api/user/change-password", body.clone(), Some(&key))
+            .await;
+        statuses.push(status);
+    }
+    assert_eq!(
+        statuses,
+        [
+            StatusCode::UNAUTHORIZED,
+            StatusCode::UNAUTHORIZED,
+            StatusCode::TOO_MANY_REQUESTS
+        ]
+    );
+}
@@ -1,7 +1,8 @@
 //! Auth edge cases that unit tests can't reach: the email-token round-trips
 //! (verify-email, password-reset) driven through the captured `FakeEmailSender`
-//! exactly as a user would from their inbox, refresh-token single-use rotation,
-//! and the login rate-limit lockout (5 / 6s per IP).
+//! exactly as a user would from their inbox, refresh-token single-use rotation
+//! (and its grace for racing refreshes), the login rate-limit lockout (5 / 6s
+//! per IP), and the machine-readable codes on bearer-token rejections.
 //!
 //! Requires `DATABASE_URL`: `set -a; source zerver/.env; set +a`.
 
@@ -10,7 +11,7 @@
 mod common;
 
 use axum::http::StatusCode;
-use common::TestApp;
+use common::{TestApp, expired_access_token};
 use serde_json::json;
 
 #[sqlx::test]
@@ -60,6 +61,18 @@ async fn password_reset_via_captured_token(pool: sqlx::PgPool) {
     let app = TestApp::new(pool);
     let _ = app.register("forgetful").await;
 
+    // an unknown email gets the same answer, and no email goes out
+    let sent = app.emails.sent.lock().unwrap().len();
+    let (status, _) = app
+        .post(
+            "/api/auth/forgot-password",
+            json!({ "email": "nobody@test.local" }),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "unknown email is not revealed");
+    assert_eq!(app.emails.sent.lock().unwrap().len(), sent);
+
     // request the reset, then read the raw token from the captured email
     let (status, _) = app
         .post(
@@ -87,6 +100,16 @@ async fn password_reset_via_captured_token(pool: sqlx::PgPool) {
         "reset-password with the captured token"
     );
 
+    // the token is single-use
+    let (status, _) = app
+        .post(
+            "/api/auth/reset-password",
+            json!({ "token": raw, "new_password": "Again789!" }),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED, "reset token reused");
+
     // the new password authenticates; the old one does not
     let (status, _) = app
         .post(
@@ -154,6 +177,175 @@ async fn refresh_token_is_single_use(pool: sqlx::PgPool) {
     );
 }
 
+/// Replaying a rotated token is treated as theft: every session the user has
+/// is revoked, including the one holding the fresh token.
+///
+/// The rotation is backdated past the grace window, so the replay isn't
+/// mistaken for a racing refresh.
+#[sqlx::test]
+async fn refresh_token_reuse_signs_out_everywhere(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let _ = app.register("replayed").await;
+
+    let login = || async {
+        let (status, session) = app
+            .post(
+                "/api/auth/login",
+                json!({ "identifier": "replayed", "password": "TestPass123!" }),
+                None,
+            )
+            .await;
+        assert_eq!(status, StatusCode::OK, "login: {session}");
+        session
+    };
+    let phone = login().await;
+    let laptop = login().await;
+    let user_id = phone["user"]["id"].as_str().unwrap().to_string();
+    let stolen = phone["refresh_token"]["value"]
+        .as_str()
+        .unwrap()
+        .to_string();
+
+    let (status, rotated) = app
+        .post(
+            "/api/auth/refresh",
+            json!({ "user_id": user_id, "refresh_token": stolen }),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "first refresh: {rotated}");
+    sqlx::query(
+        "UPDATE refresh_tokens SET rotated_at = rotated_at - INTERVAL '1 minute' \
+         WHERE rotated_at IS NOT NULL",
+    )
+    .execute(&app.pool)
+    .await
+    .unwrap();
+
+    let (status, body) = app
+        .post(
+            "/api/auth/refresh",
+            json!({ "user_id": user_id, "refresh_token": stolen }),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED, "replay: {body}");
+
+    // the rotated-in token and the other device's token are gone too
+    for token in [
+        &rotated["refresh_token"]["value"],
+        &laptop["refresh_token"]["value"],
+    ] {
+        let (status, _) = app
+            .post(
+                "/api/auth/refresh",
+                json!({ "user_id": user_id, "refresh_token": token }),
+                None,
+            )
+            .await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+}
+
+/// Two refreshes racing with one token (a retried request, two tabs waking
+/// at once): one rotates it, the other is refused, and no session is revoked.
+#[sqlx::test]
+async fn concurrent_refresh_does_not_sign_out(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let _ = app.register("racer").await;
+
+    let (status, session) = app
+        .post(
+            "/api/auth/login",
+            json!({ "identifier": "racer", "password": "TestPass123!" }),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "login: {session}");
+    let user_id = session["user"]["id"].as_str().unwrap().to_string();
+    let body = json!({
+        "user_id": user_id,
+        "refresh_token": session["refresh_token"]["value"],
+    });
+
+    let (first, second) = tokio::join!(
+        app.post("/api/auth/refresh", body.clone(), None),
+        app.post("/api/auth/refresh", body.clone(), None),
+    );
+    let mut statuses = [first.0, second.0];
+    statuses.sort();
+    assert_eq!(
+        statuses,
+        [StatusCode::OK, StatusCode::UNAUTHORIZED],
+        "exactly one racer rotates the token"
+    );
+
+    // the winner's fresh token still works: the loser revoked nothing
+    let winner = if first.0 == StatusCode::OK {
+        first.1
+    } else {
+        second.1
+    };
+    let (status, body) = app
+        .post(
+            "/api/auth/refresh",
+            json!({
+                "user_id": user_id,
+                "refresh_token": winner["refresh_token"]["value"],
+            }),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "winner's token: {body}");
+}
+
+/// A login upgrades a hash made with weaker Argon2 parameters to the
+/// current ones, and leaves a current hash alone.
+#[sqlx::test]
+async fn login_rehashes_weak_password_hashes(pool: sqlx::PgPool) {
+    use zwipe::domain::auth::models::password::HashedPassword;
+
+    let app = TestApp::new(pool.clone());
+    let (_, uid) = app.register("oldtimer").await;
+    let uid: uuid::Uuid = uid.parse().unwrap();
+    let stored = async || -> HashedPassword {
+        let raw: String = sqlx::query_scalar("SELECT password_hash FROM users WHERE id = $1")
+            .bind(uid)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        HashedPassword::new(&raw).unwrap()
+    };
+    let login = async || {
+        let (status, body) = app
+            .post(
+                "/api/auth/login",
+                json!({ "identifier": "oldtimer", "password": "TestPass123!" }),
+                None,
+            )
+            .await;
+        assert_eq!(status, StatusCode::OK, "login: {body}");
+    };
+
+    let weak = argon2::Params::new(1024, 1, 1, None).unwrap();
+    let old = HashedPassword::hash_with("TestPass123!", weak).unwrap();
+    sqlx::query("UPDATE users SET password_hash = $1 WHERE id = $2")
+        .bind(old.to_string())
+        .bind(uid)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    login().await;
+    let upgraded = stored().await;
+    assert_ne!(upgraded, old, "weak hash should be replaced");
+    assert!(!upgraded.needs_rehash(&argon2::Params::default()));
+    assert!(upgraded.verify("TestPass123!").unwrap());
+
+    login().await;
+    assert_eq!(stored().await, upgraded, "current hash should be kept");
+}
+
 #[sqlx::test]
 async fn login_rate_limit_locks_out(pool: sqlx::PgPool) {
     let app = TestApp::new(pool);
@@ -200,3 +392,22 @@ async fn login_rate_limit_locks_out(pool: sqlx::PgPool) {
         "correct creds stay locked out while limited"
     );
 }
+
+/// A protected route rejects each kind of bad bearer token with a 401 and a
+/// distinct JSON code, so the client knows whether to refresh or re-login.
+#[sqlx::test]
+async fn bearer_rejections_carry_distinct_codes(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let expired = expired_access_token();
+
+    for (token, code) in [
+        (None, "token_missing"),
+        (Some(expired.as_str()), "token_expired"),
+        (Some("not-a-jwt"), "token_invalid"),
+    ] {
+        let (status, body) = app.get("/api/user", token).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED, "{code}: {body}");
+        assert_eq!(body["code"], code, "{body}");
+        assert!(body["message"].is_string(), "{body}");
+    }
+}
@@ -1,6 +1,7 @@
 //! Auth HTTP flows through the real router: register → authed request → login →
-//! refresh (token rotation). Proves the whole stack (handler + middleware +
-//! service + repo + real SQL) works end-to-end in a test.
+//! refresh (token rotation), listing and revoking single sessions, and
+//! case-insensitive username uniqueness. Proves the whole stack (handler +
+//! middleware + service + repo + real SQL) works end-to-end in a test.
 //!
 //! Requires `DATABASE_URL` (dev's value works): `set -a; source zerver/.env`.
 
@@ -65,9 +66,192 @@ async fn login_wrong_password_is_401(pool: sqlx::PgPool) {
     assert_eq!(status, StatusCode::UNAUTHORIZED);
 }
 
+/// Usernames collide regardless of case, yet keep the casing they were
+/// registered with, and login accepts any casing.
+#[sqlx::test]
+async fn username_uniqueness_ignores_case(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    app.register("Carol").await;
+
+    let (status, body) = app
+        .post(
+            "/api/auth/register",
+            serde_json::json!({
+                "username": "carol",
+                "email": "other-carol@test.local",
+                "password": "TestPass123!",
+            }),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{body}");
+
+    let (status, session) = app
+        .post(
+            "/api/auth/login",
+            serde_json::json!({ "identifier": "CAROL", "password": "TestPass123!" }),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "login: {session}");
+    assert_eq!(session["user"]["username"], "Carol");
+}
+
 #[sqlx::test]
 async fn authed_route_without_token_is_401(pool: sqlx::PgPool) {
     let app = TestApp::new(pool);
     let (status, _) = app.get("/api/user", None).await;
     assert_eq!(status, StatusCode::UNAUTHORIZED);
 }
+
+/// `GET /api/auth/me` resolves a stored token to its user, and rejects a bad
+/// token the same way every other protected route does.
+#[sqlx::test]
+async fn me_returns_token_owner(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let (token, user_id) = app.register("whoami").await;
+    let (_other, _) = app.register("someone").await;
+
+    let (status, me) = app.get("/api/auth/me", Some(&token)).await;
+    assert_eq!(status, StatusCode::OK, "{me}");
+    assert_eq!(me["id"], user_id);
+    assert_eq!(me["username"], "whoami");
+
+    for token in [None, Some("not-a-jwt")] {
+        let (status, body) = app.get("/api/auth/me", token).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED, "{body}");
+        assert!(body["code"].is_string(), "{body}");
+    }
+}
+
+#[sqlx::test]
+async fn sessions_list_and_revoke_one_device(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let (token, user_id) = app.register("twodevices").await;
+    let (intruder, _) = app.register("intruder").await;
+
+    // a second device signs in
+    let (status, session) = app
+        .post(
+            "/api/auth/login",
+            serde_json::json!({ "identifier": "twodevices", "password": "TestPass123!" }),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "login: {session}");
+    let refresh = session["refresh_token"]["value"].as_str().unwrap();
+
+    let (status, sessions) = app.get("/api/auth/sessions", Some(&token)).await;
+    assert_eq!(status, StatusCode::OK, "{sessions}");
+    let ids: Vec<String> = sessions
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["id"].as_str().unwrap().to_string())
+        .collect();
+    assert_eq!(ids.len(), 2, "{sessions}");
+    assert!(!sessions.to_string().contains(refresh), "no tokens listed");
+
+    // rotating the token keeps the session's id
+    let (status, refreshed) = app
+        .post(
+            "/api/auth/refresh",
+            serde_json::json!({ "user_id": user_id, "refresh_token": refresh }),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "refresh: {refreshed}");
+    let refresh = refreshed["refresh_token"]["value"].as_str().unwrap();
+    let (_, sessions) = app.get("/api/auth/sessions", Some(&token)).await;
+    let newest = sessions[0]["id"].as_str().unwrap().to_string();
+    assert!(ids.contains(&newest), "{sessions}");
+    assert_eq!(sessions.as_array().unwrap().len(), 2);
+
+    // someone else's session reads as missing
+    let (status, _) = app
+        .delete(&format!("/api/auth/sessions/{newest}"), Some(&intruder))
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // revoking it logs only that device out
+    let (status, _) = app
+        .delete(&format!("/api/auth/sessions/{newest}"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = app
+        .post(
+            "/api/auth/refresh",
+            serde_json::json!({ "user_id": user_id, "refresh_token": refresh }),
+            None,
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    let (_, sessions) = app.get("/api/auth/sessions", Some(&token)).await;
+    assert_eq!(sessions.as_array().unwrap().len(), 1, "{sessions}");
+    assert_ne!(sessions[0]["id"], newest.as_str());
+}
+
+/// Sessions are labelled from the `User-Agent` (or a client-provided device
+/// name), and one opened without either lists as an unknown device.
+#[sqlx::test]
+async fn sessions_are_labelled_by_device(pool: sqlx::PgPool) {
+    use axum::{
+        body::Body,
+        http::{Request, header},
+    };
+    use http_body_util::BodyExt;
+
+    let app = TestApp::new(pool);
+    let (token, user_id) = app.register("labelled").await;
+
+    let send = |request: Request<Body>| {
+        let app = &app;
+        async move {
+            let response = app.send_raw(request).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+        }
+    };
+
+    let session = send(
+        Request::post("/api/auth/login")
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(
+                header::USER_AGENT,
+                "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 \
+                 (KHTML, like Gecko) Chrome/129.0.0.0 Safari/537.36",
+            )
+            .body(Body::from(
+                serde_json::json!({ "identifier": "labelled", "password": "TestPass123!" })
+                    .to_string(),
+            ))
+            .unwrap(),
+    )
+    .await;
+
+    let (_, sessions) = app.get("/api/auth/sessions", Some(&token)).await;
+    let mut labels: Vec<&str> = sessions
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|s| s["device_label"].as_str().unwrap())
+        .collect();
+    labels.sort_unstable();
+    assert_eq!(labels, ["Chrome on macOS", "Unknown device"], "{sessions}");
+
+    // a refresh naming the device relabels that session
+    let refresh = session["refresh_token"]["value"].as_str().unwrap();
+    send(
+        Request::post("/api/auth/refresh")
+            .header(header::CONTENT_TYPE, "application/json")
+            .header("x-device-name", "Work laptop")
+            .body(Body::from(
+                serde_json::json!({ "user_id": user_id, "refresh_token": refresh }).to_string(),
+            ))
+            .unwrap(),
+    )
+    .await;
+    let (_, sessions) = app.get("/api/auth/sessions", Some(&token)).await;
+    assert_eq!(sessions[0]["device_label"], "Work laptop", "{sessions}");
+}
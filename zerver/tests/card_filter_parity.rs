@@ -51,8 +51,12 @@ async fn sql_and_predicate_agree_on_every_criterion(pool: sqlx::PgPool) {
             .keywords(&["Haste"])
             .rarity("rare")
             .set("M10", "Magic 2010")
+            .set_type("core")
+            .games(&["paper", "mtgo"])
             .artist("Alice Art")
             .usd("5.00")
+            .eur("4.20")
+            .edhrec_rank(120)
             .flavor_text("Lightning fast")
             .categories(&["burn"])
             .legal("commander"),
@@ -65,13 +69,16 @@ async fn sql_and_predicate_agree_on_every_criterion(pool: sqlx::PgPool) {
             .produced_mana("G")
             .categories(&["ramp"])
             .rarity("common")
+            .games(&["paper", "arena"])
             .artist("Bob Art")
             .usd("0.50"),
         card("Counterspell")
             .mono("U")
+            .mana_cost("{U}{U}")
             .cmc(2.0)
             .type_line("Instant")
             .oracle_text("Counter target spell.")
+            .promo_types(&["prerelease", "datestamped"])
             .categories(&["counterspell"])
             .rarity("common"),
         card("Sol Ring")
@@ -80,24 +87,34 @@ async fn sql_and_predicate_agree_on_every_criterion(pool: sqlx::PgPool) {
             .type_line("Artifact")
             .categories(&["ramp"])
             .rarity("uncommon")
-            .usd("2.00"),
+            .set_type("box")
+            .usd("2.00")
+            .eur("")
+            .tix("0.02")
+            .edhrec_rank(1),
         card("Wrath of God")
+            .released("1994-12-31")
             .mono("W")
+            .mana_cost("{2}{W}{W}")
             .cmc(4.0)
             .type_line("Sorcery")
             .oracle_text("Destroy all creatures.")
             .categories(&["wipe", "removal"])
             .oracle_tags(&["sweeper"])
-            .rarity("rare"),
+            .rarity("rare")
+            .legality("modern", "banned"),
         card("Lightning Bolt")
             .mono("R")
+            .mana_cost("{R}")
             .cmc(1.0)
             .type_line("Instant")
             .oracle_text("Lightning Bolt deals 3 damage to any target.")
             .categories(&["burn", "removal"])
             .oracle_tags(&["burn-any", "spot-removal"])
             .rarity("common")
-            .usd("1.00"),
+            .usd("1.00")
+            .edhrec_rank(40)
+            .released("1993-08-05"),
         card("Krenko, Mob Boss")
             .mono("R")
             .cmc(4.0)
@@ -105,6 +122,7 @@ async fn sql_and_predicate_agree_on_every_criterion(pool: sqlx::PgPool) {
             .power("3")
             .toughness("3")
             .rarity("mythic")
+            .released("2012-07-13")
             .legal("commander"),
         card("Island")
             .color_identity("")
@@ -146,16 +164,71 @@ async fn sql_and_predicate_agree_on_every_criterion(pool: sqlx::PgPool) {
             .cmc(3.0)
             .type_line("Creature — Horror")
             .content_warning(true),
+        card("Tarmogoyf")
+            .mono("G")
+            .cmc(2.0)
+            .type_line("Creature — Lhurgoyf")
+            .power("*")
+            .toughness("1+*"),
+        card("Jace Beleren")
+            .mono("U")
+            .cmc(3.0)
+            .type_line("Legendary Planeswalker — Jace")
+            .loyalty("3"),
+        card("Nissa, Steward of Elements")
+            .mono("G")
+            .cmc(2.0)
+            .type_line("Legendary Planeswalker — Nissa")
+            .loyalty("X"),
+        card("Invasion of Zendikar")
+            .layout("battle")
+            .mono("G")
+            .cmc(4.0)
+            .type_line("Battle — Siege")
+            .textless(true)
+            .defense("3"),
+        card("Rhystic Study")
+            .mono("U")
+            .cmc(3.0)
+            .story_spotlight(true)
+            .type_line("Enchantment")
+            .game_changer(true),
         card("Ancestral Recall")
             .mono("U")
             .cmc(1.0)
             .type_line("Instant")
             .lang("ja")
             .rarity("common"),
+        card("Gitaxian Probe")
+            .mono("U")
+            .mana_cost("{U/P}")
+            .cmc(1.0)
+            .type_line("Sorcery")
+            .rarity("common"),
+        card("Serra Angel")
+            .mono("W")
+            .mana_cost("{3}{W}{W}")
+            .cmc(5.0)
+            .type_line("Creature — Angel")
+            .power("4")
+            .toughness("4")
+            .frame_effects(&["showcase", "legendary"])
+            .keywords(&["Flying", "Vigilance"])
+            .rarity("uncommon"),
+        card("Lightning Helix")
+            .mono("RW")
+            .mana_cost("{R}{W}")
+            .cmc(2.0)
+            .type_line("Instant")
+            .oracle_text("Lightning Helix deals 3 damage to any target and you gain 3 life.")
+            .watermark("boros")
+            .rarity("uncommon"),
         card("Black Lotus")
             .color_identity("")
             .cmc(0.0)
             .type_line("Artifact")
+            .border_color("borderless")
+            .finishes(&["nonfoil", "foil"])
             .usd("10000.00")
             .rarity("rare")
             .flavor_text("Power nine"),
@@ -176,6 +249,8 @@ async fn sql_and_predicate_agree_on_every_criterion(pool: sqlx::PgPool) {
     let battery: Vec<(&str, Value)> = vec![
         // text
         ("name_contains", json!({ "name_contains": "goblin" })),
+        ("name_equals", json!({ "name_equals": "goblin guide" })),
+        ("text_search", json!({ "text_search": "goblin" })),
         (
             "name_not_contains",
             json!({ "name_not_contains": "goblin" }),
@@ -299,13 +374,69 @@ async fn sql_and_predicate_agree_on_every_criterion(pool: sqlx::PgPool) {
         // mana / combat
         ("cmc_equals", json!({ "cmc_equals": 1.0 })),
         ("cmc_range", json!({ "cmc_range": [2.0, 4.0] })),
+        (
+            "mana_cost_contains",
+            json!({ "mana_cost_contains": "{U/P}" }),
+        ),
+        (
+            "mana_cost_equals",
+            json!({ "mana_cost_equals": "{2}{W}{W}" }),
+        ),
         ("power_equals", json!({ "power_equals": 2 })),
         ("power_range", json!({ "power_range": [1, 3] })),
         ("toughness_equals", json!({ "toughness_equals": 2 })),
         ("toughness_range", json!({ "toughness_range": [1, 3] })),
+        ("power_is_variable", json!({ "power_is_variable": true })),
+        (
+            "toughness_not_variable",
+            json!({ "toughness_is_variable": false }),
+        ),
+        (
+            "power_range_star_floor",
+            json!({ "power_range": [0, 1], "variable_stat_floor": 0 }),
+        ),
+        ("loyalty_equals", json!({ "loyalty_equals": 3 })),
+        ("loyalty_range", json!({ "loyalty_range": [0, 5] })),
+        (
+            "loyalty_is_variable",
+            json!({ "loyalty_is_variable": true }),
+        ),
+        (
+            "loyalty_not_variable",
+            json!({ "loyalty_is_variable": false }),
+        ),
+        ("defense_equals", json!({ "defense_equals": 3 })),
+        ("defense_range", json!({ "defense_range": [5, 2] })),
+        (
+            "toughness_range_star_floor",
+            json!({ "toughness_range": [2, 3], "variable_stat_floor": 1 }),
+        ),
         // price (usd is the default currency)
         ("price_max", json!({ "price_max": 5.0 })),
         ("price_min", json!({ "price_min": 100.0 })),
+        (
+            "price_eur_range",
+            json!({ "price_min": 0.0, "price_max": 5.0, "price_currency": "Eur" }),
+        ),
+        (
+            "price_tix_range",
+            json!({ "price_min": 0.01, "price_max": 0.05, "price_currency": "Tix" }),
+        ),
+        // popularity (unranked cards never match)
+        (
+            "edhrec_rank_range",
+            json!({ "edhrec_rank_range": [100, 1] }),
+        ),
+        // release date (fixtures default to 2020-01-01)
+        ("released_after", json!({ "released_after": "2000-01-01" })),
+        (
+            "released_before",
+            json!({ "released_before": "2000-01-01" }),
+        ),
+        (
+            "released_window",
+            json!({ "released_after": "1993-01-01", "released_before": "1994-12-31" }),
+        ),
         // colors
         (
             "color_identity_equals",
@@ -315,6 +446,12 @@ async fn sql_and_predicate_agree_on_every_criterion(pool: sqlx::PgPool) {
             "color_identity_within",
             json!({ "color_identity_within": ["R", "G"] }),
         ),
+        ("color_count_equals", json!({ "color_count_equals": 1 })),
+        ("color_count_range", json!({ "color_count_range": [2, 0] })),
+        ("is_multicolor", json!({ "is_multicolor": true })),
+        ("is_not_multicolor", json!({ "is_multicolor": false })),
+        ("is_colorless", json!({ "is_colorless": true })),
+        ("is_not_colorless", json!({ "is_colorless": false })),
         // metadata
         (
             "rarity_equals_any",
@@ -332,6 +469,10 @@ async fn sql_and_predicate_agree_on_every_criterion(pool: sqlx::PgPool) {
             "set_excludes_any",
             json!({ "set_excludes_any": ["Test Set"] }),
         ),
+        (
+            "set_type_equals_any",
+            json!({ "set_type_equals_any": ["core", "box"] }),
+        ),
         (
             "artist_equals_any",
             json!({ "artist_equals_any": ["Alice Art"] }),
@@ -340,6 +481,28 @@ async fn sql_and_predicate_agree_on_every_criterion(pool: sqlx::PgPool) {
             "artist_excludes_any",
             json!({ "artist_excludes_any": ["Alice Art"] }),
         ),
+        (
+            "frame_effects_contains_any",
+            json!({ "frame_effects_contains_any": ["showcase", "etched"] }),
+        ),
+        (
+            "border_color_equals_any",
+            json!({ "border_color_equals_any": ["borderless"] }),
+        ),
+        (
+            "watermark_equals_any",
+            json!({ "watermark_equals_any": ["boros", "azorius"] }),
+        ),
+        ("available_in_game", json!({ "available_in_game": "arena" })),
+        (
+            "available_in_game_paper",
+            json!({ "available_in_game": "paper" }),
+        ),
+        ("finish_available", json!({ "finish_available": "foil" })),
+        (
+            "promo_types_contains_any",
+            json!({ "promo_types_contains_any": ["prerelease", "bundle"] }),
+        ),
         ("language", json!({ "language": "ja" })),
         // flags
         ("is_token_true", json!({ "is_token": true })),
@@ -351,6 +514,12 @@ async fn sql_and_predicate_agree_on_every_criterion(pool: sqlx::PgPool) {
         ("promo", json!({ "promo": true })),
         ("content_warning_true", json!({ "content_warning": true })),
         ("content_warning_false", json!({ "content_warning": false })),
+        ("game_changer_true", json!({ "game_changer": true })),
+        ("game_changer_false", json!({ "game_changer": false })),
+        ("story_spotlight_true", json!({ "story_spotlight": true })),
+        ("story_spotlight_false", json!({ "story_spotlight": false })),
+        ("textless_true", json!({ "textless": true })),
+        ("textless_false", json!({ "textless": false })),
         // legality + commander eligibility
         (
             "legalities_contains_any",
@@ -360,6 +529,14 @@ async fn sql_and_predicate_agree_on_every_criterion(pool: sqlx::PgPool) {
             "is_commander_in_format",
             json!({ "is_commander_in_format": "commander" }),
         ),
+        (
+            "legal_in_banned",
+            json!({ "legal_in": [["modern", "banned"]] }),
+        ),
+        (
+            "legal_in_not_legal_when_unlisted",
+            json!({ "legal_in": [["modern", "not_legal"], ["commander", "legal"]] }),
+        ),
     ];
 
     let mut mismatches: Vec<String> = Vec::new();
@@ -12,6 +12,10 @@ mod common;
 use axum::http::StatusCode;
 use common::{TestApp, card, seed_cards};
 use uuid::Uuid;
+use zwipe::{
+    domain::card::ports::CardRepository, inbound::external::scryfall::set::ScryfallSet,
+    outbound::sqlx::postgres::Postgres,
+};
 
 /// The string list a metadata endpoint returns.
 async fn list(app: &TestApp, path: &str) -> Vec<String> {
@@ -116,6 +120,119 @@ async fn printings_returns_all_printings_of_an_oracle(pool: sqlx::PgPool) {
     );
 }
 
+#[sqlx::test]
+async fn card_with_printings_lists_the_other_printings(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let shared = Uuid::from_u128(0x0B01);
+    let focused = card("Reprinted Card")
+        .oracle(Some(shared))
+        .set("A", "Alpha Set")
+        .released("2001-01-01");
+    let older = card("Reprinted Card")
+        .oracle(Some(shared))
+        .set("B", "Beta Set")
+        .released("1999-01-01")
+        .usd("4.20");
+    let newer = card("Reprinted Card")
+        .oracle(Some(shared))
+        .set("C", "Gamma Set")
+        .released("2020-01-01");
+    let (focused_id, older_id, newer_id) = (focused.id(), older.id(), newer.id());
+    seed_cards(&pool, &[focused, older, newer, card("Unrelated Card")]).await;
+
+    let (status, body) = app
+        .get(&format!("/api/card/{focused_id}/with-printings"), None)
+        .await;
+    assert_eq!(status, StatusCode::OK, "card with printings: {body}");
+    assert_eq!(body["card"]["scryfall_data"]["id"], focused_id.to_string());
+    let printings = body["printings"].as_array().unwrap();
+    let ids: Vec<&str> = printings
+        .iter()
+        .map(|p| p["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        ids,
+        [older_id.to_string(), newer_id.to_string()],
+        "the other printings, oldest first, without the focused one"
+    );
+    assert_eq!(printings[0]["set_name"], "Beta Set");
+    assert_eq!(printings[0]["price_usd"], "4.20");
+
+    let (status, _) = app
+        .get(&format!("/api/card/{}/with-printings", Uuid::nil()), None)
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND, "unknown card");
+}
+
+#[sqlx::test]
+async fn set_icon_resolves_known_code_and_404s_unknown(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    seed_cards(&pool, &[card("Goblin Guide").set("ZEN", "Zendikar")]).await;
+
+    // case-insensitive on the code; with no catalog entry, the URI is keyed
+    // by the lowercase code
+    let (status, body) = app.get("/api/card/sets/Zen/icon", None).await;
+    assert_eq!(status, StatusCode::OK, "set icon: {body}");
+    assert_eq!(body["set_code"], "zen");
+    assert_eq!(body["set_name"], "Zendikar");
+    assert_eq!(
+        body["icon_svg_uri"],
+        "https://svgs.scryfall.io/sets/zen.svg"
+    );
+    assert!(
+        !body["rarity_colors"].as_array().unwrap().is_empty(),
+        "rarity color hints: {body}"
+    );
+
+    let (status, _) = app.get("/api/card/sets/nope/icon", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND, "unknown set code");
+}
+
+/// A synced catalog entry wins over the code-keyed URI: a token set draws
+/// its parent's symbol, which its own code doesn't name.
+#[sqlx::test]
+async fn set_icon_uses_the_catalog_symbol(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    seed_cards(
+        &pool,
+        &[card("Goblin Token").set("TZEN", "Zendikar Tokens")],
+    )
+    .await;
+    let repo = Postgres { pool: pool.clone() };
+    let tokens = ScryfallSet {
+        code: "tzen".to_string(),
+        name: "Zendikar Tokens".to_string(),
+        parent_set_code: Some("zen".to_string()),
+        icon_svg_uri: "https://svgs.scryfall.io/sets/zen.svg?1".to_string(),
+    };
+    assert_eq!(
+        repo.sync_set_catalog(std::slice::from_ref(&tokens))
+            .await
+            .unwrap(),
+        1
+    );
+
+    let (status, body) = app.get("/api/card/sets/TZEN/icon", None).await;
+    assert_eq!(status, StatusCode::OK, "set icon: {body}");
+    assert_eq!(body["set_code"], "tzen");
+    assert_eq!(
+        body["icon_svg_uri"],
+        "https://svgs.scryfall.io/sets/zen.svg?1"
+    );
+
+    // a re-sync updates the entry in place
+    let refreshed = ScryfallSet {
+        icon_svg_uri: "https://svgs.scryfall.io/sets/zen.svg?2".to_string(),
+        ..tokens
+    };
+    assert_eq!(repo.sync_set_catalog(&[refreshed]).await.unwrap(), 1);
+    let (_, body) = app.get("/api/card/sets/tzen/icon", None).await;
+    assert_eq!(
+        body["icon_svg_uri"],
+        "https://svgs.scryfall.io/sets/zen.svg?2"
+    );
+}
+
 /// A fresh `TestApp` over a cloned pool — the metadata test issues several
 /// independent public GETs and each helper call wants an app to drive.
 fn app_of(pool: sqlx::PgPool) -> TestApp {
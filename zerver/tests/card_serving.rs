@@ -162,6 +162,43 @@ async fn search_requires_auth(pool: sqlx::PgPool) {
     assert_eq!(status, StatusCode::UNAUTHORIZED);
 }
 
+#[sqlx::test]
+async fn search_refuses_queries_without_intent(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, _) = app.register("firehose").await;
+    seed_cards(&pool, &[card("Any Card").mono("R")]).await;
+
+    // config alone (no criterion, sort, or synergy) would serve the whole pool
+    for path in ["/api/card/search", "/api/card/search/summary"] {
+        let (status, body) = app
+            .post(
+                path,
+                json!({ "is_playable": true, "language": "en", "limit": 50 }),
+                Some(&token),
+            )
+            .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{path}: {body}");
+    }
+    let (status, _) = app
+        .post(
+            "/api/card/search",
+            json!({ "name_contains": "Any", "then_order_by": "Random" }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // a sort alone is intent enough
+    let (status, _) = app
+        .post(
+            "/api/card/search",
+            json!({ "order_by": "Name" }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
 /// Phase 4 end-to-end: a deck's *persisted* selected oracle tags lift matching
 /// cards through the real serve endpoint (`POST /api/deck/{id}/card/search`).
 /// Proves the whole path — `search_deck_cards` loading `deck_profile.oracle_tags`
@@ -281,3 +318,52 @@ async fn deck_selected_otags_lift_matching_cards_end_to_end(pool: sqlx::PgPool)
         "no selected otags => matching cards stay in band 1, off the first page"
     );
 }
+
+#[sqlx::test]
+async fn search_negotiates_language_from_accept_language(pool: sqlx::PgPool) {
+    use axum::{
+        body::Body,
+        http::{Request, header},
+    };
+    use http_body_util::BodyExt;
+
+    let app = TestApp::new(pool.clone());
+    let (token, _) = app.register("polyglot").await;
+    seed_cards(
+        &pool,
+        &[
+            card("Island Sanctuary"),
+            card("Island Fish Jasconius").lang("ja"),
+        ],
+    )
+    .await;
+
+    let search = |accept_language: &'static str, body: serde_json::Value| {
+        let request = Request::post("/api/card/search")
+            .header(header::AUTHORIZATION, format!("Bearer {token}"))
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::ACCEPT_LANGUAGE, accept_language)
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let app = &app;
+        async move {
+            let response = app.send_raw(request).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            let bytes = response.into_body().collect().await.unwrap().to_bytes();
+            names(&serde_json::from_slice(&bytes).unwrap())
+        }
+    };
+
+    let island = json!({ "name_contains": "Island" });
+    assert_eq!(
+        search("ja-JP,ja;q=0.9", island.clone()).await,
+        vec!["Island Fish Jasconius"]
+    );
+    // unsupported: falls back to English
+    assert_eq!(search("xx", island).await, vec!["Island Sanctuary"]);
+    // a language named in the body wins over the header
+    assert_eq!(
+        search("xx", json!({ "name_contains": "Island", "language": "ja" })).await,
+        vec!["Island Fish Jasconius"]
+    );
+}
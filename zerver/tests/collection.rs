@@ -0,0 +1,346 @@
+//! Card collection through the real router: recording owned quantities and
+//! listing what a deck still needs after them — partial and full ownership,
+//! maybeboard and command zone handling, per-user isolation, set
+//! completion counted by distinct card, and NDJSON collection import (which
+//! takes bodies past the general request size cap).
+//!
+//! Requires `DATABASE_URL`: `set -a; source zerver/.env; set +a`.
+
+#![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{TestApp, card, seed_cards};
+use serde_json::{Value, json};
+use uuid::Uuid;
+
+/// `name -> (needed, owned, missing)` from a missing-cards listing.
+fn lines(list: &Value) -> Vec<(String, i64, i64, i64)> {
+    list.as_array()
+        .unwrap()
+        .iter()
+        .map(|c| {
+            (
+                c["name"].as_str().unwrap().to_string(),
+                c["needed"].as_i64().unwrap(),
+                c["owned"].as_i64().unwrap(),
+                c["missing"].as_i64().unwrap(),
+            )
+        })
+        .collect()
+}
+
+#[sqlx::test]
+async fn missing_cards_subtract_the_collection(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, _) = app.register("collector").await;
+
+    let commander = card("Krenko, Mob Boss")
+        .mono("R")
+        .type_line("Legendary Creature — Goblin Warrior");
+    let bolt = card("Lightning Bolt").mono("R").type_line("Instant");
+    let guide = card("Goblin Guide")
+        .mono("R")
+        .type_line("Creature — Goblin");
+    let shock = card("Shock").mono("R").type_line("Instant");
+    let commander_id = commander.id();
+    let [bolt_oracle, guide_oracle, shock_oracle] =
+        [&bolt, &guide, &shock].map(|c| c.oracle_id().unwrap());
+    let rows = [
+        (&bolt, 2, "deck"),
+        (&guide, 1, "sideboard"),
+        (&shock, 3, "maybeboard"),
+    ]
+    .map(|(c, qty, board)| (c.id(), c.oracle_id().unwrap(), qty, board));
+    seed_cards(&pool, &[commander, bolt, guide, shock]).await;
+
+    let (status, deck) = app
+        .post(
+            "/api/deck",
+            json!({ "name": "Goblins", "format": "commander", "commander_id": commander_id }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "{deck}");
+    let deck_id = Uuid::parse_str(deck["id"].as_str().unwrap()).unwrap();
+    for (sid, oid, qty, board) in rows {
+        sqlx::query(
+            "INSERT INTO deck_cards (deck_id, scryfall_data_id, oracle_id, quantity, board) \
+             VALUES ($1, $2, $3, $4, $5)",
+        )
+        .bind(deck_id)
+        .bind(sid)
+        .bind(oid)
+        .bind(qty)
+        .bind(board)
+        .execute(&pool)
+        .await
+        .unwrap();
+    }
+
+    // one of two bolts, every goblin guide (and then some)
+    for (oracle_id, quantity) in [(bolt_oracle, 1), (guide_oracle, 4), (shock_oracle, 1)] {
+        let (status, body) = app
+            .put(
+                &format!("/api/collection/{oracle_id}"),
+                json!({ "quantity": quantity }),
+                Some(&token),
+            )
+            .await;
+        assert_eq!(status, StatusCode::NO_CONTENT, "{body}");
+    }
+    let (status, owned) = app.get("/api/collection", Some(&token)).await;
+    assert_eq!(status, StatusCode::OK, "{owned}");
+    assert_eq!(owned.as_array().unwrap().len(), 3);
+
+    let (status, missing) = app
+        .get(&format!("/api/deck/{deck_id}/missing"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::OK, "{missing}");
+    // maybeboard cards aren't needed; the commander is
+    assert_eq!(
+        lines(&missing),
+        [
+            ("Goblin Guide".to_string(), 1, 4, 0),
+            ("Krenko, Mob Boss".to_string(), 1, 0, 1),
+            ("Lightning Bolt".to_string(), 2, 1, 1),
+        ]
+    );
+
+    // buying the second bolt covers it; 0 drops a card from the collection
+    for (oracle_id, quantity) in [(bolt_oracle, 2), (guide_oracle, 0)] {
+        let (status, _) = app
+            .put(
+                &format!("/api/collection/{oracle_id}"),
+                json!({ "quantity": quantity }),
+                Some(&token),
+            )
+            .await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+    }
+    let (_, missing) = app
+        .get(&format!("/api/deck/{deck_id}/missing"), Some(&token))
+        .await;
+    assert_eq!(
+        lines(&missing),
+        [
+            ("Goblin Guide".to_string(), 1, 0, 1),
+            ("Krenko, Mob Boss".to_string(), 1, 0, 1),
+            ("Lightning Bolt".to_string(), 2, 2, 0),
+        ]
+    );
+    let (_, owned) = app.get("/api/collection", Some(&token)).await;
+    assert_eq!(owned.as_array().unwrap().len(), 2);
+}
+
+#[sqlx::test]
+async fn collection_is_validated_and_private(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (alice, _) = app.register("alice").await;
+    let (mallory, _) = app.register("mallory").await;
+    let bolt = card("Lightning Bolt").mono("R");
+    let bolt_oracle = bolt.oracle_id().unwrap();
+    seed_cards(&pool, &[bolt]).await;
+
+    let (status, _) = app
+        .put(
+            &format!("/api/collection/{bolt_oracle}"),
+            json!({ "quantity": -1 }),
+            Some(&alice),
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    let (status, _) = app
+        .put(
+            &format!("/api/collection/{}", Uuid::new_v4()),
+            json!({ "quantity": 1 }),
+            Some(&alice),
+        )
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = app
+        .put(
+            &format!("/api/collection/{bolt_oracle}"),
+            json!({ "quantity": 4 }),
+            Some(&alice),
+        )
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    // mallory sees neither alice's collection nor her deck's shopping list
+    let (_, owned) = app.get("/api/collection", Some(&mallory)).await;
+    assert!(owned.as_array().unwrap().is_empty(), "{owned}");
+    let (status, deck) = app
+        .post(
+            "/api/deck",
+            json!({ "name": "Burn", "format": "modern" }),
+            Some(&alice),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "{deck}");
+    let (status, _) = app
+        .get(
+            &format!("/api/deck/{}/missing", deck["id"].as_str().unwrap()),
+            Some(&mallory),
+        )
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test]
+async fn set_completion_counts_distinct_owned_cards(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, _) = app.register("completionist").await;
+    let (other, _) = app.register("bystander").await;
+
+    let set: Vec<_> = (1..=10)
+        .map(|i| card(&format!("Set Card {i}")).set("TST", "Test Set"))
+        .collect();
+    // owned, but from another set: must not count toward TST
+    let elsewhere = card("Elsewhere").set("OTH", "Other Set");
+    let owned: Vec<Uuid> = set
+        .iter()
+        .take(5)
+        .chain([&elsewhere])
+        .map(|c| c.oracle_id().unwrap())
+        .collect();
+    let mut cards = set;
+    cards.push(elsewhere);
+    seed_cards(&pool, &cards).await;
+
+    for (i, oracle_id) in owned.iter().enumerate() {
+        // quantity doesn't matter, only that the card is owned
+        let (status, _) = app
+            .put(
+                &format!("/api/collection/{oracle_id}"),
+                json!({ "quantity": i + 1 }),
+                Some(&token),
+            )
+            .await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+    }
+
+    let (status, body) = app.get("/api/card/sets/tst/completion", Some(&token)).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["set_code"], "tst");
+    assert_eq!(body["set_name"], "Test Set");
+    assert_eq!(body["owned"], 5);
+    assert_eq!(body["total"], 10);
+    assert_eq!(body["percent"], 50.0);
+
+    let (_, body) = app.get("/api/card/sets/TST/completion", Some(&other)).await;
+    assert_eq!(body["owned"], 0);
+
+    let (status, _) = app
+        .get("/api/card/sets/nope/completion", Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test]
+async fn ndjson_import_stores_known_cards_and_reports_the_rest(pool: sqlx::PgPool) {
+    use axum::{
+        body::Body,
+        http::{Request, header},
+    };
+    use http_body_util::BodyExt;
+
+    let app = TestApp::new(pool.clone());
+    let (token, _) = app.register("importer").await;
+
+    let bolt = card("Lightning Bolt").mono("R");
+    let bolt_reprint = card("Lightning Bolt").mono("R").oracle(bolt.oracle_id());
+    let sol_ring = card("Sol Ring");
+    let (bolt_oracle, reprint_id) = (bolt.oracle_id().unwrap(), bolt_reprint.id());
+    let sol_ring_oracle = sol_ring.oracle_id().unwrap();
+    seed_cards(&pool, &[bolt, bolt_reprint, sol_ring]).await;
+    let unknown = Uuid::new_v4();
+
+    // by oracle id, by printing id (adds to the same card), unknown, and junk
+    let ndjson = format!(
+        "{{\"oracle_or_scryfall_id\":\"{bolt_oracle}\",\"quantity\":2}}\n\
+         {{\"oracle_or_scryfall_id\":\"{reprint_id}\",\"quantity\":1}}\n\
+         \n\
+         {{\"oracle_or_scryfall_id\":\"{unknown}\",\"quantity\":4}}\n\
+         not json\n\
+         {{\"oracle_or_scryfall_id\":\"{sol_ring_oracle}\",\"quantity\":1}}\n"
+    );
+    let request = Request::post("/api/collection/import")
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(header::CONTENT_TYPE, "application/x-ndjson")
+        .body(Body::from(ndjson))
+        .unwrap();
+    let response = app.send_raw(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let report: Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(report["imported"], 2);
+    assert_eq!(report["unknown_ids"], json!([unknown.to_string()]));
+    assert_eq!(report["invalid_lines"], json!([5]));
+
+    let (_, owned) = app.get("/api/collection", Some(&token)).await;
+    let mut owned: Vec<(String, i64)> = owned
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| {
+            (
+                c["oracle_id"].as_str().unwrap().to_string(),
+                c["quantity"].as_i64().unwrap(),
+            )
+        })
+        .collect();
+    owned.sort();
+    let mut expected = vec![
+        (bolt_oracle.to_string(), 3),
+        (sol_ring_oracle.to_string(), 1),
+    ];
+    expected.sort();
+    assert_eq!(owned, expected);
+}
+
+#[sqlx::test]
+async fn imports_take_bodies_past_the_general_size_cap(pool: sqlx::PgPool) {
+    use axum::{
+        body::Body,
+        http::{Request, header},
+    };
+
+    let app = TestApp::new(pool.clone());
+    let (token, _) = app.register("bulk_importer").await;
+    let sol_ring = card("Sol Ring");
+    let sol_ring_oracle = sol_ring.oracle_id().unwrap();
+    seed_cards(&pool, &[sol_ring]).await;
+
+    // 2 MiB: past the 1 MiB default, within the 16 MiB import cap
+    let padding = "\n".repeat(2 * 1024 * 1024);
+    let search = format!("{padding}{{\"name_contains\":\"Sol\"}}");
+    let request = Request::post("/api/card/search")
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(search))
+        .unwrap();
+    let response = app.send_raw(request).await;
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    let ndjson =
+        format!("{padding}{{\"oracle_or_scryfall_id\":\"{sol_ring_oracle}\",\"quantity\":1}}\n");
+    let request = Request::post("/api/collection/import")
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .header(header::CONTENT_TYPE, "application/x-ndjson")
+        .body(Body::from(ndjson))
+        .unwrap();
+    let response = app.send_raw(request).await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    // an ordinary request is untouched
+    let (status, _) = app
+        .post(
+            "/api/card/search",
+            json!({ "name_contains": "Sol" }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+}
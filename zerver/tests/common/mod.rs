@@ -40,13 +40,35 @@ use zwipe::{
         },
         health, metrics, user,
     },
-    inbound::http::{AppState, build_router},
+    inbound::http::{
+        AppState, body_limit::BodyLimits, build_router, client_ip::TrustedProxies,
+        https::HttpsPolicy, security_headers::SecurityHeaders,
+    },
     outbound::sqlx::postgres::Postgres,
 };
 use zwipe_core::domain::card::scryfall_data::rarity::Rarity;
 
 const TEST_JWT_SECRET: &str = "test-jwt-secret-that-is-at-least-32-characters-long";
 
+/// A correctly signed access token whose `exp` is an hour in the past — what a
+/// client holds after idling past expiry.
+pub fn expired_access_token() -> String {
+    let now = chrono::Utc::now().timestamp();
+    let claims = json!({
+        "user_id": Uuid::new_v4(),
+        "username": "expired",
+        "email": "expired@test.local",
+        "exp": now - 3600,
+        "iat": now - 7200,
+    });
+    jsonwebtoken::encode(
+        &jsonwebtoken::Header::default(),
+        &claims,
+        &jsonwebtoken::EncodingKey::from_secret(TEST_JWT_SECRET.as_bytes()),
+    )
+    .unwrap()
+}
+
 /// Captures every outbound email instead of hitting Resend. Later slices read
 /// the verify/reset token out of the last captured body.
 #[derive(Clone, Default)]
@@ -100,6 +122,11 @@ pub struct TestApp {
 
 impl TestApp {
     pub fn new(pool: PgPool) -> Self {
+        Self::with_origins(pool, vec![])
+    }
+
+    /// Like [`TestApp::new`], with CORS allowing the given origins.
+    pub fn with_origins(pool: PgPool, allowed_origins: Vec<header::HeaderValue>) -> Self {
         let db = Postgres { pool: pool.clone() };
         let emails = FakeEmailSender::default();
         let jwt_secret = JwtSecret::new(TEST_JWT_SECRET).unwrap();
@@ -133,7 +160,15 @@ impl TestApp {
             web_base_url: Arc::from("http://localhost"),
         };
 
-        let router = build_router(state, jwt_secret, vec![]);
+        let router = build_router(
+            state,
+            jwt_secret,
+            allowed_origins,
+            TrustedProxies::default(),
+            HttpsPolicy::default(),
+            SecurityHeaders::default(),
+            BodyLimits::default(),
+        );
 
         let n = IP_COUNTER.fetch_add(1, Ordering::Relaxed);
         let o = n.to_be_bytes();
@@ -150,6 +185,13 @@ impl TestApp {
         }
     }
 
+    /// Sends a prepared request through the router and returns the raw
+    /// response, for tests that inspect headers rather than the JSON body.
+    pub async fn send_raw(&self, mut request: Request<Body>) -> axum::response::Response {
+        request.extensions_mut().insert(ConnectInfo(self.fake_ip));
+        self.router.clone().oneshot(request).await.unwrap()
+    }
+
     async fn send(
         &self,
         method: Method,
@@ -279,23 +321,38 @@ pub struct CardFixture {
     oracle_text: Option<String>,
     power: Option<String>,
     toughness: Option<String>,
+    loyalty: Option<String>,
+    defense: Option<String>,
     produced_mana: Option<Vec<String>>,
     type_line: Option<String>,
     rarity: String,
     edhrec_rank: Option<i32>,
     usd: Option<String>,
+    eur: Option<String>,
+    tix: Option<String>,
     set: String,
     set_name: String,
+    set_type: String,
     set_id: Uuid,
     collector_number: String,
+    released_at: NaiveDate,
     legalities: Value,
     flavor_text: Option<String>,
+    frame_effects: Option<Vec<String>>,
+    border_color: String,
+    watermark: Option<String>,
+    finishes: Vec<String>,
+    promo_types: Option<Vec<String>>,
+    games: Option<Vec<String>>,
     artist: Option<String>,
     lang: String,
     digital: bool,
     oversized: bool,
     promo: bool,
     content_warning: Option<bool>,
+    game_changer: Option<bool>,
+    story_spotlight: bool,
+    textless: bool,
     card_roles: Vec<String>,
     oracle_tags: Vec<String>,
 }
@@ -318,23 +375,38 @@ pub fn card(name: &str) -> CardFixture {
         oracle_text: None,
         power: None,
         toughness: None,
+        loyalty: None,
+        defense: None,
         produced_mana: None,
         type_line: Some("Creature".to_string()),
         rarity: "common".to_string(),
         edhrec_rank: None,
         usd: None,
+        eur: None,
+        tix: None,
         set: "TST".to_string(),
         set_name: "Test Set".to_string(),
+        set_type: "expansion".to_string(),
         set_id: Uuid::from_u128(0x5E7),
         collector_number: n.to_string(),
+        released_at: NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
         legalities: json!({}),
         flavor_text: None,
+        frame_effects: None,
+        border_color: "black".to_string(),
+        watermark: None,
+        games: None,
+        finishes: vec!["nonfoil".to_string()],
+        promo_types: None,
         artist: None,
         lang: "en".to_string(),
         digital: false,
         oversized: false,
         promo: false,
         content_warning: None,
+        game_changer: None,
+        story_spotlight: false,
+        textless: false,
         card_roles: Vec::new(),
         oracle_tags: Vec::new(),
     }
@@ -420,6 +492,14 @@ impl CardFixture {
         self.toughness = Some(toughness.to_string());
         self
     }
+    pub fn loyalty(mut self, loyalty: &str) -> Self {
+        self.loyalty = Some(loyalty.to_string());
+        self
+    }
+    pub fn defense(mut self, defense: &str) -> Self {
+        self.defense = Some(defense.to_string());
+        self
+    }
     pub fn produced_mana(mut self, colors: &str) -> Self {
         self.produced_mana = Some(colors_of(colors));
         self
@@ -440,14 +520,35 @@ impl CardFixture {
         self.usd = Some(usd.to_string());
         self
     }
+    pub fn eur(mut self, eur: &str) -> Self {
+        self.eur = Some(eur.to_string());
+        self
+    }
+    pub fn tix(mut self, tix: &str) -> Self {
+        self.tix = Some(tix.to_string());
+        self
+    }
     pub fn set(mut self, code: &str, name: &str) -> Self {
         self.set = code.to_string();
         self.set_name = name.to_string();
         self
     }
+    pub fn collector_number(mut self, number: &str) -> Self {
+        self.collector_number = number.to_string();
+        self
+    }
+    /// Scryfall set type (`"expansion"` by default), e.g. `"box"` or `"promo"`.
+    pub fn set_type(mut self, set_type: &str) -> Self {
+        self.set_type = set_type.to_string();
+        self
+    }
     /// Mark this card legal in a format (e.g. `"commander"`). Repeatable.
-    pub fn legal(mut self, format: &str) -> Self {
-        self.legalities[format] = json!("legal");
+    pub fn legal(self, format: &str) -> Self {
+        self.legality(format, "legal")
+    }
+    /// Set this card's status in a format (`"banned"`, `"restricted"`, …). Repeatable.
+    pub fn legality(mut self, format: &str, status: &str) -> Self {
+        self.legalities[format] = json!(status);
         self
     }
     pub fn commander_legal(self) -> Self {
@@ -461,6 +562,42 @@ impl CardFixture {
         self.artist = Some(artist.to_string());
         self
     }
+    /// Frame effects (e.g. `"showcase"`); unset means a standard frame (NULL).
+    pub fn frame_effects(mut self, effects: &[&str]) -> Self {
+        self.frame_effects = Some(effects.iter().map(|e| e.to_string()).collect());
+        self
+    }
+    /// Border color (`"black"` by default), e.g. `"borderless"`.
+    pub fn border_color(mut self, border_color: &str) -> Self {
+        self.border_color = border_color.to_string();
+        self
+    }
+    pub fn watermark(mut self, watermark: &str) -> Self {
+        self.watermark = Some(watermark.to_string());
+        self
+    }
+    /// Games the printing is available in (e.g. `["paper", "arena"]`); unset
+    /// means no known availability (NULL).
+    pub fn games(mut self, games: &[&str]) -> Self {
+        self.games = Some(games.iter().map(|g| g.to_string()).collect());
+        self
+    }
+    /// Finishes the printing exists in (`["nonfoil"]` by default).
+    pub fn finishes(mut self, finishes: &[&str]) -> Self {
+        self.finishes = finishes.iter().map(|f| f.to_string()).collect();
+        self
+    }
+    /// Promo types (e.g. `"prerelease"`); unset means none (NULL).
+    pub fn promo_types(mut self, promo_types: &[&str]) -> Self {
+        self.promo_types = Some(promo_types.iter().map(|p| p.to_string()).collect());
+        self
+    }
+    /// Release date (`YYYY-MM-DD`); printings of one oracle card rank newest
+    /// first in `latest_cards`.
+    pub fn released(mut self, date: &str) -> Self {
+        self.released_at = NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap();
+        self
+    }
     pub fn lang(mut self, lang: &str) -> Self {
         self.lang = lang.to_string();
         self
@@ -481,6 +618,18 @@ impl CardFixture {
         self.content_warning = Some(warning);
         self
     }
+    pub fn game_changer(mut self, game_changer: bool) -> Self {
+        self.game_changer = Some(game_changer);
+        self
+    }
+    pub fn story_spotlight(mut self, story_spotlight: bool) -> Self {
+        self.story_spotlight = story_spotlight;
+        self
+    }
+    pub fn textless(mut self, textless: bool) -> Self {
+        self.textless = textless;
+        self
+    }
     /// A token card (`layout = "token"`); the seeded `card_profiles.is_token`
     /// tracks the layout, so this also flips the `is_token` flag.
     pub fn token(mut self) -> Self {
@@ -501,26 +650,27 @@ impl CardFixture {
 }
 
 /// Seed `cards` with the given fixtures, write their `card_profiles` rows, and
-/// refresh the `latest_cards` / `card_signal_rollup` materialized views (both
-/// start empty — the views must be refreshed before any card query, even with
-/// zero rows, or Postgres errors that they are unpopulated).
+/// refresh the `latest_cards` / `card_signal_rollup` / `format_staples`
+/// materialized views (all start empty — the views must be refreshed before
+/// any card query, even with zero rows, or Postgres errors that they are
+/// unpopulated).
 pub async fn seed_cards(pool: &PgPool, cards: &[CardFixture]) {
     if !cards.is_empty() {
-        let released = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
         let mut qb = QueryBuilder::new(
             "INSERT INTO scryfall_data (\
              id, lang, object, layout, oracle_id, \
              prints_search_uri, rulings_uri, scryfall_uri, uri, \
              cmc, color_identity, colors, keywords, legalities, mana_cost, name, \
              oracle_text, power, produced_mana, reserved, toughness, type_line, edhrec_rank, \
-             artist, flavor_text, content_warning, \
-             border_color, booster, collector_number, digital, finishes, frame, full_art, \
-             highres_image, image_status, oversized, prices, promo, rarity, related_uris, \
-             released_at, reprint, scryfall_set_uri, set_name, set_search_uri, set_type, \
-             set_uri, set, set_id, story_spotlight, textless, variation) ",
+             artist, flavor_text, content_warning, game_changer, loyalty, defense, \
+             border_color, booster, collector_number, digital, finishes, frame, frame_effects, \
+             full_art, games, highres_image, image_status, oversized, prices, promo, promo_types, \
+             rarity, related_uris, released_at, reprint, scryfall_set_uri, set_name, \
+             set_search_uri, set_type, set_uri, set, set_id, story_spotlight, textless, variation, \
+             watermark) ",
         );
         qb.push_values(cards.iter(), |mut b, c| {
-            let prices = json!({ "usd": c.usd });
+            let prices = json!({ "usd": c.usd, "eur": c.eur, "tix": c.tix });
             // Production stores rarity as the short code ("R"/"C"/…) — the SQL
             // rarity filters compare against `to_short_name()`. Storing the long
             // word here would read back fine (try_from accepts both) but silently
@@ -554,32 +704,39 @@ pub async fn seed_cards(pool: &PgPool, cards: &[CardFixture]) {
                 .push_bind(c.artist.as_deref())
                 .push_bind(c.flavor_text.as_deref())
                 .push_bind(c.content_warning)
-                .push_bind("black") // border_color
+                .push_bind(c.game_changer)
+                .push_bind(c.loyalty.as_deref())
+                .push_bind(c.defense.as_deref())
+                .push_bind(c.border_color.as_str())
                 .push_bind(true) // booster
                 .push_bind(c.collector_number.as_str())
                 .push_bind(c.digital)
-                .push_bind(vec!["nonfoil".to_string()]) // finishes
+                .push_bind(c.finishes.as_slice())
                 .push_bind("2015") // frame
+                .push_bind(c.frame_effects.as_deref())
                 .push_bind(false) // full_art
+                .push_bind(c.games.as_deref())
                 .push_bind(true) // highres_image
                 .push_bind("highres_scan") // image_status
                 .push_bind(c.oversized)
                 .push_bind(Json(prices))
                 .push_bind(c.promo)
+                .push_bind(c.promo_types.as_deref())
                 .push_bind(rarity)
                 .push_bind(Json(json!({}))) // related_uris
-                .push_bind(released)
+                .push_bind(c.released_at)
                 .push_bind(false) // reprint
                 .push_bind("https://scryfall.test/set")
                 .push_bind(c.set_name.as_str())
                 .push_bind("https://scryfall.test/set-search")
-                .push_bind("expansion")
+                .push_bind(c.set_type.as_str())
                 .push_bind("https://scryfall.test/set-uri")
                 .push_bind(c.set.as_str())
                 .push_bind(c.set_id)
-                .push_bind(false) // story_spotlight
-                .push_bind(false) // textless
-                .push_bind(false); // variation
+                .push_bind(c.story_spotlight)
+                .push_bind(c.textless)
+                .push_bind(false) // variation
+                .push_bind(c.watermark.as_deref());
         });
         qb.build().execute(pool).await.unwrap();
 
@@ -609,4 +766,8 @@ pub async fn refresh_card_views(pool: &PgPool) {
         .execute(pool)
         .await
         .unwrap();
+    sqlx::query("REFRESH MATERIALIZED VIEW format_staples")
+        .execute(pool)
+        .await
+        .unwrap();
 }
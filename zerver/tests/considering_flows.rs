@@ -0,0 +1,107 @@
+//! Per-user considering list (`/api/card/considering`): the cross-deck
+//! up-swipe wishlist. Covers add → list → remove, per-card dedup, per-user
+//! isolation, and the 404 for an unknown card.
+//!
+//! Requires `DATABASE_URL`: `set -a; source zerver/.env; set +a`.
+
+#![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{TestApp, card, seed_cards};
+use serde_json::json;
+use uuid::Uuid;
+
+/// Names on the caller's considering list, in served order.
+async fn considering_names(app: &TestApp, token: &str) -> Vec<String> {
+    let (status, body) = app.get("/api/card/considering", Some(token)).await;
+    assert_eq!(status, StatusCode::OK, "list considering: {body}");
+    body.as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["scryfall_data"]["name"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[sqlx::test]
+async fn add_list_remove_round_trip(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, _) = app.register("considerer").await;
+
+    let bolt = card("Lightning Bolt");
+    let counterspell = card("Counterspell");
+    let (bolt_id, counter_id) = (bolt.id(), counterspell.id());
+    seed_cards(&pool, &[bolt, counterspell]).await;
+
+    assert!(considering_names(&app, &token).await.is_empty());
+
+    for id in [bolt_id, counter_id] {
+        let (status, body) = app
+            .post(
+                &format!("/api/card/considering/{id}"),
+                json!({}),
+                Some(&token),
+            )
+            .await;
+        assert_eq!(status, StatusCode::NO_CONTENT, "add: {body}");
+    }
+    let names = considering_names(&app, &token).await;
+    assert_eq!(names.len(), 2, "{names:?}");
+    assert!(names.contains(&"Lightning Bolt".to_string()), "{names:?}");
+
+    let (status, _) = app
+        .delete(&format!("/api/card/considering/{bolt_id}"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert_eq!(considering_names(&app, &token).await, vec!["Counterspell"]);
+
+    // removing an absent card is a no-op, not an error
+    let (status, _) = app
+        .delete(&format!("/api/card/considering/{bolt_id}"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+}
+
+#[sqlx::test]
+async fn re_adding_a_card_does_not_duplicate_it(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, _) = app.register("deduper").await;
+    let (other_token, _) = app.register("bystander").await;
+
+    let sol_ring = card("Sol Ring");
+    let id = sol_ring.id();
+    seed_cards(&pool, &[sol_ring]).await;
+
+    for _ in 0..3 {
+        let (status, body) = app
+            .post(
+                &format!("/api/card/considering/{id}"),
+                json!({}),
+                Some(&token),
+            )
+            .await;
+        assert_eq!(status, StatusCode::NO_CONTENT, "add: {body}");
+    }
+    assert_eq!(considering_names(&app, &token).await, vec!["Sol Ring"]);
+    assert!(
+        considering_names(&app, &other_token).await.is_empty(),
+        "lists are per user"
+    );
+}
+
+#[sqlx::test]
+async fn adding_an_unknown_card_is_not_found(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, _) = app.register("ghosthunter").await;
+    seed_cards(&pool, &[]).await;
+
+    let (status, _) = app
+        .post(
+            &format!("/api/card/considering/{}", Uuid::from_u128(0xDEAD)),
+            json!({}),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
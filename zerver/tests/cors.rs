@@ -0,0 +1,53 @@
+//! CORS through the real middleware stack: an allowed origin's preflight is
+//! answered, and its actual responses expose the pagination / caching /
+//! request-id headers to browser JS. Per the Fetch spec the expose list rides
+//! on the actual response, not the preflight, so that's where it's asserted.
+//!
+//! Requires `DATABASE_URL`: `set -a; source zerver/.env; set +a`.
+
+#![allow(clippy::unwrap_used)]
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{HeaderValue, Method, Request, StatusCode, header},
+};
+use common::TestApp;
+use zwipe::inbound::http::CORS_EXPOSE_HEADERS;
+
+const ORIGIN: &str = "https://app.zwipe.test";
+
+#[sqlx::test]
+async fn allowed_origin_sees_exposed_headers(pool: sqlx::PgPool) {
+    let app = TestApp::with_origins(pool, vec![HeaderValue::from_static(ORIGIN)]);
+
+    let preflight = Request::builder()
+        .method(Method::OPTIONS)
+        .uri("/health/server")
+        .header(header::ORIGIN, ORIGIN)
+        .header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+        .body(Body::empty())
+        .unwrap();
+    let response = app.send_raw(preflight).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN],
+        ORIGIN
+    );
+
+    let get = Request::builder()
+        .uri("/health/server")
+        .header(header::ORIGIN, ORIGIN)
+        .body(Body::empty())
+        .unwrap();
+    let response = app.send_raw(get).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let exposed = response.headers()[header::ACCESS_CONTROL_EXPOSE_HEADERS]
+        .to_str()
+        .unwrap()
+        .to_lowercase();
+    for name in CORS_EXPOSE_HEADERS {
+        assert!(exposed.contains(name), "{name} not exposed: {exposed}");
+    }
+}
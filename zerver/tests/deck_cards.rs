@@ -1,7 +1,9 @@
 //! Deck-card operations through the real router: add, quantity delta, board
-//! placement, remove, and text import (resolved + unresolved). These were
-//! deferred out of `deck_flows.rs` because they need real `cards` rows — the
-//! `card()` / `seed_cards()` fixture builder now supplies them.
+//! placement (sideboard copies included), remove, text import (resolved +
+//! unresolved), and the undiscovered-staples anti-join against the caller's
+//! decks. These were deferred out of `deck_flows.rs` because they need real
+//! `cards` rows — the `card()` / `seed_cards()` fixture builder now supplies
+//! them.
 //!
 //! Note the create route is `POST /api/deck/{id}/card` — no trailing slash
 //! (the nested `/` leaf resolves without one, same as `/api/deck`).
@@ -13,8 +15,9 @@
 mod common;
 
 use axum::http::StatusCode;
-use common::{TestApp, card, seed_cards};
-use serde_json::json;
+use common::{TestApp, card, refresh_card_views, seed_cards};
+use serde_json::{Value, json};
+use zwipe::{domain::card::ports::CardRepository, outbound::sqlx::postgres::Postgres};
 
 /// Creates a verified user + a commander deck, returns `(token, deck_id)`.
 async fn deck_for(app: &TestApp, username: &str) -> (String, String) {
@@ -121,6 +124,141 @@ async fn deck_card_added_to_maybeboard(pool: sqlx::PgPool) {
     assert_eq!(entries[0]["deck_card"]["board"], "maybeboard");
 }
 
+/// A 60-card deck keeps sideboard copies of a card beside its main ones:
+/// `?board=sideboard` picks that copy, the copy limit counts both, and the
+/// decklist text round-trips the two sections.
+#[sqlx::test]
+async fn sideboard_copies_live_beside_the_main_deck(pool: sqlx::PgPool) {
+    use axum::{
+        body::Body,
+        http::{Request, header},
+    };
+    use http_body_util::BodyExt;
+
+    let app = TestApp::new(pool.clone());
+    let (token, uid) = app.register("sideboarder").await;
+    app.verify_email(&uid).await;
+    let did = deck_with_format(&app, &token, "Rakdos", "modern").await;
+    let duress = card("Duress").mono("B").type_line("Sorcery");
+    let (sid, oid) = (duress.id(), duress.oracle_id().unwrap());
+    seed_cards(&pool, &[duress]).await;
+
+    for board in ["deck", "sideboard"] {
+        let (status, dc) = app
+            .post(
+                &format!("/api/deck/{did}/card"),
+                json!({
+                    "scryfall_data_id": sid.to_string(),
+                    "oracle_id": oid.to_string(),
+                    "quantity": 2,
+                    "board": board
+                }),
+                Some(&token),
+            )
+            .await;
+        assert_eq!(status, StatusCode::CREATED, "add to {board}: {dc}");
+    }
+
+    let (status, dc) = app
+        .put(
+            &format!("/api/deck/{did}/card/{sid}?board=sideboard"),
+            json!({ "update_quantity": 1 }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "bump sideboard: {dc}");
+    assert_eq!(
+        (dc["board"].as_str(), dc["quantity"].as_i64()),
+        (Some("sideboard"), Some(3))
+    );
+    // no selector: the main copy
+    let (_, dc) = app
+        .put(
+            &format!("/api/deck/{did}/card/{sid}"),
+            json!({ "update_quantity": 1 }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(
+        (dc["board"].as_str(), dc["quantity"].as_i64()),
+        (Some("deck"), Some(3))
+    );
+    // the sideboard already holds a copy
+    let (status, _) = app
+        .put(
+            &format!("/api/deck/{did}/card/{sid}"),
+            json!({ "board": "sideboard" }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let (_, full) = app.get(&format!("/api/deck/{did}"), Some(&token)).await;
+    let warnings: Vec<&str> = full["warnings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|w| w["message"].as_str().unwrap())
+        .collect();
+    assert!(
+        warnings.contains(&"duress exceeds copy limit (6/4)"),
+        "{warnings:?}"
+    );
+
+    let request = Request::get(format!("/api/deck/{did}/export/text"))
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+        .body(Body::empty())
+        .unwrap();
+    let bytes = app
+        .send_raw(request)
+        .await
+        .into_body()
+        .collect()
+        .await
+        .unwrap()
+        .to_bytes();
+    let text = String::from_utf8(bytes.to_vec()).unwrap();
+    assert_eq!(text, "Deck\n3 Duress\n\nSideboard\n3 Duress\n");
+    let (status, report) = app
+        .post(
+            "/api/deck/import/text",
+            json!({ "name": "Rakdos copy", "text": text }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "import: {report}");
+    let copy = report["deck"]["id"].as_str().unwrap();
+    let (_, full) = app.get(&format!("/api/deck/{copy}"), Some(&token)).await;
+    let mut boards: Vec<(String, i64)> = full["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| {
+            (
+                e["deck_card"]["board"].as_str().unwrap().to_string(),
+                e["deck_card"]["quantity"].as_i64().unwrap(),
+            )
+        })
+        .collect();
+    boards.sort();
+    assert_eq!(
+        boards,
+        [("deck".to_string(), 3), ("sideboard".to_string(), 3)]
+    );
+
+    let (status, _) = app
+        .delete(
+            &format!("/api/deck/{did}/card/{sid}?board=sideboard"),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, full) = app.get(&format!("/api/deck/{did}"), Some(&token)).await;
+    let entries = full["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 1, "{full}");
+    assert_eq!(entries[0]["deck_card"]["board"], "deck");
+}
+
 #[sqlx::test]
 async fn clone_copies_the_cards(pool: sqlx::PgPool) {
     let app = TestApp::new(pool.clone());
@@ -181,6 +319,258 @@ async fn clone_copies_the_cards(pool: sqlx::PgPool) {
             ("Sol Ring".to_string(), 1)
         ]
     );
+
+    // the copy's rows are its own: removing a card leaves the source whole
+    assert_ne!(clone_id, did);
+    let (status, _) = app
+        .delete(&format!("/api/deck/{clone_id}/card/{a_sid}"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, source) = app.get(&format!("/api/deck/{did}"), Some(&token)).await;
+    assert_eq!(source["entries"].as_array().unwrap().len(), 2);
+}
+
+/// Importing a shared deck gives the caller their own copy, on the card's
+/// current printing; a stale or unknown share token copies nothing.
+#[sqlx::test]
+async fn shared_deck_imports_as_an_owned_copy(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (owner, did) = deck_for(&app, "sharer").await;
+
+    let old = card("Sol Ring")
+        .color_identity("")
+        .type_line("Artifact")
+        .released("2019-01-01");
+    let (old_sid, oid) = (old.id(), old.oracle_id().unwrap());
+    seed_cards(&pool, &[old]).await;
+    let (status, _) = app
+        .post(
+            &format!("/api/deck/{did}/card"),
+            json!({ "scryfall_data_id": old_sid.to_string(), "oracle_id": oid.to_string(), "quantity": 1 }),
+            Some(&owner),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // a newer printing lands after the deck was built
+    let reprint = card("Sol Ring")
+        .color_identity("")
+        .type_line("Artifact")
+        .released("2024-01-01")
+        .oracle(Some(oid));
+    let reprint_id = reprint.id();
+    seed_cards(&pool, &[reprint]).await;
+
+    let (_, share) = app
+        .post(&format!("/api/deck/{did}/share"), json!({}), Some(&owner))
+        .await;
+    let share_token = share["share_token"].as_str().unwrap().to_string();
+
+    let (importer, _) = deck_for(&app, "importer").await;
+    let (status, imported) = app
+        .post(
+            &format!("/api/deck/shared/{share_token}"),
+            json!({ "new_name": "My Sol Ring" }),
+            Some(&importer),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "import: {imported}");
+    let copy_id = imported["deck_id"].as_str().unwrap();
+
+    let (status, full) = app
+        .get(&format!("/api/deck/{copy_id}"), Some(&importer))
+        .await;
+    assert_eq!(status, StatusCode::OK, "importer owns the copy: {full}");
+    assert_eq!(full["deck_profile"]["name"], "My Sol Ring");
+    let entries = full["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 1, "same cards: {full}");
+    assert_eq!(entries[0]["deck_card"]["oracle_id"], oid.to_string());
+    assert_eq!(
+        entries[0]["deck_card"]["scryfall_data_id"],
+        reprint_id.to_string()
+    );
+
+    // the sharer's deck is untouched and still theirs alone
+    let (_, original) = app.get(&format!("/api/deck/{did}"), Some(&owner)).await;
+    assert_eq!(
+        original["entries"][0]["deck_card"]["scryfall_data_id"],
+        old_sid.to_string()
+    );
+
+    // revoked and unknown tokens copy nothing
+    let (status, _) = app
+        .delete(&format!("/api/deck/{did}/share"), Some(&owner))
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    for token in [share_token, uuid::Uuid::new_v4().to_string()] {
+        let (status, _) = app
+            .post(
+                &format!("/api/deck/shared/{token}"),
+                json!({}),
+                Some(&importer),
+            )
+            .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
+
+/// Text export serves the deck as a decklist the other tools import, set and
+/// collector number included for Arena; an unknown format is refused.
+#[sqlx::test]
+async fn deck_exports_as_decklist_text(pool: sqlx::PgPool) {
+    use axum::{
+        body::Body,
+        http::{Request, header},
+    };
+    use http_body_util::BodyExt;
+
+    let app = TestApp::new(pool.clone());
+    let (token, did) = deck_for(&app, "exporter").await;
+    let bolt = card("Lightning Bolt").mono("R").type_line("Instant");
+    let (sid, oid) = (bolt.id(), bolt.oracle_id().unwrap());
+    seed_cards(&pool, &[bolt]).await;
+    app.post(
+        &format!("/api/deck/{did}/card"),
+        json!({ "scryfall_data_id": sid.to_string(), "oracle_id": oid.to_string(), "quantity": 2 }),
+        Some(&token),
+    )
+    .await;
+
+    let export = async |format: &str| {
+        let request = Request::get(format!("/api/deck/{did}/export/{format}"))
+            .header(header::AUTHORIZATION, format!("Bearer {token}"))
+            .body(Body::empty())
+            .unwrap();
+        let response = app.send_raw(request).await;
+        let status = response.status();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        (status, String::from_utf8(bytes.to_vec()).unwrap())
+    };
+
+    let (status, text) = export("text").await;
+    assert_eq!(status, StatusCode::OK, "{text}");
+    assert_eq!(text, "Deck\n2 Lightning Bolt\n");
+    let (_, arena) = export("arena").await;
+    assert!(
+        arena.starts_with("Deck\n2 Lightning Bolt (TST) "),
+        "{arena}"
+    );
+    let (status, _) = export("cockatrice").await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+/// Deck stats chart the active board by quantity: curve, pips, types.
+#[sqlx::test]
+async fn deck_stats_chart_the_active_board(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, did) = deck_for(&app, "statistician").await;
+    let bolt = card("Lightning Bolt")
+        .mono("R")
+        .type_line("Instant")
+        .cmc(1.0);
+    let (sid, oid) = (bolt.id(), bolt.oracle_id().unwrap());
+    seed_cards(&pool, &[bolt]).await;
+    app.post(
+        &format!("/api/deck/{did}/card"),
+        json!({ "scryfall_data_id": sid.to_string(), "oracle_id": oid.to_string(), "quantity": 3 }),
+        Some(&token),
+    )
+    .await;
+
+    let (status, stats) = app
+        .get(&format!("/api/deck/{did}/stats"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::OK, "{stats}");
+    assert_eq!(stats["total_cards"], 3);
+    assert_eq!(stats["mana_curve"], json!([0, 3, 0, 0, 0, 0, 0]));
+    assert_eq!(
+        stats["type_distribution"],
+        json!([{ "card_type": "instants", "count": 3 }])
+    );
+    assert_eq!(stats["average_cmc"], 1.0);
+
+    let (other, _) = app.register("snoop").await;
+    let (status, _) = app
+        .get(&format!("/api/deck/{did}/stats"), Some(&other))
+        .await;
+    assert_ne!(status, StatusCode::OK);
+}
+
+/// A pasted Arena list becomes a new deck: the commander section fills the
+/// command zone, a named printing is kept, `SB:` lines go to the sideboard,
+/// and unknown names are reported instead of failing the import.
+#[sqlx::test]
+async fn decklist_text_imports_as_a_new_deck(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, uid) = app.register("listpaster").await;
+    app.verify_email(&uid).await;
+
+    let krenko = card("Krenko, Mob Boss")
+        .mono("R")
+        .type_line("Legendary Creature — Goblin Warrior")
+        .commander_legal();
+    let krenko_sid = krenko.id();
+    let bolt = card("Lightning Bolt").mono("R").type_line("Instant");
+    let promo_bolt = card("Lightning Bolt")
+        .mono("R")
+        .type_line("Instant")
+        .oracle(bolt.oracle_id())
+        .set("prm", "Promos")
+        .collector_number("7")
+        .released("2000-01-01");
+    let promo_sid = promo_bolt.id();
+    let pyroblast = card("Pyroblast").mono("R").type_line("Instant");
+    seed_cards(&pool, &[krenko, bolt, promo_bolt, pyroblast]).await;
+
+    let text = "Commander\n1 Krenko, Mob Boss\n\n\
+                Deck\n// burn\n4 Lightning Bolt (PRM) 7\nSB: 2 Pyroblast\n1 Not A Real Card\n";
+    let (status, report) = app
+        .post(
+            "/api/deck/import/text",
+            json!({ "name": "Goblins", "text": text, "format": "arena" }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "import: {report}");
+    assert_eq!(report["deck"]["format"], "commander", "{report}");
+    assert_eq!(report["deck"]["commander_id"], krenko_sid.to_string());
+    let unresolved: Vec<&str> = report["unresolved"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|u| u["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(unresolved, ["Not A Real Card"]);
+
+    let did = report["deck"]["id"].as_str().unwrap();
+    let (_, full) = app.get(&format!("/api/deck/{did}"), Some(&token)).await;
+    let mut entries: Vec<(String, i64, String, String)> = full["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| {
+            (
+                e["card"]["scryfall_data"]["name"]
+                    .as_str()
+                    .unwrap()
+                    .to_string(),
+                e["deck_card"]["quantity"].as_i64().unwrap(),
+                e["deck_card"]["board"].as_str().unwrap().to_string(),
+                e["deck_card"]["scryfall_data_id"]
+                    .as_str()
+                    .unwrap()
+                    .to_string(),
+            )
+        })
+        .collect();
+    entries.sort();
+    assert_eq!(entries[0].0, "Lightning Bolt");
+    assert_eq!(
+        (entries[0].1, entries[0].3.as_str()),
+        (4, promo_sid.to_string().as_str())
+    );
+    assert_eq!((entries[1].0.as_str(), entries[1].1), ("Pyroblast", 2));
+    assert_eq!(entries[1].2, "sideboard");
 }
 
 #[sqlx::test]
@@ -238,20 +628,20 @@ async fn get_deck_carries_command_zone_cards(pool: sqlx::PgPool) {
 
     // A priced commander (command zone) and a priced mainboard card.
     let commander = card("Atraxa, Praetors' Voice")
-        .colors("WUBG")
+        .mono("WUBG")
         .cmc(4.0)
         .type_line("Legendary Creature — Phyrexian Angel Horror")
         .commander_legal()
         .usd("10.00");
     let cmd_sid = commander.id();
-    let bolt = card("Lightning Bolt")
-        .mono("R")
+    let swords = card("Swords to Plowshares")
+        .mono("W")
         .cmc(1.0)
         .type_line("Instant")
         .usd("2.00");
-    let bolt_sid = bolt.id();
-    let bolt_oid = bolt.oracle_id().unwrap();
-    seed_cards(&pool, &[commander, bolt]).await;
+    let swords_sid = swords.id();
+    let swords_oid = swords.oracle_id().unwrap();
+    seed_cards(&pool, &[commander, swords]).await;
 
     // Set the commander on the deck (Opdate::Set wire form).
     let (status, updated) = app
@@ -273,11 +663,11 @@ async fn get_deck_carries_command_zone_cards(pool: sqlx::PgPool) {
     let (status, dc) = app
         .post(
             &format!("/api/deck/{did}/card"),
-            json!({ "scryfall_data_id": bolt_sid.to_string(), "oracle_id": bolt_oid.to_string(), "quantity": 1 }),
+            json!({ "scryfall_data_id": swords_sid.to_string(), "oracle_id": swords_oid.to_string(), "quantity": 1 }),
             Some(&token),
         )
         .await;
-    assert_eq!(status, StatusCode::CREATED, "add bolt: {dc}");
+    assert_eq!(status, StatusCode::CREATED, "add swords: {dc}");
 
     let (status, full) = app.get(&format!("/api/deck/{did}"), Some(&token)).await;
     assert_eq!(status, StatusCode::OK, "get deck: {full}");
@@ -297,6 +687,714 @@ async fn get_deck_carries_command_zone_cards(pool: sqlx::PgPool) {
     );
     assert_eq!(
         entries[0]["card"]["scryfall_data"]["name"],
-        "Lightning Bolt"
+        "Swords to Plowshares"
+    );
+}
+
+/// A commander deck only takes cards inside the commander's color identity;
+/// colorless cards, basic lands and the maybeboard are exempt.
+#[sqlx::test]
+async fn commander_color_identity_gates_added_cards(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, uid) = app.register("goblin").await;
+    app.verify_email(&uid).await;
+
+    let krenko = card("Krenko, Mob Boss")
+        .mono("R")
+        .type_line("Legendary Creature — Goblin Warrior");
+    let krenko_id = krenko.id();
+    let cards = [
+        card("Lightning Bolt").mono("R").type_line("Instant"),
+        card("Counterspell").mono("U").type_line("Instant"),
+        card("Sol Ring").color_identity("").type_line("Artifact"),
+        card("Forest").mono("G").type_line("Basic Land — Forest"),
+    ];
+    let [bolt, counterspell, sol_ring, forest] =
+        cards.each_ref().map(|c| (c.id(), c.oracle_id().unwrap()));
+    seed_cards(&pool, &[krenko]).await;
+    seed_cards(&pool, &cards).await;
+
+    let (status, deck) = app
+        .post(
+            "/api/deck",
+            json!({ "name": "Goblins", "format": "commander", "commander_id": krenko_id }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "{deck}");
+    let did = deck["id"].as_str().unwrap();
+
+    for ((sid, oid), board, expected) in [
+        (bolt, "deck", StatusCode::CREATED),
+        (counterspell, "deck", StatusCode::UNPROCESSABLE_ENTITY),
+        (sol_ring, "deck", StatusCode::CREATED),
+        (forest, "deck", StatusCode::CREATED),
+        (counterspell, "maybeboard", StatusCode::CREATED),
+    ] {
+        let (status, body) = app
+            .post(
+                &format!("/api/deck/{did}/card"),
+                json!({
+                    "scryfall_data_id": sid.to_string(),
+                    "oracle_id": oid.to_string(),
+                    "quantity": 1,
+                    "board": board
+                }),
+                Some(&token),
+            )
+            .await;
+        assert_eq!(status, expected, "{oid} to {board}: {body}");
+    }
+}
+
+#[sqlx::test]
+async fn orphaned_deck_card_is_detected_and_remapped(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, did) = deck_for(&app, "orphans").await;
+
+    // two printings of one oracle card, plus a card with a single printing
+    let old = card("Sol Ring").type_line("Artifact");
+    let new = card("Sol Ring")
+        .type_line("Artifact")
+        .oracle(old.oracle_id());
+    let lone = card("Black Lotus").type_line("Artifact");
+    let (old_id, new_id, lone_id) = (old.id(), new.id(), lone.id());
+    let oid = old.oracle_id().unwrap();
+    let lone_oid = lone.oracle_id().unwrap();
+    seed_cards(&pool, &[old, new, lone]).await;
+
+    for (sid, oid) in [(old_id, oid), (lone_id, lone_oid)] {
+        let (status, dc) = app
+            .post(
+                &format!("/api/deck/{did}/card"),
+                json!({ "scryfall_data_id": sid.to_string(), "oracle_id": oid.to_string(), "quantity": 1 }),
+                Some(&token),
+            )
+            .await;
+        assert_eq!(status, StatusCode::CREATED, "add card: {dc}");
+    }
+
+    let (_, none) = app.get("/api/deck/orphaned-cards", Some(&token)).await;
+    assert_eq!(none, json!([]), "nothing dangles yet");
+
+    // a sync drops both printings the deck points at
+    sqlx::query("DELETE FROM card_profiles WHERE scryfall_data_id = ANY($1)")
+        .bind(vec![old_id, lone_id])
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let (status, orphans) = app.get("/api/deck/orphaned-cards", Some(&token)).await;
+    assert_eq!(status, StatusCode::OK, "find: {orphans}");
+    let orphans = orphans.as_array().unwrap();
+    assert_eq!(
+        orphans.len(),
+        2,
+        "both dangling cards reported: {orphans:?}"
+    );
+    let sol = orphans
+        .iter()
+        .find(|o| o["deck_card"]["scryfall_data_id"] == old_id.to_string())
+        .unwrap();
+    assert_eq!(sol["replacement_id"], new_id.to_string());
+    let lotus = orphans
+        .iter()
+        .find(|o| o["deck_card"]["scryfall_data_id"] == lone_id.to_string())
+        .unwrap();
+    assert!(lotus["replacement_id"].is_null(), "no printing survives");
+
+    let (status, repaired) = app
+        .post("/api/deck/orphaned-cards/repair", json!({}), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::OK, "repair: {repaired}");
+    assert_eq!(repaired.as_array().unwrap().len(), 2);
+
+    // the Sol Ring now points at the surviving printing; the lone card stays
+    let (_, left) = app.get("/api/deck/orphaned-cards", Some(&token)).await;
+    let left = left.as_array().unwrap();
+    assert_eq!(left.len(), 1, "only the unrepairable orphan remains");
+    assert_eq!(
+        left[0]["deck_card"]["scryfall_data_id"],
+        lone_id.to_string()
+    );
+
+    let (_, full) = app.get(&format!("/api/deck/{did}"), Some(&token)).await;
+    let remapped = full["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["deck_card"]["oracle_id"] == oid.to_string())
+        .unwrap();
+    assert_eq!(
+        remapped["deck_card"]["scryfall_data_id"],
+        new_id.to_string()
+    );
+    assert_eq!(remapped["deck_card"]["quantity"], 1);
+}
+
+#[sqlx::test]
+async fn oracle_reference_follows_the_preferred_printing(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, did) = deck_for(&app, "follower").await;
+
+    let original = card("Swords to Plowshares")
+        .type_line("Instant")
+        .released("2019-01-01");
+    let oid = original.oracle_id().unwrap();
+    let pinned = card("Path to Exile")
+        .type_line("Instant")
+        .released("2019-01-01");
+    let pinned_oid = pinned.oracle_id().unwrap();
+    let (original_id, pinned_id) = (original.id(), pinned.id());
+    seed_cards(&pool, &[original, pinned]).await;
+
+    for (sid, oid, reference) in [
+        (original_id, oid, "oracle"),
+        (pinned_id, pinned_oid, "printing"),
+    ] {
+        let (status, dc) = app
+            .post(
+                &format!("/api/deck/{did}/card"),
+                json!({
+                    "scryfall_data_id": sid.to_string(),
+                    "oracle_id": oid.to_string(),
+                    "quantity": 1,
+                    "reference": reference,
+                }),
+                Some(&token),
+            )
+            .await;
+        assert_eq!(status, StatusCode::CREATED, "add card: {dc}");
+        assert_eq!(dc["reference"], reference);
+    }
+
+    // newer printings of both cards land
+    let reprint = card("Swords to Plowshares")
+        .type_line("Instant")
+        .released("2024-01-01")
+        .oracle(Some(oid));
+    let pinned_reprint = card("Path to Exile")
+        .type_line("Instant")
+        .released("2024-01-01")
+        .oracle(Some(pinned_oid));
+    let reprint_id = reprint.id();
+    seed_cards(&pool, &[reprint, pinned_reprint]).await;
+
+    // then the original printing is removed outright (its profile first:
+    // card_profiles doesn't cascade)
+    sqlx::query("DELETE FROM card_profiles WHERE scryfall_data_id = $1")
+        .bind(original_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM scryfall_data WHERE id = $1")
+        .bind(original_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    refresh_card_views(&pool).await;
+
+    let (status, full) = app.get(&format!("/api/deck/{did}"), Some(&token)).await;
+    assert_eq!(status, StatusCode::OK, "get deck: {full}");
+    let entries = full["entries"].as_array().unwrap();
+    assert_eq!(
+        entries.len(),
+        2,
+        "oracle card survived its printing: {full}"
+    );
+    let by_oracle = |oid: uuid::Uuid| {
+        entries
+            .iter()
+            .find(|e| e["deck_card"]["oracle_id"] == oid.to_string())
+            .unwrap()
+    };
+    assert_eq!(
+        by_oracle(oid)["deck_card"]["scryfall_data_id"],
+        reprint_id.to_string(),
+        "oracle reference resolves to the current best printing"
+    );
+    assert_eq!(
+        by_oracle(pinned_oid)["deck_card"]["scryfall_data_id"],
+        pinned_id.to_string(),
+        "pinned printing keeps its art"
+    );
+
+    // per-card routes address the resolved printing
+    let (status, dc) = app
+        .put(
+            &format!("/api/deck/{did}/card/{reprint_id}"),
+            json!({ "update_quantity": 1 }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "bump resolved card: {dc}");
+    assert_eq!(dc["quantity"], 2);
+}
+
+/// A sync that bans a card records the change, and the deck holding it in
+/// that format is reported; a deck of another format isn't, and neither is
+/// anything once the window starts after the ban.
+#[sqlx::test]
+async fn banlist_change_reports_decks_holding_the_card(pool: sqlx::PgPool) {
+    use zwipe::{domain::deck::ports::DeckRepository, outbound::sqlx::postgres::Postgres};
+
+    let app = TestApp::new(pool.clone());
+    let (token, uid) = app.register("banned").await;
+    app.verify_email(&uid).await;
+    let modern = deck_with_format(&app, &token, "Elves", "modern").await;
+    let legacy = deck_with_format(&app, &token, "Legacy Elves", "legacy").await;
+
+    let hogaak = card("Hogaak, Arisen Necropolis")
+        .legality("modern", "legal")
+        .legality("legacy", "legal");
+    let (sid, oid) = (hogaak.id(), hogaak.oracle_id().unwrap());
+    seed_cards(&pool, &[hogaak]).await;
+    for did in [&modern, &legacy] {
+        let (status, dc) = app
+            .post(
+                &format!("/api/deck/{did}/card"),
+                json!({ "scryfall_data_id": sid.to_string(), "oracle_id": oid.to_string(), "quantity": 1 }),
+                Some(&token),
+            )
+            .await;
+        assert_eq!(status, StatusCode::CREATED, "add: {dc}");
+    }
+
+    let before = chrono::Utc::now();
+    sqlx::query(
+        "UPDATE scryfall_data \
+         SET legalities = jsonb_set(legalities, '{modern}', '\"banned\"') WHERE id = $1",
+    )
+    .bind(sid)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let repo = Postgres { pool };
+    let hits = repo
+        .decks_affected_by_banlist_changes(before)
+        .await
+        .unwrap();
+    assert_eq!(hits.len(), 1, "{hits:?}");
+    assert_eq!(hits[0].deck_id.to_string(), modern);
+    assert_eq!(hits[0].oracle_id, oid);
+    assert_eq!(hits[0].card_name, "Hogaak, Arisen Necropolis");
+    assert_eq!(hits[0].user_id.to_string(), uid);
+
+    let later = chrono::Utc::now();
+    assert!(
+        repo.decks_affected_by_banlist_changes(later)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+}
+
+/// Undiscovered staples skip every card already in one of the caller's decks
+/// (matched by oracle_id) and serve the rest most popular first.
+#[sqlx::test]
+async fn undiscovered_staples_exclude_cards_in_my_decks(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, did) = deck_for(&app, "discoverer").await;
+
+    let sol_ring = card("Sol Ring")
+        .color_identity("")
+        .edhrec_rank(1)
+        .commander_legal();
+    let (sid, oid) = (sol_ring.id(), sol_ring.oracle_id().unwrap());
+    seed_cards(
+        &pool,
+        &[
+            sol_ring,
+            card("Arcane Signet")
+                .color_identity("")
+                .edhrec_rank(2)
+                .commander_legal(),
+            card("Counterspell")
+                .mono("U")
+                .edhrec_rank(3)
+                .commander_legal(),
+            card("Lightning Bolt")
+                .mono("R")
+                .edhrec_rank(4)
+                .commander_legal(),
+        ],
+    )
+    .await;
+    refresh_card_views(&pool).await;
+
+    let (status, dc) = app
+        .post(
+            &format!("/api/deck/{did}/card"),
+            json!({ "scryfall_data_id": sid.to_string(), "oracle_id": oid.to_string(), "quantity": 1 }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "add card: {dc}");
+
+    let (status, body) = app
+        .post(
+            "/api/card/undiscovered-staples",
+            json!({ "color_identity": ["U"], "limit": 10 }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "undiscovered staples: {body}");
+    let names: Vec<&str> = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["scryfall_data"]["name"].as_str().unwrap())
+        .collect();
+    assert_eq!(names, ["Arcane Signet", "Counterspell"]);
+}
+
+/// Creates a deck with the given format for an existing user, returns its id.
+async fn deck_with_format(app: &TestApp, token: &str, name: &str, format: &str) -> String {
+    let (status, deck) = app
+        .post(
+            "/api/deck",
+            json!({ "name": name, "format": format }),
+            Some(token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "deck create: {deck}");
+    deck["id"].as_str().unwrap().to_string()
+}
+
+/// Quantity of the deck's only entry, or `None` if the deck is empty.
+async fn only_quantity(app: &TestApp, token: &str, did: &str) -> Option<i64> {
+    let (_, full) = app.get(&format!("/api/deck/{did}"), Some(token)).await;
+    let entries = full["entries"].as_array().unwrap();
+    assert!(entries.len() <= 1, "at most one entry expected: {full}");
+    entries
+        .first()
+        .map(|e| e["deck_card"]["quantity"].as_i64().unwrap())
+}
+
+#[sqlx::test]
+async fn move_transfers_quantities_between_decks(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, uid) = app.register("mover").await;
+    app.verify_email(&uid).await;
+    let from = deck_with_format(&app, &token, "Burn", "modern").await;
+    let to = deck_with_format(&app, &token, "Burn II", "modern").await;
+
+    let bolt = card("Lightning Bolt")
+        .mono("R")
+        .cmc(1.0)
+        .type_line("Instant");
+    let sid = bolt.id();
+    let oid = bolt.oracle_id().unwrap();
+    seed_cards(&pool, &[bolt]).await;
+    let (status, _) = app
+        .post(
+            &format!("/api/deck/{from}/card"),
+            json!({ "scryfall_data_id": sid.to_string(), "oracle_id": oid.to_string(), "quantity": 4 }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let move_body = |quantity: i32| {
+        json!({
+            "to_deck_id": to,
+            "cards": [{ "scryfall_data_id": sid.to_string(), "quantity": quantity }],
+        })
+    };
+
+    // 3 of 4 move; the source keeps 1
+    let (status, body) = app
+        .post(
+            &format!("/api/deck/{from}/card/move"),
+            move_body(3),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT, "move: {body}");
+    assert_eq!(only_quantity(&app, &token, &from).await, Some(1));
+    assert_eq!(only_quantity(&app, &token, &to).await, Some(3));
+
+    // more than the source holds is refused
+    let (status, _) = app
+        .post(
+            &format!("/api/deck/{from}/card/move"),
+            move_body(2),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // the last copy merges into the destination entry and empties the source
+    let (status, _) = app
+        .post(
+            &format!("/api/deck/{from}/card/move"),
+            move_body(1),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    assert_eq!(only_quantity(&app, &token, &from).await, None);
+    assert_eq!(only_quantity(&app, &token, &to).await, Some(4));
+}
+
+#[sqlx::test]
+async fn move_over_destination_copy_limit_rolls_back(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, uid) = app.register("overmover").await;
+    app.verify_email(&uid).await;
+    let from = deck_with_format(&app, &token, "Burn", "modern").await;
+    let to = deck_with_format(&app, &token, "Big Red", "commander").await;
+
+    let bolt = card("Lightning Bolt")
+        .mono("R")
+        .cmc(1.0)
+        .type_line("Instant");
+    let sid = bolt.id();
+    let oid = bolt.oracle_id().unwrap();
+    seed_cards(&pool, &[bolt]).await;
+    app.post(
+        &format!("/api/deck/{from}/card"),
+        json!({ "scryfall_data_id": sid.to_string(), "oracle_id": oid.to_string(), "quantity": 4 }),
+        Some(&token),
+    )
+    .await;
+
+    // a commander deck is singleton: two copies can't land
+    let (status, body) = app
+        .post(
+            &format!("/api/deck/{from}/card/move"),
+            json!({
+                "to_deck_id": to,
+                "cards": [{ "scryfall_data_id": sid.to_string(), "quantity": 2 }],
+            }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "move: {body}");
+    assert_eq!(only_quantity(&app, &token, &from).await, Some(4));
+    assert_eq!(only_quantity(&app, &token, &to).await, None);
+}
+
+#[sqlx::test]
+async fn bulk_add_lands_every_card_or_none(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, uid) = app.register("bulkadder").await;
+    app.verify_email(&uid).await;
+    let did = deck_with_format(&app, &token, "Burn", "modern").await;
+
+    let bolt = card("Lightning Bolt")
+        .mono("R")
+        .cmc(1.0)
+        .type_line("Instant");
+    let mountain = card("Mountain").type_line("Basic Land — Mountain");
+    let (bolt_id, mountain_id) = (bolt.id(), mountain.id());
+    seed_cards(&pool, &[bolt, mountain]).await;
+    let bulk = format!("/api/deck/{did}/card/bulk");
+
+    // repeats merge: 2 + 1 bolts, 20 mountains
+    let (status, added) = app
+        .post(
+            &bulk,
+            json!({ "cards": [
+                { "scryfall_data_id": bolt_id.to_string(), "quantity": 2 },
+                { "scryfall_data_id": mountain_id.to_string(), "quantity": 20 },
+                { "scryfall_data_id": bolt_id.to_string(), "quantity": 1 },
+            ] }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "bulk add: {added}");
+    assert_eq!(added.as_array().unwrap().len(), 2);
+    assert_eq!(added[0]["quantity"], 3);
+
+    // a fifth bolt breaks the limit, so the mountains don't land either
+    let (status, body) = app
+        .post(
+            &bulk,
+            json!({ "cards": [
+                { "scryfall_data_id": mountain_id.to_string(), "quantity": 4 },
+                { "scryfall_data_id": bolt_id.to_string(), "quantity": 2 },
+            ] }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "bulk add: {body}");
+    let (_, full) = app.get(&format!("/api/deck/{did}"), Some(&token)).await;
+    let total: i64 = full["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| e["deck_card"]["quantity"].as_i64().unwrap())
+        .sum();
+    assert_eq!(total, 23);
+
+    // an unknown card fails the whole batch
+    let (status, _) = app
+        .post(
+            &bulk,
+            json!({ "cards": [{ "scryfall_data_id": uuid::Uuid::new_v4().to_string(), "quantity": 1 }] }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[sqlx::test]
+async fn legality_report_lists_every_broken_rule(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, uid) = app.register("rulebreaker").await;
+    app.verify_email(&uid).await;
+    let did = deck_with_format(&app, &token, "Oko Pile", "modern").await;
+
+    let oko = card("Oko, Thief of Crowns")
+        .color_identity("GU")
+        .type_line("Legendary Planeswalker — Oko")
+        .legality("modern", "banned");
+    let sid = oko.id();
+    let oid = oko.oracle_id().unwrap();
+    seed_cards(&pool, &[oko]).await;
+    app.post(
+        &format!("/api/deck/{did}/card"),
+        json!({ "scryfall_data_id": sid.to_string(), "oracle_id": oid.to_string(), "quantity": 1 }),
+        Some(&token),
+    )
+    .await;
+
+    // one banned card: both the ban and the 60-card minimum are broken
+    let (status, report) = app
+        .get(&format!("/api/deck/{did}/legality"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::OK, "report: {report}");
+    let rules: Vec<&str> = report["violations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v["rule"].as_str().unwrap())
+        .collect();
+    assert!(rules.contains(&"deck_size"), "report: {report}");
+    assert!(rules.contains(&"card_legality"), "report: {report}");
+    assert_eq!(report["format"], "modern");
+
+    // someone else's deck stays hidden
+    let (other, other_uid) = app.register("bystander").await;
+    app.verify_email(&other_uid).await;
+    let (status, _) = app
+        .get(&format!("/api/deck/{did}/legality"), Some(&other))
+        .await;
+    assert_ne!(status, StatusCode::OK);
+}
+
+#[sqlx::test]
+async fn deck_validates_against_another_format(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, uid) = app.register("format_shopper").await;
+    app.verify_email(&uid).await;
+    let did = deck_with_format(&app, &token, "Oko Pile", "legacy").await;
+
+    let oko = card("Oko, Thief of Crowns")
+        .color_identity("GU")
+        .type_line("Legendary Planeswalker — Oko")
+        .legality("legacy", "legal")
+        .legality("standard", "banned");
+    let sid = oko.id();
+    let oid = oko.oracle_id().unwrap();
+    seed_cards(&pool, &[oko]).await;
+    app.post(
+        &format!("/api/deck/{did}/card"),
+        json!({ "scryfall_data_id": sid.to_string(), "oracle_id": oid.to_string(), "quantity": 1 }),
+        Some(&token),
+    )
+    .await;
+
+    let rules = |report: &Value| -> Vec<String> {
+        report["violations"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["rule"].as_str().unwrap().to_string())
+            .collect()
+    };
+    let (status, legacy) = app
+        .get(&format!("/api/deck/{did}/legality/legacy"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::OK, "legacy: {legacy}");
+    assert!(!rules(&legacy).contains(&"card_legality".to_string()));
+
+    // the ban only counts where it applies; the deck keeps its own format
+    let (status, standard) = app
+        .get(&format!("/api/deck/{did}/legality/standard"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::OK, "standard: {standard}");
+    assert_eq!(standard["format"], "standard");
+    assert!(rules(&standard).contains(&"card_legality".to_string()));
+    let (_, profile) = app
+        .get(&format!("/api/deck/profile/{did}"), Some(&token))
+        .await;
+    assert_eq!(profile["format"], "legacy");
+
+    let (status, _) = app
+        .get(
+            &format!("/api/deck/{did}/legality/not-a-format"),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+/// Reading a deck never writes: an oracle reference moves to a newer printing
+/// when the card sync refreshes `latest_cards`, not when the deck is fetched.
+#[sqlx::test]
+async fn oracle_reference_moves_on_sync_not_on_read(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, did) = deck_for(&app, "syncer").await;
+
+    let original = card("Counterspell")
+        .type_line("Instant")
+        .released("2019-01-01");
+    let oid = original.oracle_id().unwrap();
+    let original_id = original.id();
+    seed_cards(&pool, &[original]).await;
+
+    let (status, dc) = app
+        .post(
+            &format!("/api/deck/{did}/card"),
+            json!({
+                "scryfall_data_id": original_id.to_string(),
+                "oracle_id": oid.to_string(),
+                "quantity": 1,
+                "reference": "oracle",
+            }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "add card: {dc}");
+
+    let reprint = card("Counterspell")
+        .type_line("Instant")
+        .released("2024-01-01")
+        .oracle(Some(oid));
+    let reprint_id = reprint.id();
+    seed_cards(&pool, &[reprint]).await;
+
+    let printing = || async {
+        let (status, full) = app.get(&format!("/api/deck/{did}"), Some(&token)).await;
+        assert_eq!(status, StatusCode::OK, "get deck: {full}");
+        full["entries"][0]["deck_card"]["scryfall_data_id"].clone()
+    };
+    assert_eq!(
+        printing().await,
+        original_id.to_string(),
+        "a read leaves the stored printing alone"
+    );
+
+    Postgres { pool: pool.clone() }
+        .refresh_latest_cards()
+        .await
+        .unwrap();
+    assert_eq!(
+        printing().await,
+        reprint_id.to_string(),
+        "the sync moves the reference to the newer printing"
     );
 }
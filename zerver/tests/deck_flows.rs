@@ -1,5 +1,6 @@
 //! Deck lifecycle through the real router: profile CRUD, the unverified-email
-//! deck cap, duplicate-name rejection, cross-user isolation (IDOR), and clone.
+//! deck cap, duplicate-name rejection, cross-user isolation (IDOR), clone, and
+//! public share links.
 //!
 //! Card-level tests (add/remove, card-copy on clone, suppressions) need real
 //! `cards` rows and live in the card-serving slice with the fixture builder.
@@ -242,3 +243,57 @@ async fn deck_oracle_tags_round_trip(pool: sqlx::PgPool) {
     assert_eq!(status, StatusCode::OK, "clear: {cleared}");
     assert_eq!(cleared["oracle_tags"], json!([]));
 }
+
+#[sqlx::test]
+async fn share_link_reads_anonymously_until_revoked(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let (token, uid) = app.register("sharer").await;
+    let (status, deck) = app
+        .post(
+            "/api/deck",
+            json!({ "name": "Goblin Tribal", "format": "commander" }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "create: {deck}");
+    let id = deck["id"].as_str().unwrap();
+
+    let (status, share) = app
+        .post(&format!("/api/deck/{id}/share"), json!({}), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::OK, "share: {share}");
+    let share_token = share["share_token"].as_str().unwrap().to_string();
+
+    // no Authorization header, and nothing naming the owner comes back
+    let (status, shared) = app
+        .get(&format!("/api/share/deck/{share_token}"), None)
+        .await;
+    assert_eq!(status, StatusCode::OK, "shared read: {shared}");
+    assert_eq!(shared["name"], "Goblin Tribal");
+    let raw = shared.to_string();
+    for owner_data in [uid.as_str(), "sharer", "user_id", "email"] {
+        assert!(!raw.contains(owner_data), "{owner_data} leaked: {raw}");
+    }
+
+    // someone else can't revoke it
+    let (other, _) = app.register("bystander").await;
+    let (status, _) = app
+        .delete(&format!("/api/deck/{id}/share"), Some(&other))
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, _) = app
+        .delete(&format!("/api/deck/{id}/share"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, _) = app
+        .get(&format!("/api/share/deck/{share_token}"), None)
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    // unknown and malformed tokens answer the same
+    for unknown in [uuid::Uuid::new_v4().to_string(), "not-a-token".to_string()] {
+        let (status, _) = app.get(&format!("/api/share/deck/{unknown}"), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,168 @@
+//! Deck JSON backup through the real router: export a deck, restore it as a
+//! new deck, and check the restored deck carries the same settings and card
+//! multiset; plus ownership and unknown-version refusals.
+//!
+//! Requires `DATABASE_URL`: `set -a; source zerver/.env; set +a`.
+
+#![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{TestApp, card, seed_cards};
+use serde_json::{Value, json};
+use std::collections::BTreeMap;
+
+/// `(oracle_id, board) -> quantity` for every card in an export.
+fn multiset(export: &Value) -> BTreeMap<(String, String), i64> {
+    export["cards"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| {
+            (
+                (
+                    c["oracle_id"].as_str().unwrap().to_string(),
+                    c["board"].as_str().unwrap().to_string(),
+                ),
+                c["quantity"].as_i64().unwrap(),
+            )
+        })
+        .collect()
+}
+
+#[sqlx::test]
+async fn export_then_import_reproduces_the_deck(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, uid) = app.register("archivist").await;
+    app.verify_email(&uid).await;
+
+    let commander = card("Talrand, Sky Summoner")
+        .mono("U")
+        .type_line("Legendary Creature — Merfolk Wizard");
+    let counterspell = card("Counterspell").mono("U").type_line("Instant");
+    let island = card("Island").type_line("Basic Land — Island");
+    let opt = card("Opt").mono("U").type_line("Instant");
+    let commander_id = commander.id();
+    let picks = [
+        (&counterspell, 1, "deck"),
+        (&island, 30, "deck"),
+        (&opt, 1, "maybeboard"),
+    ]
+    .map(|(c, qty, board)| (c.id(), c.oracle_id().unwrap(), qty, board));
+    seed_cards(
+        &pool,
+        &[commander, counterspell.clone(), island.clone(), opt.clone()],
+    )
+    .await;
+
+    let (status, deck) = app
+        .post(
+            "/api/deck",
+            json!({
+                "name": "Talrand Spells",
+                "format": "commander",
+                "commander_id": commander_id,
+                "power_level": "upgraded",
+                "other_tags": ["budget"],
+                "land_target": 34,
+                "price_target": 120.0,
+                "price_target_currency": "Eur",
+            }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "{deck}");
+    let deck_id = deck["id"].as_str().unwrap();
+    for (sid, oid, qty, board) in picks {
+        let (status, body) = app
+            .post(
+                &format!("/api/deck/{deck_id}/card"),
+                json!({
+                    "scryfall_data_id": sid.to_string(),
+                    "oracle_id": oid.to_string(),
+                    "quantity": qty,
+                    "board": board,
+                }),
+                Some(&token),
+            )
+            .await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+    }
+    let (status, _) = app
+        .put(
+            &format!("/api/deck/{deck_id}/labels"),
+            json!({ "labels": ["spellslinger"] }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, export) = app
+        .get(&format!("/api/deck/{deck_id}/export"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::OK, "{export}");
+    assert_eq!(export["version"], 1);
+    assert_eq!(multiset(&export).len(), 3, "{export}");
+
+    // a restore needs a fresh name (names are unique per user)
+    let mut backup = export.clone();
+    backup["name"] = json!("Talrand Spells (restored)");
+    let (status, restored) = app.post("/api/deck/import", backup, Some(&token)).await;
+    assert_eq!(status, StatusCode::CREATED, "{restored}");
+    assert_eq!(restored["unresolved"], json!([]));
+    let restored_id = restored["deck"]["id"].as_str().unwrap();
+    assert_ne!(restored_id, deck_id);
+    assert_eq!(restored["deck"]["commander_id"], json!(commander_id));
+    assert_eq!(restored["deck"]["card_count"], 31);
+
+    // re-exporting the restored deck gives back the same backup
+    let (status, again) = app
+        .get(&format!("/api/deck/{restored_id}/export"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::OK, "{again}");
+    assert_eq!(multiset(&again), multiset(&export));
+    for field in [
+        "format",
+        "power_level",
+        "other_tags",
+        "labels",
+        "land_target",
+        "price_target",
+        "price_target_currency",
+        "copy_limit",
+        "commander",
+    ] {
+        assert_eq!(again[field], export[field], "{field}");
+    }
+}
+
+#[sqlx::test]
+async fn export_and_import_are_guarded(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let (alice, _) = app.register("alice").await;
+    let (mallory, _) = app.register("mallory").await;
+    let (status, deck) = app
+        .post(
+            "/api/deck",
+            json!({ "name": "Private Deck", "format": "commander" }),
+            Some(&alice),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "{deck}");
+    let deck_id = deck["id"].as_str().unwrap();
+
+    let (status, _) = app
+        .get(&format!("/api/deck/{deck_id}/export"), Some(&mallory))
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let (status, mut export) = app
+        .get(&format!("/api/deck/{deck_id}/export"), Some(&alice))
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    export["version"] = json!(99);
+    export["name"] = json!("From The Future");
+    let (status, _) = app.post("/api/deck/import", export, Some(&alice)).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
@@ -0,0 +1,150 @@
+//! User-defined deck labels through the real router: setting and replacing a
+//! deck's labels, listing decks filtered by a label, and per-user isolation.
+//!
+//! Requires `DATABASE_URL`: `set -a; source zerver/.env; set +a`.
+
+#![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+mod common;
+
+use axum::http::StatusCode;
+use common::TestApp;
+use serde_json::{Value, json};
+
+/// Creates a deck named `name`; returns its id.
+async fn create_deck(app: &TestApp, token: &str, name: &str) -> String {
+    let (status, deck) = app
+        .post(
+            "/api/deck",
+            json!({ "name": name, "format": "commander" }),
+            Some(token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "create {name}: {deck}");
+    deck["id"].as_str().unwrap().to_string()
+}
+
+/// Names of the decks in a listing, sorted.
+fn names(list: &Value) -> Vec<String> {
+    let mut names: Vec<String> = list
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| d["name"].as_str().unwrap().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+#[sqlx::test]
+async fn set_and_replace_labels(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let (token, _uid) = app.register("labeller").await;
+    let id = create_deck(&app, &token, "Labelled Deck").await;
+
+    // normalized, deduped, sorted
+    let (status, body) = app
+        .put(
+            &format!("/api/deck/{id}/labels"),
+            json!({ "labels": [" cEDH", "Budget", "cedh", ""] }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["labels"], json!(["budget", "cedh"]));
+
+    let (_, profile) = app
+        .get(&format!("/api/deck/profile/{id}"), Some(&token))
+        .await;
+    assert_eq!(profile["labels"], json!(["budget", "cedh"]));
+
+    // replacing drops labels not in the new set
+    let (status, body) = app
+        .put(
+            &format!("/api/deck/{id}/labels"),
+            json!({ "labels": ["jank"] }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    let (_, profile) = app
+        .get(&format!("/api/deck/profile/{id}"), Some(&token))
+        .await;
+    assert_eq!(profile["labels"], json!(["jank"]));
+
+    // an empty list clears them
+    let (status, _) = app
+        .put(
+            &format!("/api/deck/{id}/labels"),
+            json!({ "labels": [] }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, profile) = app
+        .get(&format!("/api/deck/profile/{id}"), Some(&token))
+        .await;
+    assert_eq!(profile["labels"], json!([]));
+
+    // too long is refused
+    let (status, _) = app
+        .put(
+            &format!("/api/deck/{id}/labels"),
+            json!({ "labels": ["x".repeat(100)] }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[sqlx::test]
+async fn list_decks_filtered_by_label(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    let (alice, alice_id) = app.register("alice").await;
+    let (bob, _) = app.register("bob").await;
+    // unverified users get a single deck; alice needs three
+    app.verify_email(&alice_id).await;
+    let fast = create_deck(&app, &alice, "Fast Deck").await;
+    let cheap = create_deck(&app, &alice, "Cheap Deck").await;
+    let _plain = create_deck(&app, &alice, "Plain Deck").await;
+    let bobs = create_deck(&app, &bob, "Bob Deck").await;
+
+    for (token, id, labels) in [
+        (&alice, &fast, json!(["cEDH"])),
+        (&alice, &cheap, json!(["budget", "cedh"])),
+        (&bob, &bobs, json!(["cedh"])),
+    ] {
+        let (status, body) = app
+            .put(
+                &format!("/api/deck/{id}/labels"),
+                json!({ "labels": labels }),
+                Some(token),
+            )
+            .await;
+        assert_eq!(status, StatusCode::OK, "{body}");
+    }
+
+    // the filter is normalized too, and never reaches another user's decks
+    let (status, list) = app.get("/api/deck?label=CEDH", Some(&alice)).await;
+    assert_eq!(status, StatusCode::OK, "{list}");
+    assert_eq!(names(&list), vec!["Cheap Deck", "Fast Deck"]);
+    let (_, list) = app.get("/api/deck?label=budget", Some(&alice)).await;
+    assert_eq!(names(&list), vec!["Cheap Deck"]);
+    assert_eq!(list[0]["labels"], json!(["budget", "cedh"]));
+    let (_, list) = app.get("/api/deck?label=jank", Some(&alice)).await;
+    assert!(list.as_array().unwrap().is_empty(), "{list}");
+
+    // unfiltered still lists everything
+    let (_, list) = app.get("/api/deck", Some(&alice)).await;
+    assert_eq!(list.as_array().unwrap().len(), 3);
+
+    // bob can't label alice's deck
+    let (status, _) = app
+        .put(
+            &format!("/api/deck/{fast}/labels"),
+            json!({ "labels": ["stolen"] }),
+            Some(&bob),
+        )
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,93 @@
+//! Bulk favorites (`PUT /api/card/favorites`): a batch of favorite/unfavorite
+//! toggles from rapid swiping applies in one transaction, a card appearing
+//! twice takes its last entry, and an unknown card rejects the whole batch.
+//!
+//! Requires `DATABASE_URL`: `set -a; source zerver/.env; set +a`.
+
+#![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{TestApp, card, seed_cards};
+use serde_json::json;
+use uuid::Uuid;
+
+/// Sorted names on the caller's favorites list.
+async fn favorite_names(app: &TestApp, token: &str) -> Vec<String> {
+    let (status, body) = app.get("/api/card/favorites", Some(token)).await;
+    assert_eq!(status, StatusCode::OK, "list favorites: {body}");
+    let mut names: Vec<String> = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["scryfall_data"]["name"].as_str().unwrap().to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+#[sqlx::test]
+async fn mixed_batch_applies_and_dedupes_last_wins(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, _) = app.register("swiper").await;
+
+    let (a, b, c) = (card("Alpha"), card("Bravo"), card("Charlie"));
+    let (a_id, b_id, c_id) = (a.id(), b.id(), c.id());
+    seed_cards(&pool, &[a, b, c]).await;
+
+    let (status, body) = app
+        .put(
+            "/api/card/favorites",
+            json!({ "changes": [
+                { "scryfall_data_id": a_id, "favorite": true },
+                { "scryfall_data_id": b_id, "favorite": true },
+            ]}),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT, "seed batch: {body}");
+    assert_eq!(favorite_names(&app, &token).await, vec!["Alpha", "Bravo"]);
+
+    // unfavorite Alpha, favorite Charlie, and toggle Bravo off-then-on (last wins)
+    let (status, body) = app
+        .put(
+            "/api/card/favorites",
+            json!({ "changes": [
+                { "scryfall_data_id": a_id, "favorite": false },
+                { "scryfall_data_id": b_id, "favorite": false },
+                { "scryfall_data_id": c_id, "favorite": true },
+                { "scryfall_data_id": b_id, "favorite": true },
+            ]}),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::NO_CONTENT, "mixed batch: {body}");
+    assert_eq!(favorite_names(&app, &token).await, vec!["Bravo", "Charlie"]);
+}
+
+#[sqlx::test]
+async fn unknown_card_rejects_the_whole_batch(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, _) = app.register("atomic").await;
+
+    let real = card("Real Card");
+    let real_id = real.id();
+    seed_cards(&pool, &[real]).await;
+
+    let (status, _) = app
+        .put(
+            "/api/card/favorites",
+            json!({ "changes": [
+                { "scryfall_data_id": real_id, "favorite": true },
+                { "scryfall_data_id": Uuid::from_u128(0xDEAD), "favorite": true },
+            ]}),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(
+        favorite_names(&app, &token).await.is_empty(),
+        "nothing applied"
+    );
+}
@@ -1,5 +1,6 @@
 //! Health + root endpoints through the real router. `/health/database` pings
-//! Postgres, so this also proves the test pool wiring end to end.
+//! Postgres, so this also proves the test pool wiring end to end. Plus the
+//! opt-in sync freshness readiness check against recorded sync runs.
 //!
 //! Requires `DATABASE_URL`: `set -a; source zerver/.env; set +a`.
 
@@ -8,7 +9,33 @@
 mod common;
 
 use axum::http::StatusCode;
+use chrono::{TimeDelta, Utc};
 use common::TestApp;
+use zwipe::{
+    domain::health::{models::SyncFreshness, ports::HealthService, services::Service},
+    outbound::sqlx::postgres::Postgres,
+};
+
+/// Records a sync run that ended `age` ago with `status`.
+async fn record_sync(pool: &sqlx::PgPool, age: TimeDelta, status: &str) {
+    let ended_at = Utc::now() - age;
+    sqlx::query(
+        "INSERT INTO zervice_metrics
+            (started_at, ended_at, duration_in_seconds, status, received_count, upserted_count, skipped_count, error_count)
+         VALUES ($1, $2, 60, $3, 0, 0, 0, 0)",
+    )
+    .bind(ended_at - TimeDelta::minutes(1))
+    .bind(ended_at)
+    .bind(status)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+fn service(pool: &sqlx::PgPool) -> Service<Postgres> {
+    Service::new(Postgres { pool: pool.clone() })
+        .with_sync_freshness(Some(SyncFreshness::new(TimeDelta::days(10))))
+}
 
 #[sqlx::test]
 async fn health_and_root_ok(pool: sqlx::PgPool) {
@@ -18,3 +45,25 @@ async fn health_and_root_ok(pool: sqlx::PgPool) {
         assert_eq!(status, StatusCode::OK, "GET {path}");
     }
 }
+
+#[sqlx::test]
+async fn stale_last_sync_fails_readiness(pool: sqlx::PgPool) {
+    // never synced
+    assert!(service(&pool).check_sync_freshness().await.is_err());
+
+    record_sync(&pool, TimeDelta::days(12), "success").await;
+    // a recent run that failed doesn't count
+    record_sync(&pool, TimeDelta::days(1), "failure").await;
+    assert!(service(&pool).check_sync_freshness().await.is_err());
+
+    // the check is opt-in
+    let unchecked = Service::new(Postgres { pool: pool.clone() });
+    assert!(unchecked.check_sync_freshness().await.is_ok());
+}
+
+#[sqlx::test]
+async fn recent_successful_sync_passes_readiness(pool: sqlx::PgPool) {
+    record_sync(&pool, TimeDelta::days(12), "success").await;
+    record_sync(&pool, TimeDelta::days(2), "partial_success").await;
+    assert!(service(&pool).check_sync_freshness().await.is_ok());
+}
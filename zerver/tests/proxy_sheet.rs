@@ -0,0 +1,99 @@
+//! Proxy sheet through the real router: copies expand by quantity, a
+//! double-faced card contributes both faces, and another user's deck is a 404.
+//!
+//! Requires `DATABASE_URL`: `set -a; source zerver/.env; set +a`.
+
+#![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{TestApp, card, refresh_card_views, seed_cards};
+use serde_json::json;
+
+#[sqlx::test]
+async fn proxy_sheet_lists_one_image_per_printed_face(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool.clone());
+    let (token, _) = app.register("printer").await;
+    let (mallory, _) = app.register("mallory").await;
+
+    let bolt = card("Lightning Bolt").mono("R").type_line("Instant");
+    let delver = card("Delver of Secrets // Insectile Aberration").mono("U");
+    seed_cards(&pool, &[bolt.clone(), delver.clone()]).await;
+    sqlx::query("UPDATE scryfall_data SET image_uris = $2 WHERE id = $1")
+        .bind(bolt.id())
+        .bind(json!({ "large": "https://img.test/bolt.jpg" }))
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE scryfall_data SET layout = 'transform', card_faces = $2 WHERE id = $1")
+        .bind(delver.id())
+        .bind(json!([
+            {
+                "object": "card_face",
+                "name": "Delver of Secrets",
+                "mana_cost": "{U}",
+                "image_uris": { "large": "https://img.test/delver-front.jpg" }
+            },
+            {
+                "object": "card_face",
+                "name": "Insectile Aberration",
+                "mana_cost": "",
+                "image_uris": { "large": "https://img.test/delver-back.jpg" }
+            }
+        ]))
+        .execute(&pool)
+        .await
+        .unwrap();
+    refresh_card_views(&pool).await;
+
+    let (status, deck) = app
+        .post(
+            "/api/deck",
+            json!({ "name": "Proxy Deck", "format": "modern" }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::CREATED, "{deck}");
+    let deck_id = deck["id"].as_str().unwrap();
+    for (c, quantity) in [(&bolt, 2), (&delver, 1)] {
+        let (status, body) = app
+            .post(
+                &format!("/api/deck/{deck_id}/card"),
+                json!({
+                    "scryfall_data_id": c.id().to_string(),
+                    "oracle_id": c.oracle_id().unwrap().to_string(),
+                    "quantity": quantity,
+                }),
+                Some(&token),
+            )
+            .await;
+        assert_eq!(status, StatusCode::CREATED, "{body}");
+    }
+
+    let (status, sheet) = app
+        .get(&format!("/api/deck/{deck_id}/proxies"), Some(&token))
+        .await;
+    assert_eq!(status, StatusCode::OK, "{sheet}");
+    assert_eq!(sheet["truncated"], false);
+    let urls: Vec<&str> = sheet["images"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|i| i["image_url"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        urls,
+        [
+            "https://img.test/delver-front.jpg",
+            "https://img.test/delver-back.jpg",
+            "https://img.test/bolt.jpg",
+            "https://img.test/bolt.jpg",
+        ]
+    );
+
+    let (status, _) = app
+        .get(&format!("/api/deck/{deck_id}/proxies"), Some(&mallory))
+        .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
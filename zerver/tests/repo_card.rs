@@ -1,8 +1,15 @@
 //! Repo-level card tests: the SQL whose logic doesn't surface cleanly through
 //! HTTP. Constructs `Postgres { pool }` and calls `CardRepository` methods
 //! directly (no router). Covers the default synergy ordering, the
-//! `card_signal_rollup` math, and the deck-aware serve's NULL-`oracle_id`
-//! handling (the 2026-07-06 regression: `NULL || seed` NULLed the shuffle key).
+//! `card_signal_rollup` math, the deck-aware serve's NULL-`oracle_id`
+//! handling (the 2026-07-06 regression: `NULL || seed` NULLed the shuffle key),
+//! the aggregate `color_distribution` query, the `format_staples` view, the
+//! `existing_ids` batch check, the summary search projection, the secondary
+//! sort key, the EDHREC rank range and sort, battle defense and its sort,
+//! cursor paging, how the partial-sync delta upsert sorts cards into full
+//! rewrites, price refreshes and skips, the swappable `is_playable` layout
+//! whitelist, batch lookup by exact name, and the everything-search with its
+//! relevance ranking.
 //!
 //! Requires `DATABASE_URL`: `set -a; source zerver/.env; set +a`.
 
@@ -12,13 +19,30 @@ mod common;
 
 use common::{card, refresh_card_views, seed_cards};
 use serde_json::json;
+use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 
 use zwipe::{
-    domain::card::ports::{CardRepository, DeckServeContext},
+    domain::card::{
+        models::{
+            sync_checkpoint::SyncCheckpoint, sync_progress::SyncProgressReporter,
+            sync_schedule::SyncKind, zervice_metrics::ZerviceMetrics,
+        },
+        ports::{CardRepository, CardService, DeckServeContext},
+        requests::get_scryfall_data::GetScryfallData,
+        services::Service,
+    },
     outbound::sqlx::postgres::Postgres,
 };
-use zwipe_core::domain::card::search_card::card_filter::CardQuery;
+use zwipe_core::domain::card::{
+    card_summary::CardSummary,
+    color_distribution::ColorDistribution,
+    scryfall_data::{ScryfallData, colors::Colors},
+    search_card::card_filter::{
+        CardQuery, Cursor, builder::CardQueryBuilder, card_sort_key::CardSortKey,
+        criteria::playable_layouts_with_tokens,
+    },
+};
 
 /// A default `CardQuery` — no criteria, no explicit sort (so the synergy /
 /// popularity ordering is the one under test).
@@ -341,3 +365,728 @@ async fn deck_oracle_tags_lift_matching_cards_in_banded_serve(pool: sqlx::PgPool
         "with the deck's selected otag, matching cards are lifted onto the first page (got {zzz_with})"
     );
 }
+
+/// Mana cost filters: `contains` finds a phyrexian pip anywhere in the cost;
+/// `equals` matches the exact symbol multiset, whichever order either side
+/// writes it in.
+#[sqlx::test]
+async fn mana_cost_filters_match_pips_and_ignore_symbol_order(pool: sqlx::PgPool) {
+    seed_cards(
+        &pool,
+        &[
+            card("Dismember").mono("B").mana_cost("{1}{B/P}{B/P}"),
+            card("Vault Skirge").mono("B").mana_cost("{1}{B/P}"),
+            card("Doom Blade").mono("B").mana_cost("{1}{B}"),
+            card("Backwards Cleric").mono("W").mana_cost("{W}{1}{W}"),
+            card("Forwards Cleric").mono("W").mana_cost("{1}{W}{W}"),
+            card("Serra Angel").mono("W").mana_cost("{3}{W}{W}"),
+        ],
+    )
+    .await;
+    let repo = Postgres { pool: pool.clone() };
+    let search = async |builder: &mut CardQueryBuilder| -> Vec<String> {
+        let mut names: Vec<String> = repo
+            .search_cards(&builder.set_limit(100).build().unwrap())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| c.scryfall_data.name)
+            .collect();
+        names.sort();
+        names
+    };
+
+    let mut phyrexian = CardQueryBuilder::new();
+    phyrexian.set_mana_cost_contains("{b/p}");
+    assert_eq!(
+        search(&mut phyrexian).await,
+        vec!["Dismember", "Vault Skirge"]
+    );
+
+    for written in ["{1}{W}{W}", "{W}{W}{1}"] {
+        let mut exact = CardQueryBuilder::new();
+        exact.set_mana_cost_equals(written);
+        assert_eq!(
+            search(&mut exact).await,
+            vec!["Backwards Cleric", "Forwards Cleric"],
+            "`{written}` should match regardless of printed symbol order"
+        );
+    }
+}
+
+/// A secondary sort key breaks ties left by the primary: equal-CMC cards come
+/// back alphabetical, in the same order the in-memory `Cards::sorted_then`
+/// gives.
+#[sqlx::test]
+async fn sort_then_by_name_orders_equal_cmc_alphabetically(pool: sqlx::PgPool) {
+    seed_cards(
+        &pool,
+        &[
+            card("Shock").cmc(1.0),
+            card("Counterspell").cmc(2.0),
+            card("Opt").cmc(1.0),
+            card("Brainstorm").cmc(1.0),
+            card("Arcane Denial").cmc(2.0),
+        ],
+    )
+    .await;
+    let repo = Postgres { pool };
+    let names = async |ascending: bool| -> Vec<String> {
+        let query = CardQueryBuilder::with_sort(CardSortKey::Cmc)
+            .set_then_sort(CardSortKey::Name)
+            .set_ascending(ascending)
+            .set_limit(100)
+            .build()
+            .unwrap();
+        repo.search_scryfall_data(&query)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| c.name)
+            .collect()
+    };
+
+    assert_eq!(
+        names(true).await,
+        [
+            "Brainstorm",
+            "Opt",
+            "Shock",
+            "Arcane Denial",
+            "Counterspell"
+        ]
+    );
+    assert_eq!(
+        names(false).await,
+        [
+            "Counterspell",
+            "Arcane Denial",
+            "Shock",
+            "Opt",
+            "Brainstorm"
+        ]
+    );
+}
+
+/// EDHREC rank: the range is inclusive and the sort drops unranked cards,
+/// most popular (lowest rank) first when ascending.
+#[sqlx::test]
+async fn edhrec_rank_range_and_sort_skip_unranked(pool: sqlx::PgPool) {
+    seed_cards(
+        &pool,
+        &[
+            card("Sol Ring").edhrec_rank(1),
+            card("Arcane Signet").edhrec_rank(2),
+            card("Cultivate").edhrec_rank(40),
+            card("Rampant Growth").edhrec_rank(150),
+            card("Brand New Card"),
+        ],
+    )
+    .await;
+    let repo = Postgres { pool };
+    let names = async |builder: &mut CardQueryBuilder| -> Vec<String> {
+        let query = builder.set_limit(100).build().unwrap();
+        repo.search_scryfall_data(&query)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| c.name)
+            .collect()
+    };
+
+    let mut top = CardQueryBuilder::with_edhrec_rank_range((100, 1));
+    top.set_sort(CardSortKey::EdhrecRank);
+    assert_eq!(
+        names(&mut top).await,
+        ["Sol Ring", "Arcane Signet", "Cultivate"]
+    );
+
+    let mut least_popular = CardQueryBuilder::with_sort(CardSortKey::EdhrecRank);
+    least_popular.set_ascending(false);
+    assert_eq!(
+        names(&mut least_popular).await,
+        ["Rampant Growth", "Cultivate", "Arcane Signet", "Sol Ring"]
+    );
+}
+
+/// Battle defense: numeric matches only, and the sort drops non-battles.
+#[sqlx::test]
+async fn defense_range_and_sort_skip_non_battles(pool: sqlx::PgPool) {
+    seed_cards(
+        &pool,
+        &[
+            card("Invasion of Zendikar").layout("battle").defense("3"),
+            card("Invasion of Tarkir").layout("battle").defense("5"),
+            card("Invasion of Ravnica").layout("battle").defense("4"),
+            card("Grizzly Bears").power("2").toughness("2"),
+        ],
+    )
+    .await;
+    let repo = Postgres { pool };
+    let names = async |builder: &mut CardQueryBuilder| -> Vec<String> {
+        let query = builder.set_limit(100).build().unwrap();
+        repo.search_scryfall_data(&query)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| c.name)
+            .collect()
+    };
+
+    let mut low = CardQueryBuilder::with_defense_range((4, 1));
+    low.set_sort(CardSortKey::Defense);
+    assert_eq!(
+        names(&mut low).await,
+        ["Invasion of Zendikar", "Invasion of Ravnica"]
+    );
+
+    let mut toughest = CardQueryBuilder::with_sort(CardSortKey::Defense);
+    toughest.set_ascending(false);
+    assert_eq!(
+        names(&mut toughest).await,
+        [
+            "Invasion of Tarkir",
+            "Invasion of Ravnica",
+            "Invasion of Zendikar"
+        ]
+    );
+}
+
+/// A power budget drops game changers and the most-played staples while a
+/// low-power brew still serves.
+#[sqlx::test]
+async fn power_budget_excludes_game_changers_and_top_staples(pool: sqlx::PgPool) {
+    seed_cards(
+        &pool,
+        &[
+            card("Rhystic Study").edhrec_rank(12).game_changer(true),
+            card("Sol Ring").edhrec_rank(1),
+            card("Goblin Bushwhacker").edhrec_rank(2500),
+        ],
+    )
+    .await;
+    let repo = Postgres { pool };
+
+    let mut budget = CardQueryBuilder::with_sort(CardSortKey::Name);
+    budget.set_exclude_game_changers(true).set_limit(100);
+    let names: Vec<String> = repo
+        .search_scryfall_data(&budget.build().unwrap())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|c| c.name)
+        .collect();
+    assert_eq!(names, ["Goblin Bushwhacker", "Sol Ring"]);
+
+    budget.set_min_edhrec_rank(10);
+    let names: Vec<String> = repo
+        .search_scryfall_data(&budget.build().unwrap())
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|c| c.name)
+        .collect();
+    assert_eq!(names, ["Goblin Bushwhacker"]);
+}
+
+/// Cursor pages walk the same order as one big page, ties included: shared
+/// mana values fall back to the id tiebreak, so nothing repeats or drops
+/// between pages in either direction.
+#[sqlx::test]
+async fn cursor_pages_match_one_big_page(pool: sqlx::PgPool) {
+    seed_cards(
+        &pool,
+        &[
+            card("Sol Ring").cmc(1.0),
+            card("Arcane Signet").cmc(2.0),
+            card("Mind Stone").cmc(2.0),
+            card("Fellwar Stone").cmc(2.0),
+            card("Cultivate").cmc(3.0),
+        ],
+    )
+    .await;
+    let repo = Postgres { pool };
+    for ascending in [true, false] {
+        let mut builder = CardQueryBuilder::with_sort(CardSortKey::Cmc);
+        builder.set_ascending(ascending).set_limit(100);
+        let all: Vec<Uuid> = repo
+            .search_scryfall_data(&builder.build().unwrap())
+            .await
+            .unwrap()
+            .iter()
+            .map(|c| c.id)
+            .collect();
+        assert_eq!(all.len(), 5);
+
+        builder.set_limit(2);
+        let mut paged = Vec::new();
+        loop {
+            let page = repo
+                .search_scryfall_data(&builder.build().unwrap())
+                .await
+                .unwrap();
+            paged.extend(page.iter().map(|c| c.id));
+            let Some(last) = page.last().filter(|_| page.len() == 2) else {
+                break;
+            };
+            builder.set_after_cursor(Cursor::after(CardSortKey::Cmc, last).unwrap());
+        }
+        assert_eq!(paged, all, "ascending: {ascending}");
+    }
+}
+
+/// Color distribution over a known small set: identity colors overlap
+/// (a W/U card counts for both), colorless/multicolor bucket by cardinality,
+/// and cards outside the filter don't count.
+#[sqlx::test]
+async fn color_distribution_counts_a_filtered_set(pool: sqlx::PgPool) {
+    seed_cards(
+        &pool,
+        &[
+            card("Serra Angel").mono("W").set("TST", "Test Overview"),
+            card("Swords to Plowshares")
+                .mono("W")
+                .set("TST", "Test Overview"),
+            card("Azorius Charm")
+                .color_identity("WU")
+                .set("TST", "Test Overview"),
+            card("Lightning Bolt").mono("R").set("TST", "Test Overview"),
+            card("Sol Ring")
+                .color_identity("")
+                .set("TST", "Test Overview"),
+            card("Llanowar Elves").mono("G").set("OTH", "Other Set"),
+        ],
+    )
+    .await;
+    let repo = Postgres { pool: pool.clone() };
+    let query: CardQuery =
+        serde_json::from_value(json!({ "set_equals_any": ["Test Overview"] })).unwrap();
+
+    let distribution = repo.color_distribution(&query).await.unwrap();
+    assert_eq!(
+        distribution,
+        ColorDistribution {
+            total: 5,
+            white: 3,
+            blue: 1,
+            black: 0,
+            red: 1,
+            green: 0,
+            colorless: 1,
+            multicolor: 1,
+        }
+    );
+}
+
+/// `format_staples` serves a color identity's subset buckets best rank first,
+/// skipping off-identity, commander-illegal, unranked, and basic land cards,
+/// and only reflects rank changes once the post-sync refresh runs.
+#[sqlx::test]
+async fn format_staples_serve_subset_identities_after_refresh(pool: sqlx::PgPool) {
+    seed_cards(
+        &pool,
+        &[
+            card("Sol Ring")
+                .color_identity("")
+                .edhrec_rank(1)
+                .commander_legal(),
+            card("Swords to Plowshares")
+                .mono("W")
+                .edhrec_rank(3)
+                .commander_legal(),
+            card("Azorius Signet")
+                .color_identity("WU")
+                .edhrec_rank(2)
+                .commander_legal(),
+            card("Counterspell")
+                .mono("U")
+                .edhrec_rank(4)
+                .commander_legal(),
+            card("Lightning Bolt")
+                .mono("R")
+                .edhrec_rank(5)
+                .commander_legal(),
+            card("Mana Crypt").color_identity("").edhrec_rank(6),
+            card("Plains")
+                .color_identity("W")
+                .type_line("Basic Land — Plains")
+                .edhrec_rank(7)
+                .commander_legal(),
+            card("Unranked Angel").mono("W").commander_legal(),
+        ],
+    )
+    .await;
+    let repo = Postgres { pool: pool.clone() };
+    let azorius = Colors::from_short_names(vec!["W".into(), "U".into()]).unwrap();
+    let names = |cards: Vec<zwipe_core::domain::card::Card>| -> Vec<String> {
+        cards.into_iter().map(|c| c.scryfall_data.name).collect()
+    };
+
+    let staples = repo.format_staples(&azorius, 10).await.unwrap();
+    assert_eq!(
+        names(staples),
+        [
+            "Sol Ring",
+            "Azorius Signet",
+            "Swords to Plowshares",
+            "Counterspell"
+        ]
+    );
+    let top_two = repo.format_staples(&azorius, 2).await.unwrap();
+    assert_eq!(names(top_two), ["Sol Ring", "Azorius Signet"]);
+
+    // A sync moves Counterspell to the top; the view is stale until the
+    // zervice refresh order (latest_cards, then format_staples) runs.
+    sqlx::query("UPDATE scryfall_data SET edhrec_rank = 0 WHERE name = 'Counterspell'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let stale = repo.format_staples(&azorius, 1).await.unwrap();
+    assert_eq!(names(stale), ["Sol Ring"]);
+
+    repo.refresh_latest_cards().await.unwrap();
+    repo.refresh_format_staples().await.unwrap();
+    let fresh = repo.format_staples(&azorius, 1).await.unwrap();
+    assert_eq!(names(fresh), ["Counterspell"]);
+}
+
+/// `existing_ids` returns only the requested ids that are stored.
+#[sqlx::test]
+async fn existing_ids_returns_only_present_ids(pool: sqlx::PgPool) {
+    let sol_ring = card("Sol Ring");
+    let counterspell = card("Counterspell");
+    let present = [sol_ring.id(), counterspell.id()];
+    seed_cards(&pool, &[sol_ring, counterspell]).await;
+    let repo = Postgres { pool };
+    let missing = Uuid::new_v4();
+
+    let existing = repo
+        .existing_ids(&[present[0], missing, present[1]])
+        .await
+        .unwrap();
+    assert_eq!(existing, present.into_iter().collect());
+    assert!(!existing.contains(&missing));
+}
+
+/// The summary projection serves the same cards as the full search, with
+/// every summary field populated from the narrow column list.
+#[sqlx::test]
+async fn summary_search_projects_the_full_search(pool: sqlx::PgPool) {
+    seed_cards(
+        &pool,
+        &[
+            card("Counterspell")
+                .mono("U")
+                .mana_cost("{U}{U}")
+                .oracle_text("Counter target spell.")
+                .edhrec_rank(4)
+                .usd("1.50"),
+            card("Sol Ring").color_identity("").edhrec_rank(1),
+        ],
+    )
+    .await;
+    let repo = Postgres { pool };
+    let query = CardQueryBuilder::new()
+        .set_sort(CardSortKey::Name)
+        .set_ascending(true)
+        .build()
+        .unwrap();
+
+    let full = repo.search_scryfall_data(&query).await.unwrap();
+    let summaries = repo.search_card_summaries(&query).await.unwrap();
+    assert_eq!(
+        summaries,
+        full.iter().map(CardSummary::from).collect::<Vec<_>>()
+    );
+
+    let counterspell = &summaries[0];
+    assert_eq!(counterspell.name, "Counterspell");
+    assert_eq!(counterspell.mana_cost.as_deref(), Some("{U}{U}"));
+    assert_eq!(counterspell.edhrec_rank, Some(4));
+    assert_eq!(counterspell.prices.usd.as_deref(), Some("1.50"));
+    assert_eq!(counterspell.color_identity.to_short_names(), ["U"]);
+    // The full path still carries the columns the summary leaves out.
+    assert_eq!(
+        full[0].oracle_text.as_deref(),
+        Some("Counter target spell.")
+    );
+}
+
+/// The partial-sync delta rewrites a card only when its gameplay fields
+/// change; a price move alone refreshes prices, and an identical card is
+/// skipped.
+#[sqlx::test]
+async fn delta_upsert_splits_full_price_only_and_unchanged(pool: sqlx::PgPool) {
+    let fixtures = [
+        card("Counterspell").usd("1.00"),
+        card("Lightning Bolt").usd("2.00"),
+        card("Sol Ring").usd("3.00"),
+    ];
+    seed_cards(&pool, &fixtures).await;
+    let repo = Postgres { pool: pool.clone() };
+    let mut stored = Vec::new();
+    for fixture in &fixtures {
+        let request = GetScryfallData::new(&fixture.id().to_string()).unwrap();
+        stored.push(repo.get_scryfall_data(&request).await.unwrap());
+    }
+
+    let (mut reworded, mut repriced, identical) =
+        (stored[0].clone(), stored[1].clone(), stored[2].clone());
+    reworded.oracle_text = Some("Counter target spell. Draw a card.".to_string());
+    repriced.prices.usd = Some("2.50".to_string());
+    repriced.edhrec_rank = Some(7); // not a gameplay field: not written
+
+    let mut metrics = ZerviceMetrics::new();
+    let upserted = repo
+        .batch_delta_upsert(&[reworded, repriced, identical], 100, 1, &mut metrics, None)
+        .await
+        .unwrap();
+    assert_eq!(upserted.len(), 1);
+    assert_eq!(upserted[0].scryfall_data.name, "Counterspell");
+    assert_eq!(
+        (
+            metrics.upserted_count(),
+            metrics.price_updated_count(),
+            metrics.skipped_count()
+        ),
+        (1, 1, 1)
+    );
+
+    let reread = |id: Uuid| {
+        let repo = &repo;
+        async move {
+            let request = GetScryfallData::new(&id.to_string()).unwrap();
+            repo.get_scryfall_data(&request).await.unwrap()
+        }
+    };
+    let counterspell = reread(fixtures[0].id()).await;
+    assert_eq!(
+        counterspell.oracle_text.as_deref(),
+        Some("Counter target spell. Draw a card.")
+    );
+    let bolt = reread(fixtures[1].id()).await;
+    assert_eq!(bolt.prices.usd.as_deref(), Some("2.50"));
+    assert_eq!(bolt.edhrec_rank, stored[1].edhrec_rank);
+}
+
+/// Chunks upserted concurrently on separate connections still add up to one
+/// set of sync metrics, every chunk commits, and progress advances per chunk.
+#[sqlx::test]
+async fn concurrent_delta_upsert_aggregates_chunk_metrics(pool: sqlx::PgPool) {
+    let fixtures: Vec<_> = ["Opt", "Ponder", "Preordain", "Brainstorm", "Consider"]
+        .into_iter()
+        .map(|name| card(name).usd("1.00"))
+        .collect();
+    seed_cards(&pool, &fixtures).await;
+    let repo = Postgres { pool: pool.clone() };
+    let mut repriced = Vec::new();
+    for fixture in &fixtures {
+        let request = GetScryfallData::new(&fixture.id().to_string()).unwrap();
+        let mut stored = repo.get_scryfall_data(&request).await.unwrap();
+        stored.prices.usd = Some("9.99".to_string());
+        repriced.push(stored);
+    }
+
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&events);
+    let reporter = SyncProgressReporter::new(2, move |p| sink.lock().unwrap().push(*p));
+    let mut tracker = reporter.start(Some(repriced.len()));
+    let mut metrics = ZerviceMetrics::new();
+    repo.batch_delta_upsert(&repriced, 2, 3, &mut metrics, Some(&mut tracker))
+        .await
+        .unwrap();
+    tracker.finish(repriced.len(), &metrics);
+    let last = *events.lock().unwrap().last().unwrap();
+    assert_eq!((last.processed, last.upserted, last.errors), (5, 5, 0));
+    assert_eq!(
+        (
+            metrics.upserted_count(),
+            metrics.price_updated_count(),
+            metrics.skipped_count(),
+            metrics.error_count()
+        ),
+        (0, 5, 0, 0)
+    );
+    for fixture in &fixtures {
+        let request = GetScryfallData::new(&fixture.id().to_string()).unwrap();
+        let stored = repo.get_scryfall_data(&request).await.unwrap();
+        assert_eq!(
+            stored.prices.usd.as_deref(),
+            Some("9.99"),
+            "{}",
+            stored.name
+        );
+    }
+}
+
+/// A sync checkpoint is kept per sync kind, replaced on save, and gone
+/// once cleared.
+#[sqlx::test]
+async fn sync_checkpoints_round_trip_per_kind(pool: sqlx::PgPool) {
+    let repo = Postgres { pool: pool.clone() };
+    assert_eq!(
+        repo.get_sync_checkpoint(SyncKind::Full).await.unwrap(),
+        None
+    );
+
+    let mut checkpoint = SyncCheckpoint {
+        sync_kind: SyncKind::Full,
+        download_uri: "https://data.scryfall.io/default-cards-20261001.json".to_string(),
+        processed_count: 327,
+    };
+    repo.save_sync_checkpoint(&checkpoint).await.unwrap();
+    checkpoint.processed_count = 654;
+    repo.save_sync_checkpoint(&checkpoint).await.unwrap();
+    assert_eq!(
+        repo.get_sync_checkpoint(SyncKind::Full).await.unwrap(),
+        Some(checkpoint)
+    );
+    assert_eq!(
+        repo.get_sync_checkpoint(SyncKind::Partial).await.unwrap(),
+        None
+    );
+
+    repo.clear_sync_checkpoint(SyncKind::Full).await.unwrap();
+    assert_eq!(
+        repo.get_sync_checkpoint(SyncKind::Full).await.unwrap(),
+        None
+    );
+}
+
+/// `playable_layouts` swaps the `is_playable` whitelist: the same search
+/// hides a token under the deck whitelist and serves it under one that
+/// includes token layouts.
+#[sqlx::test]
+async fn playable_layouts_decide_whether_tokens_serve(pool: sqlx::PgPool) {
+    seed_cards(
+        &pool,
+        &[
+            card("Soldier").mono("W").token(),
+            card("Soldier of Fortune").mono("R"),
+        ],
+    )
+    .await;
+    let repo = Postgres { pool: pool.clone() };
+    let query = CardQueryBuilder::with_name_contains("Soldier")
+        .build()
+        .unwrap();
+
+    let names = |served: Vec<ScryfallData>| {
+        let mut names: Vec<String> = served.into_iter().map(|s| s.name).collect();
+        names.sort();
+        names
+    };
+    let deck = repo
+        .search_scryfall_data_deck_aware(&query, DeckServeContext::default())
+        .await
+        .unwrap();
+    assert_eq!(names(deck), vec!["Soldier of Fortune"]);
+
+    let with_tokens = playable_layouts_with_tokens();
+    let tokens = repo
+        .search_scryfall_data_deck_aware(
+            &query,
+            DeckServeContext {
+                playable_layouts: Some(&with_tokens),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(names(tokens), vec!["Soldier", "Soldier of Fortune"]);
+}
+
+/// `get_cards_by_names` pairs every requested name, in order, with its exact
+/// match: English, non-promo printings win over newer foreign or promo ones,
+/// a front face finds its double-faced card, and a prefix or a missing name
+/// pairs with `None`.
+#[sqlx::test]
+async fn cards_by_names_pair_exact_matches_with_preferred_printings(pool: sqlx::PgPool) {
+    let island = card("Island").released("2020-01-01");
+    let oracle = island.oracle_id();
+    let preferred = island.id();
+    seed_cards(
+        &pool,
+        &[
+            island,
+            card("Island")
+                .with_ids(Uuid::new_v4(), oracle)
+                .lang("ja")
+                .released("2024-01-01"),
+            card("Island")
+                .with_ids(Uuid::new_v4(), oracle)
+                .promo(true)
+                .released("2025-01-01"),
+            card("Island Sanctuary"),
+            card("Boggart Trawler // Boggart Bog").layout("modal_dfc"),
+        ],
+    )
+    .await;
+    let service = Service::new(Postgres { pool });
+    let names: Vec<String> = ["ISLAND", "Boggart Trawler", "Isl", "Missing Card"]
+        .map(String::from)
+        .to_vec();
+
+    let pairs = service.get_cards_by_names(&names).await.unwrap();
+    let resolved: Vec<(&str, Option<&str>)> = pairs
+        .iter()
+        .map(|(name, card)| {
+            (
+                name.as_str(),
+                card.as_ref().map(|c| c.scryfall_data.name.as_str()),
+            )
+        })
+        .collect();
+    assert_eq!(
+        resolved,
+        [
+            ("ISLAND", Some("Island")),
+            ("Boggart Trawler", Some("Boggart Trawler // Boggart Bog")),
+            ("Isl", None),
+            ("Missing Card", None),
+        ]
+    );
+    assert_eq!(pairs[0].1.as_ref().unwrap().scryfall_data.id, preferred);
+    assert!(service.get_cards_by_names(&[]).await.unwrap().is_empty());
+}
+
+/// `text_search` matches across name, type line, oracle and flavor text —
+/// stemmed words and partial words alike — and the Relevance sort puts the
+/// card that mentions the term most first, whatever the name order.
+#[sqlx::test]
+async fn text_search_spans_fields_and_ranks_by_relevance(pool: sqlx::PgPool) {
+    seed_cards(
+        &pool,
+        &[
+            card("Goblin Guide").type_line("Creature — Goblin Scout"),
+            card("Krenko, Mob Boss")
+                .type_line("Legendary Creature — Goblin Warrior")
+                .oracle_text(
+                    "{T}: Create X 1/1 red Goblin creature tokens, where X is the \
+                     number of Goblins you control.",
+                ),
+            card("Mountain").flavor_text("Goblin country."),
+            card("Counterspell").oracle_text("Counter target spell."),
+        ],
+    )
+    .await;
+    let repo = Postgres { pool };
+    let names =
+        |served: Vec<ScryfallData>| -> Vec<String> { served.into_iter().map(|s| s.name).collect() };
+
+    let mut builder = CardQueryBuilder::with_text_search("goblins");
+    builder.set_sort(CardSortKey::Relevance);
+    let ranked = repo
+        .search_scryfall_data(&builder.build().unwrap())
+        .await
+        .unwrap();
+    assert_eq!(
+        names(ranked),
+        ["Krenko, Mob Boss", "Goblin Guide", "Mountain"]
+    );
+
+    let partial = CardQueryBuilder::with_text_search("gob").build().unwrap();
+    let mut partial = names(repo.search_scryfall_data(&partial).await.unwrap());
+    partial.sort();
+    assert_eq!(partial, ["Goblin Guide", "Krenko, Mob Boss", "Mountain"]);
+}
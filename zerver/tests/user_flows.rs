@@ -57,6 +57,33 @@ async fn change_username_wrong_password_rejected(pool: sqlx::PgPool) {
     assert_eq!(me["username"], "stayput");
 }
 
+#[sqlx::test]
+async fn change_username_rejects_case_variant_of_taken_name(pool: sqlx::PgPool) {
+    let app = TestApp::new(pool);
+    app.register("Taken").await;
+    let (token, _) = app.register("renamer").await;
+
+    let (status, body) = app
+        .put(
+            "/api/user/change-username",
+            json!({ "new_username": "tAKEN", "password": "TestPass123!" }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{body}");
+
+    // recasing one's own name isn't a collision
+    let (status, updated) = app
+        .put(
+            "/api/user/change-username",
+            json!({ "new_username": "Renamer", "password": "TestPass123!" }),
+            Some(&token),
+        )
+        .await;
+    assert_eq!(status, StatusCode::OK, "recase: {updated}");
+    assert_eq!(updated["username"], "Renamer");
+}
+
 #[sqlx::test]
 async fn change_password_then_login_with_new(pool: sqlx::PgPool) {
     let app = TestApp::new(pool);
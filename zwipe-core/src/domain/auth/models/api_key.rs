@@ -0,0 +1,195 @@
+//! Long-lived API keys for programmatic access.
+//!
+//! Session JWTs expire daily and rotate with refresh tokens, which suits apps
+//! but not scripts and tools. An API key is a stable bearer credential a user
+//! mints for such a tool, labelled so they can tell keys apart and scoped so a
+//! read-only key can't change anything.
+//!
+//! # Security Features
+//!
+//! - **Shown Once**: The secret is returned only at creation
+//! - **SHA-256 Hashing**: Only the hash of the secret is stored
+//! - **Cryptographically Random**: 32 random bytes behind a fixed prefix
+//! - **Revocable**: A revoked key is rejected immediately
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Prefix on every API key secret. Lets the server tell a key from a JWT in
+/// the same `Authorization: Bearer` header, and makes a leaked key easy to
+/// recognise in logs and secret scanners.
+pub const API_KEY_PREFIX: &str = "zwk_";
+
+/// Maximum length of a key's label.
+pub const API_KEY_LABEL_MAX_LEN: usize = 64;
+
+// =======
+//  scope
+// =======
+
+/// What an API key may do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiKeyScope {
+    /// Reads only; any request that would change data is refused.
+    ReadOnly,
+    /// Everything the owning user can do.
+    ReadWrite,
+}
+
+impl ApiKeyScope {
+    /// Snake-case wire/storage token.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiKeyScope::ReadOnly => "read_only",
+            ApiKeyScope::ReadWrite => "read_write",
+        }
+    }
+
+    /// True when the key may change data.
+    pub fn can_write(&self) -> bool {
+        matches!(self, ApiKeyScope::ReadWrite)
+    }
+}
+
+impl fmt::Display for ApiKeyScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The string did not name a known [`ApiKeyScope`].
+#[derive(Debug, Error)]
+#[error("unknown api key scope: {0}")]
+pub struct ParseApiKeyScopeError(pub String);
+
+impl FromStr for ApiKeyScope {
+    type Err = ParseApiKeyScopeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "read_only" => Ok(ApiKeyScope::ReadOnly),
+            "read_write" => Ok(ApiKeyScope::ReadWrite),
+            other => Err(ParseApiKeyScopeError(other.to_string())),
+        }
+    }
+}
+
+// ========
+//  secret
+// ========
+
+/// A freshly generated API key secret: the prefix followed by 64 hex
+/// characters (32 random bytes).
+///
+/// # Storage
+///
+/// - **Database**: SHA-256 hash of the full secret (see [`ApiKeySecret::hash`])
+/// - **Client**: Plaintext secret, returned once at creation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiKeySecret(String);
+
+impl ApiKeySecret {
+    /// Generates a new cryptographically-secure secret.
+    pub fn generate() -> Self {
+        let mut bytes = [0u8; 32];
+        rand::rng().fill_bytes(&mut bytes);
+        Self(format!("{API_KEY_PREFIX}{}", hex::encode(bytes)))
+    }
+
+    /// True when `token` looks like an API key rather than a JWT.
+    pub fn is_api_key(token: &str) -> bool {
+        token.starts_with(API_KEY_PREFIX)
+    }
+
+    /// SHA-256 hash of `secret` as hex — the value stored and looked up.
+    pub fn hash(secret: &str) -> String {
+        hex::encode(Sha256::digest(secret.as_bytes()))
+    }
+
+    /// The plaintext secret.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for ApiKeySecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// ======
+//  main
+// ======
+
+/// An API key as listed to its owner; never includes the secret.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiKey {
+    /// Key identifier, used to revoke it.
+    pub id: Uuid,
+    /// Owner-chosen name (e.g. the tool it's for).
+    pub label: String,
+    /// What the key may do.
+    pub scope: ApiKeyScope,
+    /// When the key was minted.
+    pub created_at: DateTime<Utc>,
+    /// When the key last authenticated a request; `None` if never used.
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// A newly created key together with its secret. The only time the secret
+/// leaves the server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CreatedApiKey {
+    /// The stored key.
+    #[serde(flatten)]
+    pub key: ApiKey,
+    /// The plaintext secret to send as `Authorization: Bearer <secret>`.
+    pub secret: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_secret_is_prefixed_and_unique() {
+        let a = ApiKeySecret::generate();
+        let b = ApiKeySecret::generate();
+        assert!(ApiKeySecret::is_api_key(a.as_str()));
+        assert_eq!(a.as_str().len(), API_KEY_PREFIX.len() + 64);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn jwt_is_not_an_api_key() {
+        assert!(!ApiKeySecret::is_api_key("header.payload.signature"));
+    }
+
+    #[test]
+    fn hash_is_stable_and_hides_the_secret() {
+        let secret = ApiKeySecret::generate();
+        let hash = ApiKeySecret::hash(secret.as_str());
+        assert_eq!(hash, ApiKeySecret::hash(secret.as_str()));
+        assert_eq!(hash.len(), 64);
+        assert!(!hash.contains(&secret.as_str()[API_KEY_PREFIX.len()..]));
+    }
+
+    #[test]
+    fn scope_round_trips_through_str_and_serde() {
+        for scope in [ApiKeyScope::ReadOnly, ApiKeyScope::ReadWrite] {
+            assert_eq!(ApiKeyScope::from_str(scope.as_str()).unwrap(), scope);
+            let wire = serde_json::to_string(&scope).unwrap();
+            assert_eq!(wire, format!("\"{scope}\""));
+        }
+        assert!(ApiKeyScope::from_str("admin").is_err());
+        assert!(!ApiKeyScope::ReadOnly.can_write());
+        assert!(ApiKeyScope::ReadWrite.can_write());
+    }
+}
@@ -1,4 +1,5 @@
 pub mod access_token;
+pub mod api_key;
 pub mod password;
 pub mod platform;
 pub mod refresh_token;
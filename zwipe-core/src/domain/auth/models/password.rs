@@ -70,6 +70,10 @@ pub enum InvalidPassword {
     /// Minimum required is 6 unique characters.
     #[error(transparent)]
     TooFewUniqueChars(#[from] TooFewUniqueChars),
+
+    /// Password appears in a known data breach (server-side check only).
+    #[error("has appeared in a data breach; choose a different one")]
+    Breached,
 }
 
 /// Error indicating too many consecutive repeated characters in the password.
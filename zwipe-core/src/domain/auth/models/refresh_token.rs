@@ -19,8 +19,8 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use thiserror::Error;
 
-/// Refresh token lifespan (14 days).
-const REFRESH_TOKEN_LIFESPAN: Duration = Duration::days(14);
+/// Default refresh token lifespan (14 days).
+pub const REFRESH_TOKEN_LIFESPAN: Duration = Duration::days(14);
 
 // =======
 //  error
@@ -61,10 +61,15 @@ pub struct RefreshToken {
 impl RefreshToken {
     /// Generates a new cryptographically-secure refresh token.
     pub fn generate() -> Self {
+        Self::generate_with_lifespan(REFRESH_TOKEN_LIFESPAN)
+    }
+
+    /// Generates a new refresh token that expires `lifespan` from now.
+    pub fn generate_with_lifespan(lifespan: Duration) -> Self {
         let mut bytes = [0u8; 32];
         rand::rng().fill_bytes(&mut bytes);
         let value = hex::encode(bytes);
-        let expires_at = Utc::now() + REFRESH_TOKEN_LIFESPAN;
+        let expires_at = Utc::now() + lifespan;
         Self { value, expires_at }
     }
 }
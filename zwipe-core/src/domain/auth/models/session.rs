@@ -5,17 +5,24 @@
 //! - **Refresh Token**: Long-lived token (14d) for obtaining new access tokens
 
 use crate::domain::{
-    auth::models::{access_token::AccessToken, refresh_token::RefreshToken},
+    auth::models::{
+        access_token::AccessToken, platform::ClientPlatform, refresh_token::RefreshToken,
+    },
     user::models::{User, preferences::UserPreferences},
 };
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
-/// Maximum number of concurrent sessions allowed per user.
+/// Default maximum number of concurrent sessions allowed per user; the
+/// server can be configured with another limit.
 ///
-/// When this limit is exceeded, the oldest session is automatically revoked.
+/// When the limit is exceeded, the oldest session is automatically revoked.
 pub const MAXIMUM_SESSION_COUNT: u8 = 5;
 
+/// Device label of a session opened without a recognisable `User-Agent`.
+pub const UNKNOWN_DEVICE: &str = "Unknown device";
+
 /// A successful authentication response containing user data and tokens.
 #[derive(Debug, Clone, Serialize, PartialEq, Deserialize)]
 pub struct Session {
@@ -39,6 +46,31 @@ impl Session {
     }
 }
 
+/// A live session as listed to its owner; never includes the refresh token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionInfo {
+    /// Session identifier, stable across token refreshes; used to revoke it.
+    pub id: Uuid,
+    /// When the user signed in.
+    pub created_at: DateTime<Utc>,
+    /// When the session's refresh token was last rotated.
+    pub last_refreshed_at: DateTime<Utc>,
+    /// When the session ends unless refreshed first.
+    pub expires_at: DateTime<Utc>,
+    /// Client platform the session was opened from, if reported.
+    pub platform: Option<ClientPlatform>,
+    /// Client app version at the last refresh, if reported.
+    pub client_version: Option<String>,
+    /// Friendly device name (e.g. "Chrome on macOS"); [`UNKNOWN_DEVICE`]
+    /// when the client didn't say and its `User-Agent` wasn't recognised.
+    #[serde(default = "unknown_device")]
+    pub device_label: String,
+}
+
+fn unknown_device() -> String {
+    UNKNOWN_DEVICE.to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,13 +2,119 @@
 //!
 //! CardProfile stores application-computed metadata for each card:
 //! - Token status (whether this is a token vs. real card)
+//! - Copy-limit exception ("A deck can have any number of cards named …")
 //! - Timestamps (when card was added/updated in database)
 
+use crate::domain::card::scryfall_data::ScryfallData;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use thiserror::Error;
 use uuid::Uuid;
 
+/// Error returned when parsing an invalid stored copy limit.
+#[derive(Debug, Clone, Error)]
+#[error("invalid card copy limit")]
+pub struct InvalidCardCopyLimit;
+
+/// A card's own exception to its format's copy limit, printed in its rules
+/// text (Relentless Rats, Persistent Petitioners, Seven Dwarves).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardCopyLimit {
+    /// "A deck can have any number of cards named …"
+    AnyNumber,
+    /// "A deck can have up to seven cards named …"
+    UpTo(u32),
+}
+
+impl CardCopyLimit {
+    /// Reads the exception off the card's rules text (any face); `None` for
+    /// the vast majority of cards, which follow their format's limit.
+    pub fn of(card: &ScryfallData) -> Option<Self> {
+        let faces = card
+            .card_faces
+            .iter()
+            .flat_map(|faces| faces.iter())
+            .filter_map(|face| face.oracle_text.as_deref());
+        card.oracle_text
+            .as_deref()
+            .into_iter()
+            .chain(faces)
+            .find_map(Self::from_oracle_text)
+    }
+
+    /// Parses the exception from one block of oracle text.
+    fn from_oracle_text(text: &str) -> Option<Self> {
+        let text = text.to_lowercase();
+        let (_, rest) = text.split_once("a deck can have ")?;
+        if rest.starts_with("any number of cards named") {
+            return Some(Self::AnyNumber);
+        }
+        let (count, _) = rest.strip_prefix("up to ")?.split_once(" cards named")?;
+        count_word(count).map(Self::UpTo)
+    }
+
+    /// Maximum copies allowed in a deck under this exception.
+    pub fn max(&self) -> Option<u32> {
+        match self {
+            Self::AnyNumber => None,
+            Self::UpTo(n) => Some(*n),
+        }
+    }
+}
+
+/// "seven" → 7; Scryfall spells these counts out, digits are accepted too.
+fn count_word(word: &str) -> Option<u32> {
+    const WORDS: [&str; 20] = [
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+        "twenty",
+    ];
+    WORDS
+        .iter()
+        .position(|w| *w == word)
+        .map(|i| i as u32 + 1)
+        .or_else(|| word.parse().ok())
+}
+
+/// Database form: `any` or the count.
+impl std::fmt::Display for CardCopyLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AnyNumber => write!(f, "any"),
+            Self::UpTo(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+impl TryFrom<&str> for CardCopyLimit {
+    type Error = InvalidCardCopyLimit;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "any" => Ok(Self::AnyNumber),
+            x => x.parse().map(Self::UpTo).map_err(|_| InvalidCardCopyLimit),
+        }
+    }
+}
+
 /// Application metadata for a card.
 ///
 /// Stores computed properties and timestamps for each card in the database.
@@ -38,6 +144,10 @@ pub struct CardProfile {
     /// noise already stripped server-side. `#[serde(default)]`.
     #[serde(default)]
     pub other_oracle_tags: Vec<String>,
+    /// The card's own copy-limit exception, derived from its rules text at
+    /// upsert. `None` means the format's limit applies. `#[serde(default)]`.
+    #[serde(default)]
+    pub copy_limit: Option<CardCopyLimit>,
     /// When this profile was created in database.
     pub created_at: DateTime<Utc>,
     /// When this profile was last updated.
@@ -60,6 +170,7 @@ mod tests {
             oracle_tags: vec![],
             oracle_tags_by_role: BTreeMap::new(),
             other_oracle_tags: vec![],
+            copy_limit: None,
             created_at: ts,
             updated_at: ts,
         };
@@ -92,4 +203,30 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn copy_limit_exceptions_are_read_from_oracle_text() {
+        let mut card = crate::test_utils::make_card("Relentless Rats").scryfall_data;
+        card.oracle_text = Some(
+            "Relentless Rats gets +1/+1 for each other creature on the battlefield named Relentless Rats.\nA deck can have any number of cards named Relentless Rats."
+                .to_string(),
+        );
+        assert_eq!(CardCopyLimit::of(&card), Some(CardCopyLimit::AnyNumber));
+
+        card.oracle_text = Some(
+            "Seven Dwarves gets +1/+1 for each other creature named Seven Dwarves you control.\nA deck can have up to seven cards named Seven Dwarves."
+                .to_string(),
+        );
+        assert_eq!(CardCopyLimit::of(&card), Some(CardCopyLimit::UpTo(7)));
+
+        card.oracle_text = Some("Flying".to_string());
+        assert_eq!(CardCopyLimit::of(&card), None);
+
+        for limit in [CardCopyLimit::AnyNumber, CardCopyLimit::UpTo(9)] {
+            assert_eq!(
+                CardCopyLimit::try_from(limit.to_string().as_str()).unwrap(),
+                limit
+            );
+        }
+    }
 }
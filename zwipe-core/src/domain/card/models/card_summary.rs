@@ -0,0 +1,67 @@
+//! Narrow card projection for list and swipe views.
+//!
+//! A full [`ScryfallData`] row is ~90 columns; summary views only render a
+//! handful of them. Summary searches select just these columns, so rows stay
+//! small between Postgres and the server.
+
+use crate::domain::card::scryfall_data::{
+    ScryfallData, colors::Colors, image_uris::ImageUris, prices::Prices, rarity::Rarity,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The fields a card list or swipe card renders.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CardSummary {
+    /// Scryfall id of the printing.
+    pub id: Uuid,
+    /// Oracle id shared by every printing of the card.
+    pub oracle_id: Option<Uuid>,
+    /// Card name (both faces for multi-face cards).
+    pub name: String,
+    /// Mana cost, e.g. `{2}{U}{U}`.
+    pub mana_cost: Option<String>,
+    /// Converted mana cost.
+    pub cmc: Option<f64>,
+    /// Full type line.
+    pub type_line: Option<String>,
+    /// Commander color identity.
+    pub color_identity: Colors,
+    /// Printing rarity.
+    pub rarity: Rarity,
+    /// Set code.
+    pub set: String,
+    /// EDHREC popularity rank, lower is more popular.
+    pub edhrec_rank: Option<i32>,
+    /// Printing prices.
+    pub prices: Prices,
+    /// Front-face images: the card's own, or its first face's for
+    /// multi-face layouts.
+    pub image_uris: Option<ImageUris>,
+}
+
+impl From<&ScryfallData> for CardSummary {
+    fn from(value: &ScryfallData) -> Self {
+        let image_uris = value.image_uris.clone().or_else(|| {
+            value
+                .card_faces
+                .as_ref()
+                .and_then(|faces| faces.first())
+                .and_then(|face| face.image_uris.clone())
+        });
+        Self {
+            id: value.id,
+            oracle_id: value.oracle_id,
+            name: value.name.clone(),
+            mana_cost: value.mana_cost.clone(),
+            cmc: value.cmc,
+            type_line: value.type_line.clone(),
+            color_identity: value.color_identity.clone(),
+            rarity: value.rarity,
+            set: value.set.clone(),
+            edhrec_rank: value.edhrec_rank,
+            prices: value.prices.clone(),
+            image_uris,
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Color pie coverage of a filtered card set.
+//!
+//! Counts are by color identity: a card counts once toward each of its
+//! colors, so a W/U card adds to `white`, `blue` and `multicolor`. The color
+//! buckets therefore overlap; `total` is the number of distinct cards.
+
+use crate::domain::card::scryfall_data::colors::{Color, Colors};
+use serde::{Deserialize, Serialize};
+
+/// Per-color card counts for a search result set (set-overview charts).
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ColorDistribution {
+    /// Cards matched.
+    pub total: u32,
+    /// Cards with white in their identity.
+    pub white: u32,
+    /// Cards with blue in their identity.
+    pub blue: u32,
+    /// Cards with black in their identity.
+    pub black: u32,
+    /// Cards with red in their identity.
+    pub red: u32,
+    /// Cards with green in their identity.
+    pub green: u32,
+    /// Cards with an empty identity.
+    pub colorless: u32,
+    /// Cards with two or more colors in their identity.
+    pub multicolor: u32,
+}
+
+impl ColorDistribution {
+    /// Tallies a set of color identities.
+    pub fn from_identities<'a>(identities: impl IntoIterator<Item = &'a Colors>) -> Self {
+        let mut distribution = Self::default();
+        for identity in identities {
+            distribution.total += 1;
+            match identity.len() {
+                0 => distribution.colorless += 1,
+                1 => {}
+                _ => distribution.multicolor += 1,
+            }
+            for color in identity.iter() {
+                *distribution.count_mut(*color) += 1;
+            }
+        }
+        distribution
+    }
+
+    /// Cards with `color` in their identity.
+    pub fn count(&self, color: Color) -> u32 {
+        match color {
+            Color::White => self.white,
+            Color::Blue => self.blue,
+            Color::Black => self.black,
+            Color::Red => self.red,
+            Color::Green => self.green,
+        }
+    }
+
+    /// Mutable counter for `color`.
+    pub fn count_mut(&mut self, color: Color) -> &mut u32 {
+        match color {
+            Color::White => &mut self.white,
+            Color::Blue => &mut self.blue,
+            Color::Black => &mut self.black,
+            Color::Red => &mut self.red,
+            Color::Green => &mut self.green,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_each_identity_color_and_buckets() {
+        let identities: Vec<Colors> = vec![
+            [Color::White].into_iter().collect(),
+            [Color::White, Color::Blue].into_iter().collect(),
+            std::iter::empty::<Color>().collect(),
+            [Color::Red].into_iter().collect(),
+        ];
+        let distribution = ColorDistribution::from_identities(&identities);
+        assert_eq!(distribution.total, 4);
+        assert_eq!(distribution.count(Color::White), 2);
+        assert_eq!(distribution.count(Color::Blue), 1);
+        assert_eq!(distribution.count(Color::Red), 1);
+        assert_eq!(distribution.count(Color::Green), 0);
+        assert_eq!(distribution.colorless, 1);
+        assert_eq!(distribution.multicolor, 1);
+    }
+}
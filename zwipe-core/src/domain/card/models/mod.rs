@@ -4,14 +4,24 @@
 pub mod card_profile;
 /// Mechanical category classification (ramp, draw, removal, etc.).
 pub mod card_role;
+/// Narrow card projection for list and swipe views.
+pub mod card_summary;
+/// Per-color card counts over a filtered card set.
+pub mod color_distribution;
 /// Reminder text for keyword abilities (Flying, Trample, …).
 pub mod keyword;
 /// Oracle tag catalog entry (community-maintained functional tags).
 pub mod oracle_tag;
+/// Card plus its alternative printings (card detail payload).
+pub mod printing_summary;
 /// Scryfall API data models.
 pub mod scryfall_data;
 /// Card search with comprehensive filtering.
 pub mod search_card;
+/// Owned vs total distinct cards of a set.
+pub mod set_completion;
+/// Set symbol icon URI and rarity color hints.
+pub mod set_icon;
 
 use card_profile::CardProfile;
 use scryfall_data::ScryfallData;
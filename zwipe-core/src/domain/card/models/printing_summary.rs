@@ -0,0 +1,54 @@
+//! Card detail payload: one focused printing plus its alternatives.
+//!
+//! The detail screen shows the full card the user tapped and a compact strip
+//! of other printings to switch to. Alternatives only carry what the strip
+//! renders, so a card with hundreds of printings stays a small response.
+
+use crate::domain::card::{
+    Card,
+    scryfall_data::{ScryfallData, image_uris::ImageSize},
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// The fields of a printing the alternatives strip needs.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PrintingSummary {
+    /// Scryfall id of the printing.
+    pub id: Uuid,
+    /// Set code, e.g. `mh2`.
+    pub set: String,
+    /// Full set name, e.g. `Modern Horizons 2`.
+    pub set_name: String,
+    /// Collector number within the set.
+    pub collector_number: String,
+    /// Nonfoil USD price, when Scryfall has one.
+    pub price_usd: Option<String>,
+    /// Small front-face image, when available.
+    pub image_uri: Option<String>,
+}
+
+impl From<&ScryfallData> for PrintingSummary {
+    fn from(value: &ScryfallData) -> Self {
+        Self {
+            id: value.id,
+            set: value.set.clone(),
+            set_name: value.set_name.clone(),
+            collector_number: value.collector_number.clone(),
+            price_usd: value.prices.usd.clone(),
+            image_uri: value
+                .primary_image_url(ImageSize::Small)
+                .map(str::to_string),
+        }
+    }
+}
+
+/// A card with the other printings of its oracle card.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CardWithPrintings {
+    /// The focused printing, in full.
+    pub card: Card,
+    /// Every other printing of the same oracle card, oldest release first.
+    /// Empty for cards without an oracle id or with a single printing.
+    pub printings: Vec<PrintingSummary>,
+}
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use thiserror::Error;
 
 /// Error returned when parsing an invalid color string.
@@ -7,6 +8,9 @@ use thiserror::Error;
 pub struct InvalidColor;
 
 /// Magic: The Gathering's five colors.
+///
+/// Variants are declared in WUBRG order, so the derived `Ord` sorts colors
+/// the way mana symbols are printed, matching [`Color::all`].
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Color {
@@ -42,7 +46,7 @@ impl Color {
         s.to_string()
     }
 
-    /// Returns all five colors in WUBRG order.
+    /// Returns all five colors in WUBRG order (the same order `Ord` gives).
     pub fn all() -> [Self; 5] {
         [Self::White, Self::Blue, Self::Black, Self::Red, Self::Green]
     }
@@ -117,7 +121,9 @@ impl Colors {
 
 /// Collection of card colors.
 ///
-/// Empty collection means colorless.
+/// Empty collection means colorless. Deserializing is lenient: an entry that
+/// isn't a color symbol is skipped with a warning instead of failing the
+/// whole card, so one malformed card can't abort a sync batch.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Colors(Vec<Color>);
 
@@ -138,6 +144,64 @@ impl Colors {
     pub fn to_long_names(&self) -> Vec<String> {
         self.0.iter().map(|c| c.to_long_name()).collect()
     }
+
+    /// Returns these colors in WUBRG order, each once.
+    pub fn sorted_wubrg(&self) -> Self {
+        Color::all()
+            .into_iter()
+            .filter(|c| self.contains(c))
+            .collect()
+    }
+
+    /// Returns the community name of this color combination: "Mono-Red",
+    /// guild names for two colors ("Azorius"), shard and wedge names for
+    /// three ("Bant", "Jeskai"), Nephilim names for four ("Yore-Tiller"),
+    /// "WUBRG" for all five and "Colorless" for none. Order and duplicates
+    /// don't matter.
+    pub fn identity_name(&self) -> Option<&'static str> {
+        let has = |c: Color| self.contains(&c);
+        let name = match (
+            has(Color::White),
+            has(Color::Blue),
+            has(Color::Black),
+            has(Color::Red),
+            has(Color::Green),
+        ) {
+            (false, false, false, false, false) => "Colorless",
+            (true, false, false, false, false) => "Mono-White",
+            (false, true, false, false, false) => "Mono-Blue",
+            (false, false, true, false, false) => "Mono-Black",
+            (false, false, false, true, false) => "Mono-Red",
+            (false, false, false, false, true) => "Mono-Green",
+            (true, true, false, false, false) => "Azorius",
+            (false, true, true, false, false) => "Dimir",
+            (false, false, true, true, false) => "Rakdos",
+            (false, false, false, true, true) => "Gruul",
+            (true, false, false, false, true) => "Selesnya",
+            (true, false, true, false, false) => "Orzhov",
+            (false, true, false, true, false) => "Izzet",
+            (false, false, true, false, true) => "Golgari",
+            (true, false, false, true, false) => "Boros",
+            (false, true, false, false, true) => "Simic",
+            (true, true, false, false, true) => "Bant",
+            (true, true, true, false, false) => "Esper",
+            (false, true, true, true, false) => "Grixis",
+            (false, false, true, true, true) => "Jund",
+            (true, false, false, true, true) => "Naya",
+            (true, false, true, false, true) => "Abzan",
+            (true, true, false, true, false) => "Jeskai",
+            (false, true, true, false, true) => "Sultai",
+            (true, false, true, true, false) => "Mardu",
+            (false, true, false, true, true) => "Temur",
+            (true, true, true, true, false) => "Yore-Tiller",
+            (true, true, true, false, true) => "Witch-Maw",
+            (true, true, false, true, true) => "Ink-Treader",
+            (true, false, true, true, true) => "Dune-Brood",
+            (false, true, true, true, true) => "Glint-Eye",
+            (true, true, true, true, true) => "WUBRG",
+        };
+        Some(name)
+    }
 }
 
 impl<'de> Deserialize<'de> for Colors {
@@ -145,7 +209,17 @@ impl<'de> Deserialize<'de> for Colors {
     where
         D: serde::Deserializer<'de>,
     {
-        Vec::<Color>::deserialize(deserializer).map(Colors)
+        let entries = Vec::<Value>::deserialize(deserializer)?;
+        Ok(entries
+            .into_iter()
+            .filter_map(|entry| {
+                let color = entry.as_str().and_then(|s| Color::try_from(s).ok());
+                if color.is_none() {
+                    tracing::warn!(%entry, "skipping unknown color symbol");
+                }
+                color
+            })
+            .collect())
     }
 }
 
@@ -172,3 +246,95 @@ impl FromIterator<Color> for Colors {
         Colors(iter.into_iter().collect())
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn colors(short_names: &str) -> Colors {
+        short_names
+            .chars()
+            .map(|c| Color::try_from(c.to_string()).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn ord_follows_wubrg() {
+        let mut shuffled = vec![
+            Color::Green,
+            Color::White,
+            Color::Red,
+            Color::Black,
+            Color::Blue,
+        ];
+        shuffled.sort();
+        assert_eq!(shuffled, Color::all());
+    }
+
+    #[test]
+    fn deserializing_skips_junk_entries() {
+        let parsed: Colors = serde_json::from_str(r#"["W", "X", 3, null, "g"]"#).unwrap();
+        assert_eq!(parsed, colors("WG"));
+        // a single color stays strict
+        assert!(serde_json::from_str::<Color>(r#""X""#).is_err());
+    }
+
+    #[test]
+    fn sorted_wubrg_orders_and_dedupes() {
+        assert_eq!(colors("GWUW").sorted_wubrg(), colors("WUG"));
+        assert_eq!(colors("").sorted_wubrg(), colors(""));
+    }
+
+    #[test]
+    fn every_combination_has_its_name() {
+        let expected = [
+            ("", "Colorless"),
+            ("W", "Mono-White"),
+            ("U", "Mono-Blue"),
+            ("B", "Mono-Black"),
+            ("R", "Mono-Red"),
+            ("G", "Mono-Green"),
+            ("WU", "Azorius"),
+            ("UB", "Dimir"),
+            ("BR", "Rakdos"),
+            ("RG", "Gruul"),
+            ("WG", "Selesnya"),
+            ("WB", "Orzhov"),
+            ("UR", "Izzet"),
+            ("BG", "Golgari"),
+            ("WR", "Boros"),
+            ("UG", "Simic"),
+            ("WUG", "Bant"),
+            ("WUB", "Esper"),
+            ("UBR", "Grixis"),
+            ("BRG", "Jund"),
+            ("WRG", "Naya"),
+            ("WBG", "Abzan"),
+            ("WUR", "Jeskai"),
+            ("UBG", "Sultai"),
+            ("WBR", "Mardu"),
+            ("URG", "Temur"),
+            ("WUBR", "Yore-Tiller"),
+            ("WUBG", "Witch-Maw"),
+            ("WURG", "Ink-Treader"),
+            ("WBRG", "Dune-Brood"),
+            ("UBRG", "Glint-Eye"),
+            ("WUBRG", "WUBRG"),
+        ];
+        assert_eq!(expected.len(), 32);
+        let combinations: HashSet<Vec<Color>> = expected
+            .iter()
+            .map(|(short, _)| colors(short).sorted_wubrg().to_vec())
+            .collect();
+        assert_eq!(combinations.len(), 32, "each combination listed once");
+
+        for (short, name) in expected {
+            assert_eq!(colors(short).identity_name(), Some(name), "{short}");
+            // order doesn't matter
+            let reversed: String = short.chars().rev().collect();
+            assert_eq!(colors(&reversed).identity_name(), Some(name), "{reversed}");
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Error returned when parsing an unknown finish name.
+#[derive(Debug, Clone, Error)]
+#[error("invalid finish")]
+pub struct InvalidFinish;
+
+/// A finish a printing exists in, as listed in Scryfall's `finishes` array.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Finish {
+    Nonfoil,
+    Foil,
+    Etched,
+}
+
+impl Finish {
+    /// Returns Scryfall's identifier (e.g., "nonfoil", "etched").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Nonfoil => "nonfoil",
+            Self::Foil => "foil",
+            Self::Etched => "etched",
+        }
+    }
+
+    /// Returns the display name (e.g., "Non-foil").
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Nonfoil => "Non-foil",
+            Self::Foil => "Foil",
+            Self::Etched => "Etched",
+        }
+    }
+}
+
+impl FromStr for Finish {
+    type Err = InvalidFinish;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "nonfoil" => Ok(Self::Nonfoil),
+            "foil" => Ok(Self::Foil),
+            "etched" => Ok(Self::Etched),
+            _ => Err(InvalidFinish),
+        }
+    }
+}
+
+impl std::fmt::Display for Finish {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
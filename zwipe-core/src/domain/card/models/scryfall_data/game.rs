@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Error returned when parsing an unknown game name.
+#[derive(Debug, Clone, Error)]
+#[error("invalid game")]
+pub struct InvalidGame;
+
+/// A game a printing is available in, as listed in Scryfall's `games` array.
+///
+/// Independent of the `digital` flag: a paper card can also be on Arena, so
+/// "available in paper" and "not digital" are different questions.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Game {
+    Paper,
+    Arena,
+    Mtgo,
+}
+
+impl Game {
+    /// Returns Scryfall's identifier (e.g., "paper", "mtgo").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Paper => "paper",
+            Self::Arena => "arena",
+            Self::Mtgo => "mtgo",
+        }
+    }
+
+    /// Returns the display name (e.g., "Arena", "MTGO").
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::Paper => "Paper",
+            Self::Arena => "Arena",
+            Self::Mtgo => "MTGO",
+        }
+    }
+}
+
+impl FromStr for Game {
+    type Err = InvalidGame;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "paper" => Ok(Self::Paper),
+            "arena" => Ok(Self::Arena),
+            "mtgo" => Ok(Self::Mtgo),
+            _ => Err(InvalidGame),
+        }
+    }
+}
+
+impl std::fmt::Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -47,4 +47,58 @@ impl ImageUris {
             ImageSize::Large => self.large.as_deref(),
         }
     }
+
+    /// Returns the highest-quality URL present: png, then large, normal,
+    /// small, and finally the art crop. `None` when no image is listed.
+    pub fn best_available(&self) -> Option<&str> {
+        [
+            &self.png,
+            &self.large,
+            &self.normal,
+            &self.small,
+            &self.art_crop,
+        ]
+        .into_iter()
+        .find_map(|url| url.as_deref())
+    }
+
+    /// Returns the thumbnail (small) URL if available.
+    pub fn thumbnail(&self) -> Option<&str> {
+        self.small.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn uris(png: Option<&str>, normal: Option<&str>, art_crop: Option<&str>) -> ImageUris {
+        ImageUris {
+            small: None,
+            normal: normal.map(String::from),
+            large: None,
+            png: png.map(String::from),
+            border_crop: None,
+            art_crop: art_crop.map(String::from),
+        }
+    }
+
+    #[test]
+    fn best_available_prefers_higher_quality() {
+        assert_eq!(
+            uris(Some("p"), Some("n"), Some("a")).best_available(),
+            Some("p")
+        );
+        assert_eq!(uris(None, Some("n"), Some("a")).best_available(), Some("n"));
+        assert_eq!(uris(None, None, Some("a")).best_available(), Some("a"));
+        assert_eq!(uris(None, None, None).best_available(), None);
+    }
+
+    #[test]
+    fn thumbnail_is_the_small_image() {
+        let mut image_uris = uris(Some("p"), None, None);
+        assert_eq!(image_uris.thumbnail(), None);
+        image_uris.small = Some("s".to_string());
+        assert_eq!(image_uris.thumbnail(), Some("s"));
+    }
 }
@@ -0,0 +1,187 @@
+use thiserror::Error;
+
+/// Error returned when parsing an unknown Scryfall language code.
+#[derive(Debug, Clone, Error)]
+#[error("invalid language")]
+pub struct InvalidLanguage;
+
+/// A language Scryfall prints cards in, keyed by its Scryfall code (`lang`).
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    French,
+    German,
+    Italian,
+    Portuguese,
+    Japanese,
+    Korean,
+    Russian,
+    SimplifiedChinese,
+    TraditionalChinese,
+    Hebrew,
+    Latin,
+    AncientGreek,
+    Arabic,
+    Sanskrit,
+    Phyrexian,
+    Quenya,
+}
+
+impl TryFrom<&str> for Language {
+    type Error = InvalidLanguage;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim().to_lowercase().as_str() {
+            "en" => Ok(Self::English),
+            "es" => Ok(Self::Spanish),
+            "fr" => Ok(Self::French),
+            "de" => Ok(Self::German),
+            "it" => Ok(Self::Italian),
+            "pt" => Ok(Self::Portuguese),
+            "ja" => Ok(Self::Japanese),
+            "ko" => Ok(Self::Korean),
+            "ru" => Ok(Self::Russian),
+            "zhs" => Ok(Self::SimplifiedChinese),
+            "zht" => Ok(Self::TraditionalChinese),
+            "he" => Ok(Self::Hebrew),
+            "la" => Ok(Self::Latin),
+            "grc" => Ok(Self::AncientGreek),
+            "ar" => Ok(Self::Arabic),
+            "sa" => Ok(Self::Sanskrit),
+            "ph" => Ok(Self::Phyrexian),
+            "qya" => Ok(Self::Quenya),
+            _ => Err(InvalidLanguage),
+        }
+    }
+}
+
+impl Language {
+    /// Returns Scryfall's language code (e.g., "en", "zhs").
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::English => "en",
+            Self::Spanish => "es",
+            Self::French => "fr",
+            Self::German => "de",
+            Self::Italian => "it",
+            Self::Portuguese => "pt",
+            Self::Japanese => "ja",
+            Self::Korean => "ko",
+            Self::Russian => "ru",
+            Self::SimplifiedChinese => "zhs",
+            Self::TraditionalChinese => "zht",
+            Self::Hebrew => "he",
+            Self::Latin => "la",
+            Self::AncientGreek => "grc",
+            Self::Arabic => "ar",
+            Self::Sanskrit => "sa",
+            Self::Phyrexian => "ph",
+            Self::Quenya => "qya",
+        }
+    }
+
+    /// Returns the full display name (e.g., "Japanese").
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Self::English => "English",
+            Self::Spanish => "Spanish",
+            Self::French => "French",
+            Self::German => "German",
+            Self::Italian => "Italian",
+            Self::Portuguese => "Portuguese",
+            Self::Japanese => "Japanese",
+            Self::Korean => "Korean",
+            Self::Russian => "Russian",
+            Self::SimplifiedChinese => "Simplified Chinese",
+            Self::TraditionalChinese => "Traditional Chinese",
+            Self::Hebrew => "Hebrew",
+            Self::Latin => "Latin",
+            Self::AncientGreek => "Ancient Greek",
+            Self::Arabic => "Arabic",
+            Self::Sanskrit => "Sanskrit",
+            Self::Phyrexian => "Phyrexian",
+            Self::Quenya => "Quenya",
+        }
+    }
+
+    /// Maps a BCP 47 language tag (e.g., "ja", "pt-BR", "zh-Hant-TW") to the
+    /// language Scryfall prints it as. Chinese resolves by script or region:
+    /// Traditional for `Hant`, TW, HK and MO, Simplified otherwise.
+    pub fn from_language_tag(tag: &str) -> Option<Self> {
+        let tag = tag.trim().to_lowercase();
+        let mut subtags = tag.split(['-', '_']);
+        let primary = subtags.next()?;
+        if primary == "zh" {
+            let traditional = subtags.any(|s| matches!(s, "hant" | "tw" | "hk" | "mo"));
+            return Some(if traditional {
+                Self::TraditionalChinese
+            } else {
+                Self::SimplifiedChinese
+            });
+        }
+        Self::try_from(primary).ok()
+    }
+
+    /// Picks the client's most preferred printed language from an
+    /// `Accept-Language` header value, honoring `q` weights (ties go to the
+    /// earlier entry). Falls back to English when nothing listed is
+    /// supported.
+    pub fn negotiate(accept_language: &str) -> Self {
+        let mut best: Option<(f32, Self)> = None;
+        for range in accept_language.split(',') {
+            let mut params = range.split(';');
+            let tag = params.next().unwrap_or_default();
+            let weight = params
+                .find_map(|p| p.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok());
+            let (Some(weight), Some(language)) = (weight, Self::from_language_tag(tag)) else {
+                continue;
+            };
+            if weight > 0.0 && best.is_none_or(|(w, _)| weight > w) {
+                best = Some((weight, language));
+            }
+        }
+        best.map_or(Self::English, |(_, language)| language)
+    }
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiates_the_highest_weighted_supported_language() {
+        assert_eq!(Language::negotiate("ja"), Language::Japanese);
+        assert_eq!(
+            Language::negotiate("xx, fr;q=0.8, de;q=0.9"),
+            Language::German
+        );
+        assert_eq!(Language::negotiate("pt-BR,pt;q=0.9"), Language::Portuguese);
+        assert_eq!(Language::negotiate("zh-TW"), Language::TraditionalChinese);
+        assert_eq!(Language::negotiate("zh-CN"), Language::SimplifiedChinese);
+    }
+
+    #[test]
+    fn unsupported_or_refused_languages_fall_back_to_english() {
+        assert_eq!(Language::negotiate("xx-YY"), Language::English);
+        assert_eq!(Language::negotiate("*"), Language::English);
+        assert_eq!(Language::negotiate("ja;q=0"), Language::English);
+        assert_eq!(Language::negotiate(""), Language::English);
+    }
+
+    #[test]
+    fn codes_round_trip() {
+        for code in ["en", "ja", "zhs", "zht", "grc", "qya"] {
+            assert_eq!(Language::try_from(code).map(|l| l.code()).ok(), Some(code));
+        }
+        assert!(Language::try_from("xx").is_err());
+    }
+}
@@ -1,10 +1,13 @@
 use crate::domain::deck::Format;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Card legality status across all Magic: The Gathering formats.
 ///
 /// Each field represents a different format's legality status for a card.
 /// `None` means legality information is not available for that format.
+/// Formats Scryfall adds after this build land in [`Legalities::other`]
+/// rather than failing or vanishing.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub struct Legalities {
     /// Standard format (most recent sets, ~2 years rotation).
@@ -53,6 +56,9 @@ pub struct Legalities {
     pub explorer: Option<LegalityKind>,
     /// Historic Brawl format (MTG Arena Brawl with Historic pool).
     pub historicbrawl: Option<LegalityKind>,
+    /// Any other format Scryfall lists, keyed by its legality key.
+    #[serde(flatten, default)]
+    pub other: BTreeMap<String, LegalityKind>,
 }
 
 impl Legalities {
@@ -84,6 +90,25 @@ impl Legalities {
             Format::HistoricBrawl => self.historicbrawl.as_ref(),
         }
     }
+
+    /// Whether the card can be played in `format`: legal or restricted. A
+    /// format missing from the object reads as not legal.
+    pub fn is_legal(&self, format: &Format) -> bool {
+        matches!(
+            self.get(format),
+            Some(LegalityKind::Legal | LegalityKind::Restricted)
+        )
+    }
+
+    /// Looks up legality by Scryfall legality key (`"commander"`,
+    /// `"paupercommander"`), falling back to [`Self::other`] for formats
+    /// without a [`Format`] variant.
+    pub fn get_by_key(&self, key: &str) -> Option<&LegalityKind> {
+        match Format::all().iter().find(|f| f.to_legality_key() == key) {
+            Some(format) => self.get(format),
+            None => self.other.get(key),
+        }
+    }
 }
 
 /// Card legality status within a format.
@@ -106,3 +131,71 @@ pub enum LegalityKind {
     /// Card is explicitly banned in this format.
     Banned,
 }
+
+impl LegalityKind {
+    /// Returns Scryfall's value for this status (`"legal"`, `"not_legal"`, …),
+    /// as stored in the `legalities` JSONB.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Legal => "legal",
+            Self::NotLegal => "not_legal",
+            Self::Restricted => "restricted",
+            Self::Banned => "banned",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sol Ring's legalities as Scryfall serves them, plus a format this
+    /// build doesn't know.
+    const SOL_RING: &str = r#"{
+        "standard": "not_legal", "future": "not_legal", "historic": "not_legal",
+        "timeless": "not_legal", "gladiator": "not_legal", "pioneer": "not_legal",
+        "modern": "not_legal", "legacy": "banned", "pauper": "not_legal",
+        "vintage": "restricted", "penny": "not_legal", "commander": "legal",
+        "oathbreaker": "banned", "standardbrawl": "not_legal", "brawl": "not_legal",
+        "alchemy": "not_legal", "paupercommander": "not_legal", "duel": "banned",
+        "oldschool": "not_legal", "premodern": "not_legal", "predh": "legal",
+        "newformat": "legal"
+    }"#;
+
+    #[test]
+    fn parses_known_formats_and_keeps_unknown_ones() {
+        let legalities: Legalities = serde_json::from_str(SOL_RING).unwrap();
+        assert_eq!(
+            legalities.get(&Format::Commander),
+            Some(&LegalityKind::Legal)
+        );
+        assert_eq!(legalities.get(&Format::Legacy), Some(&LegalityKind::Banned));
+        assert_eq!(
+            legalities.get(&Format::Vintage),
+            Some(&LegalityKind::Restricted)
+        );
+        assert_eq!(
+            legalities.get(&Format::Modern),
+            Some(&LegalityKind::NotLegal)
+        );
+        // Missing from this object entirely.
+        assert_eq!(legalities.get(&Format::Explorer), None);
+
+        assert_eq!(legalities.get_by_key("duel"), Some(&LegalityKind::Banned));
+        assert_eq!(
+            legalities.get_by_key("newformat"),
+            Some(&LegalityKind::Legal)
+        );
+        assert_eq!(legalities.get_by_key("nosuchformat"), None);
+
+        assert!(legalities.is_legal(&Format::Commander));
+        assert!(legalities.is_legal(&Format::Vintage));
+        assert!(!legalities.is_legal(&Format::Legacy));
+        assert!(!legalities.is_legal(&Format::Explorer));
+
+        // The unknown format survives a round trip (it's stored as JSONB).
+        let round_trip: Legalities =
+            serde_json::from_value(serde_json::to_value(&legalities).unwrap()).unwrap();
+        assert_eq!(round_trip, legalities);
+    }
+}
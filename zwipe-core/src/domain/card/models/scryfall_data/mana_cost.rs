@@ -0,0 +1,90 @@
+//! Mana cost symbol parsing.
+//!
+//! Scryfall writes costs as a run of braced symbols (`{2}{W}{U/P}`); split and
+//! double-faced cards join their halves with ` // `. Only the braced symbols
+//! carry meaning, so parsing keeps those and drops everything in between.
+
+use serde::{Deserialize, Serialize};
+
+/// A parsed mana cost: its symbols in printed order, each uppercased and
+/// still wrapped in braces (`{W/P}`, `{10}`, `{X}`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManaCost(Vec<String>);
+
+impl ManaCost {
+    /// Parses every `{...}` symbol out of `raw`. Text outside braces (the
+    /// ` // ` face separator, stray whitespace) and unterminated or empty
+    /// braces are ignored, so malformed input yields fewer symbols, not an error.
+    pub fn parse(raw: &str) -> Self {
+        let mut symbols = Vec::new();
+        let mut rest = raw;
+        while let Some((_, after)) = rest.split_once('{') {
+            let Some((inner, tail)) = after.split_once('}') else {
+                break;
+            };
+            // An unclosed `{` before this symbol: keep only the innermost open.
+            let inner = inner.rsplit('{').next().unwrap_or(inner).trim();
+            if !inner.is_empty() {
+                symbols.push(format!("{{{}}}", inner.to_uppercase()));
+            }
+            rest = tail;
+        }
+        Self(symbols)
+    }
+
+    /// The symbols in printed order.
+    pub fn symbols(&self) -> &[String] {
+        &self.0
+    }
+
+    /// True when no symbols were parsed.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Order-insensitive form for equality: symbols sorted bytewise and joined,
+    /// so `{W}{1}` and `{1}{W}` both become `{1}{W}`. The SQL adapter sorts
+    /// with `COLLATE "C"` to produce the same string server-side.
+    pub fn canonical(&self) -> String {
+        let mut sorted = self.0.clone();
+        sorted.sort();
+        sorted.concat()
+    }
+}
+
+impl std::fmt::Display for ManaCost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.concat())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_symbols_in_printed_order() {
+        let cost = ManaCost::parse("{2}{w}{U/P}");
+        assert_eq!(cost.symbols(), ["{2}", "{W}", "{U/P}"]);
+        assert_eq!(cost.to_string(), "{2}{W}{U/P}");
+    }
+
+    #[test]
+    fn skips_face_separator_and_malformed_braces() {
+        let cost = ManaCost::parse("{1}{R} // {}{G} {3");
+        assert_eq!(cost.symbols(), ["{1}", "{R}", "{G}"]);
+        assert!(ManaCost::parse("").is_empty());
+    }
+
+    #[test]
+    fn canonical_ignores_symbol_order() {
+        assert_eq!(
+            ManaCost::parse("{W}{1}{W}").canonical(),
+            ManaCost::parse("{1}{W}{W}").canonical()
+        );
+        assert_ne!(
+            ManaCost::parse("{1}{W}").canonical(),
+            ManaCost::parse("{1}{W}{W}").canonical()
+        );
+    }
+}
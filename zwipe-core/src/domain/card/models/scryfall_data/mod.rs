@@ -4,21 +4,32 @@ pub mod all_parts;
 pub mod card_faces;
 /// Color and color identity types and conversions.
 pub mod colors;
+/// Finishes a printing exists in (non-foil, foil, etched).
+pub mod finish;
+/// Games a printing is available in (paper, Arena, MTGO).
+pub mod game;
 /// Card image URIs at various resolutions.
 pub mod image_uris;
+/// Printed language codes and `Accept-Language` negotiation.
+pub mod language;
 /// Format legality status (Standard, Modern, Commander, etc.).
 pub mod legalities;
+/// Mana cost symbol parsing and order-insensitive comparison.
+pub mod mana_cost;
 /// Card pricing data from various sources.
 pub mod prices;
 /// Rarity types (Common, Uncommon, Rare, Mythic).
 pub mod rarity;
+/// Set type classification (core, expansion, masters, commander, etc.).
+pub mod set_type;
 
+use crate::domain::deck::Format;
 use all_parts::AllParts;
 use card_faces::CardFaces;
 use colors::Colors;
 pub use image_uris::ImageSize;
 use image_uris::ImageUris;
-use legalities::Legalities;
+use legalities::{Legalities, LegalityKind};
 use prices::Prices;
 use rarity::Rarity;
 use serde::{Deserialize, Serialize};
@@ -316,6 +327,15 @@ pub struct ScryfallData {
 }
 
 impl ScryfallData {
+    /// This card's legality in `format`. Scryfall lists every format it knows
+    /// for every card, so a format missing from the object reads as not legal.
+    pub fn legality(&self, format: &Format) -> LegalityKind {
+        self.legalities
+            .get(format)
+            .cloned()
+            .unwrap_or(LegalityKind::NotLegal)
+    }
+
     /// Returns `true` if this card is a basic land (e.g. Forest, Island, Mountain,
     /// or a snow basic like Snow-Covered Plains, whose type line is "Basic Snow Land — Plains").
     pub fn is_basic_land(&self) -> bool {
@@ -346,6 +366,40 @@ impl ScryfallData {
         self.face_image_url(0, size)
     }
 
+    /// Returns the best available image of the card's front: the top-level
+    /// `image_uris` when they list one, else those of `card_faces[0]` (for
+    /// double-faced layouts, which have no top-level images). See
+    /// [`ImageUris::best_available`] for the quality order.
+    pub fn front_image(&self) -> Option<&str> {
+        self.image_uris
+            .as_ref()
+            .and_then(ImageUris::best_available)
+            .or_else(|| {
+                self.card_faces
+                    .as_ref()
+                    .and_then(|faces| faces.first())
+                    .and_then(|face| face.image_uris.as_ref())
+                    .and_then(ImageUris::best_available)
+            })
+    }
+
+    /// Number of colors the card is, by its printed `colors` (not its color
+    /// identity: a mono-red card with a `{U}` activation counts 1). Cards
+    /// without top-level colors, like double-faced layouts, count their front
+    /// face's; a card with neither is colorless. The search filters count the
+    /// same way.
+    pub fn color_count(&self) -> usize {
+        match &self.colors {
+            Some(colors) => colors.len(),
+            None => self
+                .card_faces
+                .as_ref()
+                .and_then(|faces| faces.first())
+                .and_then(|face| face.colors.as_ref())
+                .map_or(0, Vec::len),
+        }
+    }
+
     /// Returns the URL for a specific face at the given size.
     ///
     /// `face_index == 0` returns the front face, falling back from top-level `image_uris`
@@ -397,8 +451,22 @@ where
 
 #[cfg(test)]
 mod tests {
+    use super::{Colors, Format, LegalityKind};
     use crate::test_utils::make_card;
 
+    #[test]
+    fn legality_reads_the_format_and_defaults_to_not_legal() {
+        let mut card = make_card("Mana Crypt").scryfall_data;
+        card.legalities = serde_json::from_str(
+            r#"{"commander":"banned","vintage":"restricted","legacy":"legal"}"#,
+        )
+        .unwrap();
+        assert_eq!(card.legality(&Format::Commander), LegalityKind::Banned);
+        assert_eq!(card.legality(&Format::Vintage), LegalityKind::Restricted);
+        assert_eq!(card.legality(&Format::Legacy), LegalityKind::Legal);
+        assert_eq!(card.legality(&Format::Standard), LegalityKind::NotLegal);
+    }
+
     fn is_basic_land(type_line: Option<&str>) -> bool {
         let mut card = make_card("test");
         card.scryfall_data.type_line = type_line.map(String::from);
@@ -433,4 +501,57 @@ mod tests {
         assert!(!is_basic_land(Some("Legendary Creature — God")));
         assert!(!is_basic_land(None));
     }
+
+    #[test]
+    fn front_image_falls_back_to_the_first_face() {
+        let mut card = make_card("Delver of Secrets").scryfall_data;
+        assert_eq!(card.front_image(), None);
+
+        card.card_faces = serde_json::from_value(serde_json::json!([
+            {
+                "name": "Delver of Secrets",
+                "mana_cost": "{U}",
+                "object": "card_face",
+                "image_uris": { "normal": "front-normal", "large": "front-large" }
+            },
+            {
+                "name": "Insectile Aberration",
+                "mana_cost": "",
+                "object": "card_face",
+                "image_uris": { "png": "back-png" }
+            }
+        ]))
+        .unwrap();
+        assert_eq!(card.front_image(), Some("front-large"));
+
+        card.image_uris =
+            serde_json::from_value(serde_json::json!({ "small": "top-small" })).unwrap();
+        assert_eq!(card.front_image(), Some("top-small"));
+    }
+
+    #[test]
+    fn color_count_reads_colors_then_the_front_face() {
+        let mut card = make_card("Delver of Secrets").scryfall_data;
+        assert_eq!(card.color_count(), 0);
+
+        card.card_faces = serde_json::from_value(serde_json::json!([
+            {
+                "name": "Delver of Secrets",
+                "mana_cost": "{U}",
+                "object": "card_face",
+                "colors": ["U"]
+            },
+            {
+                "name": "Insectile Aberration",
+                "mana_cost": "",
+                "object": "card_face",
+                "colors": ["U"]
+            }
+        ]))
+        .unwrap();
+        assert_eq!(card.color_count(), 1);
+
+        card.colors = Some(Colors::from_short_names(vec!["R".into(), "W".into()]).unwrap());
+        assert_eq!(card.color_count(), 2);
+    }
 }
@@ -1,10 +1,20 @@
 use serde::{Deserialize, Serialize};
+use std::{
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign},
+    str::FromStr,
+};
+use thiserror::Error;
 
 /// Current market prices for a card in various currencies and finishes.
 ///
 /// Prices are sourced from TCGplayer (USD), Cardmarket (EUR), and MTGO (TIX).
-/// All prices are stored as strings to preserve exact decimal precision.
-/// `None` means the card is not available in that finish or market.
+/// All prices are stored as strings to preserve exact decimal precision (and
+/// Scryfall's wire format); read them as numbers through the [`Price`]
+/// accessors ([`usd`](Prices::usd), [`eur`](Prices::eur), ...), which parse
+/// each one the same way. `None` means the card is not available in that
+/// finish or market.
 ///
 /// # Finishes
 /// - **Regular (nonfoil)**: Standard printing
@@ -32,3 +42,187 @@ pub struct Prices {
     /// MTGO Event Ticket price. `None` if not available on MTGO.
     pub tix: Option<String>,
 }
+
+impl Prices {
+    /// Nonfoil US Dollar price.
+    pub fn usd(&self) -> Option<Price> {
+        parse(&self.usd)
+    }
+
+    /// Foil US Dollar price.
+    pub fn usd_foil(&self) -> Option<Price> {
+        parse(&self.usd_foil)
+    }
+
+    /// Etched foil US Dollar price.
+    pub fn usd_etched(&self) -> Option<Price> {
+        parse(&self.usd_etched)
+    }
+
+    /// Nonfoil Euro price.
+    pub fn eur(&self) -> Option<Price> {
+        parse(&self.eur)
+    }
+
+    /// Foil Euro price.
+    pub fn eur_foil(&self) -> Option<Price> {
+        parse(&self.eur_foil)
+    }
+
+    /// Etched foil Euro price.
+    pub fn eur_etched(&self) -> Option<Price> {
+        parse(&self.eur_etched)
+    }
+
+    /// MTGO Event Ticket price.
+    pub fn tix(&self) -> Option<Price> {
+        parse(&self.tix)
+    }
+
+    /// Cheapest way to buy the paper card in US Dollars: the lowest of the
+    /// nonfoil, foil and etched prices. `None` when none is listed.
+    pub fn cheapest_paper(&self) -> Option<Price> {
+        [self.usd(), self.usd_foil(), self.usd_etched()]
+            .into_iter()
+            .flatten()
+            .min()
+    }
+}
+
+/// Reads a stored price, treating an unparseable one as absent.
+fn parse(raw: &Option<String>) -> Option<Price> {
+    raw.as_deref().and_then(|s| s.parse().ok())
+}
+
+/// A price string that isn't a non-negative amount with at most two decimals.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("invalid price: {0:?}")]
+pub struct InvalidPrice(String);
+
+/// An exact, non-negative amount of a currency, to the hundredth.
+///
+/// Held as whole hundredths (cents, for USD/EUR), so sums such as a deck's
+/// budget total add up exactly; convert with [`to_f64`](Price::to_f64) only
+/// for display or ratios. Parses Scryfall's price strings (`"1.50"`,
+/// `"12"`, `"0.03"`) and displays with two decimals.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Price(u64);
+
+impl Price {
+    /// The zero price.
+    pub const ZERO: Self = Self(0);
+
+    /// A price of `hundredths` hundredths of the currency (`150` is 1.50).
+    pub fn from_hundredths(hundredths: u64) -> Self {
+        Self(hundredths)
+    }
+
+    /// The price in hundredths of the currency.
+    pub fn hundredths(&self) -> u64 {
+        self.0
+    }
+
+    /// The price as a float, for display and ratios.
+    pub fn to_f64(&self) -> f64 {
+        self.0 as f64 / 100.0
+    }
+
+    /// This price `quantity` times over (e.g. for several copies).
+    pub fn times(self, quantity: u64) -> Self {
+        Self(self.0.saturating_mul(quantity))
+    }
+}
+
+impl FromStr for Price {
+    type Err = InvalidPrice;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidPrice(s.to_string());
+        let (whole, fraction) = s.trim().split_once('.').unwrap_or_else(|| (s.trim(), ""));
+        let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if whole.is_empty() || fraction.len() > 2 || !all_digits(whole) || !all_digits(fraction) {
+            return Err(invalid());
+        }
+        let whole: u64 = whole.parse().map_err(|_| invalid())?;
+        // "1.5" is 1.50: pad the fraction to two digits
+        let fraction: u64 = format!("{fraction:0<2}").parse().map_err(|_| invalid())?;
+        whole
+            .checked_mul(100)
+            .and_then(|h| h.checked_add(fraction))
+            .map(Self)
+            .ok_or_else(invalid)
+    }
+}
+
+impl fmt::Display for Price {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{:02}", self.0 / 100, self.0 % 100)
+    }
+}
+
+impl Add for Price {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl AddAssign for Price {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sum for Price {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn prices() -> Prices {
+        Prices {
+            usd: Some("1.50".to_string()),
+            usd_foil: Some("0.99".to_string()),
+            usd_etched: None,
+            eur: Some("2".to_string()),
+            eur_foil: None,
+            eur_etched: None,
+            tix: Some("not a price".to_string()),
+        }
+    }
+
+    #[test]
+    fn parses_scryfall_price_strings() {
+        assert_eq!("1.50".parse(), Ok(Price::from_hundredths(150)));
+        assert_eq!("12".parse(), Ok(Price::from_hundredths(1200)));
+        assert_eq!("0.5".parse(), Ok(Price::from_hundredths(50)));
+        assert_eq!("0.03".parse(), Ok(Price::from_hundredths(3)));
+        for bad in ["", ".50", "-1.00", "1.005", "1,50", "abc", "1.2.3"] {
+            assert!(bad.parse::<Price>().is_err(), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn accessors_parse_and_skip_garbage() {
+        let prices = prices();
+        assert_eq!(prices.usd(), Some(Price::from_hundredths(150)));
+        assert_eq!(prices.eur(), Some(Price::from_hundredths(200)));
+        assert_eq!(prices.usd_etched(), None);
+        assert_eq!(prices.tix(), None);
+        assert_eq!(prices.cheapest_paper(), Some(Price::from_hundredths(99)));
+    }
+
+    #[test]
+    fn sums_exactly_and_displays_two_decimals() {
+        // 0.10 three times is 0.30 exactly, unlike in floating point
+        let total: Price = std::iter::repeat_n(Price::from_hundredths(10), 3).sum();
+        assert_eq!(total, Price::from_hundredths(30));
+        assert_eq!(total.to_string(), "0.30");
+        assert_eq!(Price::from_hundredths(150).times(4).to_string(), "6.00");
+    }
+}
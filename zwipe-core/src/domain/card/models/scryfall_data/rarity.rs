@@ -68,6 +68,18 @@ impl Rarity {
         }
     }
 
+    /// Returns the conventional set-symbol fill for this rarity as a hex color
+    /// (black, silver, gold, orange-red; bonus/special share the purple).
+    pub fn set_symbol_color(&self) -> &'static str {
+        match self {
+            Self::Common => "#1a1718",
+            Self::Uncommon => "#707883",
+            Self::Rare => "#a58e4a",
+            Self::Mythic => "#bf4427",
+            Self::Bonus | Self::Special => "#652978",
+        }
+    }
+
     /// Returns all rarity variants.
     pub fn all() -> Vec<Self> {
         vec![
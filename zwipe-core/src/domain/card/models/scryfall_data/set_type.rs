@@ -0,0 +1,145 @@
+use serde::{Deserialize, Serialize};
+use std::{convert::Infallible, str::FromStr};
+
+/// Scryfall's classification of the set a printing belongs to.
+///
+/// Scryfall adds set types over time, so parsing never fails: a value this
+/// enum doesn't know yet is kept verbatim in [`SetType::Other`] and written
+/// back out unchanged.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SetType {
+    Core,
+    Expansion,
+    Masters,
+    Alchemy,
+    Masterpiece,
+    Arsenal,
+    FromTheVault,
+    Spellbook,
+    PremiumDeck,
+    DuelDeck,
+    DraftInnovation,
+    TreasureChest,
+    Commander,
+    Planechase,
+    Archenemy,
+    Vanguard,
+    Funny,
+    Starter,
+    Box,
+    Promo,
+    Token,
+    Memorabilia,
+    Minigame,
+    /// A set type not (yet) known to this enum, as Scryfall spells it.
+    Other(String),
+}
+
+impl SetType {
+    /// Returns Scryfall's identifier (e.g., "expansion", "from_the_vault").
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Core => "core",
+            Self::Expansion => "expansion",
+            Self::Masters => "masters",
+            Self::Alchemy => "alchemy",
+            Self::Masterpiece => "masterpiece",
+            Self::Arsenal => "arsenal",
+            Self::FromTheVault => "from_the_vault",
+            Self::Spellbook => "spellbook",
+            Self::PremiumDeck => "premium_deck",
+            Self::DuelDeck => "duel_deck",
+            Self::DraftInnovation => "draft_innovation",
+            Self::TreasureChest => "treasure_chest",
+            Self::Commander => "commander",
+            Self::Planechase => "planechase",
+            Self::Archenemy => "archenemy",
+            Self::Vanguard => "vanguard",
+            Self::Funny => "funny",
+            Self::Starter => "starter",
+            Self::Box => "box",
+            Self::Promo => "promo",
+            Self::Token => "token",
+            Self::Memorabilia => "memorabilia",
+            Self::Minigame => "minigame",
+            Self::Other(other) => other,
+        }
+    }
+}
+
+impl FromStr for SetType {
+    type Err = Infallible;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim().to_lowercase().as_str() {
+            "core" => Self::Core,
+            "expansion" => Self::Expansion,
+            "masters" => Self::Masters,
+            "alchemy" => Self::Alchemy,
+            "masterpiece" => Self::Masterpiece,
+            "arsenal" => Self::Arsenal,
+            "from_the_vault" => Self::FromTheVault,
+            "spellbook" => Self::Spellbook,
+            "premium_deck" => Self::PremiumDeck,
+            "duel_deck" => Self::DuelDeck,
+            "draft_innovation" => Self::DraftInnovation,
+            "treasure_chest" => Self::TreasureChest,
+            "commander" => Self::Commander,
+            "planechase" => Self::Planechase,
+            "archenemy" => Self::Archenemy,
+            "vanguard" => Self::Vanguard,
+            "funny" => Self::Funny,
+            "starter" => Self::Starter,
+            "box" => Self::Box,
+            "promo" => Self::Promo,
+            "token" => Self::Token,
+            "memorabilia" => Self::Memorabilia,
+            "minigame" => Self::Minigame,
+            other => Self::Other(other.to_string()),
+        })
+    }
+}
+
+impl std::fmt::Display for SetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for SetType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.as_str().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SetType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        let Ok(set_type) = s.parse();
+        Ok(set_type)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_set_types_round_trip_instead_of_failing() {
+        let known: SetType = serde_json::from_str(r#""from_the_vault""#).unwrap();
+        assert_eq!(known, SetType::FromTheVault);
+        let unknown: SetType = serde_json::from_str(r#""eternal_edition""#).unwrap();
+        assert_eq!(unknown, SetType::Other("eternal_edition".to_string()));
+        assert_eq!(
+            serde_json::to_string(&unknown).unwrap(),
+            r#""eternal_edition""#
+        );
+    }
+}
@@ -5,14 +5,20 @@
 
 use crate::domain::{
     card::{
-        scryfall_data::{colors::Colors, rarity::Rarities},
+        scryfall_data::{
+            colors::Colors, finish::Finish, game::Game, legalities::LegalityKind, rarity::Rarities,
+            set_type::SetType,
+        },
         search_card::{
-            card_filter::{CardSortKey, builder::CardQueryBuilder, price_currency::PriceCurrency},
+            card_filter::{
+                CardSortKey, Cursor, builder::CardQueryBuilder, price_currency::PriceCurrency,
+            },
             card_type::CardType,
         },
     },
     deck::Format,
 };
+use chrono::NaiveDate;
 
 impl CardQueryBuilder {
     // =================================
@@ -24,6 +30,16 @@ impl CardQueryBuilder {
         self.name_contains.as_deref()
     }
 
+    /// Returns the exact name filter value.
+    pub fn name_equals(&self) -> Option<&str> {
+        self.name_equals.as_deref()
+    }
+
+    /// Returns the everything-search filter value.
+    pub fn text_search(&self) -> Option<&str> {
+        self.text_search.as_deref()
+    }
+
     /// Returns the oracle text filter value.
     pub fn oracle_text_contains(&self) -> Option<&str> {
         self.oracle_text_contains.as_deref()
@@ -101,11 +117,46 @@ impl CardQueryBuilder {
         self.set_equals_any.as_deref()
     }
 
+    /// Returns the set type filter value.
+    pub fn set_type_equals_any(&self) -> Option<&[SetType]> {
+        self.set_type_equals_any.as_deref()
+    }
+
     /// Returns the artist filter value.
     pub fn artist_equals_any(&self) -> Option<&[String]> {
         self.artist_equals_any.as_deref()
     }
 
+    /// Returns the frame effects filter value.
+    pub fn frame_effects_contains_any(&self) -> Option<&[String]> {
+        self.frame_effects_contains_any.as_deref()
+    }
+
+    /// Returns the border color filter value.
+    pub fn border_color_equals_any(&self) -> Option<&[String]> {
+        self.border_color_equals_any.as_deref()
+    }
+
+    /// Returns the watermark filter value.
+    pub fn watermark_equals_any(&self) -> Option<&[String]> {
+        self.watermark_equals_any.as_deref()
+    }
+
+    /// Returns the game availability filter value.
+    pub fn available_in_game(&self) -> Option<Game> {
+        self.available_in_game
+    }
+
+    /// Returns the finish filter value.
+    pub fn finish_available(&self) -> Option<Finish> {
+        self.finish_available
+    }
+
+    /// Returns the promo types filter value.
+    pub fn promo_types_contains_any(&self) -> Option<&[String]> {
+        self.promo_types_contains_any.as_deref()
+    }
+
     /// Returns the rarity filter value.
     pub fn rarity_equals_any(&self) -> Option<&Rarities> {
         self.rarity_equals_any.as_ref()
@@ -125,6 +176,16 @@ impl CardQueryBuilder {
         self.cmc_range
     }
 
+    /// Returns the mana cost symbol-contains filter value.
+    pub fn mana_cost_contains(&self) -> Option<&str> {
+        self.mana_cost_contains.as_deref()
+    }
+
+    /// Returns the exact mana cost filter value.
+    pub fn mana_cost_equals(&self) -> Option<&str> {
+        self.mana_cost_equals.as_deref()
+    }
+
     /// Returns the minimum price bound.
     pub fn price_min(&self) -> Option<f64> {
         self.price_min
@@ -140,11 +201,46 @@ impl CardQueryBuilder {
         self.price_currency
     }
 
+    /// Returns the EDHREC rank range filter value.
+    pub fn edhrec_rank_range(&self) -> Option<(i32, i32)> {
+        self.edhrec_rank_range
+    }
+
+    /// Returns the earliest release date filter value.
+    pub fn released_after(&self) -> Option<NaiveDate> {
+        self.released_after
+    }
+
+    /// Returns the latest release date filter value.
+    pub fn released_before(&self) -> Option<NaiveDate> {
+        self.released_before
+    }
+
     /// Returns the color_identity_equals filter value.
     pub fn color_identity_equals(&self) -> Option<&Colors> {
         self.color_identity_equals.as_ref()
     }
 
+    /// Returns the color_count_equals filter value.
+    pub fn color_count_equals(&self) -> Option<u8> {
+        self.color_count_equals
+    }
+
+    /// Returns the color_count_range filter value.
+    pub fn color_count_range(&self) -> Option<(u8, u8)> {
+        self.color_count_range
+    }
+
+    /// Returns the is_multicolor filter value.
+    pub fn is_multicolor(&self) -> Option<bool> {
+        self.is_multicolor
+    }
+
+    /// Returns the is_colorless filter value.
+    pub fn is_colorless(&self) -> Option<bool> {
+        self.is_colorless
+    }
+
     /// Returns the color_identity_within filter value.
     pub fn color_identity_within(&self) -> Option<&Colors> {
         self.color_identity_within.as_ref()
@@ -188,6 +284,46 @@ impl CardQueryBuilder {
         self.toughness_range
     }
 
+    /// Returns the power_is_variable filter value.
+    pub fn power_is_variable(&self) -> Option<bool> {
+        self.power_is_variable
+    }
+
+    /// Returns the toughness_is_variable filter value.
+    pub fn toughness_is_variable(&self) -> Option<bool> {
+        self.toughness_is_variable
+    }
+
+    /// Returns the value `*` counts as in power/toughness ranges.
+    pub fn variable_stat_floor(&self) -> Option<i32> {
+        self.variable_stat_floor
+    }
+
+    /// Returns the loyalty exact match filter value.
+    pub fn loyalty_equals(&self) -> Option<i32> {
+        self.loyalty_equals
+    }
+
+    /// Returns the loyalty range filter value.
+    pub fn loyalty_range(&self) -> Option<(i32, i32)> {
+        self.loyalty_range
+    }
+
+    /// Returns the loyalty_is_variable filter value.
+    pub fn loyalty_is_variable(&self) -> Option<bool> {
+        self.loyalty_is_variable
+    }
+
+    /// Returns the defense exact match filter value.
+    pub fn defense_equals(&self) -> Option<i32> {
+        self.defense_equals
+    }
+
+    /// Returns the defense range filter value.
+    pub fn defense_range(&self) -> Option<(i32, i32)> {
+        self.defense_range
+    }
+
     // =================================
     // Card Flag Getters
     // =================================
@@ -222,6 +358,21 @@ impl CardQueryBuilder {
         self.content_warning
     }
 
+    /// Returns the game_changer filter value.
+    pub fn game_changer(&self) -> Option<bool> {
+        self.game_changer
+    }
+
+    /// Returns the story_spotlight filter value.
+    pub fn story_spotlight(&self) -> Option<bool> {
+        self.story_spotlight
+    }
+
+    /// Returns the textless filter value.
+    pub fn textless(&self) -> Option<bool> {
+        self.textless
+    }
+
     /// Returns the language filter value.
     pub fn language(&self) -> Option<&str> {
         self.language.as_deref()
@@ -236,6 +387,11 @@ impl CardQueryBuilder {
         self.legalities_contains_any.as_deref()
     }
 
+    /// Returns the per-format legality status requirements.
+    pub fn legal_in(&self) -> Option<&[(Format, LegalityKind)]> {
+        self.legal_in.as_deref()
+    }
+
     // =================================
     // Commander Filter Getters
     // =================================
@@ -364,11 +520,21 @@ impl CardQueryBuilder {
         self.offset
     }
 
+    /// Returns the cursor the search resumes after.
+    pub fn after_cursor(&self) -> Option<&Cursor> {
+        self.after_cursor.as_ref()
+    }
+
     /// Returns the sort value.
     pub fn sort(&self) -> Option<CardSortKey> {
         self.sort
     }
 
+    /// Returns the secondary sort key.
+    pub fn then_sort(&self) -> Option<CardSortKey> {
+        self.then_sort
+    }
+
     /// Returns the ascending sort direction flag.
     pub fn ascending(&self) -> bool {
         self.ascending
@@ -11,15 +11,21 @@ use crate::domain::{
     card::{
         scryfall_data::{
             colors::{Color, Colors},
+            finish::Finish,
+            game::Game,
+            legalities::LegalityKind,
+            mana_cost::ManaCost,
             rarity::Rarities,
+            set_type::SetType,
         },
         search_card::{
             card_filter::{
                 CardSortKey,
                 criteria::CardCriteria,
+                cursor::Cursor,
                 error::InvalidCardCriteria,
                 price_currency::PriceCurrency,
-                query::{CardQuery, Limit},
+                query::{CardQuery, Limit, check_cursor},
                 strip_punctuation,
             },
             card_type::CardType,
@@ -27,6 +33,7 @@ use crate::domain::{
     },
     deck::Format,
 };
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
@@ -89,7 +96,7 @@ fn check_contains_not_contains_clash(
 ///
 /// # Filter Categories
 ///
-/// - **Combat**: Power/toughness (exact or range)
+/// - **Combat**: Power/toughness (exact, range, or variable `*`), loyalty, defense
 /// - **Mana**: CMC, color identity (within/equals)
 /// - **Text**: Name, oracle text, flavor text, type line
 /// - **Metadata**: Rarity, set, artist, language
@@ -135,15 +142,36 @@ pub struct CardQueryBuilder {
     power_range: Option<(i32, i32)>,
     toughness_equals: Option<i32>,
     toughness_range: Option<(i32, i32)>,
+    power_is_variable: Option<bool>,
+    toughness_is_variable: Option<bool>,
+    variable_stat_floor: Option<i32>,
+    loyalty_equals: Option<i32>,
+    loyalty_range: Option<(i32, i32)>,
+    loyalty_is_variable: Option<bool>,
+    defense_equals: Option<i32>,
+    defense_range: Option<(i32, i32)>,
     // mana
     cmc_equals: Option<f64>,
     cmc_range: Option<(f64, f64)>,
     color_identity_within: Option<Colors>,
     color_identity_equals: Option<Colors>,
-    // price (min/max against the selected currency's price)
+    // color count (printed colors, see ScryfallData::color_count)
+    color_count_equals: Option<u8>,
+    color_count_range: Option<(u8, u8)>,
+    is_multicolor: Option<bool>,
+    is_colorless: Option<bool>,
+    mana_cost_contains: Option<String>,
+    mana_cost_equals: Option<String>,
+    // price (min/max against the selected currency's price on this printing,
+    // not the card's cheapest printing; unpriced cards never match)
     price_min: Option<f64>,
     price_max: Option<f64>,
     price_currency: Option<PriceCurrency>,
+    // popularity (EDHREC rank; lower = more popular)
+    edhrec_rank_range: Option<(i32, i32)>,
+    // release date (inclusive bounds)
+    released_after: Option<NaiveDate>,
+    released_before: Option<NaiveDate>,
     // produced mana
     produced_mana_contains_any: Option<Vec<String>>,
     produced_mana_contains_all: Option<Vec<String>>,
@@ -154,11 +182,24 @@ pub struct CardQueryBuilder {
     // set
     set_equals_any: Option<Vec<String>>,
     set_excludes_any: Option<Vec<String>>,
+    // set type
+    set_type_equals_any: Option<Vec<SetType>>,
     // artist
     artist_equals_any: Option<Vec<String>>,
     artist_excludes_any: Option<Vec<String>>,
+    // frame/border/watermark
+    frame_effects_contains_any: Option<Vec<String>>,
+    border_color_equals_any: Option<Vec<String>>,
+    watermark_equals_any: Option<Vec<String>>,
+    // availability
+    available_in_game: Option<Game>,
+    finish_available: Option<Finish>,
+    // promo types
+    promo_types_contains_any: Option<Vec<String>>,
     // text
     name_contains: Option<String>,
+    name_equals: Option<String>,
+    text_search: Option<String>,
     name_not_contains: Option<String>,
     oracle_text_contains: Option<String>,
     oracle_text_not_contains: Option<String>,
@@ -188,9 +229,13 @@ pub struct CardQueryBuilder {
     oversized: Option<bool>,
     promo: Option<bool>,
     content_warning: Option<bool>,
+    game_changer: Option<bool>,
+    story_spotlight: Option<bool>,
+    textless: Option<bool>,
     language: Option<String>,
     // legalities
     legalities_contains_any: Option<Vec<String>>,
+    legal_in: Option<Vec<(Format, LegalityKind)>>,
     // commander
     is_commander_in_format: Option<Format>,
     // partner/background/spell
@@ -208,7 +253,9 @@ pub struct CardQueryBuilder {
     // config
     limit: u32,
     offset: u32,
+    after_cursor: Option<Cursor>,
     sort: Option<CardSortKey>,
+    then_sort: Option<CardSortKey>,
     ascending: bool,
     synergy: bool,
 }
@@ -220,13 +267,30 @@ impl Default for CardQueryBuilder {
             power_range: None,
             toughness_equals: None,
             toughness_range: None,
+            power_is_variable: None,
+            toughness_is_variable: None,
+            variable_stat_floor: None,
+            loyalty_equals: None,
+            loyalty_range: None,
+            loyalty_is_variable: None,
+            defense_equals: None,
+            defense_range: None,
             cmc_equals: None,
             cmc_range: None,
             color_identity_within: None,
             color_identity_equals: None,
+            color_count_equals: None,
+            color_count_range: None,
+            is_multicolor: None,
+            is_colorless: None,
+            mana_cost_contains: None,
+            mana_cost_equals: None,
             price_min: None,
             price_max: None,
             price_currency: None,
+            edhrec_rank_range: None,
+            released_after: None,
+            released_before: None,
             produced_mana_contains_any: None,
             produced_mana_contains_all: None,
             produced_mana_excludes: None,
@@ -234,9 +298,18 @@ impl Default for CardQueryBuilder {
             rarity_excludes_any: None,
             set_equals_any: None,
             set_excludes_any: None,
+            set_type_equals_any: None,
             artist_equals_any: None,
             artist_excludes_any: None,
+            frame_effects_contains_any: None,
+            border_color_equals_any: None,
+            watermark_equals_any: None,
+            available_in_game: None,
+            finish_available: None,
+            promo_types_contains_any: None,
             name_contains: None,
+            name_equals: None,
+            text_search: None,
             name_not_contains: None,
             oracle_text_contains: None,
             oracle_text_not_contains: None,
@@ -263,8 +336,12 @@ impl Default for CardQueryBuilder {
             oversized: Some(false),
             promo: None,
             content_warning: Some(false),
+            game_changer: None,
+            story_spotlight: None,
+            textless: None,
             language: Some("en".to_string()),
             legalities_contains_any: None,
+            legal_in: None,
             is_commander_in_format: None,
             is_partner: None,
             is_background: None,
@@ -277,7 +354,9 @@ impl Default for CardQueryBuilder {
             oracle_tags_excludes: None,
             limit: 25,
             offset: 0,
+            after_cursor: None,
             sort: None,
+            then_sort: None,
             ascending: true,
             synergy: false,
         }
@@ -365,6 +444,34 @@ impl CardQueryBuilder {
         }
     }
 
+    /// Creates builder with exact name filter (case-insensitive, whole name).
+    pub fn with_name_equals(name_equals: impl Into<String>) -> CardQueryBuilder {
+        let s = name_equals.into();
+        CardQueryBuilder {
+            name_equals: if s.is_empty() { None } else { Some(s) },
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder searching name, type line, oracle and flavor text at
+    /// once (any field may match).
+    pub fn with_text_search(text_search: impl Into<String>) -> CardQueryBuilder {
+        let s = text_search.into();
+        CardQueryBuilder {
+            text_search: if s.is_empty() { None } else { Some(s) },
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder excluding names containing `name_not_contains`.
+    pub fn with_name_not_contains(name_not_contains: impl Into<String>) -> CardQueryBuilder {
+        let s = name_not_contains.into();
+        CardQueryBuilder {
+            name_not_contains: if s.is_empty() { None } else { Some(s) },
+            ..CardQueryBuilder::default()
+        }
+    }
+
     /// Creates builder matching any of the provided oracle text substrings (keyword abilities).
     pub fn with_oracle_text_contains_any<I, S>(oracle_text_contains_any: I) -> CardQueryBuilder
     where
@@ -442,6 +549,15 @@ impl CardQueryBuilder {
         }
     }
 
+    /// Creates builder matching cards with the given legality status in
+    /// `format` (e.g. banned in Modern).
+    pub fn with_legal_in(format: Format, status: LegalityKind) -> CardQueryBuilder {
+        CardQueryBuilder {
+            legal_in: Some(vec![(format, status)]),
+            ..CardQueryBuilder::default()
+        }
+    }
+
     /// Creates builder with oracle text filter (ability text substring match).
     pub fn with_oracle_text_contains(oracle_text_contains: impl Into<String>) -> CardQueryBuilder {
         let s = oracle_text_contains.into();
@@ -539,6 +655,96 @@ impl CardQueryBuilder {
         }
     }
 
+    /// Creates builder matching any of the provided set types (e.g., only
+    /// expansions, leaving out Secret Lair drops and promos).
+    pub fn with_set_type_equals_any(
+        set_type_equals_any: impl IntoIterator<Item = SetType>,
+    ) -> CardQueryBuilder {
+        let set_type_equals_any: Vec<SetType> = set_type_equals_any.into_iter().collect();
+        CardQueryBuilder {
+            set_type_equals_any: (!set_type_equals_any.is_empty()).then_some(set_type_equals_any),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder matching printings with any of the provided frame
+    /// effects (e.g., "showcase", "extendedart"). Standard frames never match.
+    pub fn with_frame_effects_contains_any(
+        frame_effects_contains_any: impl IntoIterator<Item = impl Into<String>>,
+    ) -> CardQueryBuilder {
+        CardQueryBuilder {
+            frame_effects_contains_any: Some(
+                frame_effects_contains_any
+                    .into_iter()
+                    .map(|x| x.into())
+                    .collect(),
+            ),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder matching printings with any of the provided border
+    /// colors (e.g., `["borderless"]`).
+    pub fn with_border_color_equals_any(
+        border_color_equals_any: impl IntoIterator<Item = impl Into<String>>,
+    ) -> CardQueryBuilder {
+        CardQueryBuilder {
+            border_color_equals_any: Some(
+                border_color_equals_any
+                    .into_iter()
+                    .map(|x| x.into())
+                    .collect(),
+            ),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder matching printings with any of the provided watermarks
+    /// (e.g., "azorius", "boros"). Printings without a watermark never match.
+    pub fn with_watermark_equals_any(
+        watermark_equals_any: impl IntoIterator<Item = impl Into<String>>,
+    ) -> CardQueryBuilder {
+        CardQueryBuilder {
+            watermark_equals_any: Some(
+                watermark_equals_any.into_iter().map(|x| x.into()).collect(),
+            ),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder matching printings available in `game` (e.g., paper
+    /// only, for building a physical deck).
+    pub fn with_available_in_game(available_in_game: Game) -> CardQueryBuilder {
+        CardQueryBuilder {
+            available_in_game: Some(available_in_game),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder matching printings that exist in `finish` (e.g., foil).
+    pub fn with_finish_available(finish_available: Finish) -> CardQueryBuilder {
+        CardQueryBuilder {
+            finish_available: Some(finish_available),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder matching printings with any of the provided promo types
+    /// (e.g., "prerelease", "bundle"). Printings without promo types never match.
+    pub fn with_promo_types_contains_any(
+        promo_types_contains_any: impl IntoIterator<Item = impl Into<String>>,
+    ) -> CardQueryBuilder {
+        CardQueryBuilder {
+            promo_types_contains_any: Some(
+                promo_types_contains_any
+                    .into_iter()
+                    .map(|x| x.into())
+                    .collect(),
+            ),
+            ..CardQueryBuilder::default()
+        }
+    }
+
     /// Creates builder matching any of the provided rarities.
     pub fn with_rarity_equals_any(rarity_equals_any: Rarities) -> CardQueryBuilder {
         let rarity_equals_any = if rarity_equals_any.is_empty() {
@@ -662,6 +868,118 @@ impl CardQueryBuilder {
         }
     }
 
+    /// Creates builder filtering by whether power contains `*`.
+    pub fn with_power_is_variable(power_is_variable: bool) -> CardQueryBuilder {
+        CardQueryBuilder {
+            power_is_variable: Some(power_is_variable),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder filtering by whether toughness contains `*`.
+    pub fn with_toughness_is_variable(toughness_is_variable: bool) -> CardQueryBuilder {
+        CardQueryBuilder {
+            toughness_is_variable: Some(toughness_is_variable),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder with exact starting loyalty filter.
+    pub fn with_loyalty_equals(loyalty_equals: i32) -> CardQueryBuilder {
+        CardQueryBuilder {
+            loyalty_equals: Some(loyalty_equals),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder with starting loyalty range filter (inclusive).
+    pub fn with_loyalty_range(loyalty_range: (i32, i32)) -> CardQueryBuilder {
+        CardQueryBuilder {
+            loyalty_range: Some(loyalty_range),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder with exact battle defense filter.
+    pub fn with_defense_equals(defense_equals: i32) -> CardQueryBuilder {
+        CardQueryBuilder {
+            defense_equals: Some(defense_equals),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder with battle defense range filter (inclusive).
+    pub fn with_defense_range(defense_range: (i32, i32)) -> CardQueryBuilder {
+        CardQueryBuilder {
+            defense_range: Some(defense_range),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder with exact color count filter (printed colors).
+    pub fn with_color_count_equals(color_count_equals: u8) -> CardQueryBuilder {
+        CardQueryBuilder {
+            color_count_equals: Some(color_count_equals),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder with color count range filter (inclusive, printed colors).
+    pub fn with_color_count_range(color_count_range: (u8, u8)) -> CardQueryBuilder {
+        CardQueryBuilder {
+            color_count_range: Some(color_count_range),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder filtering by multicolor (two or more printed colors).
+    pub fn with_is_multicolor(is_multicolor: bool) -> CardQueryBuilder {
+        CardQueryBuilder {
+            is_multicolor: Some(is_multicolor),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder filtering by colorless (no printed colors).
+    pub fn with_is_colorless(is_colorless: bool) -> CardQueryBuilder {
+        CardQueryBuilder {
+            is_colorless: Some(is_colorless),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder with EDHREC rank range filter (inclusive).
+    pub fn with_edhrec_rank_range(edhrec_rank_range: (i32, i32)) -> CardQueryBuilder {
+        CardQueryBuilder {
+            edhrec_rank_range: Some(edhrec_rank_range),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder with cards released on or after `released_after`.
+    pub fn with_released_after(released_after: NaiveDate) -> CardQueryBuilder {
+        CardQueryBuilder {
+            released_after: Some(released_after),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder with cards released on or before `released_before`.
+    pub fn with_released_before(released_before: NaiveDate) -> CardQueryBuilder {
+        CardQueryBuilder {
+            released_before: Some(released_before),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder filtering by non-numeric starting loyalty (`X`).
+    pub fn with_loyalty_is_variable(loyalty_is_variable: bool) -> CardQueryBuilder {
+        CardQueryBuilder {
+            loyalty_is_variable: Some(loyalty_is_variable),
+            ..CardQueryBuilder::default()
+        }
+    }
+
     /// Creates builder filtering by token status (tokens vs. real cards).
     pub fn with_is_token(is_token: bool) -> CardQueryBuilder {
         CardQueryBuilder {
@@ -710,6 +1028,30 @@ impl CardQueryBuilder {
         }
     }
 
+    /// Creates builder filtering by Commander game changer status.
+    pub fn with_game_changer(game_changer: bool) -> CardQueryBuilder {
+        CardQueryBuilder {
+            game_changer: Some(game_changer),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder filtering by story spotlight status.
+    pub fn with_story_spotlight(story_spotlight: bool) -> CardQueryBuilder {
+        CardQueryBuilder {
+            story_spotlight: Some(story_spotlight),
+            ..CardQueryBuilder::default()
+        }
+    }
+
+    /// Creates builder filtering by textless printings.
+    pub fn with_textless(textless: bool) -> CardQueryBuilder {
+        CardQueryBuilder {
+            textless: Some(textless),
+            ..CardQueryBuilder::default()
+        }
+    }
+
     /// Creates builder with specific result ordering (name, CMC, rarity, etc.).
     pub fn with_sort(sort: CardSortKey) -> CardQueryBuilder {
         CardQueryBuilder {
@@ -729,16 +1071,25 @@ impl CardQueryBuilder {
     /// at all — no criteria, no sort, and synergy off (the unbounded full pool
     /// with no intent). A sort or synergy mode is enough to serve the pool
     /// paginated and ordered. Also returns
-    /// [`InvalidCardCriteria::Contradiction`] for include/exclude clashes.
+    /// [`InvalidCardCriteria::Contradiction`] for include/exclude clashes, and
+    /// [`InvalidCardCriteria::RandomSecondarySort`] when the secondary sort
+    /// key is `Random`, and [`InvalidCardCriteria::CursorSortMismatch`] when
+    /// the cursor was taken under another ordering.
     pub fn build(&self) -> Result<CardQuery, InvalidCardCriteria> {
+        if self.then_sort == Some(CardSortKey::Random) {
+            return Err(InvalidCardCriteria::RandomSecondarySort);
+        }
+        check_cursor(self.after_cursor.as_ref(), self.sort, self.then_sort)?;
         Ok(CardQuery::new(
             self.build_criteria()?,
             Limit::new(self.limit),
             self.offset,
             self.sort,
+            self.then_sort,
             self.ascending,
             self.synergy,
-        ))
+        )
+        .with_after_cursor(self.after_cursor.clone()))
     }
 
     /// Builds bare [`CardCriteria`] for the in-memory path
@@ -886,18 +1237,51 @@ impl CardQueryBuilder {
                 .filter(|v: &Vec<String>| !v.is_empty())
         };
 
+        // Mana costs parse into braced, uppercased symbols. `contains` keeps the
+        // written order (a substring probe; a bare `W/P` becomes `{W/P}`);
+        // `equals` is canonicalized so symbol order doesn't matter.
+        let mana_cost_contains = trim(&self.mana_cost_contains).map(|s| {
+            let cost = ManaCost::parse(&s);
+            if cost.is_empty() {
+                format!("{{{}}}", s.to_uppercase())
+            } else {
+                cost.to_string()
+            }
+        });
+        let mana_cost_equals = trim(&self.mana_cost_equals)
+            .map(|s| ManaCost::parse(&s))
+            .filter(|cost| !cost.is_empty())
+            .map(|cost| cost.canonical());
+
         Ok(CardCriteria {
             power_equals: self.power_equals,
             power_range: self.power_range,
             toughness_equals: self.toughness_equals,
             toughness_range: self.toughness_range,
+            power_is_variable: self.power_is_variable,
+            toughness_is_variable: self.toughness_is_variable,
+            variable_stat_floor: self.variable_stat_floor,
+            loyalty_equals: self.loyalty_equals,
+            loyalty_range: self.loyalty_range,
+            loyalty_is_variable: self.loyalty_is_variable,
+            defense_equals: self.defense_equals,
+            defense_range: self.defense_range,
             cmc_equals: self.cmc_equals,
             cmc_range: self.cmc_range,
             color_identity_within: self.color_identity_within.clone(),
             color_identity_equals: self.color_identity_equals.clone(),
+            color_count_equals: self.color_count_equals,
+            color_count_range: self.color_count_range,
+            is_multicolor: self.is_multicolor,
+            is_colorless: self.is_colorless,
+            mana_cost_contains,
+            mana_cost_equals,
             price_min: self.price_min,
             price_max: self.price_max,
             price_currency: self.price_currency,
+            edhrec_rank_range: self.edhrec_rank_range,
+            released_after: self.released_after,
+            released_before: self.released_before,
             produced_mana_contains_any: self.produced_mana_contains_any.clone(),
             produced_mana_contains_all: self.produced_mana_contains_all.clone(),
             produced_mana_excludes: self.produced_mana_excludes.clone(),
@@ -905,9 +1289,18 @@ impl CardQueryBuilder {
             rarity_excludes_any: self.rarity_excludes_any.clone(),
             set_equals_any: trim_vec(&self.set_equals_any),
             set_excludes_any: trim_vec(&self.set_excludes_any),
+            set_type_equals_any: self.set_type_equals_any.clone(),
             artist_equals_any: trim_vec(&self.artist_equals_any),
             artist_excludes_any: trim_vec(&self.artist_excludes_any),
+            frame_effects_contains_any: trim_vec(&self.frame_effects_contains_any),
+            border_color_equals_any: trim_vec(&self.border_color_equals_any),
+            watermark_equals_any: trim_vec(&self.watermark_equals_any),
+            available_in_game: self.available_in_game,
+            finish_available: self.finish_available,
+            promo_types_contains_any: trim_vec(&self.promo_types_contains_any),
             name_contains: clean(&self.name_contains),
+            name_equals: trim(&self.name_equals),
+            text_search: clean(&self.text_search),
             name_not_contains: clean(&self.name_not_contains),
             oracle_text_contains: clean(&self.oracle_text_contains),
             oracle_text_not_contains: clean(&self.oracle_text_not_contains),
@@ -934,8 +1327,12 @@ impl CardQueryBuilder {
             oversized: self.oversized,
             promo: self.promo,
             content_warning: self.content_warning,
+            game_changer: self.game_changer,
+            story_spotlight: self.story_spotlight,
+            textless: self.textless,
             language: trim(&self.language),
             legalities_contains_any: self.legalities_contains_any.clone(),
+            legal_in: self.legal_in.clone(),
             is_commander_in_format: self.is_commander_in_format,
             is_partner: self.is_partner,
             is_background: self.is_background,
@@ -1106,6 +1503,16 @@ mod tests {
         assert!(builder.build().is_ok());
     }
 
+    #[test]
+    fn mana_cost_filters_normalize_at_build() {
+        let mut builder = CardQueryBuilder::new();
+        builder.set_mana_cost_contains(" w/p ");
+        builder.set_mana_cost_equals("{W}{1}{w}");
+        let criteria = builder.build_criteria().unwrap();
+        assert_eq!(criteria.mana_cost_contains(), Some("{W/P}"));
+        assert_eq!(criteria.mana_cost_equals(), Some("{1}{W}{W}"));
+    }
+
     #[test]
     fn synergy_is_a_mode_not_a_criterion() {
         // Synergy is not a search *criterion*: it never counts toward
@@ -8,8 +8,12 @@
 //! Text filters (`set_name_contains`, etc.) treat empty strings as `None`
 //! to avoid ineffective filters.
 
-use super::{CardQueryBuilder, CardSortKey, CardType, Colors, Format, PriceCurrency};
-use crate::domain::card::scryfall_data::rarity::Rarities;
+use super::{CardQueryBuilder, CardSortKey, CardType, Colors, Cursor, Format, PriceCurrency};
+use crate::domain::card::scryfall_data::{
+    colors::Color, finish::Finish, game::Game, legalities::LegalityKind, rarity::Rarities,
+    set_type::SetType,
+};
+use chrono::NaiveDate;
 
 impl CardQueryBuilder {
     // =================================
@@ -29,6 +33,35 @@ impl CardQueryBuilder {
         self
     }
 
+    /// Sets exact card name filter (case-insensitive, whole name, so "Island"
+    /// never matches "Island Sanctuary"). Empty strings = None.
+    pub fn set_name_equals(&mut self, name_equals: impl Into<String>) -> &mut Self {
+        let s = name_equals.into();
+        self.name_equals = if s.is_empty() { None } else { Some(s) };
+        self
+    }
+
+    /// Clears the exact name filter.
+    pub fn unset_name_equals(&mut self) -> &mut Self {
+        self.name_equals = None;
+        self
+    }
+
+    /// Sets the everything-search filter: matches the term in the name, type
+    /// line, oracle text, or flavor text (punctuation-insensitive), and
+    /// enables [`CardSortKey::Relevance`]. Empty strings = None.
+    pub fn set_text_search(&mut self, text_search: impl Into<String>) -> &mut Self {
+        let s = text_search.into();
+        self.text_search = if s.is_empty() { None } else { Some(s) };
+        self
+    }
+
+    /// Clears the everything-search filter.
+    pub fn unset_text_search(&mut self) -> &mut Self {
+        self.text_search = None;
+        self
+    }
+
     /// Sets oracle text filter (ability text substring). Empty strings = None.
     pub fn set_oracle_text_contains(
         &mut self,
@@ -271,6 +304,22 @@ impl CardQueryBuilder {
         self
     }
 
+    /// Sets filter matching any of multiple set types (e.g., [`SetType::Expansion`]). Empty vec = None.
+    pub fn set_set_type_equals_any(
+        &mut self,
+        set_type_equals_any: impl IntoIterator<Item = SetType>,
+    ) -> &mut Self {
+        let v: Vec<SetType> = set_type_equals_any.into_iter().collect();
+        self.set_type_equals_any = if v.is_empty() { None } else { Some(v) };
+        self
+    }
+
+    /// Clears the set_type_equals_any filter.
+    pub fn unset_set_type_equals_any(&mut self) -> &mut Self {
+        self.set_type_equals_any = None;
+        self
+    }
+
     /// Sets filter matching any of multiple artist names. Empty vec = None.
     pub fn set_artist_equals_any(
         &mut self,
@@ -291,6 +340,116 @@ impl CardQueryBuilder {
         self
     }
 
+    /// Sets filter matching printings with any of multiple frame effects
+    /// (e.g., "showcase", "etched"). Standard frames never match. Empty vec = None.
+    pub fn set_frame_effects_contains_any(
+        &mut self,
+        frame_effects_contains_any: impl IntoIterator<Item = impl Into<String>>,
+    ) -> &mut Self {
+        let s: Vec<String> = frame_effects_contains_any
+            .into_iter()
+            .map(|x| x.into())
+            .filter(|s| !s.is_empty())
+            .collect();
+        self.frame_effects_contains_any = if s.is_empty() { None } else { Some(s) };
+        self
+    }
+
+    /// Clears the frame_effects_contains_any filter.
+    pub fn unset_frame_effects_contains_any(&mut self) -> &mut Self {
+        self.frame_effects_contains_any = None;
+        self
+    }
+
+    /// Sets filter matching any of multiple border colors (e.g., "borderless",
+    /// "white"). Empty vec = None.
+    pub fn set_border_color_equals_any(
+        &mut self,
+        border_color_equals_any: impl IntoIterator<Item = impl Into<String>>,
+    ) -> &mut Self {
+        let s: Vec<String> = border_color_equals_any
+            .into_iter()
+            .map(|x| x.into())
+            .filter(|s| !s.is_empty())
+            .collect();
+        self.border_color_equals_any = if s.is_empty() { None } else { Some(s) };
+        self
+    }
+
+    /// Clears the border_color_equals_any filter.
+    pub fn unset_border_color_equals_any(&mut self) -> &mut Self {
+        self.border_color_equals_any = None;
+        self
+    }
+
+    /// Sets filter matching any of multiple watermarks (e.g., "azorius",
+    /// "mtg"). Printings without a watermark never match. Empty vec = None.
+    pub fn set_watermark_equals_any(
+        &mut self,
+        watermark_equals_any: impl IntoIterator<Item = impl Into<String>>,
+    ) -> &mut Self {
+        let s: Vec<String> = watermark_equals_any
+            .into_iter()
+            .map(|x| x.into())
+            .filter(|s| !s.is_empty())
+            .collect();
+        self.watermark_equals_any = if s.is_empty() { None } else { Some(s) };
+        self
+    }
+
+    /// Clears the watermark_equals_any filter.
+    pub fn unset_watermark_equals_any(&mut self) -> &mut Self {
+        self.watermark_equals_any = None;
+        self
+    }
+
+    /// Sets filter matching printings available in a game (paper, Arena,
+    /// MTGO). Printings with no known availability never match.
+    pub fn set_available_in_game(&mut self, available_in_game: Game) -> &mut Self {
+        self.available_in_game = Some(available_in_game);
+        self
+    }
+
+    /// Clears the available_in_game filter.
+    pub fn unset_available_in_game(&mut self) -> &mut Self {
+        self.available_in_game = None;
+        self
+    }
+
+    /// Sets filter matching printings that exist in a finish (non-foil, foil,
+    /// etched).
+    pub fn set_finish_available(&mut self, finish_available: Finish) -> &mut Self {
+        self.finish_available = Some(finish_available);
+        self
+    }
+
+    /// Clears the finish_available filter.
+    pub fn unset_finish_available(&mut self) -> &mut Self {
+        self.finish_available = None;
+        self
+    }
+
+    /// Sets filter matching printings with any of multiple promo types (e.g.,
+    /// "prerelease", "promopack"). Independent of the `promo` flag. Empty vec = None.
+    pub fn set_promo_types_contains_any(
+        &mut self,
+        promo_types_contains_any: impl IntoIterator<Item = impl Into<String>>,
+    ) -> &mut Self {
+        let s: Vec<String> = promo_types_contains_any
+            .into_iter()
+            .map(|x| x.into())
+            .filter(|s| !s.is_empty())
+            .collect();
+        self.promo_types_contains_any = if s.is_empty() { None } else { Some(s) };
+        self
+    }
+
+    /// Clears the promo_types_contains_any filter.
+    pub fn unset_promo_types_contains_any(&mut self) -> &mut Self {
+        self.promo_types_contains_any = None;
+        self
+    }
+
     /// Sets filter matching any of multiple rarities. Empty = None.
     pub fn set_rarity_equals_any(&mut self, rarity_equals_any: Rarities) -> &mut Self {
         self.rarity_equals_any = if rarity_equals_any.is_empty() {
@@ -335,6 +494,34 @@ impl CardQueryBuilder {
         self
     }
 
+    /// Sets mana cost contains filter: cards whose cost includes the given
+    /// symbol(s), e.g. `{W/P}` (braces optional for a single symbol).
+    pub fn set_mana_cost_contains(&mut self, mana_cost_contains: impl Into<String>) -> &mut Self {
+        let s = mana_cost_contains.into();
+        self.mana_cost_contains = if s.is_empty() { None } else { Some(s) };
+        self
+    }
+
+    /// Clears the mana_cost_contains filter.
+    pub fn unset_mana_cost_contains(&mut self) -> &mut Self {
+        self.mana_cost_contains = None;
+        self
+    }
+
+    /// Sets exact mana cost filter, e.g. `{1}{W}{W}`. Symbol order is
+    /// ignored, so `{W}{1}{W}` matches the same cards.
+    pub fn set_mana_cost_equals(&mut self, mana_cost_equals: impl Into<String>) -> &mut Self {
+        let s = mana_cost_equals.into();
+        self.mana_cost_equals = if s.is_empty() { None } else { Some(s) };
+        self
+    }
+
+    /// Clears the mana_cost_equals filter.
+    pub fn unset_mana_cost_equals(&mut self) -> &mut Self {
+        self.mana_cost_equals = None;
+        self
+    }
+
     /// Sets the minimum price bound (in the selected currency, inclusive).
     pub fn set_price_min(&mut self, price_min: f64) -> &mut Self {
         self.price_min = Some(price_min);
@@ -371,6 +558,95 @@ impl CardQueryBuilder {
         self
     }
 
+    /// Sets both price bounds (inclusive, either order) and the currency they
+    /// compare against. Cards with no price in that currency are excluded.
+    /// Compares this printing's price, not the cheapest printing of the card.
+    pub fn set_price_range(&mut self, currency: PriceCurrency, range: (f64, f64)) -> &mut Self {
+        self.price_currency = Some(currency);
+        self.price_min = Some(range.0.min(range.1));
+        self.price_max = Some(range.0.max(range.1));
+        self
+    }
+
+    /// [`set_price_range`](Self::set_price_range) in US Dollars.
+    pub fn set_price_usd_range(&mut self, range: (f64, f64)) -> &mut Self {
+        self.set_price_range(PriceCurrency::Usd, range)
+    }
+
+    /// [`set_price_range`](Self::set_price_range) in Euros.
+    pub fn set_price_eur_range(&mut self, range: (f64, f64)) -> &mut Self {
+        self.set_price_range(PriceCurrency::Eur, range)
+    }
+
+    /// [`set_price_range`](Self::set_price_range) in MTGO Event Tickets.
+    pub fn set_price_tix_range(&mut self, range: (f64, f64)) -> &mut Self {
+        self.set_price_range(PriceCurrency::Tix, range)
+    }
+
+    /// Clears both price bounds and the currency.
+    pub fn unset_price_range(&mut self) -> &mut Self {
+        self.price_min = None;
+        self.price_max = None;
+        self.price_currency = None;
+        self
+    }
+
+    /// Sets EDHREC rank range filter (inclusive; lower = more popular).
+    /// Unranked cards never match.
+    pub fn set_edhrec_rank_range(&mut self, edhrec_rank_range: (i32, i32)) -> &mut Self {
+        self.edhrec_rank_range = Some(edhrec_rank_range);
+        self
+    }
+
+    /// Clears the edhrec_rank_range filter.
+    pub fn unset_edhrec_rank_range(&mut self) -> &mut Self {
+        self.edhrec_rank_range = None;
+        self
+    }
+
+    /// Keeps only cards ranked `min` or lower in popularity — the power
+    /// budget's floor, skipping the most-played staples. Keeps any existing
+    /// upper bound; unranked cards never match.
+    pub fn set_min_edhrec_rank(&mut self, min: i32) -> &mut Self {
+        let max = self.edhrec_rank_range.map_or(i32::MAX, |(a, b)| a.max(b));
+        self.edhrec_rank_range = Some((min, max));
+        self
+    }
+
+    /// Keeps only cards ranked `max` or higher in popularity. Keeps any
+    /// existing lower bound; unranked cards never match.
+    pub fn set_max_edhrec_rank(&mut self, max: i32) -> &mut Self {
+        let min = self.edhrec_rank_range.map_or(1, |(a, b)| a.min(b));
+        self.edhrec_rank_range = Some((min, max));
+        self
+    }
+
+    /// Sets the earliest release date (inclusive). Works alone or with
+    /// [`set_released_before`](Self::set_released_before).
+    pub fn set_released_after(&mut self, released_after: NaiveDate) -> &mut Self {
+        self.released_after = Some(released_after);
+        self
+    }
+
+    /// Clears the released_after filter.
+    pub fn unset_released_after(&mut self) -> &mut Self {
+        self.released_after = None;
+        self
+    }
+
+    /// Sets the latest release date (inclusive). Works alone or with
+    /// [`set_released_after`](Self::set_released_after).
+    pub fn set_released_before(&mut self, released_before: NaiveDate) -> &mut Self {
+        self.released_before = Some(released_before);
+        self
+    }
+
+    /// Clears the released_before filter.
+    pub fn unset_released_before(&mut self) -> &mut Self {
+        self.released_before = None;
+        self
+    }
+
     /// Sets exact color identity filter (e.g., exactly W+U).
     pub fn set_color_identity_equals(&mut self, color_identity_equals: Colors) -> &mut Self {
         self.color_identity_equals = Some(color_identity_equals);
@@ -383,6 +659,57 @@ impl CardQueryBuilder {
         self
     }
 
+    /// Sets exact color count filter. Counts printed colors, not color
+    /// identity (see [`ScryfallData::color_count`](crate::domain::card::scryfall_data::ScryfallData::color_count)).
+    pub fn set_color_count_equals(&mut self, color_count_equals: u8) -> &mut Self {
+        self.color_count_equals = Some(color_count_equals);
+        self
+    }
+
+    /// Clears the color_count_equals filter.
+    pub fn unset_color_count_equals(&mut self) -> &mut Self {
+        self.color_count_equals = None;
+        self
+    }
+
+    /// Sets color count range filter (inclusive, printed colors).
+    pub fn set_color_count_range(&mut self, color_count_range: (u8, u8)) -> &mut Self {
+        self.color_count_range = Some(color_count_range);
+        self
+    }
+
+    /// Clears the color_count_range filter.
+    pub fn unset_color_count_range(&mut self) -> &mut Self {
+        self.color_count_range = None;
+        self
+    }
+
+    /// Sets multicolor filter: `true` keeps cards of two or more printed
+    /// colors, `false` keeps mono-colored and colorless cards.
+    pub fn set_is_multicolor(&mut self, is_multicolor: bool) -> &mut Self {
+        self.is_multicolor = Some(is_multicolor);
+        self
+    }
+
+    /// Clears the is_multicolor filter.
+    pub fn unset_is_multicolor(&mut self) -> &mut Self {
+        self.is_multicolor = None;
+        self
+    }
+
+    /// Sets colorless filter: `true` keeps cards with no printed colors
+    /// (artifacts, lands, Eldrazi), `false` keeps colored cards.
+    pub fn set_is_colorless(&mut self, is_colorless: bool) -> &mut Self {
+        self.is_colorless = Some(is_colorless);
+        self
+    }
+
+    /// Clears the is_colorless filter.
+    pub fn unset_is_colorless(&mut self) -> &mut Self {
+        self.is_colorless = None;
+        self
+    }
+
     /// Sets color identity within filter (subset of provided colors).
     pub fn set_color_identity_within(&mut self, color_identity_contains_any: Colors) -> &mut Self {
         self.color_identity_within = Some(color_identity_contains_any);
@@ -445,6 +772,20 @@ impl CardQueryBuilder {
         self
     }
 
+    /// Typed form of [`set_produced_mana_contains_any`](Self::set_produced_mana_contains_any):
+    /// cards producing any of `colors`, mapped through [`Color::to_short_name`].
+    /// Cards that produce no mana never match. Empty = None.
+    pub fn set_produces_any(&mut self, colors: impl IntoIterator<Item = Color>) -> &mut Self {
+        self.set_produced_mana_contains_any(colors.into_iter().map(|c| c.to_short_name()))
+    }
+
+    /// Typed form of [`set_produced_mana_contains_all`](Self::set_produced_mana_contains_all):
+    /// cards producing every one of `colors`. Cards that produce no mana never
+    /// match. Empty = None.
+    pub fn set_produces_all(&mut self, colors: impl IntoIterator<Item = Color>) -> &mut Self {
+        self.set_produced_mana_contains_all(colors.into_iter().map(|c| c.to_short_name()))
+    }
+
     // =================================
     // Combat Stat Setters
     // =================================
@@ -497,6 +838,113 @@ impl CardQueryBuilder {
         self
     }
 
+    /// Sets filter for cards whose power contains `*` (`*`, `1+*`, `*²`, ...).
+    /// `false` keeps only cards without one, including cards with no power.
+    pub fn set_power_is_variable(&mut self, power_is_variable: bool) -> &mut Self {
+        self.power_is_variable = Some(power_is_variable);
+        self
+    }
+
+    /// Clears the power_is_variable filter.
+    pub fn unset_power_is_variable(&mut self) -> &mut Self {
+        self.power_is_variable = None;
+        self
+    }
+
+    /// Sets filter for cards whose toughness contains `*`.
+    /// `false` keeps only cards without one, including cards with no toughness.
+    pub fn set_toughness_is_variable(&mut self, toughness_is_variable: bool) -> &mut Self {
+        self.toughness_is_variable = Some(toughness_is_variable);
+        self
+    }
+
+    /// Clears the toughness_is_variable filter.
+    pub fn unset_toughness_is_variable(&mut self) -> &mut Self {
+        self.toughness_is_variable = None;
+        self
+    }
+
+    /// Lets power and toughness ranges match variable stats by counting `*`
+    /// as `floor`:
+    ///
+    /// - `*` counts as `floor`
+    /// - `N+*` and `*+N` count as `N + floor` (so `2+*` with floor 0 is 2)
+    /// - any other shape with a `*` (`*²`, `7-*`) never matches a range
+    ///
+    /// Without a floor, ranges match plain integers only. Exact-value filters
+    /// always match plain integers only.
+    pub fn set_variable_stat_floor(&mut self, floor: i32) -> &mut Self {
+        self.variable_stat_floor = Some(floor);
+        self
+    }
+
+    /// Clears the variable_stat_floor, so ranges skip variable stats again.
+    pub fn unset_variable_stat_floor(&mut self) -> &mut Self {
+        self.variable_stat_floor = None;
+        self
+    }
+
+    /// Sets exact starting loyalty filter. Only numeric loyalties match.
+    pub fn set_loyalty_equals(&mut self, loyalty_equals: i32) -> &mut Self {
+        self.loyalty_equals = Some(loyalty_equals);
+        self
+    }
+
+    /// Clears the loyalty_equals filter.
+    pub fn unset_loyalty_equals(&mut self) -> &mut Self {
+        self.loyalty_equals = None;
+        self
+    }
+
+    /// Sets starting loyalty range filter (inclusive). Only numeric loyalties match.
+    pub fn set_loyalty_range(&mut self, loyalty_range: (i32, i32)) -> &mut Self {
+        self.loyalty_range = Some(loyalty_range);
+        self
+    }
+
+    /// Clears the loyalty_range filter.
+    pub fn unset_loyalty_range(&mut self) -> &mut Self {
+        self.loyalty_range = None;
+        self
+    }
+
+    /// Sets filter for cards with a non-numeric starting loyalty (`X`).
+    /// `false` keeps only cards without one, including cards with no loyalty.
+    pub fn set_loyalty_is_variable(&mut self, loyalty_is_variable: bool) -> &mut Self {
+        self.loyalty_is_variable = Some(loyalty_is_variable);
+        self
+    }
+
+    /// Clears the loyalty_is_variable filter.
+    pub fn unset_loyalty_is_variable(&mut self) -> &mut Self {
+        self.loyalty_is_variable = None;
+        self
+    }
+
+    /// Sets exact battle defense filter. Only numeric defenses match.
+    pub fn set_defense_equals(&mut self, defense_equals: i32) -> &mut Self {
+        self.defense_equals = Some(defense_equals);
+        self
+    }
+
+    /// Clears the defense_equals filter.
+    pub fn unset_defense_equals(&mut self) -> &mut Self {
+        self.defense_equals = None;
+        self
+    }
+
+    /// Sets battle defense range filter (inclusive). Only numeric defenses match.
+    pub fn set_defense_range(&mut self, defense_range: (i32, i32)) -> &mut Self {
+        self.defense_range = Some(defense_range);
+        self
+    }
+
+    /// Clears the defense_range filter.
+    pub fn unset_defense_range(&mut self) -> &mut Self {
+        self.defense_range = None;
+        self
+    }
+
     // =================================
     // Card Flag Setters
     // =================================
@@ -573,6 +1021,55 @@ impl CardQueryBuilder {
         self
     }
 
+    /// Sets filter for cards on the Commander game changer list.
+    pub fn set_game_changer(&mut self, game_changer: bool) -> &mut Self {
+        self.game_changer = Some(game_changer);
+        self
+    }
+
+    /// Clears the game_changer filter.
+    pub fn unset_game_changer(&mut self) -> &mut Self {
+        self.game_changer = None;
+        self
+    }
+
+    /// Drops cards on the Commander game changer list from results, for
+    /// brewing under a power budget. `false` lifts the exclusion but leaves
+    /// a [`set_game_changer(true)`](Self::set_game_changer) filter alone.
+    pub fn set_exclude_game_changers(&mut self, exclude: bool) -> &mut Self {
+        if exclude {
+            self.game_changer = Some(false);
+        } else if self.game_changer == Some(false) {
+            self.game_changer = None;
+        }
+        self
+    }
+
+    /// Sets filter for story spotlight printings. No default: unset returns
+    /// both.
+    pub fn set_story_spotlight(&mut self, story_spotlight: bool) -> &mut Self {
+        self.story_spotlight = Some(story_spotlight);
+        self
+    }
+
+    /// Clears the story_spotlight filter.
+    pub fn unset_story_spotlight(&mut self) -> &mut Self {
+        self.story_spotlight = None;
+        self
+    }
+
+    /// Sets filter for textless printings. No default: unset returns both.
+    pub fn set_textless(&mut self, textless: bool) -> &mut Self {
+        self.textless = Some(textless);
+        self
+    }
+
+    /// Clears the textless filter.
+    pub fn unset_textless(&mut self) -> &mut Self {
+        self.textless = None;
+        self
+    }
+
     /// Sets card language filter (e.g., "en", "ja").
     pub fn set_language(&mut self, language: impl Into<String>) -> &mut Self {
         self.language = Some(language.into());
@@ -609,6 +1106,21 @@ impl CardQueryBuilder {
         self
     }
 
+    /// Requires `status` in `format`, replacing any earlier status for that
+    /// format. Statuses for different formats must all hold.
+    pub fn set_legal_in(&mut self, format: Format, status: LegalityKind) -> &mut Self {
+        let legal_in = self.legal_in.get_or_insert_with(Vec::new);
+        legal_in.retain(|(f, _)| *f != format);
+        legal_in.push((format, status));
+        self
+    }
+
+    /// Clears every legal_in requirement.
+    pub fn unset_legal_in(&mut self) -> &mut Self {
+        self.legal_in = None;
+        self
+    }
+
     // =================================
     // Commander Filter Setters
     // =================================
@@ -774,6 +1286,20 @@ impl CardQueryBuilder {
         self
     }
 
+    /// Resumes after `cursor`, the previous page's `next_cursor`, instead of
+    /// skipping `offset` rows. The sort must be the one the cursor was taken
+    /// under, with no secondary sort; `build()` rejects anything else.
+    pub fn set_after_cursor(&mut self, cursor: Cursor) -> &mut Self {
+        self.after_cursor = Some(cursor);
+        self
+    }
+
+    /// Clears the cursor, back to offset pagination.
+    pub fn unset_after_cursor(&mut self) -> &mut Self {
+        self.after_cursor = None;
+        self
+    }
+
     /// Sets result ordering (name, CMC, rarity, etc.).
     pub fn set_sort(&mut self, sort: CardSortKey) -> &mut Self {
         self.sort = Some(sort);
@@ -786,6 +1312,20 @@ impl CardQueryBuilder {
         self
     }
 
+    /// Sets the secondary sort key, breaking ties left by the primary sort
+    /// (e.g. CMC, then name). Ignored without a primary sort; `Random` is
+    /// rejected at build time.
+    pub fn set_then_sort(&mut self, then_sort: CardSortKey) -> &mut Self {
+        self.then_sort = Some(then_sort);
+        self
+    }
+
+    /// Clears the secondary sort key.
+    pub fn unset_then_sort(&mut self) -> &mut Self {
+        self.then_sort = None;
+        self
+    }
+
     /// Sets sort direction (ascending vs descending).
     pub fn set_ascending(&mut self, ascending: bool) -> &mut Self {
         self.ascending = ascending;
@@ -1044,13 +1584,14 @@ impl CardQueryBuilder {
     // Utility Methods
     // =================================
 
-    /// Clears all search filters, keeps only config (limit, offset, flags, language).
+    /// Clears all search filters, keeps only config (limit, offset/cursor, flags, language).
     ///
     /// Useful for resetting search while preserving pagination state and defaults.
     pub fn retain_config(&mut self) -> &mut Self {
         let default = Self {
             limit: self.limit,
             offset: self.offset,
+            after_cursor: self.after_cursor.clone(),
             is_token: self.is_token,
             is_playable: self.is_playable,
             digital: self.digital,
@@ -1059,6 +1600,7 @@ impl CardQueryBuilder {
             content_warning: self.content_warning,
             language: self.language.clone(),
             sort: self.sort,
+            then_sort: self.then_sort,
             ascending: self.ascending,
             // Synergy is a mode flag, not a search criterion: clearing the filter
             // keeps it, and it never makes the filter "active" on its own.
@@ -1,4 +1,4 @@
-use crate::domain::card::scryfall_data::ScryfallData;
+use crate::domain::card::scryfall_data::{ScryfallData, prices::Price};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
@@ -15,11 +15,17 @@ pub enum CardSortKey {
     PriceEur,
     PriceTix,
     EdhrecRank,
+    Loyalty,
+    Defense,
     Random,
+    /// Best match for the criteria's `text_search` first; by name without one.
+    Relevance,
 }
 
-#[allow(missing_docs)]
 impl CardSortKey {
+    /// Every key that orders the pool on its own, for the sort picker.
+    /// [`Relevance`](Self::Relevance) is left out: it only ranks a text
+    /// search.
     pub fn all() -> Vec<Self> {
         vec![
             Self::Name,
@@ -32,6 +38,8 @@ impl CardSortKey {
             Self::PriceEur,
             Self::PriceTix,
             Self::EdhrecRank,
+            Self::Loyalty,
+            Self::Defense,
             Self::Random,
         ]
     }
@@ -40,7 +48,8 @@ impl CardSortKey {
 impl CardSortKey {
     /// Ascending comparison of two cards under this key. Missing/non-numeric
     /// values sort last (ascending); `Random` compares equal — shuffling is the
-    /// collection's job, not a pairwise ordering.
+    /// collection's job, not a pairwise ordering — and so does `Relevance`,
+    /// whose rank only the server's text search computes.
     ///
     /// Shared by [`Cards::sorted`](crate::domain::card::search_card::cards::Cards::sorted)
     /// and deck-entry sorting so every in-memory sort agrees.
@@ -50,8 +59,9 @@ impl CardSortKey {
         fn stat_i32(v: Option<&str>) -> i32 {
             v.and_then(|s| s.parse::<i32>().ok()).unwrap_or(i32::MAX)
         }
-        fn price_f64(v: Option<&str>) -> f64 {
-            v.and_then(|s| s.parse::<f64>().ok()).unwrap_or(f64::MAX)
+        // unpriced sorts last (ascending)
+        fn price(v: Option<Price>) -> Price {
+            v.unwrap_or_else(|| Price::from_hundredths(u64::MAX))
         }
         fn cmp_f64(a: f64, b: f64) -> Ordering {
             a.partial_cmp(&b).unwrap_or(Ordering::Equal)
@@ -66,24 +76,17 @@ impl CardSortKey {
             }
             Self::Rarity => a.rarity.cmp(&b.rarity),
             Self::ReleasedAt => a.released_at.cmp(&b.released_at),
-            Self::PriceUsd => cmp_f64(
-                price_f64(a.prices.usd.as_deref()),
-                price_f64(b.prices.usd.as_deref()),
-            ),
-            Self::PriceEur => cmp_f64(
-                price_f64(a.prices.eur.as_deref()),
-                price_f64(b.prices.eur.as_deref()),
-            ),
-            Self::PriceTix => cmp_f64(
-                price_f64(a.prices.tix.as_deref()),
-                price_f64(b.prices.tix.as_deref()),
-            ),
+            Self::PriceUsd => price(a.prices.usd()).cmp(&price(b.prices.usd())),
+            Self::PriceEur => price(a.prices.eur()).cmp(&price(b.prices.eur())),
+            Self::PriceTix => price(a.prices.tix()).cmp(&price(b.prices.tix())),
             // Lower rank = more played; unranked sorts last (ascending).
             Self::EdhrecRank => a
                 .edhrec_rank
                 .unwrap_or(i32::MAX)
                 .cmp(&b.edhrec_rank.unwrap_or(i32::MAX)),
-            Self::Random => Ordering::Equal,
+            Self::Loyalty => stat_i32(a.loyalty.as_deref()).cmp(&stat_i32(b.loyalty.as_deref())),
+            Self::Defense => stat_i32(a.defense.as_deref()).cmp(&stat_i32(b.defense.as_deref())),
+            Self::Random | Self::Relevance => Ordering::Equal,
         }
     }
 }
@@ -101,7 +104,10 @@ impl std::fmt::Display for CardSortKey {
             Self::PriceEur => write!(f, "Price (EUR)"),
             Self::PriceTix => write!(f, "Price (TIX)"),
             Self::EdhrecRank => write!(f, "Popularity (EDHREC)"),
+            Self::Loyalty => write!(f, "Loyalty"),
+            Self::Defense => write!(f, "Defense"),
             Self::Random => write!(f, "Random"),
+            Self::Relevance => write!(f, "Relevance"),
         }
     }
 }
@@ -0,0 +1,566 @@
+//! Plain-words summary of the active criteria, for filter chips and saved
+//! search labels.
+//!
+//! Config-only flags (token, playable, digital, oversized, promo, content
+//! warning, language, price currency) are left out, the same set
+//! [`CardCriteria::is_empty`] ignores; the currency only shapes the price
+//! text.
+
+use super::CardCriteria;
+use crate::domain::{
+    card::scryfall_data::{colors::Colors, legalities::LegalityKind},
+    deck::Format,
+};
+use std::fmt::Display;
+
+/// One active criterion, described for display.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterDescription {
+    /// Wire key of the criterion (e.g. `cmc_range`), so a chip can clear it.
+    /// A two-sided price or release window is one entry, `price` or
+    /// `released`.
+    pub field: &'static str,
+    /// Human-readable text (e.g. `CMC 2–4`, `Colors: within WU`).
+    pub text: String,
+}
+
+impl Display for FilterDescription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Whole numbers without a trailing `.0`.
+fn number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{value:.0}")
+    } else {
+        value.to_string()
+    }
+}
+
+/// `lo–hi` with the bounds in order, or a single value when they meet.
+fn range<T: PartialOrd + Copy>(bounds: (T, T), show: impl Fn(T) -> String) -> String {
+    let (lo, hi) = if bounds.0 <= bounds.1 {
+        bounds
+    } else {
+        (bounds.1, bounds.0)
+    };
+    if lo == hi {
+        show(lo)
+    } else {
+        format!("{}–{}", show(lo), show(hi))
+    }
+}
+
+fn list<T: Display>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// WUBRG letters, or `colorless`.
+fn colors(colors: &Colors) -> String {
+    if colors.is_empty() {
+        return "colorless".to_string();
+    }
+    let mut sorted = colors.to_vec();
+    sorted.sort();
+    sorted.iter().map(|c| c.to_short_name()).collect()
+}
+
+fn legality(kind: &LegalityKind) -> &'static str {
+    match kind {
+        LegalityKind::Legal => "legal",
+        LegalityKind::NotLegal => "not legal",
+        LegalityKind::Restricted => "restricted",
+        LegalityKind::Banned => "banned",
+    }
+}
+
+/// Format display name for a legality key, or the raw key if unknown.
+fn format_key(key: &str) -> String {
+    Format::try_from(key)
+        .map(|format| format.display_name().to_string())
+        .unwrap_or_else(|_| key.to_string())
+}
+
+impl CardCriteria {
+    /// Describes each active criterion in field order. Empty when
+    /// [`is_empty`](Self::is_empty) holds.
+    pub fn describe(&self) -> Vec<FilterDescription> {
+        let mut out = Vec::new();
+        let mut push = |field: &'static str, text: String| {
+            out.push(FilterDescription { field, text });
+        };
+        let int = |n: i32| n.to_string();
+        let fixed_or_variable = |variable: bool| if variable { "variable" } else { "fixed" };
+
+        // combat
+        if let Some(n) = self.power_equals {
+            push("power_equals", format!("Power {n}"));
+        }
+        if let Some(bounds) = self.power_range {
+            push("power_range", format!("Power {}", range(bounds, int)));
+        }
+        if let Some(n) = self.toughness_equals {
+            push("toughness_equals", format!("Toughness {n}"));
+        }
+        if let Some(bounds) = self.toughness_range {
+            push(
+                "toughness_range",
+                format!("Toughness {}", range(bounds, int)),
+            );
+        }
+        if let Some(variable) = self.power_is_variable {
+            let text = format!("Power: {}", fixed_or_variable(variable));
+            push("power_is_variable", text);
+        }
+        if let Some(variable) = self.toughness_is_variable {
+            let text = format!("Toughness: {}", fixed_or_variable(variable));
+            push("toughness_is_variable", text);
+        }
+        if let Some(n) = self.variable_stat_floor {
+            push("variable_stat_floor", format!("* counts as {n}"));
+        }
+        if let Some(n) = self.loyalty_equals {
+            push("loyalty_equals", format!("Loyalty {n}"));
+        }
+        if let Some(bounds) = self.loyalty_range {
+            push("loyalty_range", format!("Loyalty {}", range(bounds, int)));
+        }
+        if let Some(variable) = self.loyalty_is_variable {
+            let text = format!("Loyalty: {}", fixed_or_variable(variable));
+            push("loyalty_is_variable", text);
+        }
+        if let Some(n) = self.defense_equals {
+            push("defense_equals", format!("Defense {n}"));
+        }
+        if let Some(bounds) = self.defense_range {
+            push("defense_range", format!("Defense {}", range(bounds, int)));
+        }
+
+        // mana
+        if let Some(cmc) = self.cmc_equals {
+            push("cmc_equals", format!("CMC {}", number(cmc)));
+        }
+        if let Some(bounds) = self.cmc_range {
+            push("cmc_range", format!("CMC {}", range(bounds, number)));
+        }
+        if let Some(within) = &self.color_identity_within {
+            let text = format!("Colors: within {}", colors(within));
+            push("color_identity_within", text);
+        }
+        if let Some(exactly) = &self.color_identity_equals {
+            let text = format!("Colors: exactly {}", colors(exactly));
+            push("color_identity_equals", text);
+        }
+        if let Some(n) = self.color_count_equals {
+            push("color_count_equals", format!("Color count {n}"));
+        }
+        if let Some(bounds) = self.color_count_range {
+            let text = format!("Color count {}", range(bounds, |n: u8| n.to_string()));
+            push("color_count_range", text);
+        }
+        if let Some(multicolor) = self.is_multicolor {
+            let text = if multicolor {
+                "Multicolor"
+            } else {
+                "Not multicolor"
+            };
+            push("is_multicolor", text.to_string());
+        }
+        if let Some(colorless) = self.is_colorless {
+            let text = if colorless {
+                "Colorless"
+            } else {
+                "Not colorless"
+            };
+            push("is_colorless", text.to_string());
+        }
+        if let Some(symbols) = &self.mana_cost_contains {
+            push("mana_cost_contains", format!("Mana cost has {symbols}"));
+        }
+        if let Some(cost) = &self.mana_cost_equals {
+            push("mana_cost_equals", format!("Mana cost {cost}"));
+        }
+
+        // price
+        let currency = self.price_currency.unwrap_or_default();
+        match (self.price_min, self.price_max) {
+            (Some(min), Some(max)) => {
+                let text = format!("Price {}", range((min, max), |p| currency.format_amount(p)));
+                push("price", text);
+            }
+            (Some(min), None) => {
+                let text = format!("Price: at least {}", currency.format_amount(min));
+                push("price_min", text);
+            }
+            (None, Some(max)) => {
+                let text = format!("Price: up to {}", currency.format_amount(max));
+                push("price_max", text);
+            }
+            (None, None) => {}
+        }
+
+        if let Some(bounds) = self.edhrec_rank_range {
+            let text = format!("EDHREC rank {}", range(bounds, int));
+            push("edhrec_rank_range", text);
+        }
+        match (self.released_after, self.released_before) {
+            (Some(after), Some(before)) => {
+                push("released", format!("Released {after}–{before}"));
+            }
+            (Some(after), None) => push("released_after", format!("Released from {after}")),
+            (None, Some(before)) => push("released_before", format!("Released until {before}")),
+            (None, None) => {}
+        }
+
+        // produced mana
+        if let Some(symbols) = &self.produced_mana_contains_any {
+            let text = format!("Produces any of: {}", list(symbols));
+            push("produced_mana_contains_any", text);
+        }
+        if let Some(symbols) = &self.produced_mana_contains_all {
+            let text = format!("Produces all of: {}", list(symbols));
+            push("produced_mana_contains_all", text);
+        }
+        if let Some(symbols) = &self.produced_mana_excludes {
+            let text = format!("Doesn't produce: {}", list(symbols));
+            push("produced_mana_excludes", text);
+        }
+
+        // printing
+        if let Some(rarities) = &self.rarity_equals_any {
+            let text = format!("Rarity: {}", rarities.to_long_names().join(", "));
+            push("rarity_equals_any", text);
+        }
+        if let Some(rarities) = &self.rarity_excludes_any {
+            let text = format!("Rarity: not {}", rarities.to_long_names().join(", "));
+            push("rarity_excludes_any", text);
+        }
+        if let Some(sets) = &self.set_equals_any {
+            push(
+                "set_equals_any",
+                format!("Set: {}", list(sets).to_uppercase()),
+            );
+        }
+        if let Some(sets) = &self.set_excludes_any {
+            let text = format!("Set: not {}", list(sets).to_uppercase());
+            push("set_excludes_any", text);
+        }
+        if let Some(set_types) = &self.set_type_equals_any {
+            push(
+                "set_type_equals_any",
+                format!("Set type: {}", list(set_types)),
+            );
+        }
+        if let Some(artists) = &self.artist_equals_any {
+            push("artist_equals_any", format!("Artist: {}", list(artists)));
+        }
+        if let Some(artists) = &self.artist_excludes_any {
+            push(
+                "artist_excludes_any",
+                format!("Artist: not {}", list(artists)),
+            );
+        }
+        if let Some(effects) = &self.frame_effects_contains_any {
+            push(
+                "frame_effects_contains_any",
+                format!("Frame: {}", list(effects)),
+            );
+        }
+        if let Some(borders) = &self.border_color_equals_any {
+            push(
+                "border_color_equals_any",
+                format!("Border: {}", list(borders)),
+            );
+        }
+        if let Some(watermarks) = &self.watermark_equals_any {
+            push(
+                "watermark_equals_any",
+                format!("Watermark: {}", list(watermarks)),
+            );
+        }
+        if let Some(game) = self.available_in_game {
+            push(
+                "available_in_game",
+                format!("Available in: {}", game.display_name()),
+            );
+        }
+        if let Some(finish) = self.finish_available {
+            push(
+                "finish_available",
+                format!("Finish: {}", finish.display_name()),
+            );
+        }
+        if let Some(promo_types) = &self.promo_types_contains_any {
+            push(
+                "promo_types_contains_any",
+                format!("Promo type: {}", list(promo_types)),
+            );
+        }
+
+        // text
+        if let Some(text) = &self.name_contains {
+            push("name_contains", format!("Name has \"{text}\""));
+        }
+        if let Some(text) = &self.name_equals {
+            push("name_equals", format!("Name is \"{text}\""));
+        }
+        if let Some(text) = &self.text_search {
+            push("text_search", format!("Any text has \"{text}\""));
+        }
+        if let Some(text) = &self.name_not_contains {
+            push("name_not_contains", format!("Name lacks \"{text}\""));
+        }
+        if let Some(text) = &self.oracle_text_contains {
+            push("oracle_text_contains", format!("Text has \"{text}\""));
+        }
+        if let Some(text) = &self.oracle_text_not_contains {
+            push("oracle_text_not_contains", format!("Text lacks \"{text}\""));
+        }
+        if let Some(terms) = &self.oracle_text_contains_any {
+            push(
+                "oracle_text_contains_any",
+                format!("Text has any of: {}", list(terms)),
+            );
+        }
+        if let Some(terms) = &self.oracle_text_contains_all {
+            push(
+                "oracle_text_contains_all",
+                format!("Text has all of: {}", list(terms)),
+            );
+        }
+        if let Some(terms) = &self.oracle_text_excludes_any {
+            push(
+                "oracle_text_excludes_any",
+                format!("Text lacks: {}", list(terms)),
+            );
+        }
+        if let Some(keywords) = &self.keywords_contains_any {
+            let text = format!("Keywords: any of {}", list(keywords));
+            push("keywords_contains_any", text);
+        }
+        if let Some(keywords) = &self.keywords_contains_all {
+            let text = format!("Keywords: all of {}", list(keywords));
+            push("keywords_contains_all", text);
+        }
+        if let Some(keywords) = &self.keywords_excludes {
+            push(
+                "keywords_excludes",
+                format!("Keywords: not {}", list(keywords)),
+            );
+        }
+        if let Some(text) = &self.flavor_text_contains {
+            push(
+                "flavor_text_contains",
+                format!("Flavor text has \"{text}\""),
+            );
+        }
+        if let Some(text) = &self.flavor_text_not_contains {
+            push(
+                "flavor_text_not_contains",
+                format!("Flavor text lacks \"{text}\""),
+            );
+        }
+        if let Some(has) = self.has_flavor_text {
+            let text = if has {
+                "Has flavor text"
+            } else {
+                "No flavor text"
+            };
+            push("has_flavor_text", text.to_string());
+        }
+
+        // types
+        if let Some(text) = &self.type_line_contains {
+            push("type_line_contains", format!("Type has \"{text}\""));
+        }
+        if let Some(text) = &self.type_line_not_contains {
+            push("type_line_not_contains", format!("Type lacks \"{text}\""));
+        }
+        if let Some(types) = &self.type_line_contains_any {
+            push(
+                "type_line_contains_any",
+                format!("Type: any of {}", list(types)),
+            );
+        }
+        if let Some(types) = &self.type_line_contains_all {
+            push(
+                "type_line_contains_all",
+                format!("Type: all of {}", list(types)),
+            );
+        }
+        if let Some(types) = &self.type_line_excludes_any {
+            push(
+                "type_line_excludes_any",
+                format!("Type: not {}", list(types)),
+            );
+        }
+        if let Some(types) = &self.card_type_contains_any {
+            push(
+                "card_type_contains_any",
+                format!("Card type: any of {}", list(types)),
+            );
+        }
+        if let Some(types) = &self.card_type_contains_all {
+            push(
+                "card_type_contains_all",
+                format!("Card type: all of {}", list(types)),
+            );
+        }
+        if let Some(types) = &self.card_type_excludes_any {
+            push(
+                "card_type_excludes_any",
+                format!("Card type: not {}", list(types)),
+            );
+        }
+
+        if let Some(only) = self.game_changer {
+            let text = if only {
+                "Game changers only"
+            } else {
+                "No game changers"
+            };
+            push("game_changer", text.to_string());
+        }
+        if let Some(only) = self.story_spotlight {
+            let text = if only {
+                "Story spotlights only"
+            } else {
+                "No story spotlights"
+            };
+            push("story_spotlight", text.to_string());
+        }
+        if let Some(only) = self.textless {
+            let text = if only {
+                "Textless only"
+            } else {
+                "No textless printings"
+            };
+            push("textless", text.to_string());
+        }
+
+        // legality
+        if let Some(keys) = &self.legalities_contains_any {
+            let formats: Vec<String> = keys.iter().map(|key| format_key(key)).collect();
+            push(
+                "legalities_contains_any",
+                format!("Legal in: {}", list(&formats)),
+            );
+        }
+        if let Some(statuses) = &self.legal_in {
+            for (format, kind) in statuses {
+                let text = format!("{}: {}", format.display_name(), legality(kind));
+                push("legal_in", text);
+            }
+        }
+        if let Some(format) = &self.is_commander_in_format {
+            let text = format!("Commander in {}", format.display_name());
+            push("is_commander_in_format", text);
+        }
+        let role = |is: bool, what: &str| {
+            if is {
+                format!("{what}s only")
+            } else {
+                format!("No {}s", what.to_lowercase())
+            }
+        };
+        if let Some(is) = self.is_partner {
+            push("is_partner", role(is, "Partner"));
+        }
+        if let Some(is) = self.is_background {
+            push("is_background", role(is, "Background"));
+        }
+        if let Some(is) = self.is_signature_spell {
+            push("is_signature_spell", role(is, "Signature spell"));
+        }
+
+        // roles and tags
+        if let Some(roles) = &self.card_roles_contains_any {
+            push(
+                "card_roles_contains_any",
+                format!("Roles: any of {}", list(roles)),
+            );
+        }
+        if let Some(roles) = &self.card_roles_contains_all {
+            push(
+                "card_roles_contains_all",
+                format!("Roles: all of {}", list(roles)),
+            );
+        }
+        if let Some(roles) = &self.card_roles_excludes {
+            push("card_roles_excludes", format!("Roles: not {}", list(roles)));
+        }
+        if let Some(tags) = &self.oracle_tags_contains_any {
+            push(
+                "oracle_tags_contains_any",
+                format!("Tags: any of {}", list(tags)),
+            );
+        }
+        if let Some(tags) = &self.oracle_tags_contains_all {
+            push(
+                "oracle_tags_contains_all",
+                format!("Tags: all of {}", list(tags)),
+            );
+        }
+        if let Some(tags) = &self.oracle_tags_excludes {
+            push("oracle_tags_excludes", format!("Tags: not {}", list(tags)));
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::CardCriteria;
+    use crate::domain::card::{
+        scryfall_data::{colors::Color, rarity::Rarity},
+        search_card::card_filter::{builder::CardQueryBuilder, price_currency::PriceCurrency},
+    };
+
+    fn texts(builder: &CardQueryBuilder) -> Vec<String> {
+        builder
+            .build_criteria()
+            .unwrap()
+            .describe()
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn describes_cmc_range_colors_and_rarity() {
+        let mut builder = CardQueryBuilder::with_cmc_range((4.0, 2.0));
+        builder
+            .set_color_identity_within([Color::Blue, Color::White].into())
+            .set_rarity_equals_any([Rarity::Mythic].into());
+        assert_eq!(
+            texts(&builder),
+            ["CMC 2–4", "Colors: within WU", "Rarity: Mythic"]
+        );
+
+        let half = CardQueryBuilder::with_cmc_equals(2.5);
+        assert_eq!(texts(&half), ["CMC 2.5"]);
+    }
+
+    #[test]
+    fn empty_and_config_only_criteria_describe_nothing() {
+        assert!(CardCriteria::default().describe().is_empty());
+
+        let config_only = CardCriteria {
+            is_playable: Some(true),
+            digital: Some(false),
+            language: Some("en".to_string()),
+            price_currency: Some(PriceCurrency::Eur),
+            ..CardCriteria::default()
+        };
+        assert!(config_only.is_empty());
+        assert!(config_only.describe().is_empty());
+    }
+}
@@ -1,6 +1,9 @@
 use crate::domain::{
     card::{
-        scryfall_data::{colors::Colors, rarity::Rarities},
+        scryfall_data::{
+            colors::Colors, finish::Finish, game::Game, legalities::LegalityKind, rarity::Rarities,
+            set_type::SetType,
+        },
         search_card::{
             card_filter::{criteria::CardCriteria, price_currency::PriceCurrency},
             card_type::CardType,
@@ -8,6 +11,7 @@ use crate::domain::{
     },
     deck::Format,
 };
+use chrono::NaiveDate;
 
 #[allow(missing_docs)]
 impl CardCriteria {
@@ -16,6 +20,14 @@ impl CardCriteria {
         self.name_contains.as_deref()
     }
 
+    pub fn name_equals(&self) -> Option<&str> {
+        self.name_equals.as_deref()
+    }
+
+    pub fn text_search(&self) -> Option<&str> {
+        self.text_search.as_deref()
+    }
+
     pub fn oracle_text_contains(&self) -> Option<&str> {
         self.oracle_text_contains.as_deref()
     }
@@ -76,11 +88,43 @@ impl CardCriteria {
         self.set_equals_any.as_deref()
     }
 
+    // set type
+    pub fn set_type_equals_any(&self) -> Option<&[SetType]> {
+        self.set_type_equals_any.as_deref()
+    }
+
     // artist
     pub fn artist_equals_any(&self) -> Option<&[String]> {
         self.artist_equals_any.as_deref()
     }
 
+    // frame/border/watermark
+    pub fn frame_effects_contains_any(&self) -> Option<&[String]> {
+        self.frame_effects_contains_any.as_deref()
+    }
+
+    pub fn border_color_equals_any(&self) -> Option<&[String]> {
+        self.border_color_equals_any.as_deref()
+    }
+
+    pub fn watermark_equals_any(&self) -> Option<&[String]> {
+        self.watermark_equals_any.as_deref()
+    }
+
+    // availability
+    pub fn available_in_game(&self) -> Option<Game> {
+        self.available_in_game
+    }
+
+    pub fn finish_available(&self) -> Option<Finish> {
+        self.finish_available
+    }
+
+    // promo types
+    pub fn promo_types_contains_any(&self) -> Option<&[String]> {
+        self.promo_types_contains_any.as_deref()
+    }
+
     // mana
     pub fn cmc_equals(&self) -> Option<f64> {
         self.cmc_equals
@@ -90,6 +134,14 @@ impl CardCriteria {
         self.cmc_range
     }
 
+    pub fn mana_cost_contains(&self) -> Option<&str> {
+        self.mana_cost_contains.as_deref()
+    }
+
+    pub fn mana_cost_equals(&self) -> Option<&str> {
+        self.mana_cost_equals.as_deref()
+    }
+
     // price
     pub fn price_min(&self) -> Option<f64> {
         self.price_min
@@ -103,10 +155,46 @@ impl CardCriteria {
         self.price_currency
     }
 
+    pub fn edhrec_rank_range(&self) -> Option<(i32, i32)> {
+        self.edhrec_rank_range
+    }
+
+    pub fn released_after(&self) -> Option<NaiveDate> {
+        self.released_after
+    }
+
+    pub fn released_before(&self) -> Option<NaiveDate> {
+        self.released_before
+    }
+
+    /// Both release date bounds, inclusive; `None` when neither is set.
+    pub fn released_range(&self) -> Option<(Option<NaiveDate>, Option<NaiveDate>)> {
+        if self.released_after.is_none() && self.released_before.is_none() {
+            return None;
+        }
+        Some((self.released_after, self.released_before))
+    }
+
     pub fn color_identity_equals(&self) -> Option<&Colors> {
         self.color_identity_equals.as_ref()
     }
 
+    pub fn color_count_equals(&self) -> Option<u8> {
+        self.color_count_equals
+    }
+
+    pub fn color_count_range(&self) -> Option<(u8, u8)> {
+        self.color_count_range
+    }
+
+    pub fn is_multicolor(&self) -> Option<bool> {
+        self.is_multicolor
+    }
+
+    pub fn is_colorless(&self) -> Option<bool> {
+        self.is_colorless
+    }
+
     pub fn color_identity_within(&self) -> Option<&Colors> {
         self.color_identity_within.as_ref()
     }
@@ -137,6 +225,38 @@ impl CardCriteria {
         self.toughness_range
     }
 
+    pub fn power_is_variable(&self) -> Option<bool> {
+        self.power_is_variable
+    }
+
+    pub fn toughness_is_variable(&self) -> Option<bool> {
+        self.toughness_is_variable
+    }
+
+    pub fn variable_stat_floor(&self) -> Option<i32> {
+        self.variable_stat_floor
+    }
+
+    pub fn loyalty_equals(&self) -> Option<i32> {
+        self.loyalty_equals
+    }
+
+    pub fn loyalty_range(&self) -> Option<(i32, i32)> {
+        self.loyalty_range
+    }
+
+    pub fn loyalty_is_variable(&self) -> Option<bool> {
+        self.loyalty_is_variable
+    }
+
+    pub fn defense_equals(&self) -> Option<i32> {
+        self.defense_equals
+    }
+
+    pub fn defense_range(&self) -> Option<(i32, i32)> {
+        self.defense_range
+    }
+
     // flags
     pub fn is_token(&self) -> Option<bool> {
         self.is_token
@@ -162,6 +282,18 @@ impl CardCriteria {
         self.content_warning
     }
 
+    pub fn game_changer(&self) -> Option<bool> {
+        self.game_changer
+    }
+
+    pub fn story_spotlight(&self) -> Option<bool> {
+        self.story_spotlight
+    }
+
+    pub fn textless(&self) -> Option<bool> {
+        self.textless
+    }
+
     pub fn language(&self) -> Option<&str> {
         self.language.as_deref()
     }
@@ -171,6 +303,10 @@ impl CardCriteria {
         self.legalities_contains_any.as_deref()
     }
 
+    pub fn legal_in(&self) -> Option<&[(Format, LegalityKind)]> {
+        self.legal_in.as_deref()
+    }
+
     // commander
     pub fn is_commander_in_format(&self) -> Option<&Format> {
         self.is_commander_in_format.as_ref()
@@ -3,18 +3,13 @@
 //! Mirrors the SQL adapter's filtering logic exactly, so the same criteria
 //! applied against a local collection agree with a server-side search.
 
-use crate::domain::{
-    card::{
-        Card,
-        scryfall_data::legalities::LegalityKind,
-        search_card::{
-            card_filter::{
-                criteria::CardCriteria, price_currency::PriceCurrency, strip_punctuation,
-            },
-            commander_eligibility::is_valid_commander,
-        },
+use crate::domain::card::{
+    Card,
+    scryfall_data::{legalities::LegalityKind, mana_cost::ManaCost},
+    search_card::{
+        card_filter::{criteria::CardCriteria, price_currency::PriceCurrency, strip_punctuation},
+        commander_eligibility::is_valid_commander,
     },
-    deck::Format,
 };
 
 /// Layouts representing cards playable in Magic formats.
@@ -39,8 +34,19 @@ pub const PLAYABLE_LAYOUTS: &[&str] = &[
     "case",
 ];
 
+/// Token and emblem layouts. Not playable in a deck, but what token-search
+/// features want alongside [`PLAYABLE_LAYOUTS`].
+pub const TOKEN_LAYOUTS: &[&str] = &["token", "double_faced_token", "emblem"];
+
+/// [`PLAYABLE_LAYOUTS`] plus [`TOKEN_LAYOUTS`]: the whitelist for searches
+/// that should surface tokens as "playable".
+pub fn playable_layouts_with_tokens() -> Vec<&'static str> {
+    [PLAYABLE_LAYOUTS, TOKEN_LAYOUTS].concat()
+}
+
 impl CardCriteria {
-    /// True if `card` satisfies every criterion set on `self`.
+    /// True if `card` satisfies every criterion set on `self`, judging
+    /// `is_playable` against the deck whitelist ([`PLAYABLE_LAYOUTS`]).
     ///
     /// Unset criteria (`None`) never exclude a card, so an empty criteria set
     /// matches everything. Parity note: `is_partner` / `is_background` /
@@ -48,6 +54,12 @@ impl CardCriteria {
     /// in-memory filter) — they are command-zone pool constraints only the
     /// server-side search applies.
     pub fn matches(&self, card: &Card) -> bool {
+        self.matches_with_layouts(card, PLAYABLE_LAYOUTS)
+    }
+
+    /// [`matches`](Self::matches) with `is_playable` judged against
+    /// `playable_layouts` instead of the deck whitelist.
+    pub fn matches_with_layouts(&self, card: &Card, playable_layouts: &[&str]) -> bool {
         let sd = &card.scryfall_data;
         let cp = &card.card_profile;
 
@@ -60,6 +72,31 @@ impl CardCriteria {
             return false;
         }
 
+        if let Some(q) = self.name_equals()
+            && sd.name.to_lowercase() != q.to_lowercase()
+        {
+            return false;
+        }
+
+        // Anywhere: name, type line, oracle or flavor text. The server also
+        // matches stemmed words ("goblins" finds "Goblin"); this is the
+        // substring half of its test.
+        if let Some(q) = self.text_search() {
+            let q = q.to_lowercase();
+            let found = [
+                Some(&sd.name),
+                sd.type_line.as_ref(),
+                sd.oracle_text.as_ref(),
+                sd.flavor_text.as_ref(),
+            ]
+            .into_iter()
+            .flatten()
+            .any(|text| strip_punctuation(text).to_lowercase().contains(&q));
+            if !found {
+                return false;
+            }
+        }
+
         if let Some(q) = self.name_not_contains()
             && strip_punctuation(&sd.name)
                 .to_lowercase()
@@ -406,10 +443,11 @@ impl CardCriteria {
 
         // ── legality (any-of-formats) ────────────────────────────────
         if let Some(formats) = self.legalities_contains_any() {
+            // Raw legality keys, like the SQL side's `legalities->>key`, so
+            // formats without a `Format` variant filter the same way.
             let is_legal_in_any = formats.iter().any(|format_key| {
-                Format::try_from(format_key.as_str())
-                    .ok()
-                    .and_then(|fmt| sd.legalities.get(&fmt).cloned())
+                sd.legalities
+                    .get_by_key(format_key)
                     .is_some_and(|k| matches!(k, LegalityKind::Legal | LegalityKind::Restricted))
             });
             if !is_legal_in_any {
@@ -417,6 +455,15 @@ impl CardCriteria {
             }
         }
 
+        // ── legality status per format (all must hold) ───────────────
+        if let Some(legal_in) = self.legal_in()
+            && !legal_in
+                .iter()
+                .all(|(format, status)| sd.legality(format) == *status)
+        {
+            return false;
+        }
+
         // ── commander eligibility ────────────────────────────────────
         if let Some(format) = self.is_commander_in_format()
             && !is_valid_commander(card, format)
@@ -440,14 +487,28 @@ impl CardCriteria {
             }
         }
 
+        if let Some(symbols) = self.mana_cost_contains() {
+            match &sd.mana_cost {
+                Some(cost) if cost.to_uppercase().contains(symbols) => {}
+                _ => return false,
+            }
+        }
+
+        if let Some(canonical) = self.mana_cost_equals() {
+            match &sd.mana_cost {
+                Some(cost) if ManaCost::parse(cost).canonical() == canonical => {}
+                _ => return false,
+            }
+        }
+
         // ── price ─────────────────────────────────────────────────────
         if self.price_min().is_some() || self.price_max().is_some() {
             let price = match self.price_currency().unwrap_or_default() {
-                PriceCurrency::Usd => sd.prices.usd.as_deref(),
-                PriceCurrency::Eur => sd.prices.eur.as_deref(),
-                PriceCurrency::Tix => sd.prices.tix.as_deref(),
+                PriceCurrency::Usd => sd.prices.usd(),
+                PriceCurrency::Eur => sd.prices.eur(),
+                PriceCurrency::Tix => sd.prices.tix(),
             }
-            .and_then(|p| p.parse::<f64>().ok());
+            .map(|p| p.to_f64());
             match price {
                 // A missing/unparseable price can't be confirmed in budget.
                 None => return false,
@@ -462,6 +523,24 @@ impl CardCriteria {
             }
         }
 
+        // ── popularity ────────────────────────────────────────────────
+        if let Some((min, max)) = self.edhrec_rank_range() {
+            let lo = min.min(max);
+            let hi = min.max(max);
+            match sd.edhrec_rank {
+                Some(rank) if rank >= lo && rank <= hi => {}
+                _ => return false,
+            }
+        }
+
+        // ── release date ──────────────────────────────────────────────
+        if let Some((after, before)) = self.released_range()
+            && (after.is_some_and(|d| sd.released_at < d)
+                || before.is_some_and(|d| sd.released_at > d))
+        {
+            return false;
+        }
+
         if let Some(filter_colors) = self.color_identity_equals() {
             let card_ci = &sd.color_identity;
             let set_eq = filter_colors.len() == card_ci.len()
@@ -477,6 +556,34 @@ impl CardCriteria {
             return false;
         }
 
+        // ── color count ───────────────────────────────────────────────
+        let color_count = sd.color_count();
+        if let Some(n) = self.color_count_equals()
+            && color_count != usize::from(n)
+        {
+            return false;
+        }
+
+        if let Some((min, max)) = self.color_count_range() {
+            let lo = usize::from(min.min(max));
+            let hi = usize::from(min.max(max));
+            if color_count < lo || color_count > hi {
+                return false;
+            }
+        }
+
+        if let Some(multicolor) = self.is_multicolor()
+            && (color_count > 1) != multicolor
+        {
+            return false;
+        }
+
+        if let Some(colorless) = self.is_colorless()
+            && (color_count == 0) != colorless
+        {
+            return false;
+        }
+
         // ── combat ────────────────────────────────────────────────────
         if let Some(val) = self.power_equals() {
             let parsed = sd.power.as_deref().and_then(|p| p.parse::<i32>().ok());
@@ -488,7 +595,12 @@ impl CardCriteria {
         if let Some((min, max)) = self.power_range() {
             let lo = min.min(max);
             let hi = min.max(max);
-            match sd.power.as_deref().and_then(|p| p.parse::<i32>().ok()) {
+            let floor = self.variable_stat_floor();
+            match sd
+                .power
+                .as_deref()
+                .and_then(|p| combat_stat_value(p, floor))
+            {
                 Some(p) if p >= lo && p <= hi => {}
                 _ => return false,
             }
@@ -504,12 +616,80 @@ impl CardCriteria {
         if let Some((min, max)) = self.toughness_range() {
             let lo = min.min(max);
             let hi = min.max(max);
-            match sd.toughness.as_deref().and_then(|t| t.parse::<i32>().ok()) {
+            let floor = self.variable_stat_floor();
+            match sd
+                .toughness
+                .as_deref()
+                .and_then(|t| combat_stat_value(t, floor))
+            {
                 Some(t) if t >= lo && t <= hi => {}
                 _ => return false,
             }
         }
 
+        if let Some(val) = self.power_is_variable()
+            && sd.power.as_deref().is_some_and(|p| p.contains('*')) != val
+        {
+            return false;
+        }
+
+        if let Some(val) = self.toughness_is_variable()
+            && sd.toughness.as_deref().is_some_and(|t| t.contains('*')) != val
+        {
+            return false;
+        }
+
+        // Loyalty matches digits only, like the SQL `^\d+$` guard, so `X`
+        // never matches a number.
+        let numeric_loyalty = sd
+            .loyalty
+            .as_deref()
+            .filter(|l| !l.is_empty() && l.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|l| l.parse::<i32>().ok());
+
+        if let Some(val) = self.loyalty_equals()
+            && numeric_loyalty != Some(val)
+        {
+            return false;
+        }
+
+        if let Some((min, max)) = self.loyalty_range() {
+            let lo = min.min(max);
+            let hi = min.max(max);
+            match numeric_loyalty {
+                Some(l) if l >= lo && l <= hi => {}
+                _ => return false,
+            }
+        }
+
+        if let Some(val) = self.loyalty_is_variable()
+            && (sd.loyalty.is_some() && numeric_loyalty.is_none()) != val
+        {
+            return false;
+        }
+
+        // Defense likewise matches digits only; non-battles have none.
+        let numeric_defense = sd
+            .defense
+            .as_deref()
+            .filter(|d| !d.is_empty() && d.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|d| d.parse::<i32>().ok());
+
+        if let Some(val) = self.defense_equals()
+            && numeric_defense != Some(val)
+        {
+            return false;
+        }
+
+        if let Some((min, max)) = self.defense_range() {
+            let lo = min.min(max);
+            let hi = min.max(max);
+            match numeric_defense {
+                Some(d) if d >= lo && d <= hi => {}
+                _ => return false,
+            }
+        }
+
         // ── metadata ──────────────────────────────────────────────────
         if let Some(rarities) = self.rarity_equals_any()
             && !rarities.contains(&sd.rarity)
@@ -535,6 +715,12 @@ impl CardCriteria {
             return false;
         }
 
+        if let Some(set_types) = self.set_type_equals_any()
+            && !set_types.iter().any(|t| t.as_str() == sd.set_type)
+        {
+            return false;
+        }
+
         if let Some(artists) = self.artist_equals_any()
             && !artists
                 .iter()
@@ -551,6 +737,58 @@ impl CardCriteria {
             return false;
         }
 
+        // No frame effects means a standard frame, which never matches.
+        if let Some(effects) = self.frame_effects_contains_any() {
+            let matches = match &sd.frame_effects {
+                Some(fe) => effects.iter().any(|e| fe.contains(e)),
+                None => false,
+            };
+            if !matches {
+                return false;
+            }
+        }
+
+        if let Some(borders) = self.border_color_equals_any()
+            && !borders.iter().any(|b| b == &sd.border_color)
+        {
+            return false;
+        }
+
+        if let Some(watermarks) = self.watermark_equals_any()
+            && !watermarks
+                .iter()
+                .any(|w| Some(w.as_str()) == sd.watermark.as_deref())
+        {
+            return false;
+        }
+
+        // No games list means no known availability, which never matches.
+        if let Some(game) = self.available_in_game() {
+            let available = match &sd.games {
+                Some(games) => games.iter().any(|g| g == game.as_str()),
+                None => false,
+            };
+            if !available {
+                return false;
+            }
+        }
+
+        if let Some(finish) = self.finish_available()
+            && !sd.finishes.iter().any(|f| f == finish.as_str())
+        {
+            return false;
+        }
+
+        if let Some(promo_types) = self.promo_types_contains_any() {
+            let matches = match &sd.promo_types {
+                Some(pt) => promo_types.iter().any(|p| pt.contains(p)),
+                None => false,
+            };
+            if !matches {
+                return false;
+            }
+        }
+
         if let Some(lang) = self.language()
             && sd.lang != lang
         {
@@ -566,7 +804,7 @@ impl CardCriteria {
         }
 
         if let Some(want_playable) = self.is_playable() {
-            let is_playable = PLAYABLE_LAYOUTS.contains(&sd.layout.as_str());
+            let is_playable = playable_layouts.contains(&sd.layout.as_str());
             if is_playable != want_playable {
                 return false;
             }
@@ -597,6 +835,47 @@ impl CardCriteria {
             }
         }
 
+        if let Some(val) = self.game_changer()
+            && sd.game_changer.unwrap_or(false) != val
+        {
+            return false;
+        }
+
+        if let Some(val) = self.story_spotlight()
+            && sd.story_spotlight != val
+        {
+            return false;
+        }
+
+        if let Some(val) = self.textless()
+            && sd.textless != val
+        {
+            return false;
+        }
+
         true
     }
 }
+
+/// The value a printed power/toughness has in a range filter.
+///
+/// Plain integers count as themselves. With a `floor`, `*` counts as `floor`
+/// and `N+*` / `*+N` as `N + floor`; without one, or for any other shape
+/// (`*²`, `7-*`, `?`), the stat has no value and matches no range. The SQL
+/// adapter resolves stats the same way.
+fn combat_stat_value(stat: &str, floor: Option<i32>) -> Option<i32> {
+    if let Ok(value) = stat.parse::<i32>() {
+        return Some(value);
+    }
+    let floor = floor?;
+    if stat == "*" {
+        return Some(floor);
+    }
+    let fixed = stat
+        .strip_suffix("+*")
+        .or_else(|| stat.strip_prefix("*+"))?;
+    if !fixed.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    fixed.parse::<i32>().ok()?.checked_add(floor)
+}
@@ -6,20 +6,27 @@
 //! operations for in-memory filtering. It carries **no pagination and no
 //! ordering** — those are query concerns.
 
+/// Plain-words summary of the active criteria: `CardCriteria::describe()`.
+pub mod describe;
 /// Getter methods for accessing criteria values.
 pub mod getters;
 /// The in-memory predicate: `CardCriteria::matches(&Card)`.
 pub mod matches;
 
-pub use matches::PLAYABLE_LAYOUTS;
+pub use describe::FilterDescription;
+pub use matches::{PLAYABLE_LAYOUTS, TOKEN_LAYOUTS, playable_layouts_with_tokens};
 
 use crate::domain::{
     card::{
-        scryfall_data::{colors::Colors, rarity::Rarities},
+        scryfall_data::{
+            colors::Colors, finish::Finish, game::Game, legalities::LegalityKind, rarity::Rarities,
+            set_type::SetType,
+        },
         search_card::{card_filter::price_currency::PriceCurrency, card_type::CardType},
     },
     deck::Format,
 };
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -37,15 +44,36 @@ pub struct CardCriteria {
     pub(super) power_range: Option<(i32, i32)>,
     pub(super) toughness_equals: Option<i32>,
     pub(super) toughness_range: Option<(i32, i32)>,
+    pub(super) power_is_variable: Option<bool>,
+    pub(super) toughness_is_variable: Option<bool>,
+    pub(super) variable_stat_floor: Option<i32>,
+    pub(super) loyalty_equals: Option<i32>,
+    pub(super) loyalty_range: Option<(i32, i32)>,
+    pub(super) loyalty_is_variable: Option<bool>,
+    pub(super) defense_equals: Option<i32>,
+    pub(super) defense_range: Option<(i32, i32)>,
     // mana
     pub(super) cmc_equals: Option<f64>,
     pub(super) cmc_range: Option<(f64, f64)>,
     pub(super) color_identity_within: Option<Colors>,
     pub(super) color_identity_equals: Option<Colors>,
-    // price (min/max against the selected currency's price)
+    // color count (printed colors, see ScryfallData::color_count)
+    pub(super) color_count_equals: Option<u8>,
+    pub(super) color_count_range: Option<(u8, u8)>,
+    pub(super) is_multicolor: Option<bool>,
+    pub(super) is_colorless: Option<bool>,
+    pub(super) mana_cost_contains: Option<String>,
+    pub(super) mana_cost_equals: Option<String>,
+    // price (min/max against the selected currency's price on this printing,
+    // not the card's cheapest printing; unpriced cards never match)
     pub(super) price_min: Option<f64>,
     pub(super) price_max: Option<f64>,
     pub(super) price_currency: Option<PriceCurrency>,
+    // popularity (EDHREC rank; lower = more popular)
+    pub(super) edhrec_rank_range: Option<(i32, i32)>,
+    // release date (inclusive bounds)
+    pub(super) released_after: Option<NaiveDate>,
+    pub(super) released_before: Option<NaiveDate>,
     // produced mana
     pub(super) produced_mana_contains_any: Option<Vec<String>>,
     pub(super) produced_mana_contains_all: Option<Vec<String>>,
@@ -56,11 +84,24 @@ pub struct CardCriteria {
     // set
     pub(super) set_equals_any: Option<Vec<String>>,
     pub(super) set_excludes_any: Option<Vec<String>>,
+    // set type
+    pub(super) set_type_equals_any: Option<Vec<SetType>>,
     // artist
     pub(super) artist_equals_any: Option<Vec<String>>,
     pub(super) artist_excludes_any: Option<Vec<String>>,
+    // frame/border/watermark
+    pub(super) frame_effects_contains_any: Option<Vec<String>>,
+    pub(super) border_color_equals_any: Option<Vec<String>>,
+    pub(super) watermark_equals_any: Option<Vec<String>>,
+    // availability
+    pub(super) available_in_game: Option<Game>,
+    pub(super) finish_available: Option<Finish>,
+    // promo types
+    pub(super) promo_types_contains_any: Option<Vec<String>>,
     // text
     pub(super) name_contains: Option<String>,
+    pub(super) name_equals: Option<String>,
+    pub(super) text_search: Option<String>,
     pub(super) name_not_contains: Option<String>,
     pub(super) oracle_text_contains: Option<String>,
     pub(super) oracle_text_not_contains: Option<String>,
@@ -90,9 +131,13 @@ pub struct CardCriteria {
     pub(super) oversized: Option<bool>,
     pub(super) promo: Option<bool>,
     pub(super) content_warning: Option<bool>,
+    pub(super) game_changer: Option<bool>,
+    pub(super) story_spotlight: Option<bool>,
+    pub(super) textless: Option<bool>,
     pub(super) language: Option<String>,
     // legalities
     pub(super) legalities_contains_any: Option<Vec<String>>,
+    pub(super) legal_in: Option<Vec<(Format, LegalityKind)>>,
     // commander
     pub(super) is_commander_in_format: Option<Format>,
     // partner/background/spell
@@ -109,9 +154,430 @@ pub struct CardCriteria {
     pub(super) oracle_tags_excludes: Option<Vec<String>>,
 }
 
+impl CardCriteria {
+    /// Whether nothing is set beyond the config flags (token, playable,
+    /// digital, oversized, promo, content warning, language, price currency)
+    /// — the same emptiness [`CardQueryBuilder::is_empty`](super::builder::CardQueryBuilder::is_empty)
+    /// judges.
+    pub fn is_empty(&self) -> bool {
+        let config_only = Self {
+            is_token: self.is_token,
+            is_playable: self.is_playable,
+            digital: self.digital,
+            oversized: self.oversized,
+            promo: self.promo,
+            content_warning: self.content_warning,
+            language: self.language.clone(),
+            price_currency: self.price_currency,
+            ..Self::default()
+        };
+        *self == config_only
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::CardCriteria;
+    use crate::{
+        domain::card::search_card::card_filter::builder::CardQueryBuilder, test_utils::make_card,
+    };
+
+    fn creature(name: &str, power: &str, toughness: &str) -> crate::domain::card::Card {
+        let mut card = make_card(name);
+        card.scryfall_data.power = Some(power.to_string());
+        card.scryfall_data.toughness = Some(toughness.to_string());
+        card
+    }
+
+    #[test]
+    fn variable_stats_match_is_variable_and_floored_ranges() {
+        let goyf = creature("Tarmogoyf", "*", "1+*");
+        let bear = creature("Grizzly Bears", "2", "2");
+        let squared = creature("S.N.O.T.", "*²", "*²");
+        let sorcery = make_card("Divination");
+
+        let variable = CardQueryBuilder::with_power_is_variable(true)
+            .build()
+            .unwrap();
+        assert!(variable.criteria().matches(&goyf));
+        assert!(variable.criteria().matches(&squared));
+        assert!(!variable.criteria().matches(&bear));
+        let fixed = CardQueryBuilder::with_toughness_is_variable(false)
+            .build()
+            .unwrap();
+        assert!(!fixed.criteria().matches(&goyf));
+        assert!(fixed.criteria().matches(&bear));
+        assert!(fixed.criteria().matches(&sorcery));
+
+        // without a floor, ranges keep ignoring `*`
+        let mut builder = CardQueryBuilder::with_toughness_range((1, 3));
+        assert!(!builder.build().unwrap().criteria().matches(&goyf));
+
+        // with floor 1: `*` is 1, `1+*` is 2, `*²` never resolves
+        builder.set_variable_stat_floor(1);
+        let ranged = builder.build().unwrap();
+        assert!(ranged.criteria().matches(&goyf));
+        assert!(ranged.criteria().matches(&bear));
+        assert!(!ranged.criteria().matches(&squared));
+        let mut builder = CardQueryBuilder::with_toughness_range((3, 5));
+        builder.set_variable_stat_floor(1);
+        assert!(!builder.build().unwrap().criteria().matches(&goyf));
+        let mut builder = CardQueryBuilder::with_power_range((0, 0));
+        builder.set_variable_stat_floor(0);
+        assert!(builder.build().unwrap().criteria().matches(&goyf));
+    }
+
+    #[test]
+    fn loyalty_filters_skip_x_but_find_it_as_variable() {
+        let mut jace = make_card("Jace Beleren");
+        jace.scryfall_data.loyalty = Some("3".to_string());
+        let mut nissa = make_card("Nissa, Steward of Elements");
+        nissa.scryfall_data.loyalty = Some("X".to_string());
+        let bolt = make_card("Lightning Bolt");
+
+        let three = CardQueryBuilder::with_loyalty_equals(3).build().unwrap();
+        assert!(three.criteria().matches(&jace));
+        assert!(!three.criteria().matches(&nissa));
+        let range = CardQueryBuilder::with_loyalty_range((5, 0))
+            .build()
+            .unwrap();
+        assert!(range.criteria().matches(&jace));
+        assert!(!range.criteria().matches(&nissa));
+        assert!(!range.criteria().matches(&bolt));
+
+        let x = CardQueryBuilder::with_loyalty_is_variable(true)
+            .build()
+            .unwrap();
+        assert!(x.criteria().matches(&nissa));
+        assert!(!x.criteria().matches(&jace));
+        assert!(!x.criteria().matches(&bolt));
+        let fixed = CardQueryBuilder::with_loyalty_is_variable(false)
+            .build()
+            .unwrap();
+        assert!(fixed.criteria().matches(&jace));
+        assert!(fixed.criteria().matches(&bolt));
+    }
+
+    #[test]
+    fn edhrec_rank_range_is_inclusive_and_skips_unranked() {
+        let mut sol_ring = make_card("Sol Ring");
+        sol_ring.scryfall_data.edhrec_rank = Some(1);
+        let mut arcane_signet = make_card("Arcane Signet");
+        arcane_signet.scryfall_data.edhrec_rank = Some(100);
+        let unranked = make_card("Brand New Card");
+
+        let top = CardQueryBuilder::with_edhrec_rank_range((100, 1))
+            .build()
+            .unwrap();
+        assert!(top.criteria().matches(&sol_ring));
+        assert!(top.criteria().matches(&arcane_signet));
+        assert!(!top.criteria().matches(&unranked));
+        let narrow = CardQueryBuilder::with_edhrec_rank_range((2, 99))
+            .build()
+            .unwrap();
+        assert!(!narrow.criteria().matches(&sol_ring));
+        assert!(!narrow.criteria().matches(&arcane_signet));
+    }
+
+    #[test]
+    fn text_search_matches_any_text_field() {
+        let mut bolt = make_card("Lightning Bolt");
+        bolt.scryfall_data.oracle_text = Some("Deals 3 damage to any target.".to_string());
+        let mut guide = make_card("Goblin Guide");
+        guide.scryfall_data.type_line = Some("Creature — Goblin Scout".to_string());
+        let mut forest = make_card("Forest");
+        forest.scryfall_data.flavor_text = Some("Where goblins fear to tread.".to_string());
+
+        let goblin = CardQueryBuilder::with_text_search("goblin")
+            .build()
+            .unwrap();
+        assert!(!goblin.criteria().matches(&bolt));
+        assert!(goblin.criteria().matches(&guide));
+        assert!(goblin.criteria().matches(&forest));
+
+        let scout = CardQueryBuilder::with_text_search("scout").build().unwrap();
+        assert!(scout.criteria().matches(&guide));
+        let damage = CardQueryBuilder::with_text_search("3 damage")
+            .build()
+            .unwrap();
+        assert!(damage.criteria().matches(&bolt));
+        assert!(!damage.criteria().matches(&guide));
+    }
+
+    #[test]
+    fn released_bounds_are_inclusive_and_work_alone() {
+        use chrono::NaiveDate;
+
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let mut old = make_card("Counterspell");
+        old.scryfall_data.released_at = date(1995, 1, 1);
+        let mut new = make_card("Consider");
+        new.scryfall_data.released_at = date(2021, 9, 24);
+
+        let mut window = CardQueryBuilder::with_released_after(date(1995, 1, 1));
+        window.set_released_before(date(2021, 9, 24));
+        let window = window.build().unwrap();
+        assert!(window.criteria().matches(&old));
+        assert!(window.criteria().matches(&new));
+
+        let recent = CardQueryBuilder::with_released_after(date(2020, 1, 1))
+            .build()
+            .unwrap();
+        assert_eq!(
+            recent.criteria().released_range(),
+            Some((Some(date(2020, 1, 1)), None))
+        );
+        assert!(!recent.criteria().matches(&old));
+        assert!(recent.criteria().matches(&new));
+
+        let vintage = CardQueryBuilder::with_released_before(date(2021, 9, 23))
+            .build()
+            .unwrap();
+        assert!(vintage.criteria().matches(&old));
+        assert!(!vintage.criteria().matches(&new));
+    }
+
+    #[test]
+    fn color_count_filters_count_printed_colors() {
+        use crate::domain::card::scryfall_data::colors::Colors;
+
+        let colored = |name: &str, colors: &[&str]| {
+            let mut card = make_card(name);
+            let names = colors.iter().map(|c| c.to_string()).collect();
+            card.scryfall_data.colors = Some(Colors::from_short_names(names).unwrap());
+            card
+        };
+        let cards = [
+            colored("Sol Ring", &[]),
+            colored("Lightning Bolt", &["R"]),
+            colored("Lightning Helix", &["R", "W"]),
+        ];
+        let kept = |builder: CardQueryBuilder| {
+            let query = builder.build().unwrap();
+            cards
+                .iter()
+                .filter(|c| query.criteria().matches(c))
+                .map(|c| c.scryfall_data.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        assert_eq!(
+            kept(CardQueryBuilder::with_color_count_equals(1)),
+            "Lightning Bolt"
+        );
+        assert_eq!(
+            kept(CardQueryBuilder::with_color_count_range((2, 1))),
+            "Lightning Bolt, Lightning Helix"
+        );
+        assert_eq!(
+            kept(CardQueryBuilder::with_is_multicolor(true)),
+            "Lightning Helix"
+        );
+        assert_eq!(
+            kept(CardQueryBuilder::with_is_multicolor(false)),
+            "Sol Ring, Lightning Bolt"
+        );
+        assert_eq!(kept(CardQueryBuilder::with_is_colorless(true)), "Sol Ring");
+    }
+
+    #[test]
+    fn frame_and_border_filters_skip_standard_printings() {
+        let mut showcase = make_card("Showcase Bolt");
+        showcase.scryfall_data.frame_effects = Some(vec!["showcase".to_string()]);
+        showcase.scryfall_data.border_color = "black".to_string();
+        let mut borderless = make_card("Borderless Bolt");
+        borderless.scryfall_data.border_color = "borderless".to_string();
+
+        let frame = CardQueryBuilder::with_frame_effects_contains_any(["showcase", "etched"])
+            .build()
+            .unwrap();
+        assert!(frame.criteria().matches(&showcase));
+        assert!(!frame.criteria().matches(&borderless));
+
+        let border = CardQueryBuilder::with_border_color_equals_any(["borderless"])
+            .build()
+            .unwrap();
+        assert!(!border.criteria().matches(&showcase));
+        assert!(border.criteria().matches(&borderless));
+    }
+
+    #[test]
+    fn game_availability_skips_cards_with_no_known_games() {
+        use crate::domain::card::scryfall_data::game::Game;
+
+        let mut paper = make_card("Paper Only");
+        paper.scryfall_data.games = Some(vec!["paper".to_string()]);
+        let mut both = make_card("Paper And Arena");
+        both.scryfall_data.games = Some(vec!["paper".to_string(), "arena".to_string()]);
+        let unknown = make_card("Unknown");
+
+        let arena = CardQueryBuilder::with_available_in_game(Game::Arena)
+            .build()
+            .unwrap();
+        assert!(!arena.criteria().matches(&paper));
+        assert!(arena.criteria().matches(&both));
+        assert!(!arena.criteria().matches(&unknown));
+
+        let on_paper = CardQueryBuilder::with_available_in_game(Game::Paper)
+            .build()
+            .unwrap();
+        assert!(on_paper.criteria().matches(&paper));
+        assert!(on_paper.criteria().matches(&both));
+        assert!(!on_paper.criteria().matches(&unknown));
+    }
+
+    #[test]
+    fn finish_filter_checks_printed_finishes() {
+        use crate::domain::card::scryfall_data::finish::Finish;
+
+        let mut nonfoil = make_card("Nonfoil Bolt");
+        nonfoil.scryfall_data.finishes = vec!["nonfoil".to_string()];
+        let mut etched = make_card("Etched Bolt");
+        etched.scryfall_data.finishes = vec!["nonfoil".to_string(), "etched".to_string()];
+
+        let query = CardQueryBuilder::with_finish_available(Finish::Etched)
+            .build()
+            .unwrap();
+        assert!(!query.criteria().matches(&nonfoil));
+        assert!(query.criteria().matches(&etched));
+    }
+
+    #[test]
+    fn produces_filters_take_colors_and_skip_cards_without_mana() {
+        use crate::domain::card::scryfall_data::colors::Color;
+
+        let mut forest = make_card("Forest");
+        forest.scryfall_data.produced_mana = Some(vec!["G".to_string()]);
+        let mut temple = make_card("Temple of Mystery");
+        temple.scryfall_data.produced_mana = Some(vec!["G".to_string(), "U".to_string()]);
+        let bolt = make_card("Lightning Bolt");
+
+        let mut any = CardQueryBuilder::new();
+        any.set_produces_any([Color::Green, Color::Red]);
+        let any = any.build().unwrap();
+        assert_eq!(
+            any.criteria().produced_mana_contains_any(),
+            Some(&["G".to_string(), "R".to_string()][..])
+        );
+        assert!(any.criteria().matches(&forest));
+        assert!(any.criteria().matches(&temple));
+        assert!(!any.criteria().matches(&bolt));
+
+        let mut all = CardQueryBuilder::new();
+        all.set_produces_all([Color::Green, Color::Blue]);
+        let all = all.build().unwrap();
+        assert!(!all.criteria().matches(&forest));
+        assert!(all.criteria().matches(&temple));
+        assert!(!all.criteria().matches(&bolt));
+    }
+
+    #[test]
+    fn legal_in_requires_every_format_status_and_reads_missing_as_not_legal() {
+        use crate::domain::{card::scryfall_data::legalities::LegalityKind, deck::Format};
+
+        let mut sol_ring = make_card("Sol Ring");
+        sol_ring.scryfall_data.legalities =
+            serde_json::from_str(r#"{"commander":"legal","legacy":"banned"}"#).unwrap();
+        let unlisted = make_card("Unlisted");
+
+        let banned = CardQueryBuilder::with_legal_in(Format::Legacy, LegalityKind::Banned)
+            .build()
+            .unwrap();
+        assert!(banned.criteria().matches(&sol_ring));
+        assert!(!banned.criteria().matches(&unlisted));
+
+        let mut both = CardQueryBuilder::with_legal_in(Format::Legacy, LegalityKind::Legal);
+        // replaces the Legal status set above rather than adding a second one
+        both.set_legal_in(Format::Legacy, LegalityKind::Banned)
+            .set_legal_in(Format::Commander, LegalityKind::Legal);
+        assert_eq!(both.legal_in().map(<[_]>::len), Some(2));
+        assert!(both.build().unwrap().criteria().matches(&sol_ring));
+
+        let not_legal = CardQueryBuilder::with_legal_in(Format::Modern, LegalityKind::NotLegal)
+            .build()
+            .unwrap();
+        assert!(not_legal.criteria().matches(&sol_ring));
+        assert!(not_legal.criteria().matches(&unlisted));
+    }
+
+    #[test]
+    fn price_range_compares_the_chosen_currency_and_skips_unpriced() {
+        let mut bolt = make_card("Lightning Bolt");
+        bolt.scryfall_data.prices.usd = Some("1.50".to_string());
+        bolt.scryfall_data.prices.eur = Some("9.00".to_string());
+        let mut ring = make_card("Sol Ring");
+        ring.scryfall_data.prices.eur = Some("".to_string());
+        let unpriced = make_card("Unpriced");
+
+        let mut under_five = CardQueryBuilder::new();
+        under_five.set_price_usd_range((5.0, 0.0));
+        assert_eq!(under_five.price_min(), Some(0.0));
+        assert_eq!(under_five.price_max(), Some(5.0));
+        let under_five = under_five.build().unwrap();
+        assert!(under_five.criteria().matches(&bolt));
+        assert!(!under_five.criteria().matches(&ring));
+        assert!(!under_five.criteria().matches(&unpriced));
+
+        let mut eur = CardQueryBuilder::new();
+        eur.set_price_eur_range((0.0, 5.0));
+        assert!(!eur.build().unwrap().criteria().matches(&bolt));
+        eur.set_price_eur_range((5.0, 10.0));
+        let eur = eur.build().unwrap();
+        assert!(eur.criteria().matches(&bolt));
+        assert!(!eur.criteria().matches(&ring));
+
+        let mut cleared = CardQueryBuilder::with_name_contains("priced");
+        cleared.set_price_tix_range((0.0, 1.0)).unset_price_range();
+        assert!(cleared.build().unwrap().criteria().matches(&unpriced));
+    }
+
+    #[test]
+    fn game_changer_filter_treats_missing_flag_as_false() {
+        let mut rhystic = make_card("Rhystic Study");
+        rhystic.scryfall_data.game_changer = Some(true);
+        let normal = make_card("Phyrexian Rager");
+        let mut unflagged = make_card("Divination");
+        unflagged.scryfall_data.game_changer = Some(false);
+
+        let only = CardQueryBuilder::with_game_changer(true).build().unwrap();
+        assert!(only.criteria().matches(&rhystic));
+        assert!(!only.criteria().matches(&normal));
+
+        let avoid = CardQueryBuilder::with_game_changer(false).build().unwrap();
+        assert!(!avoid.criteria().matches(&rhystic));
+        assert!(avoid.criteria().matches(&normal));
+        assert!(avoid.criteria().matches(&unflagged));
+    }
+
+    #[test]
+    fn power_budget_drops_game_changers_and_top_staples() {
+        let mut rhystic = make_card("Rhystic Study");
+        rhystic.scryfall_data.game_changer = Some(true);
+        rhystic.scryfall_data.edhrec_rank = Some(400);
+        let mut sol_ring = make_card("Sol Ring");
+        sol_ring.scryfall_data.edhrec_rank = Some(1);
+        let mut brew = make_card("Goblin Bushwhacker");
+        brew.scryfall_data.edhrec_rank = Some(2500);
+
+        let mut builder = CardQueryBuilder::new();
+        builder
+            .set_exclude_game_changers(true)
+            .set_min_edhrec_rank(100);
+        let budget = builder.build().unwrap();
+        assert!(!budget.criteria().matches(&rhystic));
+        assert!(!budget.criteria().matches(&sol_ring));
+        assert!(budget.criteria().matches(&brew));
+
+        builder
+            .set_max_edhrec_rank(2000)
+            .set_exclude_game_changers(false);
+        assert_eq!(builder.edhrec_rank_range(), Some((100, 2000)));
+        assert_eq!(builder.game_changer(), None);
+        let budget = builder.build().unwrap();
+        assert!(budget.criteria().matches(&rhystic));
+        assert!(!budget.criteria().matches(&brew));
+    }
 
     #[test]
     fn accepts_card_roles_keys() {
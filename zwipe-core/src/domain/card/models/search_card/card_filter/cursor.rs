@@ -0,0 +1,141 @@
+//! Keyset pagination for the server card search.
+//!
+//! Offset pagination makes the database walk and discard every row before
+//! the page, so deep pages of the ~90k-card pool get slow, and a card synced
+//! in between shifts every later page. A [`Cursor`] instead names the last
+//! card served — its sort value and id — and the next page starts strictly
+//! after it (`WHERE (sort_col, id) > (value, id)`), riding the same ordering
+//! from any depth.
+//!
+//! A cursor only works for an explicit sort other than `Random` or
+//! `Relevance`, with no secondary sort: those orderings end in an id
+//! tiebreak, so each row has exactly one successor. On the wire it's an opaque hex string.
+
+use crate::domain::card::{
+    Card, scryfall_data::ScryfallData, search_card::card_filter::card_sort_key::CardSortKey,
+};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Error returned when a cursor string doesn't decode.
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+#[error("invalid cursor")]
+pub struct InvalidCursor;
+
+/// Position after the last card of a search page: the card's value under
+/// the page's sort key, and its id as the tiebreak.
+#[derive(Debug, Clone, PartialEq, Eq, SerializeDisplay, DeserializeFromStr)]
+pub struct Cursor {
+    sort: CardSortKey,
+    value: String,
+    id: Uuid,
+}
+
+impl Cursor {
+    /// The cursor after `card` in a search sorted by `sort`, or `None` for
+    /// `Random` (a shuffle has no position to resume from), `Relevance` (the
+    /// rank isn't stored on the card), and for cards the search would have
+    /// filtered out for lacking the sort value.
+    pub fn after(sort: CardSortKey, card: &ScryfallData) -> Option<Self> {
+        let value = match sort {
+            CardSortKey::Name => card.name.clone(),
+            // the search sorts a missing mana value where float8 puts infinity
+            CardSortKey::Cmc => card
+                .cmc
+                .map_or_else(|| "Infinity".to_string(), |cmc| cmc.to_string()),
+            CardSortKey::Power => card.power.clone()?,
+            CardSortKey::Toughness => card.toughness.clone()?,
+            CardSortKey::Loyalty => card.loyalty.clone()?,
+            CardSortKey::Defense => card.defense.clone()?,
+            CardSortKey::Rarity => card.rarity.to_short_name(),
+            CardSortKey::ReleasedAt => card.released_at.to_string(),
+            CardSortKey::PriceUsd => card.prices.usd.clone()?,
+            CardSortKey::PriceEur => card.prices.eur.clone()?,
+            CardSortKey::PriceTix => card.prices.tix.clone()?,
+            CardSortKey::EdhrecRank => card.edhrec_rank?.to_string(),
+            CardSortKey::Random | CardSortKey::Relevance => return None,
+        };
+        Some(Self {
+            sort,
+            value,
+            id: card.id,
+        })
+    }
+
+    /// The cursor after the last card of `page`.
+    pub fn after_page(sort: CardSortKey, page: &[Card]) -> Option<Self> {
+        Self::after(sort, &page.last()?.scryfall_data)
+    }
+
+    /// The sort key the cursor was taken under.
+    pub fn sort(&self) -> CardSortKey {
+        self.sort
+    }
+
+    /// The last card's value under [`sort`](Self::sort), as the database
+    /// stores or computes it (text, to be cast back by the search).
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The last card's id.
+    pub fn id(&self) -> Uuid {
+        self.id
+    }
+}
+
+impl std::fmt::Display for Cursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts =
+            serde_json::to_vec(&(self.sort, &self.value, self.id)).map_err(|_| std::fmt::Error)?;
+        f.write_str(&hex::encode(parts))
+    }
+}
+
+impl std::str::FromStr for Cursor {
+    type Err = InvalidCursor;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(s.trim()).map_err(|_| InvalidCursor)?;
+        let (sort, value, id): (CardSortKey, String, Uuid) =
+            serde_json::from_slice(&bytes).map_err(|_| InvalidCursor)?;
+        if matches!(sort, CardSortKey::Random | CardSortKey::Relevance) {
+            return Err(InvalidCursor);
+        }
+        Ok(Self { sort, value, id })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_utils::make_card;
+
+    #[test]
+    fn round_trips_through_its_wire_string() {
+        let mut card = make_card("Ætherize").scryfall_data;
+        card.cmc = Some(4.0);
+        for sort in [CardSortKey::Name, CardSortKey::Cmc, CardSortKey::Rarity] {
+            let cursor = Cursor::after(sort, &card).unwrap();
+            let wire = cursor.to_string();
+            assert!(wire.bytes().all(|b| b.is_ascii_hexdigit()));
+            assert_eq!(wire.parse::<Cursor>(), Ok(cursor));
+        }
+        assert_eq!("not a cursor".parse::<Cursor>(), Err(InvalidCursor));
+    }
+
+    #[test]
+    fn values_match_what_the_search_sorts_on() {
+        let mut card = make_card("Sol Ring").scryfall_data;
+        card.cmc = None;
+        card.edhrec_rank = Some(1);
+        let value = |sort| Cursor::after(sort, &card).map(|c| c.value().to_string());
+        assert_eq!(value(CardSortKey::Name).as_deref(), Some("Sol Ring"));
+        assert_eq!(value(CardSortKey::Cmc).as_deref(), Some("Infinity"));
+        assert_eq!(value(CardSortKey::EdhrecRank).as_deref(), Some("1"));
+        assert_eq!(value(CardSortKey::Power), None);
+        assert_eq!(value(CardSortKey::Random), None);
+        assert_eq!(value(CardSortKey::Relevance), None);
+    }
+}
@@ -16,4 +16,12 @@ pub enum InvalidCardCriteria {
         /// The clashing values, comma-separated.
         values: String,
     },
+    /// `Random` was given as the secondary sort key — a shuffle can't break
+    /// ties in a meaningful order.
+    #[error("secondary sort cannot be Random")]
+    RandomSecondarySort,
+    /// A cursor was given for a query ordered differently than the page it
+    /// was taken from, or with a secondary sort (which cursors can't resume).
+    #[error("cursor does not match the query's sort")]
+    CursorSortMismatch,
 }
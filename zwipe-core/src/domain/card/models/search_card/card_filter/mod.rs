@@ -3,7 +3,8 @@
 //! Two types share one predicate core, [`CardCriteria`]:
 //!
 //! - [`CardQuery`] — the **database query** POSTed to the server: criteria plus
-//!   bounded pagination ([`query::Limit`]) and ordering.
+//!   bounded pagination ([`query::Limit`], an offset or a [`Cursor`]) and
+//!   ordering.
 //! - [`Cards`](crate::domain::card::search_card::cards::Cards) — the
 //!   **in-memory collection**, whose operations take bare criteria and cannot
 //!   express a limit.
@@ -17,15 +18,18 @@ pub mod builder;
 pub mod card_sort_key;
 /// The shared predicate core (~50 criteria fields) and `matches()`.
 pub mod criteria;
+/// Keyset pagination cursor for the server search.
+pub mod cursor;
 /// Criteria validation errors.
 pub mod error;
 /// Currency selector for the price-range filter.
 pub mod price_currency;
-/// The server search request: criteria + `Limit` + offset + sort.
+/// The server search request: criteria + `Limit` + offset/cursor + sort.
 pub mod query;
 
 pub use card_sort_key::CardSortKey;
 pub use criteria::CardCriteria;
+pub use cursor::Cursor;
 pub use query::{CardQuery, Limit};
 
 /// Strips punctuation from a string, keeping only alphanumeric characters and whitespace.
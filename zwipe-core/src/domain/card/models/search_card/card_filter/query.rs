@@ -3,14 +3,25 @@
 //! [`CardQuery`] is what zwiper POSTs to `/api/card/search` and the deck-aware
 //! search. Its [`CardCriteria`] is `#[serde(flatten)]`ed, so the wire JSON is
 //! identical to the pre-split `CardFilter` (criteria fields at the top level
-//! alongside `limit` / `offset` / `order_by` / `ascending` / `synergy`).
+//! alongside `limit` / `offset` / `order_by` / `ascending` / `synergy`, plus the
+//! optional `then_order_by`).
+//!
+//! Pages are addressed by `offset` or by `after_cursor`, a [`Cursor`] from the
+//! previous page. Offsets stay for older clients, but the database still
+//! walks every skipped row, so deep offset pages get slow and shift when
+//! cards sync in between; a cursor resumes right after the last card served.
 //!
 //! `limit` is untrusted pagination input, so it is a clamping [`Limit`] — the
 //! in-memory path ([`Cards`](crate::domain::card::search_card::cards::Cards))
 //! cannot express a limit at all.
 
-use crate::domain::card::search_card::card_filter::{
-    card_sort_key::CardSortKey, criteria::CardCriteria,
+use crate::domain::card::{
+    Card,
+    scryfall_data::language::Language,
+    search_card::card_filter::{
+        card_sort_key::CardSortKey, criteria::CardCriteria, cursor::Cursor,
+        error::InvalidCardCriteria,
+    },
 };
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_with::skip_serializing_none;
@@ -73,9 +84,17 @@ pub struct CardQuery {
     limit: Limit,
     #[serde(default)]
     offset: u32,
+    /// Keyset page position; when set, `offset` is ignored. Serialized as
+    /// `after_cursor`.
+    #[serde(rename = "after_cursor", default)]
+    after: Option<Cursor>,
     /// Sort key; serialized as `order_by` to preserve the wire contract.
     #[serde(rename = "order_by")]
     sort: Option<CardSortKey>,
+    /// Secondary sort key, breaking ties left by `sort` in the same
+    /// direction; serialized as `then_order_by`. Never `Random`.
+    #[serde(rename = "then_order_by", default)]
+    then_sort: Option<CardSortKey>,
     #[serde(default = "default_ascending")]
     ascending: bool,
     /// Deck-aware search only: when true, constrain results to the commander's
@@ -90,6 +109,21 @@ fn default_ascending() -> bool {
     true
 }
 
+/// A cursor resumes only the ordering it was taken under: the same sort key
+/// and no secondary sort.
+pub(super) fn check_cursor(
+    after: Option<&Cursor>,
+    sort: Option<CardSortKey>,
+    then_sort: Option<CardSortKey>,
+) -> Result<(), InvalidCardCriteria> {
+    match after {
+        Some(cursor) if sort != Some(cursor.sort()) || then_sort.is_some() => {
+            Err(InvalidCardCriteria::CursorSortMismatch)
+        }
+        _ => Ok(()),
+    }
+}
+
 impl CardQuery {
     /// Assembles a query from already-validated criteria plus config. Only the
     /// builder constructs these (validation lives there).
@@ -98,6 +132,7 @@ impl CardQuery {
         limit: Limit,
         offset: u32,
         sort: Option<CardSortKey>,
+        then_sort: Option<CardSortKey>,
         ascending: bool,
         synergy: bool,
     ) -> Self {
@@ -105,12 +140,20 @@ impl CardQuery {
             criteria,
             limit,
             offset,
+            after: None,
             sort,
+            then_sort,
             ascending,
             synergy,
         }
     }
 
+    /// Sets the keyset page position (see [`after_cursor`](Self::after_cursor)).
+    pub(super) fn with_after_cursor(mut self, after: Option<Cursor>) -> Self {
+        self.after = after;
+        self
+    }
+
     /// The predicate fields.
     pub fn criteria(&self) -> &CardCriteria {
         &self.criteria
@@ -126,11 +169,33 @@ impl CardQuery {
         self.offset
     }
 
+    /// Cursor to resume after (`after_cursor` on the wire). Takes precedence
+    /// over [`offset`](Self::offset).
+    pub fn after_cursor(&self) -> Option<&Cursor> {
+        self.after.as_ref()
+    }
+
+    /// The cursor for the page after `page`, the results of this query.
+    /// `None` when the page came up short (nothing follows) or the ordering
+    /// can't be resumed by cursor: no sort, `Random`, `Relevance`, or a
+    /// secondary sort.
+    pub fn next_cursor(&self, page: &[Card]) -> Option<Cursor> {
+        if page.len() < self.limit() as usize || self.then_sort.is_some() {
+            return None;
+        }
+        Cursor::after_page(self.sort?, page)
+    }
+
     /// Sort key (`order_by` on the wire).
     pub fn sort(&self) -> Option<CardSortKey> {
         self.sort
     }
 
+    /// Secondary sort key (`then_order_by` on the wire).
+    pub fn then_sort(&self) -> Option<CardSortKey> {
+        self.then_sort
+    }
+
     /// Sort direction.
     pub fn ascending(&self) -> bool {
         self.ascending
@@ -140,12 +205,39 @@ impl CardQuery {
     pub fn synergy(&self) -> bool {
         self.synergy
     }
+
+    /// Restricts the search to printings in `language`, unless the query
+    /// already names a language.
+    pub fn default_language(&mut self, language: Language) -> &mut Self {
+        if self.criteria.language.is_none() {
+            self.criteria.language = Some(language.code().to_string());
+        }
+        self
+    }
+
+    /// Re-checks what the builder enforces that a query deserialized straight
+    /// off the wire can skip: some search intent (a criterion, a sort, or
+    /// synergy mode), no `Random` secondary sort, and a cursor taken under
+    /// the query's own sort. Include/exclude contradictions aren't
+    /// re-checked; they only match nothing.
+    pub fn validate(&self) -> Result<(), InvalidCardCriteria> {
+        if self.then_sort == Some(CardSortKey::Random) {
+            return Err(InvalidCardCriteria::RandomSecondarySort);
+        }
+        check_cursor(self.after.as_ref(), self.sort, self.then_sort)?;
+        if self.criteria.is_empty() && self.sort.is_none() && !self.synergy {
+            return Err(InvalidCardCriteria::Empty);
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::card::search_card::card_filter::builder::CardQueryBuilder;
+    use crate::{
+        domain::card::search_card::card_filter::builder::CardQueryBuilder, test_utils::make_card,
+    };
 
     // ── the Option A wire gate ────────────────────────────────────────────
     // The split must not change the POST-body JSON. These tests pin the wire
@@ -223,6 +315,21 @@ mod tests {
         assert!(obj.contains_key("limit"));
     }
 
+    #[test]
+    fn wire_queries_validate_like_the_builder() {
+        let parse = |json: &str| serde_json::from_str::<CardQuery>(json).unwrap().validate();
+        assert_eq!(parse(r#"{"name_contains":"bolt"}"#), Ok(()));
+        assert_eq!(parse(r#"{"order_by":"Cmc"}"#), Ok(()));
+        assert_eq!(
+            parse(r#"{"is_playable":true,"language":"en","limit":50}"#),
+            Err(InvalidCardCriteria::Empty)
+        );
+        assert_eq!(
+            parse(r#"{"name_contains":"bolt","then_order_by":"Random"}"#),
+            Err(InvalidCardCriteria::RandomSecondarySort)
+        );
+    }
+
     #[test]
     fn synergy_defaults_false_when_omitted() {
         // A client predating the synergy flag omits it; must parse to false
@@ -235,6 +342,80 @@ mod tests {
         assert!(query.ascending());
     }
 
+    #[test]
+    fn default_language_fills_only_an_unset_language() {
+        let mut query: CardQuery = serde_json::from_str(r#"{"name_contains":"bolt"}"#).unwrap();
+        query.default_language(Language::Japanese);
+        assert_eq!(query.criteria().language(), Some("ja"));
+
+        let mut query: CardQuery =
+            serde_json::from_str(r#"{"name_contains":"bolt","language":"de"}"#).unwrap();
+        query.default_language(Language::Japanese);
+        assert_eq!(query.criteria().language(), Some("de"));
+    }
+
+    #[test]
+    fn then_order_by_round_trips_and_rejects_random() {
+        let mut builder = CardQueryBuilder::with_sort(CardSortKey::Cmc);
+        builder.set_then_sort(CardSortKey::Name);
+        let query = builder.build().unwrap();
+        let value = serde_json::to_value(&query).unwrap();
+        assert_eq!(value.get("order_by"), Some(&serde_json::json!("Cmc")));
+        assert_eq!(value.get("then_order_by"), Some(&serde_json::json!("Name")));
+        let back: CardQuery = serde_json::from_value(value).unwrap();
+        assert_eq!(back.then_sort(), Some(CardSortKey::Name));
+
+        builder.set_then_sort(CardSortKey::Random);
+        assert_eq!(
+            builder.build(),
+            Err(InvalidCardCriteria::RandomSecondarySort)
+        );
+    }
+
+    #[test]
+    fn next_cursor_resumes_a_full_page_under_the_same_sort() {
+        let page: Vec<Card> = ["Arcane Signet", "Sol Ring"]
+            .into_iter()
+            .map(make_card)
+            .collect();
+        let mut builder = CardQueryBuilder::with_sort(CardSortKey::Name);
+        builder.set_limit(2);
+        let cursor = builder.build().unwrap().next_cursor(&page).unwrap();
+        assert_eq!(cursor.value(), "Sol Ring");
+        assert_eq!(cursor.id(), page.last().unwrap().scryfall_data.id);
+        // a short page is the last one
+        builder.set_limit(3);
+        assert_eq!(builder.build().unwrap().next_cursor(&page), None);
+
+        builder.set_after_cursor(cursor.clone());
+        let query = builder.build().unwrap();
+        let wire = serde_json::to_value(&query).unwrap();
+        assert_eq!(
+            wire.get("after_cursor"),
+            Some(&serde_json::json!(cursor.to_string()))
+        );
+        let back: CardQuery = serde_json::from_value(wire).unwrap();
+        assert_eq!(back.after_cursor(), Some(&cursor));
+
+        builder.set_sort(CardSortKey::Cmc);
+        assert_eq!(
+            builder.build(),
+            Err(InvalidCardCriteria::CursorSortMismatch)
+        );
+        builder
+            .set_sort(CardSortKey::Name)
+            .set_then_sort(CardSortKey::Cmc);
+        assert_eq!(
+            builder.build(),
+            Err(InvalidCardCriteria::CursorSortMismatch)
+        );
+        let wire = format!(r#"{{"order_by":"Cmc","after_cursor":"{cursor}"}}"#);
+        assert_eq!(
+            serde_json::from_str::<CardQuery>(&wire).unwrap().validate(),
+            Err(InvalidCardCriteria::CursorSortMismatch)
+        );
+    }
+
     #[test]
     fn wire_limit_is_clamped_on_deserialize() {
         let query: CardQuery =
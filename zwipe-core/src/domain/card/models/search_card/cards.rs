@@ -20,7 +20,10 @@
 use crate::domain::{
     card::{
         Card,
-        search_card::card_filter::{card_sort_key::CardSortKey, criteria::CardCriteria},
+        search_card::card_filter::{
+            card_sort_key::CardSortKey,
+            criteria::{CardCriteria, PLAYABLE_LAYOUTS},
+        },
     },
     deck::DeckEntry,
 };
@@ -63,10 +66,20 @@ impl IntoIterator for Cards {
 impl Cards {
     /// Keeps only the cards matching `criteria`.
     pub fn matching(self, criteria: &CardCriteria) -> Cards {
+        self.matching_with_layouts(criteria, PLAYABLE_LAYOUTS)
+    }
+
+    /// [`matching`](Self::matching) with `is_playable` judged against
+    /// `playable_layouts` (see [`CardCriteria::matches_with_layouts`]).
+    pub fn matching_with_layouts(
+        self,
+        criteria: &CardCriteria,
+        playable_layouts: &[&str],
+    ) -> Cards {
         Cards(
             self.0
                 .into_iter()
-                .filter(|card| criteria.matches(card))
+                .filter(|card| criteria.matches_with_layouts(card, playable_layouts))
                 .collect(),
         )
     }
@@ -74,7 +87,19 @@ impl Cards {
     /// Returns the cards ordered by `sort`. `None` is a no-op (pass a
     /// [`CardSortKey`] directly or a builder's `sort()`); `Random` shuffles and
     /// ignores `ascending`.
-    pub fn sorted(mut self, sort: impl Into<Option<CardSortKey>>, ascending: bool) -> Cards {
+    pub fn sorted(self, sort: impl Into<Option<CardSortKey>>, ascending: bool) -> Cards {
+        self.sorted_then(sort, None, ascending)
+    }
+
+    /// Like [`sorted`](Cards::sorted), with `then_sort` breaking ties left by
+    /// `sort` in the same direction — the in-memory mirror of a query's
+    /// `then_order_by`. A `Random` secondary key is ignored.
+    pub fn sorted_then(
+        mut self,
+        sort: impl Into<Option<CardSortKey>>,
+        then_sort: impl Into<Option<CardSortKey>>,
+        ascending: bool,
+    ) -> Cards {
         let Some(sort) = sort.into() else {
             return self;
         };
@@ -82,8 +107,12 @@ impl Cards {
             self.0.shuffle(&mut rand::rng());
             return self;
         }
+        let then_sort = then_sort.into().filter(|k| *k != CardSortKey::Random);
         self.0.sort_by(|a, b| {
-            let ord = sort.compare(&a.scryfall_data, &b.scryfall_data);
+            let mut ord = sort.compare(&a.scryfall_data, &b.scryfall_data);
+            if let Some(then_sort) = then_sort {
+                ord = ord.then_with(|| then_sort.compare(&a.scryfall_data, &b.scryfall_data));
+            }
             if ascending { ord } else { ord.reverse() }
         });
         self
@@ -130,7 +159,10 @@ mod tests {
             prices::Prices,
             rarity::{Rarities, Rarity},
         },
-        search_card::card_filter::{builder::CardQueryBuilder, card_sort_key::CardSortKey},
+        search_card::card_filter::{
+            builder::CardQueryBuilder, card_sort_key::CardSortKey,
+            criteria::playable_layouts_with_tokens,
+        },
     };
     use chrono::NaiveDate;
     use uuid::Uuid;
@@ -144,6 +176,7 @@ mod tests {
                 oracle_tags: vec![],
                 oracle_tags_by_role: Default::default(),
                 other_oracle_tags: vec![],
+                copy_limit: None,
                 created_at: NaiveDate::from_ymd_opt(2021, 1, 1)
                     .unwrap()
                     .and_hms_opt(0, 0, 0)
@@ -553,6 +586,39 @@ mod tests {
         assert_eq!(result.len(), 1);
     }
 
+    #[test]
+    fn test_name_equals_matches_whole_name_only() {
+        let cards = vec![
+            make_card("Island"),
+            make_card("Island Sanctuary"),
+            make_card("Akroma's Will"),
+        ];
+        let filter = CardQueryBuilder::with_name_equals(" island ")
+            .build_criteria()
+            .unwrap();
+        let result = Cards::from(cards.clone()).matching(&filter);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].scryfall_data.name, "Island");
+
+        let filter = CardQueryBuilder::with_name_equals("AKROMA'S WILL")
+            .build_criteria()
+            .unwrap();
+        let result = Cards::from(cards).matching(&filter);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].scryfall_data.name, "Akroma's Will");
+    }
+
+    #[test]
+    fn test_name_not_contains_excludes_matches() {
+        let cards = vec![make_card("Island"), make_card("Forest")];
+        let filter = CardQueryBuilder::with_name_not_contains("isl")
+            .build_criteria()
+            .unwrap();
+        let result = Cards::from(cards).matching(&filter);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].scryfall_data.name, "Forest");
+    }
+
     #[test]
     fn test_oracle_text_contains_ignores_punctuation() {
         let mut card = make_card("Test Card");
@@ -867,6 +933,22 @@ mod tests {
         assert_eq!(result[0].scryfall_data.name, "Forest");
     }
 
+    #[test]
+    fn test_is_playable_follows_the_layout_whitelist() {
+        let mut token_card = make_card("Soldier Token");
+        token_card.scryfall_data.layout = "token".to_string();
+        let regular = make_card("Forest");
+        let cards = Cards::from(vec![token_card, regular]);
+        let filter = CardQueryBuilder::with_legalities_contains_any(vec!["vintage"])
+            .build_criteria()
+            .unwrap();
+
+        let deck = cards.clone().matching(&filter);
+        assert_eq!(deck.len(), 1);
+        let with_tokens = cards.matching_with_layouts(&filter, &playable_layouts_with_tokens());
+        assert_eq!(with_tokens.len(), 2);
+    }
+
     // ── metadata ──────────────────────────────────────────────────────────────
 
     #[test]
@@ -913,6 +995,31 @@ mod tests {
         assert_eq!(result[0].scryfall_data.name, "MH2 Card");
     }
 
+    #[test]
+    fn test_set_type_equals_any() {
+        use crate::domain::card::scryfall_data::set_type::SetType;
+
+        let mut expansion = make_card("Expansion Card");
+        expansion.scryfall_data.set_type = "expansion".to_string();
+        let mut secret_lair = make_card("Secret Lair Card");
+        secret_lair.scryfall_data.set_type = "box".to_string();
+        let mut unknown = make_card("Future Card");
+        unknown.scryfall_data.set_type = "eternal_edition".to_string();
+        let filter = CardQueryBuilder::with_set_type_equals_any([SetType::Expansion])
+            .build_criteria()
+            .unwrap();
+        let result = Cards::from(vec![expansion, secret_lair, unknown.clone()]).matching(&filter);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].scryfall_data.name, "Expansion Card");
+
+        let filter = CardQueryBuilder::with_set_type_equals_any(["eternal_edition"
+            .parse::<SetType>()
+            .unwrap()])
+        .build_criteria()
+        .unwrap();
+        assert!(filter.matches(&unknown));
+    }
+
     // ── sort ──────────────────────────────────────────────────────────────────
 
     #[test]
@@ -944,6 +1051,27 @@ mod tests {
         assert_eq!(result[2].scryfall_data.name, "A");
     }
 
+    #[test]
+    fn test_sorted_then_breaks_cmc_ties_by_name() {
+        let card = |name: &str, cmc: f64| {
+            let mut card = make_card(name);
+            card.scryfall_data.cmc = Some(cmc);
+            card
+        };
+        let cards = vec![
+            card("Shock", 1.0),
+            card("Opt", 1.0),
+            card("Counterspell", 2.0),
+            card("Brainstorm", 1.0),
+        ];
+        let result = Cards::from(cards).sorted_then(CardSortKey::Cmc, CardSortKey::Name, true);
+        let names: Vec<&str> = result
+            .iter()
+            .map(|c| c.scryfall_data.name.as_str())
+            .collect();
+        assert_eq!(names, ["Brainstorm", "Opt", "Shock", "Counterspell"]);
+    }
+
     #[test]
     fn test_sorted_none_is_a_noop() {
         let cards = vec![make_card("Zap"), make_card("Aardvark")];
@@ -963,6 +1091,7 @@ mod tests {
                 quantity: Quantity::one(),
                 board: Board::Deck,
                 mvp_at: None,
+                reference: Default::default(),
             },
             card: make_card(name),
         };
@@ -247,6 +247,7 @@ mod tests {
                 oracle_tags: vec![],
                 oracle_tags_by_role: Default::default(),
                 other_oracle_tags: vec![],
+                copy_limit: None,
                 created_at: NaiveDate::from_ymd_opt(2021, 1, 1)
                     .unwrap()
                     .and_hms_opt(0, 0, 0)
@@ -0,0 +1,62 @@
+//! How much of a set a user's collection covers.
+//!
+//! Counted by distinct card (oracle id), so alternate printings and variants
+//! inside the set don't inflate either side: owning any printing of a card
+//! counts it as collected.
+
+use serde::{Deserialize, Serialize};
+
+/// Owned vs total distinct cards for one set.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SetCompletion {
+    /// Lowercase set code, e.g. `mh2`.
+    pub set_code: String,
+    /// Full set name, e.g. `Modern Horizons 2`.
+    pub set_name: String,
+    /// Distinct cards in the set the user owns at least one copy of.
+    pub owned: u32,
+    /// Distinct cards in the set.
+    pub total: u32,
+    /// `owned / total` as a percentage (0–100). `0` for an empty set.
+    pub percent: f64,
+}
+
+impl SetCompletion {
+    /// Builds the completion for a set, deriving the percentage.
+    pub fn new(set_code: &str, set_name: &str, owned: u32, total: u32) -> Self {
+        let percent = if total == 0 {
+            0.0
+        } else {
+            f64::from(owned) * 100.0 / f64::from(total)
+        };
+        Self {
+            set_code: set_code.trim().to_lowercase(),
+            set_name: set_name.to_string(),
+            owned,
+            total,
+            percent,
+        }
+    }
+
+    /// True once every card in the set is owned.
+    pub fn is_complete(&self) -> bool {
+        self.total > 0 && self.owned >= self.total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn percent_is_owned_over_total() {
+        let half = SetCompletion::new("MH2", "Modern Horizons 2", 5, 10);
+        assert_eq!(half.set_code, "mh2");
+        assert_eq!(half.percent, 50.0);
+        assert!(!half.is_complete());
+        assert!(SetCompletion::new("tst", "Test", 3, 3).is_complete());
+        let empty = SetCompletion::new("tst", "Test", 0, 0);
+        assert_eq!(empty.percent, 0.0);
+        assert!(!empty.is_complete());
+    }
+}
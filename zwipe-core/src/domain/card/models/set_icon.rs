@@ -0,0 +1,89 @@
+//! Set symbol resolution served to clients.
+//!
+//! Scryfall hosts one monochrome SVG per set symbol. Its set catalog says
+//! which one a set uses — child sets (tokens, promos) share their parent's —
+//! and a URI keyed by the lowercase set code is the fallback for sets the
+//! catalog hasn't reached yet. The SVG carries no rarity coloring — clients tint it
+//! themselves — so the server also ships the conventional per-rarity fill
+//! colors alongside the URI. Keeping both here means no client derives either.
+
+use crate::domain::card::scryfall_data::rarity::Rarity;
+use serde::{Deserialize, Serialize};
+
+/// Base of Scryfall's set symbol CDN; `{base}/{code}.svg` is the icon.
+pub const SET_ICON_BASE_URI: &str = "https://svgs.scryfall.io/sets";
+
+/// A set's symbol icon plus the fill color each rarity renders it in.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SetIcon {
+    /// Lowercase set code, e.g. `mh2`.
+    pub set_code: String,
+    /// Full set name, e.g. `Modern Horizons 2`.
+    pub set_name: String,
+    /// The monochrome SVG symbol for the set.
+    pub icon_svg_uri: String,
+    /// `(rarity, hex fill)` pairs in rarity tier order — the hint for drawing
+    /// the rarity-colored variant of the symbol.
+    pub rarity_colors: Vec<(Rarity, String)>,
+}
+
+impl SetIcon {
+    /// Resolves the icon for a set code (case-insensitive) and its name,
+    /// assuming the symbol is keyed by the set's own code.
+    pub fn new(set_code: &str, set_name: &str) -> Self {
+        let icon_svg_uri = format!(
+            "{}/{}.svg",
+            SET_ICON_BASE_URI,
+            set_code.trim().to_lowercase()
+        );
+        Self::with_icon_uri(set_code, set_name, &icon_svg_uri)
+    }
+
+    /// The icon for a set whose symbol URI is known, e.g. from Scryfall's set
+    /// catalog.
+    pub fn with_icon_uri(set_code: &str, set_name: &str, icon_svg_uri: &str) -> Self {
+        Self {
+            set_code: set_code.trim().to_lowercase(),
+            set_name: set_name.to_string(),
+            icon_svg_uri: icon_svg_uri.to_string(),
+            rarity_colors: Rarity::all()
+                .into_iter()
+                .map(|r| (r, r.set_symbol_color().to_string()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn icon_uri_is_keyed_by_lowercase_code() {
+        let icon = SetIcon::new(" MH2 ", "Modern Horizons 2");
+        assert_eq!(icon.set_code, "mh2");
+        assert_eq!(icon.icon_svg_uri, "https://svgs.scryfall.io/sets/mh2.svg");
+    }
+
+    #[test]
+    fn known_icon_uri_is_kept() {
+        let icon = SetIcon::with_icon_uri(
+            "TMH2",
+            "Modern Horizons 2 Tokens",
+            "https://svgs.scryfall.io/sets/mh2.svg",
+        );
+        assert_eq!(icon.set_code, "tmh2");
+        assert_eq!(icon.icon_svg_uri, "https://svgs.scryfall.io/sets/mh2.svg");
+    }
+
+    #[test]
+    fn every_rarity_gets_a_color_hint() {
+        let icon = SetIcon::new("m10", "Magic 2010");
+        assert_eq!(icon.rarity_colors.len(), Rarity::all().len());
+        assert!(
+            icon.rarity_colors
+                .iter()
+                .all(|(_, hex)| hex.starts_with('#') && hex.len() == 7)
+        );
+    }
+}
@@ -0,0 +1,28 @@
+//! Deck cards hit by a banlist update.
+
+use crate::domain::deck::Format;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A card in a deck that became banned in the deck's format, as recorded
+/// when a card sync applied the ban. One per deck and card, so a deck that
+/// lost several cards appears once for each. The command zone and sideboard
+/// count; the maybeboard doesn't.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BannedDeckCard {
+    /// The affected deck.
+    pub deck_id: Uuid,
+    /// The deck's owner, to notify.
+    pub user_id: Uuid,
+    /// The deck's name.
+    pub deck_name: String,
+    /// The deck's format, the one the card is now banned in.
+    pub format: Format,
+    /// The banned card.
+    pub oracle_id: Uuid,
+    /// The banned card's name.
+    pub card_name: String,
+    /// When the ban was recorded.
+    pub banned_at: DateTime<Utc>,
+}
@@ -0,0 +1,79 @@
+//! How a deck card refers to its card.
+//!
+//! A deck card always stores a printing, but it can hold on to it two ways:
+//! pinned to that exact printing (collectors who chose the art), or by oracle
+//! identity, where the server moves it to the current preferred printing as
+//! cards sync, so the deck survives printings being dropped or superseded.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Error returned when parsing an invalid card reference string.
+#[derive(Debug, Clone, Error)]
+#[error("invalid card reference")]
+pub struct InvalidCardReference;
+
+/// Whether a deck card is pinned to its printing or follows its oracle card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum CardReference {
+    /// Keep the selected printing exactly.
+    #[default]
+    Printing,
+    /// Follow the oracle card; the printing is moved to the current
+    /// preferred one whenever cards sync.
+    Oracle,
+}
+
+impl CardReference {
+    /// Whether the printing follows the oracle card's preferred printing.
+    pub fn is_oracle(&self) -> bool {
+        matches!(self, Self::Oracle)
+    }
+
+    /// Lowercase name matching the database TEXT column value.
+    pub fn display_name(&self) -> &str {
+        match self {
+            Self::Printing => "printing",
+            Self::Oracle => "oracle",
+        }
+    }
+}
+
+impl TryFrom<&str> for CardReference {
+    type Error = InvalidCardReference;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "printing" => Ok(Self::Printing),
+            "oracle" => Ok(Self::Oracle),
+            _ => Err(InvalidCardReference),
+        }
+    }
+}
+
+impl std::fmt::Display for CardReference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_is_printing() {
+        assert_eq!(CardReference::default(), CardReference::Printing);
+    }
+
+    #[test]
+    fn round_trip_through_str_and_serde() {
+        for reference in [CardReference::Printing, CardReference::Oracle] {
+            let parsed = CardReference::try_from(reference.display_name()).unwrap();
+            assert_eq!(reference, parsed);
+            let json = serde_json::to_string(&reference).unwrap();
+            assert_eq!(json, format!("\"{reference}\""));
+        }
+        assert!(CardReference::try_from("art").is_err());
+    }
+}
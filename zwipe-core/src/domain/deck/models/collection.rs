@@ -0,0 +1,74 @@
+//! A user's physical card collection.
+//!
+//! Ownership is tracked per oracle card, not per printing: any copy of
+//! Sol Ring covers a deck's Sol Ring, whatever its art. A deck's
+//! requirements minus what's owned is its shopping list.
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// How many copies of one card a user owns.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OwnedCard {
+    /// The oracle card (shared across printings).
+    pub oracle_id: Uuid,
+    /// Copies owned (always positive; unowned cards aren't listed).
+    pub quantity: i32,
+}
+
+/// What a collection import stored and what it couldn't use.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectionImportReport {
+    /// Distinct cards whose owned quantity was set.
+    pub imported: usize,
+    /// Ids matching no card or printing, in first-seen order.
+    pub unknown_ids: Vec<Uuid>,
+    /// 1-based numbers of lines that weren't a valid entry.
+    pub invalid_lines: Vec<usize>,
+}
+
+/// One card a deck needs, against what its owner has.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MissingDeckCard {
+    /// The oracle card (shared across printings).
+    pub oracle_id: Uuid,
+    /// Card name.
+    pub name: String,
+    /// Copies the deck needs: its deck and sideboard quantities plus one
+    /// per command zone slot. Maybeboard cards aren't needed.
+    pub needed: i32,
+    /// Copies the user owns.
+    pub owned: i32,
+    /// Copies still to acquire; `0` when fully owned.
+    pub missing: i32,
+}
+
+impl MissingDeckCard {
+    /// Computes `missing` from what's needed and owned.
+    pub fn new(oracle_id: Uuid, name: String, needed: i32, owned: i32) -> Self {
+        Self {
+            oracle_id,
+            name,
+            needed,
+            owned,
+            missing: (needed - owned).max(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(needed: i32, owned: i32) -> MissingDeckCard {
+        MissingDeckCard::new(Uuid::nil(), "Lightning Bolt".to_string(), needed, owned)
+    }
+
+    #[test]
+    fn missing_subtracts_owned_and_never_goes_negative() {
+        assert_eq!(line(2, 1).missing, 1);
+        assert_eq!(line(2, 0).missing, 2);
+        assert_eq!(line(1, 1).missing, 0);
+        assert_eq!(line(1, 4).missing, 0);
+    }
+}
@@ -0,0 +1,129 @@
+//! Deck copy limit — how many copies of one card a deck may run.
+//!
+//! Formats are either singleton (1 copy) or standard (4 copies); a deck
+//! starts from its format's limit, and `Custom` covers house rules. Basic
+//! lands are always exempt, and a card's own rules text exception
+//! ([`CardCopyLimit`](crate::domain::card::card_profile::CardCopyLimit))
+//! still beats the deck's limit.
+
+use crate::domain::{card::scryfall_data::ScryfallData, deck::format::Format};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Maximum copies of a single non-basic card in a deck.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CopyLimit {
+    /// One copy (Commander, Brawl, Oathbreaker, ...).
+    Singleton,
+    /// Four copies (60-card constructed).
+    #[default]
+    Standard,
+    /// A house rule: this many copies.
+    Custom(u8),
+}
+
+impl CopyLimit {
+    /// The limit a deck in `format` starts with; `Standard` without one.
+    pub fn for_format(format: Option<Format>) -> Self {
+        format.map_or_else(Self::default, |format| format.copy_limit())
+    }
+
+    /// Maximum copies of one card this limit allows.
+    pub fn max_copies(&self) -> u32 {
+        match self {
+            Self::Singleton => 1,
+            Self::Standard => 4,
+            Self::Custom(n) => u32::from(*n),
+        }
+    }
+
+    /// Whether the limit applies to `card` at all; basic lands are exempt.
+    pub fn applies_to(&self, card: &ScryfallData) -> bool {
+        !card.is_basic_land()
+    }
+
+    /// Whether a deck may run `quantity` copies of `card`.
+    pub fn allows(&self, card: &ScryfallData, quantity: u32) -> bool {
+        !self.applies_to(card) || quantity <= self.max_copies()
+    }
+}
+
+/// Database form: `singleton`, `standard` or `custom:<n>`.
+impl fmt::Display for CopyLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Singleton => write!(f, "singleton"),
+            Self::Standard => write!(f, "standard"),
+            Self::Custom(n) => write!(f, "custom:{n}"),
+        }
+    }
+}
+
+/// Error when parsing an unrecognized copy limit string.
+#[derive(Debug, thiserror::Error)]
+#[error("unknown copy limit: {0}")]
+pub struct InvalidCopyLimit(pub String);
+
+impl TryFrom<&str> for CopyLimit {
+    type Error = InvalidCopyLimit;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "singleton" => Ok(Self::Singleton),
+            "standard" => Ok(Self::Standard),
+            x => x
+                .strip_prefix("custom:")
+                .and_then(|n| n.parse().ok())
+                .map(Self::Custom)
+                .ok_or_else(|| InvalidCopyLimit(value.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::test_utils::make_card;
+
+    fn card(type_line: &str) -> ScryfallData {
+        let mut card = make_card("test").scryfall_data;
+        card.type_line = Some(type_line.to_string());
+        card
+    }
+
+    #[test]
+    fn each_limit_enforces_its_max_and_exempts_basics() {
+        let bolt = card("Instant");
+        let forest = card("Basic Land — Forest");
+        for (limit, max) in [
+            (CopyLimit::Singleton, 1),
+            (CopyLimit::Standard, 4),
+            (CopyLimit::Custom(7), 7),
+        ] {
+            assert_eq!(limit.max_copies(), max);
+            assert!(limit.allows(&bolt, max), "{limit}");
+            assert!(!limit.allows(&bolt, max + 1), "{limit}");
+            assert!(!limit.applies_to(&forest));
+            assert!(limit.allows(&forest, 40), "{limit}");
+        }
+    }
+
+    #[test]
+    fn round_trips_through_the_database_form() {
+        for limit in [
+            CopyLimit::Singleton,
+            CopyLimit::Standard,
+            CopyLimit::Custom(2),
+        ] {
+            assert_eq!(
+                CopyLimit::try_from(limit.to_string().as_str()).unwrap(),
+                limit
+            );
+        }
+        for bad in ["", "four", "custom:", "custom:-1", "custom:300"] {
+            assert!(CopyLimit::try_from(bad).is_err(), "{bad:?}");
+        }
+    }
+}
@@ -2,7 +2,7 @@
 
 use crate::domain::{
     card::Card,
-    deck::{DeckCard, DeckProfile, DeckWarning},
+    deck::{DeckCard, DeckProfile, DeckWarning, validate_deck::DeckCommandZone},
 };
 use serde::{Deserialize, Serialize};
 
@@ -73,4 +73,22 @@ impl Deck {
         self.command_zone_cards = cards;
         self
     }
+
+    /// The command zone resolved against
+    /// [`command_zone_cards`](Self::command_zone_cards) by the profile's IDs.
+    pub fn command_zone(&self) -> DeckCommandZone<'_> {
+        let find = |id: Option<uuid::Uuid>| {
+            id.and_then(|id| {
+                self.command_zone_cards
+                    .iter()
+                    .find(|c| c.scryfall_data.id == id)
+            })
+        };
+        DeckCommandZone {
+            commander: find(self.deck_profile.commander_id),
+            partner_commander: find(self.deck_profile.partner_commander_id),
+            background: find(self.deck_profile.background_id),
+            signature_spell: find(self.deck_profile.signature_spell_id),
+        }
+    }
 }
@@ -1,6 +1,6 @@
 //! Deck-card join entity.
 
-use super::board::Board;
+use super::{board::Board, card_reference::CardReference};
 use crate::domain::deck::Quantity;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -14,7 +14,8 @@ use uuid::Uuid;
 pub struct DeckCard {
     /// The deck containing this card.
     pub deck_id: Uuid,
-    /// The selected printing (Scryfall data ID).
+    /// The selected printing (Scryfall data ID). For oracle references this is
+    /// the preferred printing as of the last card sync.
     pub scryfall_data_id: Uuid,
     /// The logical card identity (shared across all printings).
     pub oracle_id: Uuid,
@@ -28,4 +29,8 @@ pub struct DeckCard {
     /// `#[serde(default)]` so new clients parse old servers and vice versa.
     #[serde(default)]
     pub mvp_at: Option<DateTime<Utc>>,
+    /// Pinned to `scryfall_data_id`, or following the oracle card's preferred
+    /// printing. `#[serde(default)]` = pinned, matching older servers.
+    #[serde(default)]
+    pub reference: CardReference,
 }
@@ -0,0 +1,189 @@
+//! Self-contained JSON deck backup.
+//!
+//! A [`DeckExport`] carries a deck's settings and its cards keyed by oracle
+//! id rather than printing, so restoring it survives printings being dropped
+//! or superseded between export and import — unlike art-specific exports,
+//! which break as soon as a printing disappears.
+
+use crate::domain::{
+    card::search_card::card_filter::price_currency::PriceCurrency,
+    deck::{
+        Board, CopyLimit, DeckCard, PowerLevel, Quantity, deck_profile::DeckProfile, format::Format,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Current [`DeckExport`] schema version. Bumped on a breaking change so an
+/// import can refuse a file it can't read.
+pub const DECK_EXPORT_VERSION: u32 = 1;
+
+/// One card line in a [`DeckExport`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeckExportCard {
+    /// The oracle card (shared across printings).
+    pub oracle_id: Uuid,
+    /// How many copies.
+    pub quantity: Quantity,
+    /// Which board the card is on.
+    pub board: Board,
+}
+
+/// A deck's profile settings and card list, independent of printings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeckExport {
+    /// Schema version ([`DECK_EXPORT_VERSION`] when written).
+    pub version: u32,
+    /// Deck name.
+    pub name: String,
+    /// Deck format.
+    pub format: Option<Format>,
+    /// Deck tag slugs.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Power level.
+    pub power_level: Option<PowerLevel>,
+    /// Other-tag slugs.
+    #[serde(default)]
+    pub other_tags: Vec<String>,
+    /// Oracle-tag slugs.
+    #[serde(default)]
+    pub oracle_tags: Vec<String>,
+    /// The owner's free-text labels.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// User-set land target.
+    pub land_target: Option<i32>,
+    /// User-set price target.
+    pub price_target: Option<f64>,
+    /// Currency for `price_target`.
+    pub price_target_currency: Option<PriceCurrency>,
+    /// Copy limit. `None` (exports from before it existed) follows the format.
+    #[serde(default)]
+    pub copy_limit: Option<CopyLimit>,
+    /// Commander, by oracle id.
+    pub commander: Option<Uuid>,
+    /// Partner commander, by oracle id.
+    pub partner_commander: Option<Uuid>,
+    /// Background, by oracle id.
+    pub background: Option<Uuid>,
+    /// Signature spell, by oracle id.
+    pub signature_spell: Option<Uuid>,
+    /// Every card on every board, ordered by board then oracle id.
+    #[serde(default)]
+    pub cards: Vec<DeckExportCard>,
+}
+
+impl DeckExport {
+    /// Builds an export from a deck's profile and cards. `oracle_ids` maps the
+    /// command zone's printing ids to their oracle ids; a printing missing
+    /// from it (no oracle id) is left out.
+    pub fn new(
+        profile: &DeckProfile,
+        cards: &[DeckCard],
+        oracle_ids: &HashMap<Uuid, Uuid>,
+    ) -> Self {
+        let oracle = |printing: Option<Uuid>| printing.and_then(|id| oracle_ids.get(&id).copied());
+        let mut cards: Vec<DeckExportCard> = cards
+            .iter()
+            .map(|card| DeckExportCard {
+                oracle_id: card.oracle_id,
+                quantity: card.quantity.clone(),
+                board: card.board,
+            })
+            .collect();
+        cards.sort_by_key(|card| (card.board.display_name().to_string(), card.oracle_id));
+        Self {
+            version: DECK_EXPORT_VERSION,
+            name: profile.name.to_string(),
+            format: profile.format,
+            tags: profile.tags.clone(),
+            power_level: profile.power_level,
+            other_tags: profile.other_tags.iter().map(ToString::to_string).collect(),
+            oracle_tags: profile.oracle_tags.clone(),
+            labels: profile.labels.clone(),
+            land_target: profile.land_target,
+            price_target: profile.price_target,
+            price_target_currency: profile.price_target_currency,
+            copy_limit: Some(profile.copy_limit),
+            commander: oracle(profile.commander_id),
+            partner_commander: oracle(profile.partner_commander_id),
+            background: oracle(profile.background_id),
+            signature_spell: oracle(profile.signature_spell_id),
+            cards,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::domain::deck::{CardReference, DeckName, DeckOtherTag};
+
+    fn deck_card(oracle_id: Uuid, quantity: i32, board: Board) -> DeckCard {
+        DeckCard {
+            deck_id: Uuid::nil(),
+            scryfall_data_id: Uuid::new_v4(),
+            oracle_id,
+            quantity: Quantity::new(quantity).unwrap(),
+            board,
+            mvp_at: None,
+            reference: CardReference::Printing,
+        }
+    }
+
+    #[test]
+    fn export_keys_cards_by_oracle_and_round_trips() {
+        let (commander_printing, commander_oracle) = (Uuid::new_v4(), Uuid::new_v4());
+        let (sol_ring, counterspell) = (Uuid::from_u128(2), Uuid::from_u128(1));
+        let profile = DeckProfile {
+            id: Uuid::new_v4(),
+            name: DeckName::new("Backup Deck").unwrap(),
+            commander_id: Some(commander_printing),
+            partner_commander_id: None,
+            background_id: None,
+            signature_spell_id: None,
+            format: Some(Format::Commander),
+            tags: vec!["control".to_string()],
+            power_level: Some(PowerLevel::Upgraded),
+            other_tags: vec![DeckOtherTag::Budget],
+            oracle_tags: vec!["counterspell".to_string()],
+            labels: vec!["cedh".to_string()],
+            land_target: Some(36),
+            price_target: Some(150.0),
+            price_target_currency: Some(PriceCurrency::Eur),
+            copy_limit: CopyLimit::Singleton,
+            share_token: Some(Uuid::new_v4()),
+            user_id: Uuid::new_v4(),
+            card_count: 3,
+            commander_name: None,
+            partner_commander_name: None,
+            background_name: None,
+            signature_spell_name: None,
+            color_identity: vec!["U".to_string()],
+        };
+        let cards = [
+            deck_card(sol_ring, 1, Board::Deck),
+            deck_card(counterspell, 2, Board::Deck),
+            deck_card(Uuid::from_u128(3), 1, Board::Maybeboard),
+        ];
+        let export = DeckExport::new(
+            &profile,
+            &cards,
+            &HashMap::from([(commander_printing, commander_oracle)]),
+        );
+
+        assert_eq!(export.version, DECK_EXPORT_VERSION);
+        assert_eq!(export.commander, Some(commander_oracle));
+        assert_eq!(export.other_tags, ["budget"]);
+        assert_eq!(export.cards[0].oracle_id, counterspell);
+        assert_eq!(export.cards[2].board, Board::Maybeboard);
+
+        let json = serde_json::to_string(&export).unwrap();
+        assert!(!json.contains(&profile.share_token.unwrap().to_string()));
+        let back: DeckExport = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, export);
+    }
+}
@@ -0,0 +1,88 @@
+//! User-defined deck labels.
+//!
+//! Free-text labels a user attaches to their own decks to organize them
+//! ("cedh", "budget", "jank"). Unlike [`DeckTag`](super::deck_tag::DeckTag)
+//! and [`DeckOtherTag`](super::deck_other_tag::DeckOtherTag) there is no
+//! catalog: each user's labels are whatever they've typed, and one user's
+//! labels never show up for another.
+//!
+//! Labels are compared normalized — trimmed, inner whitespace collapsed to a
+//! single space, lowercased — so "cEDH" and " cedh " are the same label.
+
+use std::collections::BTreeSet;
+use thiserror::Error;
+
+/// Maximum number of labels a single deck may carry.
+pub const MAX_DECK_LABELS: usize = 10;
+
+/// Maximum length of a label, in characters, after normalization.
+pub const DECK_LABEL_MAX_LEN: usize = 32;
+
+/// Errors from normalizing a set of deck labels.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum InvalidDeckLabels {
+    /// A label is longer than [`DECK_LABEL_MAX_LEN`].
+    #[error("label must be at most {DECK_LABEL_MAX_LEN} characters: {0}")]
+    TooLong(String),
+    /// More than [`MAX_DECK_LABELS`] distinct labels.
+    #[error("a deck can carry at most {MAX_DECK_LABELS} labels, got {0}")]
+    TooMany(usize),
+}
+
+/// Normalizes one label, or `None` if it's blank.
+pub fn normalize_deck_label(raw: &str) -> Option<String> {
+    let label = raw
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    (!label.is_empty()).then_some(label)
+}
+
+/// Normalizes, dedupes and sorts `raw`, dropping blanks.
+pub fn normalize_deck_labels<S: AsRef<str>>(raw: &[S]) -> Result<Vec<String>, InvalidDeckLabels> {
+    let mut labels = BTreeSet::new();
+    for label in raw.iter().filter_map(|s| normalize_deck_label(s.as_ref())) {
+        if label.chars().count() > DECK_LABEL_MAX_LEN {
+            return Err(InvalidDeckLabels::TooLong(label));
+        }
+        labels.insert(label);
+    }
+    if labels.len() > MAX_DECK_LABELS {
+        return Err(InvalidDeckLabels::TooMany(labels.len()));
+    }
+    Ok(labels.into_iter().collect())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_dedupes_and_sorts() {
+        let labels =
+            normalize_deck_labels(&["Jank", " cEDH ", "cedh", "", "  Big   Mana "]).unwrap();
+        assert_eq!(labels, vec!["big mana", "cedh", "jank"]);
+    }
+
+    #[test]
+    fn rejects_long_labels_and_too_many() {
+        let long = "x".repeat(DECK_LABEL_MAX_LEN + 1);
+        assert!(matches!(
+            normalize_deck_labels(&[long]),
+            Err(InvalidDeckLabels::TooLong(_))
+        ));
+
+        let many: Vec<String> = (0..=MAX_DECK_LABELS)
+            .map(|i| format!("label {i}"))
+            .collect();
+        assert_eq!(
+            normalize_deck_labels(&many),
+            Err(InvalidDeckLabels::TooMany(MAX_DECK_LABELS + 1))
+        );
+        // duplicates don't count twice
+        let repeated = vec!["same"; MAX_DECK_LABELS + 5];
+        assert_eq!(normalize_deck_labels(&repeated).unwrap(), vec!["same"]);
+    }
+}
@@ -8,7 +8,7 @@ use crate::domain::{
     card::{
         Card,
         card_role::CardRole,
-        scryfall_data::{ScryfallData, colors::Color},
+        scryfall_data::{ScryfallData, colors::Color, prices::Price},
         search_card::card_filter::price_currency::PriceCurrency,
     },
     deck::deck::DeckEntry,
@@ -115,11 +115,12 @@ impl DeckMetrics {
         let mut pip_produced = [0usize; 5];
         let all_cats = CardRole::all();
         let mut cat_buckets = vec![0usize; all_cats.len()];
-        let mut usd_sum = 0.0f64;
+        // Summed exactly, converted to f64 once at the end.
+        let mut usd_sum = Price::ZERO;
         let mut usd_count = 0usize;
-        let mut eur_sum = 0.0f64;
+        let mut eur_sum = Price::ZERO;
         let mut eur_count = 0usize;
-        let mut tix_sum = 0.0f64;
+        let mut tix_sum = Price::ZERO;
         let mut tix_count = 0usize;
         let mut power_sum = 0.0f64;
         let mut power_count = 0usize;
@@ -209,23 +210,17 @@ impl DeckMetrics {
             }
 
             // Price: prefer nonfoil → foil → etched
-            let prices = &card.scryfall_data.prices;
-            if let Some(p) = parse_price(&prices.usd)
-                .or_else(|| parse_price(&prices.usd_foil))
-                .or_else(|| parse_price(&prices.usd_etched))
-            {
-                usd_sum += p * qty as f64;
+            let sd = &card.scryfall_data;
+            if let Some(p) = exact_card_price(sd, PriceCurrency::Usd) {
+                usd_sum += p.times(qty as u64);
                 usd_count += qty;
             }
-            if let Some(p) = parse_price(&prices.eur)
-                .or_else(|| parse_price(&prices.eur_foil))
-                .or_else(|| parse_price(&prices.eur_etched))
-            {
-                eur_sum += p * qty as f64;
+            if let Some(p) = exact_card_price(sd, PriceCurrency::Eur) {
+                eur_sum += p.times(qty as u64);
                 eur_count += qty;
             }
-            if let Some(p) = parse_price(&prices.tix) {
-                tix_sum += p * qty as f64;
+            if let Some(p) = exact_card_price(sd, PriceCurrency::Tix) {
+                tix_sum += p.times(qty as u64);
                 tix_count += qty;
             }
         }
@@ -256,15 +251,15 @@ impl DeckMetrics {
                 continue;
             }
             total_cards += 1;
-            if let Some(p) = card_price(&card.scryfall_data, PriceCurrency::Usd) {
+            if let Some(p) = exact_card_price(&card.scryfall_data, PriceCurrency::Usd) {
                 usd_sum += p;
                 usd_count += 1;
             }
-            if let Some(p) = card_price(&card.scryfall_data, PriceCurrency::Eur) {
+            if let Some(p) = exact_card_price(&card.scryfall_data, PriceCurrency::Eur) {
                 eur_sum += p;
                 eur_count += 1;
             }
-            if let Some(p) = card_price(&card.scryfall_data, PriceCurrency::Tix) {
+            if let Some(p) = exact_card_price(&card.scryfall_data, PriceCurrency::Tix) {
                 tix_sum += p;
                 tix_count += 1;
             }
@@ -311,6 +306,7 @@ impl DeckMetrics {
             .collect();
         card_role_counts.sort_by_key(|a| std::cmp::Reverse(a.1));
 
+        let (usd_sum, eur_sum, tix_sum) = (usd_sum.to_f64(), eur_sum.to_f64(), tix_sum.to_f64());
         let total_price_usd = if usd_count > 0 { Some(usd_sum) } else { None };
         let avg_price_usd = if usd_count > 0 {
             Some(usd_sum / usd_count as f64)
@@ -458,23 +454,25 @@ fn produced_color_index(s: &str) -> Option<usize> {
     }
 }
 
-/// Parse an optional price string (e.g. "1.50") into f64.
-fn parse_price(price: &Option<String>) -> Option<f64> {
-    price.as_ref().and_then(|s| s.parse::<f64>().ok())
-}
-
 /// Price of a single card in the given currency, preferring nonfoil → foil →
 /// etched (USD/EUR); TIX is nonfoil only. `None` when no price is available.
 pub fn card_price(scryfall_data: &ScryfallData, currency: PriceCurrency) -> Option<f64> {
+    exact_card_price(scryfall_data, currency).map(|p| p.to_f64())
+}
+
+/// [`card_price`] as an exact [`Price`], for summing.
+fn exact_card_price(scryfall_data: &ScryfallData, currency: PriceCurrency) -> Option<Price> {
     let prices = &scryfall_data.prices;
     match currency {
-        PriceCurrency::Usd => parse_price(&prices.usd)
-            .or_else(|| parse_price(&prices.usd_foil))
-            .or_else(|| parse_price(&prices.usd_etched)),
-        PriceCurrency::Eur => parse_price(&prices.eur)
-            .or_else(|| parse_price(&prices.eur_foil))
-            .or_else(|| parse_price(&prices.eur_etched)),
-        PriceCurrency::Tix => parse_price(&prices.tix),
+        PriceCurrency::Usd => prices
+            .usd()
+            .or_else(|| prices.usd_foil())
+            .or_else(|| prices.usd_etched()),
+        PriceCurrency::Eur => prices
+            .eur()
+            .or_else(|| prices.eur_foil())
+            .or_else(|| prices.eur_etched()),
+        PriceCurrency::Tix => prices.tix(),
     }
 }
 
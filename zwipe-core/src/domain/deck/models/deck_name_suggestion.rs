@@ -0,0 +1,184 @@
+//! Deck name suggestions for the create-deck flow.
+//!
+//! Names are built from the commander alone: its short name ("Atraxa" from
+//! "Atraxa, Praetors' Voice") paired with a theme read off its rules text,
+//! its creature types, or its color identity. Pure and deterministic, so the
+//! same commander always yields the same suggestions.
+
+use crate::domain::{
+    card::scryfall_data::{ScryfallData, colors::Colors},
+    deck::DeckName,
+};
+
+/// Most suggestions returned for one commander.
+pub const MAX_DECK_NAME_SUGGESTIONS: usize = 3;
+
+/// Archetype names and the lowercase rules-text phrases that signal them,
+/// most specific first.
+const THEMES: &[(&str, &[&str])] = &[
+    ("Superfriends", &["planeswalker", "proliferate"]),
+    (
+        "Voltron",
+        &["equipped creature", "enchanted creature", "equip "],
+    ),
+    (
+        "Spellslinger",
+        &["instant or sorcery", "instant and sorcery"],
+    ),
+    (
+        "Aristocrats",
+        &["sacrifice another", "another creature you control dies"],
+    ),
+    (
+        "Reanimator",
+        &["from your graveyard to the battlefield", "from a graveyard"],
+    ),
+    ("Landfall", &["landfall", "land you control enters"]),
+    ("Counters", &["+1/+1 counter"]),
+    ("Tokens", &["token"]),
+    ("Artifacts", &["artifact"]),
+    ("Enchantress", &["enchantment"]),
+    ("Lifegain", &["gain life", "you gain"]),
+    ("Group Hug", &["each player draws"]),
+];
+
+/// Suggests up to [`MAX_DECK_NAME_SUGGESTIONS`] deck names for a commander,
+/// best guess first. Every suggestion is a valid [`DeckName`] and contains
+/// the commander's short name.
+pub fn suggest_deck_names(commander: &ScryfallData) -> Vec<String> {
+    let short_name = short_name(&commander.name);
+    let text = rules_text(commander);
+
+    let tribal = creature_types(commander)
+        .into_iter()
+        .filter(|subtype| text.contains(&subtype.to_lowercase()))
+        .map(|subtype| format!("{subtype} Tribal"));
+    let themes = THEMES
+        .iter()
+        .filter(|(_, phrases)| phrases.iter().any(|p| text.contains(p)))
+        .map(|(theme, _)| (*theme).to_string());
+    let fallbacks = [
+        color_identity_label(&commander.color_identity).to_string(),
+        "Commander".to_string(),
+    ];
+
+    let mut names: Vec<String> = Vec::new();
+    for theme in tribal.chain(themes).chain(fallbacks) {
+        let name = format!("{short_name} {theme}");
+        if !names.contains(&name) && DeckName::new(name.as_str()).is_ok() {
+            names.push(name);
+        }
+        if names.len() == MAX_DECK_NAME_SUGGESTIONS {
+            break;
+        }
+    }
+    names
+}
+
+/// Front face name up to its first comma: "Atraxa, Praetors' Voice" →
+/// "Atraxa", "Esika, God of the Tree // The Prismatic Bridge" → "Esika".
+fn short_name(name: &str) -> &str {
+    let front = name.split(" // ").next().unwrap_or(name);
+    front.split(',').next().unwrap_or(front).trim()
+}
+
+/// Lowercased oracle text of the card and all of its faces.
+fn rules_text(card: &ScryfallData) -> String {
+    let faces = card
+        .card_faces
+        .iter()
+        .flat_map(|faces| faces.iter())
+        .filter_map(|face| face.oracle_text.as_deref());
+    card.oracle_text
+        .as_deref()
+        .into_iter()
+        .chain(faces)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .to_lowercase()
+}
+
+/// Creature subtypes on the front face's type line ("Human Wizard" from
+/// "Legendary Creature — Human Wizard").
+fn creature_types(card: &ScryfallData) -> Vec<&str> {
+    let type_line = card
+        .card_faces
+        .as_ref()
+        .and_then(|faces| faces.first())
+        .and_then(|face| face.type_line.as_deref())
+        .or(card.type_line.as_deref())
+        .unwrap_or_default();
+    match type_line.split_once('—') {
+        Some((types, subtypes)) if types.contains("Creature") => {
+            subtypes.split_whitespace().collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Community name for a color identity: "Mono-Red", "Golgari", "Esper".
+/// Four- and five-color identities are spelled out rather than named.
+fn color_identity_label(identity: &Colors) -> &'static str {
+    match identity.sorted_wubrg().len() {
+        4 => "Four-Color",
+        5 => "Five-Color",
+        _ => identity.identity_name().unwrap_or("Colorless"),
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::{domain::card::scryfall_data::colors::Color, test_utils::make_card};
+
+    fn atraxa() -> ScryfallData {
+        let mut card = make_card("Atraxa, Praetors' Voice").scryfall_data;
+        card.type_line = Some("Legendary Creature — Phyrexian Angel Horror".to_string());
+        card.oracle_text = Some(
+            "Flying, vigilance, deathtouch, lifelink\nAt the beginning of your end step, proliferate."
+                .to_string(),
+        );
+        card.color_identity = Colors::from([Color::White, Color::Blue, Color::Black, Color::Green]);
+        card
+    }
+
+    #[test]
+    fn commander_yields_themed_suggestions_with_its_name() {
+        let names = suggest_deck_names(&atraxa());
+        assert_eq!(names[0], "Atraxa Superfriends");
+        assert!(names.len() <= MAX_DECK_NAME_SUGGESTIONS);
+        assert!(names.iter().all(|n| n.contains("Atraxa")));
+        assert!(names.contains(&"Atraxa Four-Color".to_string()));
+        assert_eq!(names, suggest_deck_names(&atraxa()));
+    }
+
+    #[test]
+    fn creature_types_named_in_text_become_tribal() {
+        let mut edgar = make_card("Edgar Markov").scryfall_data;
+        edgar.type_line = Some("Legendary Creature — Vampire Knight".to_string());
+        edgar.oracle_text = Some(
+            "Eminence — Whenever you cast another Vampire spell, create a 1/1 black Vampire creature token."
+                .to_string(),
+        );
+        edgar.color_identity = Colors::from([Color::White, Color::Black, Color::Red]);
+        assert_eq!(
+            suggest_deck_names(&edgar),
+            [
+                "Edgar Markov Vampire Tribal",
+                "Edgar Markov Tokens",
+                "Edgar Markov Mardu"
+            ]
+        );
+    }
+
+    #[test]
+    fn textless_commander_falls_back_to_colors() {
+        let mut card = make_card("Esika, God of the Tree // The Prismatic Bridge").scryfall_data;
+        card.color_identity = Colors::from([Color::Green]);
+        assert_eq!(
+            suggest_deck_names(&card),
+            ["Esika Mono-Green", "Esika Commander"]
+        );
+    }
+}
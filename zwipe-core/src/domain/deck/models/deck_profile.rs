@@ -2,7 +2,7 @@
 
 use crate::domain::{
     card::search_card::card_filter::price_currency::PriceCurrency,
-    deck::{DeckName, DeckOtherTag, PowerLevel, format::Format},
+    deck::{CopyLimit, DeckName, DeckOtherTag, PowerLevel, format::Format},
 };
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -42,6 +42,10 @@ pub struct DeckProfile {
     /// enum. `#[serde(default)]` so older payloads without the field parse empty.
     #[serde(default)]
     pub oracle_tags: Vec<String>,
+    /// The owner's own free-text labels (`cedh`, `budget`), normalized and
+    /// sorted. `#[serde(default)]` so older payloads without the field parse empty.
+    #[serde(default)]
+    pub labels: Vec<String>,
     /// User-set land target. `None` falls back to the format-derived heuristic
     /// ([`Format::default_land_target`]).
     pub land_target: Option<i32>,
@@ -50,6 +54,11 @@ pub struct DeckProfile {
     pub price_target: Option<f64>,
     /// Currency for `price_target`. `None` falls back to USD.
     pub price_target_currency: Option<PriceCurrency>,
+    /// Maximum copies of one non-basic card. Follows the format (set when the
+    /// deck is created or its format changes) unless the owner picks a house
+    /// rule. `#[serde(default)]` so older payloads parse to `Standard`.
+    #[serde(default)]
+    pub copy_limit: CopyLimit,
     /// Public share link token. `None` = private (default). `#[serde(default)]`
     /// so an older client reading a payload without it parses to `None`.
     #[serde(default)]
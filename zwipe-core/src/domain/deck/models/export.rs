@@ -0,0 +1,436 @@
+//! Decklist text export: the deck as the plain text, MTG Arena and MTGO
+//! formats other tools import, and the reverse — reading a pasted list in
+//! any of them back into lines to resolve.
+//!
+//! Only the command zone, main deck and sideboard are written; the
+//! maybeboard is a scratch pad, not part of the list. Each section is
+//! sorted by name.
+
+use crate::domain::{
+    card::{Card, scryfall_data::ScryfallData},
+    deck::{Board, Deck},
+};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, fmt::Write};
+use thiserror::Error;
+
+/// Error returned when parsing an unknown export format.
+#[derive(Debug, Clone, Error)]
+#[error("invalid export format")]
+pub struct InvalidExportFormat;
+
+/// Where a pasted decklist line goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecklistSection {
+    /// The command zone (`Commander` header).
+    Commander,
+    /// One of the deck's boards.
+    Board(Board),
+}
+
+/// One card line read from a pasted decklist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecklistLine {
+    /// Number of copies.
+    pub quantity: i32,
+    /// Card name as written (MTGO's `Fire/Ice` included).
+    pub name: String,
+    /// Set code, lowercased, when the line names a printing (`(M11) 149`).
+    pub set: Option<String>,
+    /// Collector number, when the line names a printing.
+    pub collector_number: Option<String>,
+    /// Section the line sits in.
+    pub section: DecklistSection,
+}
+
+/// A decklist text format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    /// `1 Lightning Bolt`, under `Commander` / `Deck` / `Sideboard` headers.
+    #[default]
+    PlainText,
+    /// `1 Lightning Bolt (M11) 149`, under the same headers, as MTG Arena
+    /// imports it.
+    Arena,
+    /// MTGO's headerless list: main deck, a blank line, then the sideboard
+    /// with the command zone at its top (where MTGO reads commanders from).
+    /// Split cards use MTGO's `Fire/Ice` spelling.
+    Mtgo,
+}
+
+impl TryFrom<&str> for ExportFormat {
+    type Error = InvalidExportFormat;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim().to_lowercase().as_str() {
+            "text" | "plain_text" => Ok(Self::PlainText),
+            "arena" => Ok(Self::Arena),
+            "mtgo" => Ok(Self::Mtgo),
+            _ => Err(InvalidExportFormat),
+        }
+    }
+}
+
+impl ExportFormat {
+    /// The name the export route takes the format by.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::PlainText => "text",
+            Self::Arena => "arena",
+            Self::Mtgo => "mtgo",
+        }
+    }
+
+    /// Writes `deck` out in this format, one line per card, newline-terminated.
+    pub fn render(self, deck: &Deck) -> String {
+        let command_zone: Vec<(&Card, i32)> = deck
+            .command_zone_cards
+            .iter()
+            .map(|card| (card, 1))
+            .collect();
+        let main = board(deck, Board::Deck);
+        let sideboard = board(deck, Board::Sideboard);
+
+        let sections: Vec<(&str, Vec<(&Card, i32)>)> = match self {
+            Self::PlainText | Self::Arena => vec![
+                ("Commander", command_zone),
+                ("Deck", main),
+                ("Sideboard", sideboard),
+            ],
+            Self::Mtgo => vec![("", main), ("", [command_zone, sideboard].concat())],
+        };
+
+        let mut out = String::new();
+        for (header, cards) in sections.iter().filter(|(_, cards)| !cards.is_empty()) {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            if !header.is_empty() {
+                out.push_str(header);
+                out.push('\n');
+            }
+            for (card, quantity) in cards {
+                self.write_line(&mut out, &card.scryfall_data, *quantity);
+            }
+        }
+        out
+    }
+
+    /// Reads a pasted decklist written in this format.
+    ///
+    /// Lines are `[qty][x] name`, optionally followed by `(SET) number` and
+    /// trailing tags, which are dropped. `Commander`, `Deck`, `Sideboard` and
+    /// `Maybeboard` headers (bare or after `//`) switch sections; any other
+    /// `//` line is a comment. An `SB:` prefix puts a single line in the
+    /// sideboard. MTGO has no headers, so there the first blank line after
+    /// the main deck starts the sideboard — which is also where MTGO keeps
+    /// commanders, so they come back as sideboard cards.
+    pub fn parse(self, text: &str) -> Vec<DecklistLine> {
+        let mut section = DecklistSection::Board(Board::Deck);
+        let mut lines: Vec<DecklistLine> = Vec::new();
+        for raw in text.lines() {
+            let trimmed = raw.trim();
+            if trimmed.is_empty() {
+                if self == Self::Mtgo && !lines.is_empty() {
+                    section = DecklistSection::Board(Board::Sideboard);
+                }
+                continue;
+            }
+            let comment = trimmed.strip_prefix("//").map(str::trim);
+            let header = comment.unwrap_or(trimmed).trim_end_matches(':');
+            match header.to_lowercase().as_str() {
+                "commander" | "commanders" => section = DecklistSection::Commander,
+                "deck" | "main" | "mainboard" => section = DecklistSection::Board(Board::Deck),
+                "sideboard" => section = DecklistSection::Board(Board::Sideboard),
+                "maybeboard" => section = DecklistSection::Board(Board::Maybeboard),
+                _ if comment.is_some() => {}
+                _ => lines.extend(parse_line(trimmed, section)),
+            }
+        }
+        lines
+    }
+
+    fn write_line(self, out: &mut String, sd: &ScryfallData, quantity: i32) {
+        let name = self.card_name(sd);
+        // writing to a String can't fail
+        let _ = match self {
+            Self::Arena => writeln!(
+                out,
+                "{quantity} {name} ({}) {}",
+                sd.set.to_uppercase(),
+                sd.collector_number
+            ),
+            Self::PlainText | Self::Mtgo => writeln!(out, "{quantity} {name}"),
+        };
+    }
+
+    /// The name other tools know the card by. Split cards keep both halves
+    /// (`Fire // Ice`, or `Fire/Ice` for MTGO); every other two-faced card
+    /// goes by its front face.
+    fn card_name(self, sd: &ScryfallData) -> Cow<'_, str> {
+        let Some((front, _)) = sd.name.split_once(" // ") else {
+            return Cow::Borrowed(&sd.name);
+        };
+        match (sd.layout.as_str(), self) {
+            ("split" | "aftermath", Self::Mtgo) => Cow::Owned(sd.name.replace(" // ", "/")),
+            ("split" | "aftermath", _) => Cow::Borrowed(&sd.name),
+            _ => Cow::Borrowed(front.trim()),
+        }
+    }
+}
+
+/// Reads one card line, or `None` when it holds no card.
+fn parse_line(line: &str, section: DecklistSection) -> Option<DecklistLine> {
+    let (line, section) = match line.strip_prefix("SB:") {
+        Some(rest) => (rest.trim(), DecklistSection::Board(Board::Sideboard)),
+        None => (line, section),
+    };
+    let (quantity, rest) = line
+        .split_once(char::is_whitespace)
+        .and_then(|(first, rest)| {
+            let qty = first.strip_suffix(['x', 'X']).unwrap_or(first);
+            qty.parse::<i32>().ok().map(|qty| (qty, rest.trim()))
+        })
+        .unwrap_or((1, line));
+    let (name, printing) = match rest.split_once('(') {
+        Some((name, meta)) => (name, meta.split_once(')')),
+        None => (rest.split('[').next().unwrap_or(rest), None),
+    };
+    let name = name.trim();
+    if name.is_empty() || quantity < 1 {
+        return None;
+    }
+    let (set, collector_number) = match printing {
+        Some((set, after)) if !set.trim().is_empty() => (
+            Some(set.trim().to_lowercase()),
+            after
+                .split_whitespace()
+                .next()
+                .filter(|n| !n.starts_with(['[', '*']))
+                .map(str::to_string),
+        ),
+        _ => (None, None),
+    };
+    Some(DecklistLine {
+        quantity,
+        name: name.to_string(),
+        set,
+        collector_number,
+        section,
+    })
+}
+
+/// One board's cards with their quantities, by name.
+fn board(deck: &Deck, board: Board) -> Vec<(&Card, i32)> {
+    let mut cards: Vec<(&Card, i32)> = deck
+        .entries
+        .iter()
+        .filter(|entry| entry.deck_card.board == board)
+        .map(|entry| (&entry.card, *entry.deck_card.quantity))
+        .collect();
+    cards.sort_by(|(a, _), (b, _)| a.scryfall_data.name.cmp(&b.scryfall_data.name));
+    cards
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::deck::{CopyLimit, DeckEntry, DeckName, deck_profile::DeckProfile},
+        test_utils::{make_card, make_entry},
+    };
+    use uuid::Uuid;
+
+    fn deck(entries: Vec<DeckEntry>, command_zone_cards: Vec<Card>) -> Deck {
+        let profile = DeckProfile {
+            id: Uuid::nil(),
+            name: DeckName::new("Export").unwrap(),
+            commander_id: None,
+            partner_commander_id: None,
+            background_id: None,
+            signature_spell_id: None,
+            format: None,
+            tags: Vec::new(),
+            power_level: None,
+            other_tags: Vec::new(),
+            oracle_tags: Vec::new(),
+            labels: Vec::new(),
+            land_target: None,
+            price_target: None,
+            price_target_currency: None,
+            copy_limit: CopyLimit::Standard,
+            share_token: None,
+            user_id: Uuid::nil(),
+            card_count: 0,
+            commander_name: None,
+            partner_commander_name: None,
+            background_name: None,
+            signature_spell_name: None,
+            color_identity: Vec::new(),
+        };
+        Deck {
+            deck_profile: profile,
+            entries,
+            warnings: Vec::new(),
+            command_zone_cards,
+        }
+    }
+
+    fn printed(mut entry: DeckEntry, set: &str, number: &str, layout: &str) -> DeckEntry {
+        entry.card.scryfall_data.set = set.to_string();
+        entry.card.scryfall_data.collector_number = number.to_string();
+        entry.card.scryfall_data.layout = layout.to_string();
+        entry
+    }
+
+    fn sample() -> Deck {
+        let bolt = printed(make_entry("Lightning Bolt", 1), "m11", "149", "normal");
+        let mountain = printed(make_entry("Mountain", 30), "znr", "275", "normal");
+        let fire_ice = printed(make_entry("Fire // Ice", 2), "mh2", "290", "split");
+        let mut bonecrusher = printed(
+            make_entry("Bonecrusher Giant // Stomp", 1),
+            "eld",
+            "115",
+            "adventure",
+        );
+        bonecrusher.deck_card.board = Board::Sideboard;
+        let mut maybe = make_entry("Opt", 1);
+        maybe.deck_card.board = Board::Maybeboard;
+        let mut krenko = make_card("Krenko, Mob Boss");
+        krenko.scryfall_data.set = "m13".to_string();
+        krenko.scryfall_data.collector_number = "141".to_string();
+        deck(
+            vec![mountain, maybe, bolt, bonecrusher, fire_ice],
+            vec![krenko],
+        )
+    }
+
+    #[test]
+    fn plain_text_lists_sections_by_name() {
+        assert_eq!(
+            ExportFormat::PlainText.render(&sample()),
+            "Commander\n1 Krenko, Mob Boss\n\n\
+             Deck\n2 Fire // Ice\n1 Lightning Bolt\n30 Mountain\n\n\
+             Sideboard\n1 Bonecrusher Giant\n"
+        );
+    }
+
+    #[test]
+    fn arena_adds_set_and_collector_number() {
+        assert_eq!(
+            ExportFormat::Arena.render(&sample()),
+            "Commander\n1 Krenko, Mob Boss (M13) 141\n\n\
+             Deck\n2 Fire // Ice (MH2) 290\n1 Lightning Bolt (M11) 149\n30 Mountain (ZNR) 275\n\n\
+             Sideboard\n1 Bonecrusher Giant (ELD) 115\n"
+        );
+    }
+
+    #[test]
+    fn mtgo_puts_the_commander_in_the_sideboard_and_slashes_split_cards() {
+        assert_eq!(
+            ExportFormat::Mtgo.render(&sample()),
+            "2 Fire/Ice\n1 Lightning Bolt\n30 Mountain\n\n\
+             1 Krenko, Mob Boss\n1 Bonecrusher Giant\n"
+        );
+    }
+
+    #[test]
+    fn a_deck_without_commander_or_sideboard_is_just_the_deck() {
+        let deck = deck(vec![make_entry("Forest", 20)], Vec::new());
+        assert_eq!(ExportFormat::PlainText.render(&deck), "Deck\n20 Forest\n");
+        assert_eq!(ExportFormat::Mtgo.render(&deck), "20 Forest\n");
+    }
+
+    #[test]
+    fn parses_what_it_renders() {
+        for format in [ExportFormat::PlainText, ExportFormat::Arena] {
+            let lines = format.parse(&format.render(&sample()));
+            let read: Vec<(i32, &str, DecklistSection)> = lines
+                .iter()
+                .map(|l| (l.quantity, l.name.as_str(), l.section))
+                .collect();
+            assert_eq!(
+                read,
+                [
+                    (1, "Krenko, Mob Boss", DecklistSection::Commander),
+                    (2, "Fire // Ice", DecklistSection::Board(Board::Deck)),
+                    (1, "Lightning Bolt", DecklistSection::Board(Board::Deck)),
+                    (30, "Mountain", DecklistSection::Board(Board::Deck)),
+                    (
+                        1,
+                        "Bonecrusher Giant",
+                        DecklistSection::Board(Board::Sideboard)
+                    ),
+                ]
+            );
+        }
+        let arena = ExportFormat::Arena.parse("1 Lightning Bolt (M11) 149");
+        assert_eq!(arena[0].set.as_deref(), Some("m11"));
+        assert_eq!(arena[0].collector_number.as_deref(), Some("149"));
+    }
+
+    #[test]
+    fn mtgo_blank_line_starts_the_sideboard() {
+        let lines = ExportFormat::Mtgo.parse("\n2 Fire/Ice\n30 Mountain\n\n1 Krenko, Mob Boss\n");
+        let sections: Vec<DecklistSection> = lines.iter().map(|l| l.section).collect();
+        assert_eq!(
+            sections,
+            [
+                DecklistSection::Board(Board::Deck),
+                DecklistSection::Board(Board::Deck),
+                DecklistSection::Board(Board::Sideboard),
+            ]
+        );
+        assert_eq!(lines[0].name, "Fire/Ice");
+    }
+
+    #[test]
+    fn reads_comments_sideboard_markers_and_tags() {
+        let lines = ExportFormat::PlainText.parse(
+            "// Burn\n4x Lightning Bolt\nSB: 2 Duress\n\
+             1x Aether Hub (KLD) 243 *F* [Land]\n1 Opt [Draw]\n0 Shock\n// Maybeboard\nGitaxian Probe",
+        );
+        let read: Vec<(i32, &str, DecklistSection)> = lines
+            .iter()
+            .map(|l| (l.quantity, l.name.as_str(), l.section))
+            .collect();
+        assert_eq!(
+            read,
+            [
+                (4, "Lightning Bolt", DecklistSection::Board(Board::Deck)),
+                (2, "Duress", DecklistSection::Board(Board::Sideboard)),
+                (1, "Aether Hub", DecklistSection::Board(Board::Deck)),
+                (1, "Opt", DecklistSection::Board(Board::Deck)),
+                (
+                    1,
+                    "Gitaxian Probe",
+                    DecklistSection::Board(Board::Maybeboard)
+                ),
+            ]
+        );
+        assert_eq!(lines[2].set.as_deref(), Some("kld"));
+        assert_eq!(lines[2].collector_number.as_deref(), Some("243"));
+        assert_eq!(lines[3].set, None);
+    }
+
+    #[test]
+    fn parses_format_names() {
+        assert_eq!(
+            ExportFormat::try_from("Arena").ok(),
+            Some(ExportFormat::Arena)
+        );
+        assert_eq!(
+            ExportFormat::try_from("text").ok(),
+            Some(ExportFormat::PlainText)
+        );
+        assert!(ExportFormat::try_from("cockatrice").is_err());
+        for format in [
+            ExportFormat::PlainText,
+            ExportFormat::Arena,
+            ExportFormat::Mtgo,
+        ] {
+            assert_eq!(ExportFormat::try_from(format.code()).ok(), Some(format));
+        }
+    }
+}
@@ -3,6 +3,7 @@
 //! Each format defines deck-building rules: card pool legality, copy limits,
 //! deck size constraints, and whether a commander is required.
 
+use crate::domain::deck::copy_limit::CopyLimit;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -212,8 +213,9 @@ impl Format {
         }
     }
 
-    /// Maximum copies of a single non-basic-land card.
-    pub fn copy_max(&self) -> u32 {
+    /// The format's copy limit: singleton for the commander-style formats
+    /// and Gladiator, standard (four) otherwise.
+    pub fn copy_limit(&self) -> CopyLimit {
         match self {
             Self::Commander
             | Self::Brawl
@@ -223,11 +225,16 @@ impl Format {
             | Self::Duel
             | Self::Predh
             | Self::Oathbreaker
-            | Self::Gladiator => 1,
-            _ => 4,
+            | Self::Gladiator => CopyLimit::Singleton,
+            _ => CopyLimit::Standard,
         }
     }
 
+    /// Maximum copies of a single non-basic-land card.
+    pub fn copy_max(&self) -> u32 {
+        self.copy_limit().max_copies()
+    }
+
     /// Whether this format requires a commander.
     pub fn has_commander(&self) -> bool {
         matches!(
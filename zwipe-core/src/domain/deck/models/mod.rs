@@ -1,5 +1,8 @@
 // Value objects
 pub mod board;
+pub mod card_reference;
+pub mod copy_limit;
+pub mod deck_label;
 pub mod deck_name;
 pub mod deck_oracle_tags;
 pub mod deck_other_tag;
@@ -10,21 +13,39 @@ pub mod power_level;
 pub mod quantity;
 
 // Entities
+pub mod banned_deck_card;
+pub mod collection;
 #[allow(clippy::module_inception)]
 pub mod deck;
 pub mod deck_card;
+pub mod deck_export;
 pub mod deck_metrics;
 pub mod deck_profile;
 pub mod deck_warning;
+pub mod orphaned_deck_card;
+pub mod proxy_sheet;
 
 // Domain logic
+pub mod deck_name_suggestion;
+pub mod export;
+pub mod stats;
 pub mod validate_deck;
 
+pub use banned_deck_card::BannedDeckCard;
 pub use board::{Board, InvalidBoard};
+pub use card_reference::{CardReference, InvalidCardReference};
+pub use collection::{CollectionImportReport, MissingDeckCard, OwnedCard};
+pub use copy_limit::{CopyLimit, InvalidCopyLimit};
 pub use deck::{Deck, DeckEntry};
 pub use deck_card::DeckCard;
+pub use deck_export::{DECK_EXPORT_VERSION, DeckExport, DeckExportCard};
+pub use deck_label::{
+    DECK_LABEL_MAX_LEN, InvalidDeckLabels, MAX_DECK_LABELS, normalize_deck_label,
+    normalize_deck_labels,
+};
 pub use deck_metrics::DeckMetrics;
 pub use deck_name::{DeckName, InvalidDeckname};
+pub use deck_name_suggestion::{MAX_DECK_NAME_SUGGESTIONS, suggest_deck_names};
 pub use deck_oracle_tags::{
     MAX_DECK_ORACLE_TAGS, dedupe_oracle_tags, seed_oracle_tags, seed_oracle_tags_from_catalog,
 };
@@ -32,8 +53,15 @@ pub use deck_other_tag::{DeckOtherTag, InvalidDeckOtherTag, MAX_DECK_OTHER_TAGS}
 pub use deck_profile::DeckProfile;
 pub use deck_tag::{DeckTag, DeckTagView, InvalidDeckTag, MAX_DECK_TAGS, deck_tag_label};
 pub use deck_warning::{DeckWarning, WarningAction};
+pub use export::{DecklistLine, DecklistSection, ExportFormat, InvalidExportFormat};
 pub use format::{Format, InvalidFormat};
 pub use import_mode::ImportMode;
+pub use orphaned_deck_card::OrphanedDeckCard;
 pub use power_level::{InvalidPowerLevel, PowerLevel};
+pub use proxy_sheet::{MAX_PROXY_IMAGES, ProxyImage, ProxySheet};
 pub use quantity::{InvalidQuantity, InvalidUpdateQuanity, Quantity, UpdateQuantity};
-pub use validate_deck::{DeckCommandZone, validate_deck};
+pub use stats::{ColorPips, DeckStats, MANA_CURVE_LABELS, TypeCount};
+pub use validate_deck::{
+    DeckCommandZone, LegalityReport, LegalityRule, LegalityViolation, legality_report,
+    legality_report_for, validate_deck,
+};
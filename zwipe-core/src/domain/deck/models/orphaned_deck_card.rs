@@ -0,0 +1,26 @@
+//! Deck cards whose printing no longer resolves to a card.
+
+use super::DeckCard;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A deck card pointing at a printing that a card sync removed or stopped
+/// profiling, so the card can't be loaded with the deck.
+///
+/// `replacement_id` is a surviving printing of the same oracle card (the
+/// preferred printing when it survives, otherwise the newest), or `None` when
+/// no printing of that card is left to remap to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrphanedDeckCard {
+    /// The dangling deck card as stored.
+    pub deck_card: DeckCard,
+    /// Printing the deck card can be remapped to, if any.
+    pub replacement_id: Option<Uuid>,
+}
+
+impl OrphanedDeckCard {
+    /// True when a surviving printing exists to remap to.
+    pub fn is_repairable(&self) -> bool {
+        self.replacement_id.is_some()
+    }
+}
@@ -0,0 +1,221 @@
+//! Proxy sheet: a deck's card images, one per physical copy, for printing
+//! playtest proxies.
+//!
+//! Every copy gets its front image; double-faced cards (see
+//! [`ScryfallData::face_count`](crate::domain::card::scryfall_data::ScryfallData::face_count))
+//! also get their back, right after the front so a cut sheet keeps the
+//! pair together.
+
+use crate::domain::{
+    card::{Card, scryfall_data::ImageSize},
+    deck::Deck,
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Most images one sheet may hold. A 100-card deck of double-faced cards
+/// fits; anything past this is dropped and the sheet marked truncated.
+pub const MAX_PROXY_IMAGES: usize = 250;
+
+/// One image to print.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxyImage {
+    /// The printing this image belongs to.
+    pub scryfall_data_id: Uuid,
+    /// Card name.
+    pub name: String,
+    /// Which face: `0` front, `1` back.
+    pub face: usize,
+    /// Print-size image URL (large, falling back to normal).
+    pub image_url: String,
+}
+
+/// A deck's proxy images in print order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxySheet {
+    /// Command zone first, then the main deck by name; each copy's faces
+    /// are adjacent.
+    pub images: Vec<ProxyImage>,
+    /// `true` when the deck had more than [`MAX_PROXY_IMAGES`] images.
+    pub truncated: bool,
+}
+
+impl ProxySheet {
+    /// Expands the deck's command zone and main deck by quantity. The
+    /// maybeboard and sideboard aren't played, so they aren't proxied;
+    /// cards with no image are skipped.
+    pub fn from_deck(deck: &Deck) -> Self {
+        let mut main: Vec<(&Card, i32)> = deck
+            .entries
+            .iter()
+            .filter(|entry| entry.deck_card.board.is_active())
+            .map(|entry| (&entry.card, *entry.deck_card.quantity))
+            .collect();
+        main.sort_by(|(a, _), (b, _)| a.scryfall_data.name.cmp(&b.scryfall_data.name));
+        let copies = deck
+            .command_zone_cards
+            .iter()
+            .map(|card| (card, 1))
+            .chain(main)
+            .flat_map(|(card, quantity)| {
+                std::iter::repeat_n(card, usize::try_from(quantity).unwrap_or(0))
+            });
+
+        let mut sheet = Self {
+            images: Vec::new(),
+            truncated: false,
+        };
+        for card in copies {
+            let faces = card_faces(card);
+            if faces.is_empty() {
+                continue;
+            }
+            if sheet.images.len() + faces.len() > MAX_PROXY_IMAGES {
+                sheet.truncated = true;
+                break;
+            }
+            sheet.images.extend(faces);
+        }
+        sheet
+    }
+}
+
+/// The printable faces of one copy: the front, plus the back when the card
+/// has separately imaged faces.
+fn card_faces(card: &Card) -> Vec<ProxyImage> {
+    let sd = &card.scryfall_data;
+    (0..sd.face_count().min(2))
+        .filter_map(|face| {
+            let url = sd
+                .face_image_url(face, ImageSize::Large)
+                .or_else(|| sd.face_image_url(face, ImageSize::Normal))?;
+            Some(ProxyImage {
+                scryfall_data_id: sd.id,
+                name: sd.name.clone(),
+                face,
+                image_url: url.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::{
+            card::scryfall_data::{card_faces::CardFace, image_uris::ImageUris},
+            deck::{Board, CopyLimit, DeckName, deck_profile::DeckProfile},
+        },
+        test_utils::{make_card, make_entry},
+    };
+
+    fn uris(url: &str) -> ImageUris {
+        ImageUris {
+            small: None,
+            normal: Some(format!("{url}/normal")),
+            large: Some(format!("{url}/large")),
+            png: None,
+            border_crop: None,
+            art_crop: None,
+        }
+    }
+
+    fn face(name: &str) -> CardFace {
+        let mut card = serde_json::from_value::<CardFace>(serde_json::json!({
+            "mana_cost": "",
+            "name": name,
+            "object": "card_face",
+        }))
+        .unwrap();
+        card.image_uris = Some(uris(name));
+        card
+    }
+
+    fn deck(entries: Vec<crate::domain::deck::DeckEntry>) -> Deck {
+        let profile = DeckProfile {
+            id: Uuid::nil(),
+            name: DeckName::new("Proxies").unwrap(),
+            commander_id: None,
+            partner_commander_id: None,
+            background_id: None,
+            signature_spell_id: None,
+            format: None,
+            tags: Vec::new(),
+            power_level: None,
+            other_tags: Vec::new(),
+            oracle_tags: Vec::new(),
+            labels: Vec::new(),
+            land_target: None,
+            price_target: None,
+            price_target_currency: None,
+            copy_limit: CopyLimit::Standard,
+            share_token: None,
+            user_id: Uuid::nil(),
+            card_count: 0,
+            commander_name: None,
+            partner_commander_name: None,
+            background_name: None,
+            signature_spell_name: None,
+            color_identity: Vec::new(),
+        };
+        Deck {
+            deck_profile: profile,
+            entries,
+            warnings: Vec::new(),
+            command_zone_cards: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn copies_expand_and_double_faced_cards_get_their_back() {
+        let mut bolt = make_entry("Lightning Bolt", 2);
+        bolt.card.scryfall_data.image_uris = Some(uris("bolt"));
+        let mut delver = make_entry("Delver of Secrets", 1);
+        delver.card.scryfall_data.card_faces =
+            Some(vec![face("Delver of Secrets"), face("Insectile Aberration")].into());
+        let mut maybe = make_entry("Opt", 1);
+        maybe.card.scryfall_data.image_uris = Some(uris("opt"));
+        maybe.deck_card.board = Board::Maybeboard;
+        let mut commander = make_card("Talrand, Sky Summoner");
+        commander.scryfall_data.image_uris = Some(uris("talrand"));
+        let mut deck = deck(vec![bolt, maybe, delver]);
+        deck.command_zone_cards = vec![commander];
+
+        let sheet = ProxySheet::from_deck(&deck);
+        let shown: Vec<(&str, usize)> = sheet
+            .images
+            .iter()
+            .map(|i| (i.image_url.as_str(), i.face))
+            .collect();
+        assert_eq!(
+            shown,
+            [
+                ("talrand/large", 0),
+                ("Delver of Secrets/large", 0),
+                ("Insectile Aberration/large", 1),
+                ("bolt/large", 0),
+                ("bolt/large", 0),
+            ]
+        );
+        assert!(!sheet.truncated);
+    }
+
+    #[test]
+    fn sheet_is_capped_without_splitting_a_card() {
+        let mut forest = make_entry("Forest", 99);
+        forest.card.scryfall_data.image_uris = Some(uris("forest"));
+        let mut island = make_entry("Island", 98);
+        island.card.scryfall_data.image_uris = Some(uris("island"));
+        let mut abbeys = make_entry("Westvale Abbey", 40);
+        abbeys.card.scryfall_data.card_faces =
+            Some(vec![face("Westvale Abbey"), face("Ormendahl, Profane Prince")].into());
+
+        // 197 lands + 26 front/back pairs = 249; a 27th pair would overflow
+        let sheet = ProxySheet::from_deck(&deck(vec![abbeys, island, forest]));
+        assert!(sheet.truncated);
+        assert_eq!(sheet.images.len(), MAX_PROXY_IMAGES - 1);
+        assert_eq!(sheet.images.last().unwrap().face, 1);
+    }
+}
@@ -0,0 +1,194 @@
+//! Deck statistics for charting: mana curve, color pips, type distribution
+//! and average mana value.
+//!
+//! A serializable view over [`DeckMetrics`], so the server and the deck
+//! screen count the active deck the same way. The command zone isn't part
+//! of any distribution.
+
+use crate::domain::deck::{Deck, DeckMetrics};
+use serde::{Deserialize, Serialize};
+
+/// Labels for the [`DeckStats::mana_curve`] buckets.
+pub const MANA_CURVE_LABELS: [&str; 7] = ["0", "1", "2", "3", "4", "5", "6+"];
+
+/// Colored mana symbol counts from the deck's mana costs. Only plain
+/// single-color symbols count; hybrid and Phyrexian symbols are skipped.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColorPips {
+    pub white: usize,
+    pub blue: usize,
+    pub black: usize,
+    pub red: usize,
+    pub green: usize,
+}
+
+/// How many cards of one type the deck holds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TypeCount {
+    /// Type bucket (`lands`, `creatures`, `instants`, ...). A card counts
+    /// once, under its first matching type, lands first.
+    pub card_type: String,
+    /// Cards of that type, by quantity.
+    pub count: usize,
+}
+
+/// Statistics over a deck's active (main) board, cards counted by quantity.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeckStats {
+    /// Total cards on the active board.
+    pub total_cards: usize,
+    /// Land cards on the active board.
+    pub land_count: usize,
+    /// Nonland cards per mana value: 0, 1, 2, 3, 4, 5 and 6+ (see
+    /// [`MANA_CURVE_LABELS`]).
+    pub mana_curve: [usize; 7],
+    /// Colored pips across mana costs.
+    pub color_pips: ColorPips,
+    /// Non-empty type buckets, in a fixed order.
+    pub type_distribution: Vec<TypeCount>,
+    /// Average mana value of nonland cards (0.0 when there are none).
+    pub average_cmc: f64,
+}
+
+impl DeckStats {
+    /// Computes the statistics for `deck`.
+    pub fn new(deck: &Deck) -> Self {
+        Self::from(&DeckMetrics::from_entries(&deck.entries))
+    }
+}
+
+impl From<&DeckMetrics> for DeckStats {
+    fn from(metrics: &DeckMetrics) -> Self {
+        let [white, blue, black, red, green] = metrics.mana_balance.map(|(consumed, _)| consumed);
+        Self {
+            total_cards: metrics.total_cards,
+            land_count: metrics.land_count,
+            mana_curve: metrics.cmc_histogram,
+            color_pips: ColorPips {
+                white,
+                blue,
+                black,
+                red,
+                green,
+            },
+            type_distribution: metrics
+                .type_counts
+                .iter()
+                .map(|(card_type, count)| TypeCount {
+                    card_type: (*card_type).to_string(),
+                    count: *count,
+                })
+                .collect(),
+            average_cmc: metrics.avg_cmc,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::deck::{Board, CopyLimit, DeckEntry, DeckName, deck_profile::DeckProfile},
+        test_utils::make_entry,
+    };
+    use uuid::Uuid;
+
+    fn entry(name: &str, qty: i32, type_line: &str, mana_cost: &str, cmc: f64) -> DeckEntry {
+        let mut entry = make_entry(name, qty);
+        let sd = &mut entry.card.scryfall_data;
+        sd.type_line = Some(type_line.to_string());
+        sd.mana_cost = (!mana_cost.is_empty()).then(|| mana_cost.to_string());
+        sd.cmc = Some(cmc);
+        entry
+    }
+
+    fn deck(entries: Vec<DeckEntry>) -> Deck {
+        Deck {
+            deck_profile: DeckProfile {
+                id: Uuid::nil(),
+                name: DeckName::new("Stats").unwrap(),
+                commander_id: None,
+                partner_commander_id: None,
+                background_id: None,
+                signature_spell_id: None,
+                format: None,
+                tags: Vec::new(),
+                power_level: None,
+                other_tags: Vec::new(),
+                oracle_tags: Vec::new(),
+                labels: Vec::new(),
+                land_target: None,
+                price_target: None,
+                price_target_currency: None,
+                copy_limit: CopyLimit::Standard,
+                share_token: None,
+                user_id: Uuid::nil(),
+                card_count: 0,
+                commander_name: None,
+                partner_commander_name: None,
+                background_name: None,
+                signature_spell_name: None,
+                color_identity: Vec::new(),
+            },
+            entries,
+            warnings: Vec::new(),
+            command_zone_cards: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn counts_curve_pips_and_types_by_quantity() {
+        let mut sideboard = entry("Pyroblast", 3, "Instant", "{R}", 1.0);
+        sideboard.deck_card.board = Board::Sideboard;
+        let stats = DeckStats::new(&deck(vec![
+            entry("Mountain", 10, "Basic Land — Mountain", "", 0.0),
+            entry("Lightning Bolt", 4, "Instant", "{R}", 1.0),
+            entry("Boros Charm", 2, "Instant", "{R}{W}", 2.0),
+            entry("Inferno Titan", 1, "Creature — Giant", "{4}{R}{R}", 6.0),
+            entry("Blightning", 1, "Sorcery", "{1}{B}{R}", 3.0),
+            sideboard,
+        ]));
+
+        assert_eq!(stats.total_cards, 18);
+        assert_eq!(stats.land_count, 10);
+        assert_eq!(stats.mana_curve, [0, 4, 2, 1, 0, 0, 1]);
+        assert_eq!(
+            stats.color_pips,
+            ColorPips {
+                white: 2,
+                blue: 0,
+                black: 1,
+                red: 9,
+                green: 0,
+            }
+        );
+        let types: Vec<(&str, usize)> = stats
+            .type_distribution
+            .iter()
+            .map(|t| (t.card_type.as_str(), t.count))
+            .collect();
+        assert_eq!(
+            types,
+            [
+                ("lands", 10),
+                ("creatures", 1),
+                ("instants", 6),
+                ("sorceries", 1)
+            ]
+        );
+        // (4 + 4 + 6 + 3) / 8 nonland cards
+        assert!((stats.average_cmc - 17.0 / 8.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn an_empty_deck_has_flat_stats() {
+        let stats = DeckStats::new(&deck(Vec::new()));
+        assert_eq!(stats.total_cards, 0);
+        assert_eq!(stats.mana_curve, [0; 7]);
+        assert_eq!(stats.color_pips, ColorPips::default());
+        assert!(stats.type_distribution.is_empty());
+        assert_eq!(stats.average_cmc, 0.0);
+    }
+}
@@ -1,7 +1,8 @@
 //! Deck validation logic.
 //!
 //! Generates informational warnings about deck-building rule violations
-//! based on the deck's format. Does not prevent invalid states.
+//! based on the deck's format, and a [`LegalityReport`] verdict over the
+//! format rules alone. Does not prevent invalid states.
 
 use crate::domain::{
     card::{
@@ -17,6 +18,7 @@ use crate::domain::{
         DeckProfile, DeckWarning, Format, WarningAction, deck::DeckEntry, deck_metrics::deck_price,
     },
 };
+use serde::{Deserialize, Serialize};
 
 /// Cards in the command zone (stored on the profile, not in deck_cards).
 pub struct DeckCommandZone<'a> {
@@ -57,7 +59,8 @@ pub fn validate_deck(
     entries: &[DeckEntry],
     command_zone: &DeckCommandZone,
 ) -> Vec<DeckWarning> {
-    // Only validate active deck cards, not maybeboard or sideboard
+    // Only validate active deck cards, not maybeboard or sideboard (copy
+    // limits and the sideboard's own size aside)
     let active_entries: Vec<DeckEntry> = entries
         .iter()
         .filter(|e| e.deck_card.board.is_active())
@@ -77,7 +80,7 @@ pub fn validate_deck(
     check_land_target(format, deck_profile, &active_entries, &mut warnings);
     check_commander_required(format, deck_profile, &mut warnings);
     check_legality(format, &active_entries, &mut warnings);
-    check_copy_limits(format, &active_entries, &mut warnings);
+    check_copy_limits(deck_profile, entries, &mut warnings);
     check_color_identity(format, &active_entries, command_zone, &mut warnings);
     check_commander_eligibility(format, deck_profile, command_zone, &mut warnings);
     check_partner_validity(format, deck_profile, command_zone, &mut warnings);
@@ -88,6 +91,155 @@ pub fn validate_deck(
     warnings
 }
 
+/// The rule a [`LegalityViolation`] breaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LegalityRule {
+    /// Too few or too many cards for the format.
+    DeckSize,
+    /// A card is banned or not legal in the format.
+    CardLegality,
+    /// More copies of a card than the deck allows (basic lands exempt).
+    CopyLimit,
+    /// A card outside the commander's color identity.
+    ColorIdentity,
+    /// A missing or invalid commander, partner, background, or signature spell.
+    CommandZone,
+    /// The sideboard breaks the format's size limit.
+    Sideboard,
+}
+
+/// One broken rule, with the warning describing it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LegalityViolation {
+    /// Which rule.
+    pub rule: LegalityRule,
+    /// What broke it (and the offending card, if any).
+    pub warning: DeckWarning,
+}
+
+/// A deck's legal/illegal verdict with the rule-by-rule breakdown.
+///
+/// Unlike [`validate_deck`], only format rules count: budget and land target
+/// warnings are advice, not legality. A deck without a format has no rules
+/// to break.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LegalityReport {
+    /// The format checked against.
+    pub format: Option<Format>,
+    /// Every broken rule, grouped by rule in [`LegalityRule`] order.
+    pub violations: Vec<LegalityViolation>,
+}
+
+impl LegalityReport {
+    /// Whether the deck breaks no rule.
+    pub fn is_legal(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// The distinct rules broken, in report order.
+    pub fn broken_rules(&self) -> Vec<LegalityRule> {
+        let mut rules: Vec<LegalityRule> = Vec::new();
+        for violation in &self.violations {
+            if !rules.contains(&violation.rule) {
+                rules.push(violation.rule);
+            }
+        }
+        rules
+    }
+}
+
+/// Runs every format rule over the deck — size, card legality, copy limits,
+/// color identity, the command zone, and the sideboard — and tags each
+/// violation with the rule it breaks.
+pub fn legality_report(
+    deck_profile: &DeckProfile,
+    entries: &[DeckEntry],
+    command_zone: &DeckCommandZone,
+) -> LegalityReport {
+    let Some(format) = &deck_profile.format else {
+        return LegalityReport {
+            format: None,
+            violations: Vec::new(),
+        };
+    };
+    let active_entries: Vec<DeckEntry> = entries
+        .iter()
+        .filter(|e| e.deck_card.board.is_active())
+        .cloned()
+        .collect();
+    let run = |check: &dyn Fn(&mut Vec<DeckWarning>)| {
+        let mut warnings = Vec::new();
+        check(&mut warnings);
+        warnings
+    };
+    let checks = [
+        (
+            LegalityRule::DeckSize,
+            run(&|w| check_card_count(format, deck_profile, w)),
+        ),
+        (
+            LegalityRule::CardLegality,
+            run(&|w| check_legality(format, &active_entries, w)),
+        ),
+        (
+            LegalityRule::CopyLimit,
+            run(&|w| check_copy_limits(deck_profile, entries, w)),
+        ),
+        (
+            LegalityRule::ColorIdentity,
+            run(&|w| check_color_identity(format, &active_entries, command_zone, w)),
+        ),
+        (
+            LegalityRule::CommandZone,
+            run(&|w| {
+                check_commander_required(format, deck_profile, w);
+                check_commander_eligibility(format, deck_profile, command_zone, w);
+                check_partner_validity(format, deck_profile, command_zone, w);
+                check_background_validity(format, deck_profile, command_zone, w);
+                check_signature_spell_validity(format, deck_profile, command_zone, w);
+            }),
+        ),
+        (
+            LegalityRule::Sideboard,
+            run(&|w| check_sideboard_limits(format, entries, w)),
+        ),
+    ];
+    let violations = checks
+        .into_iter()
+        .flat_map(|(rule, warnings)| {
+            warnings
+                .into_iter()
+                .map(move |warning| LegalityViolation { rule, warning })
+        })
+        .collect();
+    LegalityReport {
+        format: Some(*format),
+        violations,
+    }
+}
+
+/// [`legality_report`] as if the deck were built for `format`, e.g. to ask
+/// whether a casual deck would pass in Modern. The deck's own copy limit
+/// (a house rule included) applies only when `format` is its own format;
+/// any other format brings its default limit.
+pub fn legality_report_for(
+    format: Format,
+    deck_profile: &DeckProfile,
+    entries: &[DeckEntry],
+    command_zone: &DeckCommandZone,
+) -> LegalityReport {
+    if deck_profile.format == Some(format) {
+        return legality_report(deck_profile, entries, command_zone);
+    }
+    let retargeted = DeckProfile {
+        format: Some(format),
+        copy_limit: format.copy_limit(),
+        ..deck_profile.clone()
+    };
+    legality_report(&retargeted, entries, command_zone)
+}
+
 fn plural(n: u32) -> &'static str {
     if n == 1 { "card" } else { "cards" }
 }
@@ -225,36 +377,70 @@ fn check_legality(format: &Format, entries: &[DeckEntry], warnings: &mut Vec<Dec
     }
 }
 
-fn check_copy_limits(format: &Format, entries: &[DeckEntry], warnings: &mut Vec<DeckWarning>) {
-    let base_max = format.copy_max();
-
-    for entry in entries {
-        if entry.card.scryfall_data.is_basic_land() {
-            continue;
-        }
+/// Most copies of `card` the deck may run, or `None` for any number.
+///
+/// Starts from the deck's copy limit (basic lands exempt); the card's own
+/// rules text beats it (Relentless Rats: any number; Seven Dwarves: up to
+/// seven), and Vintage restricted cards are limited to one.
+pub fn max_copies_of(profile: &DeckProfile, card: &Card) -> Option<u32> {
+    if !profile.copy_limit.applies_to(&card.scryfall_data) {
+        return None;
+    }
+    if let Some(exception) = card.card_profile.copy_limit {
+        return exception.max();
+    }
+    if profile.format == Some(Format::Vintage)
+        && card.scryfall_data.legalities.get(&Format::Vintage) == Some(&LegalityKind::Restricted)
+    {
+        return Some(1);
+    }
+    Some(profile.copy_limit.max_copies())
+}
 
+/// Copies on the deck and the sideboard count together — a card's four
+/// copies in Modern include the sideboard's — so each card is checked once,
+/// over both. The maybeboard isn't part of the deck.
+fn check_copy_limits(
+    profile: &DeckProfile,
+    entries: &[DeckEntry],
+    warnings: &mut Vec<DeckWarning>,
+) {
+    let mut cards: Vec<(&DeckEntry, u32)> = Vec::new();
+    for entry in entries
+        .iter()
+        .filter(|e| !e.deck_card.board.is_maybeboard())
+    {
         let qty = *entry.deck_card.quantity as u32;
-
-        // Vintage restricted cards are limited to 1 copy
-        let max = if *format == Format::Vintage {
-            match entry.card.scryfall_data.legalities.get(format) {
-                Some(LegalityKind::Restricted) => 1,
-                _ => base_max,
+        match cards
+            .iter_mut()
+            .find(|(seen, _)| seen.deck_card.oracle_id == entry.deck_card.oracle_id)
+        {
+            Some((seen, total)) => {
+                *total += qty;
+                // the fix lands on the main deck's copies when there are some
+                if entry.deck_card.board.is_active() {
+                    *seen = entry;
+                }
             }
-        } else {
-            base_max
-        };
+            None => cards.push((entry, qty)),
+        }
+    }
 
-        if qty > max {
+    for (entry, total) in cards {
+        let Some(max) = max_copies_of(profile, &entry.card) else {
+            continue;
+        };
+        if total > max {
+            let elsewhere = total - *entry.deck_card.quantity as u32;
             warnings.push(DeckWarning::with_action(
                 format!(
                     "{} exceeds copy limit ({}/{})",
                     entry.card.scryfall_data.name.to_lowercase(),
-                    qty,
+                    total,
                     max
                 ),
                 entry.card.scryfall_data.id,
-                WarningAction::FixQuantity(max as i32),
+                WarningAction::FixQuantity(max.saturating_sub(elsewhere).max(1) as i32),
             ));
         }
     }
@@ -561,7 +747,7 @@ fn check_signature_spell_validity(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::deck::DeckName;
+    use crate::domain::deck::{CopyLimit, DeckName};
 
     fn empty_command_zone() -> DeckCommandZone<'static> {
         DeckCommandZone {
@@ -592,9 +778,11 @@ mod tests {
             power_level: None,
             other_tags: Vec::new(),
             oracle_tags: Vec::new(),
+            labels: Vec::new(),
             land_target: None,
             price_target: None,
             price_target_currency: None,
+            copy_limit: CopyLimit::for_format(format),
             share_token: None,
             user_id: uuid::Uuid::new_v4(),
             card_count: 0,
@@ -606,6 +794,162 @@ mod tests {
         }
     }
 
+    mod legality_report {
+        use super::*;
+        use crate::test_utils::make_entry;
+
+        #[test]
+        fn collects_every_broken_rule() {
+            let mut profile = test_profile(Some(Format::Standard));
+            profile.card_count = 60;
+            let mut banned = make_entry("Oko, Thief of Crowns", 1);
+            banned.card.scryfall_data.legalities.standard = Some(LegalityKind::Banned);
+            let mut bolts = make_entry("Lightning Bolt", 5);
+            bolts.card.scryfall_data.legalities.standard = Some(LegalityKind::Legal);
+
+            let report = legality_report(&profile, &[banned, bolts], &empty_command_zone());
+            assert!(!report.is_legal());
+            assert_eq!(
+                report.broken_rules(),
+                [LegalityRule::CardLegality, LegalityRule::CopyLimit]
+            );
+        }
+
+        #[test]
+        fn retargets_a_deck_at_another_format() {
+            let mut profile = test_profile(Some(Format::Standard));
+            profile.card_count = 60;
+            let mut bolts = make_entry("Lightning Bolt", 4);
+            bolts.card.scryfall_data.legalities.standard = Some(LegalityKind::Legal);
+            bolts.card.scryfall_data.legalities.commander = Some(LegalityKind::Legal);
+            let entries = [bolts];
+
+            let own =
+                legality_report_for(Format::Standard, &profile, &entries, &empty_command_zone());
+            assert!(own.is_legal());
+
+            let commander =
+                legality_report_for(Format::Commander, &profile, &entries, &empty_command_zone());
+            assert_eq!(commander.format, Some(Format::Commander));
+            assert!(commander.broken_rules().contains(&LegalityRule::CopyLimit));
+            assert!(commander.broken_rules().contains(&LegalityRule::DeckSize));
+        }
+
+        #[test]
+        fn no_format_is_always_legal() {
+            let report = legality_report(
+                &test_profile(None),
+                &[make_entry("Lightning Bolt", 9)],
+                &empty_command_zone(),
+            );
+            assert!(report.is_legal());
+            assert_eq!(report.format, None);
+        }
+    }
+
+    mod copy_limits {
+        use super::*;
+        use crate::{domain::card::card_profile::CardCopyLimit, test_utils::make_entry};
+
+        fn copy_limit_warnings(entry: DeckEntry, format: Format) -> usize {
+            validate_deck(&test_profile(Some(format)), &[entry], &empty_command_zone())
+                .iter()
+                .filter(|w| w.to_string().contains("exceeds copy limit"))
+                .count()
+        }
+
+        #[test]
+        fn any_number_card_exceeds_the_normal_limit() {
+            let mut rats = make_entry("Relentless Rats", 30);
+            rats.card.card_profile.copy_limit = Some(CardCopyLimit::AnyNumber);
+            assert_eq!(copy_limit_warnings(rats.clone(), Format::Commander), 0);
+            assert_eq!(copy_limit_warnings(rats, Format::Standard), 0);
+        }
+
+        #[test]
+        fn normal_card_cannot_exceed_the_limit() {
+            assert_eq!(
+                copy_limit_warnings(make_entry("Lightning Bolt", 2), Format::Commander),
+                1
+            );
+            assert_eq!(
+                copy_limit_warnings(make_entry("Lightning Bolt", 5), Format::Standard),
+                1
+            );
+            assert_eq!(
+                copy_limit_warnings(make_entry("Lightning Bolt", 4), Format::Standard),
+                0
+            );
+        }
+
+        #[test]
+        fn house_rule_limit_replaces_the_format_limit() {
+            let mut profile = test_profile(Some(Format::Commander));
+            profile.copy_limit = CopyLimit::Custom(2);
+            let warnings = |qty| {
+                validate_deck(
+                    &profile,
+                    &[make_entry("Lightning Bolt", qty)],
+                    &empty_command_zone(),
+                )
+                .iter()
+                .filter(|w| w.to_string().contains("exceeds copy limit"))
+                .count()
+            };
+            assert_eq!(warnings(2), 0);
+            assert_eq!(warnings(3), 1);
+        }
+
+        #[test]
+        fn up_to_card_is_capped_at_its_own_count() {
+            let mut dwarves = make_entry("Seven Dwarves", 7);
+            dwarves.card.card_profile.copy_limit = Some(CardCopyLimit::UpTo(7));
+            assert_eq!(copy_limit_warnings(dwarves.clone(), Format::Commander), 0);
+            dwarves.deck_card.quantity = crate::domain::deck::Quantity::new(8).unwrap();
+            assert_eq!(copy_limit_warnings(dwarves, Format::Commander), 1);
+        }
+
+        #[test]
+        fn sideboard_copies_count_toward_the_limit() {
+            use crate::domain::deck::{Board, Quantity};
+
+            let main = make_entry("Duress", 3);
+            let mut side = main.clone();
+            side.deck_card.board = Board::Sideboard;
+            side.deck_card.quantity = Quantity::new(2).unwrap();
+            let mut maybe = main.clone();
+            maybe.deck_card.board = Board::Maybeboard;
+
+            let profile = test_profile(Some(Format::Modern));
+            let warnings = validate_deck(
+                &profile,
+                &[side, main.clone(), maybe.clone()],
+                &empty_command_zone(),
+            );
+            let over: Vec<_> = warnings
+                .iter()
+                .filter(|w| w.to_string().contains("exceeds copy limit"))
+                .map(|w| (w.to_string(), w.action().cloned()))
+                .collect();
+            // trimming the main deck to 2 makes room for the sideboard's 2
+            assert_eq!(
+                over,
+                [(
+                    "duress exceeds copy limit (5/4)".to_string(),
+                    Some(WarningAction::FixQuantity(2))
+                )]
+            );
+
+            // the maybeboard alone never counts
+            let warnings = validate_deck(&profile, &[main, maybe], &empty_command_zone());
+            assert!(
+                !warnings
+                    .iter()
+                    .any(|w| w.to_string().contains("exceeds copy limit"))
+            );
+        }
+    }
+
     mod land_target {
         use super::*;
         use crate::{
@@ -623,6 +967,7 @@ mod tests {
                 quantity: Quantity::new(qty).unwrap(),
                 board: Board::Deck,
                 mvp_at: None,
+                reference: Default::default(),
             };
             DeckEntry { card, deck_card }
         }
@@ -0,0 +1,109 @@
+//! Bulk add cards to a deck operation (many cards in one call).
+
+use crate::domain::deck::{Board, InvalidQuantity, Quantity};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors that can occur while constructing a [`BulkAddDeckCards`] request.
+#[derive(Debug, Error)]
+pub enum InvalidBulkAddDeckCards {
+    /// Nothing to add.
+    #[error("no cards to add")]
+    NoCards,
+    /// A card's quantity is below one.
+    #[error(transparent)]
+    Quantity(#[from] InvalidQuantity),
+}
+
+/// Request to add several cards to one deck at once.
+///
+/// Every card lands on `board`, merged into the existing entry when the deck
+/// already runs the card (that entry keeps its own printing and board). The
+/// whole batch is added or none of it is.
+#[derive(Debug, Clone)]
+pub struct BulkAddDeckCards {
+    /// Requesting user; must own the deck.
+    pub user_id: Uuid,
+    /// Deck to add the cards to.
+    pub deck_id: Uuid,
+    /// Board new entries go on.
+    pub board: Board,
+    /// Cards (Scryfall data IDs) and how many copies of each to add; each
+    /// card appears once.
+    pub cards: Vec<(Uuid, Quantity)>,
+    /// Whether the requesting user's email is verified.
+    pub email_verified: bool,
+}
+
+impl BulkAddDeckCards {
+    /// Creates a new bulk add request with validation. Repeated cards are
+    /// merged by summing their quantities.
+    pub fn new(
+        user_id: Uuid,
+        deck_id: Uuid,
+        board: Option<Board>,
+        cards: impl IntoIterator<Item = (Uuid, i32)>,
+        email_verified: bool,
+    ) -> Result<Self, InvalidBulkAddDeckCards> {
+        let mut merged: Vec<(Uuid, i32)> = Vec::new();
+        for (scryfall_data_id, quantity) in cards {
+            Quantity::new(quantity)?;
+            match merged.iter_mut().find(|(id, _)| *id == scryfall_data_id) {
+                Some((_, total)) => *total = total.saturating_add(quantity),
+                None => merged.push((scryfall_data_id, quantity)),
+            }
+        }
+        if merged.is_empty() {
+            return Err(InvalidBulkAddDeckCards::NoCards);
+        }
+        let cards = merged
+            .into_iter()
+            .map(|(id, quantity)| Ok((id, Quantity::new(quantity)?)))
+            .collect::<Result<_, InvalidQuantity>>()?;
+        Ok(Self {
+            user_id,
+            deck_id,
+            board: board.unwrap_or_default(),
+            cards,
+            email_verified,
+        })
+    }
+
+    /// Total copies across the batch.
+    pub fn total_quantity(&self) -> i64 {
+        self.cards.iter().map(|(_, q)| i64::from(**q)).sum()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_cards_are_merged() {
+        let (user, deck) = (Uuid::new_v4(), Uuid::new_v4());
+        let (bolt, opt) = (Uuid::new_v4(), Uuid::new_v4());
+        let request =
+            BulkAddDeckCards::new(user, deck, None, [(bolt, 2), (opt, 1), (bolt, 1)], true)
+                .unwrap();
+        assert_eq!(request.board, Board::Deck);
+        assert_eq!(request.cards.len(), 2);
+        assert_eq!(request.cards[0].0, bolt);
+        assert_eq!(*request.cards[0].1, 3);
+        assert_eq!(request.total_quantity(), 4);
+    }
+
+    #[test]
+    fn rejects_empty_and_non_positive_batches() {
+        let (user, deck) = (Uuid::new_v4(), Uuid::new_v4());
+        assert!(matches!(
+            BulkAddDeckCards::new(user, deck, None, [], true),
+            Err(InvalidBulkAddDeckCards::NoCards)
+        ));
+        assert!(matches!(
+            BulkAddDeckCards::new(user, deck, None, [(Uuid::new_v4(), 0)], true),
+            Err(InvalidBulkAddDeckCards::Quantity(_))
+        ));
+    }
+}
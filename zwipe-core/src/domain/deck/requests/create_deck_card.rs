@@ -1,6 +1,6 @@
 //! Add card to deck operation.
 
-use crate::domain::deck::{Board, InvalidQuantity, Quantity};
+use crate::domain::deck::{Board, CardReference, InvalidQuantity, Quantity};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -44,6 +44,8 @@ pub struct CreateDeckCard {
     pub board: Board,
     /// Whether the requesting user's email is verified.
     pub email_verified: bool,
+    /// Pin the printing or follow the oracle card. Defaults to pinned.
+    pub reference: CardReference,
 }
 
 impl CreateDeckCard {
@@ -71,6 +73,13 @@ impl CreateDeckCard {
             board: board.unwrap_or_default(),
             user_id,
             email_verified,
+            reference: CardReference::default(),
         })
     }
+
+    /// Sets how the deck card refers to its card.
+    pub fn with_reference(mut self, reference: CardReference) -> Self {
+        self.reference = reference;
+        self
+    }
 }
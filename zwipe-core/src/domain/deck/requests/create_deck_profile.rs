@@ -3,8 +3,9 @@
 use crate::domain::{
     card::search_card::card_filter::price_currency::PriceCurrency,
     deck::{
-        DeckName, DeckOtherTag, DeckTag, InvalidDeckOtherTag, InvalidDeckTag, InvalidDeckname,
-        InvalidPowerLevel, MAX_DECK_ORACLE_TAGS, MAX_DECK_OTHER_TAGS, MAX_DECK_TAGS, PowerLevel,
+        CopyLimit, DeckName, DeckOtherTag, DeckTag, InvalidDeckOtherTag, InvalidDeckTag,
+        InvalidDeckname, InvalidPowerLevel, MAX_DECK_ORACLE_TAGS, MAX_DECK_OTHER_TAGS,
+        MAX_DECK_TAGS, PowerLevel,
         deck_oracle_tags::dedupe_oracle_tags,
         deck_other_tag::parse_other_tags,
         deck_tag::parse_tags,
@@ -41,6 +42,9 @@ pub enum InvalidCreateDeckProfile {
     /// More than [`MAX_DECK_ORACLE_TAGS`] oracle tags were supplied.
     #[error("a deck may have at most {MAX_DECK_ORACLE_TAGS} oracle tags")]
     TooManyOracleTags,
+    /// A custom copy limit of zero.
+    #[error("a custom copy limit must allow at least one copy")]
+    ZeroCopyLimit,
 }
 
 /// Request to create a new deck profile.
@@ -72,6 +76,8 @@ pub struct CreateDeckProfile {
     pub price_target: Option<f64>,
     /// Currency for the price target. `None` = USD.
     pub price_target_currency: Option<PriceCurrency>,
+    /// Copy limit: the owner's pick, else the format's.
+    pub copy_limit: CopyLimit,
     /// Owner of this deck.
     pub user_id: Uuid,
     /// Whether the requesting user's email is verified.
@@ -101,6 +107,7 @@ impl CreateDeckProfile {
             land_target: None,
             price_target: None,
             price_target_currency: None,
+            copy_limit: None,
         }
     }
 }
@@ -122,6 +129,7 @@ pub struct CreateDeckProfileBuilder {
     land_target: Option<i32>,
     price_target: Option<f64>,
     price_target_currency: Option<PriceCurrency>,
+    copy_limit: Option<CopyLimit>,
 }
 
 impl CreateDeckProfileBuilder {
@@ -197,6 +205,12 @@ impl CreateDeckProfileBuilder {
         self
     }
 
+    /// Sets a house-rule copy limit; `None` follows the format.
+    pub fn copy_limit(mut self, copy_limit: Option<CopyLimit>) -> Self {
+        self.copy_limit = copy_limit;
+        self
+    }
+
     /// Validates and builds the request.
     pub fn build(self) -> Result<CreateDeckProfile, InvalidCreateDeckProfile> {
         let name = DeckName::new(self.name)?;
@@ -218,6 +232,12 @@ impl CreateDeckProfileBuilder {
         if oracle_tags.len() > MAX_DECK_ORACLE_TAGS {
             return Err(InvalidCreateDeckProfile::TooManyOracleTags);
         }
+        let copy_limit = self
+            .copy_limit
+            .unwrap_or_else(|| CopyLimit::for_format(format));
+        if copy_limit.max_copies() == 0 {
+            return Err(InvalidCreateDeckProfile::ZeroCopyLimit);
+        }
         Ok(CreateDeckProfile {
             name,
             commander_id: self.commander_id,
@@ -232,6 +252,7 @@ impl CreateDeckProfileBuilder {
             land_target: self.land_target,
             price_target: self.price_target,
             price_target_currency: self.price_target_currency,
+            copy_limit,
             user_id: self.user_id,
             email_verified: self.email_verified,
         })
@@ -266,4 +287,24 @@ mod tests {
         let res = builder(vec!["aggro", "control", "tokens", "burn", "mill", "stax"]).build();
         assert!(matches!(res, Err(InvalidCreateDeckProfile::TooManyTags)));
     }
+
+    #[test]
+    fn copy_limit_follows_the_format_unless_set() {
+        let commander = builder(vec![]).format(Some("commander")).build().unwrap();
+        assert_eq!(commander.copy_limit, CopyLimit::Singleton);
+        let formatless = builder(vec![]).build().unwrap();
+        assert_eq!(formatless.copy_limit, CopyLimit::Standard);
+        let house = builder(vec![])
+            .format(Some("commander"))
+            .copy_limit(Some(CopyLimit::Custom(2)))
+            .build()
+            .unwrap();
+        assert_eq!(house.copy_limit, CopyLimit::Custom(2));
+        assert!(matches!(
+            builder(vec![])
+                .copy_limit(Some(CopyLimit::Custom(0)))
+                .build(),
+            Err(InvalidCreateDeckProfile::ZeroCopyLimit)
+        ));
+    }
 }
@@ -1,5 +1,6 @@
 //! Delete card from deck operation.
 
+use crate::domain::deck::Board;
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -23,6 +24,9 @@ pub struct DeleteDeckCard {
     pub deck_id: Uuid,
     /// Card to remove (Scryfall data ID).
     pub scryfall_data_id: Uuid,
+    /// Which copy of the card to remove: the sideboard's, or the main one
+    /// (deck or maybeboard). `None` = the main copy if there is one.
+    pub from_board: Option<Board>,
 }
 
 impl DeleteDeckCard {
@@ -39,6 +43,14 @@ impl DeleteDeckCard {
             user_id,
             deck_id,
             scryfall_data_id,
+            from_board: None,
         })
     }
+
+    /// Targets the copy of the card on `board` (see
+    /// [`from_board`](Self::from_board)).
+    pub fn on_board(mut self, board: Board) -> Self {
+        self.from_board = Some(board);
+        self
+    }
 }
@@ -6,6 +6,8 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use uuid::Uuid;
 
+use crate::domain::deck::models::deck_label::normalize_deck_label;
+
 #[allow(missing_docs)]
 #[derive(Debug, Error)]
 pub enum InvalidGetDeckProfiles {
@@ -23,12 +25,25 @@ impl From<uuid::Error> for InvalidGetDeckProfiles {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetDeckProfiles {
     pub user_id: Uuid,
+    /// Only decks carrying this (normalized) label; `None` = every deck.
+    #[serde(default)]
+    pub label: Option<String>,
 }
 
 #[allow(missing_docs)]
 impl GetDeckProfiles {
     pub fn new(user_id: Uuid) -> Self {
-        Self { user_id }
+        Self {
+            user_id,
+            label: None,
+        }
+    }
+
+    /// Narrows the listing to decks labelled `label`, compared normalized.
+    /// A blank label filters nothing.
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = normalize_deck_label(label);
+        self
     }
 }
 
@@ -36,6 +51,6 @@ impl FromStr for GetDeckProfiles {
     type Err = InvalidGetDeckProfiles;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let user_id = Uuid::try_parse(s)?;
-        Ok(Self { user_id })
+        Ok(Self::new(user_id))
     }
 }
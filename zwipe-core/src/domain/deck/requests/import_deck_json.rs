@@ -0,0 +1,149 @@
+//! Restore a deck from a JSON backup ([`DeckExport`]).
+//!
+//! The export's settings are validated exactly as a new deck's would be; its
+//! oracle ids are resolved to current printings by the server.
+
+use crate::domain::deck::{
+    CopyLimit, DECK_EXPORT_VERSION, DeckExport, DeckExportCard, InvalidDeckLabels,
+    deck_profile::DeckProfile,
+    normalize_deck_labels,
+    requests::create_deck_profile::{CreateDeckProfile, InvalidCreateDeckProfile},
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors that can occur while constructing an [`ImportDeckJson`] request.
+#[derive(Debug, Error)]
+pub enum InvalidImportDeckJson {
+    /// The export was written by a newer (or unknown) schema version.
+    #[error("unsupported deck export version {0}")]
+    UnsupportedVersion(u32),
+    /// The export's settings don't make a valid deck.
+    #[error(transparent)]
+    Profile(#[from] InvalidCreateDeckProfile),
+    /// The export's labels are invalid.
+    #[error(transparent)]
+    Labels(#[from] InvalidDeckLabels),
+}
+
+/// Request to create a new deck from a [`DeckExport`].
+#[derive(Debug, Clone)]
+pub struct ImportDeckJson {
+    /// The new deck's validated settings. Command zone ids are unset here;
+    /// the service fills them from the resolved printings.
+    pub profile: CreateDeckProfile,
+    /// Normalized labels for the new deck.
+    pub labels: Vec<String>,
+    /// Commander, by oracle id.
+    pub commander: Option<Uuid>,
+    /// Partner commander, by oracle id.
+    pub partner_commander: Option<Uuid>,
+    /// Background, by oracle id.
+    pub background: Option<Uuid>,
+    /// Signature spell, by oracle id.
+    pub signature_spell: Option<Uuid>,
+    /// Card lines, by oracle id.
+    pub cards: Vec<DeckExportCard>,
+}
+
+impl ImportDeckJson {
+    /// Validates `export` as a new deck owned by `user_id`.
+    pub fn new(
+        user_id: Uuid,
+        email_verified: bool,
+        export: DeckExport,
+    ) -> Result<Self, InvalidImportDeckJson> {
+        if export.version == 0 || export.version > DECK_EXPORT_VERSION {
+            return Err(InvalidImportDeckJson::UnsupportedVersion(export.version));
+        }
+        let mut profile = CreateDeckProfile::builder(export.name, user_id, email_verified)
+            .tags(export.tags)
+            .other_tags(export.other_tags)
+            .oracle_tags(export.oracle_tags)
+            .land_target(export.land_target)
+            .price_target(export.price_target)
+            .price_target_currency(export.price_target_currency)
+            .build()?;
+        profile.format = export.format;
+        profile.power_level = export.power_level;
+        profile.copy_limit = match export.copy_limit {
+            Some(CopyLimit::Custom(0)) => {
+                return Err(InvalidCreateDeckProfile::ZeroCopyLimit.into());
+            }
+            Some(copy_limit) => copy_limit,
+            None => CopyLimit::for_format(export.format),
+        };
+        Ok(Self {
+            profile,
+            labels: normalize_deck_labels(&export.labels)?,
+            commander: export.commander,
+            partner_commander: export.partner_commander,
+            background: export.background,
+            signature_spell: export.signature_spell,
+            cards: export.cards,
+        })
+    }
+
+    /// Every oracle id to resolve: the command zone, then the cards.
+    pub fn oracle_ids(&self) -> Vec<Uuid> {
+        [
+            self.commander,
+            self.partner_commander,
+            self.background,
+            self.signature_spell,
+        ]
+        .into_iter()
+        .flatten()
+        .chain(self.cards.iter().map(|card| card.oracle_id))
+        .collect()
+    }
+}
+
+/// Restore result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportDeckJsonResult {
+    /// The newly created deck.
+    pub deck: DeckProfile,
+    /// Oracle ids with no printing left to resolve to; those cards (or
+    /// command zone slots) were skipped.
+    pub unresolved: Vec<Uuid>,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    fn export(version: u32) -> DeckExport {
+        serde_json::from_value(serde_json::json!({
+            "version": version,
+            "name": "Restored",
+            "format": "commander",
+            "power_level": "optimized",
+            "other_tags": ["budget"],
+            "labels": ["CEDH"],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn validates_settings_and_normalizes_labels() {
+        let request = ImportDeckJson::new(Uuid::new_v4(), true, export(1)).unwrap();
+        assert_eq!(request.profile.name.to_string(), "Restored");
+        assert!(request.profile.format.is_some());
+        assert!(request.profile.power_level.is_some());
+        assert_eq!(request.labels, ["cedh"]);
+        assert!(request.oracle_ids().is_empty());
+    }
+
+    #[test]
+    fn rejects_unknown_versions() {
+        for version in [0, DECK_EXPORT_VERSION + 1] {
+            assert!(matches!(
+                ImportDeckJson::new(Uuid::new_v4(), true, export(version)),
+                Err(InvalidImportDeckJson::UnsupportedVersion(v)) if v == version
+            ));
+        }
+    }
+}
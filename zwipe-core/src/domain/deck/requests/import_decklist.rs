@@ -0,0 +1,81 @@
+//! Create a deck from a pasted decklist (plain text, Arena or MTGO).
+//!
+//! The list is parsed here; the server resolves each line to a card by name,
+//! taking the named printing when the line gives one.
+
+use crate::domain::deck::{
+    DecklistLine, DecklistSection, ExportFormat,
+    deck_profile::DeckProfile,
+    format::Format,
+    requests::{
+        create_deck_profile::{CreateDeckProfile, InvalidCreateDeckProfile},
+        import_deck_cards::{ImportedCard, UnresolvedCard},
+    },
+};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Request to create a new deck from decklist text.
+#[derive(Debug, Clone)]
+pub struct ImportDecklist {
+    /// The new deck's validated settings. Command zone ids are unset here;
+    /// the service fills them from the resolved `Commander` lines.
+    pub profile: CreateDeckProfile,
+    /// Parsed card lines.
+    pub lines: Vec<DecklistLine>,
+}
+
+impl ImportDecklist {
+    /// Parses `text` as a `format` decklist for a new deck named `name`,
+    /// owned by `user_id`. A list with a `Commander` section makes a
+    /// Commander deck.
+    pub fn new(
+        user_id: Uuid,
+        email_verified: bool,
+        name: impl Into<String>,
+        text: &str,
+        format: ExportFormat,
+    ) -> Result<Self, InvalidCreateDeckProfile> {
+        let lines = format.parse(text);
+        let has_commander = lines
+            .iter()
+            .any(|line| line.section == DecklistSection::Commander);
+        let profile = CreateDeckProfile::builder(name, user_id, email_verified)
+            .format(has_commander.then(|| Format::Commander.to_legality_key()))
+            .build()?;
+        Ok(Self { profile, lines })
+    }
+}
+
+/// Decklist import result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportReport {
+    /// The newly created deck.
+    pub deck: DeckProfile,
+    /// Cards that were added (command zone included).
+    pub imported: Vec<ImportedCard>,
+    /// Lines that matched no card, or more than one.
+    pub unresolved: Vec<UnresolvedCard>,
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_commander_section_makes_a_commander_deck() {
+        let text = "Commander\n1 Krenko, Mob Boss\n\nDeck\n40 Mountain\n";
+        let request =
+            ImportDecklist::new(Uuid::nil(), true, "Goblins", text, ExportFormat::PlainText)
+                .unwrap();
+        assert_eq!(request.profile.format, Some(Format::Commander));
+        assert_eq!(request.lines.len(), 2);
+
+        let request =
+            ImportDecklist::new(Uuid::nil(), true, "Burn", "20 Mountain", ExportFormat::Mtgo)
+                .unwrap();
+        assert_eq!(request.profile.format, None);
+        assert!(ImportDecklist::new(Uuid::nil(), true, "", "", ExportFormat::Arena).is_err());
+    }
+}
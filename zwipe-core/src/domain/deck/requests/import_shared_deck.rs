@@ -0,0 +1,44 @@
+//! Shared deck import request.
+//!
+//! Copies a deck someone shared (profile + all entries on every board) into
+//! the caller's account. The share token is the only authority needed; the
+//! copy follows each card to its current preferred printing.
+
+use crate::domain::deck::models::deck_name::{DeckName, InvalidDeckname};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Request to copy a shared deck into the caller's account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSharedDeck {
+    /// Share token of the deck to copy. Must be live (not revoked).
+    pub share_token: Uuid,
+    /// Validated name for the copy; `None` keeps the shared deck's name.
+    pub new_name: Option<DeckName>,
+    /// Authenticated caller (owner of the copy).
+    pub user_id: Uuid,
+    /// Whether the caller's email is verified (affects deck count limit).
+    pub email_verified: bool,
+}
+
+impl ImportSharedDeck {
+    /// Creates a new import request, validating the new deck name if given.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidDeckname`] if `new_name` fails [`DeckName`]
+    /// validation (empty, too long, or profanity).
+    pub fn new(
+        share_token: Uuid,
+        new_name: Option<String>,
+        user_id: Uuid,
+        email_verified: bool,
+    ) -> Result<Self, InvalidDeckname> {
+        Ok(Self {
+            share_token,
+            new_name: new_name.map(DeckName::new).transpose()?,
+            user_id,
+            email_verified,
+        })
+    }
+}
@@ -1,3 +1,4 @@
+pub mod bulk_add_deck_cards;
 pub mod clear_deck_suppressions;
 pub mod clone_deck;
 pub mod create_deck_card;
@@ -8,10 +9,17 @@ pub mod get_deck_card;
 pub mod get_deck_profile;
 pub mod get_deck_profiles;
 pub mod import_deck_cards;
+pub mod import_deck_json;
+pub mod import_decklist;
+pub mod import_shared_deck;
+pub mod move_deck_cards;
+pub mod set_deck_labels;
+pub mod set_owned_card;
 pub mod skip_deck_card;
 pub mod update_deck_card;
 pub mod update_deck_profile;
 
+pub use bulk_add_deck_cards::{BulkAddDeckCards, InvalidBulkAddDeckCards};
 pub use clear_deck_suppressions::{ClearDeckSuppressions, InvalidClearDeckSuppressions};
 pub use clone_deck::{CloneDeck, InvalidCloneDeck};
 pub use create_deck_card::{CreateDeckCard, InvalidCreateDeckCard};
@@ -24,6 +32,12 @@ pub use get_deck_profiles::{GetDeckProfiles, InvalidGetDeckProfiles};
 pub use import_deck_cards::{
     ImportDeckCards, ImportDeckCardsResult, ImportLine, ImportedCard, UnresolvedCard,
 };
+pub use import_deck_json::{ImportDeckJson, ImportDeckJsonResult, InvalidImportDeckJson};
+pub use import_decklist::{ImportDecklist, ImportReport};
+pub use import_shared_deck::ImportSharedDeck;
+pub use move_deck_cards::{InvalidMoveDeckCards, MoveDeckCards};
+pub use set_deck_labels::SetDeckLabels;
+pub use set_owned_card::{InvalidSetOwnedCard, SetOwnedCard};
 pub use skip_deck_card::{InvalidSkipDeckCard, SkipDeckCard};
 pub use update_deck_card::{InvalidUpdateDeckCard, UpdateDeckCard};
 pub use update_deck_profile::{InvalidUpdateDeckProfile, UpdateDeckProfile};
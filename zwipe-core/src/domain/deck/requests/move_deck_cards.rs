@@ -0,0 +1,108 @@
+//! Move deck cards operation (transfer quantities from one deck to another).
+
+use crate::domain::deck::{InvalidQuantity, Quantity};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors that can occur while constructing a [`MoveDeckCards`] request.
+#[derive(Debug, Error)]
+pub enum InvalidMoveDeckCards {
+    /// Source and destination are the same deck.
+    #[error("cannot move cards to the deck they are already in")]
+    SameDeck,
+    /// Nothing to move.
+    #[error("no cards to move")]
+    NoCards,
+    /// A card's quantity is below one.
+    #[error(transparent)]
+    Quantity(#[from] InvalidQuantity),
+}
+
+/// Request to move cards between two decks owned by the same user.
+///
+/// Each card leaves the source deck (its entry is removed once no copies
+/// remain) and joins the destination — merged into the existing entry if
+/// the destination already runs it, otherwise added on the board it had.
+#[derive(Debug, Clone)]
+pub struct MoveDeckCards {
+    /// Requesting user; must own both decks.
+    pub user_id: Uuid,
+    /// Deck the cards leave.
+    pub from_deck_id: Uuid,
+    /// Deck the cards join.
+    pub to_deck_id: Uuid,
+    /// Cards (Scryfall data IDs in the source deck) and how many copies of
+    /// each to move; each card appears once.
+    pub cards: Vec<(Uuid, Quantity)>,
+}
+
+impl MoveDeckCards {
+    /// Creates a new move request with validation. Repeated cards are merged
+    /// by summing their quantities.
+    pub fn new(
+        user_id: Uuid,
+        from_deck_id: Uuid,
+        to_deck_id: Uuid,
+        cards: impl IntoIterator<Item = (Uuid, i32)>,
+    ) -> Result<Self, InvalidMoveDeckCards> {
+        if from_deck_id == to_deck_id {
+            return Err(InvalidMoveDeckCards::SameDeck);
+        }
+        let mut merged: Vec<(Uuid, i32)> = Vec::new();
+        for (scryfall_data_id, quantity) in cards {
+            Quantity::new(quantity)?;
+            match merged.iter_mut().find(|(id, _)| *id == scryfall_data_id) {
+                Some((_, total)) => *total = total.saturating_add(quantity),
+                None => merged.push((scryfall_data_id, quantity)),
+            }
+        }
+        if merged.is_empty() {
+            return Err(InvalidMoveDeckCards::NoCards);
+        }
+        let cards = merged
+            .into_iter()
+            .map(|(id, quantity)| Ok((id, Quantity::new(quantity)?)))
+            .collect::<Result<_, InvalidQuantity>>()?;
+        Ok(Self {
+            user_id,
+            from_deck_id,
+            to_deck_id,
+            cards,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used, clippy::indexing_slicing)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_cards_are_merged() {
+        let (user, from, to) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let (bolt, opt) = (Uuid::new_v4(), Uuid::new_v4());
+        let request = MoveDeckCards::new(user, from, to, [(bolt, 2), (opt, 1), (bolt, 1)]).unwrap();
+        assert_eq!(request.cards.len(), 2);
+        assert_eq!(request.cards[0].0, bolt);
+        assert_eq!(*request.cards[0].1, 3);
+        assert_eq!(*request.cards[1].1, 1);
+    }
+
+    #[test]
+    fn rejects_same_deck_empty_and_non_positive_moves() {
+        let (user, deck) = (Uuid::new_v4(), Uuid::new_v4());
+        let card = Uuid::new_v4();
+        assert!(matches!(
+            MoveDeckCards::new(user, deck, deck, [(card, 1)]),
+            Err(InvalidMoveDeckCards::SameDeck)
+        ));
+        assert!(matches!(
+            MoveDeckCards::new(user, deck, Uuid::new_v4(), []),
+            Err(InvalidMoveDeckCards::NoCards)
+        ));
+        assert!(matches!(
+            MoveDeckCards::new(user, deck, Uuid::new_v4(), [(card, 0)]),
+            Err(InvalidMoveDeckCards::Quantity(_))
+        ));
+    }
+}
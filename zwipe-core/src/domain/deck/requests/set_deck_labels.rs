@@ -0,0 +1,31 @@
+//! Set deck labels operation (replace a deck's user-defined labels).
+
+use uuid::Uuid;
+
+use crate::domain::deck::models::deck_label::{InvalidDeckLabels, normalize_deck_labels};
+
+/// Request to replace every label on a deck. An empty list clears them.
+#[derive(Debug, Clone)]
+pub struct SetDeckLabels {
+    /// Requesting user (for authorization; labels are per-user).
+    pub user_id: Uuid,
+    /// Deck to label.
+    pub deck_id: Uuid,
+    /// Normalized, deduped, sorted labels.
+    pub labels: Vec<String>,
+}
+
+impl SetDeckLabels {
+    /// Creates a new request, normalizing `labels`.
+    pub fn new<S: AsRef<str>>(
+        user_id: Uuid,
+        deck_id: Uuid,
+        labels: &[S],
+    ) -> Result<Self, InvalidDeckLabels> {
+        Ok(Self {
+            user_id,
+            deck_id,
+            labels: normalize_deck_labels(labels)?,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+//! Set owned card operation (record how many copies of a card a user owns).
+
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Errors that can occur while constructing a [`SetOwnedCard`] request.
+#[derive(Debug, Error)]
+pub enum InvalidSetOwnedCard {
+    /// Owned quantities can't go below zero.
+    #[error("owned quantity must not be negative")]
+    NegativeQuantity,
+}
+
+/// Request to set a user's owned quantity of one card. `0` removes it from
+/// the collection.
+#[derive(Debug, Clone)]
+pub struct SetOwnedCard {
+    /// Collection owner.
+    pub user_id: Uuid,
+    /// The oracle card (any printing counts).
+    pub oracle_id: Uuid,
+    /// Copies owned.
+    pub quantity: i32,
+}
+
+impl SetOwnedCard {
+    /// Creates a new request with validation.
+    pub fn new(user_id: Uuid, oracle_id: Uuid, quantity: i32) -> Result<Self, InvalidSetOwnedCard> {
+        if quantity < 0 {
+            return Err(InvalidSetOwnedCard::NegativeQuantity);
+        }
+        Ok(Self {
+            user_id,
+            oracle_id,
+            quantity,
+        })
+    }
+}
@@ -1,6 +1,6 @@
 //! Update deck card operation (quantity delta and/or board change).
 
-use crate::domain::deck::{Board, InvalidUpdateQuanity, UpdateQuantity};
+use crate::domain::deck::{Board, CardReference, InvalidUpdateQuanity, UpdateQuantity};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -20,7 +20,9 @@ pub enum InvalidUpdateDeckCard {
     #[error(transparent)]
     UpdateQuantity(InvalidUpdateQuanity),
     /// No fields provided to update.
-    #[error("at least one of update_quantity, board, scryfall_data_id, or mvp must be provided")]
+    #[error(
+        "at least one of update_quantity, board, scryfall_data_id, mvp, or reference must be provided"
+    )]
     NothingToUpdate,
 }
 
@@ -42,6 +44,9 @@ pub struct UpdateDeckCard {
     pub deck_id: Uuid,
     /// Card to update (Scryfall data ID).
     pub scryfall_data_id: Uuid,
+    /// Which copy of the card to update: the sideboard's, or the main one
+    /// (deck or maybeboard). `None` = the main copy if there is one.
+    pub from_board: Option<Board>,
     /// Delta value (positive = add, negative = remove). `None` = no quantity change.
     pub update_quantity: Option<UpdateQuantity>,
     /// Move card to this board. `None` = no change.
@@ -50,13 +55,16 @@ pub struct UpdateDeckCard {
     pub new_scryfall_data_id: Option<Uuid>,
     /// Star (true) or unstar (false) the card as a deck MVP. `None` = no change.
     pub mvp: Option<bool>,
+    /// Pin the printing or follow the oracle card. `None` = no change.
+    pub reference: Option<CardReference>,
 }
 
 impl UpdateDeckCard {
     /// Creates a new deck card update request with validation.
     ///
-    /// At least one of `update_quantity`, `board`, `new_scryfall_data_id`, or
-    /// `mvp` must be `Some`.
+    /// At least one of `update_quantity`, `board`, `new_scryfall_data_id`,
+    /// `mvp`, or `reference` must be `Some`.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         user_id: Uuid,
         deck_id: &str,
@@ -65,6 +73,7 @@ impl UpdateDeckCard {
         board: Option<Board>,
         new_scryfall_data_id: Option<&str>,
         mvp: Option<bool>,
+        reference: Option<CardReference>,
     ) -> Result<Self, InvalidUpdateDeckCard> {
         let deck_id = Uuid::try_parse(deck_id).map_err(InvalidUpdateDeckCard::DeckId)?;
         let scryfall_data_id =
@@ -80,6 +89,7 @@ impl UpdateDeckCard {
             && board.is_none()
             && new_scryfall_data_id.is_none()
             && mvp.is_none()
+            && reference.is_none()
         {
             return Err(InvalidUpdateDeckCard::NothingToUpdate);
         }
@@ -88,10 +98,19 @@ impl UpdateDeckCard {
             user_id,
             deck_id,
             scryfall_data_id,
+            from_board: None,
             update_quantity,
             board,
             new_scryfall_data_id,
             mvp,
+            reference,
         })
     }
+
+    /// Targets the copy of the card on `board` (see
+    /// [`from_board`](Self::from_board)).
+    pub fn on_board(mut self, board: Board) -> Self {
+        self.from_board = Some(board);
+        self
+    }
 }
@@ -3,8 +3,9 @@
 use crate::domain::{
     card::search_card::card_filter::price_currency::PriceCurrency,
     deck::{
-        DeckName, DeckOtherTag, DeckTag, InvalidDeckOtherTag, InvalidDeckTag, InvalidDeckname,
-        InvalidPowerLevel, MAX_DECK_ORACLE_TAGS, MAX_DECK_OTHER_TAGS, MAX_DECK_TAGS, PowerLevel,
+        CopyLimit, DeckName, DeckOtherTag, DeckTag, InvalidDeckOtherTag, InvalidDeckTag,
+        InvalidDeckname, InvalidPowerLevel, MAX_DECK_ORACLE_TAGS, MAX_DECK_OTHER_TAGS,
+        MAX_DECK_TAGS, PowerLevel,
         deck_oracle_tags::dedupe_oracle_tags,
         deck_other_tag::parse_other_tags,
         deck_tag::parse_tags,
@@ -41,6 +42,9 @@ pub enum InvalidUpdateDeckProfile {
     /// More than [`MAX_DECK_ORACLE_TAGS`] oracle tags were supplied.
     #[error("a deck may have at most {MAX_DECK_ORACLE_TAGS} oracle tags")]
     TooManyOracleTags,
+    /// A custom copy limit of zero.
+    #[error("a custom copy limit must allow at least one copy")]
+    ZeroCopyLimit,
     /// No fields specified for update.
     #[error("must update at least one field")]
     NoUpdates,
@@ -113,6 +117,9 @@ pub struct UpdateDeckProfile {
     pub price_target: Option<Option<f64>>,
     /// Optional price target currency update.
     pub price_target_currency: Option<Option<PriceCurrency>>,
+    /// Optional copy limit update. A format change without an explicit limit
+    /// resets it to the new format's; `None` leaves it untouched.
+    pub copy_limit: Option<CopyLimit>,
     /// Requesting user (for authorization).
     pub user_id: Uuid,
 }
@@ -136,6 +143,7 @@ impl UpdateDeckProfile {
             land_target: None,
             price_target: None,
             price_target_currency: None,
+            copy_limit: None,
         }
     }
 }
@@ -157,6 +165,7 @@ pub struct UpdateDeckProfileBuilder {
     land_target: Option<Option<i32>>,
     price_target: Option<Option<f64>>,
     price_target_currency: Option<Option<PriceCurrency>>,
+    copy_limit: Option<CopyLimit>,
 }
 
 impl UpdateDeckProfileBuilder {
@@ -248,6 +257,13 @@ impl UpdateDeckProfileBuilder {
         self
     }
 
+    /// Sets a house-rule copy limit. `None` leaves it untouched, unless the
+    /// format changes too.
+    pub fn copy_limit(mut self, copy_limit: Option<CopyLimit>) -> Self {
+        self.copy_limit = copy_limit;
+        self
+    }
+
     /// Validates and builds the request.
     pub fn build(self) -> Result<UpdateDeckProfile, InvalidUpdateDeckProfile> {
         if self.name.is_none()
@@ -263,6 +279,7 @@ impl UpdateDeckProfileBuilder {
             && self.land_target.is_none()
             && self.price_target.is_none()
             && self.price_target_currency.is_none()
+            && self.copy_limit.is_none()
         {
             return Err(InvalidUpdateDeckProfile::NoUpdates);
         }
@@ -305,6 +322,12 @@ impl UpdateDeckProfileBuilder {
                 Some(deduped)
             }
         };
+        let copy_limit = self
+            .copy_limit
+            .or_else(|| format.map(CopyLimit::for_format));
+        if copy_limit.is_some_and(|limit| limit.max_copies() == 0) {
+            return Err(InvalidUpdateDeckProfile::ZeroCopyLimit);
+        }
 
         Ok(UpdateDeckProfile {
             deck_id: self.deck_id,
@@ -321,7 +344,30 @@ impl UpdateDeckProfileBuilder {
             land_target: self.land_target,
             price_target: self.price_target,
             price_target_currency: self.price_target_currency,
+            copy_limit,
             user_id: self.user_id,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_change_resets_the_copy_limit_unless_one_is_given() {
+        let builder = || UpdateDeckProfile::builder(Uuid::new_v4(), Uuid::new_v4());
+        let to_commander = builder().format(Some(Some("commander"))).build().unwrap();
+        assert_eq!(to_commander.copy_limit, Some(CopyLimit::Singleton));
+        let cleared = builder().format(Some(None)).build().unwrap();
+        assert_eq!(cleared.copy_limit, Some(CopyLimit::Standard));
+        let house = builder()
+            .format(Some(Some("commander")))
+            .copy_limit(Some(CopyLimit::Custom(3)))
+            .build()
+            .unwrap();
+        assert_eq!(house.copy_limit, Some(CopyLimit::Custom(3)));
+        let renamed = builder().name(Some("Renamed")).build().unwrap();
+        assert_eq!(renamed.copy_limit, None);
+    }
+}
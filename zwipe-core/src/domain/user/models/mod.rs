@@ -1,6 +1,7 @@
 pub mod email;
 pub mod hints;
 pub mod preferences;
+pub mod role;
 pub mod theme;
 pub mod username;
 
@@ -9,6 +10,7 @@ pub use hints::{InvalidHintKey, MarkHintShown};
 pub use preferences::{
     ALLOWED_THEMES, InvalidUpdatePreferences, UpdatePreferences, UserPreferences,
 };
+pub use role::{InvalidUserRole, UserRole};
 pub use username::{InvalidUsername, Username};
 
 use chrono::{DateTime, Utc};
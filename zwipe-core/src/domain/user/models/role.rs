@@ -0,0 +1,47 @@
+//! Account role, gating maintainer-only endpoints.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Error returned when parsing an unknown role string.
+#[derive(Debug, Clone, Error)]
+#[error("invalid user role: {0}")]
+pub struct InvalidUserRole(pub String);
+
+/// What an account may do beyond its own data.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserRole {
+    /// A regular account.
+    #[default]
+    User,
+    /// A maintainer; may call admin endpoints.
+    Admin,
+}
+
+impl UserRole {
+    /// Returns `true` for [`UserRole::Admin`].
+    pub fn is_admin(&self) -> bool {
+        matches!(self, Self::Admin)
+    }
+}
+
+impl TryFrom<&str> for UserRole {
+    type Error = InvalidUserRole;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "user" => Ok(Self::User),
+            "admin" => Ok(Self::Admin),
+            other => Err(InvalidUserRole(other.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for UserRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::User => write!(f, "user"),
+            Self::Admin => write!(f, "admin"),
+        }
+    }
+}
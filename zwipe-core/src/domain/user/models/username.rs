@@ -60,6 +60,9 @@ pub enum InvalidUsername {
 /// - **No Profanity**: Checked against bad word dictionary
 /// - **Trimmed**: Leading/trailing whitespace removed
 ///
+/// The casing is kept as entered and shown as-is, but uniqueness is
+/// case-insensitive: the server refuses "john" once "John" exists.
+///
 /// # Immutability
 ///
 /// Once created, a `Username` cannot be modified (no `pub` fields, no setters).
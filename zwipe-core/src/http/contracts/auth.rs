@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::domain::auth::models::platform::ClientPlatform;
+use crate::domain::auth::models::{api_key::ApiKeyScope, platform::ClientPlatform};
 
 /// Login request body.
 ///
@@ -171,6 +171,25 @@ pub struct HttpResetPassword {
     pub new_password: String,
 }
 
+/// API key creation request body.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HttpCreateApiKey {
+    /// Name to tell the key apart by (e.g. the tool it's for).
+    pub label: String,
+    /// What the key may do.
+    pub scope: ApiKeyScope,
+}
+
+impl HttpCreateApiKey {
+    /// Creates a new API key request.
+    pub fn new(label: &str, scope: ApiKeyScope) -> Self {
+        Self {
+            label: label.to_string(),
+            scope,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,3 +246,47 @@ mod tests {
         assert_eq!(back.client_version.as_deref(), Some("1.6.2"));
     }
 }
+
+/// Why the server rejected a request's bearer token. Lets the client tell
+/// "refresh and retry" (`TokenExpired`) from "log in again" (the others).
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenErrorCode {
+    /// No `Authorization` header was sent.
+    TokenMissing,
+    /// The token verified but its `exp` has passed.
+    TokenExpired,
+    /// Malformed header, unparseable token, or a bad signature.
+    TokenInvalid,
+    /// A valid read-only API key was sent on a request that changes data.
+    /// Answered with `403`, not `401`: re-authenticating won't help.
+    InsufficientScope,
+}
+
+impl TokenErrorCode {
+    /// Human-readable message sent alongside the code.
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::TokenMissing => "missing authorization token",
+            Self::TokenExpired => "authorization token expired",
+            Self::TokenInvalid => "invalid authorization token",
+            Self::InsufficientScope => "api key is read-only",
+        }
+    }
+}
+
+/// JSON body of a `401` (or scope `403`) from an authenticated route.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct HttpTokenError {
+    pub code: TokenErrorCode,
+    pub message: String,
+}
+
+impl From<TokenErrorCode> for HttpTokenError {
+    fn from(code: TokenErrorCode) -> Self {
+        Self {
+            code,
+            message: code.message().to_string(),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Card HTTP request contracts.
+
+use crate::domain::card::scryfall_data::colors::Colors;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// One favorite toggle: star (`true`) or unstar (`false`) a card.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct HttpFavoriteChange {
+    /// Scryfall data ID of the card (the printing the user swiped on).
+    pub scryfall_data_id: Uuid,
+    /// Target state: `true` = favorite, `false` = not a favorite.
+    pub favorite: bool,
+}
+
+/// Bulk favorite/unfavorite request body. Changes apply in order; when a card
+/// appears more than once, its last entry wins.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HttpSetFavorites {
+    /// Favorite toggles batched from rapid swiping.
+    pub changes: Vec<HttpFavoriteChange>,
+}
+
+/// Undiscovered staples request body: popular cards playable under
+/// `color_identity` that none of the caller's decks contain.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HttpUndiscoveredStaples {
+    /// Color identity the cards must fit within (`[]` for colorless only).
+    pub color_identity: Colors,
+    /// Most cards to return (capped server-side).
+    pub limit: u32,
+}
@@ -6,7 +6,10 @@ use uuid::Uuid;
 use crate::{
     domain::{
         card::{Card, search_card::card_filter::price_currency::PriceCurrency},
-        deck::{DeckEntry, DeckOtherTag, ImportMode, PowerLevel, format::Format},
+        deck::{
+            CopyLimit, DeckEntry, DeckOtherTag, ExportFormat, ImportMode, PowerLevel,
+            format::Format,
+        },
     },
     http::helpers::Opdate,
 };
@@ -70,6 +73,9 @@ pub struct HttpCreateDeckProfile {
     /// Currency for the price target. Absent = USD.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub price_target_currency: Option<PriceCurrency>,
+    /// House-rule copy limit. Absent = the format's limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copy_limit: Option<CopyLimit>,
 }
 
 impl HttpCreateDeckProfile {
@@ -89,6 +95,7 @@ impl HttpCreateDeckProfile {
             land_target: None,
             price_target: None,
             price_target_currency: None,
+            copy_limit: None,
         }
     }
 }
@@ -108,6 +115,7 @@ pub struct HttpCreateDeckProfileBuilder {
     land_target: Option<i32>,
     price_target: Option<f64>,
     price_target_currency: Option<PriceCurrency>,
+    copy_limit: Option<CopyLimit>,
 }
 
 impl HttpCreateDeckProfileBuilder {
@@ -183,6 +191,12 @@ impl HttpCreateDeckProfileBuilder {
         self
     }
 
+    /// Sets a house-rule copy limit.
+    pub fn copy_limit(mut self, copy_limit: Option<CopyLimit>) -> Self {
+        self.copy_limit = copy_limit;
+        self
+    }
+
     /// Builds the request.
     pub fn build(self) -> HttpCreateDeckProfile {
         HttpCreateDeckProfile {
@@ -199,6 +213,7 @@ impl HttpCreateDeckProfileBuilder {
             land_target: self.land_target,
             price_target: self.price_target,
             price_target_currency: self.price_target_currency,
+            copy_limit: self.copy_limit,
         }
     }
 }
@@ -252,6 +267,10 @@ pub struct HttpUpdateDeckProfile {
     /// Price target currency with partial update semantics. `#[serde(default)]`.
     #[serde(default)]
     pub price_target_currency: Opdate<PriceCurrency>,
+    /// House-rule copy limit. Absent leaves it unchanged, unless `format`
+    /// changes, which resets it to the new format's limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copy_limit: Option<CopyLimit>,
 }
 
 impl HttpUpdateDeckProfile {
@@ -271,6 +290,7 @@ impl HttpUpdateDeckProfile {
             land_target: Opdate::Unchanged,
             price_target: Opdate::Unchanged,
             price_target_currency: Opdate::Unchanged,
+            copy_limit: None,
         }
     }
 }
@@ -290,6 +310,7 @@ pub struct HttpUpdateDeckProfileBuilder {
     land_target: Opdate<i32>,
     price_target: Opdate<f64>,
     price_target_currency: Opdate<PriceCurrency>,
+    copy_limit: Option<CopyLimit>,
 }
 
 impl HttpUpdateDeckProfileBuilder {
@@ -371,6 +392,12 @@ impl HttpUpdateDeckProfileBuilder {
         self
     }
 
+    /// Sets the copy limit update.
+    pub fn copy_limit(mut self, copy_limit: Option<CopyLimit>) -> Self {
+        self.copy_limit = copy_limit;
+        self
+    }
+
     /// Builds the request.
     pub fn build(self) -> HttpUpdateDeckProfile {
         HttpUpdateDeckProfile {
@@ -387,6 +414,7 @@ impl HttpUpdateDeckProfileBuilder {
             land_target: self.land_target,
             price_target: self.price_target,
             price_target_currency: self.price_target_currency,
+            copy_limit: self.copy_limit,
         }
     }
 }
@@ -401,7 +429,34 @@ pub struct HttpCloneDeck {
     pub new_name: String,
 }
 
-/// Deck clone response body.
+/// Shared deck import request body.
+///
+/// The share token comes from the URL path; the caller is identified by the
+/// JWT. `#[serde(default)]` so an empty body keeps the shared deck's name.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct HttpImportSharedDeck {
+    /// Name for the copy; the shared deck's name when omitted.
+    #[serde(default)]
+    pub new_name: Option<String>,
+}
+
+/// Create a deck from a pasted decklist request body.
+///
+/// Responds with an `ImportReport`: the new deck plus the lines that matched
+/// no card (or more than one).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HttpImportDecklist {
+    /// Name for the new deck.
+    pub name: String,
+    /// Decklist text.
+    pub text: String,
+    /// Which format the text is in. Values: `"plain_text"` (default),
+    /// `"arena"`, `"mtgo"`.
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
+/// Deck clone (and shared deck import) response body.
 ///
 /// Returns only the new deck id; the client navigates to the deck view
 /// which loads the full aggregate via its own resources.
@@ -435,6 +490,37 @@ pub struct HttpDeckShareToken {
     pub share_token: Uuid,
 }
 
+/// Deck-listing query string (GET `/api/deck?label=cedh`).
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct HttpDeckProfilesQuery {
+    /// Only decks carrying this label (compared normalized).
+    pub label: Option<String>,
+}
+
+/// Set-labels request body (PUT `/api/deck/{deck_id}/labels`).
+///
+/// Replaces every label on the deck; an empty list clears them. Labels are
+/// trimmed, lowercased and deduped server-side.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HttpSetDeckLabels {
+    /// The deck's new labels.
+    pub labels: Vec<String>,
+}
+
+/// Set-labels response body: the labels as stored (normalized, sorted).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HttpDeckLabels {
+    /// The deck's labels.
+    pub labels: Vec<String>,
+}
+
+/// Set-owned request body (PUT `/api/collection/{oracle_id}`).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HttpSetOwnedCard {
+    /// Copies owned; `0` removes the card from the collection.
+    pub quantity: i32,
+}
+
 /// Public shared-deck response body (GET `/api/share/deck/{token}`).
 ///
 /// Deliberately carries **no user identity** — no username, user id, or
@@ -1,6 +1,9 @@
 //! Deck card operation HTTP request contracts.
 
-use crate::domain::{card::scryfall_data::ScryfallData, deck::ImportMode};
+use crate::domain::{
+    card::scryfall_data::ScryfallData,
+    deck::{CardReference, ImportMode},
+};
 use serde::{Deserialize, Serialize};
 
 /// Add card to deck request body.
@@ -14,6 +17,10 @@ pub struct HttpCreateDeckCard {
     pub quantity: i32,
     /// Board to place the card on ("deck", "maybeboard", "sideboard"). Defaults to "deck" if absent.
     pub board: Option<String>,
+    /// `"printing"` pins the selected printing, `"oracle"` follows the card's
+    /// preferred printing. Defaults to `"printing"` if absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<CardReference>,
 }
 
 impl HttpCreateDeckCard {
@@ -30,10 +37,20 @@ impl HttpCreateDeckCard {
                 .unwrap_or_default(),
             quantity,
             board,
+            reference: None,
         }
     }
 }
 
+/// Query string selecting which copy of a card an update or delete targets
+/// (`?board=sideboard`). A card can sit on the sideboard and, separately, on
+/// the deck or maybeboard; absent = the main copy if there is one.
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct HttpDeckCardBoard {
+    /// "deck", "maybeboard" or "sideboard".
+    pub board: Option<String>,
+}
+
 /// Card update request body.
 ///
 /// At least one field must be provided. `update_quantity` is a **delta** added
@@ -50,6 +67,10 @@ pub struct HttpUpdateDeckCard {
     /// Star (true) or unstar (false) this card as a deck MVP. Absent = untouched.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mvp: Option<bool>,
+    /// Switch between pinning the printing and following the oracle card.
+    /// Absent = untouched.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference: Option<CardReference>,
 }
 
 impl HttpUpdateDeckCard {
@@ -60,6 +81,7 @@ impl HttpUpdateDeckCard {
             board,
             scryfall_data_id: None,
             mvp: None,
+            reference: None,
         }
     }
 
@@ -70,6 +92,7 @@ impl HttpUpdateDeckCard {
             board: None,
             scryfall_data_id: Some(scryfall_data_id.to_string()),
             mvp: None,
+            reference: None,
         }
     }
 
@@ -81,6 +104,19 @@ impl HttpUpdateDeckCard {
             board: None,
             scryfall_data_id: None,
             mvp: Some(mvp),
+            reference: None,
+        }
+    }
+
+    /// Creates an update request that pins the printing or makes the card
+    /// follow its oracle card's preferred printing.
+    pub fn with_reference(reference: CardReference) -> Self {
+        Self {
+            update_quantity: None,
+            board: None,
+            scryfall_data_id: None,
+            mvp: None,
+            reference: Some(reference),
         }
     }
 }
@@ -101,3 +137,31 @@ pub struct HttpImportDeckCards {
     #[serde(default)]
     pub mode: ImportMode,
 }
+
+/// Move cards to another deck request body.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct HttpMoveDeckCards {
+    /// Deck the cards move to; must be owned by the caller.
+    pub to_deck_id: String,
+    /// Cards to move and how many copies of each.
+    pub cards: Vec<HttpMovedDeckCard>,
+}
+
+/// One card in a [`HttpMoveDeckCards`] request.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct HttpMovedDeckCard {
+    /// Scryfall data ID of the card in the source deck.
+    pub scryfall_data_id: String,
+    /// Copies to move.
+    pub quantity: i32,
+}
+
+/// Bulk add cards to a deck request body.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct HttpBulkAddDeckCards {
+    /// Cards to add and how many copies of each.
+    pub cards: Vec<HttpMovedDeckCard>,
+    /// Board to place new entries on ("deck", "maybeboard", "sideboard").
+    /// Defaults to "deck" if absent.
+    pub board: Option<String>,
+}
@@ -2,6 +2,8 @@
 
 /// Authentication request contracts.
 pub mod auth;
+/// Card request contracts (favorites).
+pub mod card;
 /// Changelog contracts (release-history feed).
 pub mod changelog;
 /// Client metadata contracts (app version gating).
@@ -1,6 +1,7 @@
 //! Path constants shared between frontend and backend for URL consistency.
 #![allow(missing_docs)]
 
+use crate::domain::deck::ExportFormat;
 use uuid::Uuid;
 
 pub fn health_route() -> String {
@@ -55,10 +56,22 @@ pub fn mark_hint_shown_route() -> String {
     "/api/user/hint".to_string()
 }
 
+pub fn me_route() -> String {
+    "/api/auth/me".to_string()
+}
+
 pub fn logout_route() -> String {
     "/api/auth/logout".to_string()
 }
 
+pub fn sessions_route() -> String {
+    "/api/auth/sessions".to_string()
+}
+
+pub fn session_route(session_id: Uuid) -> String {
+    format!("/api/auth/sessions/{}", session_id)
+}
+
 pub fn verify_email_route() -> String {
     "/api/auth/verify-email".to_string()
 }
@@ -75,22 +88,62 @@ pub fn resend_verification_route() -> String {
     "/api/auth/resend-verification".to_string()
 }
 
+pub fn api_keys_route() -> String {
+    "/api/auth/api-keys".to_string()
+}
+
+pub fn api_key_route(key_id: Uuid) -> String {
+    format!("/api/auth/api-keys/{}", key_id)
+}
+
 pub fn get_card_route(scryfall_data_id: Uuid) -> String {
     format!("/api/card/{}", scryfall_data_id)
 }
 
+pub fn admin_raw_card_route(scryfall_data_id: Uuid) -> String {
+    format!("/api/admin/card/{}/raw", scryfall_data_id)
+}
+
 pub fn get_printings_route(oracle_id: Uuid) -> String {
     format!("/api/card/{}/printings", oracle_id)
 }
 
+pub fn get_card_with_printings_route(scryfall_data_id: Uuid) -> String {
+    format!("/api/card/{}/with-printings", scryfall_data_id)
+}
+
 pub fn search_cards_route() -> String {
     "/api/card/search".to_string()
 }
 
+pub fn search_card_summaries_route() -> String {
+    "/api/card/search/summary".to_string()
+}
+
+pub fn card_color_distribution_route() -> String {
+    "/api/card/search/color-distribution".to_string()
+}
+
+pub fn undiscovered_staples_route() -> String {
+    "/api/card/undiscovered-staples".to_string()
+}
+
 pub fn search_commanders_route() -> String {
     "/api/card/search/commanders".to_string()
 }
 
+pub fn list_considering_route() -> String {
+    "/api/card/considering".to_string()
+}
+
+pub fn considering_card_route(scryfall_data_id: Uuid) -> String {
+    format!("/api/card/considering/{}", scryfall_data_id)
+}
+
+pub fn favorites_route() -> String {
+    "/api/card/favorites".to_string()
+}
+
 pub fn get_artists_route() -> String {
     "api/card/artists".to_string()
 }
@@ -123,6 +176,14 @@ pub fn get_sets_route() -> String {
     "api/card/sets".to_string()
 }
 
+pub fn set_completion_route(set_code: &str) -> String {
+    format!("/api/card/sets/{}/completion", set_code)
+}
+
+pub fn get_set_icon_route(set_code: &str) -> String {
+    format!("/api/card/sets/{}/icon", set_code)
+}
+
 pub fn get_languages_route() -> String {
     "/api/card/languages".to_string()
 }
@@ -139,6 +200,10 @@ pub fn get_deck_tokens_route(deck_id: Uuid) -> String {
     format!("/api/deck/{}/tokens", deck_id)
 }
 
+pub fn proxy_sheet_route(deck_id: Uuid) -> String {
+    format!("/api/deck/{}/proxies", deck_id)
+}
+
 pub fn get_deck_profiles_route() -> String {
     "/api/deck".to_string()
 }
@@ -155,6 +220,14 @@ pub fn delete_deck_route(deck_id: Uuid) -> String {
     format!("/api/deck/{}", deck_id)
 }
 
+pub fn find_orphaned_deck_cards_route() -> String {
+    "/api/deck/orphaned-cards".to_string()
+}
+
+pub fn repair_orphaned_deck_cards_route() -> String {
+    "/api/deck/orphaned-cards/repair".to_string()
+}
+
 pub fn clone_deck_route(source_deck_id: Uuid) -> String {
     format!("/api/deck/{}/clone", source_deck_id)
 }
@@ -163,10 +236,54 @@ pub fn share_deck_route(deck_id: Uuid) -> String {
     format!("/api/deck/{}/share", deck_id)
 }
 
+pub fn deck_labels_route(deck_id: Uuid) -> String {
+    format!("/api/deck/{}/labels", deck_id)
+}
+
+pub fn export_deck_json_route(deck_id: Uuid) -> String {
+    format!("/api/deck/{}/export", deck_id)
+}
+
+pub fn export_deck_route(deck_id: Uuid, format: ExportFormat) -> String {
+    format!("/api/deck/{}/export/{}", deck_id, format.code())
+}
+
+pub fn deck_stats_route(deck_id: Uuid) -> String {
+    format!("/api/deck/{}/stats", deck_id)
+}
+
+pub fn import_deck_json_route() -> String {
+    "/api/deck/import".to_string()
+}
+
+pub fn import_decklist_route() -> String {
+    "/api/deck/import/text".to_string()
+}
+
+pub fn deck_missing_cards_route(deck_id: Uuid) -> String {
+    format!("/api/deck/{}/missing", deck_id)
+}
+
+pub fn collection_route() -> String {
+    "/api/collection".to_string()
+}
+
+pub fn owned_card_route(oracle_id: Uuid) -> String {
+    format!("/api/collection/{}", oracle_id)
+}
+
+pub fn suggest_deck_name_route(commander_id: Uuid) -> String {
+    format!("/api/deck/name-suggestions/{}", commander_id)
+}
+
 pub fn get_shared_deck_route(token: Uuid) -> String {
     format!("/api/share/deck/{}", token)
 }
 
+pub fn import_shared_deck_route(token: Uuid) -> String {
+    format!("/api/deck/shared/{}", token)
+}
+
 pub fn clear_deck_suppressions_route(deck_id: Uuid) -> String {
     format!("/api/deck/{}/suppressions", deck_id)
 }
@@ -203,6 +320,10 @@ pub fn import_deck_cards_route(deck_id: Uuid) -> String {
     format!("/api/deck/{}/card/import", deck_id)
 }
 
+pub fn bulk_add_deck_cards_route(deck_id: Uuid) -> String {
+    format!("/api/deck/{}/card/bulk", deck_id)
+}
+
 pub fn record_usage_route() -> String {
     "/api/metrics/usage".to_string()
 }
@@ -28,6 +28,7 @@ pub fn make_entry(name: &str, qty: i32) -> DeckEntry {
             quantity: Quantity::new(qty).unwrap(),
             board: Board::default(),
             mvp_at: None,
+            reference: Default::default(),
         },
     }
 }
@@ -45,6 +46,7 @@ pub fn make_card(name: &str) -> Card {
             oracle_tags: vec![],
             oracle_tags_by_role: Default::default(),
             other_oracle_tags: vec![],
+            copy_limit: None,
             created_at: NaiveDate::from_ymd_opt(2021, 1, 1)
                 .unwrap()
                 .and_hms_opt(0, 0, 0)
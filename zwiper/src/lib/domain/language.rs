@@ -3,6 +3,8 @@
 //! Provides conversions from Scryfall language codes (e.g., "en", "ja") to
 //! full display names for UI presentation.
 
+use zwipe_core::domain::card::scryfall_data::language::Language;
+
 /// Extension trait to convert language codes to full display names.
 ///
 /// # Fallback Behavior
@@ -15,26 +17,7 @@ pub trait LanguageCodeToFullName {
 
 impl LanguageCodeToFullName for str {
     fn language_code_to_full_name(&self) -> &str {
-        match self {
-            "en" => "English",
-            "es" => "Spanish",
-            "fr" => "French",
-            "de" => "German",
-            "it" => "Italian",
-            "pt" => "Portuguese",
-            "ja" => "Japanese",
-            "ko" => "Korean",
-            "ru" => "Russian",
-            "zhs" => "Simplified Chinese",
-            "zht" => "Traditional Chinese",
-            "ar" => "Arabic",
-            "grc" => "Ancient Greek",
-            "he" => "Hebrew",
-            "la" => "Latin",
-            "ph" => "Phyrexian",
-            "qya" => "Quenya",
-            "sa" => "Sanskrit",
-            _ => self, // Fallback: show the code itself for unknown languages
-        }
+        // Fallback: show the code itself for unknown languages
+        Language::try_from(self).map_or(self, |language| language.display_name())
     }
 }
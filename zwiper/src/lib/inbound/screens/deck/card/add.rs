@@ -1017,6 +1017,7 @@ pub fn Add(deck_id: Uuid) -> Element {
                                     quantity: Quantity::one(),
                                     board: Board::Maybeboard,
                                     mvp_at: None,
+                                    reference: Default::default(),
                                 },
                             });
                             toast.success(